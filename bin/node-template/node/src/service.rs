@@ -192,16 +192,23 @@ pub fn new_full(mut config: Configuration) -> Result<TaskManager, ServiceError>
 		Vec::default(),
 	));
 
-	let (network, system_rpc_tx, tx_handler_controller, network_starter, sync_service) =
-		sc_service::build_network(sc_service::BuildNetworkParams {
-			config: &config,
-			client: client.clone(),
-			transaction_pool: transaction_pool.clone(),
-			spawn_handle: task_manager.spawn_handle(),
-			import_queue,
-			block_announce_validator_builder: None,
-			warp_sync_params: Some(WarpSyncParams::WithProvider(warp_sync)),
-		})?;
+	let (
+		network,
+		system_rpc_tx,
+		tx_handler_controller,
+		network_starter,
+		sync_service,
+		bitswap_client,
+	) = sc_service::build_network(sc_service::BuildNetworkParams {
+		config: &config,
+		client: client.clone(),
+		transaction_pool: transaction_pool.clone(),
+		spawn_handle: task_manager.spawn_handle(),
+		import_queue,
+		block_announce_validator_builder: None,
+		warp_sync_params: Some(WarpSyncParams::WithProvider(warp_sync)),
+		peer_authorization: None,
+	})?;
 
 	if config.offchain_worker.enabled {
 		sc_service::build_offchain_workers(
@@ -209,6 +216,7 @@ pub fn new_full(mut config: Configuration) -> Result<TaskManager, ServiceError>
 			task_manager.spawn_handle(),
 			client.clone(),
 			network.clone(),
+			Some(bitswap_client.clone()),
 		);
 	}
 
@@ -241,6 +249,7 @@ pub fn new_full(mut config: Configuration) -> Result<TaskManager, ServiceError>
 		system_rpc_tx,
 		tx_handler_controller,
 		sync_service: sync_service.clone(),
+		bitswap_client: Some(bitswap_client),
 		config,
 		telemetry: telemetry.as_mut(),
 	})?;
@@ -37,6 +37,14 @@ pub struct Cli {
 	#[arg(long)]
 	pub no_hardware_benchmarks: bool,
 
+	/// Prune the canonical offchain MMR nodes that are only needed to prove leaves older than
+	/// the last `mmr_pruning_depth` ones.
+	///
+	/// By default all the canonical nodes are kept. Only has an effect if offchain indexing is
+	/// enabled.
+	#[arg(long, value_name = "LEAVES")]
+	pub mmr_pruning_depth: Option<u64>,
+
 	#[allow(missing_docs)]
 	#[clap(flatten)]
 	pub storage_monitor: sc_storage_monitor::StorageMonitorParams,
@@ -84,15 +92,24 @@ pub enum Subcommand {
 	/// Validate blocks.
 	CheckBlock(sc_cli::CheckBlockCmd),
 
+	/// Check the integrity of the database.
+	CheckDb(sc_cli::CheckDbCmd),
+
 	/// Export blocks.
 	ExportBlocks(sc_cli::ExportBlocksCmd),
 
 	/// Export the state of a given block into a chain spec.
 	ExportState(sc_cli::ExportStateCmd),
 
+	/// Export the state of a given block into a binary snapshot.
+	ExportSnapshot(sc_cli::ExportSnapshotCmd),
+
 	/// Import blocks.
 	ImportBlocks(sc_cli::ImportBlocksCmd),
 
+	/// Import a binary state snapshot to start the node from its block.
+	ImportSnapshot(sc_cli::ImportSnapshotCmd),
+
 	/// Remove the whole chain.
 	PurgeChain(sc_cli::PurgeChainCmd),
 
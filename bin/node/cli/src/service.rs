@@ -29,18 +29,24 @@ use kitchensink_runtime::RuntimeApi;
 use node_executor::ExecutorDispatch;
 use node_primitives::Block;
 use sc_client_api::BlockBackend;
-use sc_consensus_babe::{self, SlotProportion};
+use sc_consensus_babe::{self, CompatibleDigestItem, SlotProportion};
 use sc_executor::NativeElseWasmExecutor;
 use sc_network::NetworkService;
 use sc_network_common::{
-	protocol::event::Event, service::NetworkEventStream, sync::warp::WarpSyncParams,
+	protocol::event::Event,
+	service::{NetworkEventStream, NetworkStateInfo},
+	sync::warp::WarpSyncParams,
 };
 use sc_network_sync::SyncingService;
 use sc_service::{config::Configuration, error::Error as ServiceError, RpcHandlers, TaskManager};
 use sc_telemetry::{Telemetry, TelemetryWorker};
 use sp_api::ProvideRuntimeApi;
 use sp_core::crypto::Pair;
-use sp_runtime::{generic, traits::Block as BlockT, SaturatedConversion};
+use sp_runtime::{
+	generic,
+	traits::{Block as BlockT, Header as HeaderT},
+	SaturatedConversion,
+};
 use std::sync::Arc;
 
 /// The full client type definition.
@@ -185,6 +191,16 @@ pub fn new_partial(
 		telemetry
 	});
 
+	// With `--state-pruning sparse-archive:external`, keep the state of the blocks starting a
+	// new epoch.
+	backend.set_state_checkpoint_predicate(|header: &<Block as BlockT>::Header| {
+		header
+			.digest()
+			.logs()
+			.iter()
+			.any(|log| log.as_next_epoch_descriptor().is_some())
+	});
+
 	let select_chain = sc_consensus::LongestChain::new(backend.clone());
 
 	let transaction_pool = sc_transaction_pool::BasicPool::new_full(
@@ -316,6 +332,7 @@ pub struct NewFullBase {
 pub fn new_full_base(
 	mut config: Configuration,
 	disable_hardware_benchmarks: bool,
+	mmr_pruning_depth: Option<u64>,
 	with_startup_data: impl FnOnce(
 		&sc_consensus_babe::BabeBlockImport<Block, FullClient, FullGrandpaBlockImport>,
 		&sc_consensus_babe::BabeLink<Block>,
@@ -355,17 +372,45 @@ pub fn new_full_base(
 		import_setup.1.shared_authority_set().clone(),
 		Vec::default(),
 	));
+	let node_authorization =
+		Arc::new(sc_network_authorization::NodeAuthorization::new(client.clone()));
 
-	let (network, system_rpc_tx, tx_handler_controller, network_starter, sync_service) =
-		sc_service::build_network(sc_service::BuildNetworkParams {
-			config: &config,
-			client: client.clone(),
-			transaction_pool: transaction_pool.clone(),
-			spawn_handle: task_manager.spawn_handle(),
-			import_queue,
-			block_announce_validator_builder: None,
-			warp_sync_params: Some(WarpSyncParams::WithProvider(warp_sync)),
-		})?;
+	let (
+		network,
+		system_rpc_tx,
+		tx_handler_controller,
+		network_starter,
+		sync_service,
+		bitswap_client,
+	) = sc_service::build_network(sc_service::BuildNetworkParams {
+		config: &config,
+		client: client.clone(),
+		transaction_pool: transaction_pool.clone(),
+		spawn_handle: task_manager.spawn_handle(),
+		import_queue,
+		block_announce_validator_builder: None,
+		warp_sync_params: Some(WarpSyncParams::WithProvider(warp_sync)),
+		peer_authorization: Some(node_authorization.clone()),
+	})?;
+
+	task_manager.spawn_handle().spawn(
+		"node-authorization",
+		None,
+		node_authorization.run(network.local_peer_id()),
+	);
+
+	if config.offchain_worker.indexing_enabled {
+		task_manager.spawn_handle().spawn(
+			"mmr-gadget",
+			None,
+			mmr_gadget::MmrGadget::start(
+				client.clone(),
+				backend.clone(),
+				sp_mmr_primitives::INDEXING_PREFIX.to_vec(),
+				mmr_pruning_depth,
+			),
+		);
+	}
 
 	if config.offchain_worker.enabled {
 		sc_service::build_offchain_workers(
@@ -373,6 +418,7 @@ pub fn new_full_base(
 			task_manager.spawn_handle(),
 			client.clone(),
 			network.clone(),
+			Some(bitswap_client.clone()),
 		);
 	}
 
@@ -396,6 +442,7 @@ pub fn new_full_base(
 		system_rpc_tx,
 		tx_handler_controller,
 		sync_service: sync_service.clone(),
+		bitswap_client: Some(bitswap_client),
 		telemetry: telemetry.as_mut(),
 	})?;
 
@@ -565,8 +612,9 @@ pub fn new_full_base(
 /// Builds a new service for a full client.
 pub fn new_full(config: Configuration, cli: Cli) -> Result<TaskManager, ServiceError> {
 	let database_source = config.database.clone();
-	let task_manager = new_full_base(config, cli.no_hardware_benchmarks, |_, _| ())
-		.map(|NewFullBase { task_manager, .. }| task_manager)?;
+	let task_manager =
+		new_full_base(config, cli.no_hardware_benchmarks, cli.mmr_pruning_depth, |_, _| ())
+			.map(|NewFullBase { task_manager, .. }| task_manager)?;
 
 	sc_storage_monitor::StorageMonitorService::try_spawn(
 		cli.storage_monitor,
@@ -643,6 +691,7 @@ mod tests {
 					new_full_base(
 						config,
 						false,
+						None,
 						|block_import: &sc_consensus_babe::BabeBlockImport<Block, _, _>,
 						 babe_link: &sc_consensus_babe::BabeLink<Block>| {
 							setup_handles = Some((block_import.clone(), babe_link.clone()));
@@ -821,7 +870,7 @@ mod tests {
 			crate::chain_spec::tests::integration_test_config_with_two_authorities(),
 			|config| {
 				let NewFullBase { task_manager, client, network, sync, transaction_pool, .. } =
-					new_full_base(config, false, |_, _| ())?;
+					new_full_base(config, false, None, |_, _| ())?;
 				Ok(sc_service_test::TestNetComponents::new(
 					task_manager,
 					client,
@@ -34,9 +34,10 @@ use frame_support::{
 	traits::{
 		fungible::ItemOf,
 		tokens::{nonfungibles_v2::Inspect, GetSalary},
-		AsEnsureOriginWithArg, ConstBool, ConstU128, ConstU16, ConstU32, Currency, EitherOfDiverse,
-		EqualPrivilegeOnly, Everything, Imbalance, InstanceFilter, KeyOwnerProofSystem,
-		LockIdentifier, Nothing, OnUnbalanced, U128CurrencyToVote, WithdrawReasons,
+		AsEnsureOriginWithArg, ConstBool, ConstU128, ConstU16, ConstU32, Contains, Currency,
+		EitherOfDiverse, EqualPrivilegeOnly, Everything, Imbalance, InstanceFilter,
+		KeyOwnerProofSystem, LockIdentifier, Nothing, OnUnbalanced, U128CurrencyToVote,
+		WithdrawReasons,
 	},
 	weights::{
 		constants::{
@@ -48,10 +49,11 @@ use frame_support::{
 };
 use frame_system::{
 	limits::{BlockLength, BlockWeights},
-	EnsureRoot, EnsureRootWithSuccess, EnsureSigned, EnsureWithSuccess,
+	EnsureNever, EnsureRoot, EnsureRootWithSuccess, EnsureSigned, EnsureWithSuccess,
 };
 pub use node_primitives::{AccountId, Signature};
 use node_primitives::{AccountIndex, Balance, BlockNumber, Hash, Index, Moment};
+use pallet_asset_conversion::NativeOrAssetId;
 use pallet_election_provider_multi_phase::SolutionAccuracyOf;
 use pallet_im_online::sr25519::AuthorityId as ImOnlineId;
 use pallet_nfts::PalletFeatures;
@@ -62,7 +64,7 @@ use pallet_transaction_payment::{FeeDetails, RuntimeDispatchInfo};
 use sp_api::impl_runtime_apis;
 use sp_authority_discovery::AuthorityId as AuthorityDiscoveryId;
 use sp_consensus_grandpa::AuthorityId as GrandpaId;
-use sp_core::{crypto::KeyTypeId, OpaqueMetadata};
+use sp_core::{crypto::KeyTypeId, OpaqueMetadata, OpaquePeerId};
 use sp_inherents::{CheckInherentsResult, InherentData};
 use sp_runtime::{
 	create_runtime_str,
@@ -75,7 +77,7 @@ use sp_runtime::{
 	transaction_validity::{TransactionPriority, TransactionSource, TransactionValidity},
 	ApplyExtrinsicResult, FixedPointNumber, FixedU128, Perbill, Percent, Permill, Perquintill,
 };
-use sp_std::prelude::*;
+use sp_std::{borrow::Cow, prelude::*};
 #[cfg(any(feature = "std", test))]
 use sp_version::NativeVersion;
 use sp_version::RuntimeVersion;
@@ -220,7 +222,7 @@ impl frame_system::Config for Runtime {
 	type Hash = Hash;
 	type Hashing = BlakeTwo256;
 	type AccountId = AccountId;
-	type Lookup = Indices;
+	type Lookup = pallet_identity::UsernameLookup<Runtime, Indices>;
 	type Header = generic::Header<BlockNumber, BlakeTwo256>;
 	type RuntimeEvent = RuntimeEvent;
 	type BlockHashCount = BlockHashCount;
@@ -289,6 +291,9 @@ pub enum ProxyType {
 	NonTransfer,
 	Governance,
 	Staking,
+	/// Only transfers of the native currency and of assets, for proxies usually held to a spending
+	/// allowance.
+	Transfer,
 }
 impl Default for ProxyType {
 	fn default() -> Self {
@@ -318,6 +323,17 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 					RuntimeCall::Treasury(..)
 			),
 			ProxyType::Staking => matches!(c, RuntimeCall::Staking(..)),
+			ProxyType::Transfer => matches!(
+				c,
+				RuntimeCall::Balances(pallet_balances::Call::transfer { .. }) |
+					RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive { .. }) |
+					RuntimeCall::Balances(pallet_balances::Call::transfer_all { .. }) |
+					RuntimeCall::Assets(pallet_assets::Call::transfer { .. }) |
+					RuntimeCall::Assets(pallet_assets::Call::transfer_keep_alive { .. }) |
+					RuntimeCall::Utility(pallet_utility::Call::batch { .. }) |
+					RuntimeCall::Utility(pallet_utility::Call::batch_all { .. }) |
+					RuntimeCall::Utility(pallet_utility::Call::force_batch { .. })
+			),
 		}
 	}
 	fn is_superset(&self, o: &Self) -> bool {
@@ -325,12 +341,35 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 			(x, y) if x == y => true,
 			(ProxyType::Any, _) => true,
 			(_, ProxyType::Any) => false,
+			(ProxyType::NonTransfer, ProxyType::Transfer) => false,
 			(ProxyType::NonTransfer, _) => true,
 			_ => false,
 		}
 	}
 }
 
+/// Only transfer proxies can be held to a spending allowance, as their calls can't spend funds
+/// indirectly.
+pub struct MeterableProxyTypes;
+impl Contains<ProxyType> for MeterableProxyTypes {
+	fn contains(proxy_type: &ProxyType) -> bool {
+		matches!(proxy_type, ProxyType::Transfer)
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct ProxyBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_proxy::BenchmarkHelper<u32, ProxyType> for ProxyBenchmarkHelper {
+	fn asset_id(i: u32) -> u32 {
+		i
+	}
+
+	fn meterable_proxy_type() -> ProxyType {
+		ProxyType::Transfer
+	}
+}
+
 impl pallet_proxy::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeCall = RuntimeCall;
@@ -344,6 +383,12 @@ impl pallet_proxy::Config for Runtime {
 	type CallHasher = BlakeTwo256;
 	type AnnouncementDepositBase = AnnouncementDepositBase;
 	type AnnouncementDepositFactor = AnnouncementDepositFactor;
+	type AssetId = u32;
+	type Assets = Assets;
+	type MaxAllowanceAssets = ConstU32<8>;
+	type MeterableProxyTypes = MeterableProxyTypes;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ProxyBenchmarkHelper;
 }
 
 parameter_types! {
@@ -380,6 +425,7 @@ impl pallet_preimage::Config for Runtime {
 	type WeightInfo = pallet_preimage::weights::SubstrateWeight<Runtime>;
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
+	type RuntimeHoldReason = RuntimeHoldReason;
 	type ManagerOrigin = EnsureRoot<AccountId>;
 	type BaseDeposit = PreimageBaseDeposit;
 	type ByteDeposit = PreimageByteDeposit;
@@ -437,12 +483,18 @@ parameter_types! {
 	// This number may need to be adjusted in the future if this assumption no longer holds true.
 	pub const MaxLocks: u32 = 50;
 	pub const MaxReserves: u32 = 50;
+	pub const MaxHolds: u32 = 10;
+	pub const MaxFreezes: u32 = 10;
 }
 
 impl pallet_balances::Config for Runtime {
 	type MaxLocks = MaxLocks;
 	type MaxReserves = MaxReserves;
 	type ReserveIdentifier = [u8; 8];
+	type HoldIdentifier = RuntimeHoldReason;
+	type FreezeIdentifier = RuntimeFreezeReason;
+	type MaxHolds = MaxHolds;
+	type MaxFreezes = MaxFreezes;
 	type Balance = Balance;
 	type DustRemoval = ();
 	type RuntimeEvent = RuntimeEvent;
@@ -478,8 +530,13 @@ impl pallet_transaction_payment::Config for Runtime {
 impl pallet_asset_tx_payment::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Fungibles = Assets;
+	// Fees are converted at the average price of the pool of the asset with the native currency,
+	// or at the ratio of the minimum balances for the assets without a pool.
 	type OnChargeAssetTransaction = pallet_asset_tx_payment::FungiblesAdapter<
-		pallet_assets::BalanceToAssetBalance<Balances, Runtime, ConvertInto>,
+		pallet_asset_conversion::PoolOrFallback<
+			Runtime,
+			pallet_assets::BalanceToAssetBalance<Balances, Runtime, ConvertInto>,
+		>,
 		CreditToBlockAuthor,
 	>;
 }
@@ -827,11 +884,11 @@ pub struct TracksInfo;
 impl pallet_referenda::TracksInfo<Balance, BlockNumber> for TracksInfo {
 	type Id = u16;
 	type RuntimeOrigin = <RuntimeOrigin as frame_support::traits::OriginTrait>::PalletsOrigin;
-	fn tracks() -> &'static [(Self::Id, pallet_referenda::TrackInfo<Balance, BlockNumber>)] {
+	fn tracks() -> Cow<'static, [(Self::Id, pallet_referenda::TrackInfo<Balance, BlockNumber>)]> {
 		static DATA: [(u16, pallet_referenda::TrackInfo<Balance, BlockNumber>); 1] = [(
 			0u16,
 			pallet_referenda::TrackInfo {
-				name: "root",
+				name: Cow::Borrowed("root"),
 				max_deciding: 1,
 				decision_deposit: 10,
 				prepare_period: 4,
@@ -850,7 +907,7 @@ impl pallet_referenda::TracksInfo<Balance, BlockNumber> for TracksInfo {
 				},
 			},
 		)];
-		&DATA[..]
+		Cow::Borrowed(&DATA[..])
 	}
 	fn track_for(id: &Self::RuntimeOrigin) -> Result<Self::Id, ()> {
 		if let Ok(system_origin) = frame_system::RawOrigin::try_from(id.clone()) {
@@ -901,10 +958,40 @@ impl pallet_referenda::Config<pallet_referenda::Instance2> for Runtime {
 	type MaxQueued = ConstU32<100>;
 	type UndecidingTimeout = UndecidingTimeout;
 	type AlarmInterval = AlarmInterval;
-	type Tracks = TracksInfo;
+	type Tracks = ReferendaTracks;
 	type Preimages = Preimage;
 }
 
+parameter_types! {
+	pub const MaxTracks: u32 = 32;
+	pub const MaxTrackNameLength: u32 = 32;
+}
+
+impl pallet_referenda_tracks::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type TrackId = u16;
+	type Balance = Balance;
+	type UpdateOrigin = EnsureRoot<AccountId>;
+	type MaxTracks = MaxTracks;
+	type MaxNameLength = MaxTrackNameLength;
+	type WeightInfo = pallet_referenda_tracks::weights::SubstrateWeight<Self>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+}
+
+/// The tracks `ReferendaTracks` starts with: the root track of [`TracksInfo`], mapped from the
+/// SCALE encoded root origin.
+pub fn genesis_referenda_tracks(
+) -> Vec<(u16, pallet_referenda::TrackInfo<Balance, BlockNumber>, Vec<u8>)> {
+	use pallet_referenda::TracksInfo as _;
+	let root = OriginCaller::system(frame_system::RawOrigin::Root);
+	TracksInfo::track_for(&root)
+		.ok()
+		.and_then(|id| TracksInfo::info(id).map(|info| (id, info.into_owned(), root.encode())))
+		.into_iter()
+		.collect()
+}
+
 impl pallet_ranked_collective::Config for Runtime {
 	type WeightInfo = pallet_ranked_collective::weights::SubstrateWeight<Self>;
 	type RuntimeEvent = RuntimeEvent;
@@ -999,6 +1086,7 @@ impl pallet_collective::Config<CouncilCollective> for Runtime {
 	type MaxProposals = CouncilMaxProposals;
 	type MaxMembers = CouncilMaxMembers;
 	type DefaultVote = pallet_collective::PrimeDefaultVote;
+	type VoteWeight = ();
 	type WeightInfo = pallet_collective::weights::SubstrateWeight<Runtime>;
 }
 
@@ -1058,6 +1146,7 @@ impl pallet_collective::Config<TechnicalCollective> for Runtime {
 	type MaxProposals = TechnicalMaxProposals;
 	type MaxMembers = TechnicalMaxMembers;
 	type DefaultVote = pallet_collective::PrimeDefaultVote;
+	type VoteWeight = ();
 	type WeightInfo = pallet_collective::weights::SubstrateWeight<Runtime>;
 }
 
@@ -1091,6 +1180,7 @@ parameter_types! {
 	pub const MaximumReasonLength: u32 = 300;
 	pub const MaxApprovals: u32 = 100;
 	pub const MaxBalance: Balance = Balance::max_value();
+	pub const PayoutPeriod: BlockNumber = 30 * DAYS;
 }
 
 impl pallet_treasury::Config for Runtime {
@@ -1116,6 +1206,11 @@ impl pallet_treasury::Config for Runtime {
 	type WeightInfo = pallet_treasury::weights::SubstrateWeight<Runtime>;
 	type MaxApprovals = MaxApprovals;
 	type SpendOrigin = EnsureWithSuccess<EnsureRoot<AccountId>, AccountId, MaxBalance>;
+	type Assets = Assets;
+	type BalanceConverter = pallet_assets::BalanceToAssetBalance<Balances, Runtime, ConvertInto>;
+	type PayoutPeriod = PayoutPeriod;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = pallet_treasury::CreateFundedAsset<Assets>;
 }
 
 parameter_types! {
@@ -1161,6 +1256,9 @@ impl pallet_message_queue::Config for Runtime {
 	type HeapSize = ConstU32<{ 64 * 1024 }>;
 	type MaxStale = ConstU32<128>;
 	type ServiceWeight = MessageQueueServiceWeight;
+	type QueuePausedQuery = ();
+	type QueuePriorityQuery = ();
+	type QueueServiceBudget = ();
 }
 
 parameter_types! {
@@ -1198,10 +1296,31 @@ parameter_types! {
 	pub Schedule: pallet_contracts::Schedule<Runtime> = Default::default();
 }
 
+#[cfg(feature = "runtime-benchmarks")]
+pub struct ContractsBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_contracts::BenchmarkHelper<AccountId, u32, Balance> for ContractsBenchmarkHelper {
+	fn create_asset(owner: &AccountId) -> u32 {
+		use frame_support::traits::fungibles::metadata::Mutate;
+		let id = 1;
+		Assets::force_create(RuntimeOrigin::root(), id.into(), owner.clone().into(), true, 1)
+			.expect("asset is created by root; qed");
+		Assets::set(id, owner, b"Benchmark".to_vec(), b"BNCH".to_vec(), 12)
+			.expect("the owner pays the metadata deposit; qed");
+		id
+	}
+
+	fn mint_into(asset: u32, who: &AccountId, amount: Balance) {
+		use frame_support::traits::fungibles::Mutate;
+		Assets::mint_into(asset, who, amount).expect("minting to an existing asset works; qed");
+	}
+}
+
 impl pallet_contracts::Config for Runtime {
 	type Time = Timestamp;
 	type Randomness = RandomnessCollectiveFlip;
 	type Currency = Balances;
+	type Fungibles = Assets;
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeCall = RuntimeCall;
 	/// The safest default is to allow no calls at all.
@@ -1213,6 +1332,8 @@ impl pallet_contracts::Config for Runtime {
 	type CallFilter = Nothing;
 	type DepositPerItem = DepositPerItem;
 	type DepositPerByte = DepositPerByte;
+	type DepositAsset = ();
+	type DepositConversion = pallet_assets::BalanceToAssetBalance<Balances, Runtime, ConvertInto>;
 	type CallStack = [pallet_contracts::Frame<Self>; 5];
 	type WeightPrice = pallet_transaction_payment::Pallet<Self>;
 	type WeightInfo = pallet_contracts::weights::SubstrateWeight<Self>;
@@ -1225,6 +1346,8 @@ impl pallet_contracts::Config for Runtime {
 	type MaxStorageKeyLen = ConstU32<128>;
 	type UnsafeUnstableInterface = ConstBool<false>;
 	type MaxDebugBufferLen = ConstU32<{ 2 * 1024 * 1024 }>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ContractsBenchmarkHelper;
 }
 
 impl pallet_sudo::Config for Runtime {
@@ -1355,6 +1478,7 @@ parameter_types! {
 	pub const MaxSubAccounts: u32 = 100;
 	pub const MaxAdditionalFields: u32 = 100;
 	pub const MaxRegistrars: u32 = 20;
+	pub const PendingUsernameExpiration: BlockNumber = 7 * DAYS;
 }
 
 impl pallet_identity::Config for Runtime {
@@ -1369,6 +1493,11 @@ impl pallet_identity::Config for Runtime {
 	type Slashed = Treasury;
 	type ForceOrigin = EnsureRootOrHalfCouncil;
 	type RegistrarOrigin = EnsureRootOrHalfCouncil;
+	type UsernameAuthorityOrigin = EnsureRootOrHalfCouncil;
+	type PendingUsernameExpiration = PendingUsernameExpiration;
+	type MaxSuffixLength = ConstU32<7>;
+	type MaxUsernameLength = ConstU32<32>;
+	type MaxUsernamesPerAccount = ConstU32<8>;
 	type WeightInfo = pallet_identity::weights::SubstrateWeight<Runtime>;
 }
 
@@ -1491,6 +1620,38 @@ impl pallet_assets::Config for Runtime {
 	type ApprovalDeposit = ApprovalDeposit;
 	type StringLimit = StringLimit;
 	type Freezer = ();
+	type HoldIdentifier = RuntimeHoldReason;
+	type FreezeIdentifier = RuntimeFreezeReason;
+	type MaxHolds = MaxHolds;
+	type MaxFreezes = MaxFreezes;
+	type Extra = ();
+	type CallbackHandle = ();
+	type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
+	type RemoveItemsLimit = ConstU32<1000>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+}
+
+impl pallet_assets::Config<pallet_assets::Instance2> for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = u128;
+	type AssetId = u32;
+	type AssetIdParameter = codec::Compact<u32>;
+	type AssetLink = ();
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<EnsureNever<AccountId>>;
+	type ForceOrigin = EnsureRoot<AccountId>;
+	type AssetDeposit = ConstU128<0>;
+	type AssetAccountDeposit = ConstU128<0>;
+	type MetadataDepositBase = ConstU128<0>;
+	type MetadataDepositPerByte = ConstU128<0>;
+	type ApprovalDeposit = ApprovalDeposit;
+	type StringLimit = StringLimit;
+	type Freezer = ();
+	type HoldIdentifier = RuntimeHoldReason;
+	type FreezeIdentifier = RuntimeFreezeReason;
+	type MaxHolds = MaxHolds;
+	type MaxFreezes = MaxFreezes;
 	type Extra = ();
 	type CallbackHandle = ();
 	type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
@@ -1499,6 +1660,53 @@ impl pallet_assets::Config for Runtime {
 	type BenchmarkHelper = ();
 }
 
+parameter_types! {
+	pub const AssetConversionPalletId: PalletId = PalletId(*b"py/ascon");
+	pub const PoolSetupFee: Balance = 1 * DOLLARS;
+	pub const MintMinLiquidity: Balance = 100;
+	pub PoolSetupFeeReceiver: AccountId = Treasury::account_id();
+	pub const AssetConversionPriceWindow: BlockNumber = 1 * HOURS;
+}
+
+impl pallet_asset_conversion::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type Balance = u128;
+	type AssetId = u32;
+	type Assets = Assets;
+	type PoolAssetId = u32;
+	type PoolAssets = PoolAssets;
+	type LPFee = ConstU32<3>;
+	type PoolSetupFee = PoolSetupFee;
+	type PoolSetupFeeReceiver = PoolSetupFeeReceiver;
+	type MintMinLiquidity = MintMinLiquidity;
+	type MaxSwapPathLength = ConstU32<4>;
+	type PalletId = AssetConversionPalletId;
+	type PriceWindow = AssetConversionPriceWindow;
+	type WeightInfo = pallet_asset_conversion::weights::SubstrateWeight<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+}
+
+parameter_types! {
+	pub const AssetVestingFreezeId: RuntimeFreezeReason =
+		RuntimeFreezeReason::AssetVesting(pallet_asset_vesting::FreezeReason::Vesting);
+}
+
+impl pallet_asset_vesting::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type AssetId = u32;
+	type Assets = Assets;
+	type FreezeId = AssetVestingFreezeId;
+	type BlockNumberToBalance = ConvertInto;
+	type WeightInfo = pallet_asset_vesting::weights::SubstrateWeight<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+	// As for `pallet_vesting`, 28 schedules encode in less than 2^10 bytes.
+	const MAX_VESTING_SCHEDULES: u32 = 28;
+}
+
 parameter_types! {
 	pub IgnoredIssuance: Balance = Treasury::pot();
 	pub const QueueCount: u32 = 300;
@@ -1539,6 +1747,17 @@ impl pallet_nis::Config for Runtime {
 	type ReserveId = NisReserveId;
 }
 
+impl pallet_node_authorization::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type MaxWellKnownNodes = ConstU32<8>;
+	type MaxPeerIdLength = ConstU32<128>;
+	type AddOrigin = EnsureRoot<AccountId>;
+	type RemoveOrigin = EnsureRoot<AccountId>;
+	type SwapOrigin = EnsureRoot<AccountId>;
+	type ResetOrigin = EnsureRoot<AccountId>;
+	type WeightInfo = ();
+}
+
 parameter_types! {
 	pub const CollectionDeposit: Balance = 100 * DOLLARS;
 	pub const ItemDeposit: Balance = 1 * DOLLARS;
@@ -1687,6 +1906,7 @@ impl pallet_collective::Config<AllianceCollective> for Runtime {
 	type MaxProposals = AllianceMaxProposals;
 	type MaxMembers = AllianceMaxMembers;
 	type DefaultVote = pallet_collective::PrimeDefaultVote;
+	type VoteWeight = ();
 	type WeightInfo = pallet_collective::weights::SubstrateWeight<Runtime>;
 }
 
@@ -1783,6 +2003,9 @@ construct_runtime!(
 		Bounties: pallet_bounties,
 		Tips: pallet_tips,
 		Assets: pallet_assets,
+		PoolAssets: pallet_assets::<Instance2>,
+		AssetConversion: pallet_asset_conversion,
+		AssetVesting: pallet_asset_vesting,
 		Mmr: pallet_mmr,
 		Lottery: pallet_lottery,
 		Nis: pallet_nis,
@@ -1802,6 +2025,8 @@ construct_runtime!(
 		Alliance: pallet_alliance,
 		NominationPools: pallet_nomination_pools,
 		RankedPolls: pallet_referenda::<Instance2>,
+		ReferendaTracks: pallet_referenda_tracks,
+		NodeAuthorization: pallet_node_authorization,
 		RankedCollective: pallet_ranked_collective,
 		FastUnstake: pallet_fast_unstake,
 		MessageQueue: pallet_message_queue,
@@ -1858,6 +2083,8 @@ type Migrations = (
 	pallet_nomination_pools::migration::v2::MigrateToV2<Runtime>,
 	pallet_alliance::migration::Migration<Runtime>,
 	pallet_contracts::Migration<Runtime>,
+	pallet_multisig::migrations::v2::MigrateToV2<Runtime>,
+	pallet_preimage::migration::v2::Migration<Runtime>,
 );
 
 /// MMR helper types.
@@ -1877,6 +2104,8 @@ mod benches {
 		[frame_benchmarking_pallet_pov, Pov]
 		[pallet_alliance, Alliance]
 		[pallet_assets, Assets]
+		[pallet_asset_conversion, AssetConversion]
+		[pallet_asset_vesting, AssetVesting]
 		[pallet_babe, Babe]
 		[pallet_bags_list, VoterList]
 		[pallet_balances, Balances]
@@ -1906,6 +2135,7 @@ mod benches {
 		[pallet_proxy, Proxy]
 		[pallet_ranked_collective, RankedCollective]
 		[pallet_referenda, Referenda]
+		[pallet_referenda_tracks, ReferendaTracks]
 		[pallet_recovery, Recovery]
 		[pallet_remark, Remark]
 		[pallet_salary, Salary]
@@ -2181,6 +2411,47 @@ impl_runtime_apis! {
 				key
 			)
 		}
+
+		fn trace_call(
+			origin: AccountId,
+			dest: AccountId,
+			value: Balance,
+			gas_limit: Option<Weight>,
+			storage_deposit_limit: Option<Balance>,
+			input_data: Vec<u8>,
+		) -> pallet_contracts_primitives::ContractTraceResult<AccountId, Balance, Hash> {
+			let gas_limit = gas_limit.unwrap_or(RuntimeBlockWeights::get().max_block);
+			Contracts::bare_call_traced(
+				origin,
+				dest,
+				value,
+				gas_limit,
+				storage_deposit_limit,
+				input_data,
+				pallet_contracts::Determinism::Deterministic,
+			)
+		}
+
+		fn trace_instantiate(
+			origin: AccountId,
+			value: Balance,
+			gas_limit: Option<Weight>,
+			storage_deposit_limit: Option<Balance>,
+			code: pallet_contracts_primitives::Code<Hash>,
+			data: Vec<u8>,
+			salt: Vec<u8>,
+		) -> pallet_contracts_primitives::ContractTraceResult<AccountId, Balance, Hash> {
+			let gas_limit = gas_limit.unwrap_or(RuntimeBlockWeights::get().max_block);
+			Contracts::bare_instantiate_traced(
+				origin,
+				value,
+				gas_limit,
+				storage_deposit_limit,
+				code,
+				data,
+				salt,
+			)
+		}
 	}
 
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<
@@ -2201,6 +2472,79 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_asset_conversion::AssetConversionApi<
+		Block,
+		Balance,
+		NativeOrAssetId<u32>,
+	> for Runtime {
+		fn quote_price_exact_tokens_for_tokens(
+			asset1: NativeOrAssetId<u32>,
+			asset2: NativeOrAssetId<u32>,
+			amount: Balance,
+			include_fee: bool,
+		) -> Option<Balance> {
+			AssetConversion::quote_price_exact_tokens_for_tokens(asset1, asset2, amount, include_fee)
+		}
+
+		fn quote_price_tokens_for_exact_tokens(
+			asset1: NativeOrAssetId<u32>,
+			asset2: NativeOrAssetId<u32>,
+			amount: Balance,
+			include_fee: bool,
+		) -> Option<Balance> {
+			AssetConversion::quote_price_tokens_for_exact_tokens(asset1, asset2, amount, include_fee)
+		}
+
+		fn get_reserves(
+			asset1: NativeOrAssetId<u32>,
+			asset2: NativeOrAssetId<u32>,
+		) -> Option<(Balance, Balance)> {
+			AssetConversion::get_reserves(&asset1, &asset2).ok()
+		}
+	}
+
+	impl pallet_asset_vesting::AssetVestingApi<Block, AccountId, u32, Balance> for Runtime {
+		fn vesting_balances(
+			who: AccountId,
+			asset: u32,
+		) -> Option<pallet_asset_vesting::VestingBalances<Balance>> {
+			AssetVesting::vesting_balances(&who, asset)
+		}
+	}
+
+	impl pallet_node_authorization_runtime_api::NodeAuthorizationApi<Block> for Runtime {
+		fn authorized_nodes(node: OpaquePeerId) -> Option<Vec<OpaquePeerId>> {
+			NodeAuthorization::allowlist_in_use()
+				.then(|| NodeAuthorization::get_authorized_nodes(&node))
+		}
+	}
+
+	impl pallet_referenda::ReferendaTracksApi<Block, u16, Balance, BlockNumber> for Runtime {
+		fn tracks() -> Vec<(u16, pallet_referenda::TrackInfo<Balance, BlockNumber>)> {
+			<TracksInfo as pallet_referenda::TracksInfo<_, _>>::tracks().into_owned()
+		}
+	}
+
+	impl pallet_conviction_voting::ConvictionVotingApi<Block, AccountId, u16, Balance, BlockNumber>
+		for Runtime
+	{
+		fn class_locks_for(
+			who: AccountId,
+		) -> Vec<pallet_conviction_voting::ClassLock<u16, Balance, BlockNumber>> {
+			ConvictionVoting::class_locks_for(&who)
+		}
+	}
+
+	impl pallet_message_queue::MessageQueueApi<Block, u32> for Runtime {
+		fn queues() -> Vec<pallet_message_queue::QueueInfo<u32>> {
+			MessageQueue::queue_infos()
+		}
+
+		fn queue(origin: u32) -> Option<pallet_message_queue::QueueInfo<u32>> {
+			MessageQueue::queue_info(origin)
+		}
+	}
+
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentCallApi<Block, Balance, RuntimeCall>
 		for Runtime
 	{
@@ -2262,6 +2606,7 @@ impl_runtime_apis! {
 		}
 	}
 
+	#[api_version(3)]
 	impl pallet_mmr::primitives::MmrApi<
 		Block,
 		mmr::Hash,
@@ -2310,6 +2655,13 @@ impl_runtime_apis! {
 			let nodes = leaves.into_iter().map(|leaf|mmr::DataOrHash::Data(leaf.into_opaque_leaf())).collect();
 			pallet_mmr::verify_leaves_proof::<mmr::Hashing, _>(root, nodes, proof)
 		}
+
+		fn generate_ancestry_proof(
+			prev_leaf_count: mmr::LeafIndex,
+			best_known_block_number: Option<BlockNumber>,
+		) -> Result<mmr::AncestryProof<mmr::Hash>, mmr::Error> {
+			Mmr::generate_ancestry_proof(prev_leaf_count, best_known_block_number)
+		}
 	}
 
 	impl sp_session::SessionKeys<Block> for Runtime {
@@ -204,6 +204,14 @@ pub trait BlockImportOperation<Block: BlockT> {
 		state_version: StateVersion,
 	) -> sp_blockchain::Result<Block::Hash>;
 
+	/// Use the state that has already been written to the database, e.g. by a state import that
+	/// is streamed into the backend, as the state of the pending block.
+	///
+	/// The state is not checked against the state root of the block header.
+	fn set_written_state(&mut self) -> sp_blockchain::Result<()> {
+		Err(sp_blockchain::Error::Backend("Written state is not supported by the backend".into()))
+	}
+
 	/// Set storage changes.
 	fn update_storage(
 		&mut self,
@@ -0,0 +1,161 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+	commands::revert_cmd::AuxRevertHandler,
+	error,
+	params::{BlockNumberOrHash, DatabaseParams, GenericNumber, PruningParams, SharedParams},
+	CliConfiguration,
+};
+use clap::Parser;
+use log::info;
+use sc_client_api::{HeaderBackend, UsageProvider};
+use sc_service::{
+	chain_ops::{check_db, repair_db},
+	TFullBackend,
+};
+use sp_runtime::traits::{Block as BlockT, Header as HeaderT, One, Saturating, Zero};
+use std::{fmt::Debug, str::FromStr, sync::Arc};
+
+/// The `check-db` command used to check the integrity of the database.
+///
+/// Checks the headers, bodies, justifications and state tries of a range of canonical blocks,
+/// as well as the leaves and children metadata of the block tree.
+#[derive(Debug, Clone, Parser)]
+pub struct CheckDbCmd {
+	/// Check only the given block.
+	#[arg(long, value_name = "HASH or NUMBER", conflicts_with_all = ["from", "to"])]
+	pub at: Option<BlockNumberOrHash>,
+
+	/// First block to check. Default is the last finalized block.
+	#[arg(long, value_name = "BLOCK")]
+	pub from: Option<GenericNumber>,
+
+	/// Last block to check. Default is the best block.
+	#[arg(long, value_name = "BLOCK")]
+	pub to: Option<GenericNumber>,
+
+	/// Do not walk the state tries of the checked blocks.
+	#[arg(long)]
+	pub skip_state: bool,
+
+	/// Rewind the chain to the last consistent finalized block if an inconsistency is found.
+	///
+	/// The blocks below the first inconsistency are checked until a consistent one is found.
+	///
+	/// Finalized blocks are reverted as well, as far as the state pruning allows it.
+	#[arg(long)]
+	pub repair: bool,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub shared_params: SharedParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub pruning_params: PruningParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub database_params: DatabaseParams,
+}
+
+impl CheckDbCmd {
+	/// Run the check-db command
+	pub async fn run<B, C>(
+		&self,
+		client: Arc<C>,
+		backend: Arc<TFullBackend<B>>,
+		aux_revert: Option<AuxRevertHandler<C, TFullBackend<B>, B>>,
+	) -> error::Result<()>
+	where
+		B: BlockT,
+		C: HeaderBackend<B> + UsageProvider<B>,
+		B::Hash: FromStr,
+		<B::Hash as FromStr>::Err: Debug,
+		<<B::Header as HeaderT>::Number as FromStr>::Err: Debug,
+	{
+		let info = client.usage_info().chain;
+		let (from, to) = match &self.at {
+			Some(at) => {
+				let number = client.expect_block_number_from_id(&at.parse()?)?;
+				(number, number)
+			},
+			None => (
+				self.from
+					.as_ref()
+					.map(|n| n.parse())
+					.transpose()?
+					.unwrap_or(info.finalized_number),
+				self.to.as_ref().map(|n| n.parse()).transpose()?.unwrap_or(info.best_number),
+			),
+		};
+
+		let start = std::time::Instant::now();
+		let summary = check_db(&backend, from, to, !self.skip_state)?;
+		info!(
+			"Checked {} blocks and {} state nodes in {} ms, found {} issues.",
+			summary.checked_blocks,
+			summary.state_nodes,
+			start.elapsed().as_millis(),
+			summary.issues,
+		);
+
+		if summary.issues == 0 {
+			return Ok(())
+		}
+		if !self.repair {
+			return Err(format!("The database has {} issues", summary.issues).into())
+		}
+
+		// Issues in the metadata alone are not tied to a block, only unfinalized blocks are
+		// reverted then.
+		let target = match summary.first_inconsistent {
+			Some(number) if number.is_zero() =>
+				return Err(
+					"The genesis block is inconsistent, the database can not be repaired".into()
+				),
+			Some(number) => (number - One::one()).min(info.finalized_number),
+			None => info.finalized_number,
+		};
+		// The blocks below the first inconsistency were not necessarily checked.
+		let target = backend.last_consistent_block(target, !self.skip_state)?.ok_or(
+			"No consistent block to rewind to was found, the database can not be repaired",
+		)?;
+		if let Some(aux_revert) = aux_revert {
+			aux_revert(client.clone(), backend.clone(), info.best_number.saturating_sub(target))?;
+		}
+		repair_db(client, backend, target)?;
+
+		Ok(())
+	}
+}
+
+impl CliConfiguration for CheckDbCmd {
+	fn shared_params(&self) -> &SharedParams {
+		&self.shared_params
+	}
+
+	fn pruning_params(&self) -> Option<&PruningParams> {
+		Some(&self.pruning_params)
+	}
+
+	fn database_params(&self) -> Option<&DatabaseParams> {
+		Some(&self.database_params)
+	}
+}
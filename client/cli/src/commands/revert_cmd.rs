@@ -44,7 +44,7 @@ pub struct RevertCmd {
 }
 
 /// Revert handler for auxiliary data (e.g. consensus).
-type AuxRevertHandler<C, BA, B> =
+pub(crate) type AuxRevertHandler<C, BA, B> =
 	Box<dyn FnOnce(Arc<C>, Arc<BA>, NumberFor<B>) -> error::Result<()>>;
 
 impl RevertCmd {
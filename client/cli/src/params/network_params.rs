@@ -19,7 +19,10 @@
 use crate::{arg_enums::SyncMode, params::node_key_params::NodeKeyParams};
 use clap::Args;
 use sc_network::{config::NetworkConfiguration, multiaddr::Protocol};
-use sc_network_common::config::{NodeKeyConfig, NonReservedPeerMode, SetConfig, TransportConfig};
+use sc_network_common::config::{
+	HistoryRetention, HistorySyncConfig, NodeKeyConfig, NonReservedPeerMode, SetConfig,
+	TransportConfig,
+};
 use sc_service::{
 	config::{Multiaddr, MultiaddrWithPeerId},
 	ChainSpec, ChainType,
@@ -127,6 +130,23 @@ pub struct NetworkParams {
 	#[arg(long)]
 	pub ipfs_server: bool,
 
+	/// Persist peer reputations, banned peers and known good peer addresses in the database.
+	///
+	/// They are reloaded at startup, with reputations decayed for the time the node was offline.
+	#[arg(long)]
+	pub persist_peers: bool,
+
+	/// Maximum number of peers persisted with `--persist-peers`.
+	#[arg(long, value_name = "COUNT", default_value_t = 1000)]
+	pub max_persisted_peers: u32,
+
+	/// Limit the bandwidth and database reads spent on serving the requests of each peer.
+	///
+	/// Peers exceeding their budget have their requests delayed, and refused if they keep
+	/// exceeding it.
+	#[arg(long)]
+	pub limit_requests: bool,
+
 	/// Blockchain syncing mode.
 	///
 	/// - `full`: Download and validate full blockchain history.
@@ -142,6 +162,29 @@ pub struct NetworkParams {
 		verbatim_doc_comment
 	)]
 	pub sync: SyncMode,
+
+	/// Block bodies downloaded for the block history skipped by warp sync.
+	///
+	/// - `full`: Download the bodies of all blocks.
+	/// - `headers-only`: Don't download any body.
+	/// - number: Download the bodies of the blocks starting from this number only.
+	#[arg(long, value_name = "RETENTION", default_value = "full", verbatim_doc_comment)]
+	pub history_bodies: HistoryRetention,
+
+	/// Justifications downloaded for the block history skipped by warp sync.
+	///
+	/// - `full`: Download the justifications of all blocks.
+	/// - `headers-only`: Don't download any justification.
+	/// - number: Download the justifications of the blocks starting from this number only.
+	#[arg(long, value_name = "RETENTION", default_value = "full", verbatim_doc_comment)]
+	pub history_justifications: HistoryRetention,
+
+	/// Maximum bandwidth spent on downloading the block history skipped by warp sync, in KiB
+	/// per second.
+	///
+	/// Unlimited by default.
+	#[arg(long, value_name = "KIB_PER_SECOND")]
+	pub history_bandwidth: Option<u64>,
 }
 
 impl NetworkParams {
@@ -236,7 +279,15 @@ impl NetworkParams {
 			kademlia_disjoint_query_paths: self.kademlia_disjoint_query_paths,
 			yamux_window_size: None,
 			ipfs_server: self.ipfs_server,
+			persist_peers: self.persist_peers,
+			max_persisted_peers: self.max_persisted_peers,
+			request_budget: self.limit_requests.then(Default::default),
 			sync_mode: self.sync.into(),
+			history_sync: HistorySyncConfig {
+				bodies: self.history_bodies,
+				justifications: self.history_justifications,
+				max_bytes_per_second: self.history_bandwidth.map(|kib| kib.saturating_mul(1024)),
+			},
 		}
 	}
 }
@@ -291,4 +342,20 @@ mod tests {
 
 		assert_eq!(SyncMode::Warp, params.network_params.sync);
 	}
+
+	#[test]
+	fn history_retention_parses() {
+		let params =
+			Cli::try_parse_from(["", "--history-bodies", "1000"]).expect("Parses network params");
+
+		assert_eq!(HistoryRetention::FullFrom(1000), params.network_params.history_bodies);
+		assert_eq!(HistoryRetention::Full, params.network_params.history_justifications);
+
+		let params = Cli::try_parse_from(["", "--history-justifications", "headers-only"])
+			.expect("Parses network params");
+
+		assert_eq!(HistoryRetention::HeadersOnly, params.network_params.history_justifications);
+
+		assert!(Cli::try_parse_from(["", "--history-bodies", "some"]).is_err());
+	}
 }
@@ -18,7 +18,7 @@
 
 use crate::error;
 use clap::Args;
-use sc_service::{BlocksPruning, PruningMode};
+use sc_service::{BlocksPruning, Checkpoints, PruningMode};
 
 /// Parameters to define the pruning mode
 #[derive(Debug, Clone, PartialEq, Args)]
@@ -46,6 +46,16 @@ pub struct PruningParams {
 	///
 	///    Keep the state of the last number of finalized blocks.
 	///
+	///  - 'sparse-archive:<period>[:<number>]'
+	///
+	///    Keep the state of the last number of finalized blocks (256 by default) and of every
+	///    finalized block whose number is a multiple of period.
+	///
+	///  - 'sparse-archive:external[:<number>]'
+	///
+	///    Keep the state of the last number of finalized blocks (256 by default) and of the
+	///    finalized blocks selected by the node, for example the ones starting a new epoch.
+	///
 	/// [default: 256]
 	#[arg(alias = "pruning", long, value_name = "PRUNING_MODE")]
 	pub state_pruning: Option<DatabasePruningMode>,
@@ -84,7 +94,7 @@ impl PruningParams {
 
 	/// Get the block pruning value from the parameters
 	pub fn blocks_pruning(&self) -> error::Result<BlocksPruning> {
-		Ok(self.blocks_pruning.into())
+		self.blocks_pruning.try_into().map_err(Into::into)
 	}
 }
 
@@ -101,6 +111,8 @@ pub enum DatabasePruningMode {
 	ArchiveCanonical,
 	/// Keep the data of the last number of finalized blocks.
 	Custom(u32),
+	/// Keep the data of the last number of finalized blocks and of checkpoint blocks.
+	SparseArchive(u32, Checkpoints),
 }
 
 impl std::str::FromStr for DatabasePruningMode {
@@ -110,6 +122,26 @@ impl std::str::FromStr for DatabasePruningMode {
 		match input {
 			"archive" => Ok(Self::Archive),
 			"archive-canonical" => Ok(Self::ArchiveCanonical),
+			sparse if sparse.starts_with("sparse-archive:") => {
+				let invalid = || format!("Invalid sparse archive pruning mode specified: {sparse}");
+				let mut parts = sparse["sparse-archive:".len()..].split(':');
+				let checkpoints = match parts.next() {
+					Some("external") => Checkpoints::External,
+					Some(period) => match period.parse() {
+						Ok(period) if period > 0 => Checkpoints::Every(period),
+						_ => return Err(invalid()),
+					},
+					None => return Err(invalid()),
+				};
+				let window = match parts.next() {
+					Some(window) => window.parse().map_err(|_| invalid())?,
+					None => 256,
+				};
+				if parts.next().is_some() {
+					return Err(invalid())
+				}
+				Ok(Self::SparseArchive(window, checkpoints))
+			},
 			bc => bc
 				.parse()
 				.map_err(|_| "Invalid pruning mode specified".to_string())
@@ -124,16 +156,45 @@ impl Into<PruningMode> for DatabasePruningMode {
 			DatabasePruningMode::Archive => PruningMode::ArchiveAll,
 			DatabasePruningMode::ArchiveCanonical => PruningMode::ArchiveCanonical,
 			DatabasePruningMode::Custom(n) => PruningMode::blocks_pruning(n),
+			DatabasePruningMode::SparseArchive(n, checkpoints) =>
+				PruningMode::sparse_archive(n, checkpoints),
 		}
 	}
 }
 
-impl Into<BlocksPruning> for DatabasePruningMode {
-	fn into(self) -> BlocksPruning {
-		match self {
-			DatabasePruningMode::Archive => BlocksPruning::KeepAll,
-			DatabasePruningMode::ArchiveCanonical => BlocksPruning::KeepFinalized,
-			DatabasePruningMode::Custom(n) => BlocksPruning::Some(n),
+impl TryFrom<DatabasePruningMode> for BlocksPruning {
+	type Error = String;
+
+	fn try_from(mode: DatabasePruningMode) -> Result<Self, Self::Error> {
+		match mode {
+			DatabasePruningMode::Archive => Ok(BlocksPruning::KeepAll),
+			DatabasePruningMode::ArchiveCanonical => Ok(BlocksPruning::KeepFinalized),
+			DatabasePruningMode::Custom(n) => Ok(BlocksPruning::Some(n)),
+			DatabasePruningMode::SparseArchive(..) =>
+				Err("Sparse archive is only supported for state pruning".into()),
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn sparse_archive_is_parsed() {
+		assert_eq!(
+			"sparse-archive:100".parse::<DatabasePruningMode>(),
+			Ok(DatabasePruningMode::SparseArchive(256, Checkpoints::Every(100)))
+		);
+		assert_eq!(
+			"sparse-archive:100:64".parse::<DatabasePruningMode>(),
+			Ok(DatabasePruningMode::SparseArchive(64, Checkpoints::Every(100)))
+		);
+		assert!("sparse-archive:0".parse::<DatabasePruningMode>().is_err());
+		assert!("sparse-archive:100:64:1".parse::<DatabasePruningMode>().is_err());
+		assert_eq!(
+			"sparse-archive:external".parse::<DatabasePruningMode>(),
+			Ok(DatabasePruningMode::SparseArchive(256, Checkpoints::External))
+		);
+	}
+}
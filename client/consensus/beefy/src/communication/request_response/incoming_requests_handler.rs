@@ -79,6 +79,7 @@ impl<B: Block> IncomingRequest<B> {
 					result: Err(()),
 					reputation_changes,
 					sent_feedback: None,
+					db_reads: 0,
 				};
 				if let Err(_) = pending_response.send(response) {
 					return Err(Error::DecodingErrorNoReputationChange(peer, err))
@@ -178,6 +179,8 @@ where
 				result: maybe_encoded_proof,
 				reputation_changes: Vec::new(),
 				sent_feedback: None,
+				// Block hash lookup and justifications read.
+				db_reads: 2,
 			})
 			.map_err(|_| Error::SendResponse)
 	}
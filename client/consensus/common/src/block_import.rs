@@ -133,6 +133,9 @@ pub struct ImportedState<B: BlockT> {
 	pub block: B::Hash,
 	/// State keys and values.
 	pub state: sp_state_machine::KeyValueStates,
+	/// Whether the state has already been written to the backend, in which case `state` is empty
+	/// and the state root of the block header is trusted.
+	pub written: bool,
 }
 
 impl<B: BlockT> std::fmt::Debug for ImportedState<B> {
@@ -0,0 +1,739 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Database integrity checks.
+//!
+//! The checks read the database directly instead of going through the caches, so that corrupted
+//! or missing entries are reported instead of failing the first import that touches them.
+
+use crate::{children, columns, meta_keys, utils, Backend, StorageDb};
+use codec::{DecodeAll, Encode};
+use hash_db::{HashDBRef, Hasher, Prefix};
+use sc_client_api::{backend::Backend as _, blockchain::Backend as _};
+use sp_blockchain::{HeaderBackend, Result as ClientResult};
+use sp_core::{
+	hexdisplay::HexDisplay,
+	storage::{ChildInfo, ChildType, PrefixedStorageKey},
+};
+use sp_runtime::{
+	traits::{Block as BlockT, Hash, HashFor, Header as HeaderT, NumberFor, One, Zero},
+	StateVersion,
+};
+use sp_state_machine::DBValue;
+use sp_trie::{KeySpacedDB, LayoutV1, TrieDBBuilder, TrieDBRawIterator};
+use std::{
+	cell::{Cell, RefCell},
+	collections::HashSet,
+	fmt,
+};
+
+/// An inconsistency found in the database.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IntegrityIssue<Hash, Number> {
+	/// No canonical block is stored for the given number.
+	MissingCanonicalHash(Number),
+	/// The header of a block is missing or does not match the block it is stored for.
+	Header {
+		/// Hash of the block.
+		hash: Hash,
+		/// What is wrong with the header.
+		reason: String,
+	},
+	/// The body of a block is missing or does not match the header.
+	Body {
+		/// Hash of the block.
+		hash: Hash,
+		/// What is wrong with the body.
+		reason: String,
+	},
+	/// The justifications of a block cannot be read or should not exist.
+	Justifications {
+		/// Hash of the block.
+		hash: Hash,
+		/// What is wrong with the justifications.
+		reason: String,
+	},
+	/// A node of the state of a block is missing or corrupted.
+	State {
+		/// Hash of the block.
+		hash: Hash,
+		/// Storage key of the child trie, `None` for the top trie.
+		child_trie: Option<Vec<u8>>,
+		/// What is wrong with the trie.
+		reason: String,
+	},
+	/// The block tree metadata (best/finalized blocks, gap, leaves or children) is inconsistent.
+	Metadata(String),
+}
+
+impl<Hash: fmt::Debug, Number: fmt::Display> fmt::Display for IntegrityIssue<Hash, Number> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::MissingCanonicalHash(number) => write!(f, "No canonical block #{}", number),
+			Self::Header { hash, reason } => write!(f, "Header of {:?}: {}", hash, reason),
+			Self::Body { hash, reason } => write!(f, "Body of {:?}: {}", hash, reason),
+			Self::Justifications { hash, reason } =>
+				write!(f, "Justifications of {:?}: {}", hash, reason),
+			Self::State { hash, child_trie: None, reason } =>
+				write!(f, "State of {:?}: {}", hash, reason),
+			Self::State { hash, child_trie: Some(child), reason } => write!(
+				f,
+				"State of {:?}, child trie 0x{}: {}",
+				hash,
+				HexDisplay::from(child),
+				reason
+			),
+			Self::Metadata(reason) => write!(f, "Metadata: {}", reason),
+		}
+	}
+}
+
+/// Result of checking a single canonical block.
+#[derive(Debug, Clone)]
+pub struct BlockIntegrity<Hash, Number> {
+	/// Number of the checked block.
+	pub number: Number,
+	/// Canonical hash of the block, `None` if it is unknown or inside the block gap.
+	pub hash: Option<Hash>,
+	/// Number of state trie nodes read, `None` if the state was not checked.
+	pub state_nodes: Option<u64>,
+	/// Inconsistencies found for this block.
+	pub issues: Vec<IntegrityIssue<Hash, Number>>,
+}
+
+impl<Hash, Number> BlockIntegrity<Hash, Number> {
+	/// Returns `true` if no issue was found.
+	pub fn is_consistent(&self) -> bool {
+		self.issues.is_empty()
+	}
+}
+
+type Issue<Block> = IntegrityIssue<<Block as BlockT>::Hash, NumberFor<Block>>;
+
+impl<Block: BlockT> Backend<Block> {
+	/// Check the canonical block at `number`.
+	///
+	/// Verifies the number and hash lookup entries, the header, the body against the extrinsics
+	/// root and the justifications. If `check_state` is set and the state of the block is kept,
+	/// every node of the state trie and of its child tries is read and checked against its hash.
+	///
+	/// Blocks inside the block gap are skipped.
+	pub fn check_block_integrity(
+		&self,
+		number: NumberFor<Block>,
+		check_state: bool,
+	) -> ClientResult<BlockIntegrity<Block::Hash, NumberFor<Block>>> {
+		let info = self.blockchain.info();
+		let mut report =
+			BlockIntegrity { number, hash: None, state_nodes: None, issues: Vec::new() };
+
+		if matches!(info.block_gap, Some((start, end)) if start <= number && number <= end) {
+			return Ok(report)
+		}
+
+		let hash = match self.canonical_lookup(number)? {
+			Ok(Some(hash)) => hash,
+			Ok(None) => {
+				report.issues.push(IntegrityIssue::MissingCanonicalHash(number));
+				return Ok(report)
+			},
+			Err(issue) => {
+				report.issues.push(issue);
+				return Ok(report)
+			},
+		};
+		report.hash = Some(hash);
+
+		let header = match self.check_header(number, hash, &mut report.issues)? {
+			Some(header) => header,
+			None => return Ok(report),
+		};
+
+		if !number.is_zero() &&
+			!matches!(info.block_gap, Some((_, end)) if end + One::one() == number)
+		{
+			match self.canonical_lookup(number - One::one())? {
+				Ok(Some(parent_hash)) if parent_hash == *header.parent_hash() => {},
+				Ok(_) => report.issues.push(IntegrityIssue::Header {
+					hash,
+					reason: format!(
+						"parent {:?} is not the canonical block #{}",
+						header.parent_hash(),
+						number - One::one(),
+					),
+				}),
+				// Reported when checking the parent itself.
+				Err(_) => {},
+			}
+		}
+
+		self.check_body(&header, hash, &mut report.issues);
+
+		match self.blockchain.justifications(hash) {
+			Ok(Some(_)) if number > info.finalized_number =>
+				report.issues.push(IntegrityIssue::Justifications {
+					hash,
+					reason: "stored for a block that is not finalized".into(),
+				}),
+			Ok(_) => {},
+			Err(e) => report
+				.issues
+				.push(IntegrityIssue::Justifications { hash, reason: e.to_string() }),
+		}
+
+		// The state of the finalized block and of the blocks above it must always be available,
+		// unless the node is still syncing the state.
+		let state_required = number >= info.finalized_number && info.finalized_state.is_some();
+		if check_state && (state_required || self.have_state_at(hash, number)) {
+			let checker = StateChecker::<Block>::new(&self.storage);
+			for (child_trie, reason) in checker.check(*header.state_root()) {
+				report.issues.push(IntegrityIssue::State { hash, child_trie, reason });
+			}
+			report.state_nodes = Some(checker.nodes.get());
+		}
+
+		Ok(report)
+	}
+
+	/// Find the highest canonical block at or below `number` which is stored and passes
+	/// [`Self::check_block_integrity`].
+	///
+	/// Returns `None` if there is no such block down to the genesis block or the block gap.
+	pub fn last_consistent_block(
+		&self,
+		mut number: NumberFor<Block>,
+		check_state: bool,
+	) -> ClientResult<Option<NumberFor<Block>>> {
+		loop {
+			let report = self.check_block_integrity(number, check_state)?;
+			// Blocks inside the block gap are not checked and can not be rewound to.
+			if report.hash.is_none() {
+				return Ok(None)
+			}
+			if report.is_consistent() {
+				return Ok(Some(number))
+			}
+			if number.is_zero() {
+				return Ok(None)
+			}
+			number -= One::one();
+		}
+	}
+
+	/// Check the block tree metadata.
+	///
+	/// Verifies that the best, finalized and genesis blocks are stored, that the block gap is
+	/// well formed, that every leaf is a stored block without children that was not displaced
+	/// by finality, and that the children lists link every unfinalized block to its parent.
+	pub fn check_metadata_integrity(&self) -> ClientResult<Vec<Issue<Block>>> {
+		let info = self.blockchain.info();
+		let mut issues = Vec::new();
+
+		for (what, hash, number) in [
+			("best", info.best_hash, info.best_number),
+			("finalized", info.finalized_hash, info.finalized_number),
+			("genesis", info.genesis_hash, Zero::zero()),
+		] {
+			match self.stored_number(hash) {
+				Some(stored) if stored == number => {},
+				Some(stored) => issues.push(IntegrityIssue::Metadata(format!(
+					"{} block {:?} is stored as #{} instead of #{}",
+					what, hash, stored, number
+				))),
+				None => issues.push(IntegrityIssue::Metadata(format!(
+					"{} block #{} ({:?}) is not stored",
+					what, number, hash
+				))),
+			}
+		}
+
+		if info.finalized_number > info.best_number {
+			issues.push(IntegrityIssue::Metadata(format!(
+				"finalized block #{} is above the best block #{}",
+				info.finalized_number, info.best_number
+			)));
+		}
+		for (what, hash, number) in [
+			("finalized", info.finalized_hash, info.finalized_number),
+			("genesis", info.genesis_hash, Zero::zero()),
+		] {
+			if !matches!(self.canonical_lookup(number)?, Ok(Some(canonical)) if canonical == hash) {
+				issues.push(IntegrityIssue::Metadata(format!(
+					"{} block #{} ({:?}) is not canonical",
+					what, number, hash
+				)));
+			}
+		}
+
+		if let Some((start, end)) = info.block_gap {
+			if start > end || end >= info.best_number {
+				issues.push(IntegrityIssue::Metadata(format!(
+					"invalid block gap #{}..=#{} with best block #{}",
+					start, end, info.best_number
+				)));
+			}
+		}
+
+		let leaves = self.blockchain.leaves.read();
+		let mut checked = HashSet::new();
+		for leaf in leaves.hashes() {
+			let number = match self.stored_number(leaf) {
+				Some(number) => number,
+				None => {
+					issues.push(IntegrityIssue::Metadata(format!(
+						"leaf {:?} is not a stored block",
+						leaf
+					)));
+					continue
+				},
+			};
+			if !leaves.contains(number, leaf) {
+				issues.push(IntegrityIssue::Metadata(format!(
+					"leaf {:?} is recorded under a different number than #{}",
+					leaf, number
+				)));
+			}
+			if number < info.finalized_number {
+				issues.push(IntegrityIssue::Metadata(format!(
+					"leaf #{} ({:?}) is below the finalized block and should have been displaced",
+					number, leaf
+				)));
+			}
+			if !self.blockchain.children(leaf)?.is_empty() {
+				issues.push(IntegrityIssue::Metadata(format!(
+					"leaf #{} ({:?}) has children",
+					number, leaf
+				)));
+			}
+
+			// Walk down to the finalized block, checking that each block is recorded as a child
+			// of its parent.
+			let mut hash = leaf;
+			while checked.insert(hash) {
+				let header = match self.blockchain.header(hash) {
+					Ok(Some(header)) => header,
+					_ => break,
+				};
+				if *header.number() <= info.finalized_number {
+					break
+				}
+				let parent = *header.parent_hash();
+				let siblings = children::read_children::<_, Block::Hash>(
+					&*self.storage.db,
+					columns::META,
+					meta_keys::CHILDREN_PREFIX,
+					parent,
+				);
+				match siblings {
+					Ok(siblings) if siblings.contains(&hash) => {},
+					Ok(_) => issues.push(IntegrityIssue::Metadata(format!(
+						"block #{} ({:?}) is missing from the children of its parent {:?}",
+						header.number(),
+						hash,
+						parent
+					))),
+					Err(e) => issues
+						.push(IntegrityIssue::Metadata(format!("children of {:?}: {}", parent, e))),
+				}
+				if self.stored_number(parent).is_none() {
+					issues.push(IntegrityIssue::Metadata(format!(
+						"parent {:?} of block #{} ({:?}) is not stored",
+						parent,
+						header.number(),
+						hash
+					)));
+					break
+				}
+				hash = parent;
+			}
+		}
+
+		Ok(issues)
+	}
+
+	/// Read the canonical hash of the block at `number`.
+	///
+	/// The inner error is an issue with the lookup entries themselves.
+	fn canonical_lookup(
+		&self,
+		number: NumberFor<Block>,
+	) -> ClientResult<Result<Option<Block::Hash>, Issue<Block>>> {
+		let db = &*self.storage.db;
+		let lookup_key = match db.get(columns::KEY_LOOKUP, &utils::number_index_key(number)?) {
+			Some(lookup_key) => lookup_key,
+			None => return Ok(Ok(None)),
+		};
+		let hash = match lookup_key.get(4..).map(|mut hash| Block::Hash::decode_all(&mut hash)) {
+			Some(Ok(hash)) => hash,
+			_ =>
+				return Ok(Err(IntegrityIssue::Metadata(format!(
+					"invalid lookup key stored for block #{}",
+					number
+				)))),
+		};
+		if db.get(columns::KEY_LOOKUP, hash.as_ref()).as_ref() != Some(&lookup_key) {
+			return Ok(Err(IntegrityIssue::Metadata(format!(
+				"hash lookup of block #{} ({:?}) does not match its number lookup",
+				number, hash
+			))))
+		}
+		Ok(Ok(Some(hash)))
+	}
+
+	fn stored_number(&self, hash: Block::Hash) -> Option<NumberFor<Block>> {
+		self.blockchain.header(hash).ok().flatten().map(|header| *header.number())
+	}
+
+	fn check_header(
+		&self,
+		number: NumberFor<Block>,
+		hash: Block::Hash,
+		issues: &mut Vec<Issue<Block>>,
+	) -> ClientResult<Option<Block::Header>> {
+		let encoded = match self
+			.storage
+			.db
+			.get(columns::HEADER, &utils::number_and_hash_to_lookup_key(number, hash)?)
+		{
+			Some(encoded) => encoded,
+			None => {
+				issues.push(IntegrityIssue::Header { hash, reason: "missing".into() });
+				return Ok(None)
+			},
+		};
+		let header = match Block::Header::decode_all(&mut &encoded[..]) {
+			Ok(header) => header,
+			Err(e) => {
+				issues
+					.push(IntegrityIssue::Header { hash, reason: format!("cannot decode: {}", e) });
+				return Ok(None)
+			},
+		};
+		if header.hash() != hash {
+			issues.push(IntegrityIssue::Header {
+				hash,
+				reason: format!("stored header hashes to {:?}", header.hash()),
+			});
+			return Ok(None)
+		}
+		if *header.number() != number {
+			issues.push(IntegrityIssue::Header {
+				hash,
+				reason: format!("stored with number #{} instead of #{}", header.number(), number),
+			});
+			return Ok(None)
+		}
+		Ok(Some(header))
+	}
+
+	fn check_body(
+		&self,
+		header: &Block::Header,
+		hash: Block::Hash,
+		issues: &mut Vec<Issue<Block>>,
+	) {
+		let info = self.blockchain.info();
+		let number = *header.number();
+		let body_required = match self.blocks_pruning {
+			crate::BlocksPruning::KeepAll | crate::BlocksPruning::KeepFinalized => true,
+			crate::BlocksPruning::Some(keep) =>
+				number >= info.finalized_number ||
+					info.finalized_number - number < std::cmp::max(keep, 1).into(),
+		};
+
+		match self.blockchain.body(hash) {
+			Ok(Some(body)) => {
+				let extrinsics: Vec<_> = body.iter().map(Encode::encode).collect();
+				// The state version used for the extrinsics root depends on the runtime.
+				let matches = [StateVersion::V0, StateVersion::V1].into_iter().any(|version| {
+					HashFor::<Block>::ordered_trie_root(extrinsics.clone(), version) ==
+						*header.extrinsics_root()
+				});
+				if !matches {
+					issues.push(IntegrityIssue::Body {
+						hash,
+						reason: "does not match the extrinsics root of the header".into(),
+					});
+				}
+			},
+			Ok(None) if body_required =>
+				issues.push(IntegrityIssue::Body { hash, reason: "missing".into() }),
+			Ok(None) => {},
+			Err(e) => issues.push(IntegrityIssue::Body { hash, reason: e.to_string() }),
+		}
+	}
+}
+
+/// Reads every node of a state trie and its child tries, recording the ones that are missing or
+/// do not match their hash.
+struct StateChecker<'a, Block: BlockT> {
+	storage: &'a StorageDb<Block>,
+	nodes: Cell<u64>,
+	corrupted: RefCell<Vec<(Block::Hash, String)>>,
+}
+
+impl<'a, Block: BlockT> StateChecker<'a, Block> {
+	fn new(storage: &'a StorageDb<Block>) -> Self {
+		StateChecker { storage, nodes: Cell::new(0), corrupted: RefCell::new(Vec::new()) }
+	}
+
+	/// Check the state with the given root, returning the issues found per trie.
+	fn check(&self, root: Block::Hash) -> Vec<(Option<Vec<u8>>, String)> {
+		let mut child_roots = Vec::new();
+		let mut issues: Vec<_> = self
+			.walk(self, root, |key, value| {
+				if let Some((ChildType::ParentKeyId, storage_key)) =
+					ChildType::from_prefixed_key(PrefixedStorageKey::new_ref(&key))
+				{
+					child_roots.push((storage_key.to_vec(), value));
+				}
+			})
+			.into_iter()
+			.map(|reason| (None, reason))
+			.collect();
+
+		for (storage_key, root) in child_roots {
+			let root = match Block::Hash::decode_all(&mut &root[..]) {
+				Ok(root) => root,
+				Err(_) => {
+					issues.push((Some(storage_key), "invalid child trie root".into()));
+					continue
+				},
+			};
+			let child_info = ChildInfo::new_default(&storage_key);
+			let db = KeySpacedDB::new(self, child_info.keyspace());
+			issues.extend(
+				self.walk(&db, root, |_, _| {})
+					.into_iter()
+					.map(|reason| (Some(storage_key.clone()), reason)),
+			);
+		}
+
+		issues
+	}
+
+	fn walk(
+		&self,
+		db: &dyn HashDBRef<HashFor<Block>, DBValue>,
+		root: Block::Hash,
+		mut on_value: impl FnMut(Vec<u8>, Vec<u8>),
+	) -> Vec<String> {
+		let trie = TrieDBBuilder::<LayoutV1<HashFor<Block>>>::new(db, &root).build();
+		let mut errors = Vec::new();
+		match TrieDBRawIterator::new(&trie) {
+			Ok(mut iter) =>
+				while let Some(item) = iter.next_item(&trie) {
+					match item {
+						Ok((key, value)) => on_value(key, value),
+						Err(e) => errors.push(e),
+					}
+				},
+			Err(e) => errors.push(e),
+		}
+
+		let corrupted = std::mem::take(&mut *self.corrupted.borrow_mut());
+		let corrupted_hashes: HashSet<_> = corrupted.iter().map(|(hash, _)| *hash).collect();
+		corrupted
+			.into_iter()
+			.map(|(hash, reason)| format!("node {:?}: {}", hash, reason))
+			.chain(errors.into_iter().filter_map(|e| match *e {
+				// Nodes that failed the hash check are reported as missing by the trie.
+				sp_trie::TrieError::<LayoutV1<HashFor<Block>>>::InvalidStateRoot(hash) |
+				sp_trie::TrieError::<LayoutV1<HashFor<Block>>>::IncompleteDatabase(hash) =>
+					(!corrupted_hashes.contains(&hash)).then(|| format!("node {:?}: missing", hash)),
+				e => Some(e.to_string()),
+			}))
+			.collect()
+	}
+}
+
+impl<'a, Block: BlockT> HashDBRef<HashFor<Block>, DBValue> for StateChecker<'a, Block> {
+	fn get(&self, key: &Block::Hash, prefix: Prefix) -> Option<DBValue> {
+		match sp_state_machine::Storage::<HashFor<Block>>::get(self.storage, key, prefix) {
+			Ok(Some(value)) => {
+				self.nodes.set(self.nodes.get() + 1);
+				if <HashFor<Block> as Hasher>::hash(&value) == *key {
+					Some(value)
+				} else {
+					self.corrupted.borrow_mut().push((*key, "does not match its hash".into()));
+					None
+				}
+			},
+			Ok(None) => None,
+			Err(e) => {
+				self.corrupted.borrow_mut().push((*key, e));
+				None
+			},
+		}
+	}
+
+	fn contains(&self, key: &Block::Hash, prefix: Prefix) -> bool {
+		self.get(key, prefix).is_some()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::tests::{insert_block, Block};
+	use sc_client_api::backend::Backend as _;
+	use sp_core::H256;
+	use sp_database::Transaction;
+	use sp_runtime::{testing::ExtrinsicWrapper, traits::BlakeTwo256};
+
+	fn insert_chain(backend: &Backend<Block>, len: u64) -> Vec<H256> {
+		let extrinsics_root = BlakeTwo256::ordered_trie_root(Vec::new(), StateVersion::V1);
+		let mut hashes: Vec<H256> = Vec::new();
+		for number in 0..len {
+			let parent = hashes.last().cloned().unwrap_or_default();
+			let hash =
+				insert_block(backend, number, parent, None, extrinsics_root, Vec::new(), None)
+					.unwrap();
+			hashes.push(hash);
+		}
+		hashes
+	}
+
+	#[test]
+	fn consistent_database_has_no_issues() {
+		let backend = Backend::<Block>::new_test(10, 0);
+		let hashes = insert_chain(&backend, 4);
+		backend.finalize_block(hashes[2], None).unwrap();
+
+		for number in 0..4 {
+			let report = backend.check_block_integrity(number, true).unwrap();
+			assert_eq!(report.issues, Vec::new(), "block #{}", number);
+			assert_eq!(report.hash, Some(hashes[number as usize]));
+			assert!(report.state_nodes.unwrap() > 0);
+		}
+		assert_eq!(backend.check_metadata_integrity().unwrap(), Vec::new());
+	}
+
+	#[test]
+	fn detects_missing_and_corrupted_state_nodes() {
+		let backend = Backend::<Block>::new_test(10, 0);
+		let hashes = insert_chain(&backend, 3);
+		// Finalize to move the state out of the non-canonical overlay.
+		backend.finalize_block(hashes[2], None).unwrap();
+		let root = *backend.blockchain.header(hashes[2]).unwrap().unwrap().state_root();
+		let key = sp_trie::prefixed_key::<BlakeTwo256>(&root, hash_db::EMPTY_PREFIX);
+
+		let mut transaction = Transaction::new();
+		transaction.remove(columns::STATE, &key);
+		backend.storage.db.commit(transaction).unwrap();
+
+		let report = backend.check_block_integrity(2, true).unwrap();
+		assert_eq!(
+			report.issues,
+			vec![IntegrityIssue::State {
+				hash: hashes[2],
+				child_trie: None,
+				reason: format!("node {:?}: missing", root),
+			}],
+		);
+
+		let mut transaction = Transaction::new();
+		transaction.set(columns::STATE, &key, b"garbage");
+		backend.storage.db.commit(transaction).unwrap();
+
+		let report = backend.check_block_integrity(2, true).unwrap();
+		assert_eq!(
+			report.issues,
+			vec![IntegrityIssue::State {
+				hash: hashes[2],
+				child_trie: None,
+				reason: format!("node {:?}: does not match its hash", root),
+			}],
+		);
+
+		// The other blocks are not affected.
+		assert!(backend.check_block_integrity(1, true).unwrap().is_consistent());
+	}
+
+	#[test]
+	fn detects_missing_header_and_mismatching_body() {
+		let backend = Backend::<Block>::new_test(10, 0);
+		let hashes = insert_chain(&backend, 3);
+
+		let mut transaction = Transaction::new();
+		transaction.remove(
+			columns::HEADER,
+			&utils::number_and_hash_to_lookup_key(1u64, hashes[1]).unwrap(),
+		);
+		transaction.set_from_vec(
+			columns::BODY,
+			&utils::number_and_hash_to_lookup_key(2u64, hashes[2]).unwrap(),
+			vec![ExtrinsicWrapper::from(5u64)].encode(),
+		);
+		backend.storage.db.commit(transaction).unwrap();
+
+		assert_eq!(
+			backend.check_block_integrity(1, false).unwrap().issues,
+			vec![IntegrityIssue::Header { hash: hashes[1], reason: "missing".into() }],
+		);
+		assert_eq!(
+			backend.check_block_integrity(2, false).unwrap().issues,
+			vec![IntegrityIssue::Body {
+				hash: hashes[2],
+				reason: "does not match the extrinsics root of the header".into(),
+			}],
+		);
+	}
+
+	#[test]
+	fn last_consistent_block_skips_inconsistent_blocks() {
+		let backend = Backend::<Block>::new_test(10, 0);
+		let hashes = insert_chain(&backend, 5);
+
+		let mut transaction = Transaction::new();
+		for number in [2u64, 3] {
+			transaction.remove(
+				columns::HEADER,
+				&utils::number_and_hash_to_lookup_key(number, hashes[number as usize]).unwrap(),
+			);
+		}
+		backend.storage.db.commit(transaction).unwrap();
+
+		assert_eq!(backend.last_consistent_block(4, false).unwrap(), Some(4));
+		assert_eq!(backend.last_consistent_block(3, false).unwrap(), Some(1));
+		assert_eq!(backend.last_consistent_block(2, false).unwrap(), Some(1));
+
+		let mut transaction = Transaction::new();
+		transaction.remove(
+			columns::HEADER,
+			&utils::number_and_hash_to_lookup_key(0u64, hashes[0]).unwrap(),
+		);
+		backend.storage.db.commit(transaction).unwrap();
+
+		assert_eq!(backend.last_consistent_block(1, false).unwrap(), Some(1));
+		assert_eq!(backend.last_consistent_block(0, false).unwrap(), None);
+	}
+
+	#[test]
+	fn detects_unknown_leaves() {
+		let backend = Backend::<Block>::new_test(10, 0);
+		let hashes = insert_chain(&backend, 3);
+		let unknown = H256::repeat_byte(7);
+		backend.blockchain.leaves.write().import(unknown, 3, hashes[2]);
+
+		assert_eq!(
+			backend.check_metadata_integrity().unwrap(),
+			vec![IntegrityIssue::Metadata(format!("leaf {:?} is not a stored block", unknown))],
+		);
+	}
+}
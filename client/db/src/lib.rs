@@ -33,6 +33,7 @@ pub mod offchain;
 pub mod bench;
 
 mod children;
+mod integrity;
 mod parity_db;
 mod pinned_blocks_cache;
 mod record_stats_state;
@@ -93,10 +94,11 @@ use sp_state_machine::{
 use sp_trie::{cache::SharedTrieCache, prefixed_key, MemoryDB, PrefixedMemoryDB};
 
 // Re-export the Database trait so that one can pass an implementation of it.
-pub use sc_state_db::PruningMode;
+pub use sc_state_db::{Checkpoints, PruningMode};
 pub use sp_database::Database;
 
 pub use bench::BenchmarkingState;
+pub use integrity::{BlockIntegrity, IntegrityIssue};
 
 const CACHE_HEADERS: usize = 8;
 
@@ -932,6 +934,12 @@ impl<Block: BlockT> sc_client_api::backend::BlockImportOperation<Block>
 		Ok(root)
 	}
 
+	fn set_written_state(&mut self) -> ClientResult<()> {
+		self.db_updates = PrefixedMemoryDB::default();
+		self.commit_state = true;
+		Ok(())
+	}
+
 	fn set_genesis_state(
 		&mut self,
 		storage: Storage,
@@ -1109,6 +1117,9 @@ pub struct Backend<Block: BlockT> {
 	state_usage: Arc<StateUsageStats>,
 	genesis_state: RwLock<Option<Arc<DbGenesisStorage<Block>>>>,
 	shared_trie_cache: Option<sp_trie::cache::SharedTrieCache<HashFor<Block>>>,
+	/// Header of the block being finalized, which may not be committed to the database yet when
+	/// its state is canonicalized.
+	finalizing_header: Arc<Mutex<Option<Block::Header>>>,
 }
 
 impl<Block: BlockT> Backend<Block> {
@@ -1163,6 +1174,61 @@ impl<Block: BlockT> Backend<Block> {
 		Self::new(db_setting, canonicalization_delay).expect("failed to create test-db")
 	}
 
+	/// Set the predicate selecting the blocks whose state is kept forever when the state pruning
+	/// mode is a sparse archive with [`Checkpoints::External`].
+	///
+	/// The predicate is called with the header of each block as it gets canonicalized, for
+	/// example to keep the state of the blocks announcing a new epoch.
+	pub fn set_state_checkpoint_predicate<F>(&self, predicate: F)
+	where
+		F: Fn(&Block::Header) -> bool + Send + Sync + 'static,
+	{
+		let db = self.storage.db.clone();
+		let finalizing_header = self.finalizing_header.clone();
+		self.storage.state_db.set_checkpoint_predicate(Box::new(move |hash, _| {
+			let header = match finalizing_header.lock().as_ref() {
+				Some(header) if header.hash() == *hash => Some(header.clone()),
+				_ => utils::read_header::<Block>(
+					&*db,
+					columns::KEY_LOOKUP,
+					columns::HEADER,
+					BlockId::Hash(*hash),
+				)
+				.unwrap_or_else(|e| {
+					warn!(target: "db", "Failed to read the header of block {:?}: {}", hash, e);
+					None
+				}),
+			};
+			header.map_or(false, |header| predicate(&header))
+		}));
+	}
+
+	/// Write trie nodes of a state that is imported in parts directly to the database.
+	///
+	/// The state is attached to its block by importing the block with a written state, see
+	/// [`sc_client_api::backend::BlockImportOperation::set_written_state`]. The nodes of an
+	/// import that is not completed are left in the database.
+	pub fn write_state_nodes(
+		&self,
+		mut nodes: PrefixedMemoryDB<HashFor<Block>>,
+	) -> ClientResult<()> {
+		let mut transaction = Transaction::new();
+		let mut ops: u64 = 0;
+		let mut bytes: u64 = 0;
+		for (mut key, (val, rc)) in nodes.drain() {
+			self.storage.db.sanitize_key(&mut key);
+			// One write per reference, like the nodes inserted by a block.
+			for _ in 0..rc {
+				ops += 1;
+				bytes += key.len() as u64 + val.len() as u64;
+				transaction.set(columns::STATE, &key, &val);
+			}
+		}
+		self.state_usage.tally_writes_nodes(ops, bytes);
+		self.storage.db.commit(transaction)?;
+		Ok(())
+	}
+
 	/// Expose the Database that is used by this backend.
 	/// The second argument is the Column that stores the State.
 	///
@@ -1225,6 +1291,7 @@ impl<Block: BlockT> Backend<Block> {
 			shared_trie_cache: config.trie_cache_maximum_size.map(|maximum_size| {
 				SharedTrieCache::new(sp_trie::cache::CacheSize::new(maximum_size))
 			}),
+			finalizing_header: Default::default(),
 		};
 
 		// Older DB versions have no last state key. Check if the state is available and set it.
@@ -1777,7 +1844,10 @@ impl<Block: BlockT> Backend<Block> {
 		};
 
 		if requires_canonicalization && sc_client_api::Backend::have_state_at(self, f_hash, f_num) {
-			let commit = self.storage.state_db.canonicalize_block(&f_hash).map_err(
+			*self.finalizing_header.lock() = Some(f_header.clone());
+			let commit = self.storage.state_db.canonicalize_block(&f_hash);
+			*self.finalizing_header.lock() = None;
+			let commit = commit.map_err(
 				sp_blockchain::Error::from_state_db::<
 					sc_state_db::Error<sp_database::error::DatabaseError>,
 				>,
@@ -30,10 +30,10 @@ use jsonrpsee::{
 };
 use serde::{Deserialize, Serialize};
 
-use sp_api::{NumberFor, ProvideRuntimeApi};
+use sp_api::{ApiExt, NumberFor, ProvideRuntimeApi};
 use sp_blockchain::HeaderBackend;
 use sp_core::Bytes;
-use sp_mmr_primitives::{Error as MmrError, Proof};
+use sp_mmr_primitives::{Error as MmrError, LeafIndex, Proof};
 use sp_runtime::traits::Block as BlockT;
 
 pub use sp_mmr_primitives::MmrApi as MmrRuntimeApi;
@@ -69,6 +69,26 @@ impl<BlockHash> LeavesProof<BlockHash> {
 	}
 }
 
+/// Retrieved MMR ancestry proof.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct AncestryProof<BlockHash> {
+	/// Block hash the proof was generated for.
+	pub block_hash: BlockHash,
+	/// SCALE-encoded proof data. See [sp_mmr_primitives::AncestryProof].
+	pub proof: Bytes,
+}
+
+impl<BlockHash> AncestryProof<BlockHash> {
+	/// Create new `AncestryProof` from a given [sp_mmr_primitives::AncestryProof].
+	pub fn new<MmrHash: Encode>(
+		block_hash: BlockHash,
+		proof: sp_mmr_primitives::AncestryProof<MmrHash>,
+	) -> Self {
+		Self { block_hash, proof: Bytes(proof.encode()) }
+	}
+}
+
 /// MMR RPC methods.
 #[rpc(client, server)]
 pub trait MmrApi<BlockHash, BlockNumber, MmrHash> {
@@ -124,6 +144,26 @@ pub trait MmrApi<BlockHash, BlockNumber, MmrHash> {
 		mmr_root: MmrHash,
 		proof: LeavesProof<BlockHash>,
 	) -> RpcResult<bool>;
+
+	/// Generate an MMR ancestry proof for the prior MMR with `prev_leaf_count` leaves.
+	///
+	/// This method calls into a runtime with MMR pallet included and attempts to generate
+	/// a proof that the MMR with `prev_leaf_count` leaves is a prefix of the MMR at
+	/// `best_known_block_number`, for light clients following the MMR root.
+	///
+	/// Optionally via `at`, a block hash at which the runtime should be queried can be specified.
+	/// Optionally via `best_known_block_number`, the proof can be generated using the MMR's state
+	/// at a specific best block, as for `mmr_generateProof`.
+	///
+	/// The runtime must implement version 3 of the MMR runtime API. Returns the SCALE-encoded
+	/// proof.
+	#[method(name = "mmr_generateAncestryProof")]
+	fn generate_ancestry_proof(
+		&self,
+		prev_leaf_count: LeafIndex,
+		best_known_block_number: Option<BlockNumber>,
+		at: Option<BlockHash>,
+	) -> RpcResult<AncestryProof<BlockHash>>;
 }
 
 /// MMR RPC methods.
@@ -224,6 +264,37 @@ where
 
 		Ok(true)
 	}
+
+	fn generate_ancestry_proof(
+		&self,
+		prev_leaf_count: LeafIndex,
+		best_known_block_number: Option<NumberFor<Block>>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<AncestryProof<<Block as BlockT>::Hash>> {
+		let api = self.client.runtime_api();
+		let block_hash = at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash);
+
+		let api_version = api
+			.api_version::<dyn MmrRuntimeApi<Block, MmrHash, NumberFor<Block>>>(block_hash)
+			.map_err(runtime_error_into_rpc_error)?;
+		if !matches!(api_version, Some(version) if version >= 3) {
+			return Err(runtime_error_into_rpc_error("MMR ancestry proofs are not supported").into())
+		}
+
+		let proof = api
+			.generate_ancestry_proof_with_context(
+				block_hash,
+				sp_core::ExecutionContext::OffchainCall(None),
+				prev_leaf_count,
+				best_known_block_number,
+			)
+			.map_err(runtime_error_into_rpc_error)?
+			.map_err(mmr_error_into_rpc_error)?;
+
+		Ok(AncestryProof::new(block_hash, proof))
+	}
 }
 
 /// Converts an mmr-specific error into a [`CallError`].
@@ -300,6 +371,28 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn should_serialize_ancestry_proof() {
+		// given
+		let proof = sp_mmr_primitives::AncestryProof {
+			prev_peaks: vec![H256::repeat_byte(1)],
+			prev_leaf_count: 1,
+			leaf_count: 3,
+			items: vec![H256::repeat_byte(2)],
+		};
+
+		let ancestry_proof = AncestryProof::new(H256::repeat_byte(0), proof);
+
+		// when
+		let actual = serde_json::to_string(&ancestry_proof).unwrap();
+
+		// then
+		assert_eq!(
+			actual,
+			r#"{"blockHash":"0x0000000000000000000000000000000000000000000000000000000000000000","proof":"0x04010101010101010101010101010101010101010101010101010101010101010101000000000000000300000000000000040202020202020202020202020202020202020202020202020202020202020202"}"#
+		);
+	}
+
 	#[test]
 	fn should_deserialize_leaf_proof() {
 		// given
@@ -34,6 +34,9 @@
 //! This gadget is driven by block finality and in responsible for pruning stale forks from
 //! offchain db, and moving finalized forks under a "canonical" key based solely on node `pos`
 //! in the MMR.
+//!
+//! Optionally, it also prunes the canonical nodes that are only needed to prove leaves older than
+//! a chosen depth, keeping the peaks and what is needed to prove the more recent leaves.
 
 #![warn(missing_docs)]
 
@@ -119,6 +122,7 @@ struct OffchainMmrBuilder<B: Block, BE: Backend<B>, C> {
 	client: Arc<C>,
 	offchain_db: OffchainDb<BE::OffchainStorage>,
 	indexing_prefix: Vec<u8>,
+	pruning_depth: Option<LeafIndex>,
 
 	_phantom: PhantomData<B>,
 }
@@ -142,6 +146,7 @@ where
 					self.offchain_db,
 					self.indexing_prefix,
 					first_mmr_block_num,
+					self.pruning_depth,
 				)?;
 				// We need to make sure all blocks leading up to current notification
 				// have also been canonicalized.
@@ -189,7 +194,16 @@ where
 	}
 
 	/// Create and run the MMR gadget.
-	pub async fn start(client: Arc<C>, backend: Arc<BE>, indexing_prefix: Vec<u8>) {
+	///
+	/// If `pruning_depth = Some(n)`, the canonical offchain MMR nodes that are only needed to
+	/// prove leaves older than the last `n` ones are pruned as blocks get finalized.
+	/// Else, all the canonical nodes are kept.
+	pub async fn start(
+		client: Arc<C>,
+		backend: Arc<BE>,
+		indexing_prefix: Vec<u8>,
+		pruning_depth: Option<LeafIndex>,
+	) {
 		let offchain_db = match backend.offchain_storage() {
 			Some(offchain_storage) => OffchainDb::new(offchain_storage),
 			None => {
@@ -212,6 +226,7 @@ where
 				client,
 				offchain_db,
 				indexing_prefix,
+				pruning_depth,
 				_phantom: Default::default(),
 			})
 			.await
@@ -17,7 +17,7 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 //! Logic for canonicalizing MMR offchain entries for finalized forks,
-//! and for pruning MMR offchain entries for stale forks.
+//! and for pruning MMR offchain entries for stale forks and, optionally, old canonical entries.
 
 #![warn(missing_docs)]
 
@@ -28,7 +28,7 @@ use sc_offchain::OffchainDb;
 use sp_blockchain::{CachedHeaderMetadata, ForkBackend};
 use sp_consensus_beefy::MmrRootHash;
 use sp_core::offchain::{DbExternalities, StorageKind};
-use sp_mmr_primitives::{utils, utils::NodesUtils, MmrApi, NodeIndex};
+use sp_mmr_primitives::{utils, utils::NodesUtils, LeafIndex, MmrApi, NodeIndex};
 use sp_runtime::{
 	traits::{Block, Header, NumberFor, One},
 	Saturating,
@@ -43,6 +43,7 @@ pub struct OffchainMmr<B: Block, BE: Backend<B>, C> {
 	indexing_prefix: Vec<u8>,
 	first_mmr_block: NumberFor<B>,
 	best_canonicalized: NumberFor<B>,
+	pruning_depth: Option<LeafIndex>,
 }
 
 impl<B, BE, C> OffchainMmr<B, BE, C>
@@ -58,6 +59,7 @@ where
 		offchain_db: OffchainDb<BE::OffchainStorage>,
 		indexing_prefix: Vec<u8>,
 		first_mmr_block: NumberFor<B>,
+		pruning_depth: Option<LeafIndex>,
 	) -> Option<Self> {
 		let mut best_canonicalized = first_mmr_block.saturating_sub(One::one());
 		best_canonicalized = aux_schema::load_or_init_state::<B, BE>(&*backend, best_canonicalized)
@@ -71,6 +73,7 @@ where
 			indexing_prefix,
 			first_mmr_block,
 			best_canonicalized,
+			pruning_depth,
 		})
 	}
 
@@ -207,6 +210,8 @@ where
 				);
 			}
 		}
+		self.prune_canonicalized_nodes(header.number);
+
 		if self.best_canonicalized != header.number.saturating_sub(One::one()) {
 			warn!(
 				target: LOG_TARGET,
@@ -218,6 +223,36 @@ where
 		self.best_canonicalized = header.number;
 	}
 
+	/// Prune the canonical nodes which are only needed to prove leaves older than the last
+	/// `pruning_depth` ones, now that the leaf of block `block_num` has been canonicalized.
+	///
+	/// The peaks of the MMR, as well as everything needed to prove the recent leaves, are kept.
+	fn prune_canonicalized_nodes(&mut self, block_num: NumberFor<B>) {
+		let pruning_depth = match self.pruning_depth {
+			Some(pruning_depth) => pruning_depth,
+			None => return,
+		};
+		let leaf_idx =
+			match utils::block_num_to_leaf_index::<B::Header>(block_num, self.first_mmr_block) {
+				Ok(leaf_idx) => leaf_idx,
+				Err(_) => return,
+			};
+		// The leaf which just got out of the window of leaves to keep proving.
+		let pruned_leaf_idx = match leaf_idx.checked_sub(pruning_depth) {
+			Some(pruned_leaf_idx) => pruned_leaf_idx,
+			None => return,
+		};
+
+		for pos in NodesUtils::children_of_right_branch_ending_in_leaf(pruned_leaf_idx) {
+			let canon_key = self.node_canon_offchain_key(pos);
+			self.offchain_db.local_storage_clear(StorageKind::PERSISTENT, &canon_key);
+			debug!(
+				target: LOG_TARGET,
+				"Pruned canonical elem at pos {} with canon key {:?}", pos, canon_key
+			);
+		}
+	}
+
 	/// In case of missed finality notifications (node restarts for example),
 	/// make sure to also canon everything leading up to `notification.tree_route`.
 	pub fn canonicalize_catch_up(&mut self, notification: &FinalityNotification<B>) {
@@ -262,6 +297,8 @@ where
 	/// Move leafs and nodes added by finalized blocks in offchain db from _fork-aware key_ to
 	/// _canonical key_.
 	/// Prune leafs and nodes added by stale blocks in offchain db from _fork-aware key_.
+	/// If a pruning depth is set, also prune the canonical leafs and nodes no longer needed to
+	/// prove the most recent leafs.
 	pub fn canonicalize_and_prune(&mut self, notification: FinalityNotification<B>) {
 		// Update the first MMR block in case of a pallet reset.
 		self.handle_potential_pallet_reset(&notification);
@@ -287,7 +324,10 @@ where
 
 #[cfg(test)]
 mod tests {
-	use crate::test_utils::{run_test_with_mmr_gadget, run_test_with_mmr_gadget_pre_post};
+	use crate::test_utils::{
+		run_test_with_mmr_gadget, run_test_with_mmr_gadget_pre_post,
+		run_test_with_pruning_mmr_gadget,
+	};
 	use parking_lot::Mutex;
 	use sp_runtime::generic::BlockId;
 	use std::{sync::Arc, time::Duration};
@@ -331,6 +371,34 @@ mod tests {
 		})
 	}
 
+	#[test]
+	fn canonicalize_prunes_nodes_older_than_pruning_depth() {
+		run_test_with_pruning_mmr_gadget(2, |client| async move {
+			// G -> A1 -> A2 -> A3 -> A4 -> A5 -> A6 -> A7 -> A8
+
+			let mut blocks = vec![];
+			let mut parent = BlockId::Number(0);
+			for leaf_idx in 0..8 {
+				let name = format!("a{}", leaf_idx + 1);
+				let block = client.import_block(&parent, name.as_bytes(), Some(leaf_idx)).await;
+				parent = BlockId::Hash(block.hash());
+				blocks.push(block);
+			}
+
+			client.finalize_block(blocks[7].hash(), Some(8));
+			tokio::time::sleep(Duration::from_millis(200)).await;
+			// Only the nodes needed to prove the last 2 leaves (A7 and A8) are kept:
+			// the siblings 6 and 9 of their path to the peak 14, and the nodes 10 to 14 added by
+			// A7 and A8.
+			for pos in [0, 1, 2, 3, 4, 5, 7, 8] {
+				assert!(client.canon_node(pos).is_none());
+			}
+			for pos in [6, 9, 10, 11, 12, 13, 14] {
+				assert!(client.canon_node(pos).is_some());
+			}
+		})
+	}
+
 	#[test]
 	fn canonicalize_and_prune_handles_pallet_reset() {
 		run_test_with_mmr_gadget(|client| async move {
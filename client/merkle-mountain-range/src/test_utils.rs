@@ -175,6 +175,11 @@ impl MockClient {
 		}
 	}
 
+	pub fn canon_node(&self, node: NodeIndex) -> Option<Vec<u8>> {
+		let canon_key = NodesUtils::node_canon_offchain_key(MockRuntimeApi::INDEXING_PREFIX, node);
+		self.offchain_db().local_storage_get(StorageKind::PERSISTENT, &canon_key)
+	}
+
 	pub fn check_offchain_storage<F>(
 		&self,
 		key_type: OffchainKeyType,
@@ -332,6 +337,15 @@ where
 	run_test_with_mmr_gadget_pre_post(|_| async {}, post_gadget);
 }
 
+pub(crate) fn run_test_with_pruning_mmr_gadget<F, Fut>(pruning_depth: LeafIndex, post_gadget: F)
+where
+	F: FnOnce(Arc<MockClient>) -> Fut + 'static,
+	Fut: Future<Output = ()>,
+{
+	let client = Arc::new(MockClient::new());
+	run_test_with_mmr_gadget_impl(client, Some(pruning_depth), |_| async {}, post_gadget);
+}
+
 pub(crate) fn run_test_with_mmr_gadget_pre_post<F, G, RetF, RetG>(pre_gadget: F, post_gadget: G)
 where
 	F: FnOnce(Arc<MockClient>) -> RetF + 'static,
@@ -352,6 +366,20 @@ pub(crate) fn run_test_with_mmr_gadget_pre_post_using_client<F, G, RetF, RetG>(
 	G: FnOnce(Arc<MockClient>) -> RetG + 'static,
 	RetF: Future<Output = ()>,
 	RetG: Future<Output = ()>,
+{
+	run_test_with_mmr_gadget_impl(client, None, pre_gadget, post_gadget)
+}
+
+fn run_test_with_mmr_gadget_impl<F, G, RetF, RetG>(
+	client: Arc<MockClient>,
+	pruning_depth: Option<LeafIndex>,
+	pre_gadget: F,
+	post_gadget: G,
+) where
+	F: FnOnce(Arc<MockClient>) -> RetF + 'static,
+	G: FnOnce(Arc<MockClient>) -> RetG + 'static,
+	RetF: Future<Output = ()>,
+	RetG: Future<Output = ()>,
 {
 	let client_clone = client.clone();
 	let runtime = Runtime::new().unwrap();
@@ -360,7 +388,13 @@ pub(crate) fn run_test_with_mmr_gadget_pre_post_using_client<F, G, RetF, RetG>(
 	let client_clone = client.clone();
 	runtime.spawn(async move {
 		let backend = client_clone.backend.clone();
-		MmrGadget::start(client_clone, backend, MockRuntimeApi::INDEXING_PREFIX.to_vec()).await
+		MmrGadget::start(
+			client_clone,
+			backend,
+			MockRuntimeApi::INDEXING_PREFIX.to_vec(),
+			pruning_depth,
+		)
+		.await
 	});
 
 	runtime.block_on(async move {
@@ -0,0 +1,297 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Peer authorization based on the on-chain node allowlist.
+//!
+//! [`NodeAuthorization`] implements [`PeerAuthorization`] with the nodes returned by the
+//! [`NodeAuthorizationApi`] of the runtime for the local node, usually backed by
+//! `pallet_node_authorization`. The allowlist is read at the last finalized block and cached, so
+//! that the networking layer can consult it without blocking. [`NodeAuthorization::run`] must be
+//! spawned to load it and keep it up to date as blocks are finalized.
+//!
+//! Until the allowlist is loaded for the first time, no peer is authorized. All peers are
+//! authorized if the runtime doesn't implement the [`NodeAuthorizationApi`], or if it reports
+//! that the allowlist is not in use.
+
+use futures::StreamExt;
+use log::{debug, warn};
+use pallet_node_authorization_runtime_api::NodeAuthorizationApi;
+use parking_lot::RwLock;
+use sc_client_api::BlockchainEvents;
+use sc_network::{config::PeerAuthorization, PeerId};
+use sp_api::{ApiExt, ProvideRuntimeApi};
+use sp_blockchain::HeaderBackend;
+use sp_core::OpaquePeerId;
+use sp_runtime::traits::Block as BlockT;
+use std::{collections::HashSet, marker::PhantomData, sync::Arc};
+
+/// Log target for this crate.
+const LOG_TARGET: &str = "node-authorization";
+
+/// The peers allowed to connect to the local node.
+enum Allowlist {
+	/// All peers are allowed.
+	Unrestricted,
+	/// Only the given peers are allowed.
+	Restricted(HashSet<PeerId>),
+}
+
+/// Authorizes the peers allowed by the on-chain node allowlist at the last finalized block.
+pub struct NodeAuthorization<Block, Client> {
+	client: Arc<Client>,
+	/// The authorized peers.
+	allowlist: RwLock<Allowlist>,
+	_phantom: PhantomData<Block>,
+}
+
+impl<Block, Client> NodeAuthorization<Block, Client>
+where
+	Block: BlockT,
+	Client: ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	Client::Api: NodeAuthorizationApi<Block>,
+{
+	/// Creates a new [`NodeAuthorization`], which authorizes no peer until
+	/// [`NodeAuthorization::run`] has loaded the allowlist.
+	pub fn new(client: Arc<Client>) -> Self {
+		Self {
+			client,
+			allowlist: RwLock::new(Allowlist::Restricted(HashSet::new())),
+			_phantom: PhantomData,
+		}
+	}
+
+	/// Reloads the allowlist of `local_peer_id` at block `at`.
+	///
+	/// The previous allowlist is kept if it can't be loaded.
+	fn refresh(&self, at: Block::Hash, local_peer_id: &OpaquePeerId) {
+		match self.load(at, local_peer_id) {
+			Ok(allowlist) => {
+				match &allowlist {
+					Allowlist::Unrestricted =>
+						debug!(target: LOG_TARGET, "Node allowlist not in use at {:?}", at),
+					Allowlist::Restricted(authorized) => debug!(
+						target: LOG_TARGET,
+						"Loaded {} authorized nodes at {:?}",
+						authorized.len(),
+						at,
+					),
+				}
+				*self.allowlist.write() = allowlist;
+			},
+			Err(e) =>
+				warn!(target: LOG_TARGET, "Failed to load the authorized nodes at {:?}: {}", at, e),
+		}
+	}
+
+	fn load(
+		&self,
+		at: Block::Hash,
+		local_peer_id: &OpaquePeerId,
+	) -> Result<Allowlist, sp_api::ApiError> {
+		let api = self.client.runtime_api();
+		if !api.has_api::<dyn NodeAuthorizationApi<Block>>(at)? {
+			return Ok(Allowlist::Unrestricted)
+		}
+
+		Ok(match api.authorized_nodes(at, local_peer_id.clone())? {
+			Some(nodes) => Allowlist::Restricted(
+				nodes.into_iter().filter_map(|node| PeerId::from_bytes(&node.0).ok()).collect(),
+			),
+			None => Allowlist::Unrestricted,
+		})
+	}
+}
+
+impl<Block, Client> NodeAuthorization<Block, Client>
+where
+	Block: BlockT,
+	Client: ProvideRuntimeApi<Block> + HeaderBackend<Block> + BlockchainEvents<Block>,
+	Client::Api: NodeAuthorizationApi<Block>,
+{
+	/// Loads the allowlist of the node with the given `local_peer_id` and reloads it whenever a
+	/// block is finalized.
+	pub async fn run(self: Arc<Self>, local_peer_id: PeerId) {
+		let local_peer_id = OpaquePeerId(local_peer_id.to_bytes());
+		let mut finality_notifications = self.client.finality_notification_stream();
+		self.refresh(self.client.info().finalized_hash, &local_peer_id);
+		while let Some(notification) = finality_notifications.next().await {
+			self.refresh(notification.hash, &local_peer_id);
+		}
+	}
+}
+
+impl<Block, Client> PeerAuthorization for NodeAuthorization<Block, Client>
+where
+	Block: BlockT,
+	Client: Send + Sync,
+{
+	fn is_authorized(&self, peer_id: &PeerId) -> bool {
+		match &*self.allowlist.read() {
+			Allowlist::Unrestricted => true,
+			Allowlist::Restricted(authorized) => authorized.contains(peer_id),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use parking_lot::Mutex;
+	use sp_api::ApiRef;
+	use sp_runtime::traits::{NumberFor, Zero};
+	use substrate_test_runtime_client::runtime::Block;
+
+	#[derive(Clone, Default)]
+	struct TestApi {
+		/// The allowed connections of each node, or `None` if the allowlist is not in use.
+		connections: Arc<Mutex<Option<Vec<(OpaquePeerId, Vec<OpaquePeerId>)>>>>,
+		/// Whether loading the allowlist fails.
+		fail: Arc<Mutex<bool>>,
+	}
+
+	impl ProvideRuntimeApi<Block> for TestApi {
+		type Api = RuntimeApi;
+
+		fn runtime_api(&self) -> ApiRef<'_, Self::Api> {
+			RuntimeApi { connections: self.connections.lock().clone(), fail: *self.fail.lock() }
+				.into()
+		}
+	}
+
+	impl HeaderBackend<Block> for TestApi {
+		fn header(
+			&self,
+			_hash: <Block as BlockT>::Hash,
+		) -> sp_blockchain::Result<Option<<Block as BlockT>::Header>> {
+			Ok(None)
+		}
+
+		fn info(&self) -> sc_client_api::blockchain::Info<Block> {
+			sc_client_api::blockchain::Info {
+				best_hash: Default::default(),
+				best_number: Zero::zero(),
+				finalized_hash: Default::default(),
+				finalized_number: Zero::zero(),
+				genesis_hash: Default::default(),
+				number_leaves: Default::default(),
+				finalized_state: None,
+				block_gap: None,
+			}
+		}
+
+		fn status(
+			&self,
+			_hash: <Block as BlockT>::Hash,
+		) -> sp_blockchain::Result<sc_client_api::blockchain::BlockStatus> {
+			Ok(sc_client_api::blockchain::BlockStatus::Unknown)
+		}
+
+		fn number(
+			&self,
+			_hash: <Block as BlockT>::Hash,
+		) -> sp_blockchain::Result<Option<NumberFor<Block>>> {
+			Ok(None)
+		}
+
+		fn hash(
+			&self,
+			_number: NumberFor<Block>,
+		) -> sp_blockchain::Result<Option<<Block as BlockT>::Hash>> {
+			Ok(None)
+		}
+	}
+
+	struct RuntimeApi {
+		connections: Option<Vec<(OpaquePeerId, Vec<OpaquePeerId>)>>,
+		fail: bool,
+	}
+
+	sp_api::mock_impl_runtime_apis! {
+		impl NodeAuthorizationApi<Block> for RuntimeApi {
+			#[advanced]
+			fn authorized_nodes(
+				&self,
+				_: <Block as BlockT>::Hash,
+				node: OpaquePeerId,
+			) -> Result<Option<Vec<OpaquePeerId>>, sp_api::ApiError> {
+				if self.fail {
+					return Err(sp_api::ApiError::Application("failed to load".into()))
+				}
+				Ok(self.connections.as_ref().map(|connections| {
+					connections
+						.iter()
+						.find(|(n, _)| *n == node)
+						.map(|(_, nodes)| nodes.clone())
+						.unwrap_or_default()
+				}))
+			}
+		}
+	}
+
+	fn opaque(peer_id: &PeerId) -> OpaquePeerId {
+		OpaquePeerId(peer_id.to_bytes())
+	}
+
+	#[test]
+	fn allowed_connections_of_local_node_are_authorized() {
+		let (local, allowed, other) = (PeerId::random(), PeerId::random(), PeerId::random());
+		let client = TestApi::default();
+		*client.connections.lock() = Some(vec![
+			(opaque(&local), vec![opaque(&allowed)]),
+			(opaque(&allowed), vec![opaque(&other)]),
+		]);
+
+		let node_authorization = NodeAuthorization::new(Arc::new(client.clone()));
+		node_authorization.refresh(Default::default(), &opaque(&local));
+		assert!(node_authorization.is_authorized(&allowed));
+		assert!(!node_authorization.is_authorized(&other));
+
+		// The allowlist is only updated once it is reloaded.
+		client.connections.lock().as_mut().unwrap()[0].1.push(opaque(&other));
+		assert!(!node_authorization.is_authorized(&other));
+		node_authorization.refresh(Default::default(), &opaque(&local));
+		assert!(node_authorization.is_authorized(&other));
+
+		// All peers are authorized once the allowlist is no longer in use.
+		*client.connections.lock() = None;
+		node_authorization.refresh(Default::default(), &opaque(&local));
+		assert!(node_authorization.is_authorized(&PeerId::random()));
+	}
+
+	#[test]
+	fn no_peer_is_authorized_until_the_allowlist_is_loaded() {
+		let (local, allowed) = (PeerId::random(), PeerId::random());
+		let client = TestApi::default();
+		*client.connections.lock() = Some(vec![(opaque(&local), vec![opaque(&allowed)])]);
+		*client.fail.lock() = true;
+
+		let node_authorization = NodeAuthorization::new(Arc::new(client.clone()));
+		assert!(!node_authorization.is_authorized(&allowed));
+		node_authorization.refresh(Default::default(), &opaque(&local));
+		assert!(!node_authorization.is_authorized(&allowed));
+
+		*client.fail.lock() = false;
+		node_authorization.refresh(Default::default(), &opaque(&local));
+		assert!(node_authorization.is_authorized(&allowed));
+
+		// A failure to reload keeps the allowlist.
+		*client.fail.lock() = true;
+		node_authorization.refresh(Default::default(), &opaque(&local));
+		assert!(node_authorization.is_authorized(&allowed));
+	}
+}
@@ -0,0 +1,287 @@
+// Copyright Parity Technologies (UK) Ltd.
+// This file is part of Substrate.
+
+// Substrate is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Substrate is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Substrate.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Bitswap client.
+//!
+//! Retrieves blocks from the peers we are syncing with. A block is requested from one peer after
+//! another until a peer returns data hashing to the digest of the CID.
+
+use crate::{
+	is_supported_cid,
+	schema::bitswap::{
+		message::{
+			wantlist::{Entry, WantType},
+			Wantlist,
+		},
+		Message as BitswapMessage,
+	},
+	BitswapError, LOG_TARGET, PROTOCOL_NAME,
+};
+use cid::Cid;
+use futures::{
+	channel::oneshot,
+	future::BoxFuture,
+	stream::{Fuse, FuturesUnordered, Stream, StreamExt},
+	FutureExt,
+};
+use libp2p::PeerId;
+use log::{debug, trace};
+use prost::Message;
+use sc_network_common::{
+	protocol::ProtocolName,
+	request_responses::IfDisconnected,
+	service::{NetworkPeers, NetworkRequest},
+	sync::{SyncEvent, SyncEventStream},
+};
+use sc_peerset::ReputationChange;
+use sc_utils::mpsc::{tracing_unbounded, TracingUnboundedReceiver, TracingUnboundedSender};
+use sp_core::hashing::blake2_256;
+use std::pin::Pin;
+
+/// Maximum number of peers a block is requested from before giving up.
+const MAX_PEERS_PER_BLOCK: usize = 8;
+
+/// Reputation change for a peer which returned data not matching the requested CID.
+const INVALID_BLOCK_REPUTATION_CHANGE: ReputationChange =
+	ReputationChange::new_fatal("Bitswap block doesn't match the CID");
+
+/// Messages from [`BitswapClientHandle`] to [`BitswapClient`].
+enum ToClient {
+	Get { cid: Cid, tx: oneshot::Sender<Result<Vec<u8>, BitswapError>> },
+}
+
+/// Handle to request blocks from a running [`BitswapClient`].
+#[derive(Clone)]
+pub struct BitswapClientHandle {
+	to_client: TracingUnboundedSender<ToClient>,
+}
+
+impl BitswapClientHandle {
+	/// Retrieve the block referenced by `cid` from the connected peers.
+	///
+	/// Only CIDv1 referencing a Blake2b-256 hash, like the ones of the transactions stored by
+	/// `pallet-transaction-storage`, are supported.
+	pub async fn get(&self, cid: Cid) -> Result<Vec<u8>, BitswapError> {
+		if !is_supported_cid(&cid) {
+			return Err(BitswapError::UnsupportedCid)
+		}
+
+		let (tx, rx) = oneshot::channel();
+		self.to_client
+			.unbounded_send(ToClient::Get { cid, tx })
+			.map_err(|_| BitswapError::ClientTerminated)?;
+		rx.await.map_err(|_| BitswapError::ClientTerminated)?
+	}
+}
+
+/// Bitswap client, requesting blocks from the peers we are syncing with.
+pub struct BitswapClient<N> {
+	network: N,
+	sync_event_stream: Fuse<Pin<Box<dyn Stream<Item = SyncEvent> + Send>>>,
+	from_handles: TracingUnboundedReceiver<ToClient>,
+	/// Connected peers, in the order they connected.
+	peers: Vec<PeerId>,
+	pending_requests: FuturesUnordered<BoxFuture<'static, ()>>,
+}
+
+impl<N> BitswapClient<N>
+where
+	N: NetworkRequest + NetworkPeers + Clone + Send + Sync + 'static,
+{
+	/// Create a new [`BitswapClient`] and a handle to request blocks from it.
+	pub fn new<S: SyncEventStream>(network: N, sync: &S) -> (Self, BitswapClientHandle) {
+		let (to_client, from_handles) = tracing_unbounded("mpsc_bitswap_client", 1_000);
+		let client = Self {
+			network,
+			sync_event_stream: sync.event_stream("bitswap-client").fuse(),
+			from_handles,
+			peers: Vec::new(),
+			pending_requests: FuturesUnordered::new(),
+		};
+
+		(client, BitswapClientHandle { to_client })
+	}
+
+	/// Run [`BitswapClient`].
+	pub async fn run(mut self) {
+		loop {
+			futures::select! {
+				sync_event = self.sync_event_stream.next() => match sync_event {
+					Some(SyncEvent::PeerConnected(peer)) => self.peers.push(peer),
+					Some(SyncEvent::PeerDisconnected(peer)) => self.peers.retain(|p| *p != peer),
+					// Syncing has seemingly closed. Closing as well.
+					None => return,
+				},
+				message = self.from_handles.next() => match message {
+					Some(ToClient::Get { cid, tx }) => {
+						let peers = self.peers.iter().take(MAX_PEERS_PER_BLOCK).copied().collect();
+						self.pending_requests.push(
+							get_block(self.network.clone(), peers, cid)
+								.map(move |result| {
+									let _ = tx.send(result);
+								})
+								.boxed(),
+						);
+					},
+					// All the handles are gone.
+					None => return,
+				},
+				_ = self.pending_requests.select_next_some() => {},
+			}
+		}
+	}
+}
+
+/// Build the request for the block referenced by `cid`.
+fn block_request(cid: &Cid) -> Vec<u8> {
+	BitswapMessage {
+		wantlist: Some(Wantlist {
+			entries: vec![Entry {
+				block: cid.to_bytes(),
+				priority: 1,
+				cancel: false,
+				want_type: WantType::Block as i32,
+				send_dont_have: false,
+			}],
+			full: true,
+		}),
+		..Default::default()
+	}
+	.encode_to_vec()
+}
+
+/// Extract the block referenced by `cid` from `response`.
+///
+/// Returns `Ok(None)` if the response doesn't contain any block, and an error if it contains
+/// a block not matching `cid`.
+fn block_from_response(cid: &Cid, response: &[u8]) -> Result<Option<Vec<u8>>, BitswapError> {
+	let message = BitswapMessage::decode(response)?;
+	// Only a single block was requested.
+	match message.payload.into_iter().next() {
+		Some(block) if blake2_256(&block.data)[..] == cid.hash().digest()[..] =>
+			Ok(Some(block.data)),
+		Some(_) => Err(BitswapError::InvalidBlock),
+		None => Ok(None),
+	}
+}
+
+/// Request the block referenced by `cid` from `peers`, one after another.
+async fn get_block<N: NetworkRequest + NetworkPeers>(
+	network: N,
+	peers: Vec<PeerId>,
+	cid: Cid,
+) -> Result<Vec<u8>, BitswapError> {
+	let request = block_request(&cid);
+
+	for peer in peers {
+		let response = match network
+			.request(
+				peer,
+				ProtocolName::from(PROTOCOL_NAME),
+				request.clone(),
+				IfDisconnected::ImmediateError,
+			)
+			.await
+		{
+			Ok(response) => response,
+			Err(err) => {
+				trace!(target: LOG_TARGET, "Failed to request {cid} from {peer}: {err}");
+				continue
+			},
+		};
+
+		match block_from_response(&cid, &response) {
+			Ok(Some(block)) => {
+				trace!(target: LOG_TARGET, "Retrieved {cid} from {peer}");
+				return Ok(block)
+			},
+			Ok(None) => trace!(target: LOG_TARGET, "{peer} doesn't have {cid}"),
+			Err(err) => {
+				debug!(target: LOG_TARGET, "Invalid response to {cid} from {peer}: {err}");
+				network.report_peer(peer, INVALID_BLOCK_REPUTATION_CHANGE);
+			},
+		}
+	}
+
+	Err(BitswapError::NotFound)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::schema::bitswap::message::Block as MessageBlock;
+
+	fn cid_of(data: &[u8]) -> Cid {
+		let hash = cid::multihash::Multihash::wrap(
+			u64::from(cid::multihash::Code::Blake2b256),
+			&blake2_256(data),
+		)
+		.unwrap();
+		Cid::new_v1(0x55, hash)
+	}
+
+	fn response(blocks: &[&[u8]]) -> Vec<u8> {
+		BitswapMessage {
+			payload: blocks
+				.iter()
+				.map(|data| MessageBlock { prefix: vec![], data: data.to_vec() })
+				.collect(),
+			..Default::default()
+		}
+		.encode_to_vec()
+	}
+
+	#[test]
+	fn block_request_wants_the_block() {
+		let cid = cid_of(b"some data");
+		let message = BitswapMessage::decode(&block_request(&cid)[..]).unwrap();
+		let entries = message.wantlist.unwrap().entries;
+
+		assert_eq!(entries.len(), 1);
+		assert_eq!(Cid::read_bytes(&entries[0].block[..]).unwrap(), cid);
+		assert_eq!(entries[0].want_type, WantType::Block as i32);
+	}
+
+	#[test]
+	fn cid_binary_layout() {
+		let hash = blake2_256(b"some data");
+		let mut bytes = vec![0x01, 0x55, 0xa0, 0xe4, 0x02, 0x20];
+		bytes.extend_from_slice(&hash);
+
+		let cid = Cid::read_bytes(&bytes[..]).unwrap();
+		assert_eq!(cid, cid_of(b"some data"));
+		assert!(is_supported_cid(&cid));
+	}
+
+	#[test]
+	fn blocks_are_verified() {
+		let cid = cid_of(b"some data");
+
+		assert_eq!(
+			block_from_response(&cid, &response(&[b"some data"])).unwrap(),
+			Some(b"some data".to_vec()),
+		);
+		assert_eq!(block_from_response(&cid, &response(&[])).unwrap(), None);
+		assert!(matches!(
+			block_from_response(&cid, &response(&[b"other data"])),
+			Err(BitswapError::InvalidBlock),
+		));
+		assert!(matches!(
+			block_from_response(&cid, &[0x13, 0x37]),
+			Err(BitswapError::DecodeProto(_)),
+		));
+	}
+}
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with Substrate.  If not, see <http://www.gnu.org/licenses/>.
 
-//! Bitswap server for Substrate.
+//! Bitswap server and client for Substrate.
 //!
 //! Allows querying transactions by hash over standard bitswap protocol
 //! Only supports bitswap 1.2.0.
@@ -38,8 +38,12 @@ use sp_runtime::traits::Block as BlockT;
 use std::{io, sync::Arc, time::Duration};
 use unsigned_varint::encode as varint_encode;
 
+mod client;
 mod schema;
 
+pub use cid::Cid;
+pub use client::{BitswapClient, BitswapClientHandle};
+
 const LOG_TARGET: &str = "bitswap";
 
 // Undocumented, but according to JS the bitswap messages have a max size of 512*1024 bytes
@@ -90,6 +94,31 @@ impl Prefix {
 	}
 }
 
+/// Whether `cid` references a 256-bit Blake2b hash, the only kind of CID we serve and request.
+fn is_supported_cid(cid: &cid::Cid) -> bool {
+	cid.version() == cid::Version::V1 &&
+		cid.hash().code() == u64::from(cid::multihash::Code::Blake2b256) &&
+		cid.hash().size() == 32
+}
+
+/// Configuration of the bitswap protocol, serving requests through `inbound_queue` if any.
+fn protocol_config(inbound_queue: Option<mpsc::Sender<IncomingRequest>>) -> ProtocolConfig {
+	ProtocolConfig {
+		name: ProtocolName::from(PROTOCOL_NAME),
+		fallback_names: vec![],
+		max_request_size: MAX_PACKET_SIZE,
+		max_response_size: MAX_PACKET_SIZE,
+		request_timeout: Duration::from_secs(15),
+		inbound_queue,
+	}
+}
+
+/// Configuration of the bitswap protocol for nodes which request blocks with a
+/// [`BitswapClient`] without serving bitswap requests themselves.
+pub fn outbound_protocol_config() -> ProtocolConfig {
+	protocol_config(None)
+}
+
 /// Bitswap request handler
 pub struct BitswapRequestHandler<B> {
 	client: Arc<dyn BlockBackend<B> + Send + Sync>,
@@ -101,16 +130,7 @@ impl<B: BlockT> BitswapRequestHandler<B> {
 	pub fn new(client: Arc<dyn BlockBackend<B> + Send + Sync>) -> (Self, ProtocolConfig) {
 		let (tx, request_receiver) = mpsc::channel(MAX_REQUEST_QUEUE);
 
-		let config = ProtocolConfig {
-			name: ProtocolName::from(PROTOCOL_NAME),
-			fallback_names: vec![],
-			max_request_size: MAX_PACKET_SIZE,
-			max_response_size: MAX_PACKET_SIZE,
-			request_timeout: Duration::from_secs(15),
-			inbound_queue: Some(tx),
-		};
-
-		(Self { client, request_receiver }, config)
+		(Self { client, request_receiver }, protocol_config(Some(tx)))
 	}
 
 	/// Run [`BitswapRequestHandler`].
@@ -119,11 +139,12 @@ impl<B: BlockT> BitswapRequestHandler<B> {
 			let IncomingRequest { peer, payload, pending_response } = request;
 
 			match self.handle_message(&peer, &payload) {
-				Ok(response) => {
+				Ok((response, db_reads)) => {
 					let response = OutgoingResponse {
 						result: Ok(response),
 						reputation_changes: Vec::new(),
 						sent_feedback: None,
+						db_reads,
 					};
 
 					match pending_response.send(response) {
@@ -145,6 +166,7 @@ impl<B: BlockT> BitswapRequestHandler<B> {
 						result: Err(()),
 						reputation_changes: vec![],
 						sent_feedback: None,
+						db_reads: 0,
 					};
 
 					if pending_response.send(response).is_err() {
@@ -159,17 +181,19 @@ impl<B: BlockT> BitswapRequestHandler<B> {
 		}
 	}
 
-	/// Handle received Bitswap request
+	/// Handle received Bitswap request, returning the encoded response and the number of
+	/// transactions looked up in the database.
 	fn handle_message(
 		&mut self,
 		peer: &PeerId,
 		payload: &Vec<u8>,
-	) -> Result<Vec<u8>, BitswapError> {
+	) -> Result<(Vec<u8>, u64), BitswapError> {
 		let request = schema::bitswap::Message::decode(&payload[..])?;
 
 		trace!(target: LOG_TARGET, "Received request: {:?} from {}", request, peer);
 
 		let mut response = BitswapMessage::default();
+		let mut db_reads = 0;
 
 		let wantlist = match request.wantlist {
 			Some(wantlist) => wantlist,
@@ -193,16 +217,14 @@ impl<B: BlockT> BitswapRequestHandler<B> {
 				},
 			};
 
-			if cid.version() != cid::Version::V1 ||
-				cid.hash().code() != u64::from(cid::multihash::Code::Blake2b256) ||
-				cid.hash().size() != 32
-			{
+			if !is_supported_cid(&cid) {
 				debug!(target: LOG_TARGET, "Ignoring unsupported CID {}: {}", peer, cid);
 				continue
 			}
 
 			let mut hash = B::Hash::default();
 			hash.as_mut().copy_from_slice(&cid.hash().digest()[0..32]);
+			db_reads += 1;
 			let transaction = match self.client.indexed_transaction(hash) {
 				Ok(ex) => ex,
 				Err(e) => {
@@ -245,7 +267,7 @@ impl<B: BlockT> BitswapRequestHandler<B> {
 			}
 		}
 
-		Ok(response.encode_to_vec())
+		Ok((response.encode_to_vec(), db_reads))
 	}
 }
 
@@ -283,6 +305,22 @@ pub enum BitswapError {
 	/// Too many blocks requested.
 	#[error("Too many block entries in the request.")]
 	TooManyEntries,
+
+	/// The CID doesn't reference a 256-bit Blake2b hash.
+	#[error("Unsupported CID.")]
+	UnsupportedCid,
+
+	/// A peer returned a block not matching the requested CID.
+	#[error("Block doesn't match the CID.")]
+	InvalidBlock,
+
+	/// None of the peers returned the requested block.
+	#[error("Block not found.")]
+	NotFound,
+
+	/// The bitswap client isn't running anymore.
+	#[error("Bitswap client terminated.")]
+	ClientTerminated,
 }
 
 #[cfg(test)]
@@ -318,7 +356,7 @@ mod tests {
 			.await
 			.unwrap();
 
-		if let Ok(OutgoingResponse { result, reputation_changes, sent_feedback }) = rx.await {
+		if let Ok(OutgoingResponse { result, reputation_changes, sent_feedback, .. }) = rx.await {
 			assert_eq!(result, Err(()));
 			assert_eq!(reputation_changes, Vec::new());
 			assert!(sent_feedback.is_none());
@@ -347,7 +385,7 @@ mod tests {
 			.await
 			.unwrap();
 
-		if let Ok(OutgoingResponse { result, reputation_changes, sent_feedback }) = rx.await {
+		if let Ok(OutgoingResponse { result, reputation_changes, sent_feedback, .. }) = rx.await {
 			assert_eq!(result, Err(()));
 			assert_eq!(reputation_changes, Vec::new());
 			assert!(sent_feedback.is_none());
@@ -372,7 +410,7 @@ mod tests {
 			.await
 			.unwrap();
 
-		if let Ok(OutgoingResponse { result, reputation_changes, sent_feedback }) = rx.await {
+		if let Ok(OutgoingResponse { result, reputation_changes, sent_feedback, .. }) = rx.await {
 			assert_eq!(result, Ok(BitswapMessage::default().encode_to_vec()));
 			assert_eq!(reputation_changes, Vec::new());
 			assert!(sent_feedback.is_none());
@@ -409,7 +447,7 @@ mod tests {
 			.await
 			.unwrap();
 
-		if let Ok(OutgoingResponse { result, reputation_changes, sent_feedback }) = rx.await {
+		if let Ok(OutgoingResponse { result, reputation_changes, sent_feedback, .. }) = rx.await {
 			assert_eq!(result, Err(()));
 			assert_eq!(reputation_changes, Vec::new());
 			assert!(sent_feedback.is_none());
@@ -455,7 +493,7 @@ mod tests {
 			.await
 			.unwrap();
 
-		if let Ok(OutgoingResponse { result, reputation_changes, sent_feedback }) = rx.await {
+		if let Ok(OutgoingResponse { result, reputation_changes, sent_feedback, .. }) = rx.await {
 			assert_eq!(result, Ok(vec![]));
 			assert_eq!(reputation_changes, Vec::new());
 			assert!(sent_feedback.is_none());
@@ -510,7 +548,7 @@ mod tests {
 			.await
 			.unwrap();
 
-		if let Ok(OutgoingResponse { result, reputation_changes, sent_feedback }) = rx.await {
+		if let Ok(OutgoingResponse { result, reputation_changes, sent_feedback, .. }) = rx.await {
 			assert_eq!(reputation_changes, Vec::new());
 			assert!(sent_feedback.is_none());
 
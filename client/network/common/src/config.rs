@@ -22,6 +22,7 @@ pub use crate::{
 	protocol::{self, role::Role},
 	request_responses::{
 		IncomingRequest, OutgoingResponse, ProtocolConfig as RequestResponseConfig,
+		RequestBudgetConfig,
 	},
 	sync::warp::WarpSyncProvider,
 	ExHashT,
@@ -388,6 +389,73 @@ impl Default for SyncMode {
 	}
 }
 
+/// Which data is downloaded for the blocks of the history skipped by warp sync.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum HistoryRetention {
+	/// Download the data of every block.
+	Full,
+	/// Download the data of the blocks starting from the given number only.
+	FullFrom(u64),
+	/// Don't download the data of any block.
+	HeadersOnly,
+}
+
+impl HistoryRetention {
+	/// Returns if the data of block `number` is downloaded.
+	pub fn includes(&self, number: u64) -> bool {
+		match self {
+			Self::Full => true,
+			Self::FullFrom(from) => number >= *from,
+			Self::HeadersOnly => false,
+		}
+	}
+
+	/// Returns the number of the first block whose data is downloaded, if it isn't the first
+	/// block of the history.
+	pub fn boundary(&self) -> Option<u64> {
+		match self {
+			Self::FullFrom(from) => Some(*from),
+			Self::Full | Self::HeadersOnly => None,
+		}
+	}
+}
+
+impl FromStr for HistoryRetention {
+	type Err = String;
+
+	fn from_str(input: &str) -> Result<Self, Self::Err> {
+		match input {
+			"full" => Ok(Self::Full),
+			"headers-only" => Ok(Self::HeadersOnly),
+			number => number
+				.parse()
+				.map(Self::FullFrom)
+				.map_err(|_| format!("Invalid history retention specified: {input}")),
+		}
+	}
+}
+
+/// Configuration of the background download of the block history skipped by warp sync.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HistorySyncConfig {
+	/// Retention of the block bodies.
+	pub bodies: HistoryRetention,
+	/// Retention of the justifications.
+	pub justifications: HistoryRetention,
+	/// Maximum number of bytes of history downloaded per second. Unlimited if `None`.
+	pub max_bytes_per_second: Option<u64>,
+}
+
+impl Default for HistorySyncConfig {
+	fn default() -> Self {
+		Self {
+			bodies: HistoryRetention::Full,
+			justifications: HistoryRetention::Full,
+			max_bytes_per_second: None,
+		}
+	}
+}
+
 /// Network service configuration.
 #[derive(Clone, Debug)]
 pub struct NetworkConfiguration {
@@ -422,6 +490,8 @@ pub struct NetworkConfiguration {
 	pub max_parallel_downloads: u32,
 	/// Initial syncing mode.
 	pub sync_mode: SyncMode,
+	/// Download of the block history skipped by warp sync.
+	pub history_sync: HistorySyncConfig,
 
 	/// True if Kademlia random discovery should be enabled.
 	///
@@ -437,6 +507,17 @@ pub struct NetworkConfiguration {
 	/// Enable serving block data over IPFS bitswap.
 	pub ipfs_server: bool,
 
+	/// Persist the reputations and the known good addresses of peers in the node database, so
+	/// that they survive restarts.
+	pub persist_peers: bool,
+
+	/// Maximum number of peers whose reputation and addresses are persisted.
+	pub max_persisted_peers: u32,
+
+	/// Budget of the resources spent on serving the requests of each peer. Requests are served
+	/// without limit if `None`.
+	pub request_budget: Option<RequestBudgetConfig>,
+
 	/// Size of Yamux receive window of all substreams. `None` for the default (256kiB).
 	/// Any value less than 256kiB is invalid.
 	///
@@ -483,11 +564,15 @@ impl NetworkConfiguration {
 			transport: TransportConfig::Normal { enable_mdns: false, allow_private_ip: true },
 			max_parallel_downloads: 5,
 			sync_mode: SyncMode::Full,
+			history_sync: Default::default(),
 			enable_dht_random_walk: true,
 			allow_non_globals_in_dht: false,
 			kademlia_disjoint_query_paths: false,
 			yamux_window_size: None,
 			ipfs_server: false,
+			persist_peers: false,
+			max_persisted_peers: 1000,
+			request_budget: None,
 		}
 	}
 
@@ -115,6 +115,61 @@ pub struct OutgoingResponse {
 	/// >			when the response has been fully sent out, but rather when it has fully been
 	/// >			written to the buffer managed by the operating system.
 	pub sent_feedback: Option<oneshot::Sender<()>>,
+
+	/// Number of database reads performed to build the response.
+	///
+	/// Charged, along with the size of the response, against the request budget of the peer.
+	pub db_reads: u64,
+}
+
+/// Budget of the resources the local node spends on serving the requests of a single peer.
+///
+/// The budget is shared by all the request-response protocols. Every response is charged
+/// according to its size and the number of database reads needed to build it. The budget
+/// refills at a constant rate up to its capacity. Once it is exhausted, the requests of the peer
+/// are delayed until the budget is refilled, or refused if that would take too long. The requests
+/// of a peer which are being served or delayed at once are limited as well, which bounds how far
+/// the budget can be overdrawn.
+///
+/// The default budget lets a peer download several full-size block or state responses in a row
+/// before it is delayed.
+#[derive(Debug, Clone)]
+pub struct RequestBudgetConfig {
+	/// Maximal budget of a peer, in cost units.
+	pub capacity: u64,
+
+	/// Number of cost units refilled every second.
+	pub refill_per_second: u64,
+
+	/// Cost of every byte of response.
+	pub byte_cost: u64,
+
+	/// Cost of every database read.
+	///
+	/// Handlers reading a range of keys sequentially only report the seeks as reads, the keys
+	/// read are charged through the size of the response.
+	pub db_read_cost: u64,
+
+	/// Maximal number of requests of a peer which are being served or delayed at once. Further
+	/// requests are refused.
+	pub max_in_flight: usize,
+
+	/// Maximal duration a request is delayed for. Requests which would have to wait longer for
+	/// the budget to refill are refused.
+	pub max_delay: Duration,
+}
+
+impl Default for RequestBudgetConfig {
+	fn default() -> Self {
+		Self {
+			capacity: 64 * 1024 * 1024,
+			refill_per_second: 8 * 1024 * 1024,
+			byte_cost: 1,
+			db_read_cost: 4 * 1024,
+			max_in_flight: 8,
+			max_delay: Duration::from_secs(5),
+		}
+	}
 }
 
 /// When sending a request, what to do on a disconnected recipient.
@@ -85,6 +85,19 @@ pub struct StateDownloadProgress {
 	pub size: u64,
 }
 
+/// Reported progress of the download of the block history skipped by warp sync.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct HistoryDownloadProgress<BlockNumber> {
+	/// First block of the history which was missing when the download (re)started.
+	pub start: BlockNumber,
+	/// Highest block of the history queued for import.
+	pub best_queued: BlockNumber,
+	/// Last block of the history.
+	pub target: BlockNumber,
+	/// Number of bytes downloaded since the download (re)started.
+	pub downloaded_bytes: u64,
+}
+
 /// Syncing status and statistics.
 #[derive(Debug, Clone)]
 pub struct SyncStatus<Block: BlockT> {
@@ -100,6 +113,8 @@ pub struct SyncStatus<Block: BlockT> {
 	pub state_sync: Option<StateDownloadProgress>,
 	/// Warp sync in progress, if any.
 	pub warp_sync: Option<WarpSyncProgress<Block>>,
+	/// Block history download in progress, if any.
+	pub history_sync: Option<HistoryDownloadProgress<NumberFor<Block>>>,
 }
 
 /// A peer did not behave as expected and should be reported.
@@ -86,11 +86,12 @@ where
 			let IncomingRequest { peer, payload, pending_response } = request;
 
 			match self.handle_request(peer, payload) {
-				Ok(response_data) => {
+				Ok((response_data, db_reads)) => {
 					let response = OutgoingResponse {
 						result: Ok(response_data),
 						reputation_changes: Vec::new(),
 						sent_feedback: None,
+						db_reads,
 					};
 
 					match pending_response.send(response) {
@@ -124,6 +125,7 @@ where
 						result: Err(()),
 						reputation_changes,
 						sent_feedback: None,
+						db_reads: 0,
 					};
 
 					if pending_response.send(response).is_err() {
@@ -139,20 +141,22 @@ where
 		}
 	}
 
+	/// Handles a single request, returning the encoded response and the number of database
+	/// reads it took to build it.
 	fn handle_request(
 		&mut self,
 		peer: PeerId,
 		payload: Vec<u8>,
-	) -> Result<Vec<u8>, HandleRequestError> {
+	) -> Result<(Vec<u8>, u64), HandleRequestError> {
 		let request = schema::v1::light::Request::decode(&payload[..])?;
 
-		let response = match &request.request {
+		let (response, db_reads) = match &request.request {
 			Some(schema::v1::light::request::Request::RemoteCallRequest(r)) =>
-				self.on_remote_call_request(&peer, r)?,
+				(self.on_remote_call_request(&peer, r)?, 1),
 			Some(schema::v1::light::request::Request::RemoteReadRequest(r)) =>
-				self.on_remote_read_request(&peer, r)?,
+				(self.on_remote_read_request(&peer, r)?, r.keys.len() as u64),
 			Some(schema::v1::light::request::Request::RemoteReadChildRequest(r)) =>
-				self.on_remote_read_child_request(&peer, r)?,
+				(self.on_remote_read_child_request(&peer, r)?, r.keys.len() as u64),
 			None =>
 				return Err(HandleRequestError::BadRequest("Remote request without request data.")),
 		};
@@ -160,7 +164,7 @@ where
 		let mut data = Vec::new();
 		response.encode(&mut data)?;
 
-		Ok(data)
+		Ok((data, db_reads))
 	}
 
 	fn on_remote_call_request(
@@ -17,6 +17,7 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
+	config::PeerAuthorization,
 	discovery::{DiscoveryBehaviour, DiscoveryConfig, DiscoveryOut},
 	peer_info,
 	protocol::{CustomMessageOutcome, NotificationsSink, Protocol},
@@ -32,17 +33,22 @@ use libp2p::{
 	swarm::NetworkBehaviour,
 };
 
+use prometheus_endpoint::Registry;
 use sc_network_common::{
 	protocol::{
 		event::DhtEvent,
 		role::{ObservedRole, Roles},
 		ProtocolName,
 	},
-	request_responses::{IfDisconnected, ProtocolConfig, RequestFailure},
+	request_responses::{IfDisconnected, ProtocolConfig, RequestBudgetConfig, RequestFailure},
 };
 use sc_peerset::{PeersetHandle, ReputationChange};
 use sp_runtime::traits::Block as BlockT;
-use std::{collections::HashSet, time::Duration};
+use std::{
+	collections::{HashMap, HashSet},
+	sync::Arc,
+	time::Duration,
+};
 
 pub use crate::request_responses::{InboundFailure, OutboundFailure, RequestId, ResponseFailure};
 
@@ -177,6 +183,9 @@ impl<B: BlockT> Behaviour<B> {
 		disco_config: DiscoveryConfig,
 		request_response_protocols: Vec<ProtocolConfig>,
 		peerset: PeersetHandle,
+		peer_authorization: Option<Arc<dyn PeerAuthorization>>,
+		request_budget: Option<RequestBudgetConfig>,
+		metrics_registry: Option<&Registry>,
 	) -> Result<Self, request_responses::RegisterError> {
 		Ok(Self {
 			substrate,
@@ -185,6 +194,9 @@ impl<B: BlockT> Behaviour<B> {
 			request_responses: request_responses::RequestResponsesBehaviour::new(
 				request_response_protocols.into_iter(),
 				peerset,
+				peer_authorization,
+				request_budget,
+				metrics_registry,
 			)?,
 		})
 	}
@@ -194,11 +206,23 @@ impl<B: BlockT> Behaviour<B> {
 		self.discovery.known_peers()
 	}
 
+	/// Returns the addresses of the nodes in the Kademlia k-buckets, i.e. of nodes which we
+	/// managed to reach.
+	pub fn known_addresses(&mut self) -> HashMap<PeerId, Vec<Multiaddr>> {
+		self.discovery.known_addresses()
+	}
+
 	/// Adds a hard-coded address for the given peer, that never expires.
 	pub fn add_known_address(&mut self, peer_id: PeerId, addr: Multiaddr) {
 		self.discovery.add_known_address(peer_id, addr)
 	}
 
+	/// Adds an address for the given peer that was known before the last shutdown. It expires
+	/// like the addresses discovered through Kademlia.
+	pub fn add_persisted_address(&mut self, peer_id: PeerId, addr: Multiaddr) {
+		self.discovery.add_persisted_address(peer_id, addr)
+	}
+
 	/// Returns the number of nodes in each Kademlia kbucket.
 	///
 	/// Identifies kbuckets by the base 2 logarithm of their lower bound.
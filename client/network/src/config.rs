@@ -33,7 +33,9 @@ pub use sc_network_common::{
 
 pub use libp2p::{build_multiaddr, core::PublicKey, identity};
 
+use libp2p::PeerId;
 use prometheus_endpoint::Registry;
+use sc_client_api::AuxStore;
 use sc_network_common::config::NonDefaultSetConfig;
 use std::{future::Future, pin::Pin, sync::Arc};
 
@@ -66,4 +68,47 @@ pub struct Params<Client> {
 
 	/// Request response protocol configurations
 	pub request_response_protocol_configs: Vec<RequestResponseConfig>,
+
+	/// Storage for the peer reputations and addresses, which are persisted across restarts if
+	/// [`NetworkConfiguration::persist_peers`] is set.
+	pub peer_state_store: Option<Arc<dyn PeerStateStore>>,
+
+	/// Decides which peers are allowed to connect to us. All peers are allowed if `None`.
+	pub peer_authorization: Option<Arc<dyn PeerAuthorization>>,
+}
+
+/// Storage for the state of the peers which is persisted across restarts.
+///
+/// Implemented for every [`AuxStore`], such as the client.
+pub trait PeerStateStore: Send + Sync {
+	/// Load the state stored last, if any.
+	fn load(&self) -> Option<Vec<u8>>;
+
+	/// Store `state`, replacing the state stored before.
+	fn store(&self, state: &[u8]) -> Result<(), String>;
+}
+
+/// The key under which [`PeerStateStore`] keeps the state in an [`AuxStore`].
+const PEER_STATE_KEY: &[u8] = b"network_peer_state";
+
+impl<T: AuxStore + Send + Sync> PeerStateStore for T {
+	fn load(&self) -> Option<Vec<u8>> {
+		self.get_aux(PEER_STATE_KEY).ok().flatten()
+	}
+
+	fn store(&self, state: &[u8]) -> Result<(), String> {
+		self.insert_aux(&[(PEER_STATE_KEY, state)], &[]).map_err(|e| e.to_string())
+	}
+}
+
+/// Decides which peers are allowed to connect to the local node, e.g. based on an on-chain
+/// allowlist.
+///
+/// It is consulted when a peer opens a notifications substream and before an inbound request of
+/// the peer is served. Connected peers which are no longer authorized are disconnected.
+pub trait PeerAuthorization: Send + Sync {
+	/// Returns whether `peer_id` is authorized to connect to the local node.
+	///
+	/// Called frequently from the networking task, so it must not block.
+	fn is_authorized(&self, peer_id: &PeerId) -> bool;
 }
@@ -311,6 +311,24 @@ impl DiscoveryBehaviour {
 		peers
 	}
 
+	/// Returns the addresses of the nodes in the Kademlia k-buckets.
+	///
+	/// Nodes only enter the k-buckets once we managed to reach them, so these are addresses which
+	/// are known to have worked.
+	pub fn known_addresses(&mut self) -> HashMap<PeerId, Vec<Multiaddr>> {
+		let mut addresses = HashMap::new();
+		if let Some(k) = self.kademlia.as_mut() {
+			for b in k.kbuckets() {
+				for e in b.iter() {
+					addresses
+						.entry(*e.node.key.preimage())
+						.or_insert_with(|| e.node.value.iter().cloned().collect());
+				}
+			}
+		}
+		addresses
+	}
+
 	/// Adds a hard-coded address for the given peer, that never expires.
 	///
 	/// This adds an entry to the parameter that was passed to `new`.
@@ -330,6 +348,19 @@ impl DiscoveryBehaviour {
 		addrs_list.push(addr);
 	}
 
+	/// Adds an address of the given peer that was known before the last shutdown.
+	///
+	/// Unlike [`DiscoveryBehaviour::add_known_address`], the address is only added to the
+	/// Kademlia k-buckets, which evict it once the peer stops responding.
+	///
+	/// Generates a `Discovered` event if Kademlia is enabled.
+	pub fn add_persisted_address(&mut self, peer_id: PeerId, addr: Multiaddr) {
+		if let Some(k) = self.kademlia.as_mut() {
+			k.add_address(&peer_id, addr);
+			self.pending_events.push_back(DiscoveryOut::Discovered(peer_id));
+		}
+	}
+
 	/// Add a self-reported address of a remote peer to the k-buckets of the DHT
 	/// if it has compatible `supported_protocols`.
 	///
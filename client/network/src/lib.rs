@@ -247,6 +247,7 @@
 mod behaviour;
 mod discovery;
 mod peer_info;
+mod peer_state;
 mod protocol;
 mod request_responses;
 mod service;
@@ -0,0 +1,278 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Persistence of the state of the peers across restarts.
+//!
+//! The [`PeerStatePersistence`] periodically writes the reputations known to the peerset, which
+//! includes the banned peers, and the addresses of the nodes in the Kademlia k-buckets to a
+//! [`PeerStateStore`]. At startup, this state is loaded again, so that a restarted node neither
+//! forgets which peers misbehaved nor has to rediscover the network from the bootnodes only.
+//!
+//! At most [`NetworkConfiguration::max_persisted_peers`] peers are persisted, preferring those
+//! with the highest absolute reputation.
+//!
+//! [`NetworkConfiguration::max_persisted_peers`]: crate::config::NetworkConfiguration
+
+use crate::config::PeerStateStore;
+
+use codec::{Decode, Encode};
+use libp2p::{Multiaddr, PeerId};
+use log::{debug, warn};
+use std::{
+	cmp::Reverse,
+	collections::HashMap,
+	sync::Arc,
+	time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+/// Interval at which the state of the peers is persisted.
+const PERSIST_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Maximum number of addresses persisted per peer.
+const MAX_ADDRESSES_PER_PEER: usize = 4;
+
+/// Persisted state of a single peer.
+#[derive(Debug, Encode, Decode)]
+struct PersistedPeer {
+	/// The encoded [`PeerId`].
+	peer_id: Vec<u8>,
+	/// The reputation of the peer in the peerset.
+	reputation: i32,
+	/// The encoded known good addresses of the peer.
+	addresses: Vec<Vec<u8>>,
+}
+
+/// Persisted state of all the peers.
+#[derive(Debug, Encode, Decode)]
+struct PersistedPeerState {
+	/// Seconds since the Unix epoch at which the state was persisted.
+	persisted_at: u64,
+	/// The persisted peers.
+	peers: Vec<PersistedPeer>,
+}
+
+/// State of the peers loaded at startup.
+#[derive(Debug, Default, PartialEq)]
+pub struct LoadedPeerState {
+	/// The reputations of the peers, as they were persisted.
+	pub reputations: Vec<(PeerId, i32)>,
+	/// The known good addresses of the peers.
+	pub addresses: Vec<(PeerId, Multiaddr)>,
+	/// How long ago the state was persisted.
+	pub elapsed: Duration,
+}
+
+/// Periodically persists the state of the peers to a [`PeerStateStore`].
+pub struct PeerStatePersistence {
+	/// Where the state is persisted.
+	store: Arc<dyn PeerStateStore>,
+	/// Maximum number of persisted peers.
+	max_peers: usize,
+	/// When the state is persisted next.
+	next_persist: Instant,
+}
+
+impl PeerStatePersistence {
+	/// Creates a new persistence which keeps at most `max_peers` peers in `store`.
+	pub fn new(store: Arc<dyn PeerStateStore>, max_peers: u32) -> Self {
+		Self {
+			store,
+			max_peers: max_peers as usize,
+			next_persist: Instant::now() + PERSIST_INTERVAL,
+		}
+	}
+
+	/// Loads the state of the peers persisted last.
+	///
+	/// Returns an empty state if nothing was persisted or the persisted state is invalid.
+	pub fn load(&self) -> LoadedPeerState {
+		let Some(encoded) = self.store.load() else { return LoadedPeerState::default() };
+		let state = match PersistedPeerState::decode(&mut &encoded[..]) {
+			Ok(state) => state,
+			Err(e) => {
+				warn!(target: "sub-libp2p", "Ignoring invalid persisted peer state: {}", e);
+				return LoadedPeerState::default()
+			},
+		};
+
+		let mut loaded = LoadedPeerState {
+			elapsed: Duration::from_secs(unix_time().saturating_sub(state.persisted_at)),
+			..Default::default()
+		};
+		for peer in state.peers {
+			let Ok(peer_id) = PeerId::from_bytes(&peer.peer_id) else { continue };
+			if peer.reputation != 0 {
+				loaded.reputations.push((peer_id, peer.reputation));
+			}
+			loaded.addresses.extend(
+				peer.addresses
+					.into_iter()
+					.filter_map(|address| Multiaddr::try_from(address).ok())
+					.map(|address| (peer_id, address)),
+			);
+		}
+		debug!(
+			target: "sub-libp2p",
+			"Loaded {} persisted peer reputations and {} addresses, persisted {:?} ago",
+			loaded.reputations.len(),
+			loaded.addresses.len(),
+			loaded.elapsed,
+		);
+
+		loaded
+	}
+
+	/// Whether the state should be persisted again.
+	pub fn is_due(&self) -> bool {
+		Instant::now() >= self.next_persist
+	}
+
+	/// Persists the `reputations` and `addresses` of the peers.
+	///
+	/// The addresses of banned peers are not persisted.
+	pub fn persist(
+		&mut self,
+		reputations: Vec<(PeerId, i32)>,
+		mut addresses: HashMap<PeerId, Vec<Multiaddr>>,
+	) {
+		self.next_persist = Instant::now() + PERSIST_INTERVAL;
+
+		let mut peers = reputations
+			.into_iter()
+			.map(|(peer_id, reputation)| {
+				let addresses = addresses.remove(&peer_id).unwrap_or_default();
+				(peer_id, reputation, addresses)
+			})
+			.collect::<Vec<_>>();
+		peers.extend(addresses.into_iter().map(|(peer_id, addresses)| (peer_id, 0, addresses)));
+		peers.sort_by_key(|(_, reputation, addresses)| {
+			(Reverse(reputation.unsigned_abs()), addresses.is_empty())
+		});
+		peers.truncate(self.max_peers);
+
+		let state = PersistedPeerState {
+			persisted_at: unix_time(),
+			peers: peers
+				.into_iter()
+				.map(|(peer_id, reputation, addresses)| PersistedPeer {
+					peer_id: peer_id.to_bytes(),
+					reputation,
+					addresses: if reputation < sc_peerset::BANNED_THRESHOLD {
+						Vec::new()
+					} else {
+						addresses
+							.into_iter()
+							.take(MAX_ADDRESSES_PER_PEER)
+							.map(|address| address.to_vec())
+							.collect()
+					},
+				})
+				.collect(),
+		};
+		if let Err(e) = self.store.store(&state.encode()) {
+			warn!(target: "sub-libp2p", "Failed to persist the peer state: {}", e);
+		}
+	}
+}
+
+/// Seconds since the Unix epoch.
+fn unix_time() -> u64 {
+	SystemTime::now()
+		.duration_since(UNIX_EPOCH)
+		.map(|d| d.as_secs())
+		.unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use parking_lot::Mutex;
+
+	#[derive(Default)]
+	struct MemoryStore(Mutex<Option<Vec<u8>>>);
+
+	impl PeerStateStore for MemoryStore {
+		fn load(&self) -> Option<Vec<u8>> {
+			self.0.lock().clone()
+		}
+
+		fn store(&self, state: &[u8]) -> Result<(), String> {
+			*self.0.lock() = Some(state.to_vec());
+			Ok(())
+		}
+	}
+
+	fn address(port: u16) -> Multiaddr {
+		format!("/ip4/127.0.0.1/tcp/{}", port).parse().unwrap()
+	}
+
+	#[test]
+	fn persisted_state_is_loaded() {
+		let store = Arc::new(MemoryStore::default());
+		let mut persistence = PeerStatePersistence::new(store.clone(), 10);
+		assert_eq!(persistence.load(), LoadedPeerState::default());
+
+		let (good, banned, known) = (PeerId::random(), PeerId::random(), PeerId::random());
+		persistence.persist(
+			vec![(good, 100), (banned, i32::MIN)],
+			[
+				(good, vec![address(1)]),
+				(banned, vec![address(2)]),
+				(known, (3..10).map(address).collect()),
+			]
+			.into_iter()
+			.collect(),
+		);
+		assert!(!persistence.is_due());
+
+		let loaded = PeerStatePersistence::new(store, 10).load();
+		assert_eq!(loaded.reputations, vec![(banned, i32::MIN), (good, 100)]);
+		assert_eq!(
+			loaded.addresses,
+			[(good, address(1))]
+				.into_iter()
+				.chain((3..7).map(|port| (known, address(port))))
+				.collect::<Vec<_>>()
+		);
+		assert!(loaded.elapsed < Duration::from_secs(5));
+	}
+
+	#[test]
+	fn persisted_peers_are_limited() {
+		let store = Arc::new(MemoryStore::default());
+		let mut persistence = PeerStatePersistence::new(store.clone(), 2);
+		let peers = (0..4).map(|_| PeerId::random()).collect::<Vec<_>>();
+
+		persistence.persist(
+			vec![(peers[0], 1), (peers[1], -50), (peers[2], 20)],
+			[(peers[3], vec![address(1)])].into_iter().collect(),
+		);
+
+		let loaded = persistence.load();
+		assert_eq!(loaded.reputations, vec![(peers[1], -50), (peers[2], 20)]);
+		assert!(loaded.addresses.is_empty());
+	}
+
+	#[test]
+	fn invalid_persisted_state_is_ignored() {
+		let store = Arc::new(MemoryStore::default());
+		store.store(&[1, 2, 3]).unwrap();
+
+		assert_eq!(PeerStatePersistence::new(store, 10).load(), LoadedPeerState::default());
+	}
+}
@@ -16,7 +16,7 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::config;
+use crate::config::{self, PeerAuthorization};
 
 use bytes::Bytes;
 use codec::{DecodeAll, Encode};
@@ -41,7 +41,9 @@ use sp_runtime::traits::Block as BlockT;
 use std::{
 	collections::{HashMap, HashSet, VecDeque},
 	iter,
+	sync::Arc,
 	task::Poll,
+	time::Duration,
 };
 
 mod notifications;
@@ -64,6 +66,8 @@ mod rep {
 	use sc_peerset::ReputationChange as Rep;
 	/// We received a message that failed to decode.
 	pub const BAD_MESSAGE: Rep = Rep::new(-(1 << 12), "Bad message");
+	/// The peer is not authorized to connect to us.
+	pub const UNAUTHORIZED: Rep = Rep::new(-(1 << 10), "Unauthorized peer");
 }
 
 // Lock must always be taken in order declared here.
@@ -85,6 +89,8 @@ pub struct Protocol<B: BlockT> {
 	bad_handshake_substreams: HashSet<(PeerId, sc_peerset::SetId)>,
 	/// Connected peers.
 	peers: HashMap<PeerId, Roles>,
+	/// Decides which peers are allowed to open substreams with us.
+	peer_authorization: Option<Arc<dyn PeerAuthorization>>,
 	_marker: std::marker::PhantomData<B>,
 }
 
@@ -94,6 +100,7 @@ impl<B: BlockT> Protocol<B> {
 		roles: Roles,
 		network_config: &config::NetworkConfiguration,
 		block_announces_protocol: sc_network_common::config::NonDefaultSetConfig,
+		peer_authorization: Option<Arc<dyn PeerAuthorization>>,
 	) -> error::Result<(Self, sc_peerset::PeersetHandle, Vec<(PeerId, Multiaddr)>)> {
 		let mut known_addresses = Vec::new();
 
@@ -177,6 +184,7 @@ impl<B: BlockT> Protocol<B> {
 				.collect(),
 			bad_handshake_substreams: Default::default(),
 			peers: HashMap::new(),
+			peer_authorization,
 			// TODO: remove when `BlockAnnouncesHandshake` is moved away from `Protocol`
 			_marker: Default::default(),
 		};
@@ -210,6 +218,27 @@ impl<B: BlockT> Protocol<B> {
 		self.behaviour.peerset_debug_info()
 	}
 
+	/// Returns the reputation of every node known to the peerset whose reputation isn't zero.
+	pub fn peerset_reputations(&mut self) -> Vec<(PeerId, i32)> {
+		self.behaviour.peerset_reputations()
+	}
+
+	/// Restores reputations of nodes in the peerset, e.g. persisted before a restart.
+	pub fn restore_peerset_reputations(
+		&mut self,
+		reputations: impl IntoIterator<Item = (PeerId, i32)>,
+		elapsed: Duration,
+	) {
+		self.behaviour.restore_peerset_reputations(reputations, elapsed)
+	}
+
+	/// Returns whether `peer_id` is allowed to connect to us.
+	pub fn is_peer_authorized(&self, peer_id: &PeerId) -> bool {
+		self.peer_authorization
+			.as_ref()
+			.map_or(true, |auth| auth.is_authorized(peer_id))
+	}
+
 	/// Returns the number of peers we're connected to.
 	pub fn num_connected_peers(&self) -> usize {
 		self.peers.len()
@@ -424,8 +453,18 @@ impl<B: BlockT> NetworkBehaviour for Protocol<B> {
 				notifications_sink,
 				negotiated_fallback,
 			} => {
-				// Set number 0 is hardcoded the default set of peers we sync from.
-				if set_id == HARDCODED_PEERSETS_SYNC {
+				if !self.is_peer_authorized(&peer_id) {
+					debug!(
+						target: "sub-libp2p",
+						"Refusing substream with unauthorized peer {}",
+						peer_id,
+					);
+					self.bad_handshake_substreams.insert((peer_id, set_id));
+					self.behaviour.disconnect_peer(&peer_id, set_id);
+					self.peerset_handle.report_peer(peer_id, rep::UNAUTHORIZED);
+					CustomMessageOutcome::None
+				} else if set_id == HARDCODED_PEERSETS_SYNC {
+					// Set number 0 is hardcoded the default set of peers we sync from.
 					// `received_handshake` can be either a `Status` message if received from the
 					// legacy substream ,or a `BlockAnnouncesHandshake` if received from the block
 					// announces substream.
@@ -543,6 +543,21 @@ impl Notifications {
 		self.peerset.debug_info()
 	}
 
+	/// Returns the reputation of every node known to the peerset whose reputation isn't zero.
+	pub fn peerset_reputations(&mut self) -> Vec<(PeerId, i32)> {
+		self.peerset.reputations()
+	}
+
+	/// Restores reputations of nodes in the peerset, see
+	/// [`sc_peerset::Peerset::restore_reputations`].
+	pub fn restore_peerset_reputations(
+		&mut self,
+		reputations: impl IntoIterator<Item = (PeerId, i32)>,
+		elapsed: Duration,
+	) {
+		self.peerset.restore_reputations(reputations, elapsed)
+	}
+
 	/// Function that is called when the peerset wants us to connect to a peer.
 	fn peerset_report_connect(&mut self, peer_id: PeerId, set_id: sc_peerset::SetId) {
 		// If `PeerId` is unknown to us, insert an entry, start dialing, and return early.
@@ -34,11 +34,12 @@
 //! - If provided, a ["requests processing"](ProtocolConfig::inbound_queue) channel
 //! is used to handle incoming requests.
 
-use crate::ReputationChange;
+use crate::{config::PeerAuthorization, ReputationChange};
 use futures::{
 	channel::{mpsc, oneshot},
 	prelude::*,
 };
+use futures_timer::Delay;
 use libp2p::{
 	core::{connection::ConnectionId, Multiaddr, PeerId},
 	request_response::{
@@ -52,16 +53,19 @@ use libp2p::{
 		PollParameters,
 	},
 };
+use prometheus_endpoint::{register, CounterVec, Opts, PrometheusError, Registry, U64};
 use sc_network_common::{
 	protocol::ProtocolName,
 	request_responses::{
-		IfDisconnected, IncomingRequest, OutgoingResponse, ProtocolConfig, RequestFailure,
+		IfDisconnected, IncomingRequest, OutgoingResponse, ProtocolConfig, RequestBudgetConfig,
+		RequestFailure,
 	},
 };
 use std::{
-	collections::{hash_map::Entry, HashMap},
+	collections::{hash_map::Entry, HashMap, HashSet},
 	io, iter,
 	pin::Pin,
+	sync::Arc,
 	task::{Context, Poll},
 	time::{Duration, Instant},
 };
@@ -69,6 +73,13 @@ use std::{
 pub use libp2p::request_response::{InboundFailure, OutboundFailure, RequestId};
 use sc_peerset::{PeersetHandle, BANNED_THRESHOLD};
 
+mod rep {
+	use sc_peerset::ReputationChange as Rep;
+
+	/// Reputation change when a peer sends a request while its request budget is exhausted.
+	pub const REQUEST_BUDGET_EXHAUSTED: Rep = Rep::new(-(1 << 10), "Request budget exhausted");
+}
+
 /// Event generated by the [`RequestResponsesBehaviour`].
 #[derive(Debug)]
 pub enum Event {
@@ -141,7 +152,7 @@ pub struct RequestResponsesBehaviour {
 	/// Whenever an incoming request arrives, a `Future` is added to this list and will yield the
 	/// start time and the response to send back to the remote.
 	pending_responses: stream::FuturesUnordered<
-		Pin<Box<dyn Future<Output = Option<RequestProcessingOutcome>> + Send>>,
+		Pin<Box<dyn Future<Output = (PeerId, Option<RequestProcessingOutcome>)> + Send>>,
 	>,
 
 	/// Whenever an incoming request arrives, the arrival [`Instant`] is recorded here.
@@ -154,6 +165,23 @@ pub struct RequestResponsesBehaviour {
 	/// Primarily used to get a reputation of a node.
 	peerset: PeersetHandle,
 
+	/// Decides which peers we serve requests of. Requests of all peers are served if `None`.
+	peer_authorization: Option<Arc<dyn PeerAuthorization>>,
+
+	/// Budgets of the resources spent on serving the requests of each peer, if limited.
+	budgets: Option<RequestBudgets>,
+
+	/// Inbound requests refused because the budget of the peer was exhausted. Used to report
+	/// the failure which follows the refusal.
+	budget_refused: HashSet<ProtocolRequestId>,
+
+	/// Inbound requests waiting for the budget of their peer to be refilled.
+	delayed_requests:
+		stream::FuturesUnordered<Pin<Box<dyn Future<Output = DelayedRequest> + Send>>>,
+
+	/// Prometheus metrics of the request budgets.
+	budget_metrics: Option<RequestBudgetMetrics>,
+
 	/// Pending message request, holds `MessageRequest` as a Future state to poll it
 	/// until we get a response from `Peerset`
 	message_request: Option<MessageRequest>,
@@ -173,6 +201,18 @@ struct MessageRequest {
 	get_peer_reputation: Pin<Box<dyn Future<Output = Result<i32, ()>> + Send>>,
 }
 
+/// An inbound request waiting for the budget of the peer to be refilled.
+struct DelayedRequest {
+	peer: PeerId,
+	request_id: RequestId,
+	request: Vec<u8>,
+	channel: ResponseChannel<Result<Vec<u8>, ()>>,
+	protocol: ProtocolName,
+	resp_builder: Option<futures::channel::mpsc::Sender<IncomingRequest>>,
+	/// The request is refused if it can't be served by then.
+	deadline: Instant,
+}
+
 /// Generated by the response builder and waiting to be processed.
 struct RequestProcessingOutcome {
 	peer: PeerId,
@@ -182,12 +222,198 @@ struct RequestProcessingOutcome {
 	response: OutgoingResponse,
 }
 
+/// How an inbound request is handled according to the budget of the peer.
+#[derive(Debug, PartialEq, Eq)]
+enum Admission {
+	/// The request is served right away.
+	Serve,
+	/// The request is admitted again once the budget has been refilled.
+	Delay(Duration),
+	/// The request is refused.
+	Refuse,
+	/// The request is refused because too many requests of the peer are already in flight.
+	Busy,
+}
+
+/// Request budget of a single peer.
+struct PeerBudget {
+	/// Cost units left, negative if the budget is overdrawn.
+	balance: i64,
+	/// When `balance` was last refilled.
+	refilled_at: Instant,
+	/// Number of admitted requests which haven't been charged yet.
+	in_flight: usize,
+}
+
+/// Request budgets of all the peers, shared by all the protocols.
+///
+/// Requests are admitted as long as the budget of the peer isn't exhausted, and the responses
+/// are charged once they have been built, possibly overdrawing the budget. The number of requests
+/// of a peer in flight at once is capped, which bounds how far the budget can be overdrawn.
+struct RequestBudgets {
+	config: RequestBudgetConfig,
+	peers: HashMap<PeerId, PeerBudget>,
+}
+
+impl RequestBudgets {
+	fn new(config: RequestBudgetConfig) -> Self {
+		Self { config, peers: HashMap::new() }
+	}
+
+	fn capacity(&self) -> i64 {
+		self.config.capacity.try_into().unwrap_or(i64::MAX)
+	}
+
+	/// Refill the budget of `peer` at `now`.
+	fn refill(&mut self, peer: &PeerId, now: Instant) -> &mut PeerBudget {
+		let capacity = self.capacity();
+		let refill_per_second = self.config.refill_per_second as u128;
+		let refill = |budget: &mut PeerBudget| {
+			if budget.balance >= capacity {
+				budget.refilled_at = now;
+				return
+			}
+			let elapsed = now.saturating_duration_since(budget.refilled_at);
+			let refill = elapsed.as_micros().saturating_mul(refill_per_second) / 1_000_000;
+			if refill > 0 {
+				let balance = (budget.balance as i128).saturating_add(refill as i128);
+				budget.balance = balance.min(capacity as i128) as i64;
+				budget.refilled_at = now;
+			}
+		};
+
+		if !self.peers.contains_key(peer) {
+			// Peers whose budget is full again aren't any different from unknown ones.
+			self.peers.retain(|_, budget| {
+				refill(budget);
+				budget.balance < capacity || budget.in_flight > 0
+			});
+		}
+		let budget = self.peers.entry(*peer).or_insert(PeerBudget {
+			balance: capacity,
+			refilled_at: now,
+			in_flight: 0,
+		});
+		refill(budget);
+		budget
+	}
+
+	/// Decide how to handle a new request of `peer` received at `now`.
+	///
+	/// Unless it is refused, the request is in flight until it is charged or released.
+	fn admit(&mut self, peer: &PeerId, now: Instant) -> Admission {
+		let max_in_flight = self.config.max_in_flight;
+		let max_delay = self.config.max_delay;
+		if self.refill(peer, now).in_flight >= max_in_flight {
+			return Admission::Busy
+		}
+		let admission = self.wait(peer, now, max_delay);
+		if admission != Admission::Refuse {
+			self.refill(peer, now).in_flight += 1;
+		}
+		admission
+	}
+
+	/// Decide again how to handle a delayed request of `peer` at `now`. The request is refused
+	/// if it can't be served by `deadline`.
+	fn readmit(&mut self, peer: &PeerId, now: Instant, deadline: Instant) -> Admission {
+		let admission = self.wait(peer, now, deadline.saturating_duration_since(now));
+		if admission == Admission::Refuse {
+			self.release(peer, now);
+		}
+		admission
+	}
+
+	/// How long a request of `peer` has to wait at `now` for the budget to be positive again,
+	/// refusing it if that is longer than `max_delay`.
+	fn wait(&mut self, peer: &PeerId, now: Instant, max_delay: Duration) -> Admission {
+		let refill_per_second = self.config.refill_per_second as u128;
+		let budget = self.refill(peer, now);
+		if budget.balance > 0 {
+			return Admission::Serve
+		}
+		if refill_per_second == 0 {
+			return Admission::Refuse
+		}
+		// Wait until the balance is positive again.
+		let deficit = (1 - budget.balance as i128) as u128;
+		let delay = deficit.saturating_mul(1_000_000) / refill_per_second;
+		let delay = Duration::from_micros(delay.try_into().unwrap_or(u64::MAX));
+		if delay <= max_delay {
+			Admission::Delay(delay)
+		} else {
+			Admission::Refuse
+		}
+	}
+
+	/// Charge `peer` for a response of `bytes` bytes which needed `db_reads` database reads.
+	///
+	/// Returns the cost of the response.
+	fn charge(&mut self, peer: &PeerId, bytes: u64, db_reads: u64, now: Instant) -> u64 {
+		let cost = bytes
+			.saturating_mul(self.config.byte_cost)
+			.saturating_add(db_reads.saturating_mul(self.config.db_read_cost));
+		let budget = self.refill(peer, now);
+		budget.balance = budget.balance.saturating_sub(cost.try_into().unwrap_or(i64::MAX));
+		budget.in_flight = budget.in_flight.saturating_sub(1);
+		cost
+	}
+
+	/// Release a request of `peer` which was admitted but never answered.
+	fn release(&mut self, peer: &PeerId, now: Instant) {
+		let budget = self.refill(peer, now);
+		budget.in_flight = budget.in_flight.saturating_sub(1);
+	}
+}
+
+/// Prometheus metrics of the request budgets.
+///
+/// Refused requests are reported as failed incoming requests.
+struct RequestBudgetMetrics {
+	cost: CounterVec<U64>,
+	delayed: CounterVec<U64>,
+}
+
+impl RequestBudgetMetrics {
+	fn register(registry: &Registry) -> Result<Self, PrometheusError> {
+		Ok(Self {
+			cost: register(
+				CounterVec::new(
+					Opts::new(
+						"substrate_sub_libp2p_requests_in_cost_total",
+						"Total cost of the incoming requests charged against the request \
+						 budgets of the peers",
+					),
+					&["protocol"],
+				)?,
+				registry,
+			)?,
+			delayed: register(
+				CounterVec::new(
+					Opts::new(
+						"substrate_sub_libp2p_requests_in_delayed_total",
+						"Total number of incoming requests delayed because the request budget of \
+						 the peer was exhausted",
+					),
+					&["protocol"],
+				)?,
+				registry,
+			)?,
+		})
+	}
+}
+
 impl RequestResponsesBehaviour {
 	/// Creates a new behaviour. Must be passed a list of supported protocols. Returns an error if
 	/// the same protocol is passed twice.
+	///
+	/// The requests of every peer are limited by `request_budget`, if any.
 	pub fn new(
 		list: impl Iterator<Item = ProtocolConfig>,
 		peerset: PeersetHandle,
+		peer_authorization: Option<Arc<dyn PeerAuthorization>>,
+		request_budget: Option<RequestBudgetConfig>,
+		metrics_registry: Option<&Registry>,
 	) -> Result<Self, RegisterError> {
 		let mut protocols = HashMap::new();
 		for protocol in list {
@@ -225,6 +451,23 @@ impl RequestResponsesBehaviour {
 			pending_responses_arrival_time: Default::default(),
 			send_feedback: Default::default(),
 			peerset,
+			peer_authorization,
+			budget_metrics: match (&request_budget, metrics_registry) {
+				(Some(_), Some(registry)) => match RequestBudgetMetrics::register(registry) {
+					Ok(metrics) => Some(metrics),
+					Err(err) => {
+						log::error!(
+							target: "sub-libp2p",
+							"Failed to register request budget metrics: {err:?}",
+						);
+						None
+					},
+				},
+				_ => None,
+			},
+			budgets: request_budget.map(RequestBudgets::new),
+			budget_refused: Default::default(),
+			delayed_requests: Default::default(),
 			message_request: None,
 		})
 	}
@@ -289,6 +532,72 @@ impl RequestResponsesBehaviour {
 			 which is the only possible error; qed",
 		)
 	}
+
+	/// Submit an inbound request to the "response builder" passed by the user at initialization,
+	/// and wait for its response in `pending_responses`.
+	fn serve_request(
+		&mut self,
+		peer: PeerId,
+		request_id: RequestId,
+		request: Vec<u8>,
+		channel: ResponseChannel<Result<Vec<u8>, ()>>,
+		protocol: ProtocolName,
+		resp_builder: Option<futures::channel::mpsc::Sender<IncomingRequest>>,
+	) {
+		let (tx, rx) = oneshot::channel();
+
+		if let Some(mut resp_builder) = resp_builder {
+			// If the response builder is too busy, silently drop `tx`. This
+			// will be reported by the corresponding `RequestResponse` through
+			// an `InboundFailure::Omission` event.
+			let _ = resp_builder.try_send(IncomingRequest {
+				peer,
+				payload: request,
+				pending_response: tx,
+			});
+		} else {
+			debug_assert!(false, "Received message on outbound-only protocol.");
+		}
+
+		self.pending_responses.push(Box::pin(async move {
+			// The `tx` created above can be dropped if we are not capable of
+			// processing this request, which is reflected as a
+			// `InboundFailure::Omission` event.
+			if let Ok(response) = rx.await {
+				(
+					peer,
+					Some(RequestProcessingOutcome {
+						peer,
+						request_id,
+						protocol,
+						inner_channel: channel,
+						response,
+					}),
+				)
+			} else {
+				(peer, None)
+			}
+		}));
+	}
+
+	/// Refuse an inbound request of `peer` whose request budget is exhausted.
+	///
+	/// The response channel of the request must be dropped. This leads to an
+	/// `InboundFailure::Omission` event, which is reported as a refusal.
+	fn refuse_request(
+		&mut self,
+		peer: PeerId,
+		protocol: ProtocolName,
+		request_id: RequestId,
+	) -> Event {
+		log::debug!(
+			target: "sub-libp2p",
+			"Refusing request of {}, its request budget is exhausted",
+			peer,
+		);
+		self.budget_refused.insert((protocol, request_id).into());
+		Event::ReputationChanges { peer, changes: vec![rep::REQUEST_BUDGET_EXHAUSTED] }
+	}
 }
 
 impl NetworkBehaviour for RequestResponsesBehaviour {
@@ -481,46 +790,130 @@ impl NetworkBehaviour for RequestResponsesBehaviour {
 							continue 'poll_all
 						}
 
-						let (tx, rx) = oneshot::channel();
-
-						// Submit the request to the "response builder" passed by the user at
-						// initialization.
-						if let Some(mut resp_builder) = resp_builder {
-							// If the response builder is too busy, silently drop `tx`. This
-							// will be reported by the corresponding `RequestResponse` through
-							// an `InboundFailure::Omission` event.
-							let _ = resp_builder.try_send(IncomingRequest {
+						if !self
+							.peer_authorization
+							.as_ref()
+							.map_or(true, |auth| auth.is_authorized(&peer))
+						{
+							log::debug!(
+								target: "sub-libp2p",
+								"Cannot handle requests from an unauthorized node {}",
 								peer,
-								payload: request,
-								pending_response: tx,
-							});
-						} else {
-							debug_assert!(false, "Received message on outbound-only protocol.");
+							);
+							continue 'poll_all
 						}
 
-						self.pending_responses.push(Box::pin(async move {
-							// The `tx` created above can be dropped if we are not capable of
-							// processing this request, which is reflected as a
-							// `InboundFailure::Omission` event.
-							if let Ok(response) = rx.await {
-								Some(RequestProcessingOutcome {
+						let now = Instant::now();
+						let admission = self
+							.budgets
+							.as_mut()
+							.map_or(Admission::Serve, |budgets| budgets.admit(&peer, now));
+						match admission {
+							Admission::Serve => self.serve_request(
+								peer,
+								request_id,
+								request,
+								channel,
+								protocol,
+								resp_builder,
+							),
+							Admission::Delay(delay) => {
+								log::debug!(
+									target: "sub-libp2p",
+									"Delaying request of {} by {:?}, its request budget is exhausted",
+									peer,
+									delay,
+								);
+								if let Some(metrics) = &self.budget_metrics {
+									metrics.delayed.with_label_values(&[&protocol]).inc();
+								}
+								let max_delay = self
+									.budgets
+									.as_ref()
+									.map_or(Duration::ZERO, |budgets| budgets.config.max_delay);
+								let request = DelayedRequest {
 									peer,
 									request_id,
+									request,
+									channel,
 									protocol,
-									inner_channel: channel,
-									response,
-								})
-							} else {
-								None
-							}
-						}));
+									resp_builder,
+									deadline: now + max_delay,
+								};
+								self.delayed_requests.push(Box::pin(async move {
+									Delay::new(delay).await;
+									request
+								}));
+							},
+							Admission::Refuse =>
+								return Poll::Ready(NetworkBehaviourAction::GenerateEvent(
+									self.refuse_request(peer, protocol, request_id),
+								)),
+							Admission::Busy => {
+								log::debug!(
+									target: "sub-libp2p",
+									"Refusing request of {}, too many of its requests are in flight",
+									peer,
+								);
+								// Dropping `channel` leads to an `InboundFailure::Omission` event,
+								// which is reported as a refusal.
+								self.budget_refused.insert((protocol, request_id).into());
+							},
+						}
 
-						// This `continue` makes sure that `pending_responses` gets polled
-						// after we have added the new element.
+						// This `continue` makes sure that `pending_responses` and
+						// `delayed_requests` get polled after we have added the new element.
 						continue 'poll_all
 					},
 				}
 			}
+			// Admit the delayed requests again once they are due. The budget may have been
+			// overdrawn again in the meantime by the responses of other requests.
+			while let Poll::Ready(Some(delayed)) = self.delayed_requests.poll_next_unpin(cx) {
+				let DelayedRequest {
+					peer,
+					request_id,
+					request,
+					channel,
+					protocol,
+					resp_builder,
+					deadline,
+				} = delayed;
+				let now = Instant::now();
+				let admission = self
+					.budgets
+					.as_mut()
+					.map_or(Admission::Serve, |budgets| budgets.readmit(&peer, now, deadline));
+				match admission {
+					Admission::Serve => self.serve_request(
+						peer,
+						request_id,
+						request,
+						channel,
+						protocol,
+						resp_builder,
+					),
+					Admission::Delay(delay) => {
+						let request = DelayedRequest {
+							peer,
+							request_id,
+							request,
+							channel,
+							protocol,
+							resp_builder,
+							deadline,
+						};
+						self.delayed_requests.push(Box::pin(async move {
+							Delay::new(delay).await;
+							request
+						}));
+					},
+					Admission::Refuse | Admission::Busy =>
+						return Poll::Ready(NetworkBehaviourAction::GenerateEvent(
+							self.refuse_request(peer, protocol, request_id),
+						)),
+				}
+			}
 			// Poll to see if any response is ready to be sent back.
 			while let Poll::Ready(Some(outcome)) = self.pending_responses.poll_next_unpin(cx) {
 				let RequestProcessingOutcome {
@@ -528,14 +921,28 @@ impl NetworkBehaviour for RequestResponsesBehaviour {
 					request_id,
 					protocol: protocol_name,
 					inner_channel,
-					response: OutgoingResponse { result, reputation_changes, sent_feedback },
+					response:
+						OutgoingResponse { result, reputation_changes, sent_feedback, db_reads },
 				} = match outcome {
-					Some(outcome) => outcome,
+					(_, Some(outcome)) => outcome,
 					// The response builder was too busy or handling the request failed. This is
 					// later on reported as a `InboundFailure::Omission`.
-					None => continue,
+					(peer, None) => {
+						if let Some(budgets) = &mut self.budgets {
+							budgets.release(&peer, Instant::now());
+						}
+						continue
+					},
 				};
 
+				if let Some(budgets) = &mut self.budgets {
+					let bytes = result.as_ref().map_or(0, |payload| payload.len() as u64);
+					let cost = budgets.charge(&peer, bytes, db_reads, Instant::now());
+					if let Some(metrics) = &self.budget_metrics {
+						metrics.cost.with_label_values(&[&protocol_name]).inc_by(cost);
+					}
+				}
+
 				if let Ok(payload) = result {
 					if let Some((protocol, _)) = self.protocols.get_mut(&*protocol_name) {
 						if protocol.send_response(inner_channel, Ok(payload)).is_err() {
@@ -718,10 +1125,18 @@ impl NetworkBehaviour for RequestResponsesBehaviour {
 							self.pending_responses_arrival_time
 								.remove(&(protocol.clone(), request_id).into());
 							self.send_feedback.remove(&(protocol.clone(), request_id).into());
+							let failure = if self
+								.budget_refused
+								.remove(&(protocol.clone(), request_id).into())
+							{
+								ResponseFailure::BudgetExhausted
+							} else {
+								ResponseFailure::Network(error)
+							};
 							let out = Event::InboundRequest {
 								peer,
 								protocol: protocol.clone(),
-								result: Err(ResponseFailure::Network(error)),
+								result: Err(failure),
 							};
 							return Poll::Ready(NetworkBehaviourAction::GenerateEvent(out))
 						},
@@ -777,6 +1192,10 @@ pub enum ResponseFailure {
 	/// Problem on the network.
 	#[error("Problem on the network: {0}")]
 	Network(InboundFailure),
+	/// The request was refused because the request budget of the peer was exhausted, or too
+	/// many of its requests were in flight.
+	#[error("Request budget of the peer is exhausted")]
+	BudgetExhausted,
 }
 
 /// Implements the libp2p [`RequestResponseCodec`] trait. Defines how streams of bytes are turned
@@ -935,6 +1354,14 @@ mod tests {
 
 	fn build_swarm(
 		list: impl Iterator<Item = ProtocolConfig>,
+	) -> (Swarm<RequestResponsesBehaviour>, Multiaddr, Peerset) {
+		build_swarm_with(list, None, None)
+	}
+
+	fn build_swarm_with(
+		list: impl Iterator<Item = ProtocolConfig>,
+		peer_authorization: Option<Arc<dyn PeerAuthorization>>,
+		request_budget: Option<RequestBudgetConfig>,
 	) -> (Swarm<RequestResponsesBehaviour>, Multiaddr, Peerset) {
 		let keypair = Keypair::generate_ed25519();
 
@@ -959,7 +1386,9 @@ mod tests {
 
 		let (peerset, handle) = Peerset::from_config(config);
 
-		let behaviour = RequestResponsesBehaviour::new(list, handle).unwrap();
+		let behaviour =
+			RequestResponsesBehaviour::new(list, handle, peer_authorization, request_budget, None)
+				.unwrap();
 
 		let runtime = tokio::runtime::Runtime::new().unwrap();
 		let mut swarm = Swarm::with_executor(
@@ -998,6 +1427,7 @@ mod tests {
 									result: Ok(b"this is a response".to_vec()),
 									reputation_changes: Vec::new(),
 									sent_feedback: Some(fb_tx),
+									db_reads: 0,
 								});
 								fb_rx.await.unwrap();
 							}
@@ -1081,6 +1511,113 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn requests_of_unauthorized_peers_are_refused() {
+		struct NoPeerAuthorized;
+
+		impl PeerAuthorization for NoPeerAuthorized {
+			fn is_authorized(&self, _: &PeerId) -> bool {
+				false
+			}
+		}
+
+		let protocol_name = "/test/req-resp/1";
+		let mut pool = LocalPool::new();
+
+		// Build swarms whose behaviour is `RequestResponsesBehaviour`. `swarm[0]` doesn't
+		// authorize any peer.
+		let mut swarms = (0..2)
+			.map(|i| {
+				let (tx, mut rx) = mpsc::channel::<IncomingRequest>(64);
+
+				pool.spawner()
+					.spawn_obj(
+						async move {
+							if rx.next().await.is_some() {
+								panic!("Request of an unauthorized peer must not be handled");
+							}
+						}
+						.boxed()
+						.into(),
+					)
+					.unwrap();
+
+				let protocol_config = ProtocolConfig {
+					name: From::from(protocol_name),
+					fallback_names: Vec::new(),
+					max_request_size: 1024,
+					max_response_size: 1024 * 1024,
+					request_timeout: Duration::from_secs(30),
+					inbound_queue: Some(tx),
+				};
+
+				let peer_authorization =
+					(i == 0).then(|| Arc::new(NoPeerAuthorized) as Arc<dyn PeerAuthorization>);
+				build_swarm_with(iter::once(protocol_config), peer_authorization, None)
+			})
+			.collect::<Vec<_>>();
+
+		// Ask `swarm[0]` to dial `swarm[1]`. There isn't any discovery mechanism in place in
+		// this test, so they wouldn't connect to each other.
+		{
+			let dial_addr = swarms[1].1.clone();
+			Swarm::dial(&mut swarms[0].0, dial_addr).unwrap();
+		}
+
+		let (mut swarm, _, peerset) = swarms.remove(0);
+		// Process every peerset event in the background.
+		pool.spawner().spawn_obj(loop_peerset(peerset).boxed().into()).unwrap();
+		// Running `swarm[0]` in the background.
+		pool.spawner()
+			.spawn_obj({
+				async move {
+					loop {
+						match swarm.select_next_some().await {
+							SwarmEvent::Behaviour(Event::InboundRequest { result, .. }) => {
+								assert!(result.is_err());
+							},
+							_ => {},
+						}
+					}
+				}
+				.boxed()
+				.into()
+			})
+			.unwrap();
+
+		// Remove and run the remaining swarm.
+		let (mut swarm, _, peerset) = swarms.remove(0);
+		// Process every peerset event in the background.
+		pool.spawner().spawn_obj(loop_peerset(peerset).boxed().into()).unwrap();
+		pool.run_until(async move {
+			let mut response_receiver = None;
+
+			loop {
+				match swarm.select_next_some().await {
+					SwarmEvent::ConnectionEstablished { peer_id, .. } => {
+						let (sender, receiver) = oneshot::channel();
+						swarm.behaviour_mut().send_request(
+							&peer_id,
+							protocol_name,
+							b"this is a request".to_vec(),
+							sender,
+							IfDisconnected::ImmediateError,
+						);
+						assert!(response_receiver.is_none());
+						response_receiver = Some(receiver);
+					},
+					SwarmEvent::Behaviour(Event::RequestFinished { .. }) => break,
+					_ => {},
+				}
+			}
+
+			match response_receiver.unwrap().await.unwrap().unwrap_err() {
+				RequestFailure::Refused => {},
+				_ => panic!(),
+			}
+		});
+	}
+
 	#[test]
 	fn max_response_size_exceeded() {
 		let protocol_name = "/test/req-resp/1";
@@ -1100,6 +1637,7 @@ mod tests {
 									result: Ok(b"this response exceeds the limit".to_vec()),
 									reputation_changes: Vec::new(),
 									sent_feedback: None,
+									db_reads: 0,
 								});
 							}
 						}
@@ -1295,6 +1833,7 @@ mod tests {
 							result: Ok(b"this is a response".to_vec()),
 							reputation_changes: Vec::new(),
 							sent_feedback: None,
+							db_reads: 0,
 						})
 						.unwrap();
 					protocol_2_request
@@ -1304,6 +1843,7 @@ mod tests {
 							result: Ok(b"this is a response".to_vec()),
 							reputation_changes: Vec::new(),
 							sent_feedback: None,
+							db_reads: 0,
 						})
 						.unwrap();
 				}
@@ -1354,4 +1894,243 @@ mod tests {
 			assert_eq!(response_receiver_2.await.unwrap().unwrap(), b"this is a response");
 		});
 	}
+
+	fn budgets(capacity: u64, refill_per_second: u64) -> RequestBudgets {
+		RequestBudgets::new(RequestBudgetConfig {
+			capacity,
+			refill_per_second,
+			byte_cost: 1,
+			db_read_cost: 10,
+			max_in_flight: 8,
+			max_delay: Duration::from_secs(5),
+		})
+	}
+
+	#[test]
+	fn request_budget_delays_and_refuses_requests() {
+		let mut budgets = budgets(100, 10);
+		let peer = PeerId::random();
+		let now = Instant::now();
+
+		assert_eq!(budgets.admit(&peer, now), Admission::Serve);
+		assert_eq!(budgets.charge(&peer, 50, 5, now), 100);
+
+		// The budget is exhausted, the next request waits for one unit to be refilled.
+		assert_eq!(budgets.admit(&peer, now), Admission::Delay(Duration::from_millis(100)));
+
+		// Overdrawing the budget makes the peer wait longer than allowed.
+		budgets.charge(&peer, 50, 0, now);
+		assert_eq!(budgets.admit(&peer, now), Admission::Refuse);
+
+		// Other peers aren't affected.
+		assert_eq!(budgets.admit(&PeerId::random(), now), Admission::Serve);
+	}
+
+	#[test]
+	fn request_budget_refills_over_time() {
+		let mut budgets = budgets(100, 10);
+		let peer = PeerId::random();
+		let now = Instant::now();
+
+		budgets.charge(&peer, 150, 0, now);
+		assert_eq!(budgets.admit(&peer, now), Admission::Refuse);
+
+		// 5 seconds refill 50 units, the balance is back to zero.
+		let now = now + Duration::from_secs(5);
+		assert_eq!(budgets.admit(&peer, now), Admission::Delay(Duration::from_millis(100)));
+
+		let now = now + Duration::from_secs(1);
+		assert_eq!(budgets.admit(&peer, now), Admission::Serve);
+
+		// The budget never grows beyond its capacity.
+		let now = now + Duration::from_secs(60);
+		budgets.charge(&peer, 101, 0, now);
+		assert_eq!(budgets.admit(&peer, now), Admission::Delay(Duration::from_millis(200)));
+	}
+
+	#[test]
+	fn request_budget_limits_requests_in_flight() {
+		let mut budgets =
+			RequestBudgets::new(RequestBudgetConfig { max_in_flight: 2, ..Default::default() });
+		let peer = PeerId::random();
+		let now = Instant::now();
+
+		assert_eq!(budgets.admit(&peer, now), Admission::Serve);
+		assert_eq!(budgets.admit(&peer, now), Admission::Serve);
+		assert_eq!(budgets.admit(&peer, now), Admission::Busy);
+
+		// Charged and released requests are no longer in flight.
+		budgets.charge(&peer, 0, 0, now);
+		assert_eq!(budgets.admit(&peer, now), Admission::Serve);
+		budgets.release(&peer, now);
+		assert_eq!(budgets.admit(&peer, now), Admission::Serve);
+		assert_eq!(budgets.admit(&peer, now), Admission::Busy);
+
+		// Peers with requests in flight aren't forgotten, even if their budget is full.
+		assert_eq!(budgets.admit(&PeerId::random(), now), Admission::Serve);
+		assert_eq!(budgets.admit(&peer, now), Admission::Busy);
+	}
+
+	#[test]
+	fn delayed_requests_are_admitted_again() {
+		let mut budgets = budgets(100, 10);
+		let peer = PeerId::random();
+		let now = Instant::now();
+
+		budgets.charge(&peer, 100, 0, now);
+		assert_eq!(budgets.admit(&peer, now), Admission::Delay(Duration::from_millis(100)));
+		let deadline = now + Duration::from_secs(5);
+
+		// The budget was refilled in time.
+		let now = now + Duration::from_millis(100);
+		assert_eq!(budgets.readmit(&peer, now, deadline), Admission::Serve);
+
+		// The budget was overdrawn again while the request was delayed.
+		assert_eq!(budgets.admit(&peer, now), Admission::Serve);
+		budgets.charge(&peer, 30, 0, now);
+		assert_eq!(budgets.admit(&peer, now), Admission::Delay(Duration::from_secs(3)));
+		let deadline = now + Duration::from_secs(5);
+		budgets.charge(&peer, 30, 0, now);
+		let now = now + Duration::from_secs(3);
+		assert_eq!(budgets.readmit(&peer, now, deadline), Admission::Refuse);
+	}
+
+	#[test]
+	fn default_request_budget_serves_full_size_sync_responses() {
+		let mut budgets = RequestBudgets::new(RequestBudgetConfig::default());
+		let peer = PeerId::random();
+		let now = Instant::now();
+
+		// A full block response: 128 blocks with header, body and justification, 8 MiB in total.
+		assert_eq!(budgets.admit(&peer, now), Admission::Serve);
+		budgets.charge(&peer, 8 * 1024 * 1024, 128 * 3, now);
+
+		// State sync downloads ranges of 2 MiB back to back, each read sequentially.
+		for _ in 0..16 {
+			assert_eq!(budgets.admit(&peer, now), Admission::Serve);
+			budgets.charge(&peer, 2 * 1024 * 1024, 1, now);
+		}
+		assert_eq!(budgets.admit(&peer, now), Admission::Serve);
+	}
+
+	#[test]
+	fn requests_are_refused_once_the_budget_is_exhausted() {
+		let protocol_name = "/test/req-resp/1";
+		let mut pool = LocalPool::new();
+
+		// Build swarms whose behaviour is `RequestResponsesBehaviour`. The budget of `swarm[0]`
+		// is exhausted by a single response and never refilled.
+		let mut swarms = (0..2)
+			.map(|i| {
+				let (tx, mut rx) = mpsc::channel::<IncomingRequest>(64);
+
+				pool.spawner()
+					.spawn_obj(
+						async move {
+							while let Some(rq) = rx.next().await {
+								let _ = rq.pending_response.send(super::OutgoingResponse {
+									result: Ok(b"this is a response".to_vec()),
+									reputation_changes: Vec::new(),
+									sent_feedback: None,
+									db_reads: 1,
+								});
+							}
+						}
+						.boxed()
+						.into(),
+					)
+					.unwrap();
+
+				let protocol_config = ProtocolConfig {
+					name: From::from(protocol_name),
+					fallback_names: Vec::new(),
+					max_request_size: 1024,
+					max_response_size: 1024 * 1024,
+					request_timeout: Duration::from_secs(30),
+					inbound_queue: Some(tx),
+				};
+
+				let request_budget = (i == 0).then(|| RequestBudgetConfig {
+					capacity: 1,
+					refill_per_second: 0,
+					..Default::default()
+				});
+				build_swarm_with(iter::once(protocol_config), None, request_budget)
+			})
+			.collect::<Vec<_>>();
+
+		// Ask `swarm[0]` to dial `swarm[1]`. There isn't any discovery mechanism in place in
+		// this test, so they wouldn't connect to each other.
+		{
+			let dial_addr = swarms[1].1.clone();
+			Swarm::dial(&mut swarms[0].0, dial_addr).unwrap();
+		}
+
+		let (mut swarm, _, peerset) = swarms.remove(0);
+		// Process every peerset event in the background.
+		pool.spawner().spawn_obj(loop_peerset(peerset).boxed().into()).unwrap();
+		// Running `swarm[0]` in the background.
+		pool.spawner()
+			.spawn_obj({
+				async move {
+					loop {
+						match swarm.select_next_some().await {
+							SwarmEvent::Behaviour(Event::InboundRequest { result, .. }) =>
+								if let Err(failure) = result {
+									assert!(matches!(failure, ResponseFailure::BudgetExhausted));
+								},
+							_ => {},
+						}
+					}
+				}
+				.boxed()
+				.into()
+			})
+			.unwrap();
+
+		// Remove and run the remaining swarm.
+		let (mut swarm, _, peerset) = swarms.remove(0);
+		// Process every peerset event in the background.
+		pool.spawner().spawn_obj(loop_peerset(peerset).boxed().into()).unwrap();
+		pool.run_until(async move {
+			let mut responses = Vec::new();
+			let mut finished = 0;
+			let mut send_request = |swarm: &mut Swarm<RequestResponsesBehaviour>, peer_id| {
+				let (sender, receiver) = oneshot::channel();
+				swarm.behaviour_mut().send_request(
+					&peer_id,
+					protocol_name,
+					b"this is a request".to_vec(),
+					sender,
+					IfDisconnected::ImmediateError,
+				);
+				responses.push(receiver);
+			};
+
+			loop {
+				match swarm.select_next_some().await {
+					SwarmEvent::ConnectionEstablished { peer_id, .. } => {
+						send_request(&mut swarm, peer_id);
+					},
+					SwarmEvent::Behaviour(Event::RequestFinished { peer, .. }) => {
+						finished += 1;
+						if finished == 1 {
+							send_request(&mut swarm, peer);
+						} else {
+							break
+						}
+					},
+					_ => {},
+				}
+			}
+
+			let mut responses = responses.into_iter();
+			assert_eq!(responses.next().unwrap().await.unwrap().unwrap(), b"this is a response");
+			match responses.next().unwrap().await.unwrap().unwrap_err() {
+				RequestFailure::Refused => {},
+				_ => panic!(),
+			}
+			assert!(responses.next().is_none());
+		});
+	}
 }
@@ -34,6 +34,7 @@ use crate::{
 	network_state::{
 		NetworkState, NotConnectedPeer as NetworkStateNotConnectedPeer, Peer as NetworkStatePeer,
 	},
+	peer_state::PeerStatePersistence,
 	protocol::{self, NotificationsSink, NotifsHandlerError, Protocol, Ready},
 	transport, ReputationChange,
 };
@@ -87,6 +88,7 @@ use std::{
 		atomic::{AtomicUsize, Ordering},
 		Arc,
 	},
+	time::{Duration, Instant},
 };
 
 pub use behaviour::{InboundFailure, OutboundFailure, ResponseFailure};
@@ -99,6 +101,11 @@ mod tests;
 pub use libp2p::identity::{error::DecodingError, Keypair, PublicKey};
 use sc_network_common::service::NetworkRequest;
 
+/// Interval at which the connected peers are checked against the [`PeerAuthorization`].
+///
+/// [`PeerAuthorization`]: crate::config::PeerAuthorization
+const PEER_AUTHORIZATION_CHECK_INTERVAL: Duration = Duration::from_secs(5);
+
 /// Custom error that can be produced by the [`ConnectionHandler`] of the [`NetworkBehaviour`].
 /// Used as a template parameter of [`SwarmEvent`] below.
 type ConnectionHandlerErr<TBehaviour> =
@@ -226,12 +233,27 @@ where
 			local_peer_id.to_base58(),
 		);
 
-		let (protocol, peerset_handle, mut known_addresses) = Protocol::new(
+		let (mut protocol, peerset_handle, mut known_addresses) = Protocol::new(
 			From::from(&params.role),
 			&params.network_config,
 			params.block_announce_config,
+			params.peer_authorization.clone(),
 		)?;
 
+		// Restore the state of the peers persisted before the last shutdown.
+		let peer_state_persistence = params
+			.peer_state_store
+			.filter(|_| params.network_config.persist_peers)
+			.map(|store| {
+				PeerStatePersistence::new(store, params.network_config.max_persisted_peers)
+			});
+		let persisted_peer_state =
+			peer_state_persistence.as_ref().map(|p| p.load()).unwrap_or_default();
+		protocol.restore_peerset_reputations(
+			persisted_peer_state.reputations,
+			persisted_peer_state.elapsed,
+		);
+
 		// List of multiaddresses that we know in the network.
 		let mut boot_node_ids = HashSet::new();
 
@@ -367,6 +389,9 @@ where
 					discovery_config,
 					params.network_config.request_response_protocols,
 					peerset_handle.clone(),
+					params.peer_authorization.clone(),
+					params.network_config.request_budget.clone(),
+					params.metrics_registry.as_ref(),
 				);
 
 				match result {
@@ -434,6 +459,13 @@ where
 			);
 		}
 
+		// Add the persisted addresses, which unlike those of the bootnodes may expire.
+		for (peer_id, addr) in persisted_peer_state.addresses {
+			if peer_id != local_peer_id {
+				swarm.behaviour_mut().add_persisted_address(peer_id, addr);
+			}
+		}
+
 		let external_addresses = Arc::new(Mutex::new(Vec::new()));
 		let listen_addresses = Arc::new(Mutex::new(Vec::new()));
 		let peers_notifications_sinks = Arc::new(Mutex::new(HashMap::new()));
@@ -466,6 +498,10 @@ where
 			peers_notifications_sinks,
 			metrics,
 			boot_node_ids,
+			peer_state_persistence,
+			next_peer_authorization_check: params
+				.peer_authorization
+				.map(|_| Instant::now() + PEER_AUTHORIZATION_CHECK_INTERVAL),
 			_marker: Default::default(),
 			_block: Default::default(),
 		})
@@ -1178,6 +1214,13 @@ where
 	metrics: Option<Metrics>,
 	/// The `PeerId`'s of all boot nodes.
 	boot_node_ids: Arc<HashSet<PeerId>>,
+	/// Persists the state of the peers across restarts, if enabled.
+	peer_state_persistence: Option<PeerStatePersistence>,
+	/// When the connected peers are checked against the [`PeerAuthorization`] next, if there is
+	/// one.
+	///
+	/// [`PeerAuthorization`]: crate::config::PeerAuthorization
+	next_peer_authorization_check: Option<Instant>,
 	/// For each peer and protocol combination, an object that allows sending notifications to
 	/// that peer. Shared with the [`NetworkService`].
 	peers_notifications_sinks: Arc<Mutex<HashMap<(PeerId, ProtocolName), NotificationsSink>>>,
@@ -1196,6 +1239,7 @@ where
 	/// Run the network.
 	pub async fn run(mut self) {
 		while self.next_action().await {}
+		self.persist_peer_state();
 	}
 
 	/// Perform one action on the network.
@@ -1218,6 +1262,14 @@ where
 			},
 		};
 
+		if self.peer_state_persistence.as_ref().map_or(false, |p| p.is_due()) {
+			self.persist_peer_state();
+		}
+
+		if self.next_peer_authorization_check.map_or(false, |at| at <= Instant::now()) {
+			self.disconnect_unauthorized_peers();
+		}
+
 		// Update the variables shared with the `NetworkService`.
 		let num_connected_peers =
 			self.network_service.behaviour_mut().user_protocol_mut().num_connected_peers();
@@ -1262,6 +1314,33 @@ where
 		true
 	}
 
+	/// Persists the reputations and the known good addresses of the peers, if enabled.
+	fn persist_peer_state(&mut self) {
+		if let Some(persistence) = self.peer_state_persistence.as_mut() {
+			let behaviour = self.network_service.behaviour_mut();
+			let reputations = behaviour.user_protocol_mut().peerset_reputations();
+			persistence.persist(reputations, behaviour.known_addresses());
+		}
+	}
+
+	/// Disconnects the peers which are no longer authorized to be connected to us.
+	fn disconnect_unauthorized_peers(&mut self) {
+		self.next_peer_authorization_check =
+			Some(Instant::now() + PEER_AUTHORIZATION_CHECK_INTERVAL);
+
+		let protocol = self.network_service.behaviour().user_protocol();
+		let unauthorized = self
+			.network_service
+			.connected_peers()
+			.filter(|peer_id| !protocol.is_peer_authorized(peer_id))
+			.copied()
+			.collect::<Vec<_>>();
+		for peer_id in unauthorized {
+			debug!(target: "sub-libp2p", "Disconnecting unauthorized peer {}", peer_id);
+			let _ = self.network_service.disconnect_peer_id(peer_id);
+		}
+	}
+
 	/// Process the next message coming from the `NetworkService`.
 	fn handle_worker_message(&mut self, msg: ServiceToWorkerMsg) {
 		match msg {
@@ -1384,6 +1463,7 @@ where
 									Some("busy-omitted"),
 								ResponseFailure::Network(InboundFailure::ConnectionClosed) =>
 									Some("connection-closed"),
+								ResponseFailure::BudgetExhausted => Some("budget-exhausted"),
 							};
 
 							if let Some(reason) = reason {
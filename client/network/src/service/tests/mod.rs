@@ -16,7 +16,7 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{config, NetworkService, NetworkWorker};
+use crate::{config, config::PeerAuthorization, NetworkService, NetworkWorker};
 
 use futures::prelude::*;
 use libp2p::Multiaddr;
@@ -78,6 +78,7 @@ struct TestNetworkBuilder {
 	set_config: Option<SetConfig>,
 	chain_sync_network: Option<(NetworkServiceProvider, NetworkServiceHandle)>,
 	config: Option<config::NetworkConfiguration>,
+	peer_authorization: Option<Arc<dyn PeerAuthorization>>,
 }
 
 impl TestNetworkBuilder {
@@ -90,6 +91,7 @@ impl TestNetworkBuilder {
 			set_config: None,
 			chain_sync_network: None,
 			config: None,
+			peer_authorization: None,
 		}
 	}
 
@@ -108,6 +110,14 @@ impl TestNetworkBuilder {
 		self
 	}
 
+	pub fn with_peer_authorization(
+		mut self,
+		peer_authorization: Arc<dyn PeerAuthorization>,
+	) -> Self {
+		self.peer_authorization = Some(peer_authorization);
+		self
+	}
+
 	pub fn build(mut self) -> TestNetwork {
 		let client = self.client.as_mut().map_or(
 			Arc::new(TestClientBuilder::with_default_backend().build_with_longest_chain().0),
@@ -213,6 +223,7 @@ impl TestNetworkBuilder {
 			block_request_protocol_config.name.clone(),
 			state_request_protocol_config.name.clone(),
 			None,
+			None,
 		)
 		.unwrap();
 		let mut link = self.link.unwrap_or(Box::new(chain_sync_service.clone()));
@@ -236,6 +247,8 @@ impl TestNetworkBuilder {
 				light_client_request_protocol_config,
 			]
 			.to_vec(),
+			peer_state_store: None,
+			peer_authorization: self.peer_authorization,
 		})
 		.unwrap();
 
@@ -25,7 +25,13 @@ use sc_network_common::{
 	protocol::event::Event,
 	service::{NetworkNotification, NetworkPeers, NetworkStateInfo},
 };
-use std::{sync::Arc, time::Duration};
+use std::{
+	sync::{
+		atomic::{AtomicBool, Ordering},
+		Arc,
+	},
+	time::Duration,
+};
 
 type TestNetworkService = NetworkService<
 	substrate_test_runtime_client::runtime::Block,
@@ -584,3 +590,60 @@ async fn ensure_public_addresses_consistent_with_transport_not_memory() {
 		.build()
 		.start_network();
 }
+
+#[tokio::test]
+async fn unauthorized_peers_are_disconnected() {
+	struct SwitchableAuthorization(AtomicBool);
+
+	impl config::PeerAuthorization for SwitchableAuthorization {
+		fn is_authorized(&self, _: &PeerId) -> bool {
+			self.0.load(Ordering::Relaxed)
+		}
+	}
+
+	let authorization = Arc::new(SwitchableAuthorization(AtomicBool::new(true)));
+	let listen_addr = config::build_multiaddr![Memory(rand::random::<u64>())];
+	let (node1, mut events_stream1) = TestNetworkBuilder::new()
+		.with_listen_addresses(vec![listen_addr.clone()])
+		.with_peer_authorization(authorization.clone())
+		.build()
+		.start_network();
+
+	let (node2, _events_stream2) = TestNetworkBuilder::new()
+		.with_set_config(SetConfig {
+			reserved_nodes: vec![MultiaddrWithPeerId {
+				multiaddr: listen_addr,
+				peer_id: node1.local_peer_id(),
+			}],
+			..Default::default()
+		})
+		.build()
+		.start_network();
+
+	// Wait for the `NotificationStreamOpened` of the authorized peer.
+	loop {
+		match events_stream1.next().await.unwrap() {
+			Event::NotificationStreamOpened { remote, protocol, .. }
+				if protocol == PROTOCOL_NAME.into() =>
+			{
+				assert_eq!(remote, node2.local_peer_id());
+				break
+			},
+			_ => {},
+		};
+	}
+
+	// Once the peer is no longer authorized, it is disconnected.
+	authorization.0.store(false, Ordering::Relaxed);
+	loop {
+		match events_stream1.next().await.unwrap() {
+			Event::NotificationStreamClosed { remote, protocol }
+				if protocol == PROTOCOL_NAME.into() =>
+			{
+				assert_eq!(remote, node2.local_peer_id());
+				break
+			},
+			_ => {},
+		};
+	}
+}
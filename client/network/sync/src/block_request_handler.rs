@@ -297,6 +297,11 @@ where
 			maybe_block_response.as_ref().map(|res| res.encoded_len()),
 		);
 
+		// Every requested attribute of every returned block is a separate read.
+		let db_reads = maybe_block_response.as_ref().map_or(0, |res| {
+			res.blocks.len() as u64 * u64::from(attributes.bits().count_ones().max(1))
+		});
+
 		let result = if let Some(block_response) = maybe_block_response {
 			let mut data = Vec::with_capacity(block_response.encoded_len());
 			block_response.encode(&mut data)?;
@@ -310,6 +315,7 @@ where
 				result,
 				reputation_changes: reputation_change.into_iter().collect(),
 				sent_feedback: None,
+				db_reads,
 			})
 			.map_err(|_| HandleRequestError::SendResponse)
 	}
@@ -21,6 +21,7 @@
 
 use crate::{
 	service::{self, chain_sync::ToServiceCommand},
+	state::StateSyncCheckpointStore,
 	ChainSync, ClientError, SyncingService,
 };
 
@@ -262,6 +263,7 @@ where
 		block_request_protocol_name: ProtocolName,
 		state_request_protocol_name: ProtocolName,
 		warp_sync_protocol_name: Option<ProtocolName>,
+		state_sync_checkpoint: Option<Arc<dyn StateSyncCheckpointStore>>,
 	) -> Result<(Self, SyncingService<B>, NonDefaultSetConfig), ClientError> {
 		let mode = match network_config.sync_mode {
 			SyncOperationMode::Full => SyncMode::Full,
@@ -325,12 +327,14 @@ where
 			block_announce_validator,
 			max_parallel_downloads,
 			warp_sync_params,
+			network_config.history_sync.clone(),
 			metrics_registry,
 			network_service.clone(),
 			import_queue,
 			block_request_protocol_name,
 			state_request_protocol_name,
 			warp_sync_protocol_name,
+			state_sync_checkpoint,
 		)?;
 
 		let block_announce_protocol_name = block_announce_config.notifications_protocol.clone();
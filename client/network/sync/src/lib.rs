@@ -42,7 +42,7 @@ pub mod warp_request_handler;
 use crate::{
 	blocks::BlockCollection,
 	schema::v1::{StateRequest, StateResponse},
-	state::StateSync,
+	state::{StateSync, StateSyncCheckpointStore},
 	warp::{WarpProofImportResult, WarpSync},
 };
 use codec::{Decode, DecodeAll, Encode};
@@ -52,7 +52,7 @@ use futures::{
 };
 use libp2p::{request_response::OutboundFailure, PeerId};
 use log::{debug, error, info, trace, warn};
-use prometheus_endpoint::{register, Counter, PrometheusError, Registry, U64};
+use prometheus_endpoint::{register, Counter, Gauge, PrometheusError, Registry, U64};
 use prost::Message;
 use sc_client_api::{BlockBackend, ProofProvider};
 use sc_consensus::{
@@ -60,7 +60,8 @@ use sc_consensus::{
 };
 use sc_network_common::{
 	config::{
-		NonDefaultSetConfig, NonReservedPeerMode, NotificationHandshake, ProtocolId, SetConfig,
+		HistorySyncConfig, NonDefaultSetConfig, NonReservedPeerMode, NotificationHandshake,
+		ProtocolId, SetConfig,
 	},
 	protocol::{role::Roles, ProtocolName},
 	request_responses::{IfDisconnected, RequestFailure},
@@ -70,10 +71,10 @@ use sc_network_common::{
 			BlockResponse, Direction, FromBlock,
 		},
 		warp::{EncodedProof, WarpProofRequest, WarpSyncParams, WarpSyncPhase, WarpSyncProgress},
-		BadPeer, ChainSync as ChainSyncT, ImportResult, Metrics, OnBlockData, OnBlockJustification,
-		OnStateData, OpaqueBlockRequest, OpaqueBlockResponse, OpaqueStateRequest,
-		OpaqueStateResponse, PeerInfo, PeerRequest, PollBlockAnnounceValidation, SyncMode,
-		SyncState, SyncStatus,
+		BadPeer, ChainSync as ChainSyncT, HistoryDownloadProgress, ImportResult, Metrics,
+		OnBlockData, OnBlockJustification, OnStateData, OpaqueBlockRequest, OpaqueBlockResponse,
+		OpaqueStateRequest, OpaqueStateResponse, PeerInfo, PeerRequest,
+		PollBlockAnnounceValidation, SyncMode, SyncState, SyncStatus,
 	},
 };
 pub use service::chain_sync::SyncingService;
@@ -96,6 +97,7 @@ use std::{
 	ops::Range,
 	pin::Pin,
 	sync::Arc,
+	time::{Duration, Instant},
 };
 use warp::TargetBlockImportResult;
 
@@ -128,6 +130,10 @@ const MAX_CONCURRENT_BLOCK_ANNOUNCE_VALIDATIONS_PER_PEER: usize = 4;
 /// Pick the state to sync as the latest finalized number minus this.
 const STATE_SYNC_FINALITY_THRESHOLD: u32 = 8;
 
+/// A state sync resumed after a restart is abandoned once its target is older than this number
+/// of finalized blocks, as peers are unlikely to keep its state any longer.
+const STATE_SYNC_CHECKPOINT_MAX_AGE: u32 = 256;
+
 /// We use a heuristic that with a high likelihood, by the time
 /// `MAJOR_SYNC_BLOCKS` have been imported we'll be on the same
 /// chain as (or at least closer to) the peer so we want to delay
@@ -236,6 +242,8 @@ impl Default for AllowedRequests {
 struct SyncingMetrics {
 	pub import_queue_blocks_submitted: Counter<U64>,
 	pub import_queue_justifications_submitted: Counter<U64>,
+	pub history_remaining_blocks: Gauge<U64>,
+	pub history_downloaded_bytes: Counter<U64>,
 }
 
 impl SyncingMetrics {
@@ -255,6 +263,20 @@ impl SyncingMetrics {
 				)?,
 				registry,
 			)?,
+			history_remaining_blocks: register(
+				Gauge::new(
+					"substrate_sync_history_remaining_blocks",
+					"Number of blocks of the history skipped by warp sync left to download.",
+				)?,
+				registry,
+			)?,
+			history_downloaded_bytes: register(
+				Counter::new(
+					"substrate_sync_history_downloaded_bytes",
+					"Number of bytes downloaded for the history skipped by warp sync.",
+				)?,
+				registry,
+			)?,
 		})
 	}
 }
@@ -263,6 +285,79 @@ struct GapSync<B: BlockT> {
 	blocks: BlockCollection<B>,
 	best_queued_number: NumberFor<B>,
 	target: NumberFor<B>,
+	/// First block of the gap when the download (re)started.
+	start: NumberFor<B>,
+	config: HistorySyncConfig,
+	downloaded_bytes: u64,
+	/// Earliest time the next request can be sent at without exceeding the bandwidth limit.
+	next_request_at: Option<Instant>,
+}
+
+impl<B: BlockT> GapSync<B> {
+	fn new(start: NumberFor<B>, target: NumberFor<B>, config: HistorySyncConfig) -> Self {
+		Self {
+			blocks: BlockCollection::new(),
+			best_queued_number: start - One::one(),
+			target,
+			start,
+			config,
+			downloaded_bytes: 0,
+			next_request_at: None,
+		}
+	}
+
+	fn progress(&self) -> HistoryDownloadProgress<NumberFor<B>> {
+		HistoryDownloadProgress {
+			start: self.start,
+			best_queued: self.best_queued_number,
+			target: self.target,
+			downloaded_bytes: self.downloaded_bytes,
+		}
+	}
+
+	/// Returns if no request can be sent at `now` without exceeding the bandwidth limit.
+	fn is_throttled(&self, now: Instant) -> bool {
+		self.next_request_at.map_or(false, |at| now < at)
+	}
+
+	/// Account for `bytes` downloaded at `now`.
+	fn on_downloaded(&mut self, bytes: usize, now: Instant) {
+		self.downloaded_bytes = self.downloaded_bytes.saturating_add(bytes as u64);
+		if let Some(limit) = self.config.max_bytes_per_second {
+			let delay = Duration::from_secs_f64(bytes as f64 / limit.max(1) as f64);
+			let from = self.next_request_at.map_or(now, |at| at.max(now));
+			self.next_request_at = Some(from + delay);
+		}
+	}
+
+	/// Blocks from which on the retained data changes and which are still to be downloaded, in
+	/// ascending order.
+	fn retention_boundaries(&self) -> Vec<NumberFor<B>> {
+		let mut boundaries: Vec<NumberFor<B>> = [self.config.bodies, self.config.justifications]
+			.iter()
+			.filter_map(|retention| retention.boundary())
+			.map(|boundary| boundary.saturated_into())
+			.filter(|boundary| {
+				*boundary > self.best_queued_number + One::one() && *boundary <= self.target
+			})
+			.collect();
+		boundaries.sort();
+		boundaries.dedup();
+		boundaries
+	}
+
+	/// Attributes to request for the blocks of a range starting at `start`.
+	fn attributes(&self, attrs: BlockAttributes, start: NumberFor<B>) -> BlockAttributes {
+		let start = start.saturated_into::<u64>();
+		let mut attrs = attrs;
+		if !self.config.bodies.includes(start) {
+			attrs.remove(BlockAttributes::BODY | BlockAttributes::INDEXED_BODY);
+		}
+		if !self.config.justifications.includes(start) {
+			attrs.remove(BlockAttributes::JUSTIFICATION);
+		}
+		attrs
+	}
 }
 
 type PendingResponse<B> = Pin<
@@ -325,6 +420,8 @@ pub struct ChainSync<B: BlockT, Client> {
 	import_existing: bool,
 	/// Gap download process.
 	gap_sync: Option<GapSync<B>>,
+	/// Configuration of the gap download.
+	history_sync: HistorySyncConfig,
 	/// Handle for communicating with `NetworkService`
 	network_service: service::network::NetworkServiceHandle,
 	/// Protocol name used for block announcements
@@ -335,6 +432,8 @@ pub struct ChainSync<B: BlockT, Client> {
 	state_request_protocol_name: ProtocolName,
 	/// Protocol name used to send out warp sync requests
 	warp_sync_protocol_name: Option<ProtocolName>,
+	/// Storage for the state sync progress, if it is to be resumed after a restart.
+	state_sync_checkpoint: Option<Arc<dyn StateSyncCheckpointStore>>,
 	/// Pending responses
 	pending_responses: FuturesUnordered<PendingResponse<B>>,
 	/// Handle to import queue.
@@ -520,6 +619,7 @@ where
 			queued_blocks: self.queue_blocks.len() as u32,
 			state_sync: self.state_sync.as_ref().map(|s| s.progress()),
 			warp_sync: warp_sync_progress,
+			history_sync: self.gap_sync.as_ref().map(|s| s.progress()),
 		}
 	}
 
@@ -634,7 +734,11 @@ where
 					{
 						log::debug!(target: "sync", "Starting warp state sync.");
 						if let Some(params) = self.warp_sync_params.take() {
-							self.warp_sync = Some(WarpSync::new(self.client.clone(), params));
+							self.warp_sync = Some(WarpSync::new(
+								self.client.clone(),
+								params,
+								self.state_sync_checkpoint.clone(),
+							));
 						}
 					}
 				}
@@ -1072,6 +1176,18 @@ where
 		});
 
 		if let SyncMode::LightState { skip_proofs, .. } = &self.mode {
+			let abandon_state_sync = self.state_sync.as_ref().map_or(false, |sync| {
+				sync.is_restored() &&
+					number >
+						sync.target_block_num()
+							.saturating_add(STATE_SYNC_CHECKPOINT_MAX_AGE.saturated_into())
+			});
+			if abandon_state_sync {
+				debug!(target: "sync", "Abandoning state sync resumed from an outdated checkpoint");
+				if let Some(sync) = self.state_sync.take() {
+					sync.clear_checkpoint();
+				}
+			}
 			if self.state_sync.is_none() && !self.peers.is_empty() && self.queue_blocks.is_empty() {
 				// Finalized a recent block.
 				let mut heads: Vec<_> = self.peers.values().map(|peer| peer.best_number).collect();
@@ -1091,6 +1207,7 @@ where
 							None,
 							None,
 							*skip_proofs,
+							self.state_sync_checkpoint.clone(),
 						));
 						self.allowed_requests.set_all();
 					}
@@ -1229,6 +1346,12 @@ where
 			gap_sync.blocks.clear_peer_download(who)
 		}
 		self.peers.remove(who);
+		if let Some(state_sync) = &mut self.state_sync {
+			state_sync.peer_disconnected(who);
+		}
+		if let Some(warp_sync) = &mut self.warp_sync {
+			warp_sync.peer_disconnected(who);
+		}
 		self.extra_justifications.peer_disconnected(who);
 		self.allowed_requests.set_all();
 		self.fork_targets.retain(|_, target| {
@@ -1397,12 +1520,14 @@ where
 		block_announce_validator: Box<dyn BlockAnnounceValidator<B> + Send>,
 		max_parallel_downloads: u32,
 		warp_sync_params: Option<WarpSyncParams<B>>,
+		history_sync: HistorySyncConfig,
 		metrics_registry: Option<&Registry>,
 		network_service: service::network::NetworkServiceHandle,
 		import_queue: Box<dyn ImportQueueService<B>>,
 		block_request_protocol_name: ProtocolName,
 		state_request_protocol_name: ProtocolName,
 		warp_sync_protocol_name: Option<ProtocolName>,
+		state_sync_checkpoint: Option<Arc<dyn StateSyncCheckpointStore>>,
 	) -> Result<(Self, NonDefaultSetConfig), ClientError> {
 		let block_announce_config = Self::get_block_announce_proto_config(
 			protocol_id,
@@ -1437,11 +1562,13 @@ where
 			warp_sync: None,
 			import_existing: false,
 			gap_sync: None,
+			history_sync,
 			network_service,
 			block_request_protocol_name,
 			state_request_protocol_name,
 			warp_sync_params,
 			warp_sync_protocol_name,
+			state_sync_checkpoint,
 			block_announce_protocol_name: block_announce_config
 				.notifications_protocol
 				.clone()
@@ -1462,6 +1589,15 @@ where
 		};
 
 		sync.reset_sync_start_point()?;
+		if let Some(store) = sync.state_sync_checkpoint.clone() {
+			match sync.mode {
+				SyncMode::LightState { skip_proofs, .. } =>
+					sync.state_sync = StateSync::restore(sync.client.clone(), store, skip_proofs),
+				// Resumed once warp sync knows its new target block.
+				SyncMode::Warp => {},
+				SyncMode::Full | SyncMode::Light => state::clear_checkpoint(&*store),
+			}
+		}
 		Ok((sync, block_announce_config))
 	}
 
@@ -1479,6 +1615,15 @@ where
 		}
 	}
 
+	fn report_history_progress(&self) {
+		if let Some(metrics) = &self.metrics {
+			let remaining = self.gap_sync.as_ref().map_or(0, |gap_sync| {
+				(gap_sync.target - gap_sync.best_queued_number).saturated_into()
+			});
+			metrics.history_remaining_blocks.set(remaining);
+		}
+	}
+
 	fn required_block_attributes(&self) -> BlockAttributes {
 		match self.mode {
 			SyncMode::Full =>
@@ -1557,6 +1702,7 @@ where
 		if let Some(gap_sync) = &mut self.gap_sync {
 			if number > gap_sync.best_queued_number && number <= gap_sync.target {
 				gap_sync.best_queued_number = number;
+				self.report_history_progress();
 			}
 		}
 		if number > self.best_queued_number {
@@ -1799,6 +1945,13 @@ where
 				return None
 			}
 
+			// peers that were downloading state are kept in that state until they respond,
+			// as the state sync expects the response.
+			if let PeerSyncState::DownloadingState = p.state {
+				self.peers.insert(id, p);
+				return None
+			}
+
 			// handle peers that were in other states.
 			match self.new_peer(id, p.best_hash, p.best_number) {
 				Ok(None) => None,
@@ -1848,12 +2001,9 @@ where
 
 		if let Some((start, end)) = info.block_gap {
 			debug!(target: "sync", "Starting gap sync #{} - #{}", start, end);
-			self.gap_sync = Some(GapSync {
-				best_queued_number: start - One::one(),
-				target: end,
-				blocks: BlockCollection::new(),
-			});
+			self.gap_sync = Some(GapSync::new(start, end, self.history_sync.clone()));
 		}
+		self.report_history_progress();
 		trace!(target: "sync", "Restarted sync at #{} ({:?})", self.best_queued_number, self.best_queued_hash);
 		Ok(())
 	}
@@ -2150,7 +2300,7 @@ where
 			self.send_block_request(id, request);
 		}
 
-		if let Some((id, request)) = self.state_request() {
+		for (id, request) in self.state_requests() {
 			self.send_state_request(id, request);
 		}
 
@@ -2170,6 +2320,15 @@ where
 			match response {
 				Ok(Ok(resp)) => match request {
 					PeerRequest::Block(req) => {
+						if let (Some(gap_sync), Some(PeerSyncState::DownloadingGap(_))) =
+							(&mut self.gap_sync, self.peers.get(&id).map(|peer| &peer.state))
+						{
+							gap_sync.on_downloaded(resp.len(), Instant::now());
+							if let Some(metrics) = &self.metrics {
+								metrics.history_downloaded_bytes.inc_by(resp.len() as u64);
+							}
+						}
+
 						let response = match Self::decode_block_response(&resp[..]) {
 							Ok(proto) => proto,
 							Err(e) => {
@@ -2359,7 +2518,10 @@ where
 		let allowed_requests = self.allowed_requests.take();
 		let max_parallel = if is_major_syncing { 1 } else { self.max_parallel_downloads };
 		let gap_sync = &mut self.gap_sync;
-		self.peers
+		let gap_throttled =
+			gap_sync.as_ref().map_or(false, |sync| sync.is_throttled(Instant::now()));
+		let requests = self
+			.peers
 			.iter_mut()
 			.filter_map(move |(&id, peer)| {
 				if !peer.state.is_available() || !allowed_requests.contains(&id) {
@@ -2427,16 +2589,11 @@ where
 					trace!(target: "sync", "Downloading fork {:?} from {}", hash, id);
 					peer.state = PeerSyncState::DownloadingStale(hash);
 					Some((id, req))
-				} else if let Some((range, req)) = gap_sync.as_mut().and_then(|sync| {
-					peer_gap_block_request(
-						&id,
-						peer,
-						&mut sync.blocks,
-						attrs,
-						sync.target,
-						sync.best_queued_number,
-					)
-				}) {
+				} else if let Some((range, req)) = gap_sync
+					.as_mut()
+					.filter(|_| !gap_throttled)
+					.and_then(|sync| peer_gap_block_request(&id, peer, sync, attrs))
+				{
 					peer.state = PeerSyncState::DownloadingGap(range.start);
 					trace!(
 						target: "sync",
@@ -2451,53 +2608,61 @@ where
 					None
 				}
 			})
-			.collect()
-		// Box::new(iter)
+			.collect();
+		// Gap requests held back by the bandwidth limit are retried once it allows them.
+		if gap_throttled {
+			self.allowed_requests.set_all();
+		}
+		requests
 	}
 
-	fn state_request(&mut self) -> Option<(PeerId, OpaqueStateRequest)> {
+	fn state_requests(&mut self) -> Vec<(PeerId, OpaqueStateRequest)> {
+		let mut requests = Vec::new();
 		if self.allowed_requests.is_empty() {
-			return None
+			return requests
 		}
-		if (self.state_sync.is_some() || self.warp_sync.is_some()) &&
-			self.peers.iter().any(|(_, peer)| peer.state == PeerSyncState::DownloadingState)
-		{
-			// Only one pending state request is allowed.
-			return None
-		}
-		if let Some(sync) = &self.state_sync {
+		// State is requested in parallel from several peers, each downloading a different range.
+		if let Some(sync) = &mut self.state_sync {
 			if sync.is_complete() {
-				return None
+				return requests
 			}
 
 			for (id, peer) in self.peers.iter_mut() {
 				if peer.state.is_available() && peer.common_number >= sync.target_block_num() {
-					peer.state = PeerSyncState::DownloadingState;
-					let request = sync.next_request();
-					trace!(target: "sync", "New StateRequest for {}: {:?}", id, request);
-					self.allowed_requests.clear();
-					return Some((*id, OpaqueStateRequest(Box::new(request))))
+					match sync.next_request(*id) {
+						Some(request) => {
+							trace!(target: "sync", "New StateRequest for {}: {:?}", id, request);
+							peer.state = PeerSyncState::DownloadingState;
+							requests.push((*id, OpaqueStateRequest(Box::new(request))));
+						},
+						None => break,
+					}
 				}
 			}
 		}
-		if let Some(sync) = &self.warp_sync {
+		if let Some(sync) = &mut self.warp_sync {
 			if sync.is_complete() {
-				return None
+				return requests
 			}
-			if let (Some(request), Some(target)) =
-				(sync.next_state_request(), sync.target_block_number())
-			{
+			if let Some(target) = sync.target_block_number() {
 				for (id, peer) in self.peers.iter_mut() {
 					if peer.state.is_available() && peer.best_number >= target {
-						trace!(target: "sync", "New StateRequest for {}: {:?}", id, request);
-						peer.state = PeerSyncState::DownloadingState;
-						self.allowed_requests.clear();
-						return Some((*id, OpaqueStateRequest(Box::new(request))))
+						match sync.next_state_request(*id) {
+							Some(request) => {
+								trace!(target: "sync", "New StateRequest for {}: {:?}", id, request);
+								peer.state = PeerSyncState::DownloadingState;
+								requests.push((*id, OpaqueStateRequest(Box::new(request))));
+							},
+							None => break,
+						}
 					}
 				}
 			}
 		}
-		None
+		if !requests.is_empty() {
+			self.allowed_requests.clear();
+		}
+		requests
 	}
 
 	fn warp_sync_request(&mut self) -> Option<(PeerId, WarpProofRequest<B>)> {
@@ -2559,7 +2724,7 @@ where
 				response.entries.len(),
 				response.proof.len(),
 			);
-			sync.import(*response)
+			sync.import(*who, *response)
 		} else if let Some(sync) = &mut self.warp_sync {
 			debug!(
 				target: "sync",
@@ -2568,7 +2733,7 @@ where
 				response.entries.len(),
 				response.proof.len(),
 			);
-			sync.import_state(*response)
+			sync.import_state(*who, *response)
 		} else {
 			debug!(target: "sync", "Ignored obsolete state response from {}", who);
 			return Err(BadPeer(*who, rep::NOT_REQUESTED))
@@ -2752,6 +2917,7 @@ where
 							"Block history download is complete."
 						);
 						self.gap_sync = None;
+						self.report_history_progress();
 					}
 				},
 				Err(BlockImportError::IncompleteHeader(who)) =>
@@ -2949,19 +3115,27 @@ fn peer_block_request<B: BlockT>(
 fn peer_gap_block_request<B: BlockT>(
 	id: &PeerId,
 	peer: &PeerSync<B>,
-	blocks: &mut BlockCollection<B>,
+	gap_sync: &mut GapSync<B>,
 	attrs: BlockAttributes,
-	target: NumberFor<B>,
-	common_number: NumberFor<B>,
 ) -> Option<(Range<NumberFor<B>>, BlockRequest<B>)> {
-	let range = blocks.needed_blocks(
-		*id,
-		MAX_BLOCKS_TO_REQUEST,
-		std::cmp::min(peer.best_number, target),
-		common_number,
-		1,
-		MAX_DOWNLOAD_AHEAD,
-	)?;
+	let peer_best = std::cmp::min(peer.best_number, gap_sync.target);
+	// Ranges don't cross the blocks from which on the retained data changes, so that the same
+	// attributes are requested for all the blocks of a range.
+	let range = gap_sync
+		.retention_boundaries()
+		.into_iter()
+		.map(|boundary| std::cmp::min(peer_best, boundary - One::one()))
+		.chain(iter::once(peer_best))
+		.find_map(|peer_best| {
+			gap_sync.blocks.needed_blocks(
+				*id,
+				MAX_BLOCKS_TO_REQUEST,
+				peer_best,
+				gap_sync.best_queued_number,
+				1,
+				MAX_DOWNLOAD_AHEAD,
+			)
+		})?;
 
 	// The end is not part of the range.
 	let last = range.end.saturating_sub(One::one());
@@ -2969,7 +3143,7 @@ fn peer_gap_block_request<B: BlockT>(
 
 	let request = BlockRequest::<B> {
 		id: 0,
-		fields: attrs,
+		fields: gap_sync.attributes(attrs, range.start),
 		from,
 		direction: Direction::Descending,
 		max: Some((range.end - range.start).saturated_into::<u32>()),
@@ -3193,12 +3367,14 @@ mod test {
 			block_announce_validator,
 			1,
 			None,
+			Default::default(),
 			None,
 			chain_sync_network_handle,
 			import_queue,
 			ProtocolName::from("block-request"),
 			ProtocolName::from("state-request"),
 			None,
+			None,
 		)
 		.unwrap();
 
@@ -3259,12 +3435,14 @@ mod test {
 			Box::new(DefaultBlockAnnounceValidator),
 			1,
 			None,
+			Default::default(),
 			None,
 			chain_sync_network_handle,
 			import_queue,
 			ProtocolName::from("block-request"),
 			ProtocolName::from("state-request"),
 			None,
+			None,
 		)
 		.unwrap();
 
@@ -3440,12 +3618,14 @@ mod test {
 			Box::new(DefaultBlockAnnounceValidator),
 			5,
 			None,
+			Default::default(),
 			None,
 			chain_sync_network_handle,
 			import_queue,
 			ProtocolName::from("block-request"),
 			ProtocolName::from("state-request"),
 			None,
+			None,
 		)
 		.unwrap();
 
@@ -3566,12 +3746,14 @@ mod test {
 			Box::new(DefaultBlockAnnounceValidator),
 			5,
 			None,
+			Default::default(),
 			None,
 			chain_sync_network_handle,
 			import_queue,
 			ProtocolName::from("block-request"),
 			ProtocolName::from("state-request"),
 			None,
+			None,
 		)
 		.unwrap();
 
@@ -3722,12 +3904,14 @@ mod test {
 			Box::new(DefaultBlockAnnounceValidator),
 			5,
 			None,
+			Default::default(),
 			None,
 			chain_sync_network_handle,
 			import_queue,
 			ProtocolName::from("block-request"),
 			ProtocolName::from("state-request"),
 			None,
+			None,
 		)
 		.unwrap();
 
@@ -3863,12 +4047,14 @@ mod test {
 			Box::new(DefaultBlockAnnounceValidator),
 			5,
 			None,
+			Default::default(),
 			None,
 			chain_sync_network_handle,
 			import_queue,
 			ProtocolName::from("block-request"),
 			ProtocolName::from("state-request"),
 			None,
+			None,
 		)
 		.unwrap();
 
@@ -4004,12 +4190,14 @@ mod test {
 			Box::new(DefaultBlockAnnounceValidator),
 			1,
 			None,
+			Default::default(),
 			None,
 			chain_sync_network_handle,
 			import_queue,
 			ProtocolName::from("block-request"),
 			ProtocolName::from("state-request"),
 			None,
+			None,
 		)
 		.unwrap();
 
@@ -4049,12 +4237,14 @@ mod test {
 			Box::new(DefaultBlockAnnounceValidator),
 			1,
 			None,
+			Default::default(),
 			None,
 			chain_sync_network_handle,
 			import_queue,
 			ProtocolName::from("block-request"),
 			ProtocolName::from("state-request"),
 			None,
+			None,
 		)
 		.unwrap();
 
@@ -4085,4 +4275,63 @@ mod test {
 		let state = AncestorSearchState::<Block>::BinarySearch(1, 3);
 		assert!(handle_ancestor_search_state(&state, 2, true).is_none());
 	}
+
+	#[test]
+	fn gap_requests_follow_history_retention() {
+		use sc_network_common::config::HistoryRetention;
+
+		let config = HistorySyncConfig {
+			bodies: HistoryRetention::FullFrom(100),
+			justifications: HistoryRetention::HeadersOnly,
+			max_bytes_per_second: None,
+		};
+		let mut gap_sync = GapSync::<Block>::new(1, 1000, config);
+		let attrs =
+			BlockAttributes::HEADER | BlockAttributes::BODY | BlockAttributes::JUSTIFICATION;
+		let peer = |peer_id| PeerSync::<Block> {
+			peer_id,
+			common_number: 0,
+			best_hash: Hash::random(),
+			best_number: 1000,
+			state: PeerSyncState::Available,
+		};
+
+		// Headers only are requested before block 100, without crossing it.
+		let mut last_range = 0..1;
+		while last_range.end < 100 {
+			let peer_id = PeerId::random();
+			let (range, request) =
+				peer_gap_block_request(&peer_id, &peer(peer_id), &mut gap_sync, attrs).unwrap();
+			assert_eq!(range.start, last_range.end);
+			assert!(range.end <= 100);
+			assert_eq!(request.fields, BlockAttributes::HEADER);
+			last_range = range;
+		}
+
+		// Bodies are requested from block 100 on.
+		let peer_id = PeerId::random();
+		let (range, request) =
+			peer_gap_block_request(&peer_id, &peer(peer_id), &mut gap_sync, attrs).unwrap();
+		assert_eq!(range.start, 100);
+		assert_eq!(request.fields, BlockAttributes::HEADER | BlockAttributes::BODY);
+	}
+
+	#[test]
+	fn gap_sync_respects_bandwidth_limit() {
+		let config = HistorySyncConfig { max_bytes_per_second: Some(1000), ..Default::default() };
+		let mut gap_sync = GapSync::<Block>::new(1, 1000, config);
+		let now = Instant::now();
+
+		assert!(!gap_sync.is_throttled(now));
+		gap_sync.on_downloaded(500, now);
+		gap_sync.on_downloaded(500, now);
+		assert!(gap_sync.is_throttled(now + Duration::from_millis(900)));
+		assert!(!gap_sync.is_throttled(now + Duration::from_secs(1)));
+		assert_eq!(gap_sync.progress().downloaded_bytes, 1000);
+
+		// Unlimited by default.
+		let mut gap_sync = GapSync::<Block>::new(1, 1000, Default::default());
+		gap_sync.on_downloaded(1_000_000, now);
+		assert!(!gap_sync.is_throttled(now));
+	}
 }
@@ -20,8 +20,9 @@
 
 use crate::schema::v1::{StateEntry, StateRequest, StateResponse};
 use codec::{Decode, Encode};
-use log::debug;
-use sc_client_api::{CompactProof, ProofProvider};
+use libp2p::PeerId;
+use log::{debug, warn};
+use sc_client_api::{AuxStore, CompactProof, ProofProvider};
 use sc_consensus::ImportedState;
 use sc_network_common::sync::StateDownloadProgress;
 use smallvec::SmallVec;
@@ -30,24 +31,192 @@ use sp_runtime::{
 	traits::{Block as BlockT, Header, NumberFor},
 	Justifications,
 };
-use std::{collections::HashMap, sync::Arc};
+use std::{
+	collections::{HashMap, HashSet},
+	sync::Arc,
+};
+
+/// Number of ranges the key space is split into. Each range is downloaded from a different peer.
+const STATE_SYNC_RANGES: usize = 16;
+
+/// The key under which the state sync checkpoint is kept.
+const CHECKPOINT_KEY: &[u8] = b"state_sync_checkpoint";
+
+/// Prefix of the keys under which the state chunks of the checkpoint are kept.
+const CHECKPOINT_CHUNK_PREFIX: &[u8] = b"state_sync_checkpoint_chunk";
+
+/// Storage for the progress of the state sync, which is resumed after a restart.
+///
+/// Both the state sync of
+/// [`SyncMode::LightState`](sc_network_common::sync::SyncMode::LightState) and the state phase of
+/// warp sync are checkpointed.
+///
+/// Implemented for every [`AuxStore`], such as the client.
+pub trait StateSyncCheckpointStore: Send + Sync {
+	/// Load the value stored under `key`, if any.
+	fn load(&self, key: &[u8]) -> Option<Vec<u8>>;
+
+	/// Atomically insert the `insert` pairs and delete the `delete` keys.
+	fn store(&self, insert: &[(&[u8], &[u8])], delete: &[&[u8]]) -> Result<(), String>;
+}
+
+impl<T: AuxStore + Send + Sync> StateSyncCheckpointStore for T {
+	fn load(&self, key: &[u8]) -> Option<Vec<u8>> {
+		self.get_aux(key).ok().flatten()
+	}
+
+	fn store(&self, insert: &[(&[u8], &[u8])], delete: &[&[u8]]) -> Result<(), String> {
+		self.insert_aux(insert, delete).map_err(|e| e.to_string())
+	}
+}
+
+/// Progress of a state sync as kept in the checkpoint store.
+#[derive(Encode, Decode)]
+struct Checkpoint<H, E> {
+	/// Number of state chunks stored so far. Kept first so that it can be decoded without
+	/// knowing the header type.
+	chunks: u32,
+	target_header: H,
+	target_body: Option<Vec<E>>,
+	target_justifications: Option<Justifications>,
+	skip_proof: bool,
+	/// Cursor and completion of each range.
+	ranges: Vec<(Vec<Vec<u8>>, bool)>,
+	imported_bytes: u64,
+}
+
+/// State downloaded by a single response: the state root of each trie (empty for the top
+/// trie) along with its keys and values.
+type StateChunk = Vec<(Vec<u8>, Vec<(Vec<u8>, Vec<u8>)>)>;
+
+fn checkpoint_chunk_key(index: u32) -> Vec<u8> {
+	let mut key = CHECKPOINT_CHUNK_PREFIX.to_vec();
+	index.encode_to(&mut key);
+	key
+}
+
+/// Remove the state sync checkpoint from `store`, if any.
+pub fn clear_checkpoint(store: &dyn StateSyncCheckpointStore) {
+	let chunks = match store.load(CHECKPOINT_KEY) {
+		Some(checkpoint) => u32::decode(&mut checkpoint.as_slice()).unwrap_or_default(),
+		None => return,
+	};
+	let chunk_keys = (0..chunks).map(checkpoint_chunk_key).collect::<Vec<_>>();
+	let delete = chunk_keys
+		.iter()
+		.map(|key| key.as_slice())
+		.chain(std::iter::once(CHECKPOINT_KEY))
+		.collect::<Vec<_>>();
+	if let Err(e) = store.store(&[], &delete) {
+		warn!(target: "sync", "Failed to clear the state sync checkpoint: {}", e);
+	}
+}
+
+/// Range of the top trie keys downloaded independently of the other ranges.
+///
+/// Child tries are downloaded along with the range containing their root key.
+struct StateRange {
+	/// First byte of the keys in the range.
+	first: u32,
+	/// Last key of the range, `None` for the last range.
+	end: Option<Vec<u8>>,
+	/// Last key downloaded, the next request starts after it.
+	last_key: SmallVec<[Vec<u8>; 2]>,
+	complete: bool,
+}
+
+impl StateRange {
+	/// Split the key space into `count` ranges of the same width.
+	fn split(count: usize) -> Vec<Self> {
+		let width = 256 / count as u32;
+		(0..count as u32)
+			.map(|index| {
+				let first = index * width;
+				let mut last_key = SmallVec::new();
+				if index > 0 {
+					// Keys are requested after the cursor, this is the end of the previous range.
+					last_key.push(vec![first as u8]);
+				}
+				let end = (index + 1 < count as u32).then(|| vec![(first + width) as u8]);
+				StateRange { first, end, last_key, complete: false }
+			})
+			.collect()
+	}
+
+	/// Check if the cursor `last_key` is past the end of the range.
+	fn is_past_end(&self, last_key: &[Vec<u8>]) -> bool {
+		match (&self.end, last_key.first()) {
+			(Some(end), Some(key)) => key > end || (last_key.len() == 1 && key == end),
+			_ => false,
+		}
+	}
+
+	/// Drop the keys of `chunk` which are past the end of the range, along with the child tries
+	/// whose root keys were dropped.
+	fn trim(&self, chunk: &mut StateChunk) {
+		let end = match &self.end {
+			Some(end) => end,
+			None => return,
+		};
+		let mut kept_roots = HashSet::new();
+		let mut dropped_roots = HashSet::new();
+		for (_, key_values) in chunk.iter_mut().filter(|(root, _)| root.is_empty()) {
+			key_values.retain(|(key, value)| {
+				let keep = key <= end;
+				if well_known_keys::is_child_storage_key(key) {
+					if keep {
+						kept_roots.insert(value.clone());
+					} else {
+						dropped_roots.insert(value.clone());
+					}
+				}
+				keep
+			});
+		}
+		chunk.retain(|(root, _)| {
+			root.is_empty() || kept_roots.contains(root) || !dropped_roots.contains(root)
+		});
+	}
+
+	/// Number of first key bytes downloaded, used to estimate the progress.
+	fn downloaded(&self) -> u32 {
+		let end = self.end.as_ref().map_or(256, |end| end[0] as u32);
+		if self.complete {
+			return end - self.first
+		}
+		let cursor = self
+			.last_key
+			.first()
+			.and_then(|key| key.first())
+			.map_or(self.first, |b| *b as u32);
+		cursor.clamp(self.first, end) - self.first
+	}
+}
 
 /// State sync state machine. Accumulates partial state data until it
 /// is ready to be imported.
+///
+/// The key space is split into ranges, which are downloaded in parallel from different peers.
+/// If a checkpoint store is set, the progress is saved after each response so that the download
+/// can be resumed after a restart.
 pub struct StateSync<B: BlockT, Client> {
 	target_block: B::Hash,
 	target_header: B::Header,
 	target_root: B::Hash,
 	target_body: Option<Vec<B::Extrinsic>>,
 	target_justifications: Option<Justifications>,
-	last_key: SmallVec<[Vec<u8>; 2]>,
+	ranges: Vec<StateRange>,
+	/// Range requested from each peer.
+	requested: HashMap<PeerId, usize>,
 	state: HashMap<Vec<u8>, (Vec<(Vec<u8>, Vec<u8>)>, Vec<Vec<u8>>)>,
 	complete: bool,
 	client: Arc<Client>,
 	imported_bytes: u64,
 	skip_proof: bool,
+	checkpoint_store: Option<Arc<dyn StateSyncCheckpointStore>>,
+	checkpoint_chunks: u32,
+	restored: bool,
 }
-
 /// Import state chunk result.
 pub enum ImportResult<B: BlockT> {
 	/// State is complete and ready for import.
@@ -64,30 +233,118 @@ where
 	Client: ProofProvider<B> + Send + Sync + 'static,
 {
 	///  Create a new instance.
+	///
+	/// Any checkpoint left in `checkpoint_store` by a previous state sync is discarded.
 	pub fn new(
 		client: Arc<Client>,
 		target_header: B::Header,
 		target_body: Option<Vec<B::Extrinsic>>,
 		target_justifications: Option<Justifications>,
 		skip_proof: bool,
+		checkpoint_store: Option<Arc<dyn StateSyncCheckpointStore>>,
 	) -> Self {
-		Self {
+		if let Some(store) = &checkpoint_store {
+			clear_checkpoint(&**store);
+		}
+		let mut sync = Self {
 			client,
 			target_block: target_header.hash(),
 			target_root: *target_header.state_root(),
 			target_header,
 			target_body,
 			target_justifications,
-			last_key: SmallVec::default(),
+			ranges: StateRange::split(STATE_SYNC_RANGES),
+			requested: HashMap::default(),
 			state: HashMap::default(),
 			complete: false,
 			imported_bytes: 0,
 			skip_proof,
+			checkpoint_store,
+			checkpoint_chunks: 0,
+			restored: false,
+		};
+		sync.store_checkpoint(None);
+		sync
+	}
+
+	/// Resume the state sync saved in `checkpoint_store`, if any.
+	///
+	/// The checkpoint is discarded if it can't be resumed.
+	pub fn restore(
+		client: Arc<Client>,
+		checkpoint_store: Arc<dyn StateSyncCheckpointStore>,
+		skip_proof: bool,
+	) -> Option<Self> {
+		let checkpoint = checkpoint_store.load(CHECKPOINT_KEY)?;
+		let restored = Self::restore_checkpoint(client, checkpoint_store.clone(), &checkpoint);
+		match restored {
+			Some(sync) if sync.skip_proof == skip_proof => {
+				debug!(
+					target: "sync",
+					"Resuming state sync for #{} ({}) from {} chunks",
+					sync.target_block_num(),
+					sync.target_block,
+					sync.checkpoint_chunks,
+				);
+				Some(sync)
+			},
+			_ => {
+				debug!(target: "sync", "Discarding state sync checkpoint");
+				clear_checkpoint(&*checkpoint_store);
+				None
+			},
+		}
+	}
+
+	fn restore_checkpoint(
+		client: Arc<Client>,
+		checkpoint_store: Arc<dyn StateSyncCheckpointStore>,
+		checkpoint: &[u8],
+	) -> Option<Self> {
+		let checkpoint =
+			Checkpoint::<B::Header, B::Extrinsic>::decode(&mut &checkpoint[..]).ok()?;
+		let mut ranges = StateRange::split(STATE_SYNC_RANGES);
+		if checkpoint.ranges.len() != ranges.len() {
+			return None
+		}
+		for (range, (last_key, complete)) in ranges.iter_mut().zip(checkpoint.ranges) {
+			range.last_key = last_key.into();
+			range.complete = complete;
+		}
+		let mut sync = Self {
+			client,
+			target_block: checkpoint.target_header.hash(),
+			target_root: *checkpoint.target_header.state_root(),
+			target_header: checkpoint.target_header,
+			target_body: checkpoint.target_body,
+			target_justifications: checkpoint.target_justifications,
+			ranges,
+			requested: HashMap::default(),
+			state: HashMap::default(),
+			complete: false,
+			imported_bytes: 0,
+			skip_proof: checkpoint.skip_proof,
+			checkpoint_store: Some(checkpoint_store.clone()),
+			checkpoint_chunks: checkpoint.chunks,
+			restored: true,
+		};
+		for index in 0..checkpoint.chunks {
+			let chunk = checkpoint_store.load(&checkpoint_chunk_key(index))?;
+			sync.insert(StateChunk::decode(&mut chunk.as_slice()).ok()?);
 		}
+		sync.imported_bytes = checkpoint.imported_bytes;
+		Some(sync)
 	}
 
-	///  Validate and import a state response.
-	pub fn import(&mut self, response: StateResponse) -> ImportResult<B> {
+	///  Validate and import a state response from `who`.
+	pub fn import(&mut self, who: PeerId, response: StateResponse) -> ImportResult<B> {
+		let index = match self.requested.remove(&who) {
+			Some(index) => index,
+			None => {
+				debug!(target: "sync", "Unexpected state response from {}", who);
+				return ImportResult::BadResponse
+			},
+		};
 		if response.entries.is_empty() && response.proof.is_empty() {
 			debug!(target: "sync", "Bad state response");
 			return ImportResult::BadResponse
@@ -96,7 +353,9 @@ where
 			debug!(target: "sync", "Missing proof");
 			return ImportResult::BadResponse
 		}
-		let complete = if !self.skip_proof {
+		let range = &self.ranges[index];
+		let mut last_key = range.last_key.clone();
+		let (mut chunk, complete) = if !self.skip_proof {
 			debug!(target: "sync", "Importing state from {} trie nodes", response.proof.len());
 			let proof_size = response.proof.len() as u64;
 			let proof = match CompactProof::decode(&mut response.proof.as_ref()) {
@@ -109,7 +368,7 @@ where
 			let (values, completed) = match self.client.verify_range_proof(
 				self.target_root,
 				proof,
-				self.last_key.as_slice(),
+				last_key.as_slice(),
 			) {
 				Err(e) => {
 					debug!(
@@ -124,63 +383,30 @@ where
 			debug!(target: "sync", "Imported with {} keys", values.len());
 
 			let complete = completed == 0;
-			if !complete && !values.update_last_key(completed, &mut self.last_key) {
+			if !complete && !values.update_last_key(completed, &mut last_key) {
 				debug!(target: "sync", "Error updating key cursor, depth: {}", completed);
 			};
-
-			for values in values.0 {
-				let key_values = if values.state_root.is_empty() {
-					// Read child trie roots.
-					values
-						.key_values
-						.into_iter()
-						.filter(|key_value| {
-							if well_known_keys::is_child_storage_key(key_value.0.as_slice()) {
-								self.state
-									.entry(key_value.1.clone())
-									.or_default()
-									.1
-									.push(key_value.0.clone());
-								false
-							} else {
-								true
-							}
-						})
-						.collect()
-				} else {
-					values.key_values
-				};
-				let mut entry = self.state.entry(values.state_root).or_default();
-				if entry.0.len() > 0 && entry.1.len() > 1 {
-					// Already imported child_trie with same root.
-					// Warning this will not work with parallel download.
-				} else if entry.0.is_empty() {
-					for (key, _value) in key_values.iter() {
-						self.imported_bytes += key.len() as u64;
-					}
-
-					entry.0 = key_values;
-				} else {
-					for (key, value) in key_values {
-						self.imported_bytes += key.len() as u64;
-						entry.0.push((key, value))
-					}
-				}
-			}
 			self.imported_bytes += proof_size;
-			complete
+
+			let chunk: StateChunk = values
+				.0
+				.into_iter()
+				.map(|values| (values.state_root, values.key_values))
+				.collect();
+			(chunk, complete)
 		} else {
 			let mut complete = true;
 			// if the trie is a child trie and one of its parent trie is empty,
 			// the parent cursor stays valid.
 			// Empty parent trie content only happens when all the response content
 			// is part of a single child trie.
-			if self.last_key.len() == 2 && response.entries[0].entries.is_empty() {
+			if last_key.len() == 2 && response.entries[0].entries.is_empty() {
 				// Do not remove the parent trie position.
-				self.last_key.pop();
+				last_key.pop();
 			} else {
-				self.last_key.clear();
+				last_key.clear();
 			}
+			let mut chunk = StateChunk::new();
 			for state in response.entries {
 				debug!(
 					target: "sync",
@@ -191,56 +417,143 @@ where
 
 				if !state.complete {
 					if let Some(e) = state.entries.last() {
-						self.last_key.push(e.key.clone());
+						last_key.push(e.key.clone());
 					}
 					complete = false;
 				}
-				let is_top = state.state_root.is_empty();
-				let entry = self.state.entry(state.state_root).or_default();
-				if entry.0.len() > 0 && entry.1.len() > 1 {
-					// Already imported child trie with same root.
-				} else {
-					let mut child_roots = Vec::new();
-					for StateEntry { key, value } in state.entries {
-						// Skip all child key root (will be recalculated on import).
-						if is_top && well_known_keys::is_child_storage_key(key.as_slice()) {
-							child_roots.push((value, key));
-						} else {
-							self.imported_bytes += key.len() as u64;
-							entry.0.push((key, value))
-						}
-					}
-					for (root, storage_key) in child_roots {
-						self.state.entry(root).or_default().1.push(storage_key);
-					}
-				}
+				let key_values = state
+					.entries
+					.into_iter()
+					.map(|StateEntry { key, value }| (key, value))
+					.collect();
+				chunk.push((state.state_root, key_values));
 			}
-			complete
+			(chunk, complete)
 		};
-		if complete {
+
+		let range = &mut self.ranges[index];
+		// Responses are not bounded by the end of the range, drop what belongs to the next one.
+		range.trim(&mut chunk);
+		range.complete = complete || range.is_past_end(&last_key);
+		range.last_key = last_key;
+		let encoded_chunk = self.checkpoint_store.is_some().then(|| chunk.encode());
+		self.insert(chunk);
+
+		if self.ranges.iter().all(|range| range.complete) {
 			self.complete = true;
+			self.clear_checkpoint();
 			ImportResult::Import(
 				self.target_block,
 				self.target_header.clone(),
 				ImportedState {
 					block: self.target_block,
 					state: std::mem::take(&mut self.state).into(),
+					written: false,
 				},
 				self.target_body.clone(),
 				self.target_justifications.clone(),
 			)
 		} else {
+			self.store_checkpoint(encoded_chunk);
 			ImportResult::Continue
 		}
 	}
 
-	/// Produce next state request.
-	pub fn next_request(&self) -> StateRequest {
-		StateRequest {
+	/// Add the downloaded `chunk` to the state.
+	///
+	/// A child trie is downloaded once for each child storage key holding its root, possibly by
+	/// different ranges at the same time. All copies are the same and downloaded in key order, so
+	/// only the keys past the last one of the child trie so far are added.
+	fn insert(&mut self, chunk: StateChunk) {
+		for (state_root, key_values) in chunk {
+			let key_values: Vec<_> = if state_root.is_empty() {
+				// Read child trie roots, the child keys are recalculated on import.
+				key_values
+					.into_iter()
+					.filter(|(key, value)| {
+						if well_known_keys::is_child_storage_key(key) {
+							self.state.entry(value.clone()).or_default().1.push(key.clone());
+							false
+						} else {
+							true
+						}
+					})
+					.collect()
+			} else {
+				key_values
+			};
+			let is_child = !state_root.is_empty();
+			let entry = self.state.entry(state_root).or_default();
+			let last_key = if is_child { entry.0.last().map(|(key, _)| key.clone()) } else { None };
+			for (key, value) in key_values {
+				if last_key.as_ref().map_or(true, |last_key| key > *last_key) {
+					self.imported_bytes += key.len() as u64;
+					entry.0.push((key, value));
+				}
+			}
+		}
+	}
+
+	/// Save the progress, along with the newly downloaded and encoded `chunk`, to the checkpoint
+	/// store.
+	fn store_checkpoint(&mut self, chunk: Option<Vec<u8>>) {
+		let store = match &self.checkpoint_store {
+			Some(store) => store,
+			None => return,
+		};
+		let chunks = self.checkpoint_chunks + chunk.is_some() as u32;
+		let checkpoint = Checkpoint {
+			chunks,
+			target_header: self.target_header.clone(),
+			target_body: self.target_body.clone(),
+			target_justifications: self.target_justifications.clone(),
+			skip_proof: self.skip_proof,
+			ranges: self
+				.ranges
+				.iter()
+				.map(|range| (range.last_key.to_vec(), range.complete))
+				.collect(),
+			imported_bytes: self.imported_bytes,
+		}
+		.encode();
+		let chunk_key = checkpoint_chunk_key(self.checkpoint_chunks);
+		let mut insert = vec![(CHECKPOINT_KEY, checkpoint.as_slice())];
+		if let Some(chunk) = &chunk {
+			insert.push((chunk_key.as_slice(), chunk.as_slice()));
+		}
+		match store.store(&insert, &[]) {
+			Ok(()) => self.checkpoint_chunks = chunks,
+			Err(e) => {
+				// A checkpoint missing a chunk can't be resumed, stop saving the progress.
+				warn!(target: "sync", "Failed to store the state sync checkpoint: {}", e);
+				clear_checkpoint(&**store);
+				self.checkpoint_store = None;
+			},
+		}
+	}
+
+	/// Produce the next state request for `who`.
+	///
+	/// Returns `None` if all the remaining ranges are already requested from other peers.
+	pub fn next_request(&mut self, who: PeerId) -> Option<StateRequest> {
+		if self.requested.contains_key(&who) {
+			return None
+		}
+		let index = (0..self.ranges.len()).find(|index| {
+			!self.ranges[*index].complete && !self.requested.values().any(|i| i == index)
+		})?;
+		self.requested.insert(who, index);
+		Some(StateRequest {
 			block: self.target_block.encode(),
-			start: self.last_key.clone().into_vec(),
+			start: self.ranges[index].last_key.clone().into_vec(),
 			no_proof: self.skip_proof,
-		}
+		})
+	}
+
+	/// Release the range requested from a disconnected peer, so that it can be requested from
+	/// another one.
+	pub fn peer_disconnected(&mut self, who: &PeerId) {
+		self.requested.remove(who);
 	}
 
 	/// Check if the state is complete.
@@ -248,6 +561,18 @@ where
 		self.complete
 	}
 
+	/// Check if the state sync was resumed from a checkpoint.
+	pub fn is_restored(&self) -> bool {
+		self.restored
+	}
+
+	/// Discard the checkpoint of this state sync, if any.
+	pub fn clear_checkpoint(&self) {
+		if let Some(store) = &self.checkpoint_store {
+			clear_checkpoint(&**store);
+		}
+	}
+
 	/// Returns target block number.
 	pub fn target_block_num(&self) -> NumberFor<B> {
 		*self.target_header.number()
@@ -260,8 +585,178 @@ where
 
 	/// Returns state sync estimated progress.
 	pub fn progress(&self) -> StateDownloadProgress {
-		let cursor = *self.last_key.get(0).and_then(|last| last.get(0)).unwrap_or(&0u8);
-		let percent_done = cursor as u32 * 100 / 256;
+		let downloaded: u32 = self.ranges.iter().map(StateRange::downloaded).sum();
+		let percent_done = downloaded * 100 / 256;
 		StateDownloadProgress { percentage: percent_done, size: self.imported_bytes }
 	}
 }
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use sc_client_api::StorageProvider;
+	use sp_blockchain::HeaderBackend;
+	use sp_core::storage::ChildInfo;
+	use substrate_test_runtime_client::{
+		runtime::Block, DefaultTestClientBuilderExt, TestClientBuilder, TestClientBuilderExt,
+	};
+
+	#[test]
+	fn ranges_cover_the_key_space() {
+		let ranges = StateRange::split(STATE_SYNC_RANGES);
+		assert_eq!(ranges.len(), STATE_SYNC_RANGES);
+		assert!(ranges[0].last_key.is_empty());
+		for (range, next) in ranges.iter().zip(ranges.iter().skip(1)) {
+			assert_eq!(range.end.as_ref(), next.last_key.first());
+		}
+		assert_eq!(ranges[STATE_SYNC_RANGES - 1].end, None);
+
+		let range = &ranges[1];
+		assert!(!range.is_past_end(&[vec![0x1f, 0xff]]));
+		assert!(range.is_past_end(&[vec![0x20]]));
+		assert!(range.is_past_end(&[vec![0x20, 0x00]]));
+		assert!(!ranges[STATE_SYNC_RANGES - 1].is_past_end(&[vec![0xff]]));
+	}
+
+	#[test]
+	fn responses_are_trimmed_to_the_range() {
+		let range = &StateRange::split(STATE_SYNC_RANGES)[2];
+		let child_key = well_known_keys::CHILD_STORAGE_KEY_PREFIX.to_vec();
+		let mut chunk: StateChunk = vec![
+			(
+				Vec::new(),
+				vec![
+					(vec![0x2f, 0x01], vec![1]),
+					(vec![0x30], vec![2]),
+					(vec![0x30, 0x01], vec![3]),
+					(child_key.clone(), b"child_root".to_vec()),
+				],
+			),
+			(b"child_root".to_vec(), vec![(vec![1], vec![4])]),
+		];
+		range.trim(&mut chunk);
+		assert_eq!(
+			chunk,
+			vec![(Vec::new(), vec![(vec![0x2f, 0x01], vec![1]), (vec![0x30], vec![2])])],
+		);
+	}
+
+	#[test]
+	fn shared_child_trie_is_merged_across_ranges() {
+		let client = Arc::new(TestClientBuilder::new().build());
+		let header = client.header(client.info().genesis_hash).unwrap().unwrap();
+		let mut sync = StateSync::<Block, _>::new(client, header, None, None, false, None);
+		let child_key = |name: &[u8]| {
+			let mut key = well_known_keys::DEFAULT_CHILD_STORAGE_KEY_PREFIX.to_vec();
+			key.extend_from_slice(name);
+			key
+		};
+		let root = b"child_root".to_vec();
+		let key_values =
+			|keys: std::ops::Range<u8>| keys.map(|key| (vec![key], vec![key])).collect::<Vec<_>>();
+
+		// Two ranges download the same child trie for different child storage keys, each in
+		// several responses, one getting ahead of the other.
+		sync.insert(vec![
+			(Vec::new(), vec![(child_key(b"a"), root.clone())]),
+			(root.clone(), key_values(0..2)),
+		]);
+		sync.insert(vec![
+			(Vec::new(), vec![(child_key(b"b"), root.clone())]),
+			(root.clone(), key_values(0..3)),
+		]);
+		sync.insert(vec![(root.clone(), key_values(2..5))]);
+		sync.insert(vec![(root.clone(), key_values(3..4))]);
+
+		let (key_values, parent_keys) = &sync.state[&root];
+		assert_eq!(*key_values, (0..5).map(|key| (vec![key], vec![key])).collect::<Vec<_>>());
+		assert_eq!(*parent_keys, vec![child_key(b"a"), child_key(b"b")]);
+	}
+
+	#[test]
+	fn state_is_downloaded_from_several_peers_and_resumed() {
+		let child_info = ChildInfo::new_default(b"child");
+		let mut builder = TestClientBuilder::new();
+		for i in 0..=255u8 {
+			builder = builder.add_extra_storage(vec![i, i], vec![i; 64]);
+		}
+		let client = Arc::new(
+			builder
+				.add_extra_child_storage(&child_info, b"key".to_vec(), b"value".to_vec())
+				.build(),
+		);
+		let hash = client.info().genesis_hash;
+		let header = client.header(hash).unwrap().unwrap();
+		let serve = |request: &StateRequest| {
+			let (proof, _) =
+				client.read_proof_collection(hash, request.start.as_slice(), 1024).unwrap();
+			StateResponse { entries: Vec::new(), proof: proof.encode() }
+		};
+		let peers = [PeerId::random(), PeerId::random()];
+		let download = |sync: &mut StateSync<Block, _>, rounds: Option<usize>| {
+			let mut round = 0;
+			while rounds.map_or(true, |rounds| round < rounds) {
+				round += 1;
+				let requests = peers
+					.iter()
+					.filter_map(|peer| sync.next_request(*peer).map(|request| (*peer, request)))
+					.collect::<Vec<_>>();
+				if let [(_, first), (_, second)] = requests.as_slice() {
+					// Both peers download a different range.
+					assert_ne!(first.start, second.start);
+				}
+				for (peer, request) in requests {
+					match sync.import(peer, serve(&request)) {
+						ImportResult::Import(_, _, state, _, _) => return Some(state),
+						ImportResult::Continue => {},
+						ImportResult::BadResponse => panic!("Valid state response is rejected"),
+					}
+				}
+			}
+			None
+		};
+
+		// Warp sync also imports the body of the target block.
+		let mut sync = StateSync::<Block, _>::new(
+			client.clone(),
+			header,
+			Some(Vec::new()),
+			None,
+			false,
+			Some(client.clone()),
+		);
+		assert!(download(&mut sync, Some(4)).is_none());
+		let progress = sync.progress();
+		drop(sync);
+
+		let mut sync = StateSync::<Block, _>::restore(client.clone(), client.clone(), false)
+			.expect("Checkpoint is stored");
+		assert!(sync.is_restored());
+		assert_eq!(sync.target_body, Some(Vec::new()));
+		assert_eq!(sync.progress().percentage, progress.percentage);
+		assert_eq!(sync.progress().size, progress.size);
+		let state = download(&mut sync, None).expect("State is downloaded").state;
+
+		let mut keys = state
+			.0
+			.iter()
+			.filter(|level| level.state_root.is_empty())
+			.flat_map(|level| level.key_values.iter().map(|(key, _)| key.clone()))
+			.collect::<Vec<_>>();
+		keys.sort();
+		let expected = client
+			.storage_keys(hash, None, None)
+			.unwrap()
+			.map(|key| key.0)
+			.filter(|key| !well_known_keys::is_child_storage_key(key))
+			.collect::<Vec<_>>();
+		assert_eq!(keys, expected);
+		assert!(state
+			.0
+			.iter()
+			.any(|level| level.key_values == vec![(b"key".to_vec(), b"value".to_vec())]));
+
+		// The checkpoint is removed once the download is complete.
+		assert!(StateSync::<Block, _>::restore(client.clone(), client, false).is_none());
+	}
+}
@@ -203,6 +203,9 @@ where
 			request.no_proof,
 		);
 
+		// The keys of a range are read sequentially, only seeking to the start of the range in
+		// each trie is charged as a read. The size of the response accounts for the keys read.
+		let mut db_reads = 0;
 		let result = if reputation_changes.is_empty() {
 			let mut response = StateResponse::default();
 
@@ -212,6 +215,7 @@ where
 					request.start.as_slice(),
 					MAX_RESPONSE_BYTES,
 				)?;
+				db_reads = 1;
 				response.proof = proof.encode();
 			} else {
 				let entries = self.client.storage_collection(
@@ -219,6 +223,7 @@ where
 					request.start.as_slice(),
 					MAX_RESPONSE_BYTES,
 				)?;
+				db_reads = entries.len() as u64;
 				response.entries = entries
 					.into_iter()
 					.map(|(state, complete)| KeyValueStateEntry {
@@ -263,7 +268,7 @@ where
 		};
 
 		pending_response
-			.send(OutgoingResponse { result, reputation_changes, sent_feedback: None })
+			.send(OutgoingResponse { result, reputation_changes, sent_feedback: None, db_reads })
 			.map_err(|_| HandleRequestError::SendResponse)
 	}
 }
@@ -21,9 +21,11 @@
 use crate::{
 	oneshot,
 	schema::v1::{StateRequest, StateResponse},
-	state::{ImportResult, StateSync},
+	state::{ImportResult, StateSync, StateSyncCheckpointStore},
+	STATE_SYNC_CHECKPOINT_MAX_AGE,
 };
 use futures::FutureExt;
+use libp2p::PeerId;
 use log::error;
 use sc_client_api::ProofProvider;
 use sc_network_common::sync::{
@@ -35,7 +37,10 @@ use sc_network_common::sync::{
 };
 use sp_blockchain::HeaderBackend;
 use sp_consensus_grandpa::{AuthorityList, SetId};
-use sp_runtime::traits::{Block as BlockT, Header, NumberFor, Zero};
+use sp_runtime::{
+	traits::{Block as BlockT, Header, NumberFor, Zero},
+	SaturatedConversion,
+};
 use std::{sync::Arc, task::Poll};
 
 enum Phase<B: BlockT, Client> {
@@ -73,6 +78,8 @@ pub struct WarpSync<B: BlockT, Client> {
 	phase: Phase<B, Client>,
 	client: Arc<Client>,
 	total_proof_bytes: u64,
+	/// Storage for the progress of the state download, if it is to be resumed after a restart.
+	checkpoint_store: Option<Arc<dyn StateSyncCheckpointStore>>,
 }
 
 impl<B, Client> WarpSync<B, Client>
@@ -83,7 +90,15 @@ where
 	/// Create a new instance. When passing a warp sync provider we will be checking for proof and
 	/// authorities. Alternatively we can pass a target block when we want to skip downloading
 	/// proofs, in this case we will continue polling until the target block is known.
-	pub fn new(client: Arc<Client>, warp_sync_params: WarpSyncParams<B>) -> Self {
+	///
+	/// The state download is saved to `checkpoint_store`, if any. Once the target block is known,
+	/// the download of a previous warp sync is resumed if its target is at most
+	/// `STATE_SYNC_CHECKPOINT_MAX_AGE` blocks older.
+	pub fn new(
+		client: Arc<Client>,
+		warp_sync_params: WarpSyncParams<B>,
+		checkpoint_store: Option<Arc<dyn StateSyncCheckpointStore>>,
+	) -> Self {
 		let last_hash = client.hash(Zero::zero()).unwrap().expect("Genesis header always exists");
 		match warp_sync_params {
 			WarpSyncParams::WithProvider(warp_sync_provider) => {
@@ -93,16 +108,47 @@ where
 					last_hash,
 					warp_sync_provider: warp_sync_provider.clone(),
 				};
-				Self { client, phase, total_proof_bytes: 0 }
+				Self { client, phase, total_proof_bytes: 0, checkpoint_store }
 			},
 			WarpSyncParams::WaitForTarget(block) => Self {
 				client,
 				phase: Phase::PendingTargetBlock { target_block: Some(block) },
 				total_proof_bytes: 0,
+				checkpoint_store,
 			},
 		}
 	}
 
+	/// The phase once the target block `header` is known: either the resumed state download of
+	/// a recent enough previous target, or the download of the target block.
+	fn target_block_phase(&self, header: B::Header) -> Phase<B, Client> {
+		let store = match &self.checkpoint_store {
+			Some(store) => store.clone(),
+			None => return Phase::TargetBlock(header),
+		};
+		match StateSync::restore(self.client.clone(), store, false) {
+			Some(sync)
+				if sync
+					.target_block_num()
+					.saturating_add(STATE_SYNC_CHECKPOINT_MAX_AGE.saturated_into()) >=
+					*header.number() =>
+			{
+				log::debug!(
+					target: "sync",
+					"Resuming the state download of warp sync for #{}",
+					sync.target_block_num(),
+				);
+				Phase::State(sync)
+			},
+			Some(sync) => {
+				log::debug!(target: "sync", "Discarding the outdated warp sync state download");
+				sync.clear_checkpoint();
+				Phase::TargetBlock(header)
+			},
+			None => Phase::TargetBlock(header),
+		}
+	}
+
 	/// Poll to make progress.
 	///
 	/// This only makes progress when `phase = Phase::PendingTargetBlock` and the pending block was
@@ -112,7 +158,7 @@ where
 			&mut self.phase
 		{
 			match target_block.poll_unpin(cx) {
-				Poll::Ready(Ok(target)) => Phase::TargetBlock(target),
+				Poll::Ready(Ok(target)) => self.target_block_phase(target),
 				Poll::Ready(Err(e)) => {
 					error!(target: "sync", "Failed to get target block. Error: {:?}",e);
 					Phase::PendingTargetBlock { target_block: None }
@@ -126,14 +172,14 @@ where
 		self.phase = new_phase;
 	}
 
-	///  Validate and import a state response.
-	pub fn import_state(&mut self, response: StateResponse) -> ImportResult<B> {
+	///  Validate and import a state response from `who`.
+	pub fn import_state(&mut self, who: PeerId, response: StateResponse) -> ImportResult<B> {
 		match &mut self.phase {
 			Phase::WarpProof { .. } | Phase::TargetBlock(_) | Phase::PendingTargetBlock { .. } => {
 				log::debug!(target: "sync", "Unexpected state response");
 				ImportResult::BadResponse
 			},
-			Phase::State(sync) => sync.import(response),
+			Phase::State(sync) => sync.import(who, response),
 		}
 	}
 
@@ -161,7 +207,7 @@ where
 					Ok(VerificationResult::Complete(new_set_id, _, header)) => {
 						log::debug!(target: "sync", "Verified complete proof, set_id={:?}", new_set_id);
 						self.total_proof_bytes += response.0.len() as u64;
-						self.phase = Phase::TargetBlock(header);
+						self.phase = self.target_block_phase(header);
 						WarpProofImportResult::Success
 					},
 				},
@@ -185,6 +231,7 @@ where
 								block.body,
 								block.justifications,
 								false,
+								self.checkpoint_store.clone(),
 							);
 							self.phase = Phase::State(state_sync);
 							TargetBlockImportResult::Success
@@ -209,12 +256,19 @@ where
 		}
 	}
 
-	/// Produce next state request.
-	pub fn next_state_request(&self) -> Option<StateRequest> {
-		match &self.phase {
+	/// Produce next state request for `who`.
+	pub fn next_state_request(&mut self, who: PeerId) -> Option<StateRequest> {
+		match &mut self.phase {
 			Phase::WarpProof { .. } | Phase::TargetBlock(_) | Phase::PendingTargetBlock { .. } =>
 				None,
-			Phase::State(sync) => Some(sync.next_request()),
+			Phase::State(sync) => sync.next_request(who),
+		}
+	}
+
+	/// Release the state requested from a disconnected peer.
+	pub fn peer_disconnected(&mut self, who: &PeerId) {
+		if let Phase::State(sync) = &mut self.phase {
+			sync.peer_disconnected(who);
 		}
 	}
 
@@ -107,6 +107,8 @@ impl<TBlock: BlockT> RequestHandler<TBlock> {
 				result: Ok(proof),
 				reputation_changes: Vec::new(),
 				sent_feedback: None,
+				// The provider doesn't report its reads; the proof size is charged instead.
+				db_reads: 0,
 			})
 			.map_err(|_| HandleRequestError::SendResponse)
 	}
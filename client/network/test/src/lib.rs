@@ -921,6 +921,7 @@ where
 				block_request_protocol_config.name.clone(),
 				state_request_protocol_config.name.clone(),
 				Some(warp_protocol_config.name.clone()),
+				Some(client.clone()),
 			)
 			.unwrap();
 		let sync_service_import_queue = Box::new(sync_service.clone());
@@ -944,6 +945,8 @@ where
 				warp_protocol_config,
 			]
 			.to_vec(),
+			peer_state_store: None,
+			peer_authorization: None,
 		})
 		.unwrap();
 
@@ -20,13 +20,14 @@ use std::{collections::HashSet, str::FromStr, sync::Arc, thread::sleep};
 
 use crate::NetworkProvider;
 use codec::{Decode, Encode};
-use futures::Future;
+use futures::{future, Future, FutureExt};
 pub use http::SharedClient;
 use libp2p::{Multiaddr, PeerId};
+use sc_network_bitswap::{BitswapClientHandle, Cid};
 use sp_core::{
 	offchain::{
-		self, HttpError, HttpRequestId, HttpRequestStatus, OffchainStorage, OpaqueMultiaddr,
-		OpaqueNetworkState, StorageKind, Timestamp,
+		self, BitswapError, HttpError, HttpRequestId, HttpRequestStatus, OffchainStorage,
+		OpaqueMultiaddr, OpaqueNetworkState, StorageKind, Timestamp,
 	},
 	OpaquePeerId,
 };
@@ -151,6 +152,8 @@ pub(crate) struct Api {
 	is_validator: bool,
 	/// Everything HTTP-related is handled by a different struct.
 	http: http::HttpApi,
+	/// Retrieves blocks over bitswap, if enabled.
+	bitswap: Option<BitswapClientHandle>,
 }
 
 impl offchain::Externalities for Api {
@@ -232,6 +235,28 @@ impl offchain::Externalities for Api {
 		self.network_provider.set_authorized_peers(peer_ids);
 		self.network_provider.set_authorized_only(authorized_only);
 	}
+
+	fn bitswap_get(
+		&mut self,
+		cid: &[u8],
+		deadline: Option<Timestamp>,
+	) -> Result<Vec<u8>, BitswapError> {
+		let bitswap = self.bitswap.as_ref().ok_or(BitswapError::Unavailable)?;
+		let cid = Cid::read_bytes(cid).map_err(|_| BitswapError::InvalidCid)?;
+
+		let get = bitswap.get(cid).boxed();
+		match futures::executor::block_on(future::select(
+			get,
+			timestamp::deadline_to_future(deadline),
+		)) {
+			future::Either::Left((result, _)) => result.map_err(|e| match e {
+				sc_network_bitswap::BitswapError::UnsupportedCid => BitswapError::InvalidCid,
+				sc_network_bitswap::BitswapError::ClientTerminated => BitswapError::Unavailable,
+				_ => BitswapError::NotFound,
+			}),
+			future::Either::Right(_) => Err(BitswapError::DeadlineReached),
+		}
+	}
 }
 
 /// Information about the local node's network state.
@@ -305,10 +330,11 @@ impl AsyncApi {
 		network_provider: Arc<dyn NetworkProvider + Send + Sync>,
 		is_validator: bool,
 		shared_http_client: SharedClient,
+		bitswap: Option<BitswapClientHandle>,
 	) -> (Api, Self) {
 		let (http_api, http_worker) = http::http(shared_http_client);
 
-		let api = Api { network_provider, is_validator, http: http_api };
+		let api = Api { network_provider, is_validator, http: http_api, bitswap };
 
 		let async_api = Self { http: Some(http_worker) };
 
@@ -430,7 +456,7 @@ mod tests {
 		let mock = Arc::new(TestNetwork());
 		let shared_client = SharedClient::new();
 
-		AsyncApi::new(mock, false, shared_client)
+		AsyncApi::new(mock, false, shared_client, None)
 	}
 
 	fn offchain_db() -> Db<LocalStorage> {
@@ -458,6 +484,13 @@ mod tests {
 		assert!(timestamp.unix_millis() >= d);
 	}
 
+	#[test]
+	fn bitswap_is_unavailable_without_client() {
+		let mut api = offchain_api().0;
+
+		assert_eq!(api.bitswap_get(&[0x01, 0x55], None), Err(BitswapError::Unavailable));
+	}
+
 	#[test]
 	fn should_sleep() {
 		let mut api = offchain_api().0;
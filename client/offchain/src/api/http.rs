@@ -1101,7 +1101,7 @@ mod tests {
 
 		{
 			let mock = Arc::new(TestNetwork());
-			let (mut api, async_api) = AsyncApi::new(mock, false, shared_client.clone());
+			let (mut api, async_api) = AsyncApi::new(mock, false, shared_client.clone(), None);
 			api.timestamp();
 
 			futures::executor::block_on(async move {
@@ -1116,7 +1116,7 @@ mod tests {
 
 		{
 			let mock = Arc::new(TestNetwork());
-			let (mut api, async_api) = AsyncApi::new(mock, false, shared_client.clone());
+			let (mut api, async_api) = AsyncApi::new(mock, false, shared_client.clone(), None);
 			let id = api.http_request_start("lol", "nope", &[]).unwrap();
 			api.http_request_write_body(id, &[], None).unwrap();
 			futures::executor::block_on(async move {
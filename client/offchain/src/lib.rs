@@ -42,6 +42,7 @@ use futures::{
 	prelude::*,
 };
 use parking_lot::Mutex;
+use sc_network_bitswap::BitswapClientHandle;
 use sc_network_common::service::{NetworkPeers, NetworkStateInfo};
 use sp_api::{ApiExt, ProvideRuntimeApi};
 use sp_core::{offchain, traits::SpawnNamed, ExecutionContext};
@@ -67,6 +68,11 @@ pub struct OffchainWorkerOptions {
 	///
 	/// If not enabled, any http request will panic.
 	pub enable_http_requests: bool,
+
+	/// Client retrieving blocks over bitswap for offchain workers.
+	///
+	/// If `None`, retrieving blocks fails with [`offchain::BitswapError::Unavailable`].
+	pub bitswap_client: Option<BitswapClientHandle>,
 }
 
 /// An offchain workers manager.
@@ -76,12 +82,16 @@ pub struct OffchainWorkers<Client, Block: traits::Block> {
 	thread_pool: Mutex<ThreadPool>,
 	shared_http_client: api::SharedClient,
 	enable_http: bool,
+	bitswap_client: Option<BitswapClientHandle>,
 }
 
 impl<Client, Block: traits::Block> OffchainWorkers<Client, Block> {
 	/// Creates new [`OffchainWorkers`].
 	pub fn new(client: Arc<Client>) -> Self {
-		Self::new_with_options(client, OffchainWorkerOptions { enable_http_requests: true })
+		Self::new_with_options(
+			client,
+			OffchainWorkerOptions { enable_http_requests: true, bitswap_client: None },
+		)
 	}
 
 	/// Creates new [`OffchainWorkers`] using the given `options`.
@@ -95,6 +105,7 @@ impl<Client, Block: traits::Block> OffchainWorkers<Client, Block> {
 			)),
 			shared_http_client: api::SharedClient::new(),
 			enable_http: options.enable_http_requests,
+			bitswap_client: options.bitswap_client,
 		}
 	}
 }
@@ -145,8 +156,12 @@ where
 			version
 		);
 		let process = (version > 0).then(|| {
-			let (api, runner) =
-				api::AsyncApi::new(network_provider, is_validator, self.shared_http_client.clone());
+			let (api, runner) = api::AsyncApi::new(
+				network_provider,
+				is_validator,
+				self.shared_http_client.clone(),
+				self.bitswap_client.clone(),
+			);
 			tracing::debug!(target: LOG_TARGET, "Spawning offchain workers at {:?}", hash);
 			let header = header.clone();
 			let client = self.client.clone();
@@ -484,23 +484,8 @@ impl Peerset {
 		};
 
 		// For each elapsed second, move the node reputation towards zero.
-		// If we multiply each second the reputation by `k` (where `k` is between 0 and 1), it
-		// takes `ln(0.5) / ln(k)` seconds to reduce the reputation by half. Use this formula to
-		// empirically determine a value of `k` that looks correct.
 		for _ in 0..secs_diff {
 			for peer_id in self.data.peers().cloned().collect::<Vec<_>>() {
-				// We use `k = 0.98`, so we divide by `50`. With that value, it takes 34.3 seconds
-				// to reduce the reputation by half.
-				fn reput_tick(reput: i32) -> i32 {
-					let mut diff = reput / 50;
-					if diff == 0 && reput < 0 {
-						diff = -1;
-					} else if diff == 0 && reput > 0 {
-						diff = 1;
-					}
-					reput.saturating_sub(diff)
-				}
-
 				let mut peer_reputation = self.data.peer_reputation(peer_id);
 
 				let before = peer_reputation.reputation();
@@ -533,6 +518,43 @@ impl Peerset {
 		}
 	}
 
+	/// Returns the reputation of every node whose reputation isn't zero, e.g. to persist them.
+	pub fn reputations(&mut self) -> Vec<(PeerId, i32)> {
+		// We want reputations to be up-to-date before returning them.
+		self.update_time();
+
+		self.data.reputations().filter(|(_, reputation)| *reputation != 0).collect()
+	}
+
+	/// Restores reputations, e.g. persisted before a restart, after moving them towards zero as
+	/// if `elapsed` had passed. Nodes whose reputation isn't zero keep it.
+	///
+	/// Banned nodes stay banned until their restored reputation recovers.
+	pub fn restore_reputations(
+		&mut self,
+		reputations: impl IntoIterator<Item = (PeerId, i32)>,
+		elapsed: Duration,
+	) {
+		self.update_time();
+
+		for (peer_id, mut reputation) in reputations {
+			let mut peer_reputation = self.data.peer_reputation(peer_id);
+			if peer_reputation.reputation() != 0 {
+				continue
+			}
+
+			// Every reputation reaches zero within minutes, so this loop is short.
+			for _ in 0..elapsed.as_secs() {
+				if reputation == 0 {
+					break
+				}
+				reputation = reput_tick(reputation);
+			}
+			trace!(target: "peerset", "Restoring {} with reputation {}", peer_id, reputation);
+			peer_reputation.set_reputation(reputation);
+		}
+	}
+
 	/// Try to fill available out slots with nodes for the given set.
 	fn alloc_slots(&mut self, set_id: SetId) {
 		self.update_time();
@@ -719,6 +741,23 @@ impl Peerset {
 	}
 }
 
+/// Moves a reputation one second's worth towards zero.
+///
+/// If we multiply each second the reputation by `k` (where `k` is between 0 and 1), it takes
+/// `ln(0.5) / ln(k)` seconds to reduce the reputation by half. Use this formula to empirically
+/// determine a value of `k` that looks correct.
+fn reput_tick(reput: i32) -> i32 {
+	// We use `k = 0.98`, so we divide by `50`. With that value, it takes 34.3 seconds to reduce
+	// the reputation by half.
+	let mut diff = reput / 50;
+	if diff == 0 && reput < 0 {
+		diff = -1;
+	} else if diff == 0 && reput > 0 {
+		diff = 1;
+	}
+	reput.saturating_sub(diff)
+}
+
 impl Stream for Peerset {
 	type Item = Message;
 
@@ -956,6 +995,48 @@ mod tests {
 		futures::executor::block_on(fut);
 	}
 
+	#[test]
+	fn test_restored_reputations() {
+		let (mut peerset, _handle) = Peerset::from_config(PeersetConfig {
+			sets: vec![SetConfig {
+				in_peers: 25,
+				out_peers: 25,
+				bootnodes: vec![],
+				reserved_nodes: Default::default(),
+				reserved_only: false,
+			}],
+		});
+		let banned = PeerId::random();
+		let good = PeerId::random();
+
+		peerset.restore_reputations(vec![(banned, i32::MIN), (good, 1000)], Duration::ZERO);
+		let mut reputations = peerset.reputations();
+		reputations.sort_by_key(|(_, reputation)| *reputation);
+		assert_eq!(reputations, vec![(banned, i32::MIN), (good, 1000)]);
+
+		// Check that an incoming connection from the banned node gets refused.
+		peerset.incoming(SetId::from(0), banned, IncomingIndex(1));
+		let (message, mut peerset) = next_message(peerset).unwrap();
+		assert_eq!(message, Message::Reject(IncomingIndex(1)));
+
+		// Restoring does not overwrite a known reputation.
+		peerset.restore_reputations(vec![(good, 1)], Duration::ZERO);
+		assert!(peerset.reputations().contains(&(good, 1000)));
+
+		// Reputations move towards zero for the time spent offline.
+		let (mut peerset, _handle) = Peerset::from_config(PeersetConfig { sets: vec![] });
+		peerset.restore_reputations(vec![(good, 1000)], Duration::from_secs(1));
+		assert_eq!(peerset.reputations(), vec![(good, 980)]);
+
+		let (mut peerset, _handle) = Peerset::from_config(PeersetConfig { sets: vec![] });
+		peerset.restore_reputations(
+			vec![(banned, i32::MIN), (good, i32::MAX)],
+			Duration::from_secs(3600),
+		);
+		assert!(peerset.reputations().is_empty());
+		assert_eq!(peerset.num_discovered_peers(), 0);
+	}
+
 	#[test]
 	fn test_relloc_after_banned() {
 		let (mut peerset, handle) = Peerset::from_config(PeersetConfig {
@@ -210,6 +210,11 @@ impl PeersState {
 		self.nodes.keys()
 	}
 
+	/// Returns the reputations of all the peers we know of.
+	pub fn reputations(&self) -> impl Iterator<Item = (PeerId, i32)> + '_ {
+		self.nodes.iter().map(|(peer_id, node)| (*peer_id, node.reputation))
+	}
+
 	/// Returns the list of peers we are connected to in the context of a specific set.
 	///
 	/// # Panic
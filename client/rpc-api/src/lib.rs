@@ -27,6 +27,7 @@ mod policy;
 pub use policy::DenyUnsafe;
 
 pub mod author;
+pub mod bitswap;
 pub mod chain;
 pub mod child_state;
 pub mod dev;
@@ -90,6 +90,23 @@ pub struct SyncState<Number> {
 	pub current_block: Number,
 	/// Height of the highest block in the network.
 	pub highest_block: Number,
+	/// Download of the block history skipped by warp sync, if in progress.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub history: Option<HistorySyncState<Number>>,
+}
+
+/// The state of the download of the block history skipped by warp sync.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HistorySyncState<Number> {
+	/// Height of the first missing block when the download (re)started.
+	pub starting_block: Number,
+	/// Height of the highest block of the history downloaded so far.
+	pub current_block: Number,
+	/// Height of the last block of the history.
+	pub highest_block: Number,
+	/// Number of bytes downloaded since the download (re)started.
+	pub downloaded_bytes: u64,
 }
 
 #[cfg(test)]
@@ -130,6 +147,7 @@ mod tests {
 				starting_block: 12u32,
 				current_block: 50u32,
 				highest_block: 128u32,
+				history: None,
 			})
 			.unwrap(),
 			r#"{"startingBlock":12,"currentBlock":50,"highestBlock":128}"#,
@@ -140,9 +158,26 @@ mod tests {
 				starting_block: 12u32,
 				current_block: 50u32,
 				highest_block: 50u32,
+				history: None,
 			})
 			.unwrap(),
 			r#"{"startingBlock":12,"currentBlock":50,"highestBlock":50}"#,
 		);
+
+		assert_eq!(
+			::serde_json::to_string(&SyncState {
+				starting_block: 1000u32,
+				current_block: 1000u32,
+				highest_block: 1000u32,
+				history: Some(HistorySyncState {
+					starting_block: 1u32,
+					current_block: 300u32,
+					highest_block: 999u32,
+					downloaded_bytes: 4096,
+				}),
+			})
+			.unwrap(),
+			r#"{"startingBlock":1000,"currentBlock":1000,"highestBlock":1000,"history":{"startingBlock":1,"currentBlock":300,"highestBlock":999,"downloadedBytes":4096}}"#,
+		);
 	}
 }
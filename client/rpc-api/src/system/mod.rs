@@ -23,7 +23,7 @@ use jsonrpsee::{
 	proc_macros::rpc,
 };
 
-pub use self::helpers::{Health, NodeRole, PeerInfo, SyncState, SystemInfo};
+pub use self::helpers::{Health, HistorySyncState, NodeRole, PeerInfo, SyncState, SystemInfo};
 
 pub mod error;
 pub mod helpers;
@@ -105,7 +105,8 @@ pub trait SystemApi<Hash, Number> {
 	async fn system_node_roles(&self) -> RpcResult<Vec<NodeRole>>;
 
 	/// Returns the state of the syncing of the node: starting block, current best block, highest
-	/// known block.
+	/// known block and, while it's downloaded, the progress of the block history skipped by warp
+	/// sync.
 	#[method(name = "system_syncState")]
 	async fn system_sync_state(&self) -> RpcResult<SyncState<Number>>;
 
@@ -0,0 +1,52 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+//! Substrate bitswap API.
+
+use self::error::Error;
+use jsonrpsee::core::{async_trait, RpcResult};
+use sc_network_bitswap::{BitswapClientHandle, Cid};
+pub use sc_rpc_api::bitswap::*;
+use sc_rpc_api::DenyUnsafe;
+use sp_core::Bytes;
+
+/// Bitswap API
+pub struct Bitswap {
+	client: BitswapClientHandle,
+	deny_unsafe: DenyUnsafe,
+}
+
+impl Bitswap {
+	/// Create new instance of Bitswap API.
+	pub fn new(client: BitswapClientHandle, deny_unsafe: DenyUnsafe) -> Self {
+		Self { client, deny_unsafe }
+	}
+}
+
+#[async_trait]
+impl BitswapApiServer for Bitswap {
+	async fn get_block(&self, cid: String) -> RpcResult<Bytes> {
+		self.deny_unsafe.check_if_safe()?;
+
+		let cid = Cid::try_from(cid).map_err(|e| Error::InvalidCid(e.to_string()))?;
+		self.client
+			.get(cid)
+			.await
+			.map(Into::into)
+			.map_err(|e| Error::Retrieval(e.to_string()).into())
+	}
+}
@@ -124,6 +124,7 @@ fn api<T: Into<Option<Status>>>(sync: T) -> RpcModule<System<Block>> {
 						starting_block: 1,
 						current_block: 2,
 						highest_block: 3,
+						history: None,
 					});
 				},
 			};
@@ -297,7 +298,10 @@ async fn system_node_roles() {
 async fn system_sync_state() {
 	let sync_state: SyncState<i32> =
 		api(None).call("system_syncState", EmptyParams::new()).await.unwrap();
-	assert_eq!(sync_state, SyncState { starting_block: 1, current_block: 2, highest_block: 3 });
+	assert_eq!(
+		sync_state,
+		SyncState { starting_block: 1, current_block: 2, highest_block: 3, history: None }
+	);
 }
 
 #[tokio::test]
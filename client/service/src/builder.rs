@@ -31,15 +31,15 @@ use log::info;
 use prometheus_endpoint::Registry;
 use sc_chain_spec::get_extension;
 use sc_client_api::{
-	execution_extensions::ExecutionExtensions, proof_provider::ProofProvider, BadBlocks,
+	execution_extensions::ExecutionExtensions, proof_provider::ProofProvider, AuxStore, BadBlocks,
 	BlockBackend, BlockchainEvents, ExecutorProvider, ForkBlocks, StorageProvider, UsageProvider,
 };
 use sc_client_db::{Backend, DatabaseSettings};
 use sc_consensus::import_queue::ImportQueue;
 use sc_executor::RuntimeVersionOf;
 use sc_keystore::LocalKeystore;
-use sc_network::NetworkService;
-use sc_network_bitswap::BitswapRequestHandler;
+use sc_network::{config::PeerAuthorization, NetworkService};
+use sc_network_bitswap::{BitswapClient, BitswapClientHandle, BitswapRequestHandler};
 use sc_network_common::{
 	config::SyncMode,
 	protocol::role::Roles,
@@ -54,6 +54,7 @@ use sc_network_sync::{
 };
 use sc_rpc::{
 	author::AuthorApiServer,
+	bitswap::BitswapApiServer,
 	chain::ChainApiServer,
 	offchain::OffchainApiServer,
 	state::{ChildStateApiServer, StateApiServer},
@@ -392,6 +393,8 @@ pub struct SpawnTasksParams<'a, TBl: BlockT, TCl, TExPool, TRpc, Backend> {
 		sc_network_transactions::TransactionsHandlerController<<TBl as BlockT>::Hash>,
 	/// Syncing service.
 	pub sync_service: Arc<SyncingService<TBl>>,
+	/// Client retrieving blocks over bitswap, exposed through the RPC if set.
+	pub bitswap_client: Option<BitswapClientHandle>,
 	/// Telemetry instance for this node.
 	pub telemetry: Option<&'a mut Telemetry>,
 }
@@ -402,13 +405,17 @@ pub fn build_offchain_workers<TBl, TCl>(
 	spawn_handle: SpawnTaskHandle,
 	client: Arc<TCl>,
 	network: Arc<dyn sc_offchain::NetworkProvider + Send + Sync>,
+	bitswap_client: Option<BitswapClientHandle>,
 ) -> Option<Arc<sc_offchain::OffchainWorkers<TCl, TBl>>>
 where
 	TBl: BlockT,
 	TCl: Send + Sync + ProvideRuntimeApi<TBl> + BlockchainEvents<TBl> + 'static,
 	<TCl as ProvideRuntimeApi<TBl>>::Api: sc_offchain::OffchainWorkerApi<TBl>,
 {
-	let offchain_workers = Some(Arc::new(sc_offchain::OffchainWorkers::new(client.clone())));
+	let offchain_workers = Some(Arc::new(sc_offchain::OffchainWorkers::new_with_options(
+		client.clone(),
+		sc_offchain::OffchainWorkerOptions { enable_http_requests: true, bitswap_client },
+	)));
 
 	// Inform the offchain worker about new imported blocks
 	if let Some(offchain) = offchain_workers.clone() {
@@ -470,6 +477,7 @@ where
 		system_rpc_tx,
 		tx_handler_controller,
 		sync_service,
+		bitswap_client,
 		telemetry,
 	} = params;
 
@@ -553,6 +561,7 @@ where
 			system_rpc_tx.clone(),
 			&config,
 			backend.clone(),
+			bitswap_client.clone(),
 			&*rpc_builder,
 		)
 	};
@@ -652,6 +661,7 @@ fn gen_rpc_module<TBl, TBackend, TCl, TRpc, TExPool>(
 	system_rpc_tx: TracingUnboundedSender<sc_rpc::system::Request<TBl>>,
 	config: &Configuration,
 	backend: Arc<TBackend>,
+	bitswap_client: Option<BitswapClientHandle>,
 	rpc_builder: &(dyn Fn(DenyUnsafe, SubscriptionTaskExecutor) -> Result<RpcModule<TRpc>, Error>),
 ) -> Result<RpcModule<()>, Error>
 where
@@ -736,6 +746,12 @@ where
 		rpc_api.merge(offchain).map_err(|e| Error::Application(e.into()))?;
 	}
 
+	if let Some(bitswap_client) = bitswap_client {
+		let bitswap = sc_rpc::bitswap::Bitswap::new(bitswap_client, deny_unsafe).into_rpc();
+
+		rpc_api.merge(bitswap).map_err(|e| Error::Application(e.into()))?;
+	}
+
 	// Part of the RPC v2 spec.
 	rpc_api.merge(transaction_v2).map_err(|e| Error::Application(e.into()))?;
 	rpc_api.merge(chain_head_v2).map_err(|e| Error::Application(e.into()))?;
@@ -770,6 +786,8 @@ pub struct BuildNetworkParams<'a, TBl: BlockT, TExPool, TImpQu, TCl> {
 		Option<Box<dyn FnOnce(Arc<TCl>) -> Box<dyn BlockAnnounceValidator<TBl> + Send> + Send>>,
 	/// Optional warp sync params.
 	pub warp_sync_params: Option<WarpSyncParams<TBl>>,
+	/// Optional provider deciding which peers are allowed to connect to the node.
+	pub peer_authorization: Option<Arc<dyn PeerAuthorization>>,
 }
 /// Build the network service, the network status sinks and an RPC sender.
 pub fn build_network<TBl, TExPool, TImpQu, TCl>(
@@ -781,6 +799,7 @@ pub fn build_network<TBl, TExPool, TImpQu, TCl>(
 		sc_network_transactions::TransactionsHandlerController<<TBl as BlockT>::Hash>,
 		NetworkStarter,
 		Arc<SyncingService<TBl>>,
+		BitswapClientHandle,
 	),
 	Error,
 >
@@ -794,6 +813,7 @@ where
 		+ ProofProvider<TBl>
 		+ HeaderBackend<TBl>
 		+ BlockchainEvents<TBl>
+		+ AuxStore
 		+ 'static,
 	TExPool: MaintainedTransactionPool<Block = TBl, Hash = <TBl as BlockT>::Hash> + 'static,
 	TImpQu: ImportQueue<TBl> + 'static,
@@ -806,6 +826,7 @@ where
 		import_queue,
 		block_announce_validator_builder,
 		warp_sync_params,
+		peer_authorization,
 	} = params;
 
 	let mut request_response_protocol_configs = Vec::new();
@@ -900,14 +921,18 @@ where
 		block_request_protocol_config.name.clone(),
 		state_request_protocol_config.name.clone(),
 		warp_sync_protocol_config.as_ref().map(|config| config.name.clone()),
+		Some(client.clone()),
 	)?;
 	let sync_service_import_queue = sync_service.clone();
 	let sync_service = Arc::new(sync_service);
 
-	request_response_protocol_configs.push(config.network.ipfs_server.then(|| {
+	request_response_protocol_configs.push(Some(if config.network.ipfs_server {
 		let (handler, protocol_config) = BitswapRequestHandler::new(client.clone());
 		spawn_handle.spawn("bitswap-request-handler", Some("networking"), handler.run());
 		protocol_config
+	} else {
+		// Blocks are still requested from other nodes by the bitswap client.
+		sc_network_bitswap::outbound_protocol_config()
 	}));
 
 	let mut network_params = sc_network::config::Params {
@@ -934,6 +959,8 @@ where
 			])
 			.flatten()
 			.collect::<Vec<_>>(),
+		peer_state_store: Some(client.clone()),
+		peer_authorization,
 	};
 
 	// crate transactions protocol and add it to the list of supported protocols of `network_params`
@@ -963,6 +990,10 @@ where
 	)?;
 
 	spawn_handle.spawn("network-transactions-handler", Some("networking"), tx_handler.run());
+
+	let (bitswap_client, bitswap_client_handle) =
+		BitswapClient::new(network.clone(), &sync_service);
+	spawn_handle.spawn("bitswap-client", Some("networking"), bitswap_client.run());
 	spawn_handle.spawn(
 		"chain-sync-network-service-provider",
 		Some("networking"),
@@ -1032,6 +1063,7 @@ where
 		tx_handler_controller,
 		NetworkStarter(network_start_tx),
 		sync_service.clone(),
+		bitswap_client_handle,
 	))
 }
 
@@ -0,0 +1,112 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{error::Error, TFullBackend};
+use log::{info, warn};
+use sc_client_api::{Backend, UsageProvider};
+use sp_runtime::traits::{Block as BlockT, NumberFor, One};
+use std::sync::Arc;
+
+/// Outcome of [`check_db`].
+#[derive(Debug, Clone)]
+pub struct CheckDbSummary<N> {
+	/// Number of blocks checked.
+	pub checked_blocks: u64,
+	/// Number of state trie nodes read.
+	pub state_nodes: u64,
+	/// Number of inconsistencies found.
+	pub issues: u64,
+	/// Lowest block with an inconsistency, if any.
+	pub first_inconsistent: Option<N>,
+}
+
+/// Checks the canonical blocks `from..=to` and the block tree metadata, logging every
+/// inconsistency found.
+///
+/// The state trie of each block is walked as well if `check_state` is set and the state of the
+/// block is kept.
+pub fn check_db<B: BlockT>(
+	backend: &TFullBackend<B>,
+	from: NumberFor<B>,
+	to: NumberFor<B>,
+	check_state: bool,
+) -> Result<CheckDbSummary<NumberFor<B>>, Error> {
+	let mut summary =
+		CheckDbSummary { checked_blocks: 0, state_nodes: 0, issues: 0, first_inconsistent: None };
+
+	for issue in backend.check_metadata_integrity()? {
+		warn!("{}", issue);
+		summary.issues += 1;
+	}
+
+	let mut number = from;
+	while number <= to {
+		let report = backend.check_block_integrity(number, check_state)?;
+		if !report.is_consistent() && summary.first_inconsistent.is_none() {
+			summary.first_inconsistent = Some(number);
+		}
+		for issue in report.issues {
+			warn!("#{}: {}", number, issue);
+			summary.issues += 1;
+		}
+		summary.checked_blocks += 1;
+		summary.state_nodes += report.state_nodes.unwrap_or(0);
+
+		if summary.checked_blocks % 1000 == 0 {
+			info!("#{} checked", number);
+		}
+		number += One::one();
+	}
+
+	Ok(summary)
+}
+
+/// Rewinds the chain to the canonical block `target`, reverting finalized blocks if needed.
+///
+/// Fails if the state needed to revert that far is no longer available.
+pub fn repair_db<B, C>(
+	client: Arc<C>,
+	backend: Arc<TFullBackend<B>>,
+	target: NumberFor<B>,
+) -> Result<(), Error>
+where
+	B: BlockT,
+	C: UsageProvider<B>,
+{
+	let info = client.usage_info().chain;
+	if info.best_number <= target {
+		info!("Nothing to revert, the best block is #{}.", info.best_number);
+		return Ok(())
+	}
+
+	let (reverted, _) = backend.revert(info.best_number - target, true)?;
+	let info = client.usage_info().chain;
+	if info.best_number > target {
+		return Err(Error::Other(format!(
+			"Reverted {} blocks, but the state of blocks below #{} is no longer available. \
+			Could not rewind to #{}.",
+			reverted, info.best_number, target,
+		)))
+	}
+
+	info!(
+		"Reverted {} blocks. Best: #{} ({}), finalized: #{} ({})",
+		reverted, info.best_number, info.best_hash, info.finalized_number, info.finalized_hash,
+	);
+	Ok(())
+}
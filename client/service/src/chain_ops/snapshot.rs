@@ -0,0 +1,706 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Binary state snapshots.
+//!
+//! A snapshot starts with [`SNAPSHOT_MAGIC`] and the SCALE encoded format version, followed by a
+//! sequence of frames. Each frame is a SCALE encoded `u32` length and a zstd compressed payload.
+//! The first frame holds the [`SnapshotHeader`]: the block hash, the state root, the state version
+//! and the signed block. The remaining frames hold [`SnapshotChunk`]s with the key/value pairs of
+//! the top trie, including the roots of the child tries, followed by the key/value pairs of every
+//! default child trie, terminated by [`SnapshotChunk::End`].
+//!
+//! The key/value pairs of every trie are in key order, so that the import can build the tries
+//! while reading the snapshot and write their nodes to the database in batches, without holding
+//! the whole state in memory.
+
+use crate::{error::Error, TFullBackend};
+use codec::{Decode, Encode, IoReader as CodecIoReader};
+use futures::{future, prelude::*};
+use log::{info, warn};
+use sc_client_api::{BlockBackend, CallExecutor, ExecutorProvider, HeaderBackend, StorageProvider};
+use sc_consensus::{
+	import_queue::{BlockImportError, BlockImportStatus, ImportQueue, IncomingBlock, Link},
+	ImportedState,
+};
+use sp_consensus::BlockOrigin;
+use sp_core::{
+	storage::{well_known_keys, ChildInfo, StateVersion},
+	Hasher,
+};
+use sp_runtime::{
+	generic::SignedBlock,
+	traits::{Block as BlockT, HashFor, Header as HeaderT, NumberFor},
+};
+use sp_state_machine::KeyValueStates;
+use sp_trie::{
+	trie_visit, ChildReference, HashDBT, KeySpacedDBMut, LayoutV0, LayoutV1, PrefixedMemoryDB,
+	ProcessEncodedNode, TrieLayout,
+};
+use std::{
+	collections::BTreeMap,
+	io::{Read, Write},
+	pin::Pin,
+	sync::Arc,
+	task::Poll,
+};
+
+/// Magic bytes at the start of every snapshot.
+pub const SNAPSHOT_MAGIC: [u8; 8] = *b"substsnp";
+
+/// Version of the snapshot format.
+pub const SNAPSHOT_VERSION: u32 = 1;
+
+/// Size of the uncompressed key/value pairs after which a chunk is written, and of the trie nodes
+/// after which they are written to the database on import.
+const CHUNK_SIZE: usize = 4 * 1024 * 1024;
+
+/// Maximum size of a decompressed frame.
+const MAX_FRAME_SIZE: usize = 64 * 1024 * 1024;
+
+/// Maximum size of a compressed frame, leaving room for the overhead of incompressible payloads.
+const MAX_COMPRESSED_FRAME_SIZE: usize = MAX_FRAME_SIZE + MAX_FRAME_SIZE / 128;
+
+/// Block the snapshot was taken at.
+#[derive(Encode, Decode)]
+pub struct SnapshotHeader<B: BlockT> {
+	/// Hash of the block.
+	pub block_hash: B::Hash,
+	/// State root of the block, must match the one in the header.
+	pub state_root: B::Hash,
+	/// Version of the state, the tries are built with it on import.
+	pub state_version: StateVersion,
+	/// The block with its justifications.
+	pub block: SignedBlock<B>,
+}
+
+/// A chunk of key/value pairs.
+#[derive(Encode, Decode)]
+pub enum SnapshotChunk {
+	/// Key/value pairs of the top trie, including the child trie roots.
+	Top(Vec<(Vec<u8>, Vec<u8>)>),
+	/// Key/value pairs of a default child trie.
+	Child {
+		/// Unprefixed storage key of the child trie.
+		storage_key: Vec<u8>,
+		/// Key/value pairs of the child trie.
+		key_values: Vec<(Vec<u8>, Vec<u8>)>,
+	},
+	/// End of the snapshot.
+	End {
+		/// Number of key/value pairs in the snapshot.
+		entries: u64,
+	},
+}
+
+fn write_frame(output: &mut impl Write, payload: &impl Encode) -> Result<(), Error> {
+	let frame = sp_maybe_compressed_blob::compress(&payload.encode(), MAX_FRAME_SIZE)
+		.ok_or_else(|| Error::Other("Snapshot frame is too large to compress".into()))?;
+	output.write_all(&(frame.len() as u32).encode())?;
+	output.write_all(&frame)?;
+	Ok(())
+}
+
+fn read_frame<T: Decode>(input: &mut CodecIoReader<impl Read>) -> Result<T, Error> {
+	let len = u32::decode(input).map_err(|e| format!("Error reading snapshot frame: {}", e))?;
+	if len as usize > MAX_COMPRESSED_FRAME_SIZE {
+		return Err(format!("Snapshot frame of {} bytes is too large", len).into())
+	}
+	// Only allocate as much as is actually read, the length is not trusted.
+	let mut frame = Vec::new();
+	(&mut input.0).take(len.into()).read_to_end(&mut frame)?;
+	if frame.len() != len as usize {
+		return Err("Snapshot frame is truncated".into())
+	}
+	let payload = sp_maybe_compressed_blob::decompress(&frame, MAX_FRAME_SIZE)
+		.map_err(|e| format!("Error decompressing snapshot frame: {}", e))?;
+	T::decode(&mut &payload[..]).map_err(|e| format!("Error decoding snapshot frame: {}", e).into())
+}
+
+/// Accumulates key/value pairs and writes them out in chunks of about [`CHUNK_SIZE`] bytes.
+struct ChunkWriter<W> {
+	output: W,
+	key_values: Vec<(Vec<u8>, Vec<u8>)>,
+	size: usize,
+	entries: u64,
+}
+
+impl<W: Write> ChunkWriter<W> {
+	fn push(
+		&mut self,
+		key: Vec<u8>,
+		value: Vec<u8>,
+		chunk: impl Fn(Vec<(Vec<u8>, Vec<u8>)>) -> SnapshotChunk,
+	) -> Result<(), Error> {
+		self.size += key.len() + value.len();
+		self.entries += 1;
+		self.key_values.push((key, value));
+		if self.size >= CHUNK_SIZE {
+			self.flush(chunk)?;
+		}
+		Ok(())
+	}
+
+	fn flush(
+		&mut self,
+		chunk: impl Fn(Vec<(Vec<u8>, Vec<u8>)>) -> SnapshotChunk,
+	) -> Result<(), Error> {
+		if !self.key_values.is_empty() {
+			write_frame(&mut self.output, &chunk(std::mem::take(&mut self.key_values)))?;
+			self.size = 0;
+		}
+		Ok(())
+	}
+}
+
+/// Export the state of the block with the given `hash` as a snapshot into `output`.
+pub fn export_snapshot<B, BA, C>(
+	client: Arc<C>,
+	hash: B::Hash,
+	mut output: impl Write,
+) -> Result<(), Error>
+where
+	C: StorageProvider<B, BA> + BlockBackend<B> + ExecutorProvider<B>,
+	B: BlockT,
+	BA: sc_client_api::backend::Backend<B>,
+{
+	let block = client
+		.block(hash)?
+		.ok_or_else(|| Error::Other(format!("Unknown block {:?}", hash)))?;
+	let header = SnapshotHeader::<B> {
+		block_hash: hash,
+		state_root: *block.block.header().state_root(),
+		state_version: CallExecutor::runtime_version(client.executor(), hash)?.state_version(),
+		block,
+	};
+	info!("Exporting state snapshot of block #{} ({})", header.block.block.header().number(), hash);
+
+	output.write_all(&SNAPSHOT_MAGIC)?;
+	output.write_all(&SNAPSHOT_VERSION.encode())?;
+	write_frame(&mut output, &header)?;
+
+	let mut writer = ChunkWriter { output, key_values: Vec::new(), size: 0, entries: 0 };
+	let mut child_storage_keys = Vec::new();
+	for (key, value) in client.storage_pairs(hash, None, None)? {
+		if let Some(storage_key) =
+			key.0.strip_prefix(well_known_keys::DEFAULT_CHILD_STORAGE_KEY_PREFIX)
+		{
+			child_storage_keys.push(storage_key.to_vec());
+		}
+		writer.push(key.0, value.0, SnapshotChunk::Top)?;
+	}
+	writer.flush(SnapshotChunk::Top)?;
+
+	for storage_key in child_storage_keys {
+		let child_info = ChildInfo::new_default(&storage_key);
+		let chunk =
+			|key_values| SnapshotChunk::Child { storage_key: storage_key.clone(), key_values };
+		for child_key in client.child_storage_keys(hash, child_info.clone(), None, None)? {
+			if let Some(child_value) = client.child_storage(hash, &child_info, &child_key)? {
+				writer.push(child_key.0, child_value.0, chunk)?;
+			}
+		}
+		writer.flush(chunk)?;
+	}
+
+	info!("Exported {} key/value pairs", writer.entries);
+	write_frame(&mut writer.output, &SnapshotChunk::End { entries: writer.entries })?;
+	writer.output.flush()?;
+	Ok(())
+}
+
+/// Read the header of a snapshot and check that it is consistent with its block.
+fn read_header<B: BlockT>(
+	input: &mut CodecIoReader<impl Read>,
+) -> Result<SnapshotHeader<B>, Error> {
+	let mut magic = [0u8; 8];
+	input.0.read_exact(&mut magic)?;
+	if magic != SNAPSHOT_MAGIC {
+		return Err("Input is not a state snapshot".into())
+	}
+	let version =
+		u32::decode(input).map_err(|e| format!("Error reading snapshot version: {}", e))?;
+	if version != SNAPSHOT_VERSION {
+		return Err(format!("Unsupported snapshot version {}", version).into())
+	}
+
+	let header: SnapshotHeader<B> = read_frame(input)?;
+	if header.block.block.header().hash() != header.block_hash {
+		return Err("Snapshot block hash does not match its header".into())
+	}
+	if *header.block.block.header().state_root() != header.state_root {
+		return Err("Snapshot state root does not match its header".into())
+	}
+	Ok(header)
+}
+
+/// Reads the chunks of a snapshot after its header.
+struct ChunkReader<R> {
+	input: CodecIoReader<R>,
+	/// Chunk that was read ahead, but belongs to the next trie.
+	next: Option<SnapshotChunk>,
+	entries: u64,
+}
+
+impl<R: Read> ChunkReader<R> {
+	fn next_chunk(&mut self) -> Result<SnapshotChunk, Error> {
+		match self.next.take() {
+			Some(chunk) => Ok(chunk),
+			None => read_frame(&mut self.input),
+		}
+	}
+
+	/// Read the next key/value pairs of the top trie, or of the child trie with the given storage
+	/// key. Returns `None` at the end of the trie.
+	fn next_key_values(
+		&mut self,
+		child: Option<&[u8]>,
+	) -> Result<Option<Vec<(Vec<u8>, Vec<u8>)>>, Error> {
+		let key_values = match (self.next_chunk()?, child) {
+			(SnapshotChunk::Top(key_values), None) => key_values,
+			(SnapshotChunk::Child { storage_key, key_values }, Some(child))
+				if storage_key == child =>
+				key_values,
+			(chunk, _) => {
+				self.next = Some(chunk);
+				return Ok(None)
+			},
+		};
+		self.entries += key_values.len() as u64;
+		Ok(Some(key_values))
+	}
+}
+
+/// Receives the nodes of a trie as it is built and writes them to the database in batches of about
+/// [`CHUNK_SIZE`] bytes.
+struct TrieWriter<'a, B: BlockT> {
+	backend: &'a TFullBackend<B>,
+	keyspace: Option<&'a [u8]>,
+	nodes: PrefixedMemoryDB<HashFor<B>>,
+	size: usize,
+	result: Result<(), Error>,
+	root: Option<B::Hash>,
+}
+
+impl<'a, B: BlockT> TrieWriter<'a, B> {
+	fn insert(&mut self, prefix: (&[u8], Option<u8>), value: &[u8]) -> B::Hash {
+		let hash = match self.keyspace {
+			Some(keyspace) => KeySpacedDBMut::<_, HashFor<B>>::new(&mut self.nodes, keyspace)
+				.insert(prefix, value),
+			None => self.nodes.insert(prefix, value),
+		};
+		self.size += value.len();
+		if self.size >= CHUNK_SIZE {
+			self.flush();
+		}
+		hash
+	}
+
+	fn flush(&mut self) {
+		let nodes = std::mem::take(&mut self.nodes);
+		self.size = 0;
+		if self.result.is_ok() {
+			self.result = self.backend.write_state_nodes(nodes).map_err(Into::into);
+		}
+	}
+}
+
+impl<'a, B: BlockT> ProcessEncodedNode<B::Hash> for TrieWriter<'a, B> {
+	fn process(
+		&mut self,
+		prefix: (&[u8], Option<u8>),
+		encoded_node: Vec<u8>,
+		is_root: bool,
+	) -> ChildReference<B::Hash> {
+		let len = encoded_node.len();
+		if !is_root && len < <HashFor<B> as Hasher>::LENGTH {
+			let mut hash = B::Hash::default();
+			hash.as_mut()[..len].copy_from_slice(&encoded_node);
+			return ChildReference::Inline(hash, len)
+		}
+		let hash = self.insert(prefix, &encoded_node);
+		if is_root {
+			self.root = Some(hash);
+		}
+		ChildReference::Hash(hash)
+	}
+
+	fn process_inner_hashed_value(&mut self, prefix: (&[u8], Option<u8>), value: &[u8]) -> B::Hash {
+		self.insert(prefix, value)
+	}
+}
+
+/// Build the top trie, or the child trie with the given storage key, from the key/value pairs read
+/// from `reader`, write its nodes to the database and return its root.
+///
+/// `on_pair` is called with every key/value pair of the trie.
+fn write_trie<B, L>(
+	backend: &TFullBackend<B>,
+	reader: &mut ChunkReader<impl Read>,
+	child: Option<&[u8]>,
+	mut on_pair: impl FnMut(&[u8], &[u8]),
+) -> Result<B::Hash, Error>
+where
+	B: BlockT,
+	L: TrieLayout<Hash = HashFor<B>>,
+{
+	let child_info = child.map(ChildInfo::new_default);
+	let mut writer = TrieWriter::<B> {
+		backend,
+		keyspace: child_info.as_ref().map(|child_info| child_info.keyspace()),
+		nodes: Default::default(),
+		size: 0,
+		result: Ok(()),
+		root: None,
+	};
+
+	let mut result: Result<(), Error> = Ok(());
+	let mut last_key: Option<Vec<u8>> = None;
+	let mut key_values = Vec::new().into_iter();
+	let pairs = std::iter::from_fn(|| loop {
+		if let Some((key, value)) = key_values.next() {
+			if last_key.as_ref().map_or(false, |last_key| *last_key >= key) {
+				result = Err("Snapshot key/value pairs are not in key order".into());
+				return None
+			}
+			on_pair(&key, &value);
+			last_key = Some(key.clone());
+			return Some((key, value))
+		}
+		match reader.next_key_values(child) {
+			Ok(Some(next)) => key_values = next.into_iter(),
+			Ok(None) => return None,
+			Err(e) => {
+				result = Err(e);
+				return None
+			},
+		}
+	});
+	trie_visit::<L, _, _, _, _>(pairs, &mut writer);
+	result?;
+
+	writer.flush();
+	writer.result?;
+	Ok(writer.root.expect("`trie_visit` always processes a root node; qed"))
+}
+
+/// Write all tries of a snapshot to the database and check them against the state root of its
+/// header.
+fn write_tries<B, L>(
+	backend: &TFullBackend<B>,
+	header: &SnapshotHeader<B>,
+	reader: &mut ChunkReader<impl Read>,
+) -> Result<(), Error>
+where
+	B: BlockT,
+	L: TrieLayout<Hash = HashFor<B>>,
+{
+	let mut child_roots = BTreeMap::new();
+	let root = write_trie::<B, L>(backend, reader, None, |key, value| {
+		if let Some(storage_key) =
+			key.strip_prefix(well_known_keys::DEFAULT_CHILD_STORAGE_KEY_PREFIX)
+		{
+			child_roots.insert(storage_key.to_vec(), value.to_vec());
+		}
+	})?;
+	if root != header.state_root {
+		return Err("Snapshot state does not match its state root".into())
+	}
+
+	loop {
+		match reader.next_chunk()? {
+			SnapshotChunk::Child { storage_key, key_values } => {
+				let expected_root = child_roots
+					.remove(&storage_key)
+					.ok_or("Snapshot contains an unexpected child trie")?;
+				// Put the first chunk of the child trie back, to be read with the others.
+				reader.next =
+					Some(SnapshotChunk::Child { storage_key: storage_key.clone(), key_values });
+				let root = write_trie::<B, L>(backend, reader, Some(&storage_key), |_, _| ())?;
+				if root.as_ref() != &expected_root[..] {
+					return Err("Snapshot child trie does not match its root".into())
+				}
+			},
+			SnapshotChunk::End { entries } if entries == reader.entries => break,
+			SnapshotChunk::End { entries } =>
+				return Err(format!(
+					"Snapshot is truncated: expected {} key/value pairs, found {}",
+					entries, reader.entries
+				)
+				.into()),
+			SnapshotChunk::Top(_) => return Err("Snapshot contains an unexpected top trie".into()),
+		}
+	}
+
+	if !child_roots.is_empty() {
+		return Err(format!("Snapshot is missing {} child tries", child_roots.len()).into())
+	}
+	Ok(())
+}
+
+/// Stream the state of a snapshot into the database of `backend`, after its header was read.
+///
+/// Fails if the state does not match the state root of the header. The nodes written before a
+/// failure are left in the database.
+fn write_state<B: BlockT>(
+	backend: &TFullBackend<B>,
+	header: &SnapshotHeader<B>,
+	input: CodecIoReader<impl Read>,
+) -> Result<(), Error> {
+	let mut reader = ChunkReader { input, next: None, entries: 0 };
+	match header.state_version {
+		StateVersion::V0 => write_tries::<B, LayoutV0<HashFor<B>>>(backend, header, &mut reader)?,
+		StateVersion::V1 => write_tries::<B, LayoutV1<HashFor<B>>>(backend, header, &mut reader)?,
+	}
+	info!("Imported {} key/value pairs", reader.entries);
+	Ok(())
+}
+
+/// Import the block and state of a snapshot through the `import_queue`.
+///
+/// The state is written to the database of `backend` while the snapshot is read, and checked
+/// against the state root of the header. The block is then imported without its parent, so that a
+/// node can start from it.
+pub fn import_snapshot<B, IQ, C>(
+	client: Arc<C>,
+	backend: Arc<TFullBackend<B>>,
+	mut import_queue: IQ,
+	input: impl Read + Send + 'static,
+) -> Pin<Box<dyn Future<Output = Result<(), Error>> + Send>>
+where
+	C: HeaderBackend<B> + Send + Sync + 'static,
+	B: BlockT,
+	IQ: ImportQueue<B> + 'static,
+{
+	struct WaitLink<B: BlockT> {
+		result: Option<Result<BlockImportStatus<NumberFor<B>>, BlockImportError>>,
+	}
+
+	impl<B: BlockT> Link<B> for WaitLink<B> {
+		fn blocks_processed(
+			&mut self,
+			_imported: usize,
+			_num_expected_blocks: usize,
+			results: Vec<(Result<BlockImportStatus<NumberFor<B>>, BlockImportError>, B::Hash)>,
+		) {
+			if let Some((result, _)) = results.into_iter().next() {
+				self.result = Some(result);
+			}
+		}
+	}
+
+	let mut input = CodecIoReader(input);
+	let header = match read_header::<B>(&mut input) {
+		Ok(header) => header,
+		Err(e) => return future::ready(Err(e)).boxed(),
+	};
+	let hash = header.block_hash;
+	let number = *header.block.block.header().number();
+
+	if client.status(hash).map_or(false, |s| s == sp_blockchain::BlockStatus::InChain) {
+		return future::ready(
+			Err(format!("Block #{} ({}) is already in chain", number, hash).into()),
+		)
+		.boxed()
+	}
+
+	info!("Importing state snapshot of block #{} ({})", number, hash);
+	if let Err(e) = write_state(&backend, &header, input) {
+		return future::ready(Err(e)).boxed()
+	}
+
+	let (block_header, body) = header.block.block.deconstruct();
+	import_queue.service_ref().import_blocks(
+		BlockOrigin::File,
+		vec![IncomingBlock::<B> {
+			hash,
+			header: Some(block_header),
+			body: Some(body),
+			indexed_body: None,
+			justifications: header.block.justifications,
+			origin: None,
+			allow_missing_state: true,
+			import_existing: true,
+			state: Some(ImportedState {
+				block: hash,
+				state: KeyValueStates(Vec::new()),
+				written: true,
+			}),
+			skip_execution: true,
+		}],
+	);
+
+	let mut link = WaitLink::<B> { result: None };
+	let import = future::poll_fn(move |cx| {
+		import_queue.poll_actions(cx, &mut link);
+		match link.result.take() {
+			Some(Ok(_)) => {
+				info!("🎉 Imported state snapshot. Best: #{}", client.info().best_number);
+				Poll::Ready(Ok(()))
+			},
+			Some(Err(e)) => {
+				warn!("There was an error importing the snapshot of block {:?}: {}", hash, e);
+				Poll::Ready(Err(Error::Other(format!("Error importing snapshot: {}", e))))
+			},
+			None => {
+				cx.waker().wake_by_ref();
+				Poll::Pending
+			},
+		}
+	});
+	Box::pin(import)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sc_block_builder::BlockBuilderProvider;
+	use sc_client_api::backend::Backend as _;
+	use sc_consensus::{
+		BlockImport, BlockImportParams, ForkChoiceStrategy, StateAction, StorageChanges,
+	};
+	use sp_core::storage::{well_known_keys::CODE, StorageData, StorageKey};
+	use sp_state_machine::Backend as _;
+	use substrate_test_runtime::Block;
+	use substrate_test_runtime_client::{
+		prelude::*, DefaultTestClientBuilderExt, TestClientBuilder, TestClientBuilderExt,
+	};
+
+	fn test_client_builder() -> TestClientBuilder {
+		TestClientBuilder::new().add_extra_child_storage(
+			&ChildInfo::new_default(b"child"),
+			b"key".to_vec(),
+			b"value".to_vec(),
+		)
+	}
+
+	#[test]
+	fn export_and_import_snapshot() {
+		let mut client = test_client_builder().build();
+		let mut builder = client.new_block(Default::default()).unwrap();
+		builder
+			.push_storage_change(b"snapshot".to_vec(), Some(b"value".to_vec()))
+			.unwrap();
+		let block = builder.build().unwrap().block;
+		let hash = block.hash();
+		futures::executor::block_on(client.import(BlockOrigin::Own, block)).unwrap();
+		let client = Arc::new(client);
+
+		let mut snapshot = Vec::new();
+		export_snapshot(client.clone(), hash, &mut snapshot).unwrap();
+
+		let (mut imported, backend) = test_client_builder().build_with_backend();
+		let mut input = CodecIoReader(&snapshot[..]);
+		let header = read_header::<Block>(&mut input).unwrap();
+		assert_eq!(header.block_hash, hash);
+		assert_eq!(header.block, client.block(hash).unwrap().unwrap());
+		write_state(&backend, &header, input).unwrap();
+
+		let (block_header, body) = header.block.block.deconstruct();
+		let mut import = BlockImportParams::new(BlockOrigin::File, block_header);
+		import.body = Some(body);
+		import.state_action = StateAction::ApplyChanges(StorageChanges::Import(ImportedState {
+			block: hash,
+			state: KeyValueStates(Vec::new()),
+			written: true,
+		}));
+		import.fork_choice = Some(ForkChoiceStrategy::LongestChain);
+		futures::executor::block_on(imported.import_block(import, Default::default())).unwrap();
+
+		assert_eq!(imported.info().best_hash, hash);
+		let state = backend.state_at(hash).unwrap();
+		assert_eq!(state.storage_root(std::iter::empty(), StateVersion::V1).0, header.state_root);
+		assert_eq!(
+			imported.storage_pairs(hash, None, None).unwrap().collect::<Vec<_>>(),
+			client.storage_pairs(hash, None, None).unwrap().collect::<Vec<_>>(),
+		);
+		assert!(imported.storage(hash, &StorageKey(CODE.to_vec())).unwrap().is_some());
+		assert_eq!(
+			imported
+				.child_storage(
+					hash,
+					&ChildInfo::new_default(b"child"),
+					&StorageKey(b"key".to_vec())
+				)
+				.unwrap(),
+			Some(StorageData(b"value".to_vec())),
+		);
+
+		// Dropping the end marker is detected.
+		let (_, backend) = test_client_builder().build_with_backend();
+		let mut input = CodecIoReader(&snapshot[..snapshot.len() - 1]);
+		let header = read_header::<Block>(&mut input).unwrap();
+		assert!(write_state(&backend, &header, input).is_err());
+	}
+
+	#[test]
+	fn oversized_frame_is_rejected() {
+		let mut snapshot = SNAPSHOT_MAGIC.to_vec();
+		snapshot.extend(SNAPSHOT_VERSION.encode());
+		snapshot.extend(u32::MAX.encode());
+
+		assert!(matches!(
+			read_header::<Block>(&mut CodecIoReader(&snapshot[..])),
+			Err(Error::Other(e)) if e.contains("too large")
+		));
+	}
+
+	#[test]
+	fn snapshot_with_mismatching_state_root_is_rejected() {
+		let client = Arc::new(TestClientBuilder::new().build());
+		let hash = client.info().genesis_hash;
+		let mut snapshot = SNAPSHOT_MAGIC.to_vec();
+		snapshot.extend(SNAPSHOT_VERSION.encode());
+		let block = client.block(hash).unwrap().unwrap();
+		write_frame(
+			&mut snapshot,
+			&SnapshotHeader::<Block> {
+				block_hash: hash,
+				state_root: Default::default(),
+				state_version: StateVersion::V1,
+				block,
+			},
+		)
+		.unwrap();
+		write_frame(&mut snapshot, &SnapshotChunk::End { entries: 0 }).unwrap();
+
+		assert!(read_header::<Block>(&mut CodecIoReader(&snapshot[..])).is_err());
+	}
+
+	#[test]
+	fn snapshot_with_mismatching_state_is_rejected() {
+		let (client, backend) = TestClientBuilder::new().build_with_backend();
+		let hash = client.info().genesis_hash;
+		let block = client.block(hash).unwrap().unwrap();
+		let header = SnapshotHeader::<Block> {
+			block_hash: hash,
+			state_root: *block.block.header().state_root(),
+			state_version: StateVersion::V1,
+			block,
+		};
+		let mut snapshot = Vec::new();
+		write_frame(&mut snapshot, &SnapshotChunk::Top(vec![(b"key".to_vec(), b"value".to_vec())]))
+			.unwrap();
+		write_frame(&mut snapshot, &SnapshotChunk::End { entries: 1 }).unwrap();
+
+		assert!(matches!(
+			write_state(&backend, &header, CodecIoReader(&snapshot[..])),
+			Err(Error::Other(e)) if e.contains("state root")
+		));
+	}
+}
@@ -664,6 +664,10 @@ where
 
 						Some((main_sc, child_sc))
 					},
+					sc_consensus::StorageChanges::Import(changes) if changes.written => {
+						operation.op.set_written_state()?;
+						None
+					},
 					sc_consensus::StorageChanges::Import(changes) => {
 						let mut storage = sp_storage::Storage::default();
 						for state in changes.state.0.into_iter() {
@@ -40,17 +40,26 @@
 //! # Pruning.
 //! See `RefWindow` for pruning algorithm details. `StateDb` prunes on each canonicalization until
 //! pruning constraints are satisfied.
+//!
+//! # Sparse archive.
+//! In addition to the pruning window, `PruningMode::SparseArchive` keeps the full state of
+//! checkpoint blocks forever. Checkpoints are either every Nth canonical block or blocks selected
+//! by a predicate installed with `StateDb::set_checkpoint_predicate`. Deletions of trie nodes that
+//! were part of the last checkpoint state are dropped instead of being added to the death rows.
+//! To bound the nodes tracked between two checkpoints, a block is also made a checkpoint once too
+//! many have been tracked since the last one. The checkpoints that don't follow from the period
+//! are recorded, so that their state is known to be kept.
 
 mod noncanonical;
 mod pruning;
 #[cfg(test)]
 mod test;
 
-use codec::Codec;
+use codec::{Codec, Decode, Encode};
 use log::trace;
 use noncanonical::NonCanonicalOverlay;
 use parking_lot::RwLock;
-use pruning::{HaveBlock, RefWindow};
+use pruning::{HaveBlock, RefWindow, MAX_CHECKPOINT_SEGMENT_NODES};
 use std::{
 	collections::{hash_map::Entry, HashMap},
 	fmt,
@@ -62,6 +71,8 @@ const PRUNING_MODE: &[u8] = b"mode";
 const PRUNING_MODE_ARCHIVE: &[u8] = b"archive";
 const PRUNING_MODE_ARCHIVE_CANON: &[u8] = b"archive_canonical";
 const PRUNING_MODE_CONSTRAINED: &[u8] = b"constrained";
+const PRUNING_MODE_SPARSE_ARCHIVE: &[u8] = b"sparse_archive";
+const PRUNING_MODE_CHECKPOINTS: &[u8] = b"mode_checkpoints";
 pub(crate) const DEFAULT_MAX_BLOCK_CONSTRAINT: u32 = 256;
 
 /// Database value type.
@@ -224,6 +235,21 @@ pub struct Constraints {
 	pub max_blocks: Option<u32>,
 }
 
+/// Selection of the canonical blocks whose state is kept by `PruningMode::SparseArchive`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Encode, Decode)]
+pub enum Checkpoints {
+	/// Keep the state of every block whose number is a multiple of the given period.
+	Every(u32),
+	/// Keep the state of the blocks accepted by the predicate installed with
+	/// `StateDb::set_checkpoint_predicate`. No block is a checkpoint while no predicate is set.
+	///
+	/// Not selectable from the command line, as only the node knows which blocks to select.
+	External,
+}
+
+/// Predicate deciding whether a canonicalized block is a checkpoint, given its hash and number.
+pub type CheckpointPredicate<BlockHash> = Box<dyn Fn(&BlockHash, u64) -> bool + Send + Sync>;
+
 /// Pruning mode.
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub enum PruningMode {
@@ -233,6 +259,13 @@ pub enum PruningMode {
 	ArchiveAll,
 	/// Canonicalization discards non-canonical nodes. All the canonical nodes are kept in the DB.
 	ArchiveCanonical,
+	/// Maintain a pruning window and keep the state of checkpoint blocks forever.
+	SparseArchive {
+		/// Constraints of the pruning window.
+		constraints: Constraints,
+		/// Blocks whose state is never pruned.
+		checkpoints: Checkpoints,
+	},
 }
 
 impl PruningMode {
@@ -241,11 +274,16 @@ impl PruningMode {
 		PruningMode::Constrained(Constraints { max_blocks: Some(n) })
 	}
 
+	/// Create a mode that keeps given number of blocks and the state of the checkpoint blocks.
+	pub fn sparse_archive(n: u32, checkpoints: Checkpoints) -> PruningMode {
+		PruningMode::SparseArchive { constraints: Constraints { max_blocks: Some(n) }, checkpoints }
+	}
+
 	/// Is this an archive (either ArchiveAll or ArchiveCanonical) pruning mode?
 	pub fn is_archive(&self) -> bool {
 		match *self {
 			PruningMode::ArchiveAll | PruningMode::ArchiveCanonical => true,
-			PruningMode::Constrained(_) => false,
+			PruningMode::Constrained(_) | PruningMode::SparseArchive { .. } => false,
 		}
 	}
 
@@ -255,6 +293,16 @@ impl PruningMode {
 			PruningMode::ArchiveAll => PRUNING_MODE_ARCHIVE,
 			PruningMode::ArchiveCanonical => PRUNING_MODE_ARCHIVE_CANON,
 			PruningMode::Constrained(_) => PRUNING_MODE_CONSTRAINED,
+			PruningMode::SparseArchive { .. } => PRUNING_MODE_SPARSE_ARCHIVE,
+		}
+	}
+
+	/// Returns the pruning window constraints, if this mode maintains a pruning window.
+	fn constraints(&self) -> Option<&Constraints> {
+		match self {
+			PruningMode::Constrained(constraints) |
+			PruningMode::SparseArchive { constraints, .. } => Some(constraints),
+			PruningMode::ArchiveAll | PruningMode::ArchiveCanonical => None,
 		}
 	}
 
@@ -303,6 +351,7 @@ pub struct StateDbSync<BlockHash: Hash, Key: Hash, D: MetaDb> {
 	pruning: Option<RefWindow<BlockHash, Key, D>>,
 	pinned: HashMap<BlockHash, u32>,
 	ref_counting: bool,
+	checkpoint_predicate: Option<CheckpointPredicate<BlockHash>>,
 }
 
 impl<BlockHash: Hash, Key: Hash, D: MetaDb> StateDbSync<BlockHash, Key, D> {
@@ -317,10 +366,24 @@ impl<BlockHash: Hash, Key: Hash, D: MetaDb> StateDbSync<BlockHash, Key, D> {
 		let pruning: Option<RefWindow<BlockHash, Key, D>> = match mode {
 			PruningMode::Constrained(Constraints { max_blocks }) =>
 				Some(RefWindow::new(db, max_blocks.unwrap_or(0), ref_counting)?),
+			PruningMode::SparseArchive { constraints: Constraints { max_blocks }, .. } =>
+				Some(RefWindow::new_sparse(
+					db,
+					max_blocks.unwrap_or(0),
+					ref_counting,
+					MAX_CHECKPOINT_SEGMENT_NODES,
+				)?),
 			PruningMode::ArchiveAll | PruningMode::ArchiveCanonical => None,
 		};
 
-		Ok(StateDbSync { mode, non_canonical, pruning, pinned: Default::default(), ref_counting })
+		Ok(StateDbSync {
+			mode,
+			non_canonical,
+			pruning,
+			pinned: Default::default(),
+			ref_counting,
+			checkpoint_predicate: None,
+		})
 	}
 
 	fn insert_block(
@@ -336,7 +399,9 @@ impl<BlockHash: Hash, Key: Hash, D: MetaDb> StateDbSync<BlockHash, Key, D> {
 				// write changes immediately
 				Ok(CommitSet { data: changeset, meta: Default::default() })
 			},
-			PruningMode::Constrained(_) | PruningMode::ArchiveCanonical => self
+			PruningMode::Constrained(_) |
+			PruningMode::ArchiveCanonical |
+			PruningMode::SparseArchive { .. } => self
 				.non_canonical
 				.insert(hash, number, parent_hash, changeset)
 				.map_err(Into::into),
@@ -357,6 +422,22 @@ impl<BlockHash: Hash, Key: Hash, D: MetaDb> StateDbSync<BlockHash, Key, D> {
 			commit.data.deleted.clear();
 		}
 		if let Some(ref mut pruning) = self.pruning {
+			if let PruningMode::SparseArchive { checkpoints, .. } = self.mode {
+				let (is_checkpoint, record_checkpoint) = match checkpoints {
+					Checkpoints::Every(period) =>
+						(period != 0 && number % period as u64 == 0, false),
+					Checkpoints::External => (
+						self.checkpoint_predicate.as_ref().map_or(false, |p| p(hash, number)),
+						true,
+					),
+				};
+				pruning.retain_checkpoint_state(
+					number,
+					is_checkpoint,
+					record_checkpoint,
+					&mut commit,
+				)?;
+			}
 			pruning.note_canonical(hash, number, &mut commit)?;
 		}
 		self.prune(&mut commit)?;
@@ -378,7 +459,9 @@ impl<BlockHash: Hash, Key: Hash, D: MetaDb> StateDbSync<BlockHash, Key, D> {
 	fn is_pruned(&self, hash: &BlockHash, number: u64) -> IsPruned {
 		match self.mode {
 			PruningMode::ArchiveAll => IsPruned::NotPruned,
-			PruningMode::ArchiveCanonical | PruningMode::Constrained(_) => {
+			PruningMode::ArchiveCanonical |
+			PruningMode::Constrained(_) |
+			PruningMode::SparseArchive { .. } => {
 				if self
 					.non_canonical
 					.last_canonicalized_block_number()
@@ -395,6 +478,8 @@ impl<BlockHash: Hash, Key: Hash, D: MetaDb> StateDbSync<BlockHash, Key, D> {
 						// We don't know for sure.
 						None => IsPruned::MaybePruned,
 						Some(pruning) => match pruning.have_block(hash, number) {
+							HaveBlock::No if self.may_be_checkpoint(number) =>
+								IsPruned::MaybePruned,
 							HaveBlock::No => IsPruned::Pruned,
 							HaveBlock::Yes => IsPruned::NotPruned,
 							HaveBlock::Maybe => IsPruned::MaybePruned,
@@ -405,9 +490,25 @@ impl<BlockHash: Hash, Key: Hash, D: MetaDb> StateDbSync<BlockHash, Key, D> {
 		}
 	}
 
+	/// Returns `true` if the state of the canonical block with the given number may have been kept
+	/// as a checkpoint after leaving the pruning window.
+	fn may_be_checkpoint(&self, number: u64) -> bool {
+		let is_recorded = || {
+			self.pruning
+				.as_ref()
+				.map_or(false, |pruning| pruning.is_recorded_checkpoint(number))
+		};
+		match self.mode {
+			PruningMode::SparseArchive { checkpoints: Checkpoints::Every(period), .. } =>
+				(period != 0 && number % period as u64 == 0) || is_recorded(),
+			PruningMode::SparseArchive { checkpoints: Checkpoints::External, .. } => is_recorded(),
+			_ => false,
+		}
+	}
+
 	fn prune(&mut self, commit: &mut CommitSet<Key>) -> Result<(), Error<D::Error>> {
-		if let (&mut Some(ref mut pruning), &PruningMode::Constrained(ref constraints)) =
-			(&mut self.pruning, &self.mode)
+		if let (&mut Some(ref mut pruning), Some(constraints)) =
+			(&mut self.pruning, self.mode.constraints())
 		{
 			loop {
 				if pruning.window_size() <= constraints.max_blocks.unwrap_or(0) as u64 {
@@ -440,16 +541,18 @@ impl<BlockHash: Hash, Key: Hash, D: MetaDb> StateDbSync<BlockHash, Key, D> {
 	fn revert_one(&mut self) -> Option<CommitSet<Key>> {
 		match self.mode {
 			PruningMode::ArchiveAll => Some(CommitSet::default()),
-			PruningMode::ArchiveCanonical | PruningMode::Constrained(_) =>
-				self.non_canonical.revert_one(),
+			PruningMode::ArchiveCanonical |
+			PruningMode::Constrained(_) |
+			PruningMode::SparseArchive { .. } => self.non_canonical.revert_one(),
 		}
 	}
 
 	fn remove(&mut self, hash: &BlockHash) -> Option<CommitSet<Key>> {
 		match self.mode {
 			PruningMode::ArchiveAll => Some(CommitSet::default()),
-			PruningMode::ArchiveCanonical | PruningMode::Constrained(_) =>
-				self.non_canonical.remove(hash),
+			PruningMode::ArchiveCanonical |
+			PruningMode::Constrained(_) |
+			PruningMode::SparseArchive { .. } => self.non_canonical.remove(hash),
 		}
 	}
 
@@ -459,11 +562,14 @@ impl<BlockHash: Hash, Key: Hash, D: MetaDb> StateDbSync<BlockHash, Key, D> {
 	{
 		match self.mode {
 			PruningMode::ArchiveAll => Ok(()),
-			PruningMode::ArchiveCanonical | PruningMode::Constrained(_) => {
+			PruningMode::ArchiveCanonical |
+			PruningMode::Constrained(_) |
+			PruningMode::SparseArchive { .. } => {
 				let have_block = self.non_canonical.have_block(hash) ||
 					self.pruning.as_ref().map_or_else(
 						|| hint(),
 						|pruning| match pruning.have_block(hash, number) {
+							HaveBlock::No if self.may_be_checkpoint(number) => hint(),
 							HaveBlock::No => false,
 							HaveBlock::Yes => true,
 							HaveBlock::Maybe => hint(),
@@ -562,6 +668,12 @@ impl<BlockHash: Hash, Key: Hash, D: MetaDb> StateDb<BlockHash, Key, D> {
 
 			cs.meta.inserted.push((key, value));
 
+			if let PruningMode::SparseArchive { checkpoints, .. } = &selected_mode {
+				cs.meta
+					.inserted
+					.push((to_meta_key(PRUNING_MODE_CHECKPOINTS, &()), checkpoints.encode()));
+			}
+
 			cs
 		} else {
 			Default::default()
@@ -577,6 +689,13 @@ impl<BlockHash: Hash, Key: Hash, D: MetaDb> StateDb<BlockHash, Key, D> {
 		self.db.read().mode.clone()
 	}
 
+	/// Set the predicate selecting checkpoint blocks for `Checkpoints::External`.
+	///
+	/// The predicate is called once for each canonicalized block.
+	pub fn set_checkpoint_predicate(&self, predicate: CheckpointPredicate<BlockHash>) {
+		self.db.write().checkpoint_predicate = Some(predicate);
+	}
+
 	/// Add a new non-canonical block.
 	pub fn insert_block(
 		&self,
@@ -653,7 +772,9 @@ impl<BlockHash: Hash, Key: Hash, D: MetaDb> StateDb<BlockHash, Key, D> {
 	/// Reset in-memory changes to the last disk-backed state.
 	pub fn reset(&self, db: D) -> Result<(), Error<D::Error>> {
 		let mut state_db = self.db.write();
+		let checkpoint_predicate = state_db.checkpoint_predicate.take();
 		*state_db = StateDbSync::new(state_db.mode.clone(), state_db.ref_counting, db)?;
+		state_db.checkpoint_predicate = checkpoint_predicate;
 		Ok(())
 	}
 }
@@ -672,7 +793,18 @@ pub enum IsPruned {
 fn fetch_stored_pruning_mode<D: MetaDb>(db: &D) -> Result<Option<PruningMode>, Error<D::Error>> {
 	let meta_key_mode = to_meta_key(PRUNING_MODE, &());
 	if let Some(stored_mode) = db.get_meta(&meta_key_mode).map_err(Error::Db)? {
-		if let Some(mode) = PruningMode::from_id(&stored_mode) {
+		if stored_mode == PRUNING_MODE_SPARSE_ARCHIVE {
+			let meta_key_checkpoints = to_meta_key(PRUNING_MODE_CHECKPOINTS, &());
+			let checkpoints = match db.get_meta(&meta_key_checkpoints).map_err(Error::Db)? {
+				Some(checkpoints) => Checkpoints::decode(&mut checkpoints.as_slice())?,
+				None =>
+					return Err(StateDbError::Metadata(
+						"Sparse archive StateDb does not have its checkpoints stored".into(),
+					)
+					.into()),
+			};
+			Ok(Some(PruningMode::SparseArchive { constraints: Default::default(), checkpoints }))
+		} else if let Some(mode) = PruningMode::from_id(&stored_mode) {
 			Ok(Some(mode))
 		} else {
 			Err(StateDbError::Metadata(format!(
@@ -696,6 +828,11 @@ fn choose_pruning_mode(
 			Ok(PruningMode::ArchiveCanonical),
 		(PruningMode::Constrained(_), PruningMode::Constrained(requested)) =>
 			Ok(PruningMode::Constrained(requested)),
+		(
+			PruningMode::SparseArchive { checkpoints: stored, .. },
+			PruningMode::SparseArchive { constraints, checkpoints: requested },
+		) if stored == requested =>
+			Ok(PruningMode::SparseArchive { constraints, checkpoints: requested }),
 		(stored, requested) => Err(StateDbError::IncompatiblePruningModes { requested, stored }),
 	}
 }
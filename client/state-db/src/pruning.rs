@@ -23,6 +23,14 @@
 //! If a node is re-inserted into the window it gets removed from
 //! the death list.
 //! The changes are journaled in the DB.
+//!
+//! In sparse archive mode the window also tracks the nodes inserted since the last checkpoint
+//! block. A deletion is only added to a death row when it is matched by such an insertion;
+//! any other deleted node is part of the checkpoint state and is retained. The tracked
+//! insertions are journaled per block until the next checkpoint. A segment tracking too many
+//! nodes is ended early by making the next block a checkpoint. Such checkpoints, and the ones
+//! that are requested to be recorded, are journaled so that they can still be told apart once
+//! they have left the window.
 
 use crate::{
 	noncanonical::LAST_CANONICAL, to_meta_key, CommitSet, Error, Hash, MetaDb, StateDbError,
@@ -30,10 +38,17 @@ use crate::{
 };
 use codec::{Decode, Encode};
 use log::trace;
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
 
 pub(crate) const LAST_PRUNED: &[u8] = b"last_pruned";
 const PRUNING_JOURNAL: &[u8] = b"pruning_journal";
+const CHECKPOINT_SEGMENT_START: &[u8] = b"checkpoint_segment_start";
+const CHECKPOINT_JOURNAL: &[u8] = b"checkpoint_journal";
+const RECORDED_CHECKPOINTS: &[u8] = b"recorded_checkpoints";
+
+/// Maximal number of nodes tracked by a checkpoint segment before the next canonical block is
+/// made a checkpoint.
+pub(crate) const MAX_CHECKPOINT_SEGMENT_NODES: usize = 1 << 20;
 
 /// See module documentation.
 pub struct RefWindow<BlockHash: Hash, Key: Hash, D: MetaDb> {
@@ -42,6 +57,94 @@ pub struct RefWindow<BlockHash: Hash, Key: Hash, D: MetaDb> {
 	queue: DeathRowQueue<BlockHash, Key, D>,
 	/// Block number that is next to be pruned.
 	base: u64,
+	/// Nodes inserted since the last checkpoint, only present in sparse archive mode.
+	segment: Option<CheckpointSegment<Key>>,
+	/// Maximal number of nodes tracked by `segment`.
+	max_segment_nodes: usize,
+	/// Numbers of the recorded checkpoint blocks.
+	recorded_checkpoints: BTreeSet<u64>,
+}
+
+/// Changes made to the database since the last checkpoint block.
+#[derive(Debug, Default, PartialEq, Eq)]
+struct CheckpointSegment<Key: Hash> {
+	/// Number of the first block in the segment, `None` if no block has been noted yet.
+	start: Option<u64>,
+	/// Nodes inserted in the segment, with the number of insertions not yet matched by a
+	/// deletion.
+	inserted: HashMap<Key, u32>,
+	/// Nodes of the checkpoint state that have been deleted in the segment. They are never
+	/// added to a death row until the next checkpoint, even if they are re-inserted meanwhile.
+	retained: HashSet<Key>,
+}
+
+impl<Key: Hash> CheckpointSegment<Key> {
+	/// Restore the segment from the checkpoint journal.
+	fn new<D: MetaDb>(db: &D) -> Result<CheckpointSegment<Key>, Error<D::Error>> {
+		let mut segment = CheckpointSegment::default();
+		let start =
+			match db.get_meta(&to_meta_key(CHECKPOINT_SEGMENT_START, &())).map_err(Error::Db)? {
+				Some(buffer) => u64::decode(&mut buffer.as_slice())?,
+				None => return Ok(segment),
+			};
+		segment.start = Some(start);
+		let mut block = start;
+		while let Some(record) =
+			db.get_meta(&to_checkpoint_journal_key(block)).map_err(Error::Db)?
+		{
+			let record: CheckpointJournalRecord<Key> = Decode::decode(&mut record.as_slice())?;
+			trace!(
+				target: LOG_TARGET,
+				"Checkpoint journal entry {} ({} inserted, {} deleted, {} retained)",
+				block,
+				record.inserted.len(),
+				record.deleted.len(),
+				record.retained.len(),
+			);
+			segment.apply(record);
+			block += 1;
+		}
+		Ok(segment)
+	}
+
+	/// Number of nodes tracked by the segment.
+	fn len(&self) -> usize {
+		self.inserted.len() + self.retained.len()
+	}
+
+	fn apply(&mut self, record: CheckpointJournalRecord<Key>) {
+		for k in record.deleted {
+			self.remove_insertion(&k);
+		}
+		self.retained.extend(record.retained);
+		for k in record.inserted {
+			*self.inserted.entry(k).or_default() += 1;
+		}
+	}
+
+	fn remove_insertion(&mut self, key: &Key) -> bool {
+		match self.inserted.get_mut(key) {
+			Some(count) => {
+				*count -= 1;
+				if *count == 0 {
+					self.inserted.remove(key);
+				}
+				true
+			},
+			None => false,
+		}
+	}
+}
+
+#[derive(Encode, Decode, Default)]
+struct CheckpointJournalRecord<Key: Hash> {
+	inserted: Vec<Key>,
+	deleted: Vec<Key>,
+	retained: Vec<Key>,
+}
+
+fn to_checkpoint_journal_key(block: u64) -> Vec<u8> {
+	to_meta_key(CHECKPOINT_JOURNAL, &block)
 }
 
 /// `DeathRowQueue` used to keep track of blocks in the pruning window, there are two flavors:
@@ -347,7 +450,42 @@ impl<BlockHash: Hash, Key: Hash, D: MetaDb> RefWindow<BlockHash, Key, D> {
 			DeathRowQueue::new_db_backed(db, base, last, window_size)?
 		};
 
-		Ok(RefWindow { queue, base })
+		Ok(RefWindow {
+			queue,
+			base,
+			segment: None,
+			max_segment_nodes: 0,
+			recorded_checkpoints: Default::default(),
+		})
+	}
+
+	/// Create a pruning window that also keeps the state of checkpoint blocks.
+	///
+	/// A block is made a checkpoint whenever more than `max_segment_nodes` nodes are tracked since
+	/// the last one.
+	pub fn new_sparse(
+		db: D,
+		window_size: u32,
+		count_insertions: bool,
+		max_segment_nodes: usize,
+	) -> Result<RefWindow<BlockHash, Key, D>, Error<D::Error>> {
+		let segment = CheckpointSegment::new(&db)?;
+		let recorded_checkpoints =
+			match db.get_meta(&to_meta_key(RECORDED_CHECKPOINTS, &())).map_err(Error::Db)? {
+				Some(buffer) => Vec::<u64>::decode(&mut buffer.as_slice())?.into_iter().collect(),
+				None => Default::default(),
+			};
+		let mut window = RefWindow::new(db, window_size, count_insertions)?;
+		window.segment = Some(segment);
+		window.max_segment_nodes = max_segment_nodes;
+		window.recorded_checkpoints = recorded_checkpoints;
+		Ok(window)
+	}
+
+	/// Returns `true` if block `number` has been made a checkpoint that was recorded, either
+	/// because it was requested or because its segment tracked too many nodes.
+	pub fn is_recorded_checkpoint(&self, number: u64) -> bool {
+		self.recorded_checkpoints.contains(&number)
 	}
 
 	pub fn window_size(&self) -> u64 {
@@ -398,6 +536,97 @@ impl<BlockHash: Hash, Key: Hash, D: MetaDb> RefWindow<BlockHash, Key, D> {
 		}
 	}
 
+	/// Remove the deletions of nodes that belong to the last checkpoint state from `commit` and
+	/// journal the remaining changes of the checkpoint segment. If `is_checkpoint` is set, or the
+	/// segment tracks too many nodes, the state of block `number` becomes the new checkpoint
+	/// state.
+	///
+	/// A checkpoint is recorded if `record_checkpoint` is set, e.g. because it cannot be derived
+	/// from its number, and always when it is forced by the size of the segment.
+	///
+	/// Must be called before `note_canonical` for the same block. Does nothing unless the window
+	/// was created with `new_sparse`.
+	pub fn retain_checkpoint_state(
+		&mut self,
+		number: u64,
+		is_checkpoint: bool,
+		record_checkpoint: bool,
+		commit: &mut CommitSet<Key>,
+	) -> Result<(), Error<D::Error>> {
+		let segment = match self.segment.as_mut() {
+			Some(segment) => segment,
+			None => return Ok(()),
+		};
+		let new_segment = segment.start.is_none();
+		let start = *segment.start.get_or_insert(number);
+		if start > number {
+			return Err(Error::StateDb(StateDbError::InvalidBlockNumber))
+		}
+		let (is_checkpoint, record_checkpoint) =
+			if !is_checkpoint && segment.len() >= self.max_segment_nodes {
+				log::debug!(
+					target: LOG_TARGET,
+					"Checkpoint segment since #{} tracks {} nodes, making #{} a checkpoint",
+					start,
+					segment.len(),
+					number,
+				);
+				(true, true)
+			} else {
+				(is_checkpoint, record_checkpoint)
+			};
+
+		let mut record = CheckpointJournalRecord::default();
+		for k in std::mem::take(&mut commit.data.deleted) {
+			if !segment.retained.contains(&k) && segment.remove_insertion(&k) {
+				record.deleted.push(k);
+			} else {
+				segment.retained.insert(k.clone());
+				record.retained.push(k);
+			}
+		}
+		commit.data.deleted = record.deleted.clone();
+		trace!(
+			target: LOG_TARGET,
+			"Retaining {} nodes of the checkpoint state at #{}",
+			record.retained.len(),
+			number,
+		);
+
+		if is_checkpoint {
+			trace!(target: LOG_TARGET, "Checkpoint at #{}", number);
+			for block in start..number {
+				commit.meta.deleted.push(to_checkpoint_journal_key(block));
+			}
+			commit
+				.meta
+				.inserted
+				.push((to_meta_key(CHECKPOINT_SEGMENT_START, &()), (number + 1).encode()));
+			*segment = CheckpointSegment { start: Some(number + 1), ..Default::default() };
+			if record_checkpoint {
+				self.recorded_checkpoints.insert(number);
+				let recorded: Vec<u64> = self.recorded_checkpoints.iter().copied().collect();
+				commit
+					.meta
+					.inserted
+					.push((to_meta_key(RECORDED_CHECKPOINTS, &()), recorded.encode()));
+			}
+		} else {
+			record.inserted = commit.data.inserted.iter().map(|(k, _)| k.clone()).collect();
+			for k in record.inserted.iter() {
+				*segment.inserted.entry(k.clone()).or_default() += 1;
+			}
+			if new_segment {
+				commit
+					.meta
+					.inserted
+					.push((to_meta_key(CHECKPOINT_SEGMENT_START, &()), start.encode()));
+			}
+			commit.meta.inserted.push((to_checkpoint_journal_key(number), record.encode()));
+		}
+		Ok(())
+	}
+
 	/// Add a change set to the window. Creates a journal record and pushes it to `commit`
 	pub fn note_canonical(
 		&mut self,
@@ -444,6 +673,28 @@ mod tests {
 		assert_eq!(pruning.queue.get_mem_queue_state(), restored.queue.get_mem_queue_state());
 	}
 
+	#[test]
+	fn sparse_window_bounds_checkpoint_segment() {
+		let mut db = make_db(&[0]);
+		let mut pruning: RefWindow<H256, H256, TestDb> =
+			RefWindow::new_sparse(db.clone(), 0, true, 2).unwrap();
+		for n in 1..=3 {
+			let mut commit = make_commit(&[n], &[n - 1]);
+			pruning.retain_checkpoint_state(n, false, false, &mut commit).unwrap();
+			pruning.note_canonical(&H256::random(), n, &mut commit).unwrap();
+			pruning.prune_one(&mut commit).unwrap();
+			db.commit(&commit);
+		}
+		// Node 0 was retained and node 1 inserted by block 1, so block 2 became a checkpoint.
+		assert!(db.data_eq(&make_db(&[0, 2, 3])));
+		// The forced checkpoint is recorded, also after a restart.
+		assert!(pruning.is_recorded_checkpoint(2));
+		assert!(!pruning.is_recorded_checkpoint(1));
+		let restored: RefWindow<H256, H256, TestDb> =
+			RefWindow::new_sparse(db.clone(), 0, true, 2).unwrap();
+		assert!(restored.is_recorded_checkpoint(2));
+	}
+
 	#[test]
 	fn created_from_empty_db() {
 		let db = make_db(&[]);
@@ -96,3 +96,179 @@ pub fn make_db(inserted: &[u64]) -> TestDb {
 		meta: Default::default(),
 	})))
 }
+
+#[cfg(test)]
+mod tests {
+	use super::{make_changeset, make_db, TestDb};
+	use crate::{Checkpoints, Error, IsPruned, PruningMode, StateDb, StateDbError};
+	use sp_core::H256;
+
+	fn open(
+		db: &mut TestDb,
+		mode: Option<PruningMode>,
+		should_init: bool,
+	) -> StateDb<H256, H256, TestDb> {
+		let (init, state_db) = StateDb::open(db.clone(), mode, true, should_init).unwrap();
+		db.commit(&init);
+		state_db
+	}
+
+	/// Import and canonicalize block `n`, which replaces node `n - 1` with node `n`.
+	fn import_block(db: &mut TestDb, state_db: &StateDb<H256, H256, TestDb>, n: u64) {
+		db.commit(
+			&state_db
+				.insert_block(
+					&H256::from_low_u64_be(n),
+					n,
+					&H256::from_low_u64_be(n - 1),
+					make_changeset(&[n], &[n - 1]),
+				)
+				.unwrap(),
+		);
+		db.commit(&state_db.canonicalize_block(&H256::from_low_u64_be(n)).unwrap());
+	}
+
+	#[test]
+	fn sparse_archive_keeps_checkpoints() {
+		let mut db = make_db(&[0]);
+		let state_db =
+			open(&mut db, Some(PruningMode::sparse_archive(0, Checkpoints::Every(2))), true);
+		for n in 1..=6 {
+			import_block(&mut db, &state_db, n);
+		}
+		assert!(db.data_eq(&make_db(&[0, 2, 4, 6])));
+		assert_eq!(state_db.is_pruned(&H256::from_low_u64_be(3), 3), IsPruned::Pruned);
+		assert_eq!(state_db.is_pruned(&H256::from_low_u64_be(4), 4), IsPruned::MaybePruned);
+	}
+
+	#[test]
+	fn sparse_archive_keeps_window() {
+		let mut db = make_db(&[0]);
+		let state_db =
+			open(&mut db, Some(PruningMode::sparse_archive(2, Checkpoints::Every(3))), true);
+		for n in 1..=7 {
+			import_block(&mut db, &state_db, n);
+		}
+		assert!(db.data_eq(&make_db(&[0, 3, 5, 6, 7])));
+		assert_eq!(state_db.is_pruned(&H256::from_low_u64_be(6), 6), IsPruned::NotPruned);
+	}
+
+	#[test]
+	fn sparse_archive_journal_survives_restart() {
+		let mut db = make_db(&[0]);
+		let state_db =
+			open(&mut db, Some(PruningMode::sparse_archive(1, Checkpoints::Every(4))), true);
+		for n in 1..=5 {
+			import_block(&mut db, &state_db, n);
+		}
+		std::mem::drop(state_db);
+
+		let state_db = open(&mut db, None, false);
+		assert_eq!(
+			state_db.pruning_mode(),
+			PruningMode::sparse_archive(256, Checkpoints::Every(4))
+		);
+		let state_db = {
+			std::mem::drop(state_db);
+			open(&mut db, Some(PruningMode::sparse_archive(1, Checkpoints::Every(4))), false)
+		};
+		for n in 6..=9 {
+			import_block(&mut db, &state_db, n);
+		}
+		assert!(db.data_eq(&make_db(&[0, 4, 8, 9])));
+	}
+
+	#[test]
+	fn sparse_archive_retains_reinserted_checkpoint_nodes() {
+		let mut db = make_db(&[10, 20]);
+		let state_db =
+			open(&mut db, Some(PruningMode::sparse_archive(0, Checkpoints::Every(4))), true);
+		// Node 10 belongs to the initial checkpoint state, is replaced, re-inserted and replaced
+		// again within the same segment.
+		for (n, changeset) in [
+			(1, make_changeset(&[11], &[10])),
+			(2, make_changeset(&[10], &[11])),
+			(3, make_changeset(&[12], &[10])),
+			(4, make_changeset(&[21], &[20])),
+			(5, make_changeset(&[], &[12])),
+		] {
+			db.commit(
+				&state_db
+					.insert_block(
+						&H256::from_low_u64_be(n),
+						n,
+						&H256::from_low_u64_be(n - 1),
+						changeset,
+					)
+					.unwrap(),
+			);
+			db.commit(&state_db.canonicalize_block(&H256::from_low_u64_be(n)).unwrap());
+		}
+		assert!(db.data_eq(&make_db(&[10, 12, 20, 21])));
+	}
+
+	#[test]
+	fn sparse_archive_external_checkpoints() {
+		let mut db = make_db(&[0]);
+		let state_db =
+			open(&mut db, Some(PruningMode::sparse_archive(0, Checkpoints::External)), true);
+		state_db.set_checkpoint_predicate(Box::new(|hash, number| {
+			number == 3 && *hash == H256::from_low_u64_be(3)
+		}));
+		for n in 1..=5 {
+			import_block(&mut db, &state_db, n);
+		}
+		assert!(db.data_eq(&make_db(&[0, 3, 5])));
+		assert_eq!(state_db.is_pruned(&H256::from_low_u64_be(2), 2), IsPruned::Pruned);
+		assert_eq!(state_db.is_pruned(&H256::from_low_u64_be(3), 3), IsPruned::MaybePruned);
+		std::mem::drop(state_db);
+
+		// The selected checkpoints are still known after a restart.
+		let state_db = open(&mut db, None, false);
+		assert_eq!(state_db.is_pruned(&H256::from_low_u64_be(3), 3), IsPruned::MaybePruned);
+	}
+
+	#[test]
+	fn sparse_archive_mode_compatibility() {
+		let mut db = make_db(&[]);
+		std::mem::drop(open(
+			&mut db,
+			Some(PruningMode::sparse_archive(256, Checkpoints::Every(100))),
+			true,
+		));
+
+		for (requested, expected) in [
+			(None, Ok(PruningMode::sparse_archive(256, Checkpoints::Every(100)))),
+			(
+				Some(PruningMode::sparse_archive(128, Checkpoints::Every(100))),
+				Ok(PruningMode::sparse_archive(128, Checkpoints::Every(100))),
+			),
+			(Some(PruningMode::sparse_archive(256, Checkpoints::Every(50))), Err(())),
+			(Some(PruningMode::sparse_archive(256, Checkpoints::External)), Err(())),
+			(Some(PruningMode::blocks_pruning(256)), Err(())),
+			(Some(PruningMode::ArchiveAll), Err(())),
+			(Some(PruningMode::ArchiveCanonical), Err(())),
+		] {
+			let result = StateDb::<H256, H256, TestDb>::open(db.clone(), requested, true, false);
+			match expected {
+				Ok(mode) => assert_eq!(result.unwrap().1.pruning_mode(), mode),
+				Err(()) => assert!(matches!(
+					result,
+					Err(Error::StateDb(StateDbError::IncompatiblePruningModes { .. }))
+				)),
+			}
+		}
+
+		let mut db = make_db(&[]);
+		std::mem::drop(open(&mut db, Some(PruningMode::blocks_pruning(256)), true));
+		assert!(matches!(
+			StateDb::<H256, H256, TestDb>::open(
+				db,
+				Some(PruningMode::sparse_archive(256, Checkpoints::Every(100))),
+				true,
+				false
+			),
+			Err(Error::StateDb(StateDbError::IncompatiblePruningModes { .. }))
+		));
+	}
+}
@@ -85,6 +85,10 @@ impl pallet_balances::Config for Test {
 	type MaxLocks = MaxLocks;
 	type MaxReserves = ();
 	type ReserveIdentifier = [u8; 8];
+	type HoldIdentifier = ();
+	type FreezeIdentifier = ();
+	type MaxHolds = ();
+	type MaxFreezes = ();
 }
 
 const MOTION_DURATION_IN_BLOCKS: BlockNumber = 3;
@@ -103,6 +107,7 @@ impl pallet_collective::Config<AllianceCollective> for Test {
 	type MaxProposals = MaxProposals;
 	type MaxMembers = MaxMembers;
 	type DefaultVote = pallet_collective::PrimeDefaultVote;
+	type VoteWeight = ();
 	type WeightInfo = ();
 	type SetMembersOrigin = EnsureRoot<Self::AccountId>;
 }
@@ -137,6 +142,11 @@ impl pallet_identity::Config for Test {
 	type Slashed = ();
 	type RegistrarOrigin = EnsureOneOrRoot;
 	type ForceOrigin = EnsureTwoOrRoot;
+	type UsernameAuthorityOrigin = EnsureRoot<AccountId>;
+	type PendingUsernameExpiration = frame_support::traits::ConstU64<100>;
+	type MaxSuffixLength = frame_support::traits::ConstU32<7>;
+	type MaxUsernameLength = frame_support::traits::ConstU32<32>;
+	type MaxUsernamesPerAccount = frame_support::traits::ConstU32<2>;
 	type WeightInfo = ();
 }
 
@@ -0,0 +1,194 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Asset Conversion pallet benchmarking.
+
+use super::*;
+use frame_benchmarking::{benchmarks, whitelisted_caller};
+use frame_support::{
+	assert_ok,
+	traits::{fungible::Mutate as MutateFungible, Get},
+};
+use frame_system::RawOrigin as SystemOrigin;
+
+use crate::Pallet as AssetConversion;
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+/// A native amount `factor` times the existential deposit.
+fn native<T: Config>(factor: u32) -> T::Balance {
+	T::Currency::minimum_balance().max(1u32.into()).saturating_mul(factor.into())
+}
+
+/// Fund `caller` and create the `seed`-th asset with some of it minted to `caller`.
+fn create_asset<T: Config>(caller: &T::AccountId, seed: u32) -> NativeOrAssetIdOf<T>
+where
+	T::Currency: MutateFungible<T::AccountId>,
+	T::Assets: Create<T::AccountId> + Mutate<T::AccountId>,
+{
+	assert_ok!(T::Currency::mint_into(caller, native::<T>(1_000_000)));
+	let asset = T::BenchmarkHelper::asset_id(seed);
+	if !T::Assets::asset_exists(asset) {
+		assert_ok!(T::Assets::create(asset, caller.clone(), true, 1u32.into()));
+	}
+	assert_ok!(T::Assets::mint_into(asset, caller, native::<T>(1_000_000)));
+	NativeOrAssetId::Asset(asset)
+}
+
+/// Create the pool of `asset1` and `asset2` and provide it with liquidity from `caller`.
+fn create_pool_with_liquidity<T: Config>(
+	caller: &T::AccountId,
+	asset1: NativeOrAssetIdOf<T>,
+	asset2: NativeOrAssetIdOf<T>,
+) {
+	let origin = SystemOrigin::Signed(caller.clone());
+	assert_ok!(AssetConversion::<T>::create_pool(origin.clone().into(), asset1, asset2));
+	assert_ok!(AssetConversion::<T>::add_liquidity(
+		origin.into(),
+		asset1,
+		asset2,
+		native::<T>(10_000),
+		native::<T>(10_000),
+		Zero::zero(),
+		Zero::zero(),
+		caller.clone(),
+	));
+}
+
+/// Create a path of `n` assets starting with the native currency, with a pool with liquidity
+/// between every two consecutive assets.
+fn create_path<T: Config>(caller: &T::AccountId, n: u32) -> PathOf<T>
+where
+	T::Currency: MutateFungible<T::AccountId>,
+	T::Assets: Create<T::AccountId> + Mutate<T::AccountId>,
+{
+	let mut path = vec![NativeOrAssetId::Native];
+	for seed in 1..n {
+		let asset = create_asset::<T>(caller, seed);
+		create_pool_with_liquidity::<T>(caller, path[path.len() - 1], asset);
+		path.push(asset);
+	}
+	PathOf::<T>::truncate_from(path)
+}
+
+benchmarks! {
+	where_clause {
+		where
+			T::Currency: MutateFungible<T::AccountId>,
+			T::Assets: Create<T::AccountId> + Mutate<T::AccountId>,
+	}
+
+	create_pool {
+		let caller: T::AccountId = whitelisted_caller();
+		let asset = create_asset::<T>(&caller, 1);
+		let pool_id = AssetConversion::<T>::get_pool_id(NativeOrAssetId::Native, asset);
+		let lp_token = NextPoolAssetId::<T>::get().unwrap_or_else(Zero::zero);
+	}: _(SystemOrigin::Signed(caller.clone()), NativeOrAssetId::Native, asset)
+	verify {
+		assert_last_event::<T>(Event::PoolCreated {
+			creator: caller,
+			pool_account: AssetConversion::<T>::get_pool_account(&pool_id),
+			pool_id,
+			lp_token,
+		}.into());
+	}
+
+	add_liquidity {
+		let caller: T::AccountId = whitelisted_caller();
+		let asset = create_asset::<T>(&caller, 1);
+		create_pool_with_liquidity::<T>(&caller, NativeOrAssetId::Native, asset);
+		let amount = native::<T>(1_000);
+	}: _(
+		SystemOrigin::Signed(caller.clone()),
+		NativeOrAssetId::Native,
+		asset,
+		amount,
+		amount,
+		Zero::zero(),
+		Zero::zero(),
+		caller.clone()
+	)
+	verify {
+		let pool_id = AssetConversion::<T>::get_pool_id(NativeOrAssetId::Native, asset);
+		assert!(Pools::<T>::contains_key(pool_id));
+	}
+
+	remove_liquidity {
+		let caller: T::AccountId = whitelisted_caller();
+		let asset = create_asset::<T>(&caller, 1);
+		create_pool_with_liquidity::<T>(&caller, NativeOrAssetId::Native, asset);
+		let lp_token_burn = native::<T>(1_000);
+	}: _(
+		SystemOrigin::Signed(caller.clone()),
+		NativeOrAssetId::Native,
+		asset,
+		lp_token_burn,
+		Zero::zero(),
+		Zero::zero(),
+		caller.clone()
+	)
+	verify {
+		let pool_id = AssetConversion::<T>::get_pool_id(NativeOrAssetId::Native, asset);
+		let lp_token = Pools::<T>::get(pool_id).unwrap().lp_token;
+		assert_eq!(
+			T::PoolAssets::balance(lp_token, &caller),
+			T::PoolAssets::total_issuance(lp_token) - T::MintMinLiquidity::get(),
+		);
+	}
+
+	swap_exact_tokens_for_tokens {
+		let n in 2 .. T::MaxSwapPathLength::get();
+		let caller: T::AccountId = whitelisted_caller();
+		let path = create_path::<T>(&caller, n);
+		let asset_out = path[path.len() - 1];
+		let balance_before = AssetConversion::<T>::get_balance(&caller, &asset_out);
+		let amount_in = native::<T>(100);
+		let amount_out = *AssetConversion::<T>::get_amounts_out(amount_in, &path)?.last().unwrap();
+	}: _(SystemOrigin::Signed(caller.clone()), path, amount_in, 1u32.into(), caller.clone(), false)
+	verify {
+		assert_eq!(
+			AssetConversion::<T>::get_balance(&caller, &asset_out),
+			balance_before + amount_out,
+		);
+	}
+
+	swap_tokens_for_exact_tokens {
+		let n in 2 .. T::MaxSwapPathLength::get();
+		let caller: T::AccountId = whitelisted_caller();
+		let path = create_path::<T>(&caller, n);
+		let asset_out = path[path.len() - 1];
+		let balance_before = AssetConversion::<T>::get_balance(&caller, &asset_out);
+		let amount_out = native::<T>(100);
+	}: _(
+		SystemOrigin::Signed(caller.clone()),
+		path,
+		amount_out,
+		native::<T>(1_000),
+		caller.clone(),
+		false
+	)
+	verify {
+		assert_eq!(
+			AssetConversion::<T>::get_balance(&caller, &asset_out),
+			balance_before + amount_out,
+		);
+	}
+
+	impl_benchmark_test_suite!(AssetConversion, crate::mock::new_test_ext(), crate::mock::Test);
+}
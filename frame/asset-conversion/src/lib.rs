@@ -0,0 +1,991 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Asset Conversion Pallet
+//!
+//! A simple, secure module for swapping between system tokens, and between system tokens and the
+//! native currency, using constant product ("x * y = k") liquidity pools.
+//!
+//! ## Overview
+//!
+//! Anyone can create a pool for a pair of assets, one of which may be the native currency. Each
+//! pool is assigned a liquidity provider (LP) token, which is created in `T::PoolAssets` and owned
+//! by the pool account.
+//!
+//! Liquidity providers deposit both assets of a pool in the current ratio of its reserves and
+//! receive LP tokens in return, which they can later burn to withdraw their share of the reserves.
+//! The very first deposit into a pool sets the initial price, and `T::MintMinLiquidity` of the LP
+//! tokens it mints are kept by the pool account forever, so that a pool can never be fully drained.
+//!
+//! Swaps may go through several pools at once, following a path of assets. Every swap pays a fee
+//! of `T::LPFee` per mille of the amount paid into each pool, which stays in the pool for the
+//! liquidity providers. Both an exact amount paid in and an exact amount received can be
+//! requested, bounded by a minimum received or a maximum paid respectively, so users are
+//! protected against the price moving between signing and inclusion.
+//!
+//! The pallet also quotes prices through the [`AssetConversionApi`] runtime API, and converts
+//! native balances into asset balances through [`BalanceConversion`], so transaction fees can be
+//! charged in system tokens without a fixed exchange rate.
+//!
+//! [`BalanceConversion`] does not use the current reserves of a pool, which anyone can move within
+//! a block by swapping back and forth. Instead, every pool keeps its reserves averaged over the
+//! last `T::PriceWindow` blocks, weighted by the number of blocks they were held for. The reserves
+//! are folded into the average before their first change in a block, so moving the price requires
+//! holding it for several blocks, at the mercy of arbitrageurs. A pool without an average refuses
+//! to convert. [`PoolOrFallback`] converts the balances of the assets without a pool with another
+//! [`BalanceConversion`].
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! * `create_pool` - Create a pool for a pair of assets.
+//! * `add_liquidity` - Provide liquidity to a pool and receive LP tokens.
+//! * `remove_liquidity` - Burn LP tokens to withdraw a share of the reserves of a pool.
+//! * `swap_exact_tokens_for_tokens` - Swap an exact amount of the first asset of a path for as much
+//!   as possible of the last one.
+//! * `swap_tokens_for_exact_tokens` - Swap as little as possible of the first asset of a path for
+//!   an exact amount of the last one.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+mod types;
+pub mod weights;
+
+use codec::Codec;
+use frame_support::{
+	ensure,
+	traits::{
+		fungible::{Inspect as InspectFungible, Transfer as TransferFungible},
+		fungibles::{Create, Inspect, Mutate, Transfer},
+		tokens::{AssetId, Balance, BalanceConversion},
+	},
+	BoundedVec, PalletId,
+};
+use sp_core::U256;
+use sp_runtime::{
+	traits::{
+		AccountIdConversion, AtLeast32BitUnsigned, CheckedAdd, CheckedSub, MaybeDisplay, One, Zero,
+	},
+	DispatchError, SaturatedConversion, Saturating,
+};
+use sp_std::{marker::PhantomData, prelude::*};
+
+pub use pallet::*;
+pub use types::*;
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The native currency.
+		type Currency: InspectFungible<Self::AccountId, Balance = Self::Balance>
+			+ TransferFungible<Self::AccountId>;
+
+		/// The balance type shared by the native currency, the assets and the LP tokens.
+		type Balance: Balance;
+
+		/// Identifier of the assets which can be traded.
+		type AssetId: AssetId + Ord;
+
+		/// The assets which can be traded against each other and against the native currency.
+		type Assets: Inspect<Self::AccountId, AssetId = Self::AssetId, Balance = Self::Balance>
+			+ Transfer<Self::AccountId>;
+
+		/// Identifier of the LP tokens of the pools.
+		type PoolAssetId: AssetId + AtLeast32BitUnsigned;
+
+		/// The registry in which the LP tokens of the pools are created.
+		type PoolAssets: Inspect<Self::AccountId, AssetId = Self::PoolAssetId, Balance = Self::Balance>
+			+ Create<Self::AccountId>
+			+ Mutate<Self::AccountId>;
+
+		/// The fee taken by the liquidity providers on every swap, in per mille of the amount paid
+		/// into a pool.
+		#[pallet::constant]
+		type LPFee: Get<u32>;
+
+		/// The native amount charged for creating a pool.
+		#[pallet::constant]
+		type PoolSetupFee: Get<Self::Balance>;
+
+		/// The account receiving the pool setup fee.
+		type PoolSetupFeeReceiver: Get<Self::AccountId>;
+
+		/// The amount of LP tokens kept by a pool when its first liquidity is added.
+		#[pallet::constant]
+		type MintMinLiquidity: Get<Self::Balance>;
+
+		/// The maximum number of assets in a swap path, i.e. one more than the maximum number of
+		/// pools a single swap may go through.
+		#[pallet::constant]
+		type MaxSwapPathLength: Get<u32>;
+
+		/// The pallet id, used to derive the accounts of the pools.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// The number of blocks over which the reserves of a pool are averaged for
+		/// [`BalanceConversion`].
+		#[pallet::constant]
+		type PriceWindow: Get<Self::BlockNumber>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+
+		/// Helper for creating the assets traded in benchmarks.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<Self::AssetId>;
+	}
+
+	/// The pools, by the pair of assets they trade.
+	#[pallet::storage]
+	pub type Pools<T: Config> =
+		StorageMap<_, Blake2_128Concat, PoolIdOf<T>, PoolInfo<T::PoolAssetId>, OptionQuery>;
+
+	/// The id of the LP token of the next pool to be created.
+	#[pallet::storage]
+	pub type NextPoolAssetId<T: Config> = StorageValue<_, T::PoolAssetId, OptionQuery>;
+
+	/// The time weighted average reserves of the pools which have liquidity.
+	#[pallet::storage]
+	pub type PriceOracles<T: Config> =
+		StorageMap<_, Blake2_128Concat, PoolIdOf<T>, PriceOracleOf<T>, OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A pool was created.
+		PoolCreated {
+			/// The account that created the pool.
+			creator: T::AccountId,
+			/// The pair of assets traded in the pool.
+			pool_id: PoolIdOf<T>,
+			/// The account holding the reserves of the pool.
+			pool_account: T::AccountId,
+			/// The id of the LP token of the pool.
+			lp_token: T::PoolAssetId,
+		},
+		/// Liquidity was added to a pool.
+		LiquidityAdded {
+			/// The account that provided the liquidity.
+			who: T::AccountId,
+			/// The account that received the LP tokens.
+			mint_to: T::AccountId,
+			/// The pool the liquidity was added to.
+			pool_id: PoolIdOf<T>,
+			/// The amount of the first asset of the pool that was deposited.
+			amount1_provided: T::Balance,
+			/// The amount of the second asset of the pool that was deposited.
+			amount2_provided: T::Balance,
+			/// The id of the LP token of the pool.
+			lp_token: T::PoolAssetId,
+			/// The amount of LP tokens minted.
+			lp_token_minted: T::Balance,
+		},
+		/// Liquidity was removed from a pool.
+		LiquidityRemoved {
+			/// The account that burned the LP tokens.
+			who: T::AccountId,
+			/// The account that received the withdrawn assets.
+			withdraw_to: T::AccountId,
+			/// The pool the liquidity was removed from.
+			pool_id: PoolIdOf<T>,
+			/// The amount of the first asset of the pool that was withdrawn.
+			amount1: T::Balance,
+			/// The amount of the second asset of the pool that was withdrawn.
+			amount2: T::Balance,
+			/// The id of the LP token of the pool.
+			lp_token: T::PoolAssetId,
+			/// The amount of LP tokens burned.
+			lp_token_burned: T::Balance,
+		},
+		/// Assets were swapped through one or more pools.
+		SwapExecuted {
+			/// The account that paid for the swap.
+			who: T::AccountId,
+			/// The account that received the last asset of the path.
+			send_to: T::AccountId,
+			/// The assets the swap went through.
+			path: PathOf<T>,
+			/// The amount of the first asset of the path that was paid.
+			amount_in: T::Balance,
+			/// The amount of the last asset of the path that was received.
+			amount_out: T::Balance,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// A pool must trade two different assets.
+		EqualAssets,
+		/// The asset does not exist.
+		AssetNotFound,
+		/// A pool for this pair of assets already exists.
+		PoolExists,
+		/// There is no pool for this pair of assets.
+		PoolNotFound,
+		/// The desired amounts to add must not be zero.
+		WrongDesiredAmount,
+		/// The amount of the first asset is less than the given minimum.
+		AmountOneLessThanMinimal,
+		/// The amount of the second asset is less than the given minimum.
+		AmountTwoLessThanMinimal,
+		/// The amount of LP tokens minted would be zero.
+		InsufficientLiquidityMinted,
+		/// The amount must not be zero.
+		ZeroAmount,
+		/// Removing the liquidity would take a reserve of the pool below the minimum balance of
+		/// its asset.
+		ReserveLeftLessThanMinimal,
+		/// The pool has no liquidity.
+		ZeroLiquidity,
+		/// The requested amount out is not less than the reserve of the pool.
+		AmountOutTooHigh,
+		/// The swap would give less than the given minimum amount out.
+		ProvidedMinimumNotSufficientForSwap,
+		/// The swap would take more than the given maximum amount in.
+		ProvidedMaximumNotSufficientForSwap,
+		/// A swap path must contain at least two assets, and no asset twice in a row.
+		InvalidPath,
+		/// A swap path must not go through the same pool twice.
+		NonUniquePath,
+		/// There are no ids left for new LP tokens.
+		IncorrectPoolAssetId,
+		/// An arithmetic overflow occurred.
+		Overflow,
+		/// The pool has no average reserves to convert balances at.
+		NoPriceObservation,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn integrity_test() {
+			assert!(T::LPFee::get() < 1000, "the LP fee must be less than 100%");
+			assert!(
+				T::MaxSwapPathLength::get() > 1,
+				"a swap path must be able to hold at least two assets"
+			);
+			assert!(!T::PriceWindow::get().is_zero(), "the price window must not be empty");
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Create a pool trading `asset1` against `asset2`, in any order. The pool setup fee is
+		/// charged to the origin.
+		///
+		/// Emits `PoolCreated`.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::create_pool())]
+		pub fn create_pool(
+			origin: OriginFor<T>,
+			asset1: NativeOrAssetIdOf<T>,
+			asset2: NativeOrAssetIdOf<T>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			ensure!(asset1 != asset2, Error::<T>::EqualAssets);
+
+			let pool_id = Self::get_pool_id(asset1, asset2);
+			ensure!(!Pools::<T>::contains_key(&pool_id), Error::<T>::PoolExists);
+			for asset in [pool_id.0, pool_id.1] {
+				if let NativeOrAssetId::Asset(id) = asset {
+					ensure!(T::Assets::asset_exists(id), Error::<T>::AssetNotFound);
+				}
+			}
+
+			let setup_fee = T::PoolSetupFee::get();
+			if !setup_fee.is_zero() {
+				T::Currency::transfer(&sender, &T::PoolSetupFeeReceiver::get(), setup_fee, true)?;
+			}
+
+			let pool_account = Self::get_pool_account(&pool_id);
+			// The pool account holds its reserves for as long as the pool exists.
+			frame_system::Pallet::<T>::inc_providers(&pool_account);
+
+			let lp_token = NextPoolAssetId::<T>::get().unwrap_or_else(Zero::zero);
+			let next_lp_token =
+				lp_token.checked_add(&One::one()).ok_or(Error::<T>::IncorrectPoolAssetId)?;
+			NextPoolAssetId::<T>::set(Some(next_lp_token));
+
+			T::PoolAssets::create(lp_token, pool_account.clone(), false, One::one())?;
+			Pools::<T>::insert(pool_id, PoolInfo { lp_token });
+
+			Self::deposit_event(Event::PoolCreated {
+				creator: sender,
+				pool_id,
+				pool_account,
+				lp_token,
+			});
+			Ok(())
+		}
+
+		/// Provide liquidity to the pool of `asset1` and `asset2`, minting LP tokens to
+		/// `mint_to`.
+		///
+		/// The amounts actually deposited follow the ratio of the reserves of the pool: the
+		/// larger possible deposit not exceeding `amount1_desired` and `amount2_desired` is made,
+		/// and it must not be below `amount1_min` and `amount2_min`. The first deposit into a
+		/// pool takes the desired amounts as they are and sets the price.
+		///
+		/// Emits `LiquidityAdded`.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::add_liquidity())]
+		pub fn add_liquidity(
+			origin: OriginFor<T>,
+			asset1: NativeOrAssetIdOf<T>,
+			asset2: NativeOrAssetIdOf<T>,
+			amount1_desired: T::Balance,
+			amount2_desired: T::Balance,
+			amount1_min: T::Balance,
+			amount2_min: T::Balance,
+			mint_to: T::AccountId,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			let pool_id = Self::get_pool_id(asset1, asset2);
+			// Work in the order of the assets in the pool.
+			let (amount1_desired, amount2_desired, amount1_min, amount2_min) =
+				if pool_id.0 == asset1 {
+					(amount1_desired, amount2_desired, amount1_min, amount2_min)
+				} else {
+					(amount2_desired, amount1_desired, amount2_min, amount1_min)
+				};
+			ensure!(
+				!amount1_desired.is_zero() && !amount2_desired.is_zero(),
+				Error::<T>::WrongDesiredAmount
+			);
+
+			let pool = Pools::<T>::get(&pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			let pool_account = Self::get_pool_account(&pool_id);
+
+			let reserve1 = Self::get_balance(&pool_account, &pool_id.0);
+			let reserve2 = Self::get_balance(&pool_account, &pool_id.1);
+
+			let (amount1, amount2) = if reserve1.is_zero() || reserve2.is_zero() {
+				(amount1_desired, amount2_desired)
+			} else {
+				let amount2_optimal = Self::quote(amount1_desired, reserve1, reserve2)?;
+				if amount2_optimal <= amount2_desired {
+					ensure!(amount2_optimal >= amount2_min, Error::<T>::AmountTwoLessThanMinimal);
+					(amount1_desired, amount2_optimal)
+				} else {
+					let amount1_optimal = Self::quote(amount2_desired, reserve2, reserve1)?;
+					ensure!(amount1_optimal <= amount1_desired, Error::<T>::Overflow);
+					ensure!(amount1_optimal >= amount1_min, Error::<T>::AmountOneLessThanMinimal);
+					(amount1_optimal, amount2_desired)
+				}
+			};
+			ensure!(amount1 >= amount1_min, Error::<T>::AmountOneLessThanMinimal);
+			ensure!(amount2 >= amount2_min, Error::<T>::AmountTwoLessThanMinimal);
+
+			Self::update_price_oracle(&pool_id, reserve1, reserve2);
+			Self::transfer(&pool_id.0, &sender, &pool_account, amount1, true)?;
+			Self::transfer(&pool_id.1, &sender, &pool_account, amount2, true)?;
+			// The first deposit starts the average at the reserves it sets.
+			Self::update_price_oracle(
+				&pool_id,
+				Self::get_balance(&pool_account, &pool_id.0),
+				Self::get_balance(&pool_account, &pool_id.1),
+			);
+
+			let total_supply = T::PoolAssets::total_issuance(pool.lp_token);
+			let lp_token_amount = if total_supply.is_zero() {
+				let liquidity = Self::calc_lp_amount_for_zero_supply(amount1, amount2)?;
+				let min_liquidity = T::MintMinLiquidity::get();
+				T::PoolAssets::mint_into(pool.lp_token, &pool_account, min_liquidity)?;
+				liquidity
+					.checked_sub(&min_liquidity)
+					.ok_or(Error::<T>::InsufficientLiquidityMinted)?
+			} else {
+				let side1 = Self::mul_div(amount1, total_supply, reserve1)?;
+				let side2 = Self::mul_div(amount2, total_supply, reserve2)?;
+				side1.min(side2)
+			};
+			ensure!(!lp_token_amount.is_zero(), Error::<T>::InsufficientLiquidityMinted);
+			T::PoolAssets::mint_into(pool.lp_token, &mint_to, lp_token_amount)?;
+
+			Self::deposit_event(Event::LiquidityAdded {
+				who: sender,
+				mint_to,
+				pool_id,
+				amount1_provided: amount1,
+				amount2_provided: amount2,
+				lp_token: pool.lp_token,
+				lp_token_minted: lp_token_amount,
+			});
+			Ok(())
+		}
+
+		/// Burn `lp_token_burn` LP tokens of the pool of `asset1` and `asset2`, withdrawing the
+		/// corresponding share of its reserves to `withdraw_to`. The withdrawn amounts must not
+		/// be below `amount1_min_receive` and `amount2_min_receive`.
+		///
+		/// Emits `LiquidityRemoved`.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::remove_liquidity())]
+		pub fn remove_liquidity(
+			origin: OriginFor<T>,
+			asset1: NativeOrAssetIdOf<T>,
+			asset2: NativeOrAssetIdOf<T>,
+			lp_token_burn: T::Balance,
+			amount1_min_receive: T::Balance,
+			amount2_min_receive: T::Balance,
+			withdraw_to: T::AccountId,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			let pool_id = Self::get_pool_id(asset1, asset2);
+			let (amount1_min_receive, amount2_min_receive) = if pool_id.0 == asset1 {
+				(amount1_min_receive, amount2_min_receive)
+			} else {
+				(amount2_min_receive, amount1_min_receive)
+			};
+			ensure!(!lp_token_burn.is_zero(), Error::<T>::ZeroAmount);
+
+			let pool = Pools::<T>::get(&pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			let pool_account = Self::get_pool_account(&pool_id);
+
+			let reserve1 = Self::get_balance(&pool_account, &pool_id.0);
+			let reserve2 = Self::get_balance(&pool_account, &pool_id.1);
+			let total_supply = T::PoolAssets::total_issuance(pool.lp_token);
+			ensure!(!total_supply.is_zero(), Error::<T>::ZeroLiquidity);
+
+			let amount1 = Self::mul_div(lp_token_burn, reserve1, total_supply)?;
+			let amount2 = Self::mul_div(lp_token_burn, reserve2, total_supply)?;
+			ensure!(
+				!amount1.is_zero() && amount1 >= amount1_min_receive,
+				Error::<T>::AmountOneLessThanMinimal
+			);
+			ensure!(
+				!amount2.is_zero() && amount2 >= amount2_min_receive,
+				Error::<T>::AmountTwoLessThanMinimal
+			);
+
+			ensure!(
+				reserve1.saturating_sub(amount1) >= Self::get_min_balance(&pool_id.0) &&
+					reserve2.saturating_sub(amount2) >= Self::get_min_balance(&pool_id.1),
+				Error::<T>::ReserveLeftLessThanMinimal
+			);
+
+			Self::update_price_oracle(&pool_id, reserve1, reserve2);
+			T::PoolAssets::burn_from(pool.lp_token, &sender, lp_token_burn)?;
+			Self::transfer(&pool_id.0, &pool_account, &withdraw_to, amount1, true)?;
+			Self::transfer(&pool_id.1, &pool_account, &withdraw_to, amount2, true)?;
+
+			Self::deposit_event(Event::LiquidityRemoved {
+				who: sender,
+				withdraw_to,
+				pool_id,
+				amount1,
+				amount2,
+				lp_token: pool.lp_token,
+				lp_token_burned: lp_token_burn,
+			});
+			Ok(())
+		}
+
+		/// Swap exactly `amount_in` of the first asset of `path` for as much as possible of the
+		/// last one, which is sent to `send_to`. The swap fails if it would give less than
+		/// `amount_out_min`.
+		///
+		/// `keep_alive` prevents the swap from reaping the account of the origin.
+		///
+		/// Emits `SwapExecuted`.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::swap_exact_tokens_for_tokens(path.len() as u32))]
+		pub fn swap_exact_tokens_for_tokens(
+			origin: OriginFor<T>,
+			path: PathOf<T>,
+			amount_in: T::Balance,
+			amount_out_min: T::Balance,
+			send_to: T::AccountId,
+			keep_alive: bool,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			ensure!(!amount_in.is_zero() && !amount_out_min.is_zero(), Error::<T>::ZeroAmount);
+			Self::validate_swap_path(&path)?;
+
+			let amounts = Self::get_amounts_out(amount_in, &path)?;
+			let amount_out = *amounts.last().expect("a valid path has at least two assets; qed");
+			ensure!(amount_out >= amount_out_min, Error::<T>::ProvidedMinimumNotSufficientForSwap);
+
+			Self::do_swap(sender, &amounts, path, send_to, keep_alive)
+		}
+
+		/// Swap as little as possible of the first asset of `path` for exactly `amount_out` of
+		/// the last one, which is sent to `send_to`. The swap fails if it would take more than
+		/// `amount_in_max`.
+		///
+		/// `keep_alive` prevents the swap from reaping the account of the origin.
+		///
+		/// Emits `SwapExecuted`.
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::swap_tokens_for_exact_tokens(path.len() as u32))]
+		pub fn swap_tokens_for_exact_tokens(
+			origin: OriginFor<T>,
+			path: PathOf<T>,
+			amount_out: T::Balance,
+			amount_in_max: T::Balance,
+			send_to: T::AccountId,
+			keep_alive: bool,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			ensure!(!amount_out.is_zero() && !amount_in_max.is_zero(), Error::<T>::ZeroAmount);
+			Self::validate_swap_path(&path)?;
+
+			let amounts = Self::get_amounts_in(amount_out, &path)?;
+			let amount_in = *amounts.first().expect("a valid path has at least two assets; qed");
+			ensure!(amount_in <= amount_in_max, Error::<T>::ProvidedMaximumNotSufficientForSwap);
+
+			Self::do_swap(sender, &amounts, path, send_to, keep_alive)
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Move the amounts of a swap along `path`: `amounts[0]` of the first asset from `sender`
+		/// into the first pool, and `amounts[i]` of the i-th asset out of each pool into the
+		/// next one, or into `send_to` for the last one.
+		fn do_swap(
+			sender: T::AccountId,
+			amounts: &[T::Balance],
+			path: PathOf<T>,
+			send_to: T::AccountId,
+			keep_alive: bool,
+		) -> DispatchResult {
+			let mut pools = Vec::with_capacity(path.len() - 1);
+			for pair in path.windows(2) {
+				let pool_id = Self::get_pool_id(pair[0], pair[1]);
+				let pool_account = Self::get_pool_account(&pool_id);
+				Self::update_price_oracle(
+					&pool_id,
+					Self::get_balance(&pool_account, &pool_id.0),
+					Self::get_balance(&pool_account, &pool_id.1),
+				);
+				pools.push(pool_account);
+			}
+
+			Self::transfer(&path[0], &sender, &pools[0], amounts[0], keep_alive)?;
+			for (i, pool_account) in pools.iter().enumerate() {
+				let to = pools.get(i + 1).unwrap_or(&send_to);
+				Self::transfer(&path[i + 1], pool_account, to, amounts[i + 1], true)?;
+			}
+
+			Self::deposit_event(Event::SwapExecuted {
+				who: sender,
+				send_to,
+				path,
+				amount_in: amounts[0],
+				amount_out: amounts[amounts.len() - 1],
+			});
+			Ok(())
+		}
+
+		/// Fold `reserve1` and `reserve2`, the reserves of the pool `pool_id` since its last
+		/// change, into its average reserves. Must be called before the reserves change.
+		///
+		/// Only the first call in a block has an effect, so the average never includes reserves
+		/// which were not held until the end of a block.
+		fn update_price_oracle(pool_id: &PoolIdOf<T>, reserve1: T::Balance, reserve2: T::Balance) {
+			if reserve1.is_zero() || reserve2.is_zero() {
+				return
+			}
+			PriceOracles::<T>::mutate(pool_id, |oracle| {
+				*oracle = Some(Self::average_reserves(oracle.take(), reserve1, reserve2));
+			});
+		}
+
+		/// The average reserves of `oracle` once the reserves `reserve1` and `reserve2` have
+		/// been held until the current block.
+		fn average_reserves(
+			oracle: Option<PriceOracleOf<T>>,
+			reserve1: T::Balance,
+			reserve2: T::Balance,
+		) -> PriceOracleOf<T> {
+			let now = frame_system::Pallet::<T>::block_number();
+			let oracle = match oracle {
+				Some(oracle) if oracle.last_updated < now => oracle,
+				Some(oracle) => return oracle,
+				None => return PriceOracle { reserve1, reserve2, last_updated: now },
+			};
+
+			let window = T::PriceWindow::get();
+			let elapsed = now.saturating_sub(oracle.last_updated).min(window);
+			if elapsed == window {
+				return PriceOracle { reserve1, reserve2, last_updated: now }
+			}
+			let (window, elapsed) = (
+				U256::from(window.saturated_into::<u128>()),
+				U256::from(elapsed.saturated_into::<u128>()),
+			);
+			// Neither product overflows, and the result is between `average` and `reserve`.
+			let average = |average: T::Balance, reserve: T::Balance| {
+				let sum =
+					Self::to_u256(average) * (window - elapsed) + Self::to_u256(reserve) * elapsed;
+				Self::from_u256(sum / window).unwrap_or(reserve)
+			};
+			PriceOracle {
+				reserve1: average(oracle.reserve1, reserve1),
+				reserve2: average(oracle.reserve2, reserve2),
+				last_updated: now,
+			}
+		}
+
+		/// Transfer `amount` of `asset` from `from` to `to`.
+		fn transfer(
+			asset: &NativeOrAssetIdOf<T>,
+			from: &T::AccountId,
+			to: &T::AccountId,
+			amount: T::Balance,
+			keep_alive: bool,
+		) -> Result<T::Balance, DispatchError> {
+			match asset {
+				NativeOrAssetId::Native => T::Currency::transfer(from, to, amount, keep_alive),
+				NativeOrAssetId::Asset(id) =>
+					T::Assets::transfer(*id, from, to, amount, keep_alive),
+			}
+		}
+
+		/// The balance of `owner` in `asset`.
+		pub(crate) fn get_balance(
+			owner: &T::AccountId,
+			asset: &NativeOrAssetIdOf<T>,
+		) -> T::Balance {
+			match asset {
+				NativeOrAssetId::Native => T::Currency::balance(owner),
+				NativeOrAssetId::Asset(id) => T::Assets::balance(*id, owner),
+			}
+		}
+
+		/// The minimum balance of `asset` an account must hold.
+		fn get_min_balance(asset: &NativeOrAssetIdOf<T>) -> T::Balance {
+			match asset {
+				NativeOrAssetId::Native => T::Currency::minimum_balance(),
+				NativeOrAssetId::Asset(id) => T::Assets::minimum_balance(*id),
+			}
+		}
+
+		/// The id of the pool trading `asset1` against `asset2`, which is independent of the
+		/// order of the two.
+		pub fn get_pool_id(
+			asset1: NativeOrAssetIdOf<T>,
+			asset2: NativeOrAssetIdOf<T>,
+		) -> PoolIdOf<T> {
+			if asset1 <= asset2 {
+				(asset1, asset2)
+			} else {
+				(asset2, asset1)
+			}
+		}
+
+		/// The account holding the reserves of the pool `pool_id`.
+		pub fn get_pool_account(pool_id: &PoolIdOf<T>) -> T::AccountId {
+			T::PalletId::get().into_sub_account_truncating(pool_id)
+		}
+
+		/// The reserves of the pool of `asset1` and `asset2`, in the order of the arguments.
+		pub fn get_reserves(
+			asset1: &NativeOrAssetIdOf<T>,
+			asset2: &NativeOrAssetIdOf<T>,
+		) -> Result<(T::Balance, T::Balance), Error<T>> {
+			let pool_id = Self::get_pool_id(*asset1, *asset2);
+			ensure!(Pools::<T>::contains_key(&pool_id), Error::<T>::PoolNotFound);
+			let pool_account = Self::get_pool_account(&pool_id);
+
+			let balance1 = Self::get_balance(&pool_account, asset1);
+			let balance2 = Self::get_balance(&pool_account, asset2);
+			ensure!(!balance1.is_zero() && !balance2.is_zero(), Error::<T>::ZeroLiquidity);
+			Ok((balance1, balance2))
+		}
+
+		/// The amounts of each asset of `path` moved by a swap of exactly `amount_in` of the
+		/// first one.
+		pub(crate) fn get_amounts_out(
+			amount_in: T::Balance,
+			path: &PathOf<T>,
+		) -> Result<Vec<T::Balance>, DispatchError> {
+			let mut amounts = vec![amount_in];
+			for pair in path.windows(2) {
+				let (reserve_in, reserve_out) = Self::get_reserves(&pair[0], &pair[1])?;
+				let amount_out =
+					Self::get_amount_out(amounts[amounts.len() - 1], reserve_in, reserve_out)?;
+				amounts.push(amount_out);
+			}
+			Ok(amounts)
+		}
+
+		/// The amounts of each asset of `path` moved by a swap for exactly `amount_out` of the
+		/// last one.
+		pub(crate) fn get_amounts_in(
+			amount_out: T::Balance,
+			path: &PathOf<T>,
+		) -> Result<Vec<T::Balance>, DispatchError> {
+			let mut amounts = vec![amount_out];
+			for pair in path.windows(2).rev() {
+				let (reserve_in, reserve_out) = Self::get_reserves(&pair[0], &pair[1])?;
+				let amount_in = Self::get_amount_in(amounts[0], reserve_in, reserve_out)?;
+				amounts.insert(0, amount_in);
+			}
+			Ok(amounts)
+		}
+
+		/// Ensure `path` has at least two assets, never the same twice in a row, and goes through
+		/// every pool at most once.
+		fn validate_swap_path(path: &PathOf<T>) -> DispatchResult {
+			ensure!(path.len() >= 2, Error::<T>::InvalidPath);
+
+			let mut pools = Vec::with_capacity(path.len() - 1);
+			for pair in path.windows(2) {
+				ensure!(pair[0] != pair[1], Error::<T>::InvalidPath);
+				let pool_id = Self::get_pool_id(pair[0], pair[1]);
+				ensure!(!pools.contains(&pool_id), Error::<T>::NonUniquePath);
+				pools.push(pool_id);
+			}
+			Ok(())
+		}
+
+		/// The amount of LP tokens minted for the first deposit of `amount1` and `amount2` into
+		/// a pool: their geometric mean.
+		fn calc_lp_amount_for_zero_supply(
+			amount1: T::Balance,
+			amount2: T::Balance,
+		) -> Result<T::Balance, Error<T>> {
+			let product = Self::to_u256(amount1)
+				.checked_mul(Self::to_u256(amount2))
+				.ok_or(Error::<T>::Overflow)?;
+			Self::from_u256(product.integer_sqrt())
+		}
+
+		/// The amount of the second asset with the same value as `amount` of the first one, at
+		/// the price given by the reserves `reserve1` and `reserve2`.
+		pub fn quote(
+			amount: T::Balance,
+			reserve1: T::Balance,
+			reserve2: T::Balance,
+		) -> Result<T::Balance, Error<T>> {
+			ensure!(!reserve1.is_zero() && !reserve2.is_zero(), Error::<T>::ZeroLiquidity);
+			Self::mul_div(amount, reserve2, reserve1)
+		}
+
+		/// The amount received for paying `amount_in` into a pool with the reserves `reserve_in`
+		/// and `reserve_out`, after the LP fee.
+		pub fn get_amount_out(
+			amount_in: T::Balance,
+			reserve_in: T::Balance,
+			reserve_out: T::Balance,
+		) -> Result<T::Balance, Error<T>> {
+			ensure!(!reserve_in.is_zero() && !reserve_out.is_zero(), Error::<T>::ZeroLiquidity);
+
+			let amount_in_with_fee = Self::to_u256(amount_in)
+				.checked_mul(U256::from(1000u32.saturating_sub(T::LPFee::get())))
+				.ok_or(Error::<T>::Overflow)?;
+			let numerator = amount_in_with_fee
+				.checked_mul(Self::to_u256(reserve_out))
+				.ok_or(Error::<T>::Overflow)?;
+			let denominator = Self::to_u256(reserve_in)
+				.checked_mul(U256::from(1000u32))
+				.and_then(|d| d.checked_add(amount_in_with_fee))
+				.ok_or(Error::<T>::Overflow)?;
+
+			Self::from_u256(numerator / denominator)
+		}
+
+		/// The amount to pay into a pool with the reserves `reserve_in` and `reserve_out` to
+		/// receive `amount_out`, after the LP fee.
+		pub fn get_amount_in(
+			amount_out: T::Balance,
+			reserve_in: T::Balance,
+			reserve_out: T::Balance,
+		) -> Result<T::Balance, Error<T>> {
+			ensure!(!reserve_in.is_zero() && !reserve_out.is_zero(), Error::<T>::ZeroLiquidity);
+			ensure!(amount_out < reserve_out, Error::<T>::AmountOutTooHigh);
+
+			let numerator = Self::to_u256(reserve_in)
+				.checked_mul(Self::to_u256(amount_out))
+				.and_then(|n| n.checked_mul(U256::from(1000u32)))
+				.ok_or(Error::<T>::Overflow)?;
+			let denominator = Self::to_u256(reserve_out.saturating_sub(amount_out))
+				.checked_mul(U256::from(1000u32.saturating_sub(T::LPFee::get())))
+				.ok_or(Error::<T>::Overflow)?;
+			ensure!(!denominator.is_zero(), Error::<T>::ZeroLiquidity);
+
+			// Round up, so the pool never gives out more than it is paid for.
+			Self::from_u256(numerator / denominator)?
+				.checked_add(&One::one())
+				.ok_or(Error::<T>::Overflow)
+		}
+
+		/// `a * b / c` without intermediate overflow.
+		fn mul_div(a: T::Balance, b: T::Balance, c: T::Balance) -> Result<T::Balance, Error<T>> {
+			ensure!(!c.is_zero(), Error::<T>::ZeroLiquidity);
+			let product =
+				Self::to_u256(a).checked_mul(Self::to_u256(b)).ok_or(Error::<T>::Overflow)?;
+			Self::from_u256(product / Self::to_u256(c))
+		}
+
+		fn to_u256(amount: T::Balance) -> U256 {
+			U256::from(amount.saturated_into::<u128>())
+		}
+
+		fn from_u256(amount: U256) -> Result<T::Balance, Error<T>> {
+			let amount: u128 = amount.try_into().map_err(|_| Error::<T>::Overflow)?;
+			amount.try_into().map_err(|_| Error::<T>::Overflow)
+		}
+
+		/// The amount of `asset2` received for exactly `amount` of `asset1`, if there is a pool
+		/// for them. Without `include_fee`, this is the price at the current reserves.
+		pub fn quote_price_exact_tokens_for_tokens(
+			asset1: NativeOrAssetIdOf<T>,
+			asset2: NativeOrAssetIdOf<T>,
+			amount: T::Balance,
+			include_fee: bool,
+		) -> Option<T::Balance> {
+			let (reserve1, reserve2) = Self::get_reserves(&asset1, &asset2).ok()?;
+			if include_fee {
+				Self::get_amount_out(amount, reserve1, reserve2).ok()
+			} else {
+				Self::quote(amount, reserve1, reserve2).ok()
+			}
+		}
+
+		/// The amount of `asset1` to pay for exactly `amount` of `asset2`, if there is a pool for
+		/// them. Without `include_fee`, this is the price at the current reserves.
+		pub fn quote_price_tokens_for_exact_tokens(
+			asset1: NativeOrAssetIdOf<T>,
+			asset2: NativeOrAssetIdOf<T>,
+			amount: T::Balance,
+			include_fee: bool,
+		) -> Option<T::Balance> {
+			let (reserve1, reserve2) = Self::get_reserves(&asset1, &asset2).ok()?;
+			if include_fee {
+				Self::get_amount_in(amount, reserve1, reserve2).ok()
+			} else {
+				Self::quote(amount, reserve2, reserve1).ok()
+			}
+		}
+	}
+}
+
+/// Converts native balances into asset balances at the time weighted average price of the pool of
+/// the native currency and the asset, over the last `T::PriceWindow` blocks.
+impl<T: Config> BalanceConversion<T::Balance, T::AssetId, T::Balance> for Pallet<T> {
+	type Error = DispatchError;
+
+	fn to_asset_balance(
+		balance: T::Balance,
+		asset_id: T::AssetId,
+	) -> Result<T::Balance, Self::Error> {
+		// The native currency sorts before every asset, so it is the first asset of the pool.
+		let pool_id = Self::get_pool_id(NativeOrAssetId::Native, NativeOrAssetId::Asset(asset_id));
+		let (reserve_native, reserve_asset) = Self::get_reserves(&pool_id.0, &pool_id.1)?;
+		// Never fall back to the current reserves, which can be moved within the block.
+		let oracle = PriceOracles::<T>::get(&pool_id).ok_or(Error::<T>::NoPriceObservation)?;
+		let oracle = Self::average_reserves(Some(oracle), reserve_native, reserve_asset);
+		Ok(Self::quote(balance, oracle.reserve1, oracle.reserve2)?)
+	}
+}
+
+/// Converts native balances into asset balances through the pools of [`Pallet`] like its own
+/// [`BalanceConversion`], or with `Fallback` for the assets without a pool with the native
+/// currency.
+///
+/// Fails with `PoolNotFound` if `Fallback` cannot convert the balance either.
+pub struct PoolOrFallback<T, Fallback>(PhantomData<(T, Fallback)>);
+
+impl<T, Fallback> BalanceConversion<T::Balance, T::AssetId, T::Balance>
+	for PoolOrFallback<T, Fallback>
+where
+	T: Config,
+	Fallback: BalanceConversion<T::Balance, T::AssetId, T::Balance>,
+{
+	type Error = DispatchError;
+
+	fn to_asset_balance(
+		balance: T::Balance,
+		asset_id: T::AssetId,
+	) -> Result<T::Balance, Self::Error> {
+		let pool_id =
+			Pallet::<T>::get_pool_id(NativeOrAssetId::Native, NativeOrAssetId::Asset(asset_id));
+		if Pools::<T>::contains_key(&pool_id) {
+			Pallet::<T>::to_asset_balance(balance, asset_id)
+		} else {
+			Fallback::to_asset_balance(balance, asset_id)
+				.map_err(|_| Error::<T>::PoolNotFound.into())
+		}
+	}
+}
+
+/// Creates the assets traded in benchmarks.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AssetId> {
+	/// The id of the `seed`-th asset to trade.
+	fn asset_id(seed: u32) -> AssetId;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl<AssetId: From<u32>> BenchmarkHelper<AssetId> for () {
+	fn asset_id(seed: u32) -> AssetId {
+		seed.into()
+	}
+}
+
+sp_api::decl_runtime_apis! {
+	/// Quotes the prices of swaps and exposes the reserves of the pools.
+	pub trait AssetConversionApi<Balance, AssetId>
+	where
+		Balance: Codec + MaybeDisplay,
+		AssetId: Codec,
+	{
+		/// The amount of `asset2` received for exactly `amount` of `asset1`, with or without the
+		/// LP fee. `None` if there is no pool with liquidity for the pair.
+		fn quote_price_exact_tokens_for_tokens(
+			asset1: AssetId,
+			asset2: AssetId,
+			amount: Balance,
+			include_fee: bool,
+		) -> Option<Balance>;
+
+		/// The amount of `asset1` to pay for exactly `amount` of `asset2`, with or without the
+		/// LP fee. `None` if there is no pool with liquidity for the pair.
+		fn quote_price_tokens_for_exact_tokens(
+			asset1: AssetId,
+			asset2: AssetId,
+			amount: Balance,
+			include_fee: bool,
+		) -> Option<Balance>;
+
+		/// The reserves of the pool of `asset1` and `asset2`, in the order of the arguments.
+		fn get_reserves(asset1: AssetId, asset2: AssetId) -> Option<(Balance, Balance)>;
+	}
+}
@@ -0,0 +1,570 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for Asset Conversion pallet.
+
+use super::*;
+use crate::{mock::*, Error};
+use frame_support::{assert_noop, assert_ok, traits::fungibles::Inspect as _};
+
+const NATIVE: NativeOrAssetId<u32> = NativeOrAssetId::Native;
+const ASSET_2: NativeOrAssetId<u32> = NativeOrAssetId::Asset(2);
+const ASSET_3: NativeOrAssetId<u32> = NativeOrAssetId::Asset(3);
+
+fn events() -> Vec<Event<Test>> {
+	let result = System::events()
+		.into_iter()
+		.map(|r| r.event)
+		.filter_map(
+			|e| if let RuntimeEvent::AssetConversion(inner) = e { Some(inner) } else { None },
+		)
+		.collect();
+
+	System::reset_events();
+
+	result
+}
+
+fn create_asset(id: u32, holders: &[u128]) {
+	assert_ok!(Assets::force_create(RuntimeOrigin::root(), id, 1, true, 1));
+	for who in holders {
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), id, *who, 10_000));
+	}
+}
+
+fn pool_account(asset1: NativeOrAssetId<u32>, asset2: NativeOrAssetId<u32>) -> u128 {
+	AssetConversion::get_pool_account(&AssetConversion::get_pool_id(asset1, asset2))
+}
+
+fn asset_balance(asset: u32, who: u128) -> u128 {
+	<Assets as frame_support::traits::fungibles::Inspect<u128>>::balance(asset, &who)
+}
+
+fn lp_balance(lp_token: u32, who: u128) -> u128 {
+	<PoolAssets as frame_support::traits::fungibles::Inspect<u128>>::balance(lp_token, &who)
+}
+
+/// Create the pool of the native currency and asset 2 with 1_000 native and 4_000 of the asset
+/// provided by account 1.
+fn setup_native_pool() {
+	create_asset(2, &[1, 2]);
+	assert_ok!(AssetConversion::create_pool(RuntimeOrigin::signed(1), NATIVE, ASSET_2));
+	assert_ok!(AssetConversion::add_liquidity(
+		RuntimeOrigin::signed(1),
+		NATIVE,
+		ASSET_2,
+		1_000,
+		4_000,
+		0,
+		0,
+		1
+	));
+}
+
+#[test]
+fn create_pool_works() {
+	new_test_ext().execute_with(|| {
+		create_asset(2, &[]);
+
+		assert_ok!(AssetConversion::create_pool(RuntimeOrigin::signed(1), ASSET_2, NATIVE));
+
+		let pool_id = (NATIVE, ASSET_2);
+		let pool_account = pool_account(NATIVE, ASSET_2);
+		assert_eq!(Pools::<Test>::get(pool_id), Some(PoolInfo { lp_token: 0 }));
+		assert_eq!(NextPoolAssetId::<Test>::get(), Some(1));
+		assert!(PoolAssets::asset_exists(0));
+		// the setup fee went to its receiver.
+		assert_eq!(Balances::free_balance(1), 10_000 - 100);
+		assert_eq!(Balances::free_balance(SetupFeeReceiver::get()), 200);
+		assert_eq!(
+			events(),
+			vec![Event::<Test>::PoolCreated { creator: 1, pool_id, pool_account, lp_token: 0 }]
+		);
+
+		assert_noop!(
+			AssetConversion::create_pool(RuntimeOrigin::signed(1), NATIVE, ASSET_2),
+			Error::<Test>::PoolExists
+		);
+		assert_noop!(
+			AssetConversion::create_pool(RuntimeOrigin::signed(1), ASSET_2, ASSET_2),
+			Error::<Test>::EqualAssets
+		);
+		assert_noop!(
+			AssetConversion::create_pool(RuntimeOrigin::signed(1), NATIVE, ASSET_3),
+			Error::<Test>::AssetNotFound
+		);
+
+		// pools between two assets are allowed, too.
+		create_asset(3, &[]);
+		assert_ok!(AssetConversion::create_pool(RuntimeOrigin::signed(1), ASSET_3, ASSET_2));
+		assert_eq!(Pools::<Test>::get((ASSET_2, ASSET_3)), Some(PoolInfo { lp_token: 1 }));
+	});
+}
+
+#[test]
+fn add_liquidity_works() {
+	new_test_ext().execute_with(|| {
+		create_asset(2, &[1, 2]);
+		assert_ok!(AssetConversion::create_pool(RuntimeOrigin::signed(1), NATIVE, ASSET_2));
+		let pool_account = pool_account(NATIVE, ASSET_2);
+
+		assert_noop!(
+			AssetConversion::add_liquidity(
+				RuntimeOrigin::signed(1),
+				NATIVE,
+				ASSET_3,
+				1_000,
+				4_000,
+				0,
+				0,
+				1
+			),
+			Error::<Test>::PoolNotFound
+		);
+		assert_noop!(
+			AssetConversion::add_liquidity(
+				RuntimeOrigin::signed(1),
+				NATIVE,
+				ASSET_2,
+				0,
+				4_000,
+				0,
+				0,
+				1
+			),
+			Error::<Test>::WrongDesiredAmount
+		);
+
+		// the first deposit sets the price and locks some LP tokens in the pool.
+		System::reset_events();
+		assert_ok!(AssetConversion::add_liquidity(
+			RuntimeOrigin::signed(1),
+			NATIVE,
+			ASSET_2,
+			1_000,
+			4_000,
+			0,
+			0,
+			1
+		));
+		assert_eq!(Balances::free_balance(pool_account), 1_000);
+		assert_eq!(asset_balance(2, pool_account), 4_000);
+		assert_eq!(lp_balance(0, 1), 1_900);
+		assert_eq!(lp_balance(0, pool_account), 100);
+		assert_eq!(
+			events(),
+			vec![Event::<Test>::LiquidityAdded {
+				who: 1,
+				mint_to: 1,
+				pool_id: (NATIVE, ASSET_2),
+				amount1_provided: 1_000,
+				amount2_provided: 4_000,
+				lp_token: 0,
+				lp_token_minted: 1_900,
+			}]
+		);
+
+		// later deposits follow the ratio of the reserves, in whatever order the assets are
+		// given.
+		assert_noop!(
+			AssetConversion::add_liquidity(
+				RuntimeOrigin::signed(2),
+				ASSET_2,
+				NATIVE,
+				2_000,
+				1_000,
+				0,
+				501,
+				2
+			),
+			Error::<Test>::AmountOneLessThanMinimal
+		);
+		assert_ok!(AssetConversion::add_liquidity(
+			RuntimeOrigin::signed(2),
+			ASSET_2,
+			NATIVE,
+			2_000,
+			1_000,
+			0,
+			0,
+			2
+		));
+		assert_eq!(Balances::free_balance(2), 10_000 - 500);
+		assert_eq!(asset_balance(2, 2), 10_000 - 2_000);
+		assert_eq!(lp_balance(0, 2), 1_000);
+		assert_eq!(PoolAssets::total_issuance(0), 3_000);
+	});
+}
+
+#[test]
+fn remove_liquidity_works() {
+	new_test_ext().execute_with(|| {
+		setup_native_pool();
+		let pool_account = pool_account(NATIVE, ASSET_2);
+
+		assert_noop!(
+			AssetConversion::remove_liquidity(
+				RuntimeOrigin::signed(1),
+				NATIVE,
+				ASSET_2,
+				0,
+				0,
+				0,
+				1
+			),
+			Error::<Test>::ZeroAmount
+		);
+		assert_noop!(
+			AssetConversion::remove_liquidity(
+				RuntimeOrigin::signed(1),
+				ASSET_2,
+				NATIVE,
+				950,
+				1_901,
+				0,
+				1
+			),
+			Error::<Test>::AmountTwoLessThanMinimal
+		);
+
+		System::reset_events();
+		assert_ok!(AssetConversion::remove_liquidity(
+			RuntimeOrigin::signed(1),
+			NATIVE,
+			ASSET_2,
+			950,
+			475,
+			1_900,
+			3
+		));
+		assert_eq!(lp_balance(0, 1), 950);
+		assert_eq!(Balances::free_balance(3), 475);
+		assert_eq!(asset_balance(2, 3), 1_900);
+		assert_eq!(Balances::free_balance(pool_account), 525);
+		assert_eq!(asset_balance(2, pool_account), 2_100);
+		assert_eq!(
+			events(),
+			vec![Event::<Test>::LiquidityRemoved {
+				who: 1,
+				withdraw_to: 3,
+				pool_id: (NATIVE, ASSET_2),
+				amount1: 475,
+				amount2: 1_900,
+				lp_token: 0,
+				lp_token_burned: 950,
+			}]
+		);
+
+		// the pool keeps enough liquidity for its reserves to stay alive.
+		assert_noop!(
+			AssetConversion::remove_liquidity(
+				RuntimeOrigin::signed(1),
+				NATIVE,
+				ASSET_2,
+				950,
+				0,
+				0,
+				1
+			),
+			Error::<Test>::ReserveLeftLessThanMinimal
+		);
+	});
+}
+
+#[test]
+fn swap_exact_tokens_for_tokens_works() {
+	new_test_ext().execute_with(|| {
+		setup_native_pool();
+		let pool_account = pool_account(NATIVE, ASSET_2);
+		let path = PathOf::<Test>::truncate_from(vec![NATIVE, ASSET_2]);
+
+		// 100 * 997 * 4_000 / (1_000 * 1_000 + 100 * 997)
+		let expected_out = 362;
+		assert_eq!(AssetConversion::get_amount_out(100, 1_000, 4_000).ok(), Some(expected_out));
+
+		assert_noop!(
+			AssetConversion::swap_exact_tokens_for_tokens(
+				RuntimeOrigin::signed(2),
+				path.clone(),
+				100,
+				expected_out + 1,
+				2,
+				true
+			),
+			Error::<Test>::ProvidedMinimumNotSufficientForSwap
+		);
+
+		System::reset_events();
+		assert_ok!(AssetConversion::swap_exact_tokens_for_tokens(
+			RuntimeOrigin::signed(2),
+			path.clone(),
+			100,
+			expected_out,
+			3,
+			true
+		));
+		assert_eq!(Balances::free_balance(2), 10_000 - 100);
+		assert_eq!(asset_balance(2, 3), expected_out);
+		assert_eq!(Balances::free_balance(pool_account), 1_100);
+		assert_eq!(asset_balance(2, pool_account), 4_000 - expected_out);
+		assert_eq!(
+			events(),
+			vec![Event::<Test>::SwapExecuted {
+				who: 2,
+				send_to: 3,
+				path,
+				amount_in: 100,
+				amount_out: expected_out,
+			}]
+		);
+	});
+}
+
+#[test]
+fn swap_tokens_for_exact_tokens_works() {
+	new_test_ext().execute_with(|| {
+		setup_native_pool();
+		let pool_account = pool_account(NATIVE, ASSET_2);
+		let path = PathOf::<Test>::truncate_from(vec![NATIVE, ASSET_2]);
+
+		// 1_000 * 100 * 1_000 / ((4_000 - 100) * 997) + 1
+		let expected_in = 26;
+		assert_eq!(AssetConversion::get_amount_in(100, 1_000, 4_000).ok(), Some(expected_in));
+
+		assert_noop!(
+			AssetConversion::swap_tokens_for_exact_tokens(
+				RuntimeOrigin::signed(2),
+				path.clone(),
+				100,
+				expected_in - 1,
+				2,
+				true
+			),
+			Error::<Test>::ProvidedMaximumNotSufficientForSwap
+		);
+		assert_noop!(
+			AssetConversion::swap_tokens_for_exact_tokens(
+				RuntimeOrigin::signed(2),
+				path.clone(),
+				4_000,
+				10_000,
+				2,
+				true
+			),
+			Error::<Test>::AmountOutTooHigh
+		);
+
+		assert_ok!(AssetConversion::swap_tokens_for_exact_tokens(
+			RuntimeOrigin::signed(2),
+			path,
+			100,
+			expected_in,
+			2,
+			true
+		));
+		assert_eq!(Balances::free_balance(2), 10_000 - expected_in);
+		assert_eq!(asset_balance(2, 2), 10_000 + 100);
+		assert_eq!(Balances::free_balance(pool_account), 1_000 + expected_in);
+		assert_eq!(asset_balance(2, pool_account), 3_900);
+	});
+}
+
+#[test]
+fn multi_hop_swap_works() {
+	new_test_ext().execute_with(|| {
+		setup_native_pool();
+		create_asset(3, &[1]);
+		assert_ok!(AssetConversion::create_pool(RuntimeOrigin::signed(1), NATIVE, ASSET_3));
+		assert_ok!(AssetConversion::add_liquidity(
+			RuntimeOrigin::signed(1),
+			NATIVE,
+			ASSET_3,
+			2_000,
+			2_000,
+			0,
+			0,
+			1
+		));
+
+		let path = PathOf::<Test>::truncate_from(vec![ASSET_2, NATIVE, ASSET_3]);
+		let native_out = AssetConversion::get_amount_out(400, 4_000, 1_000).unwrap();
+		let expected_out = AssetConversion::get_amount_out(native_out, 2_000, 2_000).unwrap();
+
+		assert_ok!(AssetConversion::swap_exact_tokens_for_tokens(
+			RuntimeOrigin::signed(2),
+			path,
+			400,
+			1,
+			2,
+			false
+		));
+		assert_eq!(asset_balance(2, 2), 10_000 - 400);
+		assert_eq!(asset_balance(3, 2), expected_out);
+		assert_eq!(Balances::free_balance(pool_account(NATIVE, ASSET_2)), 1_000 - native_out);
+		assert_eq!(Balances::free_balance(pool_account(NATIVE, ASSET_3)), 2_000 + native_out);
+	});
+}
+
+#[test]
+fn swap_path_is_validated() {
+	new_test_ext().execute_with(|| {
+		setup_native_pool();
+		let swap = |path: Vec<NativeOrAssetId<u32>>| {
+			AssetConversion::swap_exact_tokens_for_tokens(
+				RuntimeOrigin::signed(2),
+				PathOf::<Test>::truncate_from(path),
+				100,
+				1,
+				2,
+				true,
+			)
+		};
+
+		assert_noop!(swap(vec![NATIVE]), Error::<Test>::InvalidPath);
+		assert_noop!(swap(vec![NATIVE, NATIVE]), Error::<Test>::InvalidPath);
+		assert_noop!(swap(vec![NATIVE, ASSET_2, NATIVE]), Error::<Test>::NonUniquePath);
+		assert_noop!(swap(vec![NATIVE, ASSET_3]), Error::<Test>::PoolNotFound);
+
+		// a pool without liquidity cannot be swapped through.
+		create_asset(3, &[]);
+		assert_ok!(AssetConversion::create_pool(RuntimeOrigin::signed(1), NATIVE, ASSET_3));
+		assert_noop!(swap(vec![NATIVE, ASSET_3]), Error::<Test>::ZeroLiquidity);
+	});
+}
+
+#[test]
+fn quotes_and_balance_conversion_work() {
+	new_test_ext().execute_with(|| {
+		setup_native_pool();
+
+		assert_eq!(AssetConversion::get_reserves(&ASSET_2, &NATIVE).ok(), Some((4_000, 1_000)));
+		assert_eq!(
+			AssetConversion::quote_price_exact_tokens_for_tokens(NATIVE, ASSET_2, 100, false),
+			Some(400)
+		);
+		assert_eq!(
+			AssetConversion::quote_price_exact_tokens_for_tokens(NATIVE, ASSET_2, 100, true),
+			Some(362)
+		);
+		assert_eq!(
+			AssetConversion::quote_price_tokens_for_exact_tokens(NATIVE, ASSET_2, 100, false),
+			Some(25)
+		);
+		assert_eq!(
+			AssetConversion::quote_price_tokens_for_exact_tokens(NATIVE, ASSET_2, 100, true),
+			Some(26)
+		);
+		assert_eq!(
+			AssetConversion::quote_price_exact_tokens_for_tokens(NATIVE, ASSET_3, 100, false),
+			None
+		);
+
+		assert_eq!(
+			<AssetConversion as BalanceConversion<u128, u32, u128>>::to_asset_balance(250, 2),
+			Ok(1_000)
+		);
+		assert_eq!(
+			<AssetConversion as BalanceConversion<u128, u32, u128>>::to_asset_balance(250, 3),
+			Err(Error::<Test>::PoolNotFound.into())
+		);
+	});
+}
+
+#[test]
+fn balance_conversion_uses_average_reserves() {
+	new_test_ext().execute_with(|| {
+		let to_asset_balance = |balance| {
+			<AssetConversion as BalanceConversion<u128, u32, u128>>::to_asset_balance(balance, 2)
+		};
+		setup_native_pool();
+		let pool_id = AssetConversion::get_pool_id(NATIVE, ASSET_2);
+		assert_eq!(
+			PriceOracles::<Test>::get(pool_id),
+			Some(PriceOracle { reserve1: 1_000, reserve2: 4_000, last_updated: 1 })
+		);
+
+		// 1_000 * 997 * 4_000 / (1_000 * 1_000 + 1_000 * 997)
+		assert_ok!(AssetConversion::swap_exact_tokens_for_tokens(
+			RuntimeOrigin::signed(2),
+			PathOf::<Test>::truncate_from(vec![NATIVE, ASSET_2]),
+			1_000,
+			1,
+			2,
+			true
+		));
+		assert_eq!(AssetConversion::get_reserves(&NATIVE, &ASSET_2).ok(), Some((2_000, 2_004)));
+
+		// Moving the reserves within a block does not move the price.
+		assert_eq!(to_asset_balance(250), Ok(1_000));
+
+		// Half of the window has passed at the new reserves.
+		System::set_block_number(6);
+		assert_eq!(to_asset_balance(250), Ok(250 * 3_002 / 1_500));
+
+		// The whole window has passed at the new reserves.
+		System::set_block_number(11);
+		assert_eq!(to_asset_balance(250), Ok(250 * 2_004 / 2_000));
+	});
+}
+
+#[test]
+fn balance_conversion_requires_average_reserves() {
+	new_test_ext().execute_with(|| {
+		setup_native_pool();
+		PriceOracles::<Test>::remove(AssetConversion::get_pool_id(NATIVE, ASSET_2));
+
+		// The current reserves are not used instead.
+		assert_eq!(
+			<AssetConversion as BalanceConversion<u128, u32, u128>>::to_asset_balance(250, 2),
+			Err(Error::<Test>::NoPriceObservation.into())
+		);
+	});
+}
+
+/// Converts at twice the balance, except for asset 4.
+struct DoubleConversion;
+impl BalanceConversion<u128, u32, u128> for DoubleConversion {
+	type Error = ();
+	fn to_asset_balance(balance: u128, asset_id: u32) -> Result<u128, ()> {
+		if asset_id == 4 {
+			Err(())
+		} else {
+			Ok(balance * 2)
+		}
+	}
+}
+
+#[test]
+fn pool_or_fallback_conversion_works() {
+	new_test_ext().execute_with(|| {
+		type Conversion = PoolOrFallback<Test, DoubleConversion>;
+		setup_native_pool();
+		create_asset(3, &[1]);
+
+		// Asset 2 is converted through its pool, asset 3 has none.
+		assert_eq!(Conversion::to_asset_balance(250, 2), Ok(1_000));
+		assert_eq!(Conversion::to_asset_balance(250, 3), Ok(500));
+		assert_eq!(Conversion::to_asset_balance(250, 4), Err(Error::<Test>::PoolNotFound.into()));
+
+		// A pool without an average doesn't fall back.
+		PriceOracles::<Test>::remove(AssetConversion::get_pool_id(NATIVE, ASSET_2));
+		assert_eq!(
+			Conversion::to_asset_balance(250, 2),
+			Err(Error::<Test>::NoPriceObservation.into())
+		);
+	});
+}
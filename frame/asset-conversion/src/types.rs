@@ -0,0 +1,81 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Various basic types for use in the asset conversion pallet.
+
+use super::*;
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+/// The identifier of a pool: the two assets it trades, the smaller one first.
+pub type PoolIdOf<T> = (NativeOrAssetIdOf<T>, NativeOrAssetIdOf<T>);
+
+/// Either the native currency or an asset of `T::Assets`.
+pub type NativeOrAssetIdOf<T> = NativeOrAssetId<<T as Config>::AssetId>;
+
+/// A swap path, from the asset paid in to the asset received.
+pub type PathOf<T> = BoundedVec<NativeOrAssetIdOf<T>, <T as Config>::MaxSwapPathLength>;
+
+/// An asset which can be traded in a pool.
+#[derive(
+	Decode,
+	Encode,
+	Copy,
+	Clone,
+	Eq,
+	PartialEq,
+	Ord,
+	PartialOrd,
+	MaxEncodedLen,
+	TypeInfo,
+	RuntimeDebug,
+)]
+pub enum NativeOrAssetId<AssetId> {
+	/// The native currency of the chain.
+	Native,
+	/// An asset of the assets pallet.
+	Asset(AssetId),
+}
+
+impl<AssetId> From<AssetId> for NativeOrAssetId<AssetId> {
+	fn from(asset: AssetId) -> Self {
+		Self::Asset(asset)
+	}
+}
+
+/// The average reserves of a pool.
+pub type PriceOracleOf<T> =
+	PriceOracle<<T as Config>::Balance, <T as frame_system::Config>::BlockNumber>;
+
+/// Stores the lp_token asset id a particular pool has been assigned.
+#[derive(Decode, Encode, Default, PartialEq, Eq, MaxEncodedLen, TypeInfo, RuntimeDebug)]
+pub struct PoolInfo<PoolAssetId> {
+	/// Liquidity pool asset.
+	pub lp_token: PoolAssetId,
+}
+
+/// The reserves of a pool, averaged over time.
+#[derive(Decode, Encode, Clone, PartialEq, Eq, MaxEncodedLen, TypeInfo, RuntimeDebug)]
+pub struct PriceOracle<Balance, BlockNumber> {
+	/// Average reserve of the first asset of the pool.
+	pub reserve1: Balance,
+	/// Average reserve of the second asset of the pool.
+	pub reserve2: Balance,
+	/// The block in which the average was last updated.
+	pub last_updated: BlockNumber,
+}
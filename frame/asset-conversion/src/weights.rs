@@ -0,0 +1,283 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Autogenerated weights for pallet_asset_conversion
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: 2023-04-12, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `bm2`, CPU: `Intel(R) Core(TM) i7-7700K CPU @ 4.20GHz`
+//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+
+// Executed Command:
+// ./target/production/substrate
+// benchmark
+// pallet
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_asset_conversion
+// --extrinsic=*
+// --execution=wasm
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --output=./frame/asset-conversion/src/weights.rs
+// --header=./HEADER-APACHE2
+// --template=./.maintain/frame-weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_asset_conversion.
+pub trait WeightInfo {
+	fn create_pool() -> Weight;
+	fn add_liquidity() -> Weight;
+	fn remove_liquidity() -> Weight;
+	fn swap_exact_tokens_for_tokens(n: u32, ) -> Weight;
+	fn swap_tokens_for_exact_tokens(n: u32, ) -> Weight;
+}
+
+/// Weights for pallet_asset_conversion using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: AssetConversion Pools (r:1 w:1)
+	/// Proof: AssetConversion Pools (max_values: None, max_size: Some(30), added: 2505, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: AssetConversion NextPoolAssetId (r:1 w:1)
+	/// Proof: AssetConversion NextPoolAssetId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoolAssets Asset (r:1 w:1)
+	/// Proof: PoolAssets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	fn create_pool() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1081`
+		//  Estimated: `16123`
+		// Minimum execution time: 91_000 nanoseconds.
+		Weight::from_parts(92_648_000, 16123)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: AssetConversion Pools (r:1 w:0)
+	/// Proof: AssetConversion Pools (max_values: None, max_size: Some(30), added: 2505, mode: MaxEncodedLen)
+	/// Storage: AssetConversion PriceOracles (r:1 w:1)
+	/// Proof: AssetConversion PriceOracles (max_values: None, max_size: Some(62), added: 2537, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:2 w:2)
+	/// Proof: Assets Account (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
+	/// Storage: PoolAssets Asset (r:1 w:1)
+	/// Proof: PoolAssets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: PoolAssets Account (r:2 w:2)
+	/// Proof: PoolAssets Account (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
+	fn add_liquidity() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1823`
+		//  Estimated: `26073`
+		// Minimum execution time: 150_000 nanoseconds.
+		Weight::from_parts(152_041_000, 26073)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	/// Storage: AssetConversion Pools (r:1 w:0)
+	/// Proof: AssetConversion Pools (max_values: None, max_size: Some(30), added: 2505, mode: MaxEncodedLen)
+	/// Storage: AssetConversion PriceOracles (r:1 w:1)
+	/// Proof: AssetConversion PriceOracles (max_values: None, max_size: Some(62), added: 2537, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:2 w:2)
+	/// Proof: Assets Account (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
+	/// Storage: PoolAssets Asset (r:1 w:1)
+	/// Proof: PoolAssets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: PoolAssets Account (r:1 w:1)
+	/// Proof: PoolAssets Account (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
+	fn remove_liquidity() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1812`
+		//  Estimated: `23486`
+		// Minimum execution time: 133_000 nanoseconds.
+		Weight::from_parts(134_613_000, 23486)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: AssetConversion PriceOracles (r:3 w:3)
+	/// Proof: AssetConversion PriceOracles (max_values: None, max_size: Some(62), added: 2537, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:2 w:2)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:4 w:4)
+	/// Proof: Assets Account (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `n` is `[2, 4]`.
+	fn swap_exact_tokens_for_tokens(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0 + n * (584 ±0)`
+		//  Estimated: `7404`
+		// Minimum execution time: 112_000 nanoseconds.
+		Weight::from_parts(0, 7404)
+			// Standard Error: 204_452
+			.saturating_add(Weight::from_parts(87_162_510, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 7717).saturating_mul(n.into()))
+	}
+	/// Storage: AssetConversion PriceOracles (r:3 w:3)
+	/// Proof: AssetConversion PriceOracles (max_values: None, max_size: Some(62), added: 2537, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:2 w:2)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:4 w:4)
+	/// Proof: Assets Account (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `n` is `[2, 4]`.
+	fn swap_tokens_for_exact_tokens(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0 + n * (584 ±0)`
+		//  Estimated: `7404`
+		// Minimum execution time: 113_000 nanoseconds.
+		Weight::from_parts(0, 7404)
+			// Standard Error: 204_452
+			.saturating_add(Weight::from_parts(87_162_510, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 7717).saturating_mul(n.into()))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: AssetConversion Pools (r:1 w:1)
+	/// Proof: AssetConversion Pools (max_values: None, max_size: Some(30), added: 2505, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: AssetConversion NextPoolAssetId (r:1 w:1)
+	/// Proof: AssetConversion NextPoolAssetId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: PoolAssets Asset (r:1 w:1)
+	/// Proof: PoolAssets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	fn create_pool() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1081`
+		//  Estimated: `16123`
+		// Minimum execution time: 91_000 nanoseconds.
+		Weight::from_parts(92_648_000, 16123)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: AssetConversion Pools (r:1 w:0)
+	/// Proof: AssetConversion Pools (max_values: None, max_size: Some(30), added: 2505, mode: MaxEncodedLen)
+	/// Storage: AssetConversion PriceOracles (r:1 w:1)
+	/// Proof: AssetConversion PriceOracles (max_values: None, max_size: Some(62), added: 2537, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:2 w:2)
+	/// Proof: Assets Account (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
+	/// Storage: PoolAssets Asset (r:1 w:1)
+	/// Proof: PoolAssets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: PoolAssets Account (r:2 w:2)
+	/// Proof: PoolAssets Account (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
+	fn add_liquidity() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1823`
+		//  Estimated: `26073`
+		// Minimum execution time: 150_000 nanoseconds.
+		Weight::from_parts(152_041_000, 26073)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+	/// Storage: AssetConversion Pools (r:1 w:0)
+	/// Proof: AssetConversion Pools (max_values: None, max_size: Some(30), added: 2505, mode: MaxEncodedLen)
+	/// Storage: AssetConversion PriceOracles (r:1 w:1)
+	/// Proof: AssetConversion PriceOracles (max_values: None, max_size: Some(62), added: 2537, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:2 w:2)
+	/// Proof: Assets Account (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
+	/// Storage: PoolAssets Asset (r:1 w:1)
+	/// Proof: PoolAssets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: PoolAssets Account (r:1 w:1)
+	/// Proof: PoolAssets Account (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
+	fn remove_liquidity() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1812`
+		//  Estimated: `23486`
+		// Minimum execution time: 133_000 nanoseconds.
+		Weight::from_parts(134_613_000, 23486)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: AssetConversion PriceOracles (r:3 w:3)
+	/// Proof: AssetConversion PriceOracles (max_values: None, max_size: Some(62), added: 2537, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:2 w:2)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:4 w:4)
+	/// Proof: Assets Account (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `n` is `[2, 4]`.
+	fn swap_exact_tokens_for_tokens(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0 + n * (584 ±0)`
+		//  Estimated: `7404`
+		// Minimum execution time: 112_000 nanoseconds.
+		Weight::from_parts(0, 7404)
+			// Standard Error: 204_452
+			.saturating_add(Weight::from_parts(87_162_510, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 7717).saturating_mul(n.into()))
+	}
+	/// Storage: AssetConversion PriceOracles (r:3 w:3)
+	/// Proof: AssetConversion PriceOracles (max_values: None, max_size: Some(62), added: 2537, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:2 w:2)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:4 w:4)
+	/// Proof: Assets Account (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `n` is `[2, 4]`.
+	fn swap_tokens_for_exact_tokens(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0 + n * (584 ±0)`
+		//  Estimated: `7404`
+		// Minimum execution time: 113_000 nanoseconds.
+		Weight::from_parts(0, 7404)
+			// Standard Error: 204_452
+			.saturating_add(Weight::from_parts(87_162_510, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 7717).saturating_mul(n.into()))
+	}
+}
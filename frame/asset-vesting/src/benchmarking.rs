@@ -0,0 +1,189 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Asset Vesting pallet benchmarking.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame_benchmarking::v1::{account, benchmarks, whitelisted_caller};
+use frame_support::{
+	assert_ok,
+	traits::fungibles::{Create, Mutate},
+};
+use frame_system::{Pallet as System, RawOrigin};
+
+use crate::Pallet as AssetVesting;
+
+const SEED: u32 = 0;
+
+/// The amount locked by every schedule, which vests over 20 blocks from block 1.
+fn schedule<T: Config>() -> VestingInfoOf<T> {
+	VestingInfo::new(20_000u32.into(), 1_000u32.into(), 1u32.into())
+}
+
+/// Create the vested asset, with plenty of it minted to `source`.
+fn create_asset<T: Config>(source: &T::AccountId) -> T::AssetId
+where
+	T::Assets: Create<T::AccountId> + Mutate<T::AccountId>,
+{
+	let asset = T::BenchmarkHelper::asset_id();
+	if !T::Assets::asset_exists(asset) {
+		assert_ok!(T::Assets::create(asset, source.clone(), true, 1u32.into()));
+	}
+	assert_ok!(T::Assets::mint_into(asset, source, 1_000_000_000u32.into()));
+	asset
+}
+
+/// Add `n` schedules of `asset` to `target`, returning the total amount locked by them.
+fn add_vesting_schedules<T: Config>(
+	target: &T::AccountId,
+	asset: T::AssetId,
+	n: u32,
+) -> T::Balance {
+	let source: T::AccountId = account("source", 0, SEED);
+	System::<T>::set_block_number(Zero::zero());
+
+	let mut total_locked: T::Balance = Zero::zero();
+	for _ in 0..n {
+		let schedule = schedule::<T>();
+		total_locked = total_locked.saturating_add(schedule.locked());
+		assert_ok!(AssetVesting::<T>::do_vested_transfer(
+			source.clone(),
+			target.clone(),
+			asset,
+			schedule
+		));
+	}
+
+	total_locked
+}
+
+benchmarks! {
+	where_clause {
+		where
+			T::Assets: Create<T::AccountId> + Mutate<T::AccountId>,
+	}
+
+	vest_locked {
+		let s in 1 .. T::MAX_VESTING_SCHEDULES;
+
+		let caller: T::AccountId = whitelisted_caller();
+		let asset = create_asset::<T>(&account("source", 0, SEED));
+		let total_locked = add_vesting_schedules::<T>(&caller, asset, s);
+
+		// At block 11, half of every schedule has vested.
+		System::<T>::set_block_number(11u32.into());
+		assert_eq!(
+			AssetVesting::<T>::vesting_balances(&caller, asset).map(|b| b.vested),
+			Some(total_locked / 2u32.into()),
+		);
+	}: vest(RawOrigin::Signed(caller.clone()), asset)
+	verify {
+		assert_eq!(
+			T::Assets::balance_frozen(asset, &T::FreezeId::get(), &caller),
+			total_locked / 2u32.into(),
+		);
+	}
+
+	vest_unlocked {
+		let s in 1 .. T::MAX_VESTING_SCHEDULES;
+
+		let caller: T::AccountId = whitelisted_caller();
+		let asset = create_asset::<T>(&account("source", 0, SEED));
+		add_vesting_schedules::<T>(&caller, asset, s);
+
+		// At block 21, every schedule has fully vested.
+		System::<T>::set_block_number(21u32.into());
+	}: vest(RawOrigin::Signed(caller.clone()), asset)
+	verify {
+		assert!(Vesting::<T>::get(&caller, asset).is_none());
+		assert!(T::Assets::balance_frozen(asset, &T::FreezeId::get(), &caller).is_zero());
+	}
+
+	vested_transfer {
+		let s in 0 .. T::MAX_VESTING_SCHEDULES - 1;
+
+		let caller: T::AccountId = whitelisted_caller();
+		let asset = create_asset::<T>(&caller);
+		let target: T::AccountId = account("target", 0, SEED);
+		let target_lookup = T::Lookup::unlookup(target.clone());
+		create_asset::<T>(&account("source", 0, SEED));
+		let total_locked = add_vesting_schedules::<T>(&target, asset, s)
+			.saturating_add(schedule::<T>().locked());
+	}: _(RawOrigin::Signed(caller), target_lookup, asset, schedule::<T>())
+	verify {
+		assert_eq!(
+			T::Assets::balance_frozen(asset, &T::FreezeId::get(), &target),
+			total_locked,
+		);
+	}
+
+	force_vested_transfer {
+		let s in 0 .. T::MAX_VESTING_SCHEDULES - 1;
+
+		let source: T::AccountId = account("source", 0, SEED);
+		let source_lookup = T::Lookup::unlookup(source.clone());
+		let asset = create_asset::<T>(&source);
+		let target: T::AccountId = account("target", 0, SEED);
+		let target_lookup = T::Lookup::unlookup(target.clone());
+		let total_locked = add_vesting_schedules::<T>(&target, asset, s)
+			.saturating_add(schedule::<T>().locked());
+	}: _(RawOrigin::Root, source_lookup, target_lookup, asset, schedule::<T>())
+	verify {
+		assert_eq!(
+			T::Assets::balance_frozen(asset, &T::FreezeId::get(), &target),
+			total_locked,
+		);
+	}
+
+	not_unlocking_merge_schedules {
+		let s in 2 .. T::MAX_VESTING_SCHEDULES;
+
+		let caller: T::AccountId = whitelisted_caller();
+		let asset = create_asset::<T>(&account("source", 0, SEED));
+		// Nothing has vested before block 1.
+		let total_locked = add_vesting_schedules::<T>(&caller, asset, s);
+	}: merge_schedules(RawOrigin::Signed(caller.clone()), asset, 0, s - 1)
+	verify {
+		assert_eq!(Vesting::<T>::get(&caller, asset).map(|v| v.len() as u32), Some(s - 1));
+		assert_eq!(
+			T::Assets::balance_frozen(asset, &T::FreezeId::get(), &caller),
+			total_locked,
+		);
+	}
+
+	unlocking_merge_schedules {
+		let s in 2 .. T::MAX_VESTING_SCHEDULES;
+
+		let caller: T::AccountId = whitelisted_caller();
+		let asset = create_asset::<T>(&account("source", 0, SEED));
+		let total_locked = add_vesting_schedules::<T>(&caller, asset, s);
+
+		// At block 11, half of every schedule has vested.
+		System::<T>::set_block_number(11u32.into());
+	}: merge_schedules(RawOrigin::Signed(caller.clone()), asset, 0, s - 1)
+	verify {
+		assert_eq!(Vesting::<T>::get(&caller, asset).map(|v| v.len() as u32), Some(s - 1));
+		assert_eq!(
+			T::Assets::balance_frozen(asset, &T::FreezeId::get(), &caller),
+			total_locked / 2u32.into(),
+		);
+	}
+
+	impl_benchmark_test_suite!(AssetVesting, crate::mock::new_test_ext(), crate::mock::Test);
+}
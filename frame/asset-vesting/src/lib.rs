@@ -0,0 +1,586 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Asset Vesting Pallet
+//!
+//! - [`Config`]
+//! - [`Call`]
+//!
+//! ## Overview
+//!
+//! A pallet placing linear vesting curves on balances of assets, such as system tokens. It is the
+//! counterpart of the vesting pallet for `fungibles` implementations: the unvested amount of an
+//! asset is kept frozen through [`fungibles::MutateFreeze`], rather than locked through
+//! `LockableCurrency`.
+//!
+//! Vesting schedules are kept per account and asset. As the amount vested increases over time, the
+//! amount unvested reduces, but the freeze is only reduced through `vest` or `vest_other`.
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! - `vest` - Update the freeze of an asset of the sender, reducing it in line with the amount
+//!   vested so far.
+//! - `vest_other` - Update the freeze of an asset of another account.
+//! - `vested_transfer` - Transfer some of an asset to an account, vesting it with a schedule.
+//! - `force_vested_transfer` - Make a vested transfer from any account, as _Root_.
+//! - `merge_schedules` - Merge two vesting schedules of an asset into one.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+pub mod weights;
+
+use codec::{Codec, Decode, Encode};
+use frame_support::{
+	dispatch::{DispatchError, DispatchResult},
+	ensure,
+	storage::bounded_vec::BoundedVec,
+	traits::{
+		fungibles::{Inspect, InspectFreeze, MutateFreeze, Transfer},
+		tokens::{AssetId, Balance},
+		Get,
+	},
+};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{Convert, One, Saturating, StaticLookup, Zero},
+	RuntimeDebug,
+};
+use sp_std::{marker::PhantomData, prelude::*};
+
+pub use pallet::*;
+pub use pallet_vesting::VestingInfo;
+pub use weights::WeightInfo;
+
+type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
+type VestingInfoOf<T> =
+	VestingInfo<<T as Config>::Balance, <T as frame_system::Config>::BlockNumber>;
+type FreezeIdOf<T> =
+	<<T as Config>::Assets as InspectFreeze<<T as frame_system::Config>::AccountId>>::Id;
+
+/// Actions to take against the vesting schedules of an account.
+#[derive(Clone, Copy)]
+enum VestingAction {
+	/// Do not actively remove any schedules.
+	Passive,
+	/// Remove the two schedules, specified by index, so they can be merged.
+	Merge { index1: usize, index2: usize },
+}
+
+impl VestingAction {
+	/// Whether or not the action says the schedule index should be removed.
+	fn should_remove(&self, index: usize) -> bool {
+		match self {
+			Self::Passive => false,
+			Self::Merge { index1, index2 } => *index1 == index || *index2 == index,
+		}
+	}
+}
+
+/// The amounts of an asset of an account under vesting.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct VestingBalances<Balance> {
+	/// The amount which has not vested yet.
+	pub locked: Balance,
+	/// The amount which has vested so far under the current schedules, whether or not it has been
+	/// released through `vest`.
+	pub vested: Balance,
+}
+
+// Wrapper for `T::MAX_VESTING_SCHEDULES` to satisfy `trait Get`.
+pub struct MaxVestingSchedulesGet<T>(PhantomData<T>);
+impl<T: Config> Get<u32> for MaxVestingSchedulesGet<T> {
+	fn get() -> u32 {
+		T::MAX_VESTING_SCHEDULES
+	}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The balance type of the assets.
+		type Balance: Balance;
+
+		/// Identifier of the assets which can be vested.
+		type AssetId: AssetId + Ord;
+
+		/// The assets which can be vested, frozen in the accounts while they vest.
+		type Assets: Inspect<Self::AccountId, AssetId = Self::AssetId, Balance = Self::Balance>
+			+ Transfer<Self::AccountId>
+			+ MutateFreeze<Self::AccountId>;
+
+		/// The identifier of the freeze this pallet places on the vesting assets.
+		#[pallet::constant]
+		type FreezeId: Get<FreezeIdOf<Self>>;
+
+		/// Convert the block number into a balance.
+		type BlockNumberToBalance: Convert<Self::BlockNumber, Self::Balance>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+
+		/// Helper for creating the assets vested in benchmarks.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<Self::AssetId>;
+
+		/// Maximum number of vesting schedules an account may have for an asset at a given moment.
+		const MAX_VESTING_SCHEDULES: u32;
+	}
+
+	#[pallet::extra_constants]
+	impl<T: Config> Pallet<T> {
+		#[pallet::constant_name(MaxVestingSchedules)]
+		fn max_vesting_schedules() -> u32 {
+			T::MAX_VESTING_SCHEDULES
+		}
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn integrity_test() {
+			assert!(T::MAX_VESTING_SCHEDULES > 0, "`MaxVestingSchedules` must be greater than 0");
+		}
+	}
+
+	/// The vesting schedules of the assets of an account.
+	#[pallet::storage]
+	pub type Vesting<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::AssetId,
+		BoundedVec<VestingInfoOf<T>, MaxVestingSchedulesGet<T>>,
+	>;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	/// The reasons for which this pallet freezes assets.
+	#[pallet::composite_enum]
+	pub enum FreezeReason {
+		/// The assets are vesting.
+		#[codec(index = 0)]
+		Vesting,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The amount vested of an asset has been updated. The balance given is the amount which
+		/// is left unvested, and thus frozen.
+		VestingUpdated { account: T::AccountId, asset: T::AssetId, unvested: T::Balance },
+		/// An account has become fully vested for an asset.
+		VestingCompleted { account: T::AccountId, asset: T::AssetId },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The account given is not vesting the asset.
+		NotVesting,
+		/// The account already has `MaxVestingSchedules` count of schedules for the asset and
+		/// thus cannot add another one. Consider merging existing schedules in order to add
+		/// another.
+		AtMaxVestingSchedules,
+		/// Amount being transferred is below the minimum balance of the asset.
+		AmountLow,
+		/// An index was out of bounds of the vesting schedules.
+		ScheduleIndexOutOfBounds,
+		/// Failed to create a new schedule because some parameter was invalid.
+		InvalidScheduleParams,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Unfreeze any vested funds of `asset` of the sender account.
+		///
+		/// The dispatch origin for this call must be _Signed_ and the sender must have funds of
+		/// `asset` still frozen under this pallet.
+		///
+		/// Emits either `VestingCompleted` or `VestingUpdated`.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::vest_locked(T::MAX_VESTING_SCHEDULES)
+			.max(T::WeightInfo::vest_unlocked(T::MAX_VESTING_SCHEDULES))
+		)]
+		pub fn vest(origin: OriginFor<T>, asset: T::AssetId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_vest(who, asset)
+		}
+
+		/// Unfreeze any vested funds of `asset` of a `target` account.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// - `target`: The account whose vested funds should be unfrozen. Must have funds of
+		/// `asset` still frozen under this pallet.
+		/// - `asset`: The asset to unfreeze.
+		///
+		/// Emits either `VestingCompleted` or `VestingUpdated`.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::vest_locked(T::MAX_VESTING_SCHEDULES)
+			.max(T::WeightInfo::vest_unlocked(T::MAX_VESTING_SCHEDULES))
+		)]
+		pub fn vest_other(
+			origin: OriginFor<T>,
+			target: AccountIdLookupOf<T>,
+			asset: T::AssetId,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let who = T::Lookup::lookup(target)?;
+			Self::do_vest(who, asset)
+		}
+
+		/// Transfer some of `asset` to `target`, vesting it with `schedule`.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// - `target`: The account receiving the vested funds.
+		/// - `asset`: The asset to transfer.
+		/// - `schedule`: The vesting schedule attached to the transfer. Its locked amount must be
+		/// at least the minimum balance of `asset`.
+		///
+		/// NOTE: This will unfreeze all vested funds of `asset` of `target` through the current
+		/// block.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::vested_transfer(T::MAX_VESTING_SCHEDULES))]
+		pub fn vested_transfer(
+			origin: OriginFor<T>,
+			target: AccountIdLookupOf<T>,
+			asset: T::AssetId,
+			schedule: VestingInfoOf<T>,
+		) -> DispatchResult {
+			let source = ensure_signed(origin)?;
+			let target = T::Lookup::lookup(target)?;
+			Self::do_vested_transfer(source, target, asset, schedule)
+		}
+
+		/// Force a vested transfer of `asset`.
+		///
+		/// The dispatch origin for this call must be _Root_.
+		///
+		/// - `source`: The account whose funds should be transferred.
+		/// - `target`: The account that should be transferred the vested funds.
+		/// - `asset`: The asset to transfer.
+		/// - `schedule`: The vesting schedule attached to the transfer.
+		///
+		/// NOTE: This will unfreeze all vested funds of `asset` of `target` through the current
+		/// block.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::force_vested_transfer(T::MAX_VESTING_SCHEDULES))]
+		pub fn force_vested_transfer(
+			origin: OriginFor<T>,
+			source: AccountIdLookupOf<T>,
+			target: AccountIdLookupOf<T>,
+			asset: T::AssetId,
+			schedule: VestingInfoOf<T>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			let source = T::Lookup::lookup(source)?;
+			let target = T::Lookup::lookup(target)?;
+			Self::do_vested_transfer(source, target, asset, schedule)
+		}
+
+		/// Merge two vesting schedules of `asset` together, in the same way as `merge_schedules`
+		/// of the vesting pallet.
+		///
+		/// NOTE: If `schedule1_index == schedule2_index` this is a no-op.
+		/// NOTE: This will unfreeze all vested funds of `asset` through the current block prior
+		/// to merging.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// - `asset`: The asset of the schedules.
+		/// - `schedule1_index`: index of the first schedule to merge.
+		/// - `schedule2_index`: index of the second schedule to merge.
+		#[pallet::call_index(4)]
+		#[pallet::weight(
+			T::WeightInfo::not_unlocking_merge_schedules(T::MAX_VESTING_SCHEDULES)
+			.max(T::WeightInfo::unlocking_merge_schedules(T::MAX_VESTING_SCHEDULES))
+		)]
+		pub fn merge_schedules(
+			origin: OriginFor<T>,
+			asset: T::AssetId,
+			schedule1_index: u32,
+			schedule2_index: u32,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			if schedule1_index == schedule2_index {
+				return Ok(())
+			};
+
+			let schedules = Vesting::<T>::get(&who, asset).ok_or(Error::<T>::NotVesting)?;
+			let merge_action = VestingAction::Merge {
+				index1: schedule1_index as usize,
+				index2: schedule2_index as usize,
+			};
+
+			let (schedules, locked_now) = Self::exec_action(schedules.to_vec(), merge_action)?;
+
+			Self::write_vesting(&who, asset, schedules)?;
+			Self::write_freeze(&who, asset, locked_now)
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// The amounts of `asset` of `who` which are still locked and which have vested so far, or
+	/// `None` if `who` is not vesting `asset`.
+	pub fn vesting_balances(
+		who: &T::AccountId,
+		asset: T::AssetId,
+	) -> Option<VestingBalances<T::Balance>> {
+		let schedules = Vesting::<T>::get(who, asset)?;
+		let now = <frame_system::Pallet<T>>::block_number();
+		Some(schedules.iter().fold(
+			VestingBalances { locked: Zero::zero(), vested: Zero::zero() },
+			|total, schedule| {
+				let locked = schedule.locked_at::<T::BlockNumberToBalance>(now);
+				VestingBalances {
+					locked: total.locked.saturating_add(locked),
+					vested: total.vested.saturating_add(schedule.locked().saturating_sub(locked)),
+				}
+			},
+		))
+	}
+
+	// Create a new `VestingInfo`, based off of two other `VestingInfo`s.
+	// NOTE: We assume both schedules have had funds unlocked up through the current block.
+	fn merge_vesting_info(
+		now: T::BlockNumber,
+		schedule1: VestingInfoOf<T>,
+		schedule2: VestingInfoOf<T>,
+	) -> Option<VestingInfoOf<T>> {
+		let schedule1_ending_block = schedule1.ending_block_as_balance::<T::BlockNumberToBalance>();
+		let schedule2_ending_block = schedule2.ending_block_as_balance::<T::BlockNumberToBalance>();
+		let now_as_balance = T::BlockNumberToBalance::convert(now);
+
+		// Check if one or both schedules have ended.
+		match (schedule1_ending_block <= now_as_balance, schedule2_ending_block <= now_as_balance) {
+			(true, true) => return None,
+			(true, false) => return Some(schedule2),
+			(false, true) => return Some(schedule1),
+			_ => {},
+		}
+
+		let locked = schedule1
+			.locked_at::<T::BlockNumberToBalance>(now)
+			.saturating_add(schedule2.locked_at::<T::BlockNumberToBalance>(now));
+		debug_assert!(
+			!locked.is_zero(),
+			"merge_vesting_info validation checks failed to catch a locked of 0"
+		);
+
+		let ending_block = schedule1_ending_block.max(schedule2_ending_block);
+		let starting_block = now.max(schedule1.starting_block()).max(schedule2.starting_block());
+
+		let per_block = {
+			let duration = ending_block
+				.saturating_sub(T::BlockNumberToBalance::convert(starting_block))
+				.max(One::one());
+			(locked / duration).max(One::one())
+		};
+
+		let schedule = VestingInfo::new(locked, per_block, starting_block);
+		debug_assert!(schedule.is_valid(), "merge_vesting_info schedule validation check failed");
+
+		Some(schedule)
+	}
+
+	// Execute a vested transfer of `asset` from `source` to `target` with the given `schedule`.
+	fn do_vested_transfer(
+		source: T::AccountId,
+		target: T::AccountId,
+		asset: T::AssetId,
+		schedule: VestingInfoOf<T>,
+	) -> DispatchResult {
+		// Validate user inputs.
+		ensure!(schedule.locked() >= T::Assets::minimum_balance(asset), Error::<T>::AmountLow);
+		ensure!(schedule.is_valid(), Error::<T>::InvalidScheduleParams);
+
+		// Check we can add to this account prior to any storage writes.
+		let mut schedules = Vesting::<T>::get(&target, asset).unwrap_or_default();
+		ensure!(schedules.try_push(schedule).is_ok(), Error::<T>::AtMaxVestingSchedules);
+
+		T::Assets::transfer(asset, &source, &target, schedule.locked(), false)?;
+
+		let (schedules, locked_now) =
+			Self::exec_action(schedules.to_vec(), VestingAction::Passive)?;
+
+		Self::write_vesting(&target, asset, schedules)?;
+		Self::write_freeze(&target, asset, locked_now)
+	}
+
+	/// Iterate through the schedules to track the current locked amount and filter out completed
+	/// and specified schedules.
+	///
+	/// NOTE: the amount locked does not include any schedules that are filtered out via `action`.
+	fn report_schedule_updates(
+		schedules: Vec<VestingInfoOf<T>>,
+		action: VestingAction,
+	) -> (Vec<VestingInfoOf<T>>, T::Balance) {
+		let now = <frame_system::Pallet<T>>::block_number();
+
+		let mut total_locked_now: T::Balance = Zero::zero();
+		let filtered_schedules = schedules
+			.into_iter()
+			.enumerate()
+			.filter(|(index, schedule)| {
+				if action.should_remove(*index) {
+					return false
+				}
+				let locked_now = schedule.locked_at::<T::BlockNumberToBalance>(now);
+				total_locked_now = total_locked_now.saturating_add(locked_now);
+				!locked_now.is_zero()
+			})
+			.map(|(_, schedule)| schedule)
+			.collect::<Vec<_>>();
+
+		(filtered_schedules, total_locked_now)
+	}
+
+	/// Write the updated vesting freeze of `asset` of `who`.
+	fn write_freeze(
+		who: &T::AccountId,
+		asset: T::AssetId,
+		total_locked_now: T::Balance,
+	) -> DispatchResult {
+		if total_locked_now.is_zero() {
+			T::Assets::thaw(asset, &T::FreezeId::get(), who)?;
+			Self::deposit_event(Event::<T>::VestingCompleted { account: who.clone(), asset });
+		} else {
+			T::Assets::set_freeze(asset, &T::FreezeId::get(), who, total_locked_now)?;
+			Self::deposit_event(Event::<T>::VestingUpdated {
+				account: who.clone(),
+				asset,
+				unvested: total_locked_now,
+			});
+		};
+		Ok(())
+	}
+
+	/// Write the updated vesting schedules of `asset` of `who`.
+	fn write_vesting(
+		who: &T::AccountId,
+		asset: T::AssetId,
+		schedules: Vec<VestingInfoOf<T>>,
+	) -> DispatchResult {
+		let schedules: BoundedVec<VestingInfoOf<T>, MaxVestingSchedulesGet<T>> =
+			schedules.try_into().map_err(|_| Error::<T>::AtMaxVestingSchedules)?;
+
+		if schedules.is_empty() {
+			Vesting::<T>::remove(who, asset);
+		} else {
+			Vesting::<T>::insert(who, asset, schedules)
+		}
+
+		Ok(())
+	}
+
+	/// Unfreeze any vested funds of `asset` of `who`.
+	fn do_vest(who: T::AccountId, asset: T::AssetId) -> DispatchResult {
+		let schedules = Vesting::<T>::get(&who, asset).ok_or(Error::<T>::NotVesting)?;
+
+		let (schedules, locked_now) =
+			Self::exec_action(schedules.to_vec(), VestingAction::Passive)?;
+
+		Self::write_vesting(&who, asset, schedules)?;
+		Self::write_freeze(&who, asset, locked_now)
+	}
+
+	/// Execute a `VestingAction` against the given `schedules`. Returns the updated schedules
+	/// and locked amount.
+	fn exec_action(
+		schedules: Vec<VestingInfoOf<T>>,
+		action: VestingAction,
+	) -> Result<(Vec<VestingInfoOf<T>>, T::Balance), DispatchError> {
+		let (schedules, locked_now) = match action {
+			VestingAction::Merge { index1, index2 } => {
+				// The schedule index is based off of the schedule ordering prior to filtering out
+				// any schedules that may be ending at this block.
+				let schedule1 =
+					*schedules.get(index1).ok_or(Error::<T>::ScheduleIndexOutOfBounds)?;
+				let schedule2 =
+					*schedules.get(index2).ok_or(Error::<T>::ScheduleIndexOutOfBounds)?;
+
+				// The length of `schedules` decreases by 2 here since we filter out 2 schedules,
+				// so the merged schedule can be pushed without error.
+				let (mut schedules, mut locked_now) =
+					Self::report_schedule_updates(schedules, action);
+
+				let now = <frame_system::Pallet<T>>::block_number();
+				if let Some(new_schedule) = Self::merge_vesting_info(now, schedule1, schedule2) {
+					schedules.push(new_schedule);
+					// We use `locked_at` in case this is a schedule that started in the past.
+					let new_schedule_locked =
+						new_schedule.locked_at::<T::BlockNumberToBalance>(now);
+					locked_now = locked_now.saturating_add(new_schedule_locked);
+				}
+
+				(schedules, locked_now)
+			},
+			VestingAction::Passive => Self::report_schedule_updates(schedules, action),
+		};
+
+		debug_assert!(
+			!locked_now.is_zero() && !schedules.is_empty() ||
+				locked_now.is_zero() && schedules.is_empty()
+		);
+
+		Ok((schedules, locked_now))
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AssetId> {
+	/// The id of the asset to vest.
+	fn asset_id() -> AssetId;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl<AssetId: From<u32>> BenchmarkHelper<AssetId> for () {
+	fn asset_id() -> AssetId {
+		0u32.into()
+	}
+}
+
+sp_api::decl_runtime_apis! {
+	/// Exposes the vesting of assets.
+	pub trait AssetVestingApi<AccountId, AssetId, Balance>
+	where
+		AccountId: Codec,
+		AssetId: Codec,
+		Balance: Codec,
+	{
+		/// The amounts of `asset` of `who` which are still locked and which have vested so far.
+		/// `None` if `who` is not vesting `asset`.
+		fn vesting_balances(who: AccountId, asset: AssetId) -> Option<VestingBalances<Balance>>;
+	}
+}
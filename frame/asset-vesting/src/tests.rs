@@ -0,0 +1,198 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for Asset Vesting pallet.
+
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok};
+use pallet_assets::Error as AssetsError;
+
+fn asset_balance(who: u64) -> u64 {
+	<Assets as Inspect<u64>>::balance(ASSET, &who)
+}
+
+fn frozen(who: u64) -> u64 {
+	<Assets as InspectFreeze<u64>>::balance_frozen(ASSET, &VestingFreezeId::get(), &who)
+}
+
+#[test]
+fn vested_transfer_freezes_the_unvested_amount() {
+	new_test_ext().execute_with(|| {
+		let schedule = VestingInfo::new(1_000, 100, 10);
+		assert_ok!(AssetVesting::vested_transfer(RuntimeOrigin::signed(1), 3, ASSET, schedule));
+		System::assert_last_event(
+			Event::VestingUpdated { account: 3, asset: ASSET, unvested: 1_000 }.into(),
+		);
+		assert_eq!(asset_balance(3), 1_000);
+		assert_eq!(frozen(3), 1_000);
+		assert_eq!(Vesting::<Test>::get(3, ASSET).unwrap().to_vec(), vec![schedule]);
+		assert_noop!(
+			Assets::transfer(RuntimeOrigin::signed(3), ASSET, 2, 1),
+			AssetsError::<Test>::BalanceLow,
+		);
+
+		// Half of the schedule vests by block 15, but only `vest` releases it.
+		System::set_block_number(15);
+		assert_eq!(frozen(3), 1_000);
+		assert_ok!(AssetVesting::vest(RuntimeOrigin::signed(3), ASSET));
+		assert_eq!(frozen(3), 500);
+		// The minimum balance of the asset stays on top of the frozen amount.
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(3), ASSET, 2, 490));
+		assert_noop!(
+			Assets::transfer(RuntimeOrigin::signed(3), ASSET, 2, 1),
+			AssetsError::<Test>::BalanceLow,
+		);
+
+		System::set_block_number(20);
+		assert_ok!(AssetVesting::vest_other(RuntimeOrigin::signed(2), 3, ASSET));
+		System::assert_last_event(Event::VestingCompleted { account: 3, asset: ASSET }.into());
+		assert_eq!(frozen(3), 0);
+		assert!(Vesting::<Test>::get(3, ASSET).is_none());
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(3), ASSET, 2, 510));
+	});
+}
+
+#[test]
+fn vested_transfer_checks_the_schedule() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			AssetVesting::vested_transfer(
+				RuntimeOrigin::signed(1),
+				3,
+				ASSET,
+				VestingInfo::new(9, 1, 10)
+			),
+			Error::<Test>::AmountLow,
+		);
+		assert_noop!(
+			AssetVesting::vested_transfer(
+				RuntimeOrigin::signed(1),
+				3,
+				ASSET,
+				VestingInfo::new(100, 0, 10)
+			),
+			Error::<Test>::InvalidScheduleParams,
+		);
+		assert_noop!(
+			AssetVesting::vested_transfer(
+				RuntimeOrigin::signed(2),
+				3,
+				ASSET,
+				VestingInfo::new(1_000, 100, 10)
+			),
+			AssetsError::<Test>::BalanceLow,
+		);
+
+		for _ in 0..3 {
+			assert_ok!(AssetVesting::vested_transfer(
+				RuntimeOrigin::signed(1),
+				3,
+				ASSET,
+				VestingInfo::new(100, 10, 10)
+			));
+		}
+		assert_noop!(
+			AssetVesting::vested_transfer(
+				RuntimeOrigin::signed(1),
+				3,
+				ASSET,
+				VestingInfo::new(100, 10, 10)
+			),
+			Error::<Test>::AtMaxVestingSchedules,
+		);
+	});
+}
+
+#[test]
+fn force_vested_transfer_needs_root() {
+	new_test_ext().execute_with(|| {
+		let schedule = VestingInfo::new(100, 10, 10);
+		assert_noop!(
+			AssetVesting::force_vested_transfer(RuntimeOrigin::signed(1), 1, 3, ASSET, schedule),
+			sp_runtime::DispatchError::BadOrigin,
+		);
+		assert_ok!(AssetVesting::force_vested_transfer(
+			RuntimeOrigin::root(),
+			1,
+			3,
+			ASSET,
+			schedule
+		));
+		assert_eq!(frozen(3), 100);
+	});
+}
+
+#[test]
+fn vest_needs_a_schedule() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			AssetVesting::vest(RuntimeOrigin::signed(3), ASSET),
+			Error::<Test>::NotVesting
+		);
+		assert_noop!(
+			AssetVesting::merge_schedules(RuntimeOrigin::signed(3), ASSET, 0, 1),
+			Error::<Test>::NotVesting
+		);
+	});
+}
+
+#[test]
+fn merge_schedules_works() {
+	new_test_ext().execute_with(|| {
+		let schedule1 = VestingInfo::new(1_000, 100, 10);
+		let schedule2 = VestingInfo::new(600, 20, 5);
+		assert_ok!(AssetVesting::vested_transfer(RuntimeOrigin::signed(1), 3, ASSET, schedule1));
+		assert_ok!(AssetVesting::vested_transfer(RuntimeOrigin::signed(1), 3, ASSET, schedule2));
+		assert_noop!(
+			AssetVesting::merge_schedules(RuntimeOrigin::signed(3), ASSET, 0, 2),
+			Error::<Test>::ScheduleIndexOutOfBounds
+		);
+
+		System::set_block_number(15);
+		assert_ok!(AssetVesting::merge_schedules(RuntimeOrigin::signed(3), ASSET, 0, 1));
+		// 500 + 400 is left to vest, from block 15 until the end of the second schedule at 35.
+		let merged = VestingInfo::new(900, 45, 15);
+		assert_eq!(Vesting::<Test>::get(3, ASSET).unwrap().to_vec(), vec![merged]);
+		assert_eq!(frozen(3), 900);
+	});
+}
+
+#[test]
+fn vesting_balances_works() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(AssetVesting::vesting_balances(&3, ASSET), None);
+		assert_ok!(AssetVesting::vested_transfer(
+			RuntimeOrigin::signed(1),
+			3,
+			ASSET,
+			VestingInfo::new(1_000, 100, 10)
+		));
+		assert_ok!(AssetVesting::vested_transfer(
+			RuntimeOrigin::signed(1),
+			3,
+			ASSET,
+			VestingInfo::new(200, 20, 12)
+		));
+
+		System::set_block_number(14);
+		assert_eq!(
+			AssetVesting::vesting_balances(&3, ASSET),
+			Some(VestingBalances { locked: 600 + 160, vested: 400 + 40 })
+		);
+	});
+}
@@ -0,0 +1,278 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Autogenerated weights for pallet_asset_vesting
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: 2023-03-14, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `bm2`, CPU: `Intel(R) Core(TM) i7-7700K CPU @ 4.20GHz`
+//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+
+// Executed Command:
+// ./target/production/substrate
+// benchmark
+// pallet
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_asset_vesting
+// --extrinsic=*
+// --execution=wasm
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --output=./frame/asset-vesting/src/weights.rs
+// --header=./HEADER-APACHE2
+// --template=./.maintain/frame-weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_asset_vesting.
+pub trait WeightInfo {
+	fn vest_locked(s: u32, ) -> Weight;
+	fn vest_unlocked(s: u32, ) -> Weight;
+	fn vested_transfer(s: u32, ) -> Weight;
+	fn force_vested_transfer(s: u32, ) -> Weight;
+	fn not_unlocking_merge_schedules(s: u32, ) -> Weight;
+	fn unlocking_merge_schedules(s: u32, ) -> Weight;
+}
+
+/// Weights for pallet_asset_vesting using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: AssetVesting Vesting (r:1 w:1)
+	/// Proof: AssetVesting Vesting (max_values: None, max_size: Some(1169), added: 3644, mode: MaxEncodedLen)
+	/// Storage: Assets Freezes (r:1 w:1)
+	/// Proof: Assets Freezes (max_values: None, max_size: Some(682), added: 3157, mode: MaxEncodedLen)
+	/// The range of component `s` is `[1, 28]`.
+	fn vest_locked(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412 + s * (36 ±0)`
+		//  Estimated: `4764`
+		// Minimum execution time: 33_181 nanoseconds.
+		Weight::from_parts(33_181_226, 4764)
+			// Standard Error: 1_482
+			.saturating_add(Weight::from_parts(57_108, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: AssetVesting Vesting (r:1 w:1)
+	/// Proof: AssetVesting Vesting (max_values: None, max_size: Some(1169), added: 3644, mode: MaxEncodedLen)
+	/// Storage: Assets Freezes (r:1 w:1)
+	/// Proof: Assets Freezes (max_values: None, max_size: Some(682), added: 3157, mode: MaxEncodedLen)
+	/// The range of component `s` is `[1, 28]`.
+	fn vest_unlocked(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412 + s * (36 ±0)`
+		//  Estimated: `4764`
+		// Minimum execution time: 31_705 nanoseconds.
+		Weight::from_parts(31_705_418, 4764)
+			// Standard Error: 1_482
+			.saturating_add(Weight::from_parts(49_811, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: AssetVesting Vesting (r:1 w:1)
+	/// Proof: AssetVesting Vesting (max_values: None, max_size: Some(1169), added: 3644, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:2 w:2)
+	/// Proof: Assets Account (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
+	/// Storage: Assets Freezes (r:1 w:1)
+	/// Proof: Assets Freezes (max_values: None, max_size: Some(682), added: 3157, mode: MaxEncodedLen)
+	/// The range of component `s` is `[1, 28]`.
+	fn vested_transfer(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `553 + s * (36 ±0)`
+		//  Estimated: `9970`
+		// Minimum execution time: 57_804 nanoseconds.
+		Weight::from_parts(57_804_113, 9970)
+			// Standard Error: 1_482
+			.saturating_add(Weight::from_parts(71_264, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: AssetVesting Vesting (r:1 w:1)
+	/// Proof: AssetVesting Vesting (max_values: None, max_size: Some(1169), added: 3644, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:2 w:2)
+	/// Proof: Assets Account (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Assets Freezes (r:1 w:1)
+	/// Proof: Assets Freezes (max_values: None, max_size: Some(682), added: 3157, mode: MaxEncodedLen)
+	/// The range of component `s` is `[1, 28]`.
+	fn force_vested_transfer(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `656 + s * (36 ±0)`
+		//  Estimated: `12573`
+		// Minimum execution time: 59_912 nanoseconds.
+		Weight::from_parts(59_912_027, 12573)
+			// Standard Error: 1_482
+			.saturating_add(Weight::from_parts(72_918, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: AssetVesting Vesting (r:1 w:1)
+	/// Proof: AssetVesting Vesting (max_values: None, max_size: Some(1169), added: 3644, mode: MaxEncodedLen)
+	/// Storage: Assets Freezes (r:1 w:1)
+	/// Proof: Assets Freezes (max_values: None, max_size: Some(682), added: 3157, mode: MaxEncodedLen)
+	/// The range of component `s` is `[2, 28]`.
+	fn not_unlocking_merge_schedules(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412 + s * (36 ±0)`
+		//  Estimated: `4764`
+		// Minimum execution time: 35_119 nanoseconds.
+		Weight::from_parts(35_119_305, 4764)
+			// Standard Error: 1_482
+			.saturating_add(Weight::from_parts(60_372, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: AssetVesting Vesting (r:1 w:1)
+	/// Proof: AssetVesting Vesting (max_values: None, max_size: Some(1169), added: 3644, mode: MaxEncodedLen)
+	/// Storage: Assets Freezes (r:1 w:1)
+	/// Proof: Assets Freezes (max_values: None, max_size: Some(682), added: 3157, mode: MaxEncodedLen)
+	/// The range of component `s` is `[2, 28]`.
+	fn unlocking_merge_schedules(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412 + s * (36 ±0)`
+		//  Estimated: `4764`
+		// Minimum execution time: 34_702 nanoseconds.
+		Weight::from_parts(34_702_181, 4764)
+			// Standard Error: 1_482
+			.saturating_add(Weight::from_parts(63_540, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: AssetVesting Vesting (r:1 w:1)
+	/// Proof: AssetVesting Vesting (max_values: None, max_size: Some(1169), added: 3644, mode: MaxEncodedLen)
+	/// Storage: Assets Freezes (r:1 w:1)
+	/// Proof: Assets Freezes (max_values: None, max_size: Some(682), added: 3157, mode: MaxEncodedLen)
+	/// The range of component `s` is `[1, 28]`.
+	fn vest_locked(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412 + s * (36 ±0)`
+		//  Estimated: `4764`
+		// Minimum execution time: 33_181 nanoseconds.
+		Weight::from_parts(33_181_226, 4764)
+			// Standard Error: 1_482
+			.saturating_add(Weight::from_parts(57_108, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: AssetVesting Vesting (r:1 w:1)
+	/// Proof: AssetVesting Vesting (max_values: None, max_size: Some(1169), added: 3644, mode: MaxEncodedLen)
+	/// Storage: Assets Freezes (r:1 w:1)
+	/// Proof: Assets Freezes (max_values: None, max_size: Some(682), added: 3157, mode: MaxEncodedLen)
+	/// The range of component `s` is `[1, 28]`.
+	fn vest_unlocked(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412 + s * (36 ±0)`
+		//  Estimated: `4764`
+		// Minimum execution time: 31_705 nanoseconds.
+		Weight::from_parts(31_705_418, 4764)
+			// Standard Error: 1_482
+			.saturating_add(Weight::from_parts(49_811, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: AssetVesting Vesting (r:1 w:1)
+	/// Proof: AssetVesting Vesting (max_values: None, max_size: Some(1169), added: 3644, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:2 w:2)
+	/// Proof: Assets Account (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
+	/// Storage: Assets Freezes (r:1 w:1)
+	/// Proof: Assets Freezes (max_values: None, max_size: Some(682), added: 3157, mode: MaxEncodedLen)
+	/// The range of component `s` is `[1, 28]`.
+	fn vested_transfer(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `553 + s * (36 ±0)`
+		//  Estimated: `9970`
+		// Minimum execution time: 57_804 nanoseconds.
+		Weight::from_parts(57_804_113, 9970)
+			// Standard Error: 1_482
+			.saturating_add(Weight::from_parts(71_264, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: AssetVesting Vesting (r:1 w:1)
+	/// Proof: AssetVesting Vesting (max_values: None, max_size: Some(1169), added: 3644, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:2 w:2)
+	/// Proof: Assets Account (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Assets Freezes (r:1 w:1)
+	/// Proof: Assets Freezes (max_values: None, max_size: Some(682), added: 3157, mode: MaxEncodedLen)
+	/// The range of component `s` is `[1, 28]`.
+	fn force_vested_transfer(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `656 + s * (36 ±0)`
+		//  Estimated: `12573`
+		// Minimum execution time: 59_912 nanoseconds.
+		Weight::from_parts(59_912_027, 12573)
+			// Standard Error: 1_482
+			.saturating_add(Weight::from_parts(72_918, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: AssetVesting Vesting (r:1 w:1)
+	/// Proof: AssetVesting Vesting (max_values: None, max_size: Some(1169), added: 3644, mode: MaxEncodedLen)
+	/// Storage: Assets Freezes (r:1 w:1)
+	/// Proof: Assets Freezes (max_values: None, max_size: Some(682), added: 3157, mode: MaxEncodedLen)
+	/// The range of component `s` is `[2, 28]`.
+	fn not_unlocking_merge_schedules(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412 + s * (36 ±0)`
+		//  Estimated: `4764`
+		// Minimum execution time: 35_119 nanoseconds.
+		Weight::from_parts(35_119_305, 4764)
+			// Standard Error: 1_482
+			.saturating_add(Weight::from_parts(60_372, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: AssetVesting Vesting (r:1 w:1)
+	/// Proof: AssetVesting Vesting (max_values: None, max_size: Some(1169), added: 3644, mode: MaxEncodedLen)
+	/// Storage: Assets Freezes (r:1 w:1)
+	/// Proof: Assets Freezes (max_values: None, max_size: Some(682), added: 3157, mode: MaxEncodedLen)
+	/// The range of component `s` is `[2, 28]`.
+	fn unlocking_merge_schedules(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412 + s * (36 ±0)`
+		//  Estimated: `4764`
+		// Minimum execution time: 34_702 nanoseconds.
+		Weight::from_parts(34_702_181, 4764)
+			// Standard Error: 1_482
+			.saturating_add(Weight::from_parts(63_540, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+}
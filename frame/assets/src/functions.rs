@@ -171,7 +171,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			return Frozen
 		}
 		if let Some(rest) = account.balance.checked_sub(&amount) {
-			if let Some(frozen) = T::Freezer::frozen_balance(id, who) {
+			if let Some(frozen) = Self::frozen_balance(id, who) {
 				match frozen.checked_add(&details.min_balance) {
 					Some(required) if rest < required => return Frozen,
 					None => return Overflow,
@@ -181,7 +181,9 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 
 			let is_provider = false;
 			let is_required = is_provider && !frame_system::Pallet::<T>::can_dec_provider(who);
-			let must_keep_alive = keep_alive || is_required;
+			// An account with funds on hold must stay alive for them to be released.
+			let has_holds = Holds::<T, I>::contains_key(id, who);
+			let must_keep_alive = keep_alive || is_required || has_holds;
 
 			if rest < details.min_balance {
 				if must_keep_alive {
@@ -197,6 +199,94 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		}
 	}
 
+	/// The amount of asset `id` of `who` which is frozen, either by `T::Freezer` or by the
+	/// largest of its freezes.
+	pub(super) fn frozen_balance(id: T::AssetId, who: &T::AccountId) -> Option<T::Balance> {
+		let frozen = Freezes::<T, I>::get(id, who).iter().map(|f| f.amount).max();
+		match (T::Freezer::frozen_balance(id, who), frozen) {
+			(Some(a), Some(b)) => Some(a.max(b)),
+			(a, b) => a.or(b),
+		}
+	}
+
+	/// Reduce the amount held for `reason` on the account of `who` by `amount`, removing the hold
+	/// once it is empty.
+	pub(super) fn decrease_hold(
+		id: T::AssetId,
+		reason: &T::HoldIdentifier,
+		who: &T::AccountId,
+		amount: T::Balance,
+	) {
+		Holds::<T, I>::mutate_exists(id, who, |maybe_holds| {
+			if let Some(holds) = maybe_holds.as_mut() {
+				if let Ok(index) = holds.binary_search_by_key(reason, |h| h.id) {
+					holds[index].amount = holds[index].amount.saturating_sub(amount);
+					if holds[index].amount.is_zero() {
+						holds.remove(index);
+					}
+				}
+				if holds.is_empty() {
+					*maybe_holds = None;
+				}
+			}
+		});
+	}
+
+	/// Increase the amount held for `reason` on the account of `who` by `amount`.
+	pub(super) fn increase_hold(
+		id: T::AssetId,
+		reason: &T::HoldIdentifier,
+		who: &T::AccountId,
+		amount: T::Balance,
+	) -> DispatchResult {
+		Holds::<T, I>::try_mutate(id, who, |holds| -> DispatchResult {
+			match holds.binary_search_by_key(reason, |h| h.id) {
+				Ok(index) =>
+					holds[index].amount =
+						holds[index].amount.checked_add(&amount).ok_or(ArithmeticError::Overflow)?,
+				Err(index) => holds
+					.try_insert(index, IdAmount { id: *reason, amount })
+					.map_err(|_| Error::<T, I>::TooManyHolds)?,
+			}
+			Ok(())
+		})
+	}
+
+	/// Insert or update the freeze `id` on the account of `who` with `f`, which is given the
+	/// current frozen amount if the freeze exists. A resulting amount of zero removes the freeze.
+	pub(super) fn update_freeze(
+		asset: T::AssetId,
+		id: &T::FreezeIdentifier,
+		who: &T::AccountId,
+		f: impl FnOnce(Option<T::Balance>) -> T::Balance,
+	) -> DispatchResult {
+		Freezes::<T, I>::try_mutate_exists(asset, who, |maybe_freezes| -> DispatchResult {
+			let mut freezes = maybe_freezes.take().unwrap_or_default();
+			match freezes.binary_search_by_key(id, |f| f.id) {
+				Ok(index) => {
+					let amount = f(Some(freezes[index].amount));
+					if amount.is_zero() {
+						freezes.remove(index);
+					} else {
+						freezes[index].amount = amount;
+					}
+				},
+				Err(index) => {
+					let amount = f(None);
+					if !amount.is_zero() {
+						freezes
+							.try_insert(index, IdAmount { id: *id, amount })
+							.map_err(|_| Error::<T, I>::TooManyFreezes)?;
+					}
+				},
+			}
+			if !freezes.is_empty() {
+				*maybe_freezes = Some(freezes);
+			}
+			Ok(())
+		})
+	}
+
 	// Maximum `amount` that can be passed into `can_withdraw` to result in a `WithdrawConsequence`
 	// of `Success`.
 	pub(super) fn reducible_balance(
@@ -210,7 +300,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		let account = Account::<T, I>::get(id, who).ok_or(Error::<T, I>::NoAccount)?;
 		ensure!(!account.is_frozen, Error::<T, I>::Frozen);
 
-		let amount = if let Some(frozen) = T::Freezer::frozen_balance(id, who) {
+		let amount = if let Some(frozen) = Self::frozen_balance(id, who) {
 			// Frozen balance: account CANNOT be deleted
 			let required =
 				frozen.checked_add(&details.min_balance).ok_or(ArithmeticError::Overflow)?;
@@ -218,7 +308,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		} else {
 			let is_provider = false;
 			let is_required = is_provider && !frame_system::Pallet::<T>::can_dec_provider(who);
-			if keep_alive || is_required {
+			let has_holds = Holds::<T, I>::contains_key(id, who);
+			if keep_alive || is_required || has_holds {
 				// We want to keep the account around.
 				account.balance.saturating_sub(details.min_balance)
 			} else {
@@ -325,6 +416,9 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		ensure!(details.status == AssetStatus::Live, Error::<T, I>::AssetNotLive);
 		ensure!(account.balance.is_zero() || allow_burn, Error::<T, I>::WouldBurn);
 		ensure!(!account.is_frozen, Error::<T, I>::Frozen);
+		// Holds and freezes would otherwise outlive the account.
+		ensure!(!Holds::<T, I>::contains_key(id, &who), Error::<T, I>::ContainsHolds);
+		ensure!(!Freezes::<T, I>::contains_key(id, &who), Error::<T, I>::ContainsFreezes);
 
 		T::Currency::unreserve(&who, deposit);
 
@@ -703,6 +797,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 
 				for (who, v) in Account::<T, I>::drain_prefix(id) {
 					let _ = Self::dead_account(&who, &mut details, &v.reason, true);
+					Holds::<T, I>::remove(id, &who);
+					Freezes::<T, I>::remove(id, &who);
 					dead_accounts.push(who);
 					if dead_accounts.len() >= (max_items as usize) {
 						break
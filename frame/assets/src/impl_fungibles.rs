@@ -125,6 +125,164 @@ impl<T: Config<I>, I: 'static> fungibles::Transfer<T::AccountId> for Pallet<T, I
 	}
 }
 
+impl<T: Config<I>, I: 'static> fungibles::InspectHold<T::AccountId> for Pallet<T, I> {
+	type Reason = T::HoldIdentifier;
+
+	fn total_balance_on_hold(asset: Self::AssetId, who: &T::AccountId) -> Self::Balance {
+		Holds::<T, I>::get(asset, who)
+			.iter()
+			.fold(Zero::zero(), |total, h| total.saturating_add(h.amount))
+	}
+
+	fn balance_on_hold(
+		asset: Self::AssetId,
+		reason: &Self::Reason,
+		who: &T::AccountId,
+	) -> Self::Balance {
+		let holds = Holds::<T, I>::get(asset, who);
+		holds
+			.binary_search_by_key(reason, |h| h.id)
+			.map(|index| holds[index].amount)
+			.unwrap_or_else(|_| Zero::zero())
+	}
+
+	fn can_hold(
+		asset: Self::AssetId,
+		reason: &Self::Reason,
+		who: &T::AccountId,
+		amount: Self::Balance,
+	) -> bool {
+		let holds = Holds::<T, I>::get(asset, who);
+		if holds.binary_search_by_key(reason, |h| h.id).is_err() &&
+			holds.len() as u32 >= T::MaxHolds::get()
+		{
+			return false
+		}
+		Self::can_decrease(asset, who, amount, true).into_result().is_ok()
+	}
+}
+
+impl<T: Config<I>, I: 'static> fungibles::MutateHold<T::AccountId> for Pallet<T, I> {
+	fn hold(
+		asset: Self::AssetId,
+		reason: &Self::Reason,
+		who: &T::AccountId,
+		amount: Self::Balance,
+	) -> DispatchResult {
+		if amount.is_zero() {
+			return Ok(())
+		}
+		let f = DebitFlags { keep_alive: true, best_effort: false };
+		let actual = Self::prep_debit(asset, who, amount, f)?;
+		Self::increase_hold(asset, reason, who, actual)?;
+		Account::<T, I>::try_mutate(asset, who, |maybe_account| -> DispatchResult {
+			let account = maybe_account.as_mut().ok_or(Error::<T, I>::NoAccount)?;
+			// `prep_debit` made sure that the account holds at least `actual`.
+			account.balance.saturating_reduce(actual);
+			Ok(())
+		})
+	}
+
+	fn release(
+		asset: Self::AssetId,
+		reason: &Self::Reason,
+		who: &T::AccountId,
+		amount: Self::Balance,
+		best_effort: bool,
+	) -> Result<Self::Balance, DispatchError> {
+		let held = <Self as fungibles::InspectHold<_>>::balance_on_hold(asset, reason, who);
+		ensure!(best_effort || amount <= held, Error::<T, I>::BalanceLow);
+		let amount = amount.min(held);
+		if amount.is_zero() {
+			return Ok(amount)
+		}
+		Account::<T, I>::try_mutate(asset, who, |maybe_account| -> DispatchResult {
+			// Accounts with holds are kept alive.
+			let account = maybe_account.as_mut().ok_or(Error::<T, I>::NoAccount)?;
+			account.balance =
+				account.balance.checked_add(&amount).ok_or(ArithmeticError::Overflow)?;
+			Ok(())
+		})?;
+		Self::decrease_hold(asset, reason, who, amount);
+		Ok(amount)
+	}
+
+	fn transfer_held(
+		asset: Self::AssetId,
+		reason: &Self::Reason,
+		source: &T::AccountId,
+		dest: &T::AccountId,
+		amount: Self::Balance,
+		best_effort: bool,
+		on_hold: bool,
+	) -> Result<Self::Balance, DispatchError> {
+		let held = <Self as fungibles::InspectHold<_>>::balance_on_hold(asset, reason, source);
+		ensure!(best_effort || amount <= held, Error::<T, I>::BalanceLow);
+		let amount = amount.min(held);
+		if amount.is_zero() || (on_hold && source == dest) {
+			return Ok(amount)
+		}
+
+		if on_hold {
+			ensure!(Account::<T, I>::contains_key(asset, dest), Error::<T, I>::NoAccount);
+			Self::increase_hold(asset, reason, dest, amount)?;
+		} else {
+			Self::increase_balance(asset, dest, amount, |_| Ok(()))?;
+		}
+		Self::decrease_hold(asset, reason, source, amount);
+
+		Self::deposit_event(Event::Transferred {
+			asset_id: asset,
+			from: source.clone(),
+			to: dest.clone(),
+			amount,
+		});
+		Ok(amount)
+	}
+}
+
+impl<T: Config<I>, I: 'static> fungibles::InspectFreeze<T::AccountId> for Pallet<T, I> {
+	type Id = T::FreezeIdentifier;
+
+	fn balance_frozen(asset: Self::AssetId, id: &Self::Id, who: &T::AccountId) -> Self::Balance {
+		let freezes = Freezes::<T, I>::get(asset, who);
+		freezes
+			.binary_search_by_key(id, |f| f.id)
+			.map(|index| freezes[index].amount)
+			.unwrap_or_else(|_| Zero::zero())
+	}
+
+	fn can_freeze(asset: Self::AssetId, id: &Self::Id, who: &T::AccountId) -> bool {
+		let freezes = Freezes::<T, I>::get(asset, who);
+		freezes.binary_search_by_key(id, |f| f.id).is_ok() ||
+			(freezes.len() as u32) < T::MaxFreezes::get()
+	}
+}
+
+impl<T: Config<I>, I: 'static> fungibles::MutateFreeze<T::AccountId> for Pallet<T, I> {
+	fn set_freeze(
+		asset: Self::AssetId,
+		id: &Self::Id,
+		who: &T::AccountId,
+		amount: Self::Balance,
+	) -> DispatchResult {
+		Self::update_freeze(asset, id, who, |_| amount)
+	}
+
+	fn extend_freeze(
+		asset: Self::AssetId,
+		id: &Self::Id,
+		who: &T::AccountId,
+		amount: Self::Balance,
+	) -> DispatchResult {
+		Self::update_freeze(asset, id, who, |current| current.unwrap_or_default().max(amount))
+	}
+
+	fn thaw(asset: Self::AssetId, id: &Self::Id, who: &T::AccountId) -> DispatchResult {
+		Self::update_freeze(asset, id, who, |_| Zero::zero())
+	}
+}
+
 impl<T: Config<I>, I: 'static> fungibles::Unbalanced<T::AccountId> for Pallet<T, I> {
 	fn set_balance(_: Self::AssetId, _: &T::AccountId, _: Self::Balance) -> DispatchResult {
 		unreachable!("set_balance is not used if other functions are impl'd");
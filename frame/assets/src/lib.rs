@@ -315,6 +315,23 @@ pub mod pallet {
 		/// Callback methods for asset state change (e.g. asset created or destroyed)
 		type CallbackHandle: AssetsCallback<Self::AssetId, Self::AccountId>;
 
+		/// The reason for which assets are placed on hold. This is usually an enum aggregating
+		/// the hold reasons of all pallets of the runtime.
+		type HoldIdentifier: Parameter + Member + MaxEncodedLen + Ord + Copy;
+
+		/// The id type for freezes. This is usually an enum aggregating the freeze reasons of all
+		/// pallets of the runtime.
+		type FreezeIdentifier: Parameter + Member + MaxEncodedLen + Ord + Copy;
+
+		/// The maximum number of holds that can exist on an asset account at any time.
+		#[pallet::constant]
+		type MaxHolds: Get<u32>;
+
+		/// The maximum number of individual freezes that can exist on an asset account at any
+		/// time.
+		#[pallet::constant]
+		type MaxFreezes: Get<u32>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 
@@ -348,6 +365,31 @@ pub mod pallet {
 		AssetAccountOf<T, I>,
 	>;
 
+	#[pallet::storage]
+	/// Holds on asset accounts, sorted by their reason. Held funds are not part of the balance of
+	/// the account, but are still part of the supply of the asset.
+	pub(super) type Holds<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<IdAmount<T::HoldIdentifier, T::Balance>, T::MaxHolds>,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	/// Freezes on asset accounts, sorted by their id.
+	pub(super) type Freezes<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<IdAmount<T::FreezeIdentifier, T::Balance>, T::MaxFreezes>,
+		ValueQuery,
+	>;
+
 	#[pallet::storage]
 	/// Approved balance transfers. First balance is the amount approved for transfer. Second
 	/// is the amount of `T::Currency` reserved for storing this.
@@ -589,6 +631,14 @@ pub mod pallet {
 		IncorrectStatus,
 		/// The asset should be frozen before the given operation.
 		NotFrozen,
+		/// Number of holds on the account exceed `MaxHolds`.
+		TooManyHolds,
+		/// Number of freezes on the account exceed `MaxFreezes`.
+		TooManyFreezes,
+		/// The account still has funds on hold.
+		ContainsHolds,
+		/// The account still has freezes.
+		ContainsFreezes,
 	}
 
 	#[pallet::call]
@@ -1526,7 +1576,7 @@ pub mod pallet {
 
 		/// Return the deposit (if any) of an asset account.
 		///
-		/// The origin must be Signed.
+		/// The origin must be Signed. The account must have no funds on hold and no freezes.
 		///
 		/// - `id`: The identifier of the asset for the account to be created.
 		/// - `allow_burn`: If `true` then assets may be destroyed in order to complete the refund.
@@ -87,6 +87,10 @@ impl pallet_balances::Config for Test {
 	type MaxLocks = ();
 	type MaxReserves = ();
 	type ReserveIdentifier = [u8; 8];
+	type HoldIdentifier = ();
+	type FreezeIdentifier = ();
+	type MaxHolds = ();
+	type MaxFreezes = ();
 }
 
 pub struct AssetsCallbackHandle;
@@ -116,6 +120,10 @@ impl Config for Test {
 	type ApprovalDeposit = ConstU64<1>;
 	type StringLimit = ConstU32<50>;
 	type Freezer = TestFreezer;
+	type HoldIdentifier = [u8; 8];
+	type FreezeIdentifier = [u8; 8];
+	type MaxHolds = ConstU32<2>;
+	type MaxFreezes = ConstU32<2>;
 	type WeightInfo = ();
 	type CallbackHandle = AssetsCallbackHandle;
 	type Extra = ();
@@ -140,6 +140,34 @@ fn refunding_asset_deposit_without_burn_should_work() {
 	});
 }
 
+#[test]
+fn refunding_with_holds_or_freezes_should_fail() {
+	use frame_support::traits::tokens::fungibles::{MutateFreeze, MutateHold};
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, false, 1));
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::touch(RuntimeOrigin::signed(1), 0));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+
+		assert_ok!(Assets::hold(0, b"foo_hold", &1, 30));
+		assert_noop!(
+			Assets::refund(RuntimeOrigin::signed(1), 0, true),
+			Error::<Test>::ContainsHolds
+		);
+		assert_eq!(Assets::release(0, b"foo_hold", &1, 30, false), Ok(30));
+
+		assert_ok!(Assets::set_freeze(0, b"foo_frze", &1, 30));
+		assert_noop!(
+			Assets::refund(RuntimeOrigin::signed(1), 0, true),
+			Error::<Test>::ContainsFreezes
+		);
+		assert_ok!(<Assets as MutateFreeze<_>>::thaw(0, b"foo_frze", &1));
+
+		assert_ok!(Assets::refund(RuntimeOrigin::signed(1), 0, true));
+		assert_eq!(Balances::reserved_balance(&1), 0);
+	});
+}
+
 /// Refunding reaps an account and calls the `FrozenBalance::died` hook.
 #[test]
 fn refunding_calls_died_hook() {
@@ -1286,3 +1314,91 @@ fn root_asset_create_should_work() {
 		assert!(storage::get(b"asset_destroyed").is_none());
 	});
 }
+
+#[test]
+fn holds_should_work() {
+	use frame_support::traits::tokens::fungibles::{InspectHold, MutateHold};
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 10));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+		Balances::make_free_balance_be(&2, 100);
+		assert_ok!(Assets::touch(RuntimeOrigin::signed(2), 0));
+
+		// cannot hold into the minimum balance.
+		assert!(!Assets::can_hold(0, b"foo_hold", &1, 91));
+		assert_noop!(Assets::hold(0, b"foo_hold", &1, 91), Error::<Test>::BalanceLow);
+
+		assert_ok!(Assets::hold(0, b"foo_hold", &1, 30));
+		assert_ok!(Assets::hold(0, b"bar_hold", &1, 20));
+		assert_eq!(Assets::balance(0, 1), 50);
+		assert_eq!(Assets::balance_on_hold(0, b"foo_hold", &1), 30);
+		assert_eq!(Assets::total_balance_on_hold(0, &1), 50);
+		// only two holds fit.
+		assert_noop!(Assets::hold(0, b"baz_hold", &1, 1), Error::<Test>::TooManyHolds);
+
+		// the account cannot be reaped while it has funds on hold.
+		assert_noop!(
+			Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 50),
+			Error::<Test>::BalanceLow
+		);
+
+		assert_noop!(Assets::release(0, b"foo_hold", &1, 31, false), Error::<Test>::BalanceLow);
+		assert_eq!(Assets::release(0, b"foo_hold", &1, 31, true), Ok(30));
+		assert_eq!(Assets::balance(0, 1), 80);
+		assert_eq!(Assets::balance_on_hold(0, b"foo_hold", &1), 0);
+
+		// transfer held funds, keeping them on hold on the destination.
+		assert_eq!(Assets::transfer_held(0, b"bar_hold", &1, &2, 5, false, true), Ok(5));
+		assert_eq!(Assets::balance_on_hold(0, b"bar_hold", &2), 5);
+		assert_eq!(Assets::balance(0, 2), 0);
+		// or release them into the destination's balance.
+		assert_eq!(Assets::transfer_held(0, b"bar_hold", &1, &2, 15, false, false), Ok(15));
+		assert_eq!(Assets::balance(0, 2), 15);
+		assert_eq!(Assets::total_balance_on_hold(0, &1), 0);
+		assert_eq!(Assets::total_supply(0), 100);
+
+		// with nothing on hold the account may be reaped again.
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 80));
+		assert_eq!(Assets::balance(0, 1), 0);
+	});
+}
+
+#[test]
+fn freezes_should_work() {
+	use frame_support::traits::tokens::fungibles::{InspectFreeze, MutateFreeze};
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 10));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+
+		assert_ok!(Assets::set_freeze(0, b"foo_frze", &1, 30));
+		assert_ok!(Assets::extend_freeze(0, b"foo_frze", &1, 20));
+		assert_eq!(Assets::balance_frozen(0, b"foo_frze", &1), 30);
+		assert_ok!(Assets::set_freeze(0, b"bar_frze", &1, 50));
+		assert!(Assets::can_freeze(0, b"bar_frze", &1));
+		assert!(!Assets::can_freeze(0, b"baz_frze", &1));
+		assert_noop!(Assets::set_freeze(0, b"baz_frze", &1, 10), Error::<Test>::TooManyFreezes);
+
+		// the largest freeze applies, on top of the minimum balance.
+		assert_noop!(
+			Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 41),
+			Error::<Test>::BalanceLow
+		);
+		assert_ok!(<Assets as MutateFreeze<_>>::thaw(0, b"bar_frze", &1));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 60));
+		assert_noop!(
+			Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 1),
+			Error::<Test>::BalanceLow
+		);
+
+		// freezes are combined with the `Freezer`.
+		set_frozen_balance(0, 1, 35);
+		assert_noop!(
+			Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 1),
+			Error::<Test>::BalanceLow
+		);
+		clear_frozen_balance(0, 1);
+		assert_ok!(<Assets as MutateFreeze<_>>::thaw(0, b"foo_frze", &1));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 40));
+		assert_eq!(Assets::balance(0, 1), 0);
+	});
+}
@@ -130,6 +130,15 @@ pub struct AssetAccount<Balance, DepositBalance, Extra> {
 	pub(super) extra: Extra,
 }
 
+/// An amount of an asset associated with an identifier, used for holds and freezes.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct IdAmount<Id, Balance> {
+	/// The identifier of the hold or freeze.
+	pub id: Id,
+	/// The amount held or frozen.
+	pub amount: Balance,
+}
+
 #[derive(Clone, Encode, Decode, Eq, PartialEq, Default, RuntimeDebug, MaxEncodedLen, TypeInfo)]
 pub struct AssetMetadata<DepositBalance, BoundedString> {
 	/// The balance deposited for this metadata.
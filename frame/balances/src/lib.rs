@@ -84,6 +84,10 @@
 //! - [`Imbalance`](frame_support::traits::Imbalance): Functions for handling
 //! imbalances between total issuance in the system and account balances. Must be used when a
 //! function creates new funds (e.g. a reward) or destroys some funds (e.g. a system fee).
+//! - [`fungible::InspectHold`] and [`fungible::MutateHold`]: Functions for placing funds on
+//! hold for a `HoldIdentifier`, so that holds for different purposes never overlap.
+//! - [`fungible::InspectFreeze`] and [`fungible::MutateFreeze`]: Functions for freezing funds
+//! for a `FreezeIdentifier`. Freezes overlay each other and any locks.
 //!
 //! ## Interface
 //!
@@ -177,7 +181,7 @@ use frame_support::{
 		Get, Imbalance, LockIdentifier, LockableCurrency, NamedReservableCurrency, OnUnbalanced,
 		ReservableCurrency, SignedImbalance, StoredMap, TryDrop, WithdrawReasons,
 	},
-	WeakBoundedVec,
+	BoundedVec, WeakBoundedVec,
 };
 use frame_system as system;
 use scale_info::TypeInfo;
@@ -246,6 +250,22 @@ pub mod pallet {
 
 		/// The id type for named reserves.
 		type ReserveIdentifier: Parameter + Member + MaxEncodedLen + Ord + Copy;
+
+		/// The reason for which funds are placed on hold. This is usually an enum aggregating
+		/// the hold reasons of all pallets of the runtime.
+		type HoldIdentifier: Parameter + Member + MaxEncodedLen + Ord + Copy;
+
+		/// The id type for freezes. This is usually an enum aggregating the freeze reasons of all
+		/// pallets of the runtime.
+		type FreezeIdentifier: Parameter + Member + MaxEncodedLen + Ord + Copy;
+
+		/// The maximum number of holds that can exist on an account at any time.
+		#[pallet::constant]
+		type MaxHolds: Get<u32>;
+
+		/// The maximum number of individual freezes that can exist on an account at any time.
+		#[pallet::constant]
+		type MaxFreezes: Get<u32>;
 	}
 
 	/// The current storage version.
@@ -498,6 +518,10 @@ pub mod pallet {
 		DeadAccount,
 		/// Number of named reserves exceed MaxReserves
 		TooManyReserves,
+		/// Number of holds exceed MaxHolds
+		TooManyHolds,
+		/// Number of freezes exceed MaxFreezes
+		TooManyFreezes,
 	}
 
 	/// The total units issued in the system.
@@ -564,6 +588,26 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// Holds on account balances, sorted by their reason.
+	#[pallet::storage]
+	pub type Holds<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<IdAmount<T::HoldIdentifier, T::Balance>, T::MaxHolds>,
+		ValueQuery,
+	>;
+
+	/// Freezes on account balances, sorted by their id.
+	#[pallet::storage]
+	pub type Freezes<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<IdAmount<T::FreezeIdentifier, T::Balance>, T::MaxFreezes>,
+		ValueQuery,
+	>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config<I>, I: 'static = ()> {
 		pub balances: Vec<(T::AccountId, T::Balance)>,
@@ -681,6 +725,15 @@ pub struct ReserveData<ReserveIdentifier, Balance> {
 	pub amount: Balance,
 }
 
+/// An amount of balance associated with an identifier, used for holds and freezes.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct IdAmount<Id, Balance> {
+	/// The identifier of the hold or freeze.
+	pub id: Id,
+	/// The amount held or frozen.
+	pub amount: Balance,
+}
+
 /// All balance information for an account.
 #[derive(Encode, Decode, Clone, PartialEq, Eq, Default, RuntimeDebug, MaxEncodedLen, TypeInfo)]
 pub struct AccountData<Balance> {
@@ -952,20 +1005,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 				A runtime configuration adjustment may be needed."
 			);
 		}
-		// No way this can fail since we do not alter the existential balances.
-		let res = Self::mutate_account(who, |b| {
-			b.misc_frozen = Zero::zero();
-			b.fee_frozen = Zero::zero();
-			for l in locks.iter() {
-				if l.reasons == Reasons::All || l.reasons == Reasons::Misc {
-					b.misc_frozen = b.misc_frozen.max(l.amount);
-				}
-				if l.reasons == Reasons::All || l.reasons == Reasons::Fee {
-					b.fee_frozen = b.fee_frozen.max(l.amount);
-				}
-			}
-		});
-		debug_assert!(res.is_ok());
+		Self::update_frozen(who, locks, &Freezes::<T, I>::get(who));
 
 		let existed = Locks::<T, I>::contains_key(who);
 		if locks.is_empty() {
@@ -990,7 +1030,123 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		}
 	}
 
+	/// Update the account entry for `who`, given the freezes.
+	fn update_freezes(
+		who: &T::AccountId,
+		freezes: BoundedVec<IdAmount<T::FreezeIdentifier, T::Balance>, T::MaxFreezes>,
+	) {
+		Self::update_frozen(who, &Locks::<T, I>::get(who), &freezes);
+
+		let existed = Freezes::<T, I>::contains_key(who);
+		if freezes.is_empty() {
+			Freezes::<T, I>::remove(who);
+			if existed {
+				system::Pallet::<T>::dec_consumers(who);
+			}
+		} else {
+			Freezes::<T, I>::insert(who, freezes);
+			if !existed && system::Pallet::<T>::inc_consumers_without_limit(who).is_err() {
+				// As for locks, the frozen funds are stored in the account and therefore need a
+				// provider reference already.
+				log::warn!(
+					target: LOG_TARGET,
+					"Warning: Attempt to introduce freeze consumer reference, yet no providers. \
+					This is unexpected but should be safe."
+				);
+			}
+		}
+	}
+
+	/// Set the frozen balances of `who` from its locks and freezes. Freezes apply to all
+	/// withdrawal reasons.
+	fn update_frozen(
+		who: &T::AccountId,
+		locks: &[BalanceLock<T::Balance>],
+		freezes: &[IdAmount<T::FreezeIdentifier, T::Balance>],
+	) {
+		// No way this can fail since we do not alter the existential balances.
+		let res = Self::mutate_account(who, |b| {
+			b.misc_frozen = Zero::zero();
+			b.fee_frozen = Zero::zero();
+			for l in locks.iter() {
+				if l.reasons == Reasons::All || l.reasons == Reasons::Misc {
+					b.misc_frozen = b.misc_frozen.max(l.amount);
+				}
+				if l.reasons == Reasons::All || l.reasons == Reasons::Fee {
+					b.fee_frozen = b.fee_frozen.max(l.amount);
+				}
+			}
+			for f in freezes.iter() {
+				b.misc_frozen = b.misc_frozen.max(f.amount);
+				b.fee_frozen = b.fee_frozen.max(f.amount);
+			}
+		});
+		debug_assert!(res.is_ok());
+	}
+
+	/// Reduce the amount held for `reason` on the account of `who` by `amount`, removing the hold
+	/// once it is empty.
+	fn decrease_hold(reason: &T::HoldIdentifier, who: &T::AccountId, amount: T::Balance) {
+		Holds::<T, I>::mutate_exists(who, |maybe_holds| {
+			if let Some(holds) = maybe_holds.as_mut() {
+				if let Ok(index) = holds.binary_search_by_key(reason, |h| h.id) {
+					holds[index].amount = holds[index].amount.defensive_saturating_sub(amount);
+					if holds[index].amount.is_zero() {
+						holds.remove(index);
+					}
+				}
+				if holds.is_empty() {
+					*maybe_holds = None;
+				}
+			}
+		});
+	}
+
+	/// Get the balance of `who` which is on hold for any reason.
+	///
+	/// Held funds are part of the reserved balance, but can't be touched through
+	/// [`ReservableCurrency`].
+	fn total_held(who: &T::AccountId) -> T::Balance {
+		Holds::<T, I>::get(who)
+			.iter()
+			.fold(Zero::zero(), |total: T::Balance, hold| total.saturating_add(hold.amount))
+	}
+
+	/// Unreserve up to `value` of the reserved balance of `who`, leaving the `held` part of it
+	/// untouched.
+	///
+	/// NOTE: returns the amount which was actually unreserved.
+	fn do_unreserve(who: &T::AccountId, value: T::Balance, held: T::Balance) -> T::Balance {
+		if value.is_zero() {
+			return Zero::zero()
+		}
+		if Self::total_balance(who).is_zero() {
+			return Zero::zero()
+		}
+
+		let actual = match Self::mutate_account(who, |account| {
+			let actual = cmp::min(account.reserved.saturating_sub(held), value);
+			account.reserved -= actual;
+			// defensive only: this can never fail since total issuance which is at least
+			// free+reserved fits into the same data type.
+			account.free = account.free.defensive_saturating_add(actual);
+			actual
+		}) {
+			Ok(x) => x,
+			Err(_) => {
+				// This should never happen since we don't alter the total amount in the account.
+				// If it ever does, then we should fail gracefully though, indicating that nothing
+				// could be done.
+				return Zero::zero()
+			},
+		};
+
+		Self::deposit_event(Event::Unreserved { who: who.clone(), amount: actual });
+		actual
+	}
+
 	/// Move the reserved balance of one account into the balance of another, according to `status`.
+	/// The `held` part of the reserved balance of `slashed` is left untouched.
 	///
 	/// Is a no-op if:
 	/// - the value to be moved is zero; or
@@ -1001,6 +1157,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		slashed: &T::AccountId,
 		beneficiary: &T::AccountId,
 		value: T::Balance,
+		held: T::Balance,
 		best_effort: bool,
 		status: Status,
 	) -> Result<T::Balance, DispatchError> {
@@ -1010,7 +1167,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 
 		if slashed == beneficiary {
 			return match status {
-				Status::Free => Ok(value.saturating_sub(Self::unreserve(slashed, value))),
+				Status::Free => Ok(Self::do_unreserve(slashed, value, held)),
 				Status::Reserved => Ok(value.saturating_sub(Self::reserved_balance(slashed))),
 			}
 		}
@@ -1022,7 +1179,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 				Self::try_mutate_account_with_dust(
 					slashed,
 					|from_account, _| -> Result<T::Balance, DispatchError> {
-						let actual = cmp::min(from_account.reserved, value);
+						let actual = cmp::min(from_account.reserved.saturating_sub(held), value);
 						ensure!(best_effort || actual == value, Error::<T, I>::InsufficientBalance);
 						match status {
 							Status::Free =>
@@ -1173,10 +1330,25 @@ impl<T: Config<I>, I: 'static> fungible::Unbalanced<T::AccountId> for Pallet<T,
 }
 
 impl<T: Config<I>, I: 'static> fungible::InspectHold<T::AccountId> for Pallet<T, I> {
-	fn balance_on_hold(who: &T::AccountId) -> T::Balance {
-		Self::account(who).reserved
+	type Reason = T::HoldIdentifier;
+
+	fn total_balance_on_hold(who: &T::AccountId) -> T::Balance {
+		Self::total_held(who)
 	}
-	fn can_hold(who: &T::AccountId, amount: T::Balance) -> bool {
+	fn balance_on_hold(reason: &Self::Reason, who: &T::AccountId) -> T::Balance {
+		let holds = Holds::<T, I>::get(who);
+		holds
+			.binary_search_by_key(reason, |h| h.id)
+			.map(|index| holds[index].amount)
+			.unwrap_or_default()
+	}
+	fn can_hold(reason: &Self::Reason, who: &T::AccountId, amount: T::Balance) -> bool {
+		let holds = Holds::<T, I>::get(who);
+		if holds.binary_search_by_key(reason, |h| h.id).is_err() &&
+			holds.len() as u32 >= T::MaxHolds::get()
+		{
+			return false
+		}
 		let a = Self::account(who);
 		let min_balance = T::ExistentialDeposit::get().max(a.frozen(Reasons::All));
 		if a.reserved.checked_add(&amount).is_none() {
@@ -1192,45 +1364,160 @@ impl<T: Config<I>, I: 'static> fungible::InspectHold<T::AccountId> for Pallet<T,
 	}
 }
 impl<T: Config<I>, I: 'static> fungible::MutateHold<T::AccountId> for Pallet<T, I> {
-	fn hold(who: &T::AccountId, amount: Self::Balance) -> DispatchResult {
+	fn hold(reason: &Self::Reason, who: &T::AccountId, amount: Self::Balance) -> DispatchResult {
 		if amount.is_zero() {
 			return Ok(())
 		}
 		ensure!(Self::can_reserve(who, amount), Error::<T, I>::InsufficientBalance);
-		Self::mutate_account(who, |a| {
-			a.free -= amount;
-			a.reserved += amount;
-		})?;
-		Ok(())
+		Holds::<T, I>::try_mutate(who, |holds| -> DispatchResult {
+			match holds.binary_search_by_key(reason, |h| h.id) {
+				Ok(index) => {
+					// this add can't overflow as the hold is part of the reserved balance.
+					holds[index].amount = holds[index].amount.defensive_saturating_add(amount);
+				},
+				Err(index) => {
+					holds
+						.try_insert(index, IdAmount { id: *reason, amount })
+						.map_err(|_| Error::<T, I>::TooManyHolds)?;
+				},
+			}
+			Self::mutate_account(who, |a| {
+				a.free -= amount;
+				a.reserved += amount;
+			})?;
+			Ok(())
+		})
 	}
 	fn release(
+		reason: &Self::Reason,
 		who: &T::AccountId,
 		amount: Self::Balance,
 		best_effort: bool,
 	) -> Result<T::Balance, DispatchError> {
+		let held = <Self as fungible::InspectHold<_>>::balance_on_hold(reason, who);
+		ensure!(best_effort || amount <= held, Error::<T, I>::InsufficientBalance);
+		let amount = amount.min(held);
 		if amount.is_zero() {
 			return Ok(amount)
 		}
-		// Done on a best-effort basis.
-		Self::try_mutate_account(who, |a, _| {
-			let new_free = a.free.saturating_add(amount.min(a.reserved));
-			let actual = new_free - a.free;
-			ensure!(best_effort || actual == amount, Error::<T, I>::InsufficientBalance);
-			// ^^^ Guaranteed to be <= amount and <= a.reserved
-			a.free = new_free;
-			a.reserved = a.reserved.saturating_sub(actual);
+		let actual = Self::try_mutate_account(who, |a, _| -> Result<T::Balance, DispatchError> {
+			// The reserved balance always covers the holds, this is just defensive.
+			let actual = amount.min(a.reserved);
+			a.free = a.free.checked_add(&actual).ok_or(ArithmeticError::Overflow)?;
+			a.reserved -= actual;
 			Ok(actual)
-		})
+		})?;
+		Self::decrease_hold(reason, who, actual);
+		Ok(actual)
 	}
 	fn transfer_held(
+		reason: &Self::Reason,
 		source: &T::AccountId,
 		dest: &T::AccountId,
 		amount: Self::Balance,
 		best_effort: bool,
 		on_hold: bool,
 	) -> Result<Self::Balance, DispatchError> {
+		let held = <Self as fungible::InspectHold<_>>::balance_on_hold(reason, source);
+		ensure!(best_effort || amount <= held, Error::<T, I>::InsufficientBalance);
+		let amount = amount.min(held);
+		if amount.is_zero() || (on_hold && source == dest) {
+			return Ok(amount)
+		}
+
 		let status = if on_hold { Status::Reserved } else { Status::Free };
-		Self::do_transfer_reserved(source, dest, amount, best_effort, status)
+		if !on_hold {
+			let actual = Self::do_transfer_reserved(
+				source,
+				dest,
+				amount,
+				Zero::zero(),
+				best_effort,
+				status,
+			)?;
+			Self::decrease_hold(reason, source, actual);
+			return Ok(actual)
+		}
+
+		Holds::<T, I>::try_mutate(dest, |holds| -> Result<T::Balance, DispatchError> {
+			let index = match holds.binary_search_by_key(reason, |h| h.id) {
+				Ok(index) => index,
+				Err(index) => {
+					holds
+						.try_insert(index, IdAmount { id: *reason, amount: Zero::zero() })
+						.map_err(|_| Error::<T, I>::TooManyHolds)?;
+					index
+				},
+			};
+			let actual = Self::do_transfer_reserved(
+				source,
+				dest,
+				amount,
+				Zero::zero(),
+				best_effort,
+				status,
+			)?;
+			// this add can't overflow as the hold is part of the reserved balance.
+			holds[index].amount = holds[index].amount.defensive_saturating_add(actual);
+			Self::decrease_hold(reason, source, actual);
+			Ok(actual)
+		})
+	}
+}
+
+impl<T: Config<I>, I: 'static> fungible::InspectFreeze<T::AccountId> for Pallet<T, I> {
+	type Id = T::FreezeIdentifier;
+
+	fn balance_frozen(id: &Self::Id, who: &T::AccountId) -> Self::Balance {
+		let freezes = Freezes::<T, I>::get(who);
+		freezes
+			.binary_search_by_key(id, |f| f.id)
+			.map(|index| freezes[index].amount)
+			.unwrap_or_default()
+	}
+	fn can_freeze(id: &Self::Id, who: &T::AccountId) -> bool {
+		let freezes = Freezes::<T, I>::get(who);
+		freezes.binary_search_by_key(id, |f| f.id).is_ok() ||
+			(freezes.len() as u32) < T::MaxFreezes::get()
+	}
+}
+
+impl<T: Config<I>, I: 'static> fungible::MutateFreeze<T::AccountId> for Pallet<T, I> {
+	fn set_freeze(id: &Self::Id, who: &T::AccountId, amount: Self::Balance) -> DispatchResult {
+		if amount.is_zero() {
+			return <Self as fungible::MutateFreeze<_>>::thaw(id, who)
+		}
+		let mut freezes = Freezes::<T, I>::get(who);
+		match freezes.binary_search_by_key(id, |f| f.id) {
+			Ok(index) => freezes[index].amount = amount,
+			Err(index) => freezes
+				.try_insert(index, IdAmount { id: *id, amount })
+				.map_err(|_| Error::<T, I>::TooManyFreezes)?,
+		}
+		Self::update_freezes(who, freezes);
+		Ok(())
+	}
+	fn extend_freeze(id: &Self::Id, who: &T::AccountId, amount: Self::Balance) -> DispatchResult {
+		if amount.is_zero() {
+			return Ok(())
+		}
+		let mut freezes = Freezes::<T, I>::get(who);
+		match freezes.binary_search_by_key(id, |f| f.id) {
+			Ok(index) => freezes[index].amount = freezes[index].amount.max(amount),
+			Err(index) => freezes
+				.try_insert(index, IdAmount { id: *id, amount })
+				.map_err(|_| Error::<T, I>::TooManyFreezes)?,
+		}
+		Self::update_freezes(who, freezes);
+		Ok(())
+	}
+	fn thaw(id: &Self::Id, who: &T::AccountId) -> DispatchResult {
+		let mut freezes = Freezes::<T, I>::get(who);
+		if let Ok(index) = freezes.binary_search_by_key(id, |f| f.id) {
+			freezes.remove(index);
+			Self::update_freezes(who, freezes);
+		}
+		Ok(())
 	}
 }
 
@@ -1579,6 +1866,7 @@ where
 			return (NegativeImbalance::zero(), value)
 		}
 
+		let held = Self::total_held(who);
 		for attempt in 0..2 {
 			match Self::try_mutate_account(
 				who,
@@ -1603,8 +1891,10 @@ where
 					let remaining_slash = best_value - free_slash; // Safe because of above check
 
 					if !remaining_slash.is_zero() {
-						// If we have remaining slash, take it from reserved balance.
-						let reserved_slash = cmp::min(account.reserved, remaining_slash);
+						// If we have remaining slash, take it from reserved balance which isn't on
+						// hold.
+						let reserved_slash =
+							cmp::min(account.reserved.saturating_sub(held), remaining_slash);
 						account.reserved -= reserved_slash; // Safe because of above check
 						Ok((
 							NegativeImbalance::new(free_slash + reserved_slash),
@@ -1781,8 +2071,9 @@ where
 		})
 	}
 
+	/// The reserved balance of `who`, excluding the funds on hold.
 	fn reserved_balance(who: &T::AccountId) -> Self::Balance {
-		Self::account(who).reserved
+		Self::account(who).reserved.saturating_sub(Self::total_held(who))
 	}
 
 	/// Move `value` from the free balance from `who` to their reserved balance.
@@ -1805,42 +2096,18 @@ where
 		Ok(())
 	}
 
-	/// Unreserve some funds, returning any amount that was unable to be unreserved.
+	/// Unreserve some funds, returning any amount that was unable to be unreserved. Funds on hold
+	/// are never unreserved.
 	///
 	/// Is a no-op if the value to be unreserved is zero or the account does not exist.
 	///
 	/// NOTE: returns amount value which wasn't successfully unreserved.
 	fn unreserve(who: &T::AccountId, value: Self::Balance) -> Self::Balance {
-		if value.is_zero() {
-			return Zero::zero()
-		}
-		if Self::total_balance(who).is_zero() {
-			return value
-		}
-
-		let actual = match Self::mutate_account(who, |account| {
-			let actual = cmp::min(account.reserved, value);
-			account.reserved -= actual;
-			// defensive only: this can never fail since total issuance which is at least
-			// free+reserved fits into the same data type.
-			account.free = account.free.defensive_saturating_add(actual);
-			actual
-		}) {
-			Ok(x) => x,
-			Err(_) => {
-				// This should never happen since we don't alter the total amount in the account.
-				// If it ever does, then we should fail gracefully though, indicating that nothing
-				// could be done.
-				return value
-			},
-		};
-
-		Self::deposit_event(Event::Unreserved { who: who.clone(), amount: actual });
-		value - actual
+		value - Self::do_unreserve(who, value, Self::total_held(who))
 	}
 
 	/// Slash from reserved balance, returning the negative imbalance created,
-	/// and any amount that was unable to be slashed.
+	/// and any amount that was unable to be slashed. Funds on hold are never slashed.
 	///
 	/// Is a no-op if the value to be slashed is zero or the account does not exist.
 	fn slash_reserved(
@@ -1857,6 +2124,7 @@ where
 		// NOTE: `mutate_account` may fail if it attempts to reduce the balance to the point that an
 		//   account is attempted to be illegally destroyed.
 
+		let held = Self::total_held(who);
 		for attempt in 0..2 {
 			match Self::mutate_account(who, |account| {
 				let best_value = match attempt {
@@ -1869,7 +2137,7 @@ where
 					),
 				};
 
-				let actual = cmp::min(account.reserved, best_value);
+				let actual = cmp::min(account.reserved.saturating_sub(held), best_value);
 				account.reserved -= actual;
 
 				// underflow should never happen, but it if does, there's nothing to be done here.
@@ -1891,6 +2159,7 @@ where
 	}
 
 	/// Move the reserved balance of one account into the balance of another, according to `status`.
+	/// Funds on hold are never moved.
 	///
 	/// Is a no-op if:
 	/// - the value to be moved is zero; or
@@ -1901,7 +2170,8 @@ where
 		value: Self::Balance,
 		status: Status,
 	) -> Result<Self::Balance, DispatchError> {
-		let actual = Self::do_transfer_reserved(slashed, beneficiary, value, true, status)?;
+		let held = Self::total_held(slashed);
+		let actual = Self::do_transfer_reserved(slashed, beneficiary, value, held, true, status)?;
 		Ok(value.saturating_sub(actual))
 	}
 }
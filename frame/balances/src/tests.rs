@@ -1456,5 +1456,113 @@ macro_rules! decl_tests {
 				);
 			});
 		}
+
+		#[test]
+		fn fungible_hold_traits_work() {
+			use fungible::{InspectHold, MutateHold};
+			<$ext_builder>::default().build().execute_with(|| {
+				let _ = Balances::deposit_creating(&1, 100);
+				let _ = Balances::deposit_creating(&2, 100);
+				let foo = [1u8; 8];
+				let bar = [2u8; 8];
+
+				// holds share the reserved balance with plain reserves.
+				assert_ok!(Balances::reserve(&1, 10));
+				assert!(!Balances::can_hold(&foo, &1, 90));
+				assert_noop!(Balances::hold(&foo, &1, 91), Error::<Test, _>::InsufficientBalance);
+				assert_ok!(Balances::hold(&foo, &1, 30));
+				assert_ok!(Balances::hold(&bar, &1, 20));
+				assert_eq!(Balances::balance_on_hold(&foo, &1), 30);
+				assert_eq!(Balances::total_balance_on_hold(&1), 50);
+				assert_eq!(Balances::reserved_balance(1), 60);
+				assert_eq!(Balances::free_balance(1), 40);
+				assert_noop!(Balances::hold(&[3u8; 8], &1, 1), Error::<Test, _>::TooManyHolds);
+
+				assert_noop!(
+					Balances::release(&foo, &1, 31, false),
+					Error::<Test, _>::InsufficientBalance
+				);
+				assert_eq!(Balances::release(&foo, &1, 31, true), Ok(30));
+				assert_eq!(Balances::balance_on_hold(&foo, &1), 0);
+				assert_eq!(Balances::free_balance(1), 70);
+
+				assert_eq!(Balances::transfer_held(&bar, &1, &2, 5, false, true), Ok(5));
+				assert_eq!(Balances::balance_on_hold(&bar, &2), 5);
+				assert_eq!(Balances::reserved_balance(2), 5);
+				assert_eq!(Balances::transfer_held(&bar, &1, &2, 20, true, false), Ok(15));
+				assert_eq!(Balances::free_balance(2), 115);
+				assert_eq!(Balances::balance_on_hold(&bar, &1), 0);
+				assert_eq!(Balances::reserved_balance(1), 10);
+				assert_eq!(Balances::total_balance(&1), 80);
+			});
+		}
+
+		#[test]
+		fn reserves_leave_holds_untouched() {
+			use fungible::{InspectHold, MutateHold};
+			<$ext_builder>::default().build().execute_with(|| {
+				let _ = Balances::deposit_creating(&1, 100);
+				let _ = Balances::deposit_creating(&2, 100);
+				let foo = [1u8; 8];
+
+				assert_ok!(Balances::reserve(&1, 10));
+				assert_ok!(Balances::hold(&foo, &1, 30));
+				assert_eq!(<Balances as ReservableCurrency<_>>::reserved_balance(&1), 10);
+
+				assert_eq!(Balances::unreserve(&1, 15), 5);
+				assert_eq!(Balances::free_balance(1), 70);
+				assert_ok!(Balances::reserve(&1, 10));
+				assert_eq!(Balances::slash_reserved(&1, 15).1, 5);
+				assert_ok!(Balances::reserve(&1, 10));
+				assert_eq!(Balances::repatriate_reserved(&1, &2, 15, Status::Free), Ok(5));
+				assert_eq!(Balances::free_balance(2), 110);
+				assert_eq!(Balances::slash(&1, 100).1, 50);
+
+				assert_eq!(Balances::balance_on_hold(&foo, &1), 30);
+				assert_eq!(Balances::total_balance(&1), 30);
+				assert_eq!(Balances::release(&foo, &1, 30, false), Ok(30));
+				assert_eq!(Balances::free_balance(1), 30);
+			});
+		}
+
+		#[test]
+		fn fungible_freeze_traits_work() {
+			use fungible::{InspectFreeze, MutateFreeze};
+			<$ext_builder>::default().build().execute_with(|| {
+				let _ = Balances::deposit_creating(&1, 100);
+				let foo = [1u8; 8];
+				let bar = [2u8; 8];
+
+				assert_ok!(Balances::set_freeze(&foo, &1, 30));
+				assert_ok!(Balances::extend_freeze(&foo, &1, 20));
+				assert_eq!(Balances::balance_frozen(&foo, &1), 30);
+				assert_ok!(Balances::set_freeze(&bar, &1, 50));
+				assert!(!Balances::can_freeze(&[3u8; 8], &1));
+				assert_noop!(
+					Balances::set_freeze(&[3u8; 8], &1, 10),
+					Error::<Test, _>::TooManyFreezes
+				);
+				assert_eq!(System::consumers(&1), 1);
+
+				// freezes overlap each other and any locks.
+				Balances::set_lock(ID_1, &1, 40, WithdrawReasons::all());
+				assert_noop!(
+					<Balances as Currency<_>>::transfer(&1, &2, 51, AllowDeath),
+					Error::<Test, _>::LiquidityRestrictions
+				);
+				assert_ok!(Balances::thaw(&bar, &1));
+				assert_ok!(<Balances as Currency<_>>::transfer(&1, &2, 40, AllowDeath));
+				assert_noop!(
+					<Balances as Currency<_>>::transfer(&1, &2, 21, AllowDeath),
+					Error::<Test, _>::LiquidityRestrictions
+				);
+
+				Balances::remove_lock(ID_1, &1);
+				assert_ok!(Balances::set_freeze(&foo, &1, 0));
+				assert_eq!(Balances::balance_frozen(&foo, &1), 0);
+				assert_eq!(System::consumers(&1), 0);
+				assert_ok!(<Balances as Currency<_>>::transfer(&1, &2, 60, AllowDeath));
+			});
+		}
 	}
 }
@@ -100,6 +100,10 @@ impl Config for Test {
 	type MaxLocks = ConstU32<50>;
 	type MaxReserves = ConstU32<2>;
 	type ReserveIdentifier = [u8; 8];
+	type HoldIdentifier = [u8; 8];
+	type FreezeIdentifier = [u8; 8];
+	type MaxHolds = ConstU32<2>;
+	type MaxFreezes = ConstU32<2>;
 	type WeightInfo = ();
 }
 
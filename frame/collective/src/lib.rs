@@ -38,6 +38,11 @@
 //! abstentions and the proposal is executed if there are enough approvals counting the new votes.
 //!
 //! If there are not, or if no prime is set, then the motion is dropped without being executed.
+//!
+//! By default every member has one vote. With `Config::VoteWeight` the votes of members can be
+//! weighed differently, e.g. by the election results of `pallet_validator_election`. Thresholds,
+//! tallies and the `Members` origin are then expressed in vote weight rather than in members. The
+//! weights are snapshotted when a motion is proposed and apply to it until it is closed.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![recursion_limit = "128"]
@@ -60,7 +65,7 @@ use frame_support::{
 	weights::Weight,
 };
 
-use pallet_validator_election::CollectiveInterface;
+use pallet_validator_election::{CollectiveInterface, CollectiveVoteWeight};
 
 #[cfg(test)]
 mod tests;
@@ -81,7 +86,8 @@ pub type ProposalIndex = u32;
 /// A number of members.
 ///
 /// This also serves as a number of voting members, and since for motions, each member may
-/// vote exactly once, therefore also the number of votes for any given motion.
+/// vote exactly once, therefore also the number of votes for any given motion. If votes are
+/// weighted, it is the combined weight of the votes instead.
 pub type MemberCount = u32;
 
 /// Default voting strategy when a member is inactive.
@@ -125,7 +131,7 @@ impl DefaultVote for MoreThanMajorityThenPrimeDefaultVote {
 		_no_votes: MemberCount,
 		len: MemberCount,
 	) -> bool {
-		let more_than_majority = yes_votes * 2 > len;
+		let more_than_majority = yes_votes.saturating_mul(2) > len;
 		more_than_majority || prime_vote.unwrap_or(false)
 	}
 }
@@ -136,6 +142,9 @@ impl DefaultVote for MoreThanMajorityThenPrimeDefaultVote {
 #[codec(mel_bound(AccountId: MaxEncodedLen))]
 pub enum RawOrigin<AccountId, I> {
 	/// It has been condoned by a given number of members of the collective from a given total.
+	///
+	/// If votes are weighted, these are the vote weight of the approving members and the combined
+	/// vote weight of the collective.
 	Members(MemberCount, MemberCount),
 	/// It has been condoned by a single member of the collective.
 	Member(AccountId),
@@ -157,7 +166,7 @@ impl<AccountId, I> GetBacking for RawOrigin<AccountId, I> {
 pub struct Votes<AccountId, BlockNumber> {
 	/// The proposal's unique index.
 	index: ProposalIndex,
-	/// The number of approval votes that are needed to pass the motion.
+	/// The number of approval votes (or vote weight) that are needed to pass the motion.
 	threshold: MemberCount,
 	/// The current set of voters that approved it.
 	ayes: Vec<AccountId>,
@@ -215,6 +224,9 @@ pub mod pallet {
 		/// Default vote strategy of this collective.
 		type DefaultVote: DefaultVote;
 
+		/// The weight of each member's vote. `()` gives every member one vote.
+		type VoteWeight: CollectiveVoteWeight<Self::AccountId>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -269,6 +281,13 @@ pub mod pallet {
 	pub type Voting<T: Config<I>, I: 'static = ()> =
 		StorageMap<_, Identity, T::Hash, Votes<T::AccountId, T::BlockNumber>, OptionQuery>;
 
+	/// The vote weight of each member, sorted by account, taken when a proposal was made.
+	///
+	/// Members that joined after the proposal was made carry no weight in it.
+	#[pallet::storage]
+	pub type VoteWeights<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Identity, T::Hash, Vec<(T::AccountId, MemberCount)>, OptionQuery>;
+
 	/// Proposals so far.
 	#[pallet::storage]
 	#[pallet::getter(fn proposal_count)]
@@ -398,6 +417,7 @@ pub mod pallet {
 		///
 		/// `threshold` determines whether `proposal` is executed directly (`threshold < 2`)
 		/// or put up for voting.
+		/// If votes are weighted, `threshold` is the vote weight of the approvals needed.
 		///
 		/// ## Complexity
 		/// - `O(B + M + P1)` or `O(B + M + P2)` where:
@@ -412,13 +432,16 @@ pub mod pallet {
 				T::WeightInfo::propose_execute(
 					*length_bound, // B
 					T::MaxMembers::get(), // M
-				).saturating_add(proposal.get_dispatch_info().weight) // P1
+				)
+				.saturating_add(T::VoteWeight::lookup_weight(T::MaxMembers::get() + 1)) // M
+				.saturating_add(proposal.get_dispatch_info().weight) // P1
 			} else {
 				T::WeightInfo::propose_proposed(
 					*length_bound, // B
 					T::MaxMembers::get(), // M
 					T::MaxProposals::get(), // P2
 				)
+				.saturating_add(T::VoteWeight::lookup_weight(T::MaxMembers::get())) // M
 			},
 			DispatchClass::Operational
 		))]
@@ -433,7 +456,8 @@ pub mod pallet {
 			ensure!(members.contains(&who), Error::<T, I>::NotMember);
 
 			if threshold < 2 {
-				let (proposal_len, result) = Self::do_propose_execute(proposal, length_bound)?;
+				let (proposal_len, result) =
+					Self::do_propose_execute(&who, proposal, length_bound)?;
 
 				Ok(get_result_weight(result)
 					.map(|w| {
@@ -441,6 +465,7 @@ pub mod pallet {
 							proposal_len as u32,  // B
 							members.len() as u32, // M
 						)
+						.saturating_add(T::VoteWeight::lookup_weight(members.len() as u32 + 1)) // M
 						.saturating_add(w) // P1
 					})
 					.into())
@@ -448,11 +473,14 @@ pub mod pallet {
 				let (proposal_len, active_proposals) =
 					Self::do_propose_proposed(who, threshold, proposal, length_bound)?;
 
-				Ok(Some(T::WeightInfo::propose_proposed(
-					proposal_len as u32,  // B
-					members.len() as u32, // M
-					active_proposals,     // P2
-				))
+				Ok(Some(
+					T::WeightInfo::propose_proposed(
+						proposal_len as u32,  // B
+						members.len() as u32, // M
+						active_proposals,     // P2
+					)
+					.saturating_add(T::VoteWeight::lookup_weight(members.len() as u32)), // M
+				)
 				.into())
 			}
 		}
@@ -467,7 +495,11 @@ pub mod pallet {
 		/// ## Complexity
 		/// - `O(M)` where `M` is members-count (code- and governance-bounded)
 		#[pallet::call_index(3)]
-		#[pallet::weight((T::WeightInfo::vote(T::MaxMembers::get()), DispatchClass::Operational))]
+		#[pallet::weight((
+			T::WeightInfo::vote(T::MaxMembers::get())
+				.saturating_add(T::VoteWeight::lookup_weight(T::MaxMembers::get())),
+			DispatchClass::Operational
+		))]
 		pub fn vote(
 			origin: OriginFor<T>,
 			proposal: T::Hash,
@@ -481,10 +513,12 @@ pub mod pallet {
 			// Detects first vote of the member in the motion
 			let is_account_voting_first_time = Self::do_vote(who, proposal, index, approve)?;
 
+			let weight = T::WeightInfo::vote(members.len() as u32)
+				.saturating_add(T::VoteWeight::lookup_weight(members.len() as u32));
 			if is_account_voting_first_time {
-				Ok((Some(T::WeightInfo::vote(members.len() as u32)), Pays::No).into())
+				Ok((Some(weight), Pays::No).into())
 			} else {
-				Ok((Some(T::WeightInfo::vote(members.len() as u32)), Pays::Yes).into())
+				Ok((Some(weight), Pays::Yes).into())
 			}
 		}
 
@@ -544,6 +578,7 @@ pub mod pallet {
 					.max(T::WeightInfo::close_early_disapproved(m, p2))
 					.max(T::WeightInfo::close_approved(b, m, p2))
 					.max(T::WeightInfo::close_disapproved(m, p2))
+					.saturating_add(T::VoteWeight::lookup_weight(m))
 					.saturating_add(p1)
 			},
 			DispatchClass::Operational
@@ -582,6 +617,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 
 	/// Execute immediately when adding a new proposal.
 	pub fn do_propose_execute(
+		who: &T::AccountId,
 		proposal: Box<<T as Config<I>>::Proposal>,
 		length_bound: MemberCount,
 	) -> Result<(u32, DispatchResultWithPostInfo), DispatchError> {
@@ -591,8 +627,9 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		let proposal_hash = T::Hashing::hash_of(&proposal);
 		ensure!(!<ProposalOf<T, I>>::contains_key(proposal_hash), Error::<T, I>::DuplicateProposal);
 
-		let seats = Self::members().len() as MemberCount;
-		let result = proposal.dispatch(RawOrigin::Members(1, seats).into());
+		let seats = Self::total_weight(&Self::current_vote_weights());
+		let origin = RawOrigin::Members(T::VoteWeight::vote_weight(who), seats);
+		let result = proposal.dispatch(origin.into());
 		Self::deposit_event(Event::Executed {
 			proposal_hash,
 			result: result.map(|_| ()).map_err(|e| e.error),
@@ -627,6 +664,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			Votes { index, threshold, ayes: vec![], nays: vec![], end }
 		};
 		<Voting<T, I>>::insert(proposal_hash, votes);
+		<VoteWeights<T, I>>::insert(proposal_hash, Self::current_vote_weights());
 
 		Self::deposit_event(Event::Proposed {
 			account: who,
@@ -674,8 +712,9 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			}
 		}
 
-		let yes_votes = voting.ayes.len() as MemberCount;
-		let no_votes = voting.nays.len() as MemberCount;
+		let weights = Self::vote_weights_of(&proposal);
+		let yes_votes = Self::tally(&weights, &voting.ayes);
+		let no_votes = Self::tally(&weights, &voting.nays);
 		Self::deposit_event(Event::Voted {
 			account: who,
 			proposal_hash: proposal,
@@ -699,9 +738,12 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		let voting = Self::voting(&proposal_hash).ok_or(Error::<T, I>::ProposalMissing)?;
 		ensure!(voting.index == index, Error::<T, I>::WrongIndex);
 
-		let mut no_votes = voting.nays.len() as MemberCount;
-		let mut yes_votes = voting.ayes.len() as MemberCount;
-		let seats = Self::members().len() as MemberCount;
+		let weights = Self::vote_weights_of(&proposal_hash);
+		let mut no_votes = Self::tally(&weights, &voting.nays);
+		let mut yes_votes = Self::tally(&weights, &voting.ayes);
+		let seats = Self::total_weight(&weights);
+		let members = weights.len() as MemberCount;
+		let lookup_weight = T::VoteWeight::lookup_weight(members);
 		let approved = yes_votes >= voting.threshold;
 		let disapproved = seats.saturating_sub(no_votes) < voting.threshold;
 		// Allow (dis-)approving the proposal as soon as there are enough votes.
@@ -716,7 +758,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 				Self::do_approve_proposal(seats, yes_votes, proposal_hash, proposal);
 			return Ok((
 				Some(
-					T::WeightInfo::close_early_approved(len as u32, seats, proposal_count)
+					T::WeightInfo::close_early_approved(len as u32, members, proposal_count)
+						.saturating_add(lookup_weight)
 						.saturating_add(proposal_weight),
 				),
 				Pays::Yes,
@@ -726,7 +769,10 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			Self::deposit_event(Event::Closed { proposal_hash, yes: yes_votes, no: no_votes });
 			let proposal_count = Self::do_disapprove_proposal(proposal_hash);
 			return Ok((
-				Some(T::WeightInfo::close_early_disapproved(seats, proposal_count)),
+				Some(
+					T::WeightInfo::close_early_disapproved(members, proposal_count)
+						.saturating_add(lookup_weight),
+				),
 				Pays::No,
 			)
 				.into())
@@ -740,10 +786,10 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		// default voting strategy.
 		let default = T::DefaultVote::default_vote(prime_vote, yes_votes, no_votes, seats);
 
-		let abstentions = seats - (yes_votes + no_votes);
+		let abstentions = seats.saturating_sub(yes_votes.saturating_add(no_votes));
 		match default {
-			true => yes_votes += abstentions,
-			false => no_votes += abstentions,
+			true => yes_votes = yes_votes.saturating_add(abstentions),
+			false => no_votes = no_votes.saturating_add(abstentions),
 		}
 		let approved = yes_votes >= voting.threshold;
 
@@ -758,7 +804,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 				Self::do_approve_proposal(seats, yes_votes, proposal_hash, proposal);
 			Ok((
 				Some(
-					T::WeightInfo::close_approved(len as u32, seats, proposal_count)
+					T::WeightInfo::close_approved(len as u32, members, proposal_count)
+						.saturating_add(lookup_weight)
 						.saturating_add(proposal_weight),
 				),
 				Pays::Yes,
@@ -767,7 +814,14 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		} else {
 			Self::deposit_event(Event::Closed { proposal_hash, yes: yes_votes, no: no_votes });
 			let proposal_count = Self::do_disapprove_proposal(proposal_hash);
-			Ok((Some(T::WeightInfo::close_disapproved(seats, proposal_count)), Pays::No).into())
+			Ok((
+				Some(
+					T::WeightInfo::close_disapproved(members, proposal_count)
+						.saturating_add(lookup_weight),
+				),
+				Pays::No,
+			)
+				.into())
 		}
 	}
 
@@ -839,12 +893,46 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		// remove proposal and vote
 		ProposalOf::<T, I>::remove(&proposal_hash);
 		Voting::<T, I>::remove(&proposal_hash);
+		VoteWeights::<T, I>::remove(&proposal_hash);
 		let num_proposals = Proposals::<T, I>::mutate(|proposals| {
 			proposals.retain(|h| h != &proposal_hash);
 			proposals.len() + 1 // calculate weight based on original length
 		});
 		num_proposals as u32
 	}
+
+	/// The vote weight of each current member, sorted by account.
+	fn current_vote_weights() -> Vec<(T::AccountId, MemberCount)> {
+		let mut weights = Self::members()
+			.into_iter()
+			.map(|who| {
+				let weight = T::VoteWeight::vote_weight(&who);
+				(who, weight)
+			})
+			.collect::<Vec<_>>();
+		weights.sort_by(|a, b| a.0.cmp(&b.0));
+		weights
+	}
+
+	/// The vote weights a proposal is tallied with.
+	///
+	/// Proposals made before weights were snapshotted use those of the current members.
+	fn vote_weights_of(proposal_hash: &T::Hash) -> Vec<(T::AccountId, MemberCount)> {
+		VoteWeights::<T, I>::get(proposal_hash).unwrap_or_else(Self::current_vote_weights)
+	}
+
+	/// The combined vote weight of `voters` according to `weights`.
+	fn tally(weights: &[(T::AccountId, MemberCount)], voters: &[T::AccountId]) -> MemberCount {
+		voters
+			.iter()
+			.filter_map(|voter| weights.binary_search_by(|(who, _)| who.cmp(voter)).ok())
+			.fold(0, |total, i| total.saturating_add(weights[i].1))
+	}
+
+	/// The combined vote weight of all members in `weights`.
+	fn total_weight(weights: &[(T::AccountId, MemberCount)]) -> MemberCount {
+		weights.iter().fold(0, |total, (_, weight)| total.saturating_add(*weight))
+	}
 }
 
 impl<T: Config<I>, I: 'static> CollectiveInterface<T::AccountId> for Pallet<T, I> {
@@ -996,7 +1084,7 @@ impl<
 	type Success = ();
 	fn try_origin(o: O) -> Result<Self::Success, O> {
 		o.into().and_then(|o| match o {
-			RawOrigin::Members(n, m) if n * D > N * m => Ok(()),
+			RawOrigin::Members(n, m) if n as u64 * D as u64 > N as u64 * m as u64 => Ok(()),
 			r => Err(O::from(r)),
 		})
 	}
@@ -1021,7 +1109,7 @@ impl<
 	type Success = ();
 	fn try_origin(o: O) -> Result<Self::Success, O> {
 		o.into().and_then(|o| match o {
-			RawOrigin::Members(n, m) if n * D >= N * m => Ok(()),
+			RawOrigin::Members(n, m) if n as u64 * D as u64 >= N as u64 * m as u64 => Ok(()),
 			r => Err(O::from(r)),
 		})
 	}
@@ -45,6 +45,7 @@ frame_support::construct_runtime!(
 		Collective: pallet_collective::<Instance1>::{Pallet, Call, Event<T>, Origin<T>, Config<T>},
 		CollectiveMajority: pallet_collective::<Instance2>::{Pallet, Call, Event<T>, Origin<T>, Config<T>},
 		DefaultCollective: pallet_collective::{Pallet, Call, Event<T>, Origin<T>, Config<T>},
+		WeightedCollective: pallet_collective::<Instance3>::{Pallet, Call, Event<T>, Origin<T>, Config<T>},
 		Democracy: mock_democracy::{Pallet, Call, Event<T>},
 	}
 );
@@ -136,6 +137,7 @@ impl Config<Instance1> for Test {
 	type MaxProposals = MaxProposals;
 	type MaxMembers = MaxMembers;
 	type DefaultVote = PrimeDefaultVote;
+	type VoteWeight = ();
 	type WeightInfo = ();
 	type SetMembersOrigin = EnsureRoot<Self::AccountId>;
 }
@@ -151,9 +153,29 @@ impl Config<Instance2> for Test {
 	type MaxProposals = MaxProposals;
 	type MaxMembers = MaxMembers;
 	type DefaultVote = MoreThanMajorityThenPrimeDefaultVote;
+	type VoteWeight = ();
 	type WeightInfo = ();
 	type SetMembersOrigin = EnsureRoot<Self::AccountId>;
 }
+/// Weighs the vote of each member by its account id.
+pub struct AccountIdVoteWeight;
+impl CollectiveVoteWeight<u64> for AccountIdVoteWeight {
+	fn vote_weight(who: &u64) -> u32 {
+		*who as u32
+	}
+}
+
+impl Config<Instance3> for Test {
+	type RuntimeOrigin = RuntimeOrigin;
+	type Proposal = RuntimeCall;
+	type RuntimeEvent = RuntimeEvent;
+	type MotionDuration = ConstU64<3>;
+	type MaxProposals = MaxProposals;
+	type MaxMembers = MaxMembers;
+	type DefaultVote = PrimeDefaultVote;
+	type VoteWeight = AccountIdVoteWeight;
+	type WeightInfo = ();
+}
 impl mock_democracy::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type ExternalMajorityOrigin = EnsureProportionAtLeast<u64, Instance1, 3, 4>;
@@ -169,6 +191,7 @@ impl Config for Test {
 	type MaxProposals = MaxProposals;
 	type MaxMembers = MaxMembers;
 	type DefaultVote = PrimeDefaultVote;
+	type VoteWeight = ();
 	type WeightInfo = ();
 	type SetMembersOrigin = EnsureRoot<Self::AccountId>;
 }
@@ -184,6 +207,10 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
 			phantom: Default::default(),
 		},
 		default_collective: Default::default(),
+		weighted_collective: pallet_collective::GenesisConfig {
+			members: vec![1, 2, 3],
+			phantom: Default::default(),
+		},
 	}
 	.build_storage()
 	.unwrap()
@@ -1337,6 +1364,121 @@ fn close_disapprove_does_not_care_about_weight_or_len() {
 	})
 }
 
+#[test]
+fn weighted_votes_work() {
+	new_test_ext().execute_with(|| {
+		let proposal = make_proposal(42);
+		let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+		let proposal_weight = proposal.get_dispatch_info().weight;
+		let hash = BlakeTwo256::hash_of(&proposal);
+
+		assert_ok!(WeightedCollective::propose(
+			RuntimeOrigin::signed(1),
+			4,
+			Box::new(proposal.clone()),
+			proposal_len
+		));
+		assert_ok!(WeightedCollective::vote(RuntimeOrigin::signed(3), hash, 0, true));
+		assert_ok!(WeightedCollective::vote(RuntimeOrigin::signed(2), hash, 0, false));
+		assert_ok!(WeightedCollective::vote(RuntimeOrigin::signed(1), hash, 0, true));
+		assert_ok!(WeightedCollective::close(
+			RuntimeOrigin::signed(4),
+			hash,
+			0,
+			proposal_weight,
+			proposal_len
+		));
+
+		assert_eq!(
+			System::events(),
+			vec![
+				record(RuntimeEvent::WeightedCollective(CollectiveEvent::Proposed {
+					account: 1,
+					proposal_index: 0,
+					proposal_hash: hash,
+					threshold: 4
+				})),
+				record(RuntimeEvent::WeightedCollective(CollectiveEvent::Voted {
+					account: 3,
+					proposal_hash: hash,
+					voted: true,
+					yes: 3,
+					no: 0
+				})),
+				record(RuntimeEvent::WeightedCollective(CollectiveEvent::Voted {
+					account: 2,
+					proposal_hash: hash,
+					voted: false,
+					yes: 3,
+					no: 2
+				})),
+				record(RuntimeEvent::WeightedCollective(CollectiveEvent::Voted {
+					account: 1,
+					proposal_hash: hash,
+					voted: true,
+					yes: 4,
+					no: 2
+				})),
+				record(RuntimeEvent::WeightedCollective(CollectiveEvent::Closed {
+					proposal_hash: hash,
+					yes: 4,
+					no: 2
+				})),
+				record(RuntimeEvent::WeightedCollective(CollectiveEvent::Approved {
+					proposal_hash: hash
+				})),
+				record(RuntimeEvent::WeightedCollective(CollectiveEvent::Executed {
+					proposal_hash: hash,
+					result: Err(DispatchError::BadOrigin)
+				})),
+			]
+		);
+		assert!(VoteWeights::<Test, Instance3>::get(hash).is_none());
+	});
+}
+
+#[test]
+fn vote_weights_are_snapshotted_at_propose() {
+	new_test_ext().execute_with(|| {
+		let proposal = make_proposal(42);
+		let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+		let proposal_weight = proposal.get_dispatch_info().weight;
+		let hash = BlakeTwo256::hash_of(&proposal);
+
+		assert_ok!(WeightedCollective::propose(
+			RuntimeOrigin::signed(1),
+			4,
+			Box::new(proposal.clone()),
+			proposal_len
+		));
+		assert_eq!(VoteWeights::<Test, Instance3>::get(hash), Some(vec![(1, 1), (2, 2), (3, 3)]));
+
+		// A newly elected member does not carry weight in the ongoing motion.
+		<WeightedCollective as CollectiveInterface<u64>>::set_new_members(vec![1, 2, 9]);
+		assert_ok!(WeightedCollective::vote(RuntimeOrigin::signed(9), hash, 0, true));
+		assert_ok!(WeightedCollective::vote(RuntimeOrigin::signed(2), hash, 0, true));
+		assert_ok!(WeightedCollective::vote(RuntimeOrigin::signed(1), hash, 0, true));
+
+		System::set_block_number(4);
+		assert_ok!(WeightedCollective::close(
+			RuntimeOrigin::signed(4),
+			hash,
+			0,
+			proposal_weight,
+			proposal_len
+		));
+		assert_eq!(
+			System::events().last().map(|r| r.event.clone()),
+			Some(RuntimeEvent::WeightedCollective(CollectiveEvent::Disapproved {
+				proposal_hash: hash
+			}))
+		);
+		assert!(System::events().contains(&record(RuntimeEvent::WeightedCollective(
+			CollectiveEvent::Closed { proposal_hash: hash, yes: 3, no: 3 }
+		))));
+	});
+}
+
 #[test]
 fn disapprove_proposal_works() {
 	new_test_ext().execute_with(|| {
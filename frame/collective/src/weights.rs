@@ -137,6 +137,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof Skipped: Council ProposalCount (max_values: Some(1), max_size: None, mode: Measured)
 	/// Storage: Council Voting (r:0 w:1)
 	/// Proof Skipped: Council Voting (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Council VoteWeights (r:0 w:1)
+	/// Proof Skipped: Council VoteWeights (max_values: None, max_size: None, mode: Measured)
 	/// The range of component `b` is `[2, 1024]`.
 	/// The range of component `m` is `[2, 100]`.
 	/// The range of component `p` is `[1, 100]`.
@@ -153,7 +155,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			// Standard Error: 755
 			.saturating_add(Weight::from_parts(85_670, 0).saturating_mul(p.into()))
 			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 			.saturating_add(Weight::from_parts(0, 165).saturating_mul(m.into()))
 			.saturating_add(Weight::from_parts(0, 180).saturating_mul(p.into()))
 	}
@@ -161,18 +163,20 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof Skipped: Council Members (max_values: Some(1), max_size: None, mode: Measured)
 	/// Storage: Council Voting (r:1 w:1)
 	/// Proof Skipped: Council Voting (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Council VoteWeights (r:1 w:0)
+	/// Proof Skipped: Council VoteWeights (max_values: None, max_size: None, mode: Measured)
 	/// The range of component `m` is `[5, 100]`.
 	fn vote(m: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1006 + m * (64 ±0)`
-		//  Estimated: `4980 + m * (128 ±0)`
+		//  Estimated: `7480 + m * (164 ±0)`
 		// Minimum execution time: 19_790 nanoseconds.
-		Weight::from_parts(20_528_275, 4980)
+		Weight::from_parts(20_528_275, 7480)
 			// Standard Error: 651
 			.saturating_add(Weight::from_parts(48_856, 0).saturating_mul(m.into()))
-			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
-			.saturating_add(Weight::from_parts(0, 128).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(0, 164).saturating_mul(m.into()))
 	}
 	/// Storage: Council Voting (r:1 w:1)
 	/// Proof Skipped: Council Voting (max_values: None, max_size: None, mode: Measured)
@@ -182,21 +186,23 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof Skipped: Council Proposals (max_values: Some(1), max_size: None, mode: Measured)
 	/// Storage: Council ProposalOf (r:0 w:1)
 	/// Proof Skipped: Council ProposalOf (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Council VoteWeights (r:1 w:1)
+	/// Proof Skipped: Council VoteWeights (max_values: None, max_size: None, mode: Measured)
 	/// The range of component `m` is `[4, 100]`.
 	/// The range of component `p` is `[1, 100]`.
 	fn close_early_disapproved(m: u32, p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `626 + m * (64 ±0) + p * (36 ±0)`
-		//  Estimated: `5893 + m * (260 ±0) + p * (144 ±0)`
+		//  Estimated: `8393 + m * (296 ±0) + p * (144 ±0)`
 		// Minimum execution time: 25_564 nanoseconds.
-		Weight::from_parts(25_535_497, 5893)
+		Weight::from_parts(25_535_497, 8393)
 			// Standard Error: 610
 			.saturating_add(Weight::from_parts(27_956, 0).saturating_mul(m.into()))
 			// Standard Error: 595
 			.saturating_add(Weight::from_parts(84_835, 0).saturating_mul(p.into()))
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
-			.saturating_add(Weight::from_parts(0, 260).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(Weight::from_parts(0, 296).saturating_mul(m.into()))
 			.saturating_add(Weight::from_parts(0, 144).saturating_mul(p.into()))
 	}
 	/// Storage: Council Voting (r:1 w:1)
@@ -207,25 +213,27 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof Skipped: Council ProposalOf (max_values: None, max_size: None, mode: Measured)
 	/// Storage: Council Proposals (r:1 w:1)
 	/// Proof Skipped: Council Proposals (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Council VoteWeights (r:1 w:1)
+	/// Proof Skipped: Council VoteWeights (max_values: None, max_size: None, mode: Measured)
 	/// The range of component `b` is `[2, 1024]`.
 	/// The range of component `m` is `[4, 100]`.
 	/// The range of component `p` is `[1, 100]`.
 	fn close_early_approved(b: u32, m: u32, p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `962 + b * (1 ±0) + m * (64 ±0) + p * (40 ±0)`
-		//  Estimated: `9164 + b * (4 ±0) + m * (264 ±0) + p * (160 ±0)`
+		//  Estimated: `11664 + b * (4 ±0) + m * (300 ±0) + p * (160 ±0)`
 		// Minimum execution time: 36_515 nanoseconds.
-		Weight::from_parts(36_626_648, 9164)
+		Weight::from_parts(36_626_648, 11664)
 			// Standard Error: 98
 			.saturating_add(Weight::from_parts(2_295, 0).saturating_mul(b.into()))
 			// Standard Error: 1_036
 			.saturating_add(Weight::from_parts(22_182, 0).saturating_mul(m.into()))
 			// Standard Error: 1_010
 			.saturating_add(Weight::from_parts(100_034, 0).saturating_mul(p.into()))
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 			.saturating_add(Weight::from_parts(0, 4).saturating_mul(b.into()))
-			.saturating_add(Weight::from_parts(0, 264).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(0, 300).saturating_mul(m.into()))
 			.saturating_add(Weight::from_parts(0, 160).saturating_mul(p.into()))
 	}
 	/// Storage: Council Voting (r:1 w:1)
@@ -238,21 +246,23 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof Skipped: Council Proposals (max_values: Some(1), max_size: None, mode: Measured)
 	/// Storage: Council ProposalOf (r:0 w:1)
 	/// Proof Skipped: Council ProposalOf (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Council VoteWeights (r:1 w:1)
+	/// Proof Skipped: Council VoteWeights (max_values: None, max_size: None, mode: Measured)
 	/// The range of component `m` is `[4, 100]`.
 	/// The range of component `p` is `[1, 100]`.
 	fn close_disapproved(m: u32, p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `646 + m * (64 ±0) + p * (36 ±0)`
-		//  Estimated: `7095 + m * (325 ±0) + p * (180 ±0)`
+		//  Estimated: `9595 + m * (361 ±0) + p * (180 ±0)`
 		// Minimum execution time: 28_858 nanoseconds.
-		Weight::from_parts(28_050_047, 7095)
+		Weight::from_parts(28_050_047, 9595)
 			// Standard Error: 614
 			.saturating_add(Weight::from_parts(34_031, 0).saturating_mul(m.into()))
 			// Standard Error: 599
 			.saturating_add(Weight::from_parts(85_744, 0).saturating_mul(p.into()))
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
-			.saturating_add(Weight::from_parts(0, 325).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(Weight::from_parts(0, 361).saturating_mul(m.into()))
 			.saturating_add(Weight::from_parts(0, 180).saturating_mul(p.into()))
 	}
 	/// Storage: Council Voting (r:1 w:1)
@@ -265,25 +275,27 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof Skipped: Council ProposalOf (max_values: None, max_size: None, mode: Measured)
 	/// Storage: Council Proposals (r:1 w:1)
 	/// Proof Skipped: Council Proposals (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Council VoteWeights (r:1 w:1)
+	/// Proof Skipped: Council VoteWeights (max_values: None, max_size: None, mode: Measured)
 	/// The range of component `b` is `[2, 1024]`.
 	/// The range of component `m` is `[4, 100]`.
 	/// The range of component `p` is `[1, 100]`.
 	fn close_approved(b: u32, m: u32, p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `982 + b * (1 ±0) + m * (64 ±0) + p * (40 ±0)`
-		//  Estimated: `10565 + b * (5 ±0) + m * (330 ±0) + p * (200 ±0)`
+		//  Estimated: `13065 + b * (5 ±0) + m * (366 ±0) + p * (200 ±0)`
 		// Minimum execution time: 38_608 nanoseconds.
-		Weight::from_parts(39_948_329, 10565)
+		Weight::from_parts(39_948_329, 13065)
 			// Standard Error: 84
 			.saturating_add(Weight::from_parts(2_045, 0).saturating_mul(b.into()))
 			// Standard Error: 895
 			.saturating_add(Weight::from_parts(22_669, 0).saturating_mul(m.into()))
 			// Standard Error: 872
 			.saturating_add(Weight::from_parts(95_525, 0).saturating_mul(p.into()))
-			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 			.saturating_add(Weight::from_parts(0, 5).saturating_mul(b.into()))
-			.saturating_add(Weight::from_parts(0, 330).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(0, 366).saturating_mul(m.into()))
 			.saturating_add(Weight::from_parts(0, 200).saturating_mul(p.into()))
 	}
 	/// Storage: Council Proposals (r:1 w:1)
@@ -383,6 +395,8 @@ impl WeightInfo for () {
 	/// Proof Skipped: Council ProposalCount (max_values: Some(1), max_size: None, mode: Measured)
 	/// Storage: Council Voting (r:0 w:1)
 	/// Proof Skipped: Council Voting (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Council VoteWeights (r:0 w:1)
+	/// Proof Skipped: Council VoteWeights (max_values: None, max_size: None, mode: Measured)
 	/// The range of component `b` is `[2, 1024]`.
 	/// The range of component `m` is `[2, 100]`.
 	/// The range of component `p` is `[1, 100]`.
@@ -399,7 +413,7 @@ impl WeightInfo for () {
 			// Standard Error: 755
 			.saturating_add(Weight::from_parts(85_670, 0).saturating_mul(p.into()))
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 			.saturating_add(Weight::from_parts(0, 165).saturating_mul(m.into()))
 			.saturating_add(Weight::from_parts(0, 180).saturating_mul(p.into()))
 	}
@@ -407,18 +421,20 @@ impl WeightInfo for () {
 	/// Proof Skipped: Council Members (max_values: Some(1), max_size: None, mode: Measured)
 	/// Storage: Council Voting (r:1 w:1)
 	/// Proof Skipped: Council Voting (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Council VoteWeights (r:1 w:0)
+	/// Proof Skipped: Council VoteWeights (max_values: None, max_size: None, mode: Measured)
 	/// The range of component `m` is `[5, 100]`.
 	fn vote(m: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1006 + m * (64 ±0)`
-		//  Estimated: `4980 + m * (128 ±0)`
+		//  Estimated: `7480 + m * (164 ±0)`
 		// Minimum execution time: 19_790 nanoseconds.
-		Weight::from_parts(20_528_275, 4980)
+		Weight::from_parts(20_528_275, 7480)
 			// Standard Error: 651
 			.saturating_add(Weight::from_parts(48_856, 0).saturating_mul(m.into()))
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
-			.saturating_add(Weight::from_parts(0, 128).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(0, 164).saturating_mul(m.into()))
 	}
 	/// Storage: Council Voting (r:1 w:1)
 	/// Proof Skipped: Council Voting (max_values: None, max_size: None, mode: Measured)
@@ -428,21 +444,23 @@ impl WeightInfo for () {
 	/// Proof Skipped: Council Proposals (max_values: Some(1), max_size: None, mode: Measured)
 	/// Storage: Council ProposalOf (r:0 w:1)
 	/// Proof Skipped: Council ProposalOf (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Council VoteWeights (r:1 w:1)
+	/// Proof Skipped: Council VoteWeights (max_values: None, max_size: None, mode: Measured)
 	/// The range of component `m` is `[4, 100]`.
 	/// The range of component `p` is `[1, 100]`.
 	fn close_early_disapproved(m: u32, p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `626 + m * (64 ±0) + p * (36 ±0)`
-		//  Estimated: `5893 + m * (260 ±0) + p * (144 ±0)`
+		//  Estimated: `8393 + m * (296 ±0) + p * (144 ±0)`
 		// Minimum execution time: 25_564 nanoseconds.
-		Weight::from_parts(25_535_497, 5893)
+		Weight::from_parts(25_535_497, 8393)
 			// Standard Error: 610
 			.saturating_add(Weight::from_parts(27_956, 0).saturating_mul(m.into()))
 			// Standard Error: 595
 			.saturating_add(Weight::from_parts(84_835, 0).saturating_mul(p.into()))
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
-			.saturating_add(Weight::from_parts(0, 260).saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(Weight::from_parts(0, 296).saturating_mul(m.into()))
 			.saturating_add(Weight::from_parts(0, 144).saturating_mul(p.into()))
 	}
 	/// Storage: Council Voting (r:1 w:1)
@@ -453,25 +471,27 @@ impl WeightInfo for () {
 	/// Proof Skipped: Council ProposalOf (max_values: None, max_size: None, mode: Measured)
 	/// Storage: Council Proposals (r:1 w:1)
 	/// Proof Skipped: Council Proposals (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Council VoteWeights (r:1 w:1)
+	/// Proof Skipped: Council VoteWeights (max_values: None, max_size: None, mode: Measured)
 	/// The range of component `b` is `[2, 1024]`.
 	/// The range of component `m` is `[4, 100]`.
 	/// The range of component `p` is `[1, 100]`.
 	fn close_early_approved(b: u32, m: u32, p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `962 + b * (1 ±0) + m * (64 ±0) + p * (40 ±0)`
-		//  Estimated: `9164 + b * (4 ±0) + m * (264 ±0) + p * (160 ±0)`
+		//  Estimated: `11664 + b * (4 ±0) + m * (300 ±0) + p * (160 ±0)`
 		// Minimum execution time: 36_515 nanoseconds.
-		Weight::from_parts(36_626_648, 9164)
+		Weight::from_parts(36_626_648, 11664)
 			// Standard Error: 98
 			.saturating_add(Weight::from_parts(2_295, 0).saturating_mul(b.into()))
 			// Standard Error: 1_036
 			.saturating_add(Weight::from_parts(22_182, 0).saturating_mul(m.into()))
 			// Standard Error: 1_010
 			.saturating_add(Weight::from_parts(100_034, 0).saturating_mul(p.into()))
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 			.saturating_add(Weight::from_parts(0, 4).saturating_mul(b.into()))
-			.saturating_add(Weight::from_parts(0, 264).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(0, 300).saturating_mul(m.into()))
 			.saturating_add(Weight::from_parts(0, 160).saturating_mul(p.into()))
 	}
 	/// Storage: Council Voting (r:1 w:1)
@@ -484,21 +504,23 @@ impl WeightInfo for () {
 	/// Proof Skipped: Council Proposals (max_values: Some(1), max_size: None, mode: Measured)
 	/// Storage: Council ProposalOf (r:0 w:1)
 	/// Proof Skipped: Council ProposalOf (max_values: None, max_size: None, mode: Measured)
+	/// Storage: Council VoteWeights (r:1 w:1)
+	/// Proof Skipped: Council VoteWeights (max_values: None, max_size: None, mode: Measured)
 	/// The range of component `m` is `[4, 100]`.
 	/// The range of component `p` is `[1, 100]`.
 	fn close_disapproved(m: u32, p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `646 + m * (64 ±0) + p * (36 ±0)`
-		//  Estimated: `7095 + m * (325 ±0) + p * (180 ±0)`
+		//  Estimated: `9595 + m * (361 ±0) + p * (180 ±0)`
 		// Minimum execution time: 28_858 nanoseconds.
-		Weight::from_parts(28_050_047, 7095)
+		Weight::from_parts(28_050_047, 9595)
 			// Standard Error: 614
 			.saturating_add(Weight::from_parts(34_031, 0).saturating_mul(m.into()))
 			// Standard Error: 599
 			.saturating_add(Weight::from_parts(85_744, 0).saturating_mul(p.into()))
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
-			.saturating_add(Weight::from_parts(0, 325).saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(Weight::from_parts(0, 361).saturating_mul(m.into()))
 			.saturating_add(Weight::from_parts(0, 180).saturating_mul(p.into()))
 	}
 	/// Storage: Council Voting (r:1 w:1)
@@ -511,25 +533,27 @@ impl WeightInfo for () {
 	/// Proof Skipped: Council ProposalOf (max_values: None, max_size: None, mode: Measured)
 	/// Storage: Council Proposals (r:1 w:1)
 	/// Proof Skipped: Council Proposals (max_values: Some(1), max_size: None, mode: Measured)
+	/// Storage: Council VoteWeights (r:1 w:1)
+	/// Proof Skipped: Council VoteWeights (max_values: None, max_size: None, mode: Measured)
 	/// The range of component `b` is `[2, 1024]`.
 	/// The range of component `m` is `[4, 100]`.
 	/// The range of component `p` is `[1, 100]`.
 	fn close_approved(b: u32, m: u32, p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `982 + b * (1 ±0) + m * (64 ±0) + p * (40 ±0)`
-		//  Estimated: `10565 + b * (5 ±0) + m * (330 ±0) + p * (200 ±0)`
+		//  Estimated: `13065 + b * (5 ±0) + m * (366 ±0) + p * (200 ±0)`
 		// Minimum execution time: 38_608 nanoseconds.
-		Weight::from_parts(39_948_329, 10565)
+		Weight::from_parts(39_948_329, 13065)
 			// Standard Error: 84
 			.saturating_add(Weight::from_parts(2_045, 0).saturating_mul(b.into()))
 			// Standard Error: 895
 			.saturating_add(Weight::from_parts(22_669, 0).saturating_mul(m.into()))
 			// Standard Error: 872
 			.saturating_add(Weight::from_parts(95_525, 0).saturating_mul(p.into()))
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 			.saturating_add(Weight::from_parts(0, 5).saturating_mul(b.into()))
-			.saturating_add(Weight::from_parts(0, 330).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(0, 366).saturating_mul(m.into()))
 			.saturating_add(Weight::from_parts(0, 200).saturating_mul(p.into()))
 	}
 	/// Storage: Council Proposals (r:1 w:1)
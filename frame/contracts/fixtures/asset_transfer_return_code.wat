@@ -0,0 +1,55 @@
+;; This transfers 100 of asset 1 to the zero account, first from the contract's own
+;; balance and then from an approval granted by ALICE. The return codes of both transfers
+;; are copied to the output buffer.
+(module
+	(import "seal0" "asset_transfer" (func $asset_transfer (param i32 i32 i32) (result i32)))
+	(import "seal0" "asset_transfer_approved"
+		(func $asset_transfer_approved (param i32 i32 i32 i32) (result i32))
+	)
+	(import "seal0" "seal_return" (func $seal_return (param i32 i32 i32)))
+	(import "env" "memory" (memory 1 1))
+
+	;; [0, 32) zero-adress
+	(data (i32.const 0)
+		"\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00"
+		"\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00"
+	)
+
+	;; [32, 64) ALICE
+	(data (i32.const 32)
+		"\01\01\01\01\01\01\01\01\01\01\01\01\01\01\01\01"
+		"\01\01\01\01\01\01\01\01\01\01\01\01\01\01\01\01"
+	)
+
+	;; [64, 68) asset 1
+	(data (i32.const 64) "\01\00\00\00")
+
+	;; [68, 76) 100 balance
+	(data (i32.const 68) "\64\00\00\00\00\00\00\00")
+
+	;; [76, 84) here we store the return codes of the transfers
+
+	(func (export "deploy"))
+
+	(func (export "call")
+		(i32.store
+			(i32.const 76)
+			(call $asset_transfer
+				(i32.const 64) ;; ptr to asset id
+				(i32.const 0) ;; ptr to destination address
+				(i32.const 68) ;; ptr to value to transfer
+			)
+		)
+		(i32.store
+			(i32.const 80)
+			(call $asset_transfer_approved
+				(i32.const 64) ;; ptr to asset id
+				(i32.const 32) ;; ptr to owner address
+				(i32.const 0) ;; ptr to destination address
+				(i32.const 68) ;; ptr to value to transfer
+			)
+		)
+		;; exit with success and take both return codes to the output buffer
+		(call $seal_return (i32.const 0) (i32.const 76) (i32.const 8))
+	)
+)
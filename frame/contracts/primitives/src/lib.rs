@@ -53,7 +53,16 @@ pub struct ContractResult<R, Balance> {
 	/// The storage deposit is never actually charged from the origin in case of [`Self::result`]
 	/// is `Err`. This is because on error all storage changes are rolled back including the
 	/// payment of the deposit.
+	///
+	/// It is denominated in the asset storage deposits are charged in if the runtime configures
+	/// one. Otherwise it is denominated in the native currency.
 	pub storage_deposit: StorageDeposit<Balance>,
+	/// How much native balance was paid by the origin on top of [`Self::storage_deposit`].
+	///
+	/// This is only ever non-zero if storage deposits are charged in an asset. It then holds the
+	/// existential deposits of new accounts, the code deposit and the deposits of contracts that
+	/// hold theirs in the native currency. It is rolled back alongside [`Self::storage_deposit`].
+	pub native_storage_deposit: StorageDeposit<Balance>,
 	/// An optional debug message. This message is only filled when explicitly requested
 	/// by the code that calls into the contract. Otherwise it is empty.
 	///
@@ -81,6 +90,53 @@ pub type ContractExecResult<Balance> =
 pub type ContractInstantiateResult<AccountId, Balance> =
 	ContractResult<Result<InstantiateReturnValue<AccountId>, DispatchError>, Balance>;
 
+/// Result type of a `bare_call_traced` call.
+///
+/// The result is `Err` if the call failed before the first frame could be set up. Otherwise it
+/// holds the trace of that frame, whose own `result` reports how the execution ended.
+pub type ContractTraceResult<AccountId, Balance, Hash> =
+	ContractResult<Result<CallTrace<AccountId, Balance, Hash>, DispatchError>, Balance>;
+
+/// Result type of a `bare_call` or `bare_instantiate` call as returned by versions of the
+/// `ContractsApi` before version 4, which lack [`ContractResult::native_storage_deposit`].
+#[derive(Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct ContractResultBeforeV4<R, Balance> {
+	/// See [`ContractResult::gas_consumed`].
+	pub gas_consumed: Weight,
+	/// See [`ContractResult::gas_required`].
+	pub gas_required: Weight,
+	/// See [`ContractResult::storage_deposit`].
+	pub storage_deposit: StorageDeposit<Balance>,
+	/// See [`ContractResult::debug_message`].
+	pub debug_message: Vec<u8>,
+	/// See [`ContractResult::result`].
+	pub result: R,
+}
+
+impl<R, Balance> From<ContractResult<R, Balance>> for ContractResultBeforeV4<R, Balance> {
+	fn from(result: ContractResult<R, Balance>) -> Self {
+		Self {
+			gas_consumed: result.gas_consumed,
+			gas_required: result.gas_required,
+			storage_deposit: result.storage_deposit,
+			debug_message: result.debug_message,
+			result: result.result,
+		}
+	}
+}
+
+/// Result type of a `bare_call` call before version 4 of the `ContractsApi`.
+pub type ContractExecResultBeforeV4<Balance> =
+	ContractResultBeforeV4<Result<ExecReturnValue, DispatchError>, Balance>;
+
+/// Result type of a `bare_instantiate` call before version 4 of the `ContractsApi`.
+pub type ContractInstantiateResultBeforeV4<AccountId, Balance> =
+	ContractResultBeforeV4<Result<InstantiateReturnValue<AccountId>, DispatchError>, Balance>;
+
+/// Result type of a `bare_call_traced` call before version 4 of the `ContractsApi`.
+pub type ContractTraceResultBeforeV4<AccountId, Balance, Hash> =
+	ContractResultBeforeV4<Result<CallTrace<AccountId, Balance, Hash>, DispatchError>, Balance>;
+
 /// Result type of a `bare_code_upload` call.
 pub type CodeUploadResult<CodeHash, Balance> =
 	Result<CodeUploadReturnValue<CodeHash, Balance>, DispatchError>;
@@ -107,7 +163,7 @@ bitflags! {
 }
 
 /// Output of a contract call or instantiation which ran to completion.
-#[derive(PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
 pub struct ExecReturnValue {
 	/// Flags passed along by `seal_return`. Empty when `seal_return` was never called.
 	pub flags: ReturnFlags,
@@ -122,6 +178,51 @@ impl ExecReturnValue {
 	}
 }
 
+/// How a frame recorded in a [`CallTrace`] was entered.
+#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum CallKind {
+	/// A call into a deployed contract.
+	Call,
+	/// A call that executes the code of another contract in the context of the caller.
+	DelegateCall,
+	/// The instantiation of a new contract.
+	Instantiate,
+}
+
+/// The record of one frame of a traced contract execution.
+///
+/// Traces are only ever recorded when requested through an RPC. They are never generated during
+/// on-chain execution.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct CallTrace<AccountId, Balance, Hash> {
+	/// How the frame was entered.
+	pub kind: CallKind,
+	/// The account that called into the frame.
+	pub caller: AccountId,
+	/// The contract executing in the frame.
+	///
+	/// For a [`CallKind::DelegateCall`] this is the caller's own address.
+	pub callee: AccountId,
+	/// The hash of the code that was executed.
+	pub code_hash: Hash,
+	/// The input data passed to the frame.
+	pub input: Vec<u8>,
+	/// The value transferred along with the call or instantiation.
+	pub value: Balance,
+	/// The gas limit the frame was executed with.
+	pub gas_limit: Weight,
+	/// The gas consumed by the frame, including all nested frames.
+	pub gas_consumed: Weight,
+	/// The storage deposit recorded by the frame, including all nested frames.
+	///
+	/// The deposit is dropped again if the frame or one of its callers fails or reverts.
+	pub storage_deposit: StorageDeposit<Balance>,
+	/// The output of the frame or the error it failed with.
+	pub result: Result<ExecReturnValue, DispatchError>,
+	/// The frames that were called or instantiated from this frame, in order.
+	pub calls: Vec<CallTrace<AccountId, Balance, Hash>>,
+}
+
 /// The result of a successful contract instantiation.
 #[derive(PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
 pub struct InstantiateReturnValue<AccountId> {
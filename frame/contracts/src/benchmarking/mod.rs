@@ -37,7 +37,10 @@ use crate::{
 };
 use codec::{Encode, MaxEncodedLen};
 use frame_benchmarking::v1::{account, benchmarks, whitelisted_caller};
-use frame_support::weights::Weight;
+use frame_support::{
+	traits::tokens::fungibles::{approvals::Mutate as _, Inspect as _},
+	weights::Weight,
+};
 use frame_system::RawOrigin;
 use sp_runtime::{
 	traits::{Bounded, Hash},
@@ -174,6 +177,15 @@ fn caller_funding<T: Config>() -> BalanceOf<T> {
 	BalanceOf::<T>::max_value() / 2u32.into()
 }
 
+/// Creates the asset used by the asset host function benchmarks and returns its id together
+/// with the encoded id.
+fn create_asset<T: Config>() -> (AssetIdOf<T>, Vec<u8>) {
+	let owner = account::<T::AccountId>("asset_owner", 0, 0);
+	T::Currency::make_free_balance_be(&owner, caller_funding::<T>());
+	let asset = T::BenchmarkHelper::create_asset(&owner);
+	(asset, asset.encode())
+}
+
 /// Load the specified contract file from disk by including it into the runtime.
 ///
 /// We need to load a different version of ink! contracts when the benchmark is run as
@@ -2269,6 +2281,292 @@ benchmarks! {
 		let origin = RawOrigin::Signed(instance.caller.clone());
 	}: call(origin, instance.addr, 0u32.into(), Weight::MAX, None, vec![])
 
+	// We query the balances of unique accounts.
+	#[pov_mode = Measured]
+	seal_asset_balance {
+		let r in 0 .. API_BENCHMARK_BATCHES;
+		let (asset, asset_bytes) = create_asset::<T>();
+		let accounts = (0..r * API_BENCHMARK_BATCH_SIZE)
+			.map(|i| account::<T::AccountId>("holder", i, 0))
+			.collect::<Vec<_>>();
+		for account in &accounts {
+			T::BenchmarkHelper::mint_into(asset, account, 10u32.into());
+		}
+		let asset_len = asset_bytes.len() as u32;
+		let account_len = accounts.get(0).map(|i| i.encode().len()).unwrap_or(0) as u32;
+		let account_bytes = accounts.iter().flat_map(|x| x.encode()).collect::<Vec<_>>();
+		let out_len_ptr = asset_len + account_bytes.len() as u32;
+		let code = WasmModule::<T>::from(ModuleDefinition {
+			memory: Some(ImportedMemory::max::<T>()),
+			imported_functions: vec![ImportedFunction {
+				module: "seal0",
+				name: "asset_balance",
+				params: vec![ValueType::I32, ValueType::I32, ValueType::I32, ValueType::I32],
+				return_type: None,
+			}],
+			data_segments: vec![
+				DataSegment {
+					offset: 0,
+					value: asset_bytes,
+				},
+				DataSegment {
+					offset: asset_len,
+					value: account_bytes,
+				},
+				DataSegment {
+					offset: out_len_ptr,
+					value: 32u32.to_le_bytes().to_vec(),
+				},
+			],
+			call_body: Some(body::repeated_dyn(r * API_BENCHMARK_BATCH_SIZE, vec![
+				Regular(Instruction::I32Const(0)), // asset_ptr
+				Counter(asset_len, account_len), // account_ptr
+				Regular(Instruction::I32Const(out_len_ptr as i32 + 4)), // out_ptr
+				Regular(Instruction::I32Const(out_len_ptr as i32)), // out_len_ptr
+				Regular(Instruction::Call(0)),
+			])),
+			.. Default::default()
+		});
+		let instance = Contract::<T>::new(code, vec![])?;
+		let origin = RawOrigin::Signed(instance.caller.clone());
+	}: call(origin, instance.addr, 0u32.into(), Weight::MAX, None, vec![])
+
+	// We transfer to unique accounts.
+	#[pov_mode = Measured]
+	seal_asset_transfer {
+		let r in 0 .. API_BENCHMARK_BATCHES;
+		let (asset, asset_bytes) = create_asset::<T>();
+		let accounts = (0..r * API_BENCHMARK_BATCH_SIZE)
+			.map(|i| account::<T::AccountId>("receiver", i, 0))
+			.collect::<Vec<_>>();
+		let asset_len = asset_bytes.len() as u32;
+		let account_len = accounts.get(0).map(|i| i.encode().len()).unwrap_or(0) as u32;
+		let account_bytes = accounts.iter().flat_map(|x| x.encode()).collect::<Vec<_>>();
+		let value: BalanceOf<T> = 10u32.into();
+		let value_ptr = asset_len + account_bytes.len() as u32;
+		let code = WasmModule::<T>::from(ModuleDefinition {
+			memory: Some(ImportedMemory::max::<T>()),
+			imported_functions: vec![ImportedFunction {
+				module: "seal0",
+				name: "asset_transfer",
+				params: vec![ValueType::I32, ValueType::I32, ValueType::I32],
+				return_type: Some(ValueType::I32),
+			}],
+			data_segments: vec![
+				DataSegment {
+					offset: 0,
+					value: asset_bytes,
+				},
+				DataSegment {
+					offset: asset_len,
+					value: account_bytes,
+				},
+				DataSegment {
+					offset: value_ptr,
+					value: value.encode(),
+				},
+			],
+			call_body: Some(body::repeated_dyn(r * API_BENCHMARK_BATCH_SIZE, vec![
+				Regular(Instruction::I32Const(0)), // asset_ptr
+				Counter(asset_len, account_len), // account_ptr
+				Regular(Instruction::I32Const(value_ptr as i32)), // value_ptr
+				Regular(Instruction::Call(0)),
+				Regular(Instruction::Drop),
+			])),
+			.. Default::default()
+		});
+		let instance = Contract::<T>::new(code, vec![])?;
+		T::BenchmarkHelper::mint_into(
+			asset,
+			&instance.account_id,
+			value * (r * API_BENCHMARK_BATCH_SIZE + 1).into(),
+		);
+		let origin = RawOrigin::Signed(instance.caller.clone());
+	}: call(origin, instance.addr, 0u32.into(), Weight::MAX, None, vec![])
+	verify {
+		for account in &accounts {
+			assert_eq!(T::Fungibles::balance(asset, account), value);
+		}
+	}
+
+	// We query the approvals of unique owners.
+	#[pov_mode = Measured]
+	seal_asset_allowance {
+		let r in 0 .. API_BENCHMARK_BATCHES;
+		let (asset, asset_bytes) = create_asset::<T>();
+		let delegate = account::<T::AccountId>("delegate", 0, 0);
+		let owners = (0..r * API_BENCHMARK_BATCH_SIZE)
+			.map(|i| account::<T::AccountId>("owner", i, 0))
+			.collect::<Vec<_>>();
+		for owner in &owners {
+			T::Currency::make_free_balance_be(owner, caller_funding::<T>());
+			T::BenchmarkHelper::mint_into(asset, owner, 10u32.into());
+			T::Fungibles::approve(asset, owner, &delegate, 5u32.into())?;
+		}
+		let asset_len = asset_bytes.len() as u32;
+		let delegate_bytes = delegate.encode();
+		let account_len = delegate_bytes.len() as u32;
+		let owner_bytes = owners.iter().flat_map(|x| x.encode()).collect::<Vec<_>>();
+		let owners_ptr = asset_len + account_len;
+		let out_len_ptr = owners_ptr + owner_bytes.len() as u32;
+		let code = WasmModule::<T>::from(ModuleDefinition {
+			memory: Some(ImportedMemory::max::<T>()),
+			imported_functions: vec![ImportedFunction {
+				module: "seal0",
+				name: "asset_allowance",
+				params: vec![
+					ValueType::I32,
+					ValueType::I32,
+					ValueType::I32,
+					ValueType::I32,
+					ValueType::I32,
+				],
+				return_type: None,
+			}],
+			data_segments: vec![
+				DataSegment {
+					offset: 0,
+					value: asset_bytes,
+				},
+				DataSegment {
+					offset: asset_len,
+					value: delegate_bytes,
+				},
+				DataSegment {
+					offset: owners_ptr,
+					value: owner_bytes,
+				},
+				DataSegment {
+					offset: out_len_ptr,
+					value: 32u32.to_le_bytes().to_vec(),
+				},
+			],
+			call_body: Some(body::repeated_dyn(r * API_BENCHMARK_BATCH_SIZE, vec![
+				Regular(Instruction::I32Const(0)), // asset_ptr
+				Counter(owners_ptr, account_len), // owner_ptr
+				Regular(Instruction::I32Const(asset_len as i32)), // delegate_ptr
+				Regular(Instruction::I32Const(out_len_ptr as i32 + 4)), // out_ptr
+				Regular(Instruction::I32Const(out_len_ptr as i32)), // out_len_ptr
+				Regular(Instruction::Call(0)),
+			])),
+			.. Default::default()
+		});
+		let instance = Contract::<T>::new(code, vec![])?;
+		let origin = RawOrigin::Signed(instance.caller.clone());
+	}: call(origin, instance.addr, 0u32.into(), Weight::MAX, None, vec![])
+
+	// We transfer from unique owners to unique accounts. Both lists of accounts need to fit into
+	// the data segments of the contract which is why we only go up to half the batches.
+	#[pov_mode = Measured]
+	seal_asset_transfer_approved {
+		let r in 0 .. API_BENCHMARK_BATCHES / 2;
+		let (asset, asset_bytes) = create_asset::<T>();
+		let value: BalanceOf<T> = 10u32.into();
+		let owners = (0..r * API_BENCHMARK_BATCH_SIZE)
+			.map(|i| account::<T::AccountId>("owner", i, 0))
+			.collect::<Vec<_>>();
+		let accounts = (0..r * API_BENCHMARK_BATCH_SIZE)
+			.map(|i| account::<T::AccountId>("receiver", i, 0))
+			.collect::<Vec<_>>();
+		let asset_len = asset_bytes.len() as u32;
+		let account_len = accounts.get(0).map(|i| i.encode().len()).unwrap_or(0) as u32;
+		let owner_bytes = owners.iter().flat_map(|x| x.encode()).collect::<Vec<_>>();
+		let account_bytes = accounts.iter().flat_map(|x| x.encode()).collect::<Vec<_>>();
+		let accounts_ptr = asset_len + owner_bytes.len() as u32;
+		let value_ptr = accounts_ptr + account_bytes.len() as u32;
+		let code = WasmModule::<T>::from(ModuleDefinition {
+			memory: Some(ImportedMemory::max::<T>()),
+			imported_functions: vec![ImportedFunction {
+				module: "seal0",
+				name: "asset_transfer_approved",
+				params: vec![ValueType::I32, ValueType::I32, ValueType::I32, ValueType::I32],
+				return_type: Some(ValueType::I32),
+			}],
+			data_segments: vec![
+				DataSegment {
+					offset: 0,
+					value: asset_bytes,
+				},
+				DataSegment {
+					offset: asset_len,
+					value: owner_bytes,
+				},
+				DataSegment {
+					offset: accounts_ptr,
+					value: account_bytes,
+				},
+				DataSegment {
+					offset: value_ptr,
+					value: value.encode(),
+				},
+			],
+			call_body: Some(body::repeated_dyn(r * API_BENCHMARK_BATCH_SIZE, vec![
+				Regular(Instruction::I32Const(0)), // asset_ptr
+				Counter(asset_len, account_len), // owner_ptr
+				Counter(accounts_ptr, account_len), // account_ptr
+				Regular(Instruction::I32Const(value_ptr as i32)), // value_ptr
+				Regular(Instruction::Call(0)),
+				Regular(Instruction::Drop),
+			])),
+			.. Default::default()
+		});
+		// The owners need to approve the contract before it is instantiated by `Contract::new`.
+		let contract = Contracts::<T>::contract_address(
+			&account("instantiator", 0, 0),
+			&code.hash,
+			&[],
+			&[0xff],
+		);
+		for owner in &owners {
+			T::Currency::make_free_balance_be(owner, caller_funding::<T>());
+			T::BenchmarkHelper::mint_into(asset, owner, value * 2u32.into());
+			T::Fungibles::approve(asset, owner, &contract, value)?;
+		}
+		let instance = Contract::<T>::new(code, vec![])?;
+		assert_eq!(instance.account_id, contract);
+		let origin = RawOrigin::Signed(instance.caller.clone());
+	}: call(origin, instance.addr, 0u32.into(), Weight::MAX, None, vec![])
+	verify {
+		for account in &accounts {
+			assert_eq!(T::Fungibles::balance(asset, account), value);
+		}
+	}
+
+	#[pov_mode = Measured]
+	seal_asset_metadata {
+		let r in 0 .. API_BENCHMARK_BATCHES;
+		let (_, asset_bytes) = create_asset::<T>();
+		let asset_len = asset_bytes.len() as u32;
+		let code = WasmModule::<T>::from(ModuleDefinition {
+			memory: Some(ImportedMemory::max::<T>()),
+			imported_functions: vec![ImportedFunction {
+				module: "seal0",
+				name: "asset_metadata",
+				params: vec![ValueType::I32, ValueType::I32, ValueType::I32],
+				return_type: None,
+			}],
+			data_segments: vec![
+				DataSegment {
+					offset: 0,
+					value: asset_bytes,
+				},
+				DataSegment {
+					offset: asset_len,
+					value: 64u32.to_le_bytes().to_vec(),
+				},
+			],
+			call_body: Some(body::repeated(r * API_BENCHMARK_BATCH_SIZE, &[
+				Instruction::I32Const(0), // asset_ptr
+				Instruction::I32Const(asset_len as i32 + 4), // out_ptr
+				Instruction::I32Const(asset_len as i32), // out_len_ptr
+				Instruction::Call(0),
+			])),
+			.. Default::default()
+		});
+		let instance = Contract::<T>::new(code, vec![])?;
+		let origin = RawOrigin::Signed(instance.caller.clone());
+	}: call(origin, instance.addr, 0u32.into(), Weight::MAX, None, vec![])
+
 	// We make the assumption that pushing a constant and dropping a value takes roughly
 	// the same amount of time. We follow that `t.load` and `drop` both have the weight
 	// of this benchmark / 2. We need to make this assumption because there is no way
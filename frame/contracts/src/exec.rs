@@ -18,19 +18,28 @@
 use crate::{
 	gas::GasMeter,
 	storage::{self, DepositAccount, WriteOutcome},
-	BalanceOf, CodeHash, Config, ContractInfo, ContractInfoOf, DebugBufferVec, Determinism, Error,
-	Event, Nonce, Pallet as Contracts, Schedule, System,
+	AssetIdOf, BalanceOf, CodeHash, Config, ContractInfo, ContractInfoOf, DebugBufferVec,
+	Determinism, Error, Event, Nonce, Pallet as Contracts, Schedule, System,
 };
 use frame_support::{
 	crypto::ecdsa::ECDSAExt,
 	dispatch::{DispatchError, DispatchResult, DispatchResultWithPostInfo, Dispatchable},
 	storage::{with_transaction, TransactionOutcome},
-	traits::{Contains, Currency, ExistenceRequirement, OriginTrait, Randomness, Time},
+	traits::{
+		tokens::fungibles::{
+			approvals::{Inspect as _, Mutate as _},
+			metadata::Inspect as _,
+			Inspect as _, Transfer as _,
+		},
+		Contains, Currency, ExistenceRequirement, OriginTrait, Randomness, Time,
+	},
 	weights::Weight,
-	Blake2_128Concat, BoundedVec, StorageHasher,
+	Blake2_128Concat, BoundedVec, DefaultNoBound, StorageHasher,
 };
 use frame_system::RawOrigin;
-use pallet_contracts_primitives::ExecReturnValue;
+use pallet_contracts_primitives::{
+	CallKind, CallTrace, ExecReturnValue, ReturnFlags, StorageDeposit,
+};
 use smallvec::{Array, SmallVec};
 use sp_core::ecdsa::Public as ECDSAPublic;
 use sp_io::{crypto::secp256k1_ecdsa_recover_compressed, hashing::blake2_256};
@@ -248,6 +257,42 @@ pub trait Ext: sealing::Sealed {
 	/// Returns the minimum balance that is required for creating an account.
 	fn minimum_balance(&self) -> BalanceOf<Self::T>;
 
+	/// Returns the balance of `who` in the fungible `asset`.
+	fn asset_balance(
+		&self,
+		asset: AssetIdOf<Self::T>,
+		who: &AccountIdOf<Self::T>,
+	) -> BalanceOf<Self::T>;
+
+	/// Transfer some amount of the fungible `asset` from the current contract to `to`.
+	fn asset_transfer(
+		&mut self,
+		asset: AssetIdOf<Self::T>,
+		to: &AccountIdOf<Self::T>,
+		value: BalanceOf<Self::T>,
+	) -> DispatchResult;
+
+	/// Returns the amount of `asset` that `owner` approved `delegate` to spend.
+	fn asset_allowance(
+		&self,
+		asset: AssetIdOf<Self::T>,
+		owner: &AccountIdOf<Self::T>,
+		delegate: &AccountIdOf<Self::T>,
+	) -> BalanceOf<Self::T>;
+
+	/// Transfer some amount of `asset` from `owner` to `to`, spending an approval that `owner`
+	/// granted to the current contract.
+	fn asset_transfer_approved(
+		&mut self,
+		asset: AssetIdOf<Self::T>,
+		owner: &AccountIdOf<Self::T>,
+		to: &AccountIdOf<Self::T>,
+		value: BalanceOf<Self::T>,
+	) -> DispatchResult;
+
+	/// Returns the name, symbol and number of decimals of `asset`.
+	fn asset_metadata(&self, asset: AssetIdOf<Self::T>) -> (Vec<u8>, Vec<u8>, u8);
+
 	/// Returns a random number for the current block with the given subject.
 	fn random(&self, subject: &[u8]) -> (SeedOf<Self::T>, BlockNumberOf<Self::T>);
 
@@ -410,12 +455,59 @@ pub struct Stack<'a, T: Config, E> {
 	/// All the bytes added to this field should be valid UTF-8. The buffer has no defined
 	/// structure and is intended to be shown to users as-is for debugging purposes.
 	debug_message: Option<&'a mut DebugBufferVec<T>>,
+	/// Records a trace of every frame when set.
+	call_tracer: Option<&'a mut CallTracer<T>>,
 	/// The determinism requirement of this call stack.
 	determinism: Determinism,
 	/// No executable is held by the struct but influences its behaviour.
 	_phantom: PhantomData<E>,
 }
 
+/// A [`CallTrace`] with the types of the runtime.
+pub type CallTraceOf<T> = CallTrace<AccountIdOf<T>, BalanceOf<T>, CodeHash<T>>;
+
+/// Records the frames of a call stack as a tree of [`CallTrace`]s.
+///
+/// Every frame adds allocations which is why this must never be used on-chain.
+#[derive(DefaultNoBound)]
+pub struct CallTracer<T: Config> {
+	/// The traces of the frames that are still executing. The last one belongs to the top frame.
+	open: Vec<CallTraceOf<T>>,
+	/// The trace of the first frame once it returned.
+	root: Option<CallTraceOf<T>>,
+}
+
+impl<T: Config> CallTracer<T> {
+	/// Consume the tracer and return the trace of the first frame.
+	///
+	/// Returns `None` if no frame was executed.
+	pub fn into_trace(self) -> Option<CallTraceOf<T>> {
+		self.root
+	}
+
+	/// Start recording a new top frame.
+	fn enter(&mut self, trace: CallTraceOf<T>) {
+		self.open.push(trace);
+	}
+
+	/// Finish recording the top frame and attach it to its caller.
+	fn exit(
+		&mut self,
+		result: Result<ExecReturnValue, DispatchError>,
+		gas_consumed: Weight,
+		storage_deposit: StorageDeposit<BalanceOf<T>>,
+	) {
+		let Some(mut trace) = self.open.pop() else { return };
+		trace.result = result;
+		trace.gas_consumed = gas_consumed;
+		trace.storage_deposit = storage_deposit;
+		match self.open.last_mut() {
+			Some(caller) => caller.calls.push(trace),
+			None => self.root = Some(trace),
+		}
+	}
+}
+
 /// Represents one entry in the call stack.
 ///
 /// For each nested contract call or instantiate one frame is created. It holds specific
@@ -615,8 +707,9 @@ where
 	///
 	/// # Note
 	///
-	/// `debug_message` should only ever be set to `Some` when executing as an RPC because
-	/// it adds allocations and could be abused to drive the runtime into an OOM panic.
+	/// `debug_message` and `call_tracer` should only ever be set to `Some` when executing as an
+	/// RPC because they add allocations and could be abused to drive the runtime into an OOM
+	/// panic.
 	///
 	/// # Return Value
 	///
@@ -630,6 +723,7 @@ where
 		value: BalanceOf<T>,
 		input_data: Vec<u8>,
 		debug_message: Option<&'a mut DebugBufferVec<T>>,
+		call_tracer: Option<&'a mut CallTracer<T>>,
 		determinism: Determinism,
 	) -> Result<ExecReturnValue, ExecError> {
 		let (mut stack, executable) = Self::new(
@@ -640,6 +734,7 @@ where
 			schedule,
 			value,
 			debug_message,
+			call_tracer,
 			determinism,
 		)?;
 		stack.run(executable, input_data)
@@ -649,8 +744,9 @@ where
 	///
 	/// # Note
 	///
-	/// `debug_message` should only ever be set to `Some` when executing as an RPC because
-	/// it adds allocations and could be abused to drive the runtime into an OOM panic.
+	/// `debug_message` and `call_tracer` should only ever be set to `Some` when executing as an
+	/// RPC because they add allocations and could be abused to drive the runtime into an OOM
+	/// panic.
 	///
 	/// # Return Value
 	///
@@ -665,6 +761,7 @@ where
 		input_data: Vec<u8>,
 		salt: &[u8],
 		debug_message: Option<&'a mut DebugBufferVec<T>>,
+		call_tracer: Option<&'a mut CallTracer<T>>,
 	) -> Result<(T::AccountId, ExecReturnValue), ExecError> {
 		let (mut stack, executable) = Self::new(
 			FrameArgs::Instantiate {
@@ -680,6 +777,7 @@ where
 			schedule,
 			value,
 			debug_message,
+			call_tracer,
 			Determinism::Deterministic,
 		)?;
 		let account_id = stack.top_frame().account_id.clone();
@@ -695,6 +793,7 @@ where
 		schedule: &'a Schedule<T>,
 		value: BalanceOf<T>,
 		debug_message: Option<&'a mut DebugBufferVec<T>>,
+		call_tracer: Option<&'a mut CallTracer<T>>,
 		determinism: Determinism,
 	) -> Result<(Self, E), ExecError> {
 		let (first_frame, executable, nonce) = Self::new_frame(
@@ -717,6 +816,7 @@ where
 			first_frame,
 			frames: Default::default(),
 			debug_message,
+			call_tracer,
 			determinism,
 			_phantom: Default::default(),
 		};
@@ -843,6 +943,29 @@ where
 		let entry_point = frame.entry_point;
 		let delegated_code_hash =
 			if frame.delegate_caller.is_some() { Some(*executable.code_hash()) } else { None };
+		if self.call_tracer.is_some() {
+			let kind = match (entry_point, delegated_code_hash) {
+				(ExportedFunction::Constructor, _) => CallKind::Instantiate,
+				(ExportedFunction::Call, Some(_)) => CallKind::DelegateCall,
+				(ExportedFunction::Call, None) => CallKind::Call,
+			};
+			let trace = CallTrace {
+				kind,
+				caller: self.caller().clone(),
+				callee: frame.account_id.clone(),
+				code_hash: *executable.code_hash(),
+				input: input_data.clone(),
+				value: frame.value_transferred,
+				gas_limit: frame.nested_gas.gas_left(),
+				gas_consumed: Weight::zero(),
+				storage_deposit: Default::default(),
+				result: Ok(ExecReturnValue { flags: ReturnFlags::empty(), data: Vec::new() }),
+				calls: Vec::new(),
+			};
+			if let Some(tracer) = self.call_tracer.as_mut() {
+				tracer.enter(trace);
+			}
+		}
 		let do_transaction = || {
 			// We need to charge the storage deposit before the initial transfer so that
 			// it can create the account in case the initial transfer is < ed.
@@ -937,6 +1060,19 @@ where
 			Err(error) => (false, Err(error.into())),
 		};
 
+		if self.call_tracer.is_some() {
+			let frame = top_frame_mut!(self);
+			frame.contract_info.load(&frame.account_id);
+			let info = frame.contract_info.as_contract().cloned();
+			let gas_consumed = frame.nested_gas.gas_consumed();
+			// Only the part denominated like the storage deposit limit is traced.
+			let (storage_deposit, _) = frame.nested_storage.consumed(info).split();
+			let result = output.as_ref().map(Clone::clone).map_err(|e: &ExecError| e.error);
+			if let Some(tracer) = self.call_tracer.as_mut() {
+				tracer.exit(result, gas_consumed, storage_deposit);
+			}
+		}
+
 		self.pop_frame(success);
 		output
 	}
@@ -1330,6 +1466,46 @@ where
 		T::Currency::minimum_balance()
 	}
 
+	fn asset_balance(&self, asset: AssetIdOf<T>, who: &T::AccountId) -> BalanceOf<T> {
+		T::Fungibles::balance(asset, who)
+	}
+
+	fn asset_transfer(
+		&mut self,
+		asset: AssetIdOf<T>,
+		to: &T::AccountId,
+		value: BalanceOf<T>,
+	) -> DispatchResult {
+		T::Fungibles::transfer(asset, &self.top_frame().account_id, to, value, true)
+			.map_err(|_| Error::<T>::TransferFailed)?;
+		Ok(())
+	}
+
+	fn asset_allowance(
+		&self,
+		asset: AssetIdOf<T>,
+		owner: &T::AccountId,
+		delegate: &T::AccountId,
+	) -> BalanceOf<T> {
+		T::Fungibles::allowance(asset, owner, delegate)
+	}
+
+	fn asset_transfer_approved(
+		&mut self,
+		asset: AssetIdOf<T>,
+		owner: &T::AccountId,
+		to: &T::AccountId,
+		value: BalanceOf<T>,
+	) -> DispatchResult {
+		T::Fungibles::transfer_from(asset, owner, &self.top_frame().account_id, to, value)
+			.map_err(|_| Error::<T>::TransferFailed)?;
+		Ok(())
+	}
+
+	fn asset_metadata(&self, asset: AssetIdOf<T>) -> (Vec<u8>, Vec<u8>, u8) {
+		(T::Fungibles::name(asset), T::Fungibles::symbol(asset), T::Fungibles::decimals(asset))
+	}
+
 	fn deposit_event(&mut self, topics: Vec<T::Hash>, data: Vec<u8>) {
 		Contracts::<Self::T>::deposit_event(
 			topics,
@@ -1645,6 +1821,7 @@ mod tests {
 					value,
 					vec![],
 					None,
+					None,
 					Determinism::Deterministic,
 				),
 				Ok(_)
@@ -1699,6 +1876,7 @@ mod tests {
 				value,
 				vec![],
 				None,
+				None,
 				Determinism::Deterministic,
 			)
 			.unwrap();
@@ -1741,6 +1919,7 @@ mod tests {
 				value,
 				vec![],
 				None,
+				None,
 				Determinism::Deterministic,
 			)
 			.unwrap();
@@ -1777,6 +1956,7 @@ mod tests {
 				55,
 				vec![],
 				None,
+				None,
 				Determinism::Deterministic,
 			)
 			.unwrap();
@@ -1829,6 +2009,7 @@ mod tests {
 				0,
 				vec![],
 				None,
+				None,
 				Determinism::Deterministic,
 			);
 
@@ -1862,6 +2043,7 @@ mod tests {
 				0,
 				vec![],
 				None,
+				None,
 				Determinism::Deterministic,
 			);
 
@@ -1893,6 +2075,7 @@ mod tests {
 				0,
 				vec![1, 2, 3, 4],
 				None,
+				None,
 				Determinism::Deterministic,
 			);
 			assert_matches!(result, Ok(_));
@@ -1926,6 +2109,7 @@ mod tests {
 				vec![1, 2, 3, 4],
 				&[],
 				None,
+				None,
 			);
 			assert_matches!(result, Ok(_));
 		});
@@ -1973,6 +2157,7 @@ mod tests {
 				value,
 				vec![],
 				None,
+				None,
 				Determinism::Deterministic,
 			);
 
@@ -2019,6 +2204,7 @@ mod tests {
 				0,
 				vec![],
 				None,
+				None,
 				Determinism::Deterministic,
 			);
 
@@ -2053,6 +2239,7 @@ mod tests {
 				0,
 				vec![],
 				None,
+				None,
 				Determinism::Deterministic,
 			);
 			assert_matches!(result, Ok(_));
@@ -2083,6 +2270,7 @@ mod tests {
 				0,
 				vec![0],
 				None,
+				None,
 				Determinism::Deterministic,
 			);
 			assert_matches!(result, Ok(_));
@@ -2111,6 +2299,7 @@ mod tests {
 				0,
 				vec![0],
 				None,
+				None,
 				Determinism::Deterministic,
 			);
 			assert_matches!(result, Ok(_));
@@ -2147,6 +2336,7 @@ mod tests {
 				0,
 				vec![0],
 				None,
+				None,
 				Determinism::Deterministic,
 			);
 			assert_matches!(result, Ok(_));
@@ -2183,6 +2373,7 @@ mod tests {
 				0,
 				vec![],
 				None,
+				None,
 				Determinism::Deterministic,
 			);
 
@@ -2212,6 +2403,7 @@ mod tests {
 					vec![],
 					&[],
 					None,
+					None,
 				),
 				Err(_)
 			);
@@ -2245,6 +2437,7 @@ mod tests {
 					vec![],
 					&[],
 					None,
+					None,
 				),
 				Ok((address, ref output)) if output.data == vec![80, 65, 83, 83] => address
 			);
@@ -2289,6 +2482,7 @@ mod tests {
 					vec![],
 					&[],
 					None,
+					None,
 				),
 				Ok((address, ref output)) if output.data == vec![70, 65, 73, 76] => address
 			);
@@ -2342,6 +2536,7 @@ mod tests {
 					min_balance * 10,
 					vec![],
 					None,
+					None,
 					Determinism::Deterministic,
 				),
 				Ok(_)
@@ -2407,6 +2602,7 @@ mod tests {
 					0,
 					vec![],
 					None,
+					None,
 					Determinism::Deterministic,
 				),
 				Ok(_)
@@ -2444,6 +2640,7 @@ mod tests {
 					vec![],
 					&[],
 					None,
+					None,
 				),
 				Err(Error::<Test>::TerminatedInConstructor.into())
 			);
@@ -2493,6 +2690,7 @@ mod tests {
 				0,
 				vec![0],
 				None,
+				None,
 				Determinism::Deterministic,
 			);
 			assert_matches!(result, Ok(_));
@@ -2528,6 +2726,7 @@ mod tests {
 				vec![],
 				&[],
 				None,
+				None,
 			);
 			assert_matches!(result, Ok(_));
 		});
@@ -2559,6 +2758,7 @@ mod tests {
 				0,
 				vec![],
 				Some(&mut debug_buffer),
+				None,
 				Determinism::Deterministic,
 			)
 			.unwrap();
@@ -2593,6 +2793,7 @@ mod tests {
 				0,
 				vec![],
 				Some(&mut debug_buffer),
+				None,
 				Determinism::Deterministic,
 			);
 			assert!(result.is_err());
@@ -2630,6 +2831,7 @@ mod tests {
 				0,
 				vec![],
 				Some(&mut debug_buf_after),
+				None,
 				Determinism::Deterministic,
 			)
 			.unwrap();
@@ -2663,6 +2865,7 @@ mod tests {
 				0,
 				CHARLIE.encode(),
 				None,
+				None,
 				Determinism::Deterministic
 			));
 
@@ -2677,6 +2880,7 @@ mod tests {
 					0,
 					BOB.encode(),
 					None,
+					None,
 					Determinism::Deterministic
 				)
 				.map_err(|e| e.error),
@@ -2716,6 +2920,7 @@ mod tests {
 					0,
 					vec![0],
 					None,
+					None,
 					Determinism::Deterministic
 				)
 				.map_err(|e| e.error),
@@ -2751,6 +2956,7 @@ mod tests {
 				0,
 				vec![],
 				None,
+				None,
 				Determinism::Deterministic,
 			)
 			.unwrap();
@@ -2834,6 +3040,7 @@ mod tests {
 				0,
 				vec![],
 				None,
+				None,
 				Determinism::Deterministic,
 			)
 			.unwrap();
@@ -2936,6 +3143,7 @@ mod tests {
 				vec![],
 				&[],
 				None,
+				None,
 			)
 			.ok();
 			assert_eq!(<Nonce<Test>>::get(), 0);
@@ -2950,6 +3158,7 @@ mod tests {
 				vec![],
 				&[],
 				None,
+				None,
 			));
 			assert_eq!(<Nonce<Test>>::get(), 1);
 
@@ -2963,6 +3172,7 @@ mod tests {
 				vec![],
 				&[],
 				None,
+				None,
 			));
 			assert_eq!(<Nonce<Test>>::get(), 2);
 
@@ -2976,6 +3186,7 @@ mod tests {
 				vec![],
 				&[],
 				None,
+				None,
 			));
 			assert_eq!(<Nonce<Test>>::get(), 4);
 		});
@@ -3037,6 +3248,7 @@ mod tests {
 				0,
 				vec![],
 				None,
+				None,
 				Determinism::Deterministic
 			));
 		});
@@ -3164,6 +3376,7 @@ mod tests {
 				0,
 				vec![],
 				None,
+				None,
 				Determinism::Deterministic
 			));
 		});
@@ -3200,6 +3413,7 @@ mod tests {
 				0,
 				vec![],
 				None,
+				None,
 				Determinism::Deterministic
 			));
 		});
@@ -3236,6 +3450,7 @@ mod tests {
 				0,
 				vec![],
 				None,
+				None,
 				Determinism::Deterministic
 			));
 		});
@@ -3298,6 +3513,7 @@ mod tests {
 				0,
 				vec![],
 				None,
+				None,
 				Determinism::Deterministic
 			));
 		});
@@ -3360,6 +3576,7 @@ mod tests {
 				0,
 				vec![],
 				None,
+				None,
 				Determinism::Deterministic
 			));
 		});
@@ -3392,6 +3609,7 @@ mod tests {
 				0,
 				vec![],
 				None,
+				None,
 				Determinism::Deterministic,
 			);
 			assert_matches!(result, Ok(_));
@@ -3438,6 +3656,7 @@ mod tests {
 				0,
 				vec![],
 				None,
+				None,
 				Determinism::Deterministic
 			));
 		});
@@ -3468,6 +3687,7 @@ mod tests {
 				0,
 				vec![],
 				None,
+				None,
 				Determinism::Deterministic,
 			);
 			assert_matches!(result, Ok(_));
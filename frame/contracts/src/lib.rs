@@ -100,9 +100,12 @@ pub mod weights;
 mod tests;
 
 use crate::{
-	exec::{AccountIdOf, ErrorOrigin, ExecError, Executable, Stack as ExecStack},
+	exec::{AccountIdOf, CallTracer, ErrorOrigin, ExecError, Executable, Stack as ExecStack},
 	gas::GasMeter,
-	storage::{meter::Meter as StorageMeter, ContractInfo, DeletedContract},
+	storage::{
+		meter::{Deposits, Meter as StorageMeter},
+		ContractInfo, DeletedContract,
+	},
 	wasm::{OwnerInfo, PrefabWasmModule, TryInstantiate},
 	weights::WeightInfo,
 };
@@ -112,8 +115,8 @@ use frame_support::{
 	dispatch::{Dispatchable, GetDispatchInfo, Pays, PostDispatchInfo},
 	ensure,
 	traits::{
-		tokens::fungible::Inspect, ConstU32, Contains, Currency, Get, Randomness,
-		ReservableCurrency, Time,
+		tokens::{fungible::Inspect, fungibles, BalanceConversion},
+		ConstU32, Contains, Currency, Get, Randomness, ReservableCurrency, Time,
 	},
 	weights::{OldWeight, Weight},
 	BoundedVec, WeakBoundedVec,
@@ -121,12 +124,16 @@ use frame_support::{
 use frame_system::Pallet as System;
 use pallet_contracts_primitives::{
 	Code, CodeUploadResult, CodeUploadReturnValue, ContractAccessError, ContractExecResult,
-	ContractInstantiateResult, ExecReturnValue, GetStorageResult, InstantiateReturnValue,
-	StorageDeposit,
+	ContractExecResultBeforeV4, ContractInstantiateResult, ContractInstantiateResultBeforeV4,
+	ContractTraceResult, ContractTraceResultBeforeV4, ExecReturnValue, GetStorageResult,
+	InstantiateReturnValue, StorageDeposit,
 };
 use scale_info::TypeInfo;
 use smallvec::Array;
-use sp_runtime::traits::{Convert, Hash, Saturating, StaticLookup};
+use sp_runtime::{
+	traits::{Convert, Hash, Saturating, StaticLookup},
+	DispatchError,
+};
 use sp_std::{fmt::Debug, marker::PhantomData, prelude::*};
 
 pub use crate::{
@@ -145,6 +152,9 @@ type CodeHash<T> = <T as frame_system::Config>::Hash;
 type TrieId = BoundedVec<u8, ConstU32<128>>;
 type BalanceOf<T> =
 	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+type AssetIdOf<T> = <<T as Config>::Fungibles as fungibles::Inspect<
+	<T as frame_system::Config>::AccountId,
+>>::AssetId;
 type CodeVec<T> = BoundedVec<u8, <T as Config>::MaxCodeLen>;
 type RelaxedCodeVec<T> = WeakBoundedVec<u8, <T as Config>::MaxCodeLen>;
 type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
@@ -158,6 +168,16 @@ type DebugBufferVec<T> = BoundedVec<u8, <T as Config>::MaxDebugBufferLen>;
 /// that this value makes sense for a memory location or length.
 const SENTINEL: u32 = u32::MAX;
 
+/// Sets up the assets used by the benchmarks of the asset host functions.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AccountId, AssetId, Balance> {
+	/// Create an asset with metadata which is owned by `owner` and return its id.
+	fn create_asset(owner: &AccountId) -> AssetId;
+
+	/// Mint `amount` of `asset` into the account of `who`.
+	fn mint_into(asset: AssetId, who: &AccountId, amount: Balance);
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -165,7 +185,7 @@ pub mod pallet {
 	use frame_system::pallet_prelude::*;
 
 	/// The current storage version.
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(9);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(10);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -190,6 +210,18 @@ pub mod pallet {
 		type Currency: ReservableCurrency<Self::AccountId>
 			+ Inspect<Self::AccountId, Balance = BalanceOf<Self>>;
 
+		/// The fungible assets that contracts can inspect and move through the asset host
+		/// functions (`asset_balance`, `asset_transfer`, `asset_allowance`,
+		/// `asset_transfer_approved` and `asset_metadata`).
+		///
+		/// Asset amounts share the balance type of [`Self::Currency`] so that contracts can use
+		/// the same encoding for native and asset values.
+		type Fungibles: fungibles::Inspect<Self::AccountId, Balance = BalanceOf<Self>>
+			+ fungibles::Transfer<Self::AccountId>
+			+ fungibles::approvals::Inspect<Self::AccountId>
+			+ fungibles::approvals::Mutate<Self::AccountId>
+			+ fungibles::metadata::Inspect<Self::AccountId>;
+
 		/// The overarching event type.
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
@@ -288,6 +320,26 @@ pub mod pallet {
 		#[pallet::constant]
 		type DepositPerItem: Get<BalanceOf<Self>>;
 
+		/// The asset in which the storage deposits of newly instantiated contracts are charged
+		/// and refunded. `None` uses [`Self::Currency`].
+		///
+		/// The deposit asset of a contract is recorded on instantiation. Changing this value
+		/// only affects contracts instantiated afterwards. The storage deposit limit of a call is
+		/// denominated in this asset. It separately limits what is paid in [`Self::Currency`].
+		///
+		/// # Note
+		///
+		/// Code deposits and the existential deposits of a contract and its deposit account are
+		/// always paid in [`Self::Currency`].
+		type DepositAsset: Get<Option<AssetIdOf<Self>>>;
+
+		/// Converts [`Self::DepositPerByte`] and [`Self::DepositPerItem`] into the asset a
+		/// contract's deposit is held in.
+		///
+		/// A price that fails to convert is treated as infinite so that no storage can be paid
+		/// for in that asset.
+		type DepositConversion: BalanceConversion<BalanceOf<Self>, AssetIdOf<Self>, BalanceOf<Self>>;
+
 		/// The address generator used to generate the addresses of contracts.
 		type AddressGenerator: AddressGenerator<Self>;
 
@@ -320,6 +372,10 @@ pub mod pallet {
 		/// The maximum length of the debug buffer in bytes.
 		#[pallet::constant]
 		type MaxDebugBufferLen: Get<u32>;
+
+		/// Helper for setting up the assets used by the benchmarks.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<Self::AccountId, AssetIdOf<Self>, BalanceOf<Self>>;
 	}
 
 	#[pallet::hooks]
@@ -596,7 +652,8 @@ pub mod pallet {
 		/// * `value`: The balance to transfer from the `origin` to `dest`.
 		/// * `gas_limit`: The gas limit enforced when executing the constructor.
 		/// * `storage_deposit_limit`: The maximum amount of balance that can be charged from the
-		///   caller to pay for the storage consumed.
+		///   caller to pay for the storage consumed. It is denominated in [`Config::DepositAsset`]
+		///   and separately limits what is paid in [`Config::Currency`].
 		/// * `data`: The input data to pass to the contract.
 		///
 		/// * If the account is a smart-contract account, the associated code will be
@@ -624,6 +681,7 @@ pub mod pallet {
 				gas_limit,
 				storage_deposit_limit: storage_deposit_limit.map(Into::into),
 				debug_message: None,
+				call_tracer: None,
 			};
 			let mut output = CallInput::<T> { dest, determinism: Determinism::Deterministic }
 				.run_guarded(common);
@@ -647,7 +705,8 @@ pub mod pallet {
 		/// * `value`: The balance to transfer from the `origin` to the newly created contract.
 		/// * `gas_limit`: The gas limit enforced when executing the constructor.
 		/// * `storage_deposit_limit`: The maximum amount of balance that can be charged/reserved
-		///   from the caller to pay for the storage consumed.
+		///   from the caller to pay for the storage consumed. It is denominated in
+		///   [`Config::DepositAsset`] and separately limits what is paid in [`Config::Currency`].
 		/// * `code`: The contract code to deploy in raw bytes.
 		/// * `data`: The input data to pass to the contract constructor.
 		/// * `salt`: Used for the address derivation. See [`Pallet::contract_address`].
@@ -686,6 +745,7 @@ pub mod pallet {
 				gas_limit,
 				storage_deposit_limit: storage_deposit_limit.map(Into::into),
 				debug_message: None,
+				call_tracer: None,
 			};
 			let mut output =
 				InstantiateInput::<T> { code: Code::Upload(code), salt }.run_guarded(common);
@@ -728,6 +788,7 @@ pub mod pallet {
 				gas_limit,
 				storage_deposit_limit: storage_deposit_limit.map(Into::into),
 				debug_message: None,
+				call_tracer: None,
 			};
 			let mut output =
 				InstantiateInput::<T> { code: Code::Existing(code_hash), salt }.run_guarded(common);
@@ -963,6 +1024,7 @@ struct CommonInput<'a, T: Config> {
 	gas_limit: Weight,
 	storage_deposit_limit: Option<BalanceOf<T>>,
 	debug_message: Option<&'a mut DebugBufferVec<T>>,
+	call_tracer: Option<&'a mut CallTracer<T>>,
 }
 
 /// Input specific to a call into contract.
@@ -982,7 +1044,7 @@ struct InternalOutput<T: Config, O> {
 	/// The gas meter that was used to execute the call.
 	gas_meter: GasMeter<T>,
 	/// The storage deposit used by the call.
-	storage_deposit: StorageDeposit<BalanceOf<T>>,
+	storage_deposit: Deposits<T>,
 	/// The result of the call.
 	result: Result<O, ExecError>,
 }
@@ -1063,7 +1125,7 @@ impl<T: Config> Invokable<T> for CallInput<T> {
 			};
 		let schedule = T::Schedule::get();
 		let CallInput { dest, determinism } = self;
-		let CommonInput { origin, value, data, debug_message, .. } = common;
+		let CommonInput { origin, value, data, debug_message, call_tracer, .. } = common;
 		let result = ExecStack::<T, PrefabWasmModule<T>>::run_call(
 			origin.clone(),
 			dest.clone(),
@@ -1073,9 +1135,18 @@ impl<T: Config> Invokable<T> for CallInput<T> {
 			value,
 			data.clone(),
 			debug_message,
+			call_tracer,
 			*determinism,
 		);
-		InternalOutput { gas_meter, storage_deposit: storage_meter.into_deposit(&origin), result }
+		// A deposit that can't be paid fails the call so that its storage changes are reverted.
+		match storage_meter.try_into_deposit(&origin) {
+			Ok(storage_deposit) => InternalOutput { gas_meter, storage_deposit, result },
+			Err(err) => InternalOutput {
+				gas_meter,
+				storage_deposit: Default::default(),
+				result: result.and(Err(err.into())),
+			},
+		}
 	}
 }
 
@@ -1124,7 +1195,7 @@ impl<T: Config> Invokable<T> for InstantiateInput<T> {
 			)?;
 
 			let InstantiateInput { salt, .. } = self;
-			let CommonInput { origin, value, data, debug_message, .. } = common;
+			let CommonInput { origin, value, data, debug_message, call_tracer, .. } = common;
 			let result = ExecStack::<T, PrefabWasmModule<T>>::run_instantiate(
 				origin.clone(),
 				executable,
@@ -1135,10 +1206,17 @@ impl<T: Config> Invokable<T> for InstantiateInput<T> {
 				data.clone(),
 				&salt,
 				debug_message,
+				call_tracer,
 			);
-			storage_deposit = storage_meter
-				.into_deposit(&origin)
-				.saturating_add(&StorageDeposit::Charge(extra_deposit));
+			// A deposit that can't be paid fails the instantiation so that its storage changes
+			// are reverted.
+			match storage_meter.try_into_deposit(&origin) {
+				// The code deposit is always paid in `Config::Currency`.
+				Ok(deposit) =>
+					storage_deposit =
+						deposit.saturating_add_native(&StorageDeposit::Charge(extra_deposit)),
+				Err(err) => return result.and(Err(err.into())),
+			}
 			result
 		};
 		InternalOutput { result: try_exec(), gas_meter, storage_deposit }
@@ -1157,6 +1235,10 @@ impl<T: Config> Pallet<T> {
 	/// it adds allocations and could be abused to drive the runtime into an OOM panic.
 	/// If set to `true` it returns additional human readable debugging information.
 	///
+	/// `storage_deposit_limit` and the returned storage deposit are denominated in
+	/// [`Config::DepositAsset`]. What is paid in [`Config::Currency`] on top of it is limited by
+	/// the same `storage_deposit_limit`, as far as `origin` can afford it, and returned separately.
+	///
 	/// It returns the execution result and the amount of used weight.
 	pub fn bare_call(
 		origin: T::AccountId,
@@ -1176,17 +1258,119 @@ impl<T: Config> Pallet<T> {
 			gas_limit,
 			storage_deposit_limit,
 			debug_message: debug_message.as_mut(),
+			call_tracer: None,
 		};
 		let output = CallInput::<T> { dest, determinism }.run_guarded(common);
+		let (storage_deposit, native_storage_deposit) = output.storage_deposit.split();
 		ContractExecResult {
 			result: output.result.map_err(|r| r.error),
 			gas_consumed: output.gas_meter.gas_consumed(),
 			gas_required: output.gas_meter.gas_required(),
-			storage_deposit: output.storage_deposit,
+			storage_deposit,
+			native_storage_deposit,
 			debug_message: debug_message.unwrap_or_default().to_vec(),
 		}
 	}
 
+	/// Perform a call to a specified contract and record a trace of every frame it executes.
+	///
+	/// This function is similar to [`Self::bare_call`], but instead of the output of the call it
+	/// returns a tree of [`CallTrace`](pallet_contracts_primitives::CallTrace)s rooted at the
+	/// called contract. The debug buffer is always collected.
+	///
+	/// # Note
+	///
+	/// This function must only ever be called when executing as an RPC because recording the
+	/// trace adds allocations and could be abused to drive the runtime into an OOM panic.
+	pub fn bare_call_traced(
+		origin: T::AccountId,
+		dest: T::AccountId,
+		value: BalanceOf<T>,
+		gas_limit: Weight,
+		storage_deposit_limit: Option<BalanceOf<T>>,
+		data: Vec<u8>,
+		determinism: Determinism,
+	) -> ContractTraceResult<T::AccountId, BalanceOf<T>, CodeHash<T>> {
+		let mut debug_message = DebugBufferVec::<T>::default();
+		let mut call_tracer = CallTracer::<T>::default();
+		let common = CommonInput {
+			origin,
+			value,
+			data,
+			gas_limit,
+			storage_deposit_limit,
+			debug_message: Some(&mut debug_message),
+			call_tracer: Some(&mut call_tracer),
+		};
+		let output = CallInput::<T> { dest, determinism }.run_guarded(common);
+		Self::trace_result(output, call_tracer, debug_message)
+	}
+
+	/// Instantiate a new contract and record a trace of every frame it executes.
+	///
+	/// This function is similar to [`Self::bare_instantiate`], but instead of the output of the
+	/// instantiation it returns a tree of [`CallTrace`](pallet_contracts_primitives::CallTrace)s
+	/// rooted at the new contract. The debug buffer is always collected.
+	///
+	/// # Note
+	///
+	/// This function must only ever be called when executing as an RPC because recording the
+	/// trace adds allocations and could be abused to drive the runtime into an OOM panic.
+	pub fn bare_instantiate_traced(
+		origin: T::AccountId,
+		value: BalanceOf<T>,
+		gas_limit: Weight,
+		storage_deposit_limit: Option<BalanceOf<T>>,
+		code: Code<CodeHash<T>>,
+		data: Vec<u8>,
+		salt: Vec<u8>,
+	) -> ContractTraceResult<T::AccountId, BalanceOf<T>, CodeHash<T>> {
+		let mut debug_message = DebugBufferVec::<T>::default();
+		let mut call_tracer = CallTracer::<T>::default();
+		let common = CommonInput {
+			origin,
+			value,
+			data,
+			gas_limit,
+			storage_deposit_limit,
+			debug_message: Some(&mut debug_message),
+			call_tracer: Some(&mut call_tracer),
+		};
+		let output = InstantiateInput::<T> { code, salt }.run_guarded(common);
+		Self::trace_result(output, call_tracer, debug_message)
+	}
+
+	/// Build the result of a traced execution from its output and the frames it recorded.
+	fn trace_result<O>(
+		output: InternalOutput<T, O>,
+		call_tracer: CallTracer<T>,
+		debug_message: DebugBufferVec<T>,
+	) -> ContractTraceResult<T::AccountId, BalanceOf<T>, CodeHash<T>> {
+		let result = match (call_tracer.into_trace(), output.result) {
+			(Some(trace), Ok(_)) => Ok(trace),
+			// The frames completed but the execution failed afterwards, e.g. because the storage
+			// deposit could not be settled. All of it was reverted.
+			(Some(mut trace), Err(err)) => {
+				if trace.result.is_ok() {
+					trace.result = Err(err.error);
+				}
+				Ok(trace)
+			},
+			(None, Err(err)) => Err(err.error),
+			// Every execution that produced an output entered its first frame.
+			(None, Ok(_)) => Err(DispatchError::Other("no frame was traced")),
+		};
+		let (storage_deposit, native_storage_deposit) = output.storage_deposit.split();
+		ContractTraceResult {
+			result,
+			gas_consumed: output.gas_meter.gas_consumed(),
+			gas_required: output.gas_meter.gas_required(),
+			storage_deposit,
+			native_storage_deposit,
+			debug_message: debug_message.to_vec(),
+		}
+	}
+
 	/// Instantiate a new contract.
 	///
 	/// This function is similar to [`Self::instantiate`], but doesn't perform any address lookups
@@ -1217,8 +1401,10 @@ impl<T: Config> Pallet<T> {
 			gas_limit,
 			storage_deposit_limit,
 			debug_message: debug_message.as_mut(),
+			call_tracer: None,
 		};
 		let output = InstantiateInput::<T> { code, salt }.run_guarded(common);
+		let (storage_deposit, native_storage_deposit) = output.storage_deposit.split();
 		ContractInstantiateResult {
 			result: output
 				.result
@@ -1226,7 +1412,8 @@ impl<T: Config> Pallet<T> {
 				.map_err(|e| e.error),
 			gas_consumed: output.gas_meter.gas_consumed(),
 			gas_required: output.gas_meter.gas_required(),
-			storage_deposit: output.storage_deposit,
+			storage_deposit,
+			native_storage_deposit,
 			debug_message: debug_message.unwrap_or_default().to_vec(),
 		}
 	}
@@ -1332,7 +1519,7 @@ impl<T: Config> Pallet<T> {
 
 sp_api::decl_runtime_apis! {
 	/// The API used to dry-run contract interactions.
-	#[api_version(2)]
+	#[api_version(4)]
 	pub trait ContractsApi<AccountId, Balance, BlockNumber, Hash> where
 		AccountId: Codec,
 		Balance: Codec,
@@ -1351,6 +1538,19 @@ sp_api::decl_runtime_apis! {
 			input_data: Vec<u8>,
 		) -> ContractExecResult<Balance>;
 
+		/// Perform a call from a specified account to a given contract.
+		///
+		/// The result lacks the native storage deposit.
+		#[changed_in(4)]
+		fn call(
+			origin: AccountId,
+			dest: AccountId,
+			value: Balance,
+			gas_limit: Option<Weight>,
+			storage_deposit_limit: Option<Balance>,
+			input_data: Vec<u8>,
+		) -> ContractExecResultBeforeV4<Balance>;
+
 		/// Instantiate a new contract.
 		///
 		/// See `[crate::Pallet::bare_instantiate]`.
@@ -1364,6 +1564,19 @@ sp_api::decl_runtime_apis! {
 			salt: Vec<u8>,
 		) -> ContractInstantiateResult<AccountId, Balance>;
 
+		/// Instantiate a new contract.
+		///
+		/// The result lacks the native storage deposit.
+		#[changed_in(4)]
+		fn instantiate(
+			origin: AccountId,
+			value: Balance,
+			gas_limit: Option<Weight>,
+			storage_deposit_limit: Option<Balance>,
+			code: Code<Hash>,
+			data: Vec<u8>,
+			salt: Vec<u8>,
+		) -> ContractInstantiateResultBeforeV4<AccountId, Balance>;
 
 		/// Upload new code without instantiating a contract from it.
 		///
@@ -1384,5 +1597,47 @@ sp_api::decl_runtime_apis! {
 			address: AccountId,
 			key: Vec<u8>,
 		) -> GetStorageResult;
+
+		/// Perform a call from a specified account to a given contract and return a trace of
+		/// every frame it executed.
+		///
+		/// See [`crate::Pallet::bare_call_traced`].
+		#[api_version(4)]
+		fn trace_call(
+			origin: AccountId,
+			dest: AccountId,
+			value: Balance,
+			gas_limit: Option<Weight>,
+			storage_deposit_limit: Option<Balance>,
+			input_data: Vec<u8>,
+		) -> ContractTraceResult<AccountId, Balance, Hash>;
+
+		/// Perform a call from a specified account to a given contract and return a trace of
+		/// every frame it executed.
+		///
+		/// The result lacks the native storage deposit.
+		#[changed_in(4)]
+		fn trace_call(
+			origin: AccountId,
+			dest: AccountId,
+			value: Balance,
+			gas_limit: Option<Weight>,
+			storage_deposit_limit: Option<Balance>,
+			input_data: Vec<u8>,
+		) -> ContractTraceResultBeforeV4<AccountId, Balance, Hash>;
+
+		/// Instantiate a new contract and return a trace of every frame it executed.
+		///
+		/// See [`crate::Pallet::bare_instantiate_traced`].
+		#[api_version(4)]
+		fn trace_instantiate(
+			origin: AccountId,
+			value: Balance,
+			gas_limit: Option<Weight>,
+			storage_deposit_limit: Option<Balance>,
+			code: Code<Hash>,
+			data: Vec<u8>,
+			salt: Vec<u8>,
+		) -> ContractTraceResult<AccountId, Balance, Hash>;
 	}
 }
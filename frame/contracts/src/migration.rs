@@ -59,7 +59,11 @@ impl<T: Config> OnRuntimeUpgrade for Migration<T> {
 			v9::migrate::<T>(&mut weight);
 		}
 
-		StorageVersion::new(9).put::<Pallet<T>>();
+		if version < 10 {
+			v10::migrate::<T>(&mut weight);
+		}
+
+		StorageVersion::new(10).put::<Pallet<T>>();
 		weight.saturating_accrue(T::DbWeight::get().writes(1));
 
 		weight
@@ -400,6 +404,58 @@ mod v9 {
 	}
 }
 
+/// Record in `ContractInfo` the currency each deposit account holds.
+///
+/// All existing deposits were charged in `Config::Currency` and stay there.
+mod v10 {
+	use super::*;
+	use crate::{exec::AccountIdOf, storage::DepositAccount};
+
+	#[derive(Encode, Decode)]
+	struct OldContractInfo<T: Config> {
+		trie_id: TrieId,
+		deposit_account: AccountIdOf<T>,
+		code_hash: CodeHash<T>,
+		storage_bytes: u32,
+		storage_items: u32,
+		storage_byte_deposit: BalanceOf<T>,
+		storage_item_deposit: BalanceOf<T>,
+		storage_base_deposit: BalanceOf<T>,
+	}
+
+	#[derive(Encode, Decode)]
+	pub struct ContractInfo<T: Config> {
+		pub trie_id: TrieId,
+		pub deposit_account: DepositAccount<T>,
+		pub code_hash: CodeHash<T>,
+		pub storage_bytes: u32,
+		pub storage_items: u32,
+		pub storage_byte_deposit: BalanceOf<T>,
+		pub storage_item_deposit: BalanceOf<T>,
+		pub storage_base_deposit: BalanceOf<T>,
+	}
+
+	#[storage_alias]
+	type ContractInfoOf<T: Config, V> =
+		StorageMap<Pallet<T>, Twox64Concat, <T as frame_system::Config>::AccountId, V>;
+
+	pub fn migrate<T: Config>(weight: &mut Weight) {
+		<ContractInfoOf<T, ContractInfo<T>>>::translate_values(|old: OldContractInfo<T>| {
+			weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+			Some(ContractInfo {
+				trie_id: old.trie_id,
+				deposit_account: DepositAccount::native(old.deposit_account),
+				code_hash: old.code_hash,
+				storage_bytes: old.storage_bytes,
+				storage_items: old.storage_items,
+				storage_byte_deposit: old.storage_byte_deposit,
+				storage_item_deposit: old.storage_item_deposit,
+				storage_base_deposit: old.storage_base_deposit,
+			})
+		});
+	}
+}
+
 // Post checks always need to be run against the latest storage version. This is why we
 // do not scope them in the per version modules. They always need to be ported to the latest
 // version.
@@ -431,6 +487,20 @@ mod post_checks {
 			v9::<T>()?;
 		}
 
+		if old_version < 10 {
+			v10::<T>()?;
+		}
+
+		Ok(())
+	}
+
+	fn v10<T: Config>() -> Result<(), &'static str> {
+		for value in ContractInfoOf::<T, v10::ContractInfo<T>>::iter_values() {
+			ensure!(
+				value.deposit_account.asset().is_none(),
+				"All pre-existing deposits need to be held in the native currency."
+			);
+		}
 		Ok(())
 	}
 
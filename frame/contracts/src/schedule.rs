@@ -428,6 +428,21 @@ pub struct HostFnWeights<T: Config> {
 	/// Weight of calling `instantiation_nonce`.
 	pub instantiation_nonce: Weight,
 
+	/// Weight of calling `asset_balance`.
+	pub asset_balance: Weight,
+
+	/// Weight of calling `asset_transfer`.
+	pub asset_transfer: Weight,
+
+	/// Weight of calling `asset_allowance`.
+	pub asset_allowance: Weight,
+
+	/// Weight of calling `asset_transfer_approved`.
+	pub asset_transfer_approved: Weight,
+
+	/// Weight of calling `asset_metadata`.
+	pub asset_metadata: Weight,
+
 	/// The type parameter is used in the default implementation.
 	#[codec(skip)]
 	pub _phantom: PhantomData<T>,
@@ -680,6 +695,11 @@ impl<T: Config> Default for HostFnWeights<T> {
 			reentrance_count: cost_batched!(seal_reentrance_count),
 			account_reentrance_count: cost_batched!(seal_account_reentrance_count),
 			instantiation_nonce: cost_batched!(seal_instantiation_nonce),
+			asset_balance: cost_batched!(seal_asset_balance),
+			asset_transfer: cost_batched!(seal_asset_transfer),
+			asset_allowance: cost_batched!(seal_asset_allowance),
+			asset_transfer_approved: cost_batched!(seal_asset_transfer_approved),
+			asset_metadata: cost_batched!(seal_asset_metadata),
 			_phantom: PhantomData,
 		}
 	}
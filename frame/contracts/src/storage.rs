@@ -22,13 +22,14 @@ pub mod meter;
 use crate::{
 	exec::{AccountIdOf, StorageKey},
 	weights::WeightInfo,
-	AddressGenerator, BalanceOf, CodeHash, Config, ContractInfoOf, DeletionQueue, Error, Pallet,
-	TrieId, SENTINEL,
+	AddressGenerator, AssetIdOf, BalanceOf, CodeHash, Config, ContractInfoOf, DeletionQueue, Error,
+	Pallet, TrieId, SENTINEL,
 };
 use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::{
 	dispatch::{DispatchError, DispatchResult},
 	storage::child::{self, ChildInfo},
+	traits::Get,
 	weights::Weight,
 	RuntimeDebugNoBound,
 };
@@ -47,7 +48,7 @@ use sp_std::{ops::Deref, prelude::*};
 pub struct ContractInfo<T: Config> {
 	/// Unique ID for the subtree encoded as a bytes vector.
 	pub trie_id: TrieId,
-	/// The account that holds this contracts storage deposit.
+	/// The account that holds this contracts storage deposit and the currency it is held in.
 	///
 	/// This is held in a separate account to prevent the contract from spending it.
 	deposit_account: DepositAccount<T>,
@@ -90,7 +91,8 @@ impl<T: Config> ContractInfo<T> {
 				.expect("Runtime uses a reasonable hash size. Hence sizeof(T::Hash) <= 128; qed")
 		};
 
-		let deposit_account = DepositAccount(T::AddressGenerator::deposit_address(account));
+		let deposit_account =
+			DepositAccount(T::AddressGenerator::deposit_address(account), T::DepositAsset::get());
 
 		let contract = Self {
 			trie_id,
@@ -117,10 +119,15 @@ impl<T: Config> ContractInfo<T> {
 	}
 
 	/// Same as [`Self::extra_deposit`] but including the base deposit.
+	///
+	/// The existential deposit of the contract account is only part of the base deposit when the
+	/// deposit is held in [`Config::Currency`].
 	pub fn total_deposit(&self) -> BalanceOf<T> {
-		self.extra_deposit()
-			.saturating_add(self.storage_base_deposit)
-			.saturating_sub(Pallet::<T>::min_balance())
+		let total = self.extra_deposit().saturating_add(self.storage_base_deposit);
+		match self.deposit_account.asset() {
+			None => total.saturating_sub(Pallet::<T>::min_balance()),
+			Some(_) => total,
+		}
 	}
 
 	/// Return the account that storage deposits should be deposited into.
@@ -285,7 +292,7 @@ impl<T: Config> ContractInfo<T> {
 	/// Fill up the queue in order to exercise the limits during testing.
 	#[cfg(test)]
 	pub fn fill_queue_with_dummies() {
-		use frame_support::{traits::Get, BoundedVec};
+		use frame_support::BoundedVec;
 		let queue: Vec<DeletedContract> = (0..T::DeletionQueueDepth::get())
 			.map(|_| DeletedContract { trie_id: TrieId::default() })
 			.collect();
@@ -341,9 +348,26 @@ impl WriteOutcome {
 	}
 }
 
+/// The account holding the storage deposit of a contract.
+///
+/// The second field is the asset the deposit is held in or `None` if it is held in
+/// [`Config::Currency`]. It is fixed when the contract is instantiated so that changing
+/// [`Config::DepositAsset`] does not affect the deposits of existing contracts.
 #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebugNoBound, TypeInfo, MaxEncodedLen)]
 #[scale_info(skip_type_params(T))]
-pub struct DepositAccount<T: Config>(AccountIdOf<T>);
+pub struct DepositAccount<T: Config>(AccountIdOf<T>, Option<AssetIdOf<T>>);
+
+impl<T: Config> DepositAccount<T> {
+	/// A deposit account that holds its deposit in [`Config::Currency`].
+	pub(crate) fn native(account: AccountIdOf<T>) -> Self {
+		Self(account, None)
+	}
+
+	/// The asset the deposit is held in or `None` for [`Config::Currency`].
+	pub fn asset(&self) -> Option<AssetIdOf<T>> {
+		self.1
+	}
+}
 
 impl<T: Config> Deref for DepositAccount<T> {
 	type Target = AccountIdOf<T>;
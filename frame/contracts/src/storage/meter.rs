@@ -19,17 +19,26 @@
 
 use crate::{
 	storage::{ContractInfo, DepositAccount},
-	BalanceOf, Config, Error, Inspect, Pallet, System,
+	AssetIdOf, BalanceOf, Config, Error, Inspect, Pallet, System,
 };
 use codec::Encode;
 use frame_support::{
 	dispatch::DispatchError,
 	ensure,
-	traits::{tokens::WithdrawConsequence, Currency, ExistenceRequirement, Get},
+	traits::{
+		tokens::{
+			fungibles::{Inspect as FungiblesInspect, Transfer as FungiblesTransfer},
+			BalanceConversion, WithdrawConsequence,
+		},
+		Currency, ExistenceRequirement, Get,
+	},
 	DefaultNoBound, RuntimeDebugNoBound,
 };
 use pallet_contracts_primitives::StorageDeposit as Deposit;
-use sp_runtime::{traits::Saturating, FixedPointNumber, FixedU128};
+use sp_runtime::{
+	traits::{Bounded, Saturating, Zero},
+	FixedPointNumber, FixedU128,
+};
 use sp_std::{marker::PhantomData, vec::Vec};
 
 /// Deposit that uses the native currency's balance type.
@@ -46,45 +55,137 @@ pub type NestedMeter<T> = RawMeter<T, ReservingExt, Nested>;
 /// This can be used where we want to be generic over the state (Root vs. Nested).
 pub type GenericMeter<T, S> = RawMeter<T, ReservingExt, S>;
 
+/// The storage deposit limits of a call stack.
+///
+/// Deposits held in [`Config::Currency`] and deposits held in an asset are not denominated in
+/// the same unit. Hence each of them is limited on its own.
+#[derive(Clone, DefaultNoBound, RuntimeDebugNoBound, PartialEq, Eq)]
+pub struct Limits<T: Config> {
+	/// How much balance can be charged in [`Config::Currency`].
+	pub native: BalanceOf<T>,
+	/// How much balance can be charged in an asset.
+	pub asset: BalanceOf<T>,
+}
+
+/// Storage deposits split by the currency they are paid in.
+#[derive(Clone, DefaultNoBound, RuntimeDebugNoBound, PartialEq, Eq)]
+pub struct Deposits<T: Config> {
+	/// The deposit paid in [`Config::Currency`].
+	pub native: DepositOf<T>,
+	/// The deposit paid in an asset.
+	pub asset: DepositOf<T>,
+}
+
+impl<T: Config> Deposits<T> {
+	/// `deposit` paid in `asset` or in [`Config::Currency`] if `None`.
+	pub fn held_in(asset: Option<AssetIdOf<T>>, deposit: DepositOf<T>) -> Self {
+		match asset {
+			None => Self { native: deposit, asset: Default::default() },
+			Some(_) => Self { native: Default::default(), asset: deposit },
+		}
+	}
+
+	/// The part of the deposits paid in `asset` or in [`Config::Currency`] if `None`.
+	pub fn get(&self, asset: Option<AssetIdOf<T>>) -> &DepositOf<T> {
+		match asset {
+			None => &self.native,
+			Some(_) => &self.asset,
+		}
+	}
+
+	/// Splits the deposits into the part denominated in [`Config::DepositAsset`] and the part
+	/// that is paid in [`Config::Currency`] on top of it.
+	///
+	/// The second part is always zero if no deposit asset is configured.
+	pub fn split(self) -> (DepositOf<T>, DepositOf<T>) {
+		match T::DepositAsset::get() {
+			None => (self.native, Default::default()),
+			Some(_) => (self.asset, self.native),
+		}
+	}
+
+	/// Adds `deposit` paid in [`Config::Currency`].
+	pub fn saturating_add_native(&self, deposit: &DepositOf<T>) -> Self {
+		Self { native: self.native.saturating_add(deposit), asset: self.asset.clone() }
+	}
+
+	fn saturating_add(&self, rhs: &Self) -> Self {
+		Self {
+			native: self.native.saturating_add(&rhs.native),
+			asset: self.asset.saturating_add(&rhs.asset),
+		}
+	}
+
+	fn is_zero(&self) -> bool {
+		self.native.is_zero() && self.asset.is_zero()
+	}
+
+	/// What is left of `limits` after accounting for these deposits.
+	fn available(&self, limits: &Limits<T>) -> Limits<T> {
+		Limits {
+			native: self.native.available(&limits.native),
+			asset: self.asset.available(&limits.asset),
+		}
+	}
+
+	/// True if any of the deposits charges more than its limit.
+	fn exceeds(&self, limits: &Limits<T>) -> bool {
+		self.native.charge_or_zero() > limits.native || self.asset.charge_or_zero() > limits.asset
+	}
+}
+
 /// A trait that allows to decouple the metering from the charging of balance.
 ///
 /// This mostly exists for testing so that the charging can be mocked.
 pub trait Ext<T: Config> {
 	/// This checks whether `origin` is able to afford the storage deposit limit.
 	///
-	/// It is necessary to do this check beforehand so that the charge won't fail later on.
+	/// The limit is denominated in [`Config::DepositAsset`]. The limit for the other currency
+	/// is whatever `origin` can afford in it.
 	///
 	/// `origin`: The origin of the call stack from which is responsible for putting down a deposit.
 	/// `limit`: The limit with which the meter was constructed.
 	/// `min_leftover`: How much `free_balance` in addition to the existential deposit (ed) should
 	/// be left inside the `origin` account.
 	///
-	/// Returns the limit that should be used by the meter. If origin can't afford the `limit`
+	/// Returns the limits that should be used by the meter. If origin can't afford the `limit`
 	/// it returns `Err`.
 	fn check_limit(
 		origin: &T::AccountId,
 		limit: Option<BalanceOf<T>>,
 		min_leftover: BalanceOf<T>,
-	) -> Result<BalanceOf<T>, DispatchError>;
+	) -> Result<Limits<T>, DispatchError>;
+	/// This checks whether `origin` can afford to pay `amount` in `asset` or in
+	/// [`Config::Currency`] if `None`.
+	///
+	/// It is called for every currency before any deposit is charged so that the charges don't
+	/// fail half way through.
+	fn check_funds(
+		origin: &T::AccountId,
+		asset: Option<AssetIdOf<T>>,
+		amount: BalanceOf<T>,
+	) -> Result<(), DispatchError>;
 	/// This is called to inform the implementer that some balance should be charged due to
 	/// some interaction of the `origin` with a `contract`.
 	///
 	/// The balance transfer can either flow from `origin` to `deposit_account` or the other way
-	/// around depending on whether `amount` constitutes a `Charge` or a `Refund`.
-	/// It is guaranteed that this succeeds because no more balance than returned by
-	/// `check_limit` is ever charged. This is why this function is infallible.
+	/// around depending on whether `amount` constitutes a `Charge` or a `Refund`. It is made in
+	/// the currency the `deposit_account` holds.
 	/// `terminated` designates whether the `contract` was terminated.
+	///
+	/// A failed transfer is returned as an error which reverts the whole call stack.
 	fn charge(
 		origin: &T::AccountId,
 		deposit_account: &DepositAccount<T>,
 		amount: &DepositOf<T>,
 		terminated: bool,
-	);
+	) -> Result<(), DispatchError>;
 }
 
 /// This [`Ext`] is used for actual on-chain execution when balance needs to be charged.
 ///
-/// It uses [`ReservableCurrency`] in order to do accomplish the reserves.
+/// It transfers the deposit to the contract's deposit account either in [`Config::Currency`] or
+/// in the asset the deposit account holds.
 pub enum ReservingExt {}
 
 /// Used to implement a type state pattern for the meter.
@@ -104,10 +205,10 @@ impl State for Nested {}
 /// A type that allows the metering of consumed or freed storage of a single contract call stack.
 #[derive(DefaultNoBound, RuntimeDebugNoBound)]
 pub struct RawMeter<T: Config, E, S: State> {
-	/// The limit of how much balance this meter is allowed to consume.
-	limit: BalanceOf<T>,
+	/// The limits of how much balance this meter is allowed to consume.
+	limit: Limits<T>,
 	/// The amount of balance that was used in this meter and all of its already absorbed children.
-	total_deposit: DepositOf<T>,
+	total_deposit: Deposits<T>,
 	/// The amount of storage changes that were recorded in this meter alone.
 	own_contribution: Contribution<T>,
 	/// List of charges that should be applied at the end of a contract stack execution.
@@ -141,9 +242,15 @@ impl Diff {
 	/// In case `None` is passed for `info` only charges are calculated. This is because refunds
 	/// are calculated pro rata of the existing storage within a contract and hence need extract
 	/// this information from the passed `info`.
+	///
+	/// The deposit is priced in the currency of the contract's deposit account. Without `info` it
+	/// is priced in [`Config::DepositAsset`].
 	pub fn update_contract<T: Config>(&self, info: Option<&mut ContractInfo<T>>) -> DepositOf<T> {
-		let per_byte = T::DepositPerByte::get();
-		let per_item = T::DepositPerItem::get();
+		let asset = match &info {
+			Some(info) => info.deposit_account().asset(),
+			None => T::DepositAsset::get(),
+		};
+		let (per_byte, per_item) = storage_prices::<T>(asset);
 		let bytes_added = self.bytes_added.saturating_sub(self.bytes_removed);
 		let items_added = self.items_added.saturating_sub(self.items_removed);
 		let mut bytes_deposit = Deposit::Charge(per_byte.saturating_mul((bytes_added).into()));
@@ -194,6 +301,25 @@ impl Diff {
 	}
 }
 
+/// The deposit per byte and per storage item in `asset` or in [`Config::Currency`] if `None`.
+///
+/// A price that can't be converted into `asset` saturates. This makes any storage that is
+/// paid for with it exceed every storage deposit limit.
+fn storage_prices<T: Config>(asset: Option<AssetIdOf<T>>) -> (BalanceOf<T>, BalanceOf<T>) {
+	let per_byte = T::DepositPerByte::get();
+	let per_item = T::DepositPerItem::get();
+	match asset {
+		None => (per_byte, per_item),
+		Some(asset) => {
+			let convert = |price| {
+				T::DepositConversion::to_asset_balance(price, asset)
+					.unwrap_or_else(|_| BalanceOf::<T>::max_value())
+			};
+			(convert(per_byte), convert(per_item))
+		},
+	}
+}
+
 impl Diff {
 	fn saturating_add(&self, rhs: &Self) -> Self {
 		Self {
@@ -225,17 +351,23 @@ enum Contribution<T: Config> {
 	Alive(Diff),
 	/// The meter was checked against its limit using [`RawMeter::enforce_limit`] at the end of
 	/// its execution. In this process the [`Diff`] was converted into a [`Deposit`].
-	Checked(DepositOf<T>),
+	Checked(Deposits<T>),
 	/// The contract was terminated. In this process the [`Diff`] was converted into a [`Deposit`]
 	/// in order to calculate the refund.
-	Terminated(DepositOf<T>),
+	Terminated(Deposits<T>),
 }
 
 impl<T: Config> Contribution<T> {
 	/// See [`Diff::update_contract`].
-	fn update_contract(&self, info: Option<&mut ContractInfo<T>>) -> DepositOf<T> {
+	fn update_contract(&self, info: Option<&mut ContractInfo<T>>) -> Deposits<T> {
 		match self {
-			Self::Alive(diff) => diff.update_contract::<T>(info),
+			Self::Alive(diff) => {
+				let asset = match &info {
+					Some(info) => info.deposit_account().asset(),
+					None => T::DepositAsset::get(),
+				};
+				Deposits::held_in(asset, diff.update_contract::<T>(info))
+			},
 			Self::Terminated(deposit) | Self::Checked(deposit) => deposit.clone(),
 		}
 	}
@@ -293,15 +425,24 @@ where
 		if !own_deposit.is_zero() {
 			self.charges.extend_from_slice(&absorbed.charges);
 			self.charges.push(Charge {
+				amount: own_deposit.get(deposit_account.asset()).clone(),
 				deposit_account,
-				amount: own_deposit,
 				terminated: absorbed.is_terminated(),
 			});
 		}
 	}
 
+	/// The deposit recorded by this meter so far, including all of its absorbed children.
+	///
+	/// `info` is the contract the meter belongs to. It is needed to price refunds and is
+	/// `None` if the contract was terminated.
+	pub fn consumed(&self, mut info: Option<ContractInfo<T>>) -> Deposits<T> {
+		self.total_deposit
+			.saturating_add(&self.own_contribution.update_contract(info.as_mut()))
+	}
+
 	/// The amount of balance that is still available from the original `limit`.
-	fn available(&self) -> BalanceOf<T> {
+	fn available(&self) -> Limits<T> {
 		self.total_deposit.available(&self.limit)
 	}
 
@@ -338,16 +479,36 @@ where
 	/// that this meter was passed into. This will also perform all the charges accumulated
 	/// in the whole contract stack.
 	///
+	/// Before anything is transferred it is checked for every currency that `origin` can afford
+	/// what is charged in it. Fails if it can't or if any of the transfers fails. The caller is
+	/// expected to revert the execution in this case.
+	///
 	/// This drops the root meter in order to make sure it is only called when the whole
 	/// execution did finish.
-	pub fn into_deposit(self, origin: &T::AccountId) -> DepositOf<T> {
+	pub fn try_into_deposit(self, origin: &T::AccountId) -> Result<Deposits<T>, DispatchError> {
+		// Refunds are paid out before any charge. Hence `origin` only needs to afford what is
+		// left of the charges in a currency after deducting the refunds in that currency.
+		let mut per_currency: Vec<(Option<AssetIdOf<T>>, DepositOf<T>)> = Vec::new();
+		for charge in self.charges.iter() {
+			let asset = charge.deposit_account.asset();
+			match per_currency.iter_mut().find(|(held_in, _)| *held_in == asset) {
+				Some((_, deposit)) => *deposit = deposit.saturating_add(&charge.amount),
+				None => per_currency.push((asset, charge.amount.clone())),
+			}
+		}
+		for (asset, deposit) in per_currency {
+			if let Deposit::Charge(amount) = deposit {
+				E::check_funds(origin, asset, amount)?;
+			}
+		}
+
 		for charge in self.charges.iter().filter(|c| matches!(c.amount, Deposit::Refund(_))) {
-			E::charge(origin, &charge.deposit_account, &charge.amount, charge.terminated);
+			E::charge(origin, &charge.deposit_account, &charge.amount, charge.terminated)?;
 		}
 		for charge in self.charges.iter().filter(|c| matches!(c.amount, Deposit::Charge(_))) {
-			E::charge(origin, &charge.deposit_account, &charge.amount, charge.terminated);
+			E::charge(origin, &charge.deposit_account, &charge.amount, charge.terminated)?;
 		}
-		self.total_deposit
+		Ok(self.total_deposit)
 	}
 }
 
@@ -373,26 +534,54 @@ where
 		origin: &T::AccountId,
 		contract: &T::AccountId,
 		info: &mut ContractInfo<T>,
-	) -> Result<DepositOf<T>, DispatchError> {
+	) -> Result<Deposits<T>, DispatchError> {
 		debug_assert!(self.is_alive());
 
 		let ed = Pallet::<T>::min_balance();
-		let mut deposit =
+		let asset = info.deposit_account().asset();
+		let deposit =
 			Diff { bytes_added: info.encoded_size() as u32, items_added: 1, ..Default::default() }
 				.update_contract::<T>(None);
 
 		// Instantiate needs to transfer at least the minimum balance in order to pull the
 		// deposit account into existence.
-		// We also add another `ed` here which goes to the contract's own account into existence.
-		deposit = deposit.max(Deposit::Charge(ed)).saturating_add(&Deposit::Charge(ed));
-		if deposit.charge_or_zero() > self.limit {
-			return Err(<Error<T>>::StorageDepositLimitExhausted.into())
-		}
+		let (deposit, contract_ed) = match asset {
+			// We also add another `ed` here which goes to the contract's own account into
+			// existence. It is transferred separately below.
+			None => (
+				Deposits::held_in(
+					None,
+					deposit.max(Deposit::Charge(ed)).saturating_add(&Deposit::Charge(ed)),
+				),
+				ed,
+			),
+			// The existential deposits of both accounts are paid in `Config::Currency` and are
+			// hence not part of a deposit held in an asset.
+			Some(asset) => (
+				Deposits {
+					native: Deposit::Charge(ed.saturating_add(ed)),
+					asset: deposit.max(Deposit::Charge(T::Fungibles::minimum_balance(asset))),
+				},
+				Zero::zero(),
+			),
+		};
+		ensure!(!deposit.exceeds(&self.limit), <Error<T>>::StorageDepositLimitExhausted);
 
 		// We do not increase `own_contribution` because this will be charged later when the
 		// contract execution does conclude and hence would lead to a double charge.
 		self.total_deposit = deposit.clone();
-		info.storage_base_deposit = deposit.charge_or_zero();
+		info.storage_base_deposit = deposit.get(asset).charge_or_zero();
+
+		// A deposit account holding an asset still needs to exist in `Config::Currency` so that
+		// we can hold a consumer reference on it.
+		if asset.is_some() {
+			T::Currency::transfer(
+				origin,
+				info.deposit_account(),
+				ed,
+				ExistenceRequirement::KeepAlive,
+			)?;
+		}
 
 		// Usually, deposit charges are deferred to be able to coalesce them with refunds.
 		// However, we need to charge immediately so that the account is created before
@@ -400,9 +589,9 @@ where
 		E::charge(
 			origin,
 			info.deposit_account(),
-			&deposit.saturating_sub(&Deposit::Charge(ed)),
+			&deposit.get(asset).saturating_sub(&Deposit::Charge(contract_ed)),
 			false,
-		);
+		)?;
 		System::<T>::inc_consumers(info.deposit_account())?;
 
 		// We also need to make sure that the contract's account itself exists.
@@ -418,7 +607,10 @@ where
 	/// `contract_info` will be refunded to the `origin` of the meter.
 	pub fn terminate(&mut self, info: &ContractInfo<T>) {
 		debug_assert!(self.is_alive());
-		self.own_contribution = Contribution::Terminated(Deposit::Refund(info.total_deposit()));
+		self.own_contribution = Contribution::Terminated(Deposits::held_in(
+			info.deposit_account().asset(),
+			Deposit::Refund(info.total_deposit()),
+		));
 	}
 
 	/// [`Self::charge`] does not enforce the storage limit since we want to do this check as late
@@ -438,11 +630,7 @@ where
 		if self.is_alive() {
 			self.own_contribution = Contribution::Checked(deposit);
 		}
-		if let Deposit::Charge(amount) = total_deposit {
-			if amount > self.limit {
-				return Err(<Error<T>>::StorageDepositLimitExhausted.into())
-			}
-		}
+		ensure!(!total_deposit.exceeds(&self.limit), <Error<T>>::StorageDepositLimitExhausted);
 		Ok(())
 	}
 }
@@ -452,20 +640,59 @@ impl<T: Config> Ext<T> for ReservingExt {
 		origin: &T::AccountId,
 		limit: Option<BalanceOf<T>>,
 		min_leftover: BalanceOf<T>,
-	) -> Result<BalanceOf<T>, DispatchError> {
+	) -> Result<Limits<T>, DispatchError> {
 		// We are sending the `min_leftover` and the `min_balance` from the origin
 		// account as part of a contract call. Hence origin needs to have those left over
 		// as free balance after accounting for all deposits.
-		let max = T::Currency::reducible_balance(origin, true)
+		let native_max = T::Currency::reducible_balance(origin, true)
 			.saturating_sub(min_leftover)
 			.saturating_sub(Pallet::<T>::min_balance());
-		let limit = limit.unwrap_or(max);
-		ensure!(
-			limit <= max &&
-				matches!(T::Currency::can_withdraw(origin, limit), WithdrawConsequence::Success),
-			<Error<T>>::StorageDepositNotEnoughFunds,
-		);
-		Ok(limit)
+		match T::DepositAsset::get() {
+			None => {
+				let limit = limit.unwrap_or(native_max);
+				ensure!(
+					limit <= native_max &&
+						matches!(
+							T::Currency::can_withdraw(origin, limit),
+							WithdrawConsequence::Success
+						),
+					<Error<T>>::StorageDepositNotEnoughFunds,
+				);
+				// Deposits held in an asset can only be refunded while no deposit asset is
+				// configured.
+				Ok(Limits { native: limit, asset: Zero::zero() })
+			},
+			Some(asset) => {
+				// The existential deposits and the deposits of contracts that hold theirs in
+				// `Config::Currency` are limited by the same amount, as far as origin can afford
+				// it.
+				let native = limit.map_or(native_max, |limit| limit.min(native_max));
+				let max = T::Fungibles::reducible_balance(asset, origin, true);
+				let limit = limit.unwrap_or(max);
+				ensure!(
+					limit <= max &&
+						matches!(
+							T::Fungibles::can_withdraw(asset, origin, limit),
+							WithdrawConsequence::Success
+						),
+					<Error<T>>::StorageDepositNotEnoughFunds,
+				);
+				Ok(Limits { native, asset: limit })
+			},
+		}
+	}
+
+	fn check_funds(
+		origin: &T::AccountId,
+		asset: Option<AssetIdOf<T>>,
+		amount: BalanceOf<T>,
+	) -> Result<(), DispatchError> {
+		let reducible = match asset {
+			None => T::Currency::reducible_balance(origin, true),
+			Some(asset) => T::Fungibles::reducible_balance(asset, origin, true),
+		};
+		ensure!(reducible >= amount, <Error<T>>::StorageDepositNotEnoughFunds);
+		Ok(())
 	}
 
 	fn charge(
@@ -473,40 +700,24 @@ impl<T: Config> Ext<T> for ReservingExt {
 		deposit_account: &DepositAccount<T>,
 		amount: &DepositOf<T>,
 		terminated: bool,
-	) {
-		// There is nothing we can do when this fails as this constitutes a bug in the runtime.
-		// We need to settle for emitting an error log in this case.
-		//
-		// # Note
-		//
-		// This is infallible because it is called in a part of the execution where we cannot
-		// simply roll back. It might make sense to do some refactoring to move the deposit
-		// collection to the fallible part of execution.
+	) -> Result<(), DispatchError> {
 		match amount {
-			Deposit::Charge(amount) => {
-				// This will never fail because a deposit account is required to exist
-				// at all times. The pallet enforces this invariant by holding a consumer reference
-				// on the deposit account as long as the contract exists.
-				//
-				// The sender always has enough balance because we checked that it had enough
-				// balance when instantiating the storage meter. There is no way for the sender
-				// which is a plain account to send away this balance in the meantime.
-				let result = T::Currency::transfer(
+			// A deposit account is required to exist at all times. The pallet enforces this
+			// invariant by holding a consumer reference on the deposit account as long as the
+			// contract exists.
+			//
+			// The sender was checked to afford the charge by `check_funds` before any
+			// transfer was made.
+			Deposit::Charge(amount) => match deposit_account.asset() {
+				None => T::Currency::transfer(
 					origin,
 					deposit_account,
 					*amount,
 					ExistenceRequirement::KeepAlive,
-				);
-				if let Err(err) = result {
-					log::error!(
-						target: "runtime::contracts",
-						"Failed to transfer storage deposit {:?} from origin {:?} to deposit account {:?}: {:?}",
-						amount, origin, deposit_account, err,
-					);
-					if cfg!(debug_assertions) {
-						panic!("Unable to collect storage deposit. This is a bug.");
-					}
-				}
+				),
+				Some(asset) =>
+					T::Fungibles::transfer(asset, origin, deposit_account, *amount, true)
+						.map(|_| ()),
 			},
 			// The receiver always exists because the initial value transfer from the
 			// origin to the contract has a keep alive existence requirement. When taking a deposit
@@ -519,25 +730,34 @@ impl<T: Config> Ext<T> for ReservingExt {
 				if terminated {
 					System::<T>::dec_consumers(&deposit_account);
 				}
-				let result = T::Currency::transfer(
-					deposit_account,
-					origin,
-					*amount,
-					// We can safely use `AllowDeath` because our own consumer prevents an removal.
-					ExistenceRequirement::AllowDeath,
-				);
-				if matches!(result, Err(_)) {
-					log::error!(
-						target: "runtime::contracts",
-						"Failed to refund storage deposit {:?} from deposit account {:?} to origin {:?}: {:?}",
-						amount, deposit_account, origin, result,
-					);
-					if cfg!(debug_assertions) {
-						panic!("Unable to refund storage deposit. This is a bug.");
-					}
+				match deposit_account.asset() {
+					None => T::Currency::transfer(
+						deposit_account,
+						origin,
+						*amount,
+						// We can safely use `AllowDeath` because our own consumer prevents an
+						// removal.
+						ExistenceRequirement::AllowDeath,
+					),
+					// The base deposit keeps the asset account alive until the contract is
+					// terminated. Only then we also hand back the existential deposit that pulled
+					// the deposit account into existence.
+					Some(asset) =>
+						T::Fungibles::transfer(asset, deposit_account, origin, *amount, !terminated)
+							.and_then(|_| {
+								if !terminated {
+									return Ok(())
+								}
+								T::Currency::transfer(
+									deposit_account,
+									origin,
+									T::Currency::free_balance(deposit_account),
+									ExistenceRequirement::AllowDeath,
+								)
+							}),
 				}
 			},
-		};
+		}
 	}
 }
 
@@ -596,13 +816,21 @@ mod tests {
 			origin: &AccountIdOf<Test>,
 			limit: Option<BalanceOf<Test>>,
 			min_leftover: BalanceOf<Test>,
-		) -> Result<BalanceOf<Test>, DispatchError> {
+		) -> Result<Limits<Test>, DispatchError> {
 			let limit = limit.unwrap_or(42);
 			TestExtTestValue::mutate(|ext| {
 				ext.limit_checks
 					.push(LimitCheck { origin: origin.clone(), limit, min_leftover })
 			});
-			Ok(limit)
+			Ok(Limits { native: limit, asset: limit })
+		}
+
+		fn check_funds(
+			_origin: &AccountIdOf<Test>,
+			_asset: Option<AssetIdOf<Test>>,
+			_amount: BalanceOf<Test>,
+		) -> Result<(), DispatchError> {
+			Ok(())
 		}
 
 		fn charge(
@@ -610,7 +838,7 @@ mod tests {
 			contract: &DepositAccount<Test>,
 			amount: &DepositOf<Test>,
 			terminated: bool,
-		) {
+		) -> Result<(), DispatchError> {
 			TestExtTestValue::mutate(|ext| {
 				ext.charges.push(Charge {
 					origin: origin.clone(),
@@ -619,6 +847,7 @@ mod tests {
 					terminated,
 				})
 			});
+			Ok(())
 		}
 	}
 
@@ -637,7 +866,7 @@ mod tests {
 	fn new_info(info: StorageInfo) -> ContractInfo<Test> {
 		ContractInfo::<Test> {
 			trie_id: Default::default(),
-			deposit_account: DepositAccount([0u8; 32].into()),
+			deposit_account: DepositAccount([0u8; 32].into(), None),
 			code_hash: Default::default(),
 			storage_bytes: info.bytes,
 			storage_items: info.items,
@@ -667,12 +896,12 @@ mod tests {
 		clear_ext();
 
 		let mut meter = TestMeter::new(&ALICE, Some(1_000), 0).unwrap();
-		assert_eq!(meter.available(), 1_000);
+		assert_eq!(meter.available().native, 1_000);
 
 		// an empty charge does not create a `Charge` entry
 		let mut nested0 = meter.nested();
 		nested0.charge(&Default::default());
-		meter.absorb(nested0, DepositAccount(BOB), None);
+		meter.absorb(nested0, DepositAccount(BOB, None), None);
 
 		assert_eq!(
 			TestExtTestValue::get(),
@@ -688,7 +917,7 @@ mod tests {
 		clear_ext();
 
 		let mut meter = TestMeter::new(&ALICE, Some(100), 0).unwrap();
-		assert_eq!(meter.available(), 100);
+		assert_eq!(meter.available().native, 100);
 
 		let mut nested0_info =
 			new_info(StorageInfo { bytes: 100, items: 5, bytes_deposit: 100, items_deposit: 10 });
@@ -705,18 +934,18 @@ mod tests {
 			new_info(StorageInfo { bytes: 100, items: 10, bytes_deposit: 100, items_deposit: 20 });
 		let mut nested1 = nested0.nested();
 		nested1.charge(&Diff { items_removed: 5, ..Default::default() });
-		nested0.absorb(nested1, DepositAccount(CHARLIE), Some(&mut nested1_info));
+		nested0.absorb(nested1, DepositAccount(CHARLIE, None), Some(&mut nested1_info));
 
 		let mut nested2_info =
 			new_info(StorageInfo { bytes: 100, items: 7, bytes_deposit: 100, items_deposit: 20 });
 		let mut nested2 = nested0.nested();
 		nested2.charge(&Diff { items_removed: 7, ..Default::default() });
-		nested0.absorb(nested2, DepositAccount(CHARLIE), Some(&mut nested2_info));
+		nested0.absorb(nested2, DepositAccount(CHARLIE, None), Some(&mut nested2_info));
 
 		nested0.enforce_limit(Some(&mut nested0_info)).unwrap();
-		meter.absorb(nested0, DepositAccount(BOB), Some(&mut nested0_info));
+		meter.absorb(nested0, DepositAccount(BOB, None), Some(&mut nested0_info));
 
-		meter.into_deposit(&ALICE);
+		meter.try_into_deposit(&ALICE).unwrap();
 
 		assert_eq!(nested0_info.extra_deposit(), 112);
 		assert_eq!(nested1_info.extra_deposit(), 110);
@@ -729,19 +958,19 @@ mod tests {
 				charges: vec![
 					Charge {
 						origin: ALICE,
-						contract: DepositAccount(CHARLIE),
+						contract: DepositAccount(CHARLIE, None),
 						amount: Deposit::Refund(10),
 						terminated: false
 					},
 					Charge {
 						origin: ALICE,
-						contract: DepositAccount(CHARLIE),
+						contract: DepositAccount(CHARLIE, None),
 						amount: Deposit::Refund(20),
 						terminated: false
 					},
 					Charge {
 						origin: ALICE,
-						contract: DepositAccount(BOB),
+						contract: DepositAccount(BOB, None),
 						amount: Deposit::Charge(2),
 						terminated: false
 					}
@@ -755,7 +984,7 @@ mod tests {
 		clear_ext();
 
 		let mut meter = TestMeter::new(&ALICE, Some(1_000), 0).unwrap();
-		assert_eq!(meter.available(), 1_000);
+		assert_eq!(meter.available().native, 1_000);
 
 		let mut nested0 = meter.nested();
 		nested0.charge(&Diff {
@@ -773,10 +1002,10 @@ mod tests {
 		nested1.charge(&Diff { bytes_added: 20, ..Default::default() });
 		nested1.terminate(&nested1_info);
 		nested0.enforce_limit(Some(&mut nested1_info)).unwrap();
-		nested0.absorb(nested1, DepositAccount(CHARLIE), None);
+		nested0.absorb(nested1, DepositAccount(CHARLIE, None), None);
 
-		meter.absorb(nested0, DepositAccount(BOB), None);
-		meter.into_deposit(&ALICE);
+		meter.absorb(nested0, DepositAccount(BOB, None), None);
+		meter.try_into_deposit(&ALICE).unwrap();
 
 		assert_eq!(
 			TestExtTestValue::get(),
@@ -785,13 +1014,13 @@ mod tests {
 				charges: vec![
 					Charge {
 						origin: ALICE,
-						contract: DepositAccount(CHARLIE),
+						contract: DepositAccount(CHARLIE, None),
 						amount: Deposit::Refund(119),
 						terminated: true
 					},
 					Charge {
 						origin: ALICE,
-						contract: DepositAccount(BOB),
+						contract: DepositAccount(BOB, None),
 						amount: Deposit::Charge(12),
 						terminated: false
 					}
@@ -799,4 +1028,37 @@ mod tests {
 			}
 		)
 	}
+
+	#[test]
+	fn limits_are_enforced_per_currency() {
+		clear_ext();
+
+		let mut meter = TestMeter::new(&ALICE, Some(100), 0).unwrap();
+		let mut native_info = new_info(StorageInfo::default());
+		let mut asset_info = new_info(StorageInfo::default());
+		asset_info.deposit_account = DepositAccount(CHARLIE, Some(1));
+
+		// Storage paid in an asset is priced at three times the native price.
+		let mut nested0 = meter.nested();
+		let mut nested1 = nested0.nested();
+		nested1.charge(&Diff { bytes_added: 30, ..Default::default() });
+		nested0.absorb(nested1, DepositAccount(CHARLIE, Some(1)), Some(&mut asset_info));
+		nested0.charge(&Diff { bytes_added: 80, ..Default::default() });
+
+		// Each deposit stays within its own limit although their sum does not.
+		nested0.enforce_limit(Some(&mut native_info)).unwrap();
+		meter.absorb(nested0, DepositAccount(BOB, None), Some(&mut native_info));
+		assert_eq!(
+			meter.try_into_deposit(&ALICE).unwrap(),
+			Deposits { native: Deposit::Charge(80), asset: Deposit::Charge(90) },
+		);
+
+		let meter = TestMeter::new(&ALICE, Some(100), 0).unwrap();
+		let mut nested0 = meter.nested();
+		nested0.charge(&Diff { bytes_added: 101, ..Default::default() });
+		assert_eq!(
+			nested0.enforce_limit(Some(&mut native_info)),
+			Err(<Error<Test>>::StorageDepositLimitExhausted.into()),
+		);
+	}
 }
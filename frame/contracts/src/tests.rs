@@ -36,12 +36,14 @@ use frame_support::{
 	parameter_types,
 	storage::child,
 	traits::{
-		ConstU32, ConstU64, Contains, Currency, ExistenceRequirement, Get, LockableCurrency,
-		OnIdle, OnInitialize, ReservableCurrency, WithdrawReasons,
+		fungibles, tokens::BalanceConversion, AsEnsureOriginWithArg, ConstU32, ConstU64, Contains,
+		Currency, ExistenceRequirement, Get, LockableCurrency, OnIdle, OnInitialize,
+		ReservableCurrency, WithdrawReasons,
 	},
 	weights::{constants::WEIGHT_REF_TIME_PER_SECOND, Weight},
 };
-use frame_system::{self as system, EventRecord, Phase};
+use frame_system::{self as system, EnsureRoot, EnsureSigned, EventRecord, Phase};
+use pallet_contracts_primitives::{CallKind, StorageDeposit};
 use pretty_assertions::{assert_eq, assert_ne};
 use sp_io::hashing::blake2_256;
 use sp_keystore::{testing::KeyStore, KeystoreExt};
@@ -68,6 +70,7 @@ frame_support::construct_runtime!(
 		Timestamp: pallet_timestamp::{Pallet, Call, Storage, Inherent},
 		Randomness: pallet_insecure_randomness_collective_flip::{Pallet, Storage},
 		Utility: pallet_utility::{Pallet, Call, Storage, Event},
+		Assets: pallet_assets::{Pallet, Call, Storage, Event<T>},
 		Contracts: pallet_contracts::{Pallet, Call, Storage, Event<T>},
 	}
 );
@@ -312,6 +315,10 @@ impl pallet_balances::Config for Test {
 	type MaxLocks = ();
 	type MaxReserves = ();
 	type ReserveIdentifier = [u8; 8];
+	type HoldIdentifier = ();
+	type FreezeIdentifier = ();
+	type MaxHolds = ();
+	type MaxFreezes = ();
 	type Balance = u64;
 	type RuntimeEvent = RuntimeEvent;
 	type DustRemoval = ();
@@ -320,6 +327,34 @@ impl pallet_balances::Config for Test {
 	type WeightInfo = ();
 }
 
+impl pallet_assets::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = u64;
+	type AssetId = u32;
+	type AssetLink = ();
+	type AssetIdParameter = u32;
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<AccountId32>>;
+	type ForceOrigin = EnsureRoot<AccountId32>;
+	type AssetDeposit = ConstU64<1>;
+	type AssetAccountDeposit = ConstU64<1>;
+	type MetadataDepositBase = ConstU64<1>;
+	type MetadataDepositPerByte = ConstU64<1>;
+	type ApprovalDeposit = ConstU64<1>;
+	type StringLimit = ConstU32<50>;
+	type Freezer = ();
+	type HoldIdentifier = ();
+	type FreezeIdentifier = ();
+	type MaxHolds = ();
+	type MaxFreezes = ();
+	type Extra = ();
+	type CallbackHandle = ();
+	type WeightInfo = ();
+	type RemoveItemsLimit = ConstU32<1000>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+}
+
 impl pallet_timestamp::Config for Test {
 	type Moment = u64;
 	type OnTimestampSet = ();
@@ -340,6 +375,7 @@ parameter_types! {
 	};
 	pub static DepositPerByte: BalanceOf<Test> = 1;
 	pub const DepositPerItem: BalanceOf<Test> = 2;
+	pub static DepositAsset: Option<u32> = None;
 }
 
 impl Convert<Weight, BalanceOf<Self>> for Test {
@@ -348,6 +384,17 @@ impl Convert<Weight, BalanceOf<Self>> for Test {
 	}
 }
 
+/// Prices storage in any asset at three times the native price.
+pub struct TripleDepositConversion;
+
+impl BalanceConversion<BalanceOf<Test>, u32, BalanceOf<Test>> for TripleDepositConversion {
+	type Error = ();
+
+	fn to_asset_balance(balance: BalanceOf<Test>, _asset_id: u32) -> Result<BalanceOf<Test>, ()> {
+		Ok(balance * 3)
+	}
+}
+
 /// A filter whose filter function can be swapped at runtime.
 pub struct TestFilter;
 
@@ -387,6 +434,7 @@ impl Config for Test {
 	type Time = Timestamp;
 	type Randomness = Randomness;
 	type Currency = Balances;
+	type Fungibles = Assets;
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeCall = RuntimeCall;
 	type CallFilter = TestFilter;
@@ -400,11 +448,36 @@ impl Config for Test {
 	type Schedule = MySchedule;
 	type DepositPerByte = DepositPerByte;
 	type DepositPerItem = DepositPerItem;
+	type DepositAsset = DepositAsset;
+	type DepositConversion = TripleDepositConversion;
 	type AddressGenerator = DefaultAddressGenerator;
 	type MaxCodeLen = ConstU32<{ 123 * 1024 }>;
 	type MaxStorageKeyLen = ConstU32<128>;
 	type UnsafeUnstableInterface = UnstableInterface;
 	type MaxDebugBufferLen = ConstU32<{ 2 * 1024 * 1024 }>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = AssetsBenchmarkHelper;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct AssetsBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl crate::BenchmarkHelper<AccountId32, u32, u64> for AssetsBenchmarkHelper {
+	fn create_asset(owner: &AccountId32) -> u32 {
+		use frame_support::traits::fungibles::{metadata::Mutate as _, Create};
+		let id = 0;
+		<Assets as Create<_>>::create(id, owner.clone(), true, 1)
+			.expect("the asset does not exist yet; qed");
+		Assets::set(id, owner, vec![b'n'; 32], vec![b's'; 8], 12)
+			.expect("the owner pays the metadata deposit; qed");
+		id
+	}
+
+	fn mint_into(asset: u32, who: &AccountId32, amount: u64) {
+		use frame_support::traits::fungibles::Mutate as _;
+		Assets::mint_into(asset, who, amount).expect("the asset exists; qed");
+	}
 }
 
 pub const ALICE: AccountId32 = AccountId32::new([1u8; 32]);
@@ -564,7 +637,7 @@ fn instantiate_and_call_and_deposit_event() {
 					phase: Phase::Initialization,
 					event: RuntimeEvent::Balances(pallet_balances::Event::Endowed {
 						account: deposit_account.clone(),
-						free_balance: 131,
+						free_balance: 132,
 					}),
 					topics: vec![],
 				},
@@ -573,7 +646,7 @@ fn instantiate_and_call_and_deposit_event() {
 					event: RuntimeEvent::Balances(pallet_balances::Event::Transfer {
 						from: ALICE,
 						to: deposit_account.clone(),
-						amount: 131,
+						amount: 132,
 					}),
 					topics: vec![],
 				},
@@ -896,7 +969,7 @@ fn deploy_and_call_other_contract() {
 					phase: Phase::Initialization,
 					event: RuntimeEvent::Balances(pallet_balances::Event::Endowed {
 						account: deposit_account.clone(),
-						free_balance: 131,
+						free_balance: 132,
 					}),
 					topics: vec![],
 				},
@@ -905,7 +978,7 @@ fn deploy_and_call_other_contract() {
 					event: RuntimeEvent::Balances(pallet_balances::Event::Transfer {
 						from: ALICE,
 						to: deposit_account.clone(),
-						amount: 131,
+						amount: 132,
 					}),
 					topics: vec![],
 				},
@@ -1021,6 +1094,131 @@ fn delegate_call() {
 	});
 }
 
+#[test]
+fn call_traced_records_nested_frames() {
+	let (caller_wasm, caller_code_hash) = compile_module::<Test>("delegate_call").unwrap();
+	let (callee_wasm, callee_code_hash) = compile_module::<Test>("delegate_call_lib").unwrap();
+
+	ExtBuilder::default().existential_deposit(500).build().execute_with(|| {
+		let _ = Balances::deposit_creating(&ALICE, 1_000_000);
+
+		let caller_addr = Contracts::bare_instantiate(
+			ALICE,
+			300_000,
+			GAS_LIMIT,
+			None,
+			Code::Upload(caller_wasm),
+			vec![],
+			vec![],
+			false,
+		)
+		.result
+		.unwrap()
+		.account_id;
+		assert_ok!(Contracts::upload_code(
+			RuntimeOrigin::signed(ALICE),
+			callee_wasm,
+			Some(codec::Compact(100_000)),
+			Determinism::Deterministic,
+		));
+
+		let input = callee_code_hash.as_ref().to_vec();
+		let output = Contracts::bare_call_traced(
+			ALICE,
+			caller_addr.clone(),
+			1337,
+			GAS_LIMIT,
+			None,
+			input.clone(),
+			Determinism::Deterministic,
+		);
+		let trace = output.result.unwrap();
+
+		assert_eq!(trace.kind, CallKind::Call);
+		assert_eq!(trace.caller, ALICE);
+		assert_eq!(trace.callee, caller_addr);
+		assert_eq!(trace.code_hash, caller_code_hash);
+		assert_eq!(trace.input, input);
+		assert_eq!(trace.value, 1337);
+		assert!(trace.gas_limit.all_lte(GAS_LIMIT));
+		assert!(trace.gas_consumed.all_lte(output.gas_consumed));
+		assert_eq!(trace.storage_deposit, output.storage_deposit);
+		assert!(!trace.result.unwrap().did_revert());
+
+		// The library is executed in the context of the caller.
+		assert_eq!(trace.calls.len(), 1);
+		let nested = &trace.calls[0];
+		assert_eq!(nested.kind, CallKind::DelegateCall);
+		assert_eq!(nested.caller, ALICE);
+		assert_eq!(nested.callee, caller_addr);
+		assert_eq!(nested.code_hash, callee_code_hash);
+		assert_eq!(nested.value, 1337);
+		assert!(nested.gas_consumed.all_lte(trace.gas_consumed));
+		assert!(nested.result.is_ok());
+		assert!(nested.calls.is_empty());
+
+		// Calling an account without a contract fails before any frame is executed.
+		assert_err!(
+			Contracts::bare_call_traced(
+				ALICE,
+				BOB,
+				0,
+				GAS_LIMIT,
+				None,
+				vec![],
+				Determinism::Deterministic,
+			)
+			.result,
+			<Error<Test>>::ContractNotFound,
+		);
+	});
+}
+
+#[test]
+fn instantiate_traced_records_the_new_contract() {
+	let (wasm, code_hash) = compile_module::<Test>("dummy").unwrap();
+
+	ExtBuilder::default().existential_deposit(500).build().execute_with(|| {
+		let _ = Balances::deposit_creating(&ALICE, 1_000_000);
+
+		let output = Contracts::bare_instantiate_traced(
+			ALICE,
+			300_000,
+			GAS_LIMIT,
+			None,
+			Code::Upload(wasm),
+			vec![1, 2, 3],
+			vec![],
+		);
+		let trace = output.result.unwrap();
+
+		assert_eq!(trace.kind, CallKind::Instantiate);
+		assert_eq!(trace.caller, ALICE);
+		assert_eq!(trace.callee, Contracts::contract_address(&ALICE, &code_hash, &[1, 2, 3], &[]));
+		assert_eq!(trace.code_hash, code_hash);
+		assert_eq!(trace.input, vec![1, 2, 3]);
+		assert_eq!(trace.value, 300_000);
+		assert!(trace.gas_consumed.all_lte(output.gas_consumed));
+		assert!(!trace.result.unwrap().did_revert());
+		assert!(trace.calls.is_empty());
+
+		// Instantiating unknown code fails before any frame is executed.
+		assert_err!(
+			Contracts::bare_instantiate_traced(
+				ALICE,
+				0,
+				GAS_LIMIT,
+				None,
+				Code::Existing(Default::default()),
+				vec![],
+				vec![],
+			)
+			.result,
+			<Error<Test>>::CodeNotFound,
+		);
+	});
+}
+
 #[test]
 fn transfer_allow_death_cannot_kill_account() {
 	let (wasm, _code_hash) = compile_module::<Test>("dummy").unwrap();
@@ -1496,6 +1694,74 @@ fn transfer_return_code() {
 	});
 }
 
+#[test]
+fn asset_transfer_return_code() {
+	let (wasm, _code_hash) = compile_module::<Test>("asset_transfer_return_code").unwrap();
+	ExtBuilder::default().existential_deposit(50).build().execute_with(|| {
+		let min_balance = <Test as Config>::Currency::minimum_balance();
+		let _ = Balances::deposit_creating(&ALICE, 1000 * min_balance);
+		let zero = AccountId32::new([0u8; 32]);
+
+		let addr = Contracts::bare_instantiate(
+			ALICE,
+			min_balance * 100,
+			GAS_LIMIT,
+			None,
+			Code::Upload(wasm),
+			vec![],
+			vec![],
+			false,
+		)
+		.result
+		.unwrap()
+		.account_id;
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 1, ALICE, true, 1));
+
+		// Neither the contract nor an approval hold any of the asset yet.
+		let result = Contracts::bare_call(
+			ALICE,
+			addr.clone(),
+			0,
+			GAS_LIMIT,
+			None,
+			vec![],
+			false,
+			Determinism::Deterministic,
+		)
+		.result
+		.unwrap();
+		assert_eq!(
+			result.data,
+			(RuntimeReturnCode::TransferFailed as u32, RuntimeReturnCode::TransferFailed as u32)
+				.encode()
+		);
+
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(ALICE), 1, addr.clone(), 150));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(ALICE), 1, ALICE, 1000));
+		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(ALICE), 1, addr.clone(), 100));
+		let result = Contracts::bare_call(
+			ALICE,
+			addr.clone(),
+			0,
+			GAS_LIMIT,
+			None,
+			vec![],
+			false,
+			Determinism::Deterministic,
+		)
+		.result
+		.unwrap();
+		assert_eq!(
+			result.data,
+			(RuntimeReturnCode::Success as u32, RuntimeReturnCode::Success as u32).encode()
+		);
+		assert_eq!(Assets::balance(1, &addr), 50);
+		assert_eq!(Assets::balance(1, &ALICE), 900);
+		assert_eq!(Assets::balance(1, &zero), 200);
+		assert_eq!(<Assets as fungibles::approvals::Inspect<_>>::allowance(1, &ALICE, &addr), 0);
+	});
+}
+
 #[test]
 fn call_return_code() {
 	let (caller_code, _caller_hash) = compile_module::<Test>("call_return_code").unwrap();
@@ -4284,6 +4550,240 @@ fn deposit_limit_honors_min_leftover() {
 	});
 }
 
+#[test]
+fn storage_deposit_in_asset_works() {
+	let (wasm, code_hash) = compile_module::<Test>("store").unwrap();
+	ExtBuilder::default().existential_deposit(200).build().execute_with(|| {
+		let _ = Balances::deposit_creating(&ALICE, 1_000_000);
+		let min_balance = <Test as Config>::Currency::minimum_balance();
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 1, ALICE, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(ALICE), 1, ALICE, 1_000_000));
+		assert_ok!(Contracts::upload_code(
+			RuntimeOrigin::signed(ALICE),
+			wasm,
+			None,
+			Determinism::Deterministic,
+		));
+		let instantiate = |salt: Vec<u8>| {
+			Contracts::bare_instantiate(
+				ALICE,
+				0,
+				GAS_LIMIT,
+				None,
+				Code::Existing(code_hash),
+				vec![],
+				salt,
+				false,
+			)
+			.result
+			.unwrap()
+			.account_id
+		};
+		let store = |addr: &AccountId32, len: u32, limit: Option<u64>| {
+			Contracts::call(
+				RuntimeOrigin::signed(ALICE),
+				addr.clone(),
+				0,
+				GAS_LIMIT,
+				limit.map(codec::Compact),
+				len.to_le_bytes().to_vec(),
+			)
+		};
+
+		// A contract instantiated before the deposit asset is configured holds its deposit in
+		// the native currency.
+		let native_addr = instantiate(vec![0]);
+		assert_eq!(get_contract(&native_addr).deposit_account().asset(), None);
+
+		DepositAsset::set(Some(1));
+		let assets_before = Assets::balance(1, &ALICE);
+		let addr = instantiate(vec![1]);
+		let contract = get_contract(&addr);
+		let deposit_account = contract.deposit_account().deref().clone();
+		assert_eq!(contract.deposit_account().asset(), Some(1));
+
+		// The base deposit is held in the asset. Only the existential deposits of both
+		// accounts are paid natively.
+		assert_eq!(assets_before - Assets::balance(1, &ALICE), contract.total_deposit());
+		assert_eq!(Assets::balance(1, &deposit_account), contract.total_deposit());
+		assert_eq!(Balances::free_balance(&deposit_account), min_balance);
+		assert_eq!(Balances::free_balance(&addr), min_balance);
+
+		// Storage of the native contract is still paid natively.
+		let native_before = Balances::free_balance(&ALICE);
+		let assets_before = Assets::balance(1, &ALICE);
+		assert_ok!(store(&native_addr, 100, None));
+		let native_charge = native_before - Balances::free_balance(&ALICE);
+		assert!(native_charge > 0);
+		assert_eq!(Assets::balance(1, &ALICE), assets_before);
+
+		// Storage of the new contract is paid in the asset at the converted price.
+		let native_before = Balances::free_balance(&ALICE);
+		assert_ok!(store(&addr, 100, None));
+		assert_eq!(assets_before - Assets::balance(1, &ALICE), 3 * native_charge);
+		assert_eq!(Balances::free_balance(&ALICE), native_before);
+
+		// The limit is denominated in the asset.
+		let extra = 3 * 100 * DepositPerByte::get();
+		assert_err_ignore_postinfo!(
+			store(&addr, 200, Some(extra - 1)),
+			<Error<Test>>::StorageDepositLimitExhausted,
+		);
+		assert_ok!(store(&addr, 200, Some(extra)));
+
+		// Removing storage refunds the asset.
+		let assets_before = Assets::balance(1, &ALICE);
+		assert_ok!(store(&addr, 100, None));
+		assert_eq!(Assets::balance(1, &ALICE) - assets_before, extra);
+	});
+}
+
+#[test]
+fn native_storage_deposit_is_limited_when_charging_in_asset() {
+	let (wasm, code_hash) = compile_module::<Test>("store").unwrap();
+	ExtBuilder::default().existential_deposit(200).build().execute_with(|| {
+		let _ = Balances::deposit_creating(&ALICE, 1_000_000);
+		let min_balance = <Test as Config>::Currency::minimum_balance();
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 1, ALICE, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(ALICE), 1, ALICE, 1_000_000));
+		assert_ok!(Contracts::upload_code(
+			RuntimeOrigin::signed(ALICE),
+			wasm,
+			None,
+			Determinism::Deterministic,
+		));
+		let instantiate = |salt: Vec<u8>| {
+			Contracts::bare_instantiate(
+				ALICE,
+				0,
+				GAS_LIMIT,
+				None,
+				Code::Existing(code_hash),
+				vec![],
+				salt,
+				false,
+			)
+		};
+		let store = |addr: &AccountId32| {
+			Contracts::call(
+				RuntimeOrigin::signed(BOB),
+				addr.clone(),
+				0,
+				GAS_LIMIT,
+				None,
+				100u32.to_le_bytes().to_vec(),
+			)
+		};
+
+		let native_addr = instantiate(vec![0]).result.unwrap().account_id;
+		assert_eq!(get_contract(&native_addr).deposit_account().asset(), None);
+
+		// The existential deposits of both accounts of a contract holding its deposit in the
+		// asset are reported separately.
+		DepositAsset::set(Some(1));
+		let result = instantiate(vec![1]);
+		assert_ok!(result.result);
+		assert!(matches!(result.storage_deposit, StorageDeposit::Charge(amount) if amount > 0));
+		assert_eq!(result.native_storage_deposit, StorageDeposit::Charge(2 * min_balance));
+
+		// Storage of the native contract is limited by the native balance of the origin. A
+		// deposit it can't afford fails the call instead of being skipped.
+		let _ = Balances::deposit_creating(&BOB, min_balance + 50);
+		let deposit_before = get_contract(&native_addr).extra_deposit();
+		assert_err_ignore_postinfo!(
+			store(&native_addr),
+			<Error<Test>>::StorageDepositLimitExhausted
+		);
+		assert_eq!(get_contract(&native_addr).extra_deposit(), deposit_before);
+		assert_eq!(Balances::free_balance(&BOB), min_balance + 50);
+
+		let _ = Balances::deposit_creating(&BOB, 1_000);
+		assert_ok!(store(&native_addr));
+		assert!(Balances::free_balance(&BOB) < min_balance + 1_050);
+		assert!(get_contract(&native_addr).extra_deposit() > deposit_before);
+	});
+}
+
+#[test]
+fn storage_deposit_limit_caps_native_deposit_when_charging_in_asset() {
+	let (wasm, code_hash) = compile_module::<Test>("store").unwrap();
+	ExtBuilder::default().existential_deposit(200).build().execute_with(|| {
+		let _ = Balances::deposit_creating(&ALICE, 1_000_000);
+		let min_balance = <Test as Config>::Currency::minimum_balance();
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 1, ALICE, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(ALICE), 1, ALICE, 1_000_000));
+		assert_ok!(Contracts::upload_code(
+			RuntimeOrigin::signed(ALICE),
+			wasm,
+			None,
+			Determinism::Deterministic,
+		));
+		DepositAsset::set(Some(1));
+		let instantiate = |limit: u64| {
+			Contracts::bare_instantiate(
+				ALICE,
+				0,
+				GAS_LIMIT,
+				Some(limit),
+				Code::Existing(code_hash),
+				vec![],
+				vec![],
+				false,
+			)
+			.result
+		};
+
+		// The existential deposits of both accounts are paid natively and limited too.
+		let native_before = Balances::free_balance(&ALICE);
+		assert_err!(instantiate(2 * min_balance - 1), <Error<Test>>::StorageDepositLimitExhausted);
+		assert_eq!(Balances::free_balance(&ALICE), native_before);
+		assert_ok!(instantiate(100_000));
+		assert_eq!(Balances::free_balance(&ALICE), native_before - 2 * min_balance);
+	});
+}
+
+#[test]
+fn terminate_refunds_storage_deposit_in_asset() {
+	let (wasm, _code_hash) = compile_module::<Test>("self_destruct").unwrap();
+	ExtBuilder::default().existential_deposit(200).build().execute_with(|| {
+		let _ = Balances::deposit_creating(&ALICE, 1_000_000);
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 1, ALICE, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(ALICE), 1, ALICE, 1_000_000));
+		DepositAsset::set(Some(1));
+
+		let addr = Contracts::bare_instantiate(
+			ALICE,
+			0,
+			GAS_LIMIT,
+			None,
+			Code::Upload(wasm),
+			vec![],
+			vec![],
+			false,
+		)
+		.result
+		.unwrap()
+		.account_id;
+		let deposit_account = get_contract(&addr).deposit_account().deref().clone();
+		assert!(Assets::balance(1, &deposit_account) > 0);
+
+		assert_ok!(Contracts::call(
+			RuntimeOrigin::signed(ALICE),
+			addr.clone(),
+			0,
+			GAS_LIMIT,
+			None,
+			vec![]
+		));
+
+		// The whole deposit went back and the deposit account was removed.
+		assert_eq!(Assets::balance(1, &ALICE), 1_000_000);
+		assert_eq!(Assets::balance(1, &deposit_account), 0);
+		assert_eq!(Balances::total_balance(&deposit_account), 0);
+		assert!(!System::account_exists(&deposit_account));
+	});
+}
+
 #[test]
 fn cannot_instantiate_indeterministic_code() {
 	let (wasm, code_hash) = compile_module::<Test>("float_instruction").unwrap();
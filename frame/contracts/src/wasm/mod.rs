@@ -372,7 +372,7 @@ mod tests {
 		},
 		gas::GasMeter,
 		storage::WriteOutcome,
-		tests::{RuntimeCall, Test, ALICE, BOB},
+		tests::{RuntimeCall, Test, ALICE, BOB, CHARLIE},
 		BalanceOf, CodeHash, Error, Pallet as Contracts,
 	};
 	use assert_matches::assert_matches;
@@ -411,6 +411,14 @@ mod tests {
 		value: u64,
 	}
 
+	#[derive(Debug, PartialEq, Eq)]
+	struct AssetTransferEntry {
+		asset: u32,
+		owner: Option<AccountIdOf<Test>>,
+		to: AccountIdOf<Test>,
+		value: u64,
+	}
+
 	#[derive(Debug, PartialEq, Eq)]
 	struct CallEntry {
 		to: AccountIdOf<Test>,
@@ -432,6 +440,7 @@ mod tests {
 		calls: Vec<CallEntry>,
 		code_calls: Vec<CallCodeEntry>,
 		transfers: Vec<TransferEntry>,
+		asset_transfers: Vec<AssetTransferEntry>,
 		// (topics, data)
 		events: Vec<(Vec<H256>, Vec<u8>)>,
 		runtime_calls: RefCell<Vec<RuntimeCall>>,
@@ -457,6 +466,7 @@ mod tests {
 				calls: Default::default(),
 				code_calls: Default::default(),
 				transfers: Default::default(),
+				asset_transfers: Default::default(),
 				events: Default::default(),
 				runtime_calls: Default::default(),
 				schedule: Default::default(),
@@ -602,6 +612,49 @@ mod tests {
 		fn minimum_balance(&self) -> u64 {
 			666
 		}
+		fn asset_balance(&self, asset: u32, _who: &AccountIdOf<Self::T>) -> u64 {
+			asset as u64 * 100
+		}
+		fn asset_transfer(
+			&mut self,
+			asset: u32,
+			to: &AccountIdOf<Self::T>,
+			value: u64,
+		) -> Result<(), DispatchError> {
+			self.asset_transfers.push(AssetTransferEntry {
+				asset,
+				owner: None,
+				to: to.clone(),
+				value,
+			});
+			Ok(())
+		}
+		fn asset_allowance(
+			&self,
+			_asset: u32,
+			_owner: &AccountIdOf<Self::T>,
+			_delegate: &AccountIdOf<Self::T>,
+		) -> u64 {
+			42
+		}
+		fn asset_transfer_approved(
+			&mut self,
+			asset: u32,
+			owner: &AccountIdOf<Self::T>,
+			to: &AccountIdOf<Self::T>,
+			value: u64,
+		) -> Result<(), DispatchError> {
+			self.asset_transfers.push(AssetTransferEntry {
+				asset,
+				owner: Some(owner.clone()),
+				to: to.clone(),
+				value,
+			});
+			Ok(())
+		}
+		fn asset_metadata(&self, _asset: u32) -> (Vec<u8>, Vec<u8>, u8) {
+			(b"Infra Dollar".to_vec(), b"IUSD".to_vec(), 6)
+		}
 		fn random(&self, subject: &[u8]) -> (SeedOf<Self::T>, BlockNumberOf<Self::T>) {
 			(H256::from_slice(subject), 42)
 		}
@@ -774,6 +827,74 @@ mod tests {
 		assert_eq!(&mock_ext.transfers, &[TransferEntry { to: ALICE, value: 153 }]);
 	}
 
+	const CODE_ASSET_TRANSFER: &str = r#"
+(module
+	;; asset_transfer(asset_ptr: u32, account_ptr: u32, value_ptr: u32) -> u32
+	(import "seal0" "asset_transfer" (func $asset_transfer (param i32 i32 i32) (result i32)))
+	;; asset_transfer_approved(
+	;;    asset_ptr: u32,
+	;;    owner_ptr: u32,
+	;;    account_ptr: u32,
+	;;    value_ptr: u32,
+	;;) -> u32
+	(import "seal0" "asset_transfer_approved"
+		(func $asset_transfer_approved (param i32 i32 i32 i32) (result i32))
+	)
+	(import "env" "memory" (memory 1 1))
+	(func (export "call")
+		(drop
+			(call $asset_transfer
+				(i32.const 0)  ;; Pointer to the asset id.
+				(i32.const 4)  ;; Pointer to "account" address.
+				(i32.const 68) ;; Pointer to the buffer with value to transfer
+			)
+		)
+		(drop
+			(call $asset_transfer_approved
+				(i32.const 0)  ;; Pointer to the asset id.
+				(i32.const 36) ;; Pointer to "owner" address.
+				(i32.const 4)  ;; Pointer to "account" address.
+				(i32.const 68) ;; Pointer to the buffer with value to transfer
+			)
+		)
+	)
+	(func (export "deploy"))
+
+	;; Asset id represented by u32 (4 bytes long) in little endian.
+	(data (i32.const 0) "\07\00\00\00")
+
+	;; Destination AccountId (ALICE)
+	(data (i32.const 4)
+		"\01\01\01\01\01\01\01\01\01\01\01\01\01\01\01\01"
+		"\01\01\01\01\01\01\01\01\01\01\01\01\01\01\01\01"
+	)
+
+	;; Owner AccountId (CHARLIE)
+	(data (i32.const 36)
+		"\03\03\03\03\03\03\03\03\03\03\03\03\03\03\03\03"
+		"\03\03\03\03\03\03\03\03\03\03\03\03\03\03\03\03"
+	)
+
+	;; Amount of value to transfer.
+	;; Represented by u64 (8 bytes long) in little endian.
+	(data (i32.const 68) "\99\00\00\00\00\00\00\00")
+)
+"#;
+
+	#[test]
+	fn contract_asset_transfer() {
+		let mut mock_ext = MockExt::default();
+		assert_ok!(execute(CODE_ASSET_TRANSFER, vec![], &mut mock_ext));
+
+		assert_eq!(
+			&mock_ext.asset_transfers,
+			&[
+				AssetTransferEntry { asset: 7, owner: None, to: ALICE, value: 153 },
+				AssetTransferEntry { asset: 7, owner: Some(CHARLIE), to: ALICE, value: 153 },
+			]
+		);
+	}
+
 	const CODE_CALL: &str = r#"
 (module
 	;; seal_call(
@@ -1874,6 +1995,76 @@ mod tests {
 		assert_ok!(execute(CODE_MINIMUM_BALANCE, vec![], MockExt::default()));
 	}
 
+	const CODE_ASSET_GETTERS: &str = r#"
+(module
+	(import "seal0" "asset_balance" (func $asset_balance (param i32 i32 i32 i32)))
+	(import "seal0" "asset_allowance" (func $asset_allowance (param i32 i32 i32 i32 i32)))
+	(import "seal0" "asset_metadata" (func $asset_metadata (param i32 i32 i32)))
+	(import "seal0" "seal_return" (func $seal_return (param i32 i32 i32)))
+	(import "env" "memory" (memory 1 1))
+
+	;; [0, 4) asset id
+	(data (i32.const 0) "\03\00\00\00")
+
+	;; [4, 36) account (BOB) used as owner and delegate
+	(data (i32.const 4)
+		"\02\02\02\02\02\02\02\02\02\02\02\02\02\02\02\02"
+		"\02\02\02\02\02\02\02\02\02\02\02\02\02\02\02\02"
+	)
+
+	;; [36, 40) size of the output buffer
+	(data (i32.const 36) "\40")
+
+	;; [40, 104) output buffer
+
+	(func $assert (param i32)
+		(block $ok
+			(br_if $ok
+				(get_local 0)
+			)
+			(unreachable)
+		)
+	)
+
+	(func (export "call")
+		(call $asset_balance (i32.const 0) (i32.const 4) (i32.const 40) (i32.const 36))
+
+		;; assert len == 8 and balance == 300
+		(call $assert (i32.eq (i32.load (i32.const 36)) (i32.const 8)))
+		(call $assert (i64.eq (i64.load (i32.const 40)) (i64.const 300)))
+
+		;; reset the size of the output buffer
+		(i32.store (i32.const 36) (i32.const 64))
+		(call $asset_allowance
+			(i32.const 0) (i32.const 4) (i32.const 4) (i32.const 40) (i32.const 36)
+		)
+
+		;; assert len == 8 and allowance == 42
+		(call $assert (i32.eq (i32.load (i32.const 36)) (i32.const 8)))
+		(call $assert (i64.eq (i64.load (i32.const 40)) (i64.const 42)))
+
+		;; return the encoded metadata
+		(i32.store (i32.const 36) (i32.const 64))
+		(call $asset_metadata (i32.const 0) (i32.const 40) (i32.const 36))
+		(call $seal_return (i32.const 0) (i32.const 40) (i32.load (i32.const 36)))
+	)
+	(func (export "deploy"))
+)
+"#;
+
+	#[test]
+	fn asset_getters() {
+		let output = execute(CODE_ASSET_GETTERS, vec![], MockExt::default()).unwrap();
+
+		assert_eq!(
+			output,
+			ExecReturnValue {
+				flags: ReturnFlags::empty(),
+				data: (b"Infra Dollar".to_vec(), b"IUSD".to_vec(), 6u8).encode(),
+			}
+		);
+	}
+
 	const CODE_RANDOM: &str = r#"
 (module
 	(import "seal0" "seal_random" (func $seal_random (param i32 i32 i32 i32)))
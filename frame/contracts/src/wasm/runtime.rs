@@ -21,7 +21,7 @@ use crate::{
 	exec::{ExecError, ExecResult, Ext, FixSizedKey, TopicOf, VarSizedKey},
 	gas::{ChargedAmount, Token},
 	schedule::HostFnWeights,
-	BalanceOf, CodeHash, Config, DebugBufferVec, Error, SENTINEL,
+	AssetIdOf, BalanceOf, CodeHash, Config, DebugBufferVec, Error, SENTINEL,
 };
 
 use bitflags::bitflags;
@@ -276,6 +276,16 @@ pub enum RuntimeCosts {
 	AccountEntranceCount,
 	/// Weight of calling `instantiation_nonce`
 	InstantationNonce,
+	/// Weight of calling `asset_balance`.
+	AssetBalance,
+	/// Weight of calling `asset_transfer`.
+	AssetTransfer,
+	/// Weight of calling `asset_allowance`.
+	AssetAllowance,
+	/// Weight of calling `asset_transfer_approved`.
+	AssetTransferApproved,
+	/// Weight of calling `asset_metadata`.
+	AssetMetadata,
 }
 
 impl RuntimeCosts {
@@ -354,6 +364,11 @@ impl RuntimeCosts {
 			ReentrantCount => s.reentrance_count,
 			AccountEntranceCount => s.account_reentrance_count,
 			InstantationNonce => s.instantiation_nonce,
+			AssetBalance => s.asset_balance,
+			AssetTransfer => s.asset_transfer,
+			AssetAllowance => s.asset_allowance,
+			AssetTransferApproved => s.asset_transfer_approved,
+			AssetMetadata => s.asset_metadata,
 		};
 		RuntimeToken {
 			#[cfg(test)]
@@ -2003,6 +2018,196 @@ pub mod env {
 		)?)
 	}
 
+	/// Stores the balance of an account in a fungible asset into the supplied buffer.
+	///
+	/// The value is stored to linear memory at the address pointed to by `out_ptr`.
+	/// `out_len_ptr` must point to a u32 value that describes the available space at
+	/// `out_ptr`. This call overwrites it with the size of the value. If the available
+	/// space at `out_ptr` is less than the size of the value a trap is triggered.
+	///
+	/// The data is encoded as `T::Balance`.
+	///
+	/// # Parameters
+	///
+	/// - `asset_ptr`: a pointer to the id of the asset. Should be decodable as the `AssetId` of
+	///   `T::Fungibles`. Traps otherwise.
+	/// - `account_ptr`: a pointer to the address of the account. Should be decodable as an
+	///   `T::AccountId`. Traps otherwise.
+	#[unstable]
+	fn asset_balance(
+		ctx: _,
+		memory: _,
+		asset_ptr: u32,
+		account_ptr: u32,
+		out_ptr: u32,
+		out_len_ptr: u32,
+	) -> Result<(), TrapReason> {
+		ctx.charge_gas(RuntimeCosts::AssetBalance)?;
+		let asset: AssetIdOf<<E as Ext>::T> = ctx.read_sandbox_memory_as(memory, asset_ptr)?;
+		let account: <<E as Ext>::T as frame_system::Config>::AccountId =
+			ctx.read_sandbox_memory_as(memory, account_ptr)?;
+		Ok(ctx.write_sandbox_output(
+			memory,
+			out_ptr,
+			out_len_ptr,
+			&ctx.ext.asset_balance(asset, &account).encode(),
+			false,
+			already_charged,
+		)?)
+	}
+
+	/// Transfer some amount of a fungible asset from the calling contract to another account.
+	///
+	/// The contract's account is kept alive for the asset.
+	///
+	/// # Parameters
+	///
+	/// - `asset_ptr`: a pointer to the id of the asset. Should be decodable as the `AssetId` of
+	///   `T::Fungibles`. Traps otherwise.
+	/// - `account_ptr`: a pointer to the address of the beneficiary account. Should be decodable as
+	///   an `T::AccountId`. Traps otherwise.
+	/// - `value_ptr`: a pointer to the buffer with the amount to send. Should be decodable as a
+	///   `T::Balance`. Traps otherwise.
+	///
+	/// # Errors
+	///
+	/// - `ReturnCode::TransferFailed`
+	#[unstable]
+	fn asset_transfer(
+		ctx: _,
+		memory: _,
+		asset_ptr: u32,
+		account_ptr: u32,
+		value_ptr: u32,
+	) -> Result<ReturnCode, TrapReason> {
+		ctx.charge_gas(RuntimeCosts::AssetTransfer)?;
+		let asset: AssetIdOf<<E as Ext>::T> = ctx.read_sandbox_memory_as(memory, asset_ptr)?;
+		let dest: <<E as Ext>::T as frame_system::Config>::AccountId =
+			ctx.read_sandbox_memory_as(memory, account_ptr)?;
+		let value: BalanceOf<<E as Ext>::T> = ctx.read_sandbox_memory_as(memory, value_ptr)?;
+		match ctx.ext.asset_transfer(asset, &dest, value) {
+			Ok(()) => Ok(ReturnCode::Success),
+			Err(err) => Ok(Runtime::<E>::err_into_return_code(err)?),
+		}
+	}
+
+	/// Stores the amount of a fungible asset that `owner` approved `delegate` to spend into the
+	/// supplied buffer.
+	///
+	/// The value is stored to linear memory at the address pointed to by `out_ptr`.
+	/// `out_len_ptr` must point to a u32 value that describes the available space at
+	/// `out_ptr`. This call overwrites it with the size of the value. If the available
+	/// space at `out_ptr` is less than the size of the value a trap is triggered.
+	///
+	/// The data is encoded as `T::Balance`.
+	///
+	/// # Parameters
+	///
+	/// - `asset_ptr`: a pointer to the id of the asset. Should be decodable as the `AssetId` of
+	///   `T::Fungibles`. Traps otherwise.
+	/// - `owner_ptr`: a pointer to the address of the owner. Should be decodable as an
+	///   `T::AccountId`. Traps otherwise.
+	/// - `delegate_ptr`: a pointer to the address of the delegate. Should be decodable as an
+	///   `T::AccountId`. Traps otherwise.
+	#[unstable]
+	fn asset_allowance(
+		ctx: _,
+		memory: _,
+		asset_ptr: u32,
+		owner_ptr: u32,
+		delegate_ptr: u32,
+		out_ptr: u32,
+		out_len_ptr: u32,
+	) -> Result<(), TrapReason> {
+		ctx.charge_gas(RuntimeCosts::AssetAllowance)?;
+		let asset: AssetIdOf<<E as Ext>::T> = ctx.read_sandbox_memory_as(memory, asset_ptr)?;
+		let owner: <<E as Ext>::T as frame_system::Config>::AccountId =
+			ctx.read_sandbox_memory_as(memory, owner_ptr)?;
+		let delegate: <<E as Ext>::T as frame_system::Config>::AccountId =
+			ctx.read_sandbox_memory_as(memory, delegate_ptr)?;
+		Ok(ctx.write_sandbox_output(
+			memory,
+			out_ptr,
+			out_len_ptr,
+			&ctx.ext.asset_allowance(asset, &owner, &delegate).encode(),
+			false,
+			already_charged,
+		)?)
+	}
+
+	/// Transfer some amount of a fungible asset that `owner` approved the calling contract to
+	/// spend to another account.
+	///
+	/// # Parameters
+	///
+	/// - `asset_ptr`: a pointer to the id of the asset. Should be decodable as the `AssetId` of
+	///   `T::Fungibles`. Traps otherwise.
+	/// - `owner_ptr`: a pointer to the address of the owner who approved the transfer. Should be
+	///   decodable as an `T::AccountId`. Traps otherwise.
+	/// - `account_ptr`: a pointer to the address of the beneficiary account. Should be decodable as
+	///   an `T::AccountId`. Traps otherwise.
+	/// - `value_ptr`: a pointer to the buffer with the amount to send. Should be decodable as a
+	///   `T::Balance`. Traps otherwise.
+	///
+	/// # Errors
+	///
+	/// - `ReturnCode::TransferFailed`
+	#[unstable]
+	fn asset_transfer_approved(
+		ctx: _,
+		memory: _,
+		asset_ptr: u32,
+		owner_ptr: u32,
+		account_ptr: u32,
+		value_ptr: u32,
+	) -> Result<ReturnCode, TrapReason> {
+		ctx.charge_gas(RuntimeCosts::AssetTransferApproved)?;
+		let asset: AssetIdOf<<E as Ext>::T> = ctx.read_sandbox_memory_as(memory, asset_ptr)?;
+		let owner: <<E as Ext>::T as frame_system::Config>::AccountId =
+			ctx.read_sandbox_memory_as(memory, owner_ptr)?;
+		let dest: <<E as Ext>::T as frame_system::Config>::AccountId =
+			ctx.read_sandbox_memory_as(memory, account_ptr)?;
+		let value: BalanceOf<<E as Ext>::T> = ctx.read_sandbox_memory_as(memory, value_ptr)?;
+		match ctx.ext.asset_transfer_approved(asset, &owner, &dest, value) {
+			Ok(()) => Ok(ReturnCode::Success),
+			Err(err) => Ok(Runtime::<E>::err_into_return_code(err)?),
+		}
+	}
+
+	/// Stores the metadata of a fungible asset into the supplied buffer.
+	///
+	/// The value is stored to linear memory at the address pointed to by `out_ptr`.
+	/// `out_len_ptr` must point to a u32 value that describes the available space at
+	/// `out_ptr`. This call overwrites it with the size of the value. If the available
+	/// space at `out_ptr` is less than the size of the value a trap is triggered.
+	///
+	/// The data is encoded as `(Vec<u8>, Vec<u8>, u8)`: the name, the symbol and the number
+	/// of decimals of the asset. Assets without metadata yield empty strings and zero decimals.
+	///
+	/// # Parameters
+	///
+	/// - `asset_ptr`: a pointer to the id of the asset. Should be decodable as the `AssetId` of
+	///   `T::Fungibles`. Traps otherwise.
+	#[unstable]
+	fn asset_metadata(
+		ctx: _,
+		memory: _,
+		asset_ptr: u32,
+		out_ptr: u32,
+		out_len_ptr: u32,
+	) -> Result<(), TrapReason> {
+		ctx.charge_gas(RuntimeCosts::AssetMetadata)?;
+		let asset: AssetIdOf<<E as Ext>::T> = ctx.read_sandbox_memory_as(memory, asset_ptr)?;
+		Ok(ctx.write_sandbox_output(
+			memory,
+			out_ptr,
+			out_len_ptr,
+			&ctx.ext.asset_metadata(asset).encode(),
+			false,
+			|len| Some(RuntimeCosts::CopyToContract(len)),
+		)?)
+	}
+
 	/// Stores the tombstone deposit into the supplied buffer.
 	///
 	/// The value is stored to linear memory at the address pointed to by `out_ptr`.
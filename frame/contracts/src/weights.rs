@@ -115,6 +115,11 @@ pub trait WeightInfo {
 	fn seal_reentrance_count(r: u32, ) -> Weight;
 	fn seal_account_reentrance_count(r: u32, ) -> Weight;
 	fn seal_instantiation_nonce(r: u32, ) -> Weight;
+	fn seal_asset_balance(r: u32, ) -> Weight;
+	fn seal_asset_transfer(r: u32, ) -> Weight;
+	fn seal_asset_allowance(r: u32, ) -> Weight;
+	fn seal_asset_transfer_approved(r: u32, ) -> Weight;
+	fn seal_asset_metadata(r: u32, ) -> Weight;
 	fn instr_i64const(r: u32, ) -> Weight;
 	fn instr_i64load(r: u32, ) -> Weight;
 	fn instr_i64store(r: u32, ) -> Weight;
@@ -1694,6 +1699,148 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 			.saturating_add(Weight::from_parts(0, 1440).saturating_mul(r.into()))
 	}
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: Measured)
+	/// Storage: Contracts ContractInfoOf (r:1 w:1)
+	/// Proof: Contracts ContractInfoOf (max_values: None, max_size: Some(290), added: 2765, mode: Measured)
+	/// Storage: Contracts CodeStorage (r:1 w:0)
+	/// Proof: Contracts CodeStorage (max_values: None, max_size: Some(126001), added: 128476, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: Measured)
+	/// Storage: Assets Account (r:1600 w:0)
+	/// Proof: Assets Account (max_values: None, max_size: Some(102), added: 2577, mode: Measured)
+	/// Storage: System EventTopics (r:2 w:2)
+	/// Proof Skipped: System EventTopics (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `r` is `[0, 20]`.
+	fn seal_asset_balance(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1012 + r * (2560 ±0)`
+		//  Estimated: `18998 + r * (206161 ±0)`
+		// Minimum execution time: 402_113 nanoseconds.
+		Weight::from_parts(251_470_326, 0)
+			.saturating_add(Weight::from_parts(0, 18998))
+			// Standard Error: 468_902
+			.saturating_add(Weight::from_parts(263_081_447, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().reads((80_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(Weight::from_parts(0, 206161).saturating_mul(r.into()))
+	}
+	/// Storage: System Account (r:1601 w:1600)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: Measured)
+	/// Storage: Contracts ContractInfoOf (r:1 w:1)
+	/// Proof: Contracts ContractInfoOf (max_values: None, max_size: Some(290), added: 2765, mode: Measured)
+	/// Storage: Contracts CodeStorage (r:1 w:0)
+	/// Proof: Contracts CodeStorage (max_values: None, max_size: Some(126001), added: 128476, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: Measured)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: Measured)
+	/// Storage: Assets Account (r:1601 w:1601)
+	/// Proof: Assets Account (max_values: None, max_size: Some(102), added: 2577, mode: Measured)
+	/// Storage: System EventTopics (r:2 w:2)
+	/// Proof Skipped: System EventTopics (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `r` is `[0, 20]`.
+	fn seal_asset_transfer(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1531 + r * (3648 ±0)`
+		//  Estimated: `24292 + r * (422262 ±0)`
+		// Minimum execution time: 421_502 nanoseconds.
+		Weight::from_parts(329_615_204, 0)
+			.saturating_add(Weight::from_parts(0, 24292))
+			// Standard Error: 1_176_398
+			.saturating_add(Weight::from_parts(2_241_762_905, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().reads((160_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().writes((160_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_parts(0, 422262).saturating_mul(r.into()))
+	}
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: Measured)
+	/// Storage: Contracts ContractInfoOf (r:1 w:1)
+	/// Proof: Contracts ContractInfoOf (max_values: None, max_size: Some(290), added: 2765, mode: Measured)
+	/// Storage: Contracts CodeStorage (r:1 w:0)
+	/// Proof: Contracts CodeStorage (max_values: None, max_size: Some(126001), added: 128476, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: Measured)
+	/// Storage: Assets Approvals (r:1600 w:0)
+	/// Proof: Assets Approvals (max_values: None, max_size: Some(148), added: 2623, mode: Measured)
+	/// Storage: System EventTopics (r:2 w:2)
+	/// Proof Skipped: System EventTopics (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `r` is `[0, 20]`.
+	fn seal_asset_allowance(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1014 + r * (2592 ±0)`
+		//  Estimated: `18998 + r * (209841 ±0)`
+		// Minimum execution time: 405_781 nanoseconds.
+		Weight::from_parts(253_018_932, 0)
+			.saturating_add(Weight::from_parts(0, 18998))
+			// Standard Error: 471_655
+			.saturating_add(Weight::from_parts(268_294_713, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().reads((80_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(Weight::from_parts(0, 209841).saturating_mul(r.into()))
+	}
+	/// Storage: System Account (r:1601 w:1600)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: Measured)
+	/// Storage: Contracts ContractInfoOf (r:1 w:1)
+	/// Proof: Contracts ContractInfoOf (max_values: None, max_size: Some(290), added: 2765, mode: Measured)
+	/// Storage: Contracts CodeStorage (r:1 w:0)
+	/// Proof: Contracts CodeStorage (max_values: None, max_size: Some(126001), added: 128476, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: Measured)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: Measured)
+	/// Storage: Assets Account (r:1601 w:1601)
+	/// Proof: Assets Account (max_values: None, max_size: Some(102), added: 2577, mode: Measured)
+	/// Storage: Assets Approvals (r:800 w:800)
+	/// Proof: Assets Approvals (max_values: None, max_size: Some(148), added: 2623, mode: Measured)
+	/// Storage: System EventTopics (r:2 w:2)
+	/// Proof Skipped: System EventTopics (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `r` is `[0, 10]`.
+	fn seal_asset_transfer_approved(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1626 + r * (6208 ±0)`
+		//  Estimated: `27017 + r * (1054365 ±0)`
+		// Minimum execution time: 437_309 nanoseconds.
+		Weight::from_parts(342_874_190, 0)
+			.saturating_add(Weight::from_parts(0, 27017))
+			// Standard Error: 2_314_906
+			.saturating_add(Weight::from_parts(4_318_539_671, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().reads((400_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().writes((400_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_parts(0, 1054365).saturating_mul(r.into()))
+	}
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: Measured)
+	/// Storage: Contracts ContractInfoOf (r:1 w:1)
+	/// Proof: Contracts ContractInfoOf (max_values: None, max_size: Some(290), added: 2765, mode: Measured)
+	/// Storage: Contracts CodeStorage (r:1 w:0)
+	/// Proof: Contracts CodeStorage (max_values: None, max_size: Some(126001), added: 128476, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: Measured)
+	/// Storage: Assets Metadata (r:1 w:0)
+	/// Proof: Assets Metadata (max_values: None, max_size: Some(140), added: 2615, mode: Measured)
+	/// Storage: System EventTopics (r:2 w:2)
+	/// Proof Skipped: System EventTopics (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `r` is `[0, 20]`.
+	fn seal_asset_metadata(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1087 + r * (240 ±0)`
+		//  Estimated: `21613 + r * (1440 ±0)`
+		// Minimum execution time: 378_650 nanoseconds.
+		Weight::from_parts(383_940_516, 0)
+			.saturating_add(Weight::from_parts(0, 21613))
+			// Standard Error: 35_814
+			.saturating_add(Weight::from_parts(10_822_047, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(Weight::from_parts(0, 1440).saturating_mul(r.into()))
+	}
 	/// The range of component `r` is `[0, 50]`.
 	fn instr_i64const(r: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -3792,6 +3939,148 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
 			.saturating_add(Weight::from_parts(0, 1440).saturating_mul(r.into()))
 	}
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: Measured)
+	/// Storage: Contracts ContractInfoOf (r:1 w:1)
+	/// Proof: Contracts ContractInfoOf (max_values: None, max_size: Some(290), added: 2765, mode: Measured)
+	/// Storage: Contracts CodeStorage (r:1 w:0)
+	/// Proof: Contracts CodeStorage (max_values: None, max_size: Some(126001), added: 128476, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: Measured)
+	/// Storage: Assets Account (r:1600 w:0)
+	/// Proof: Assets Account (max_values: None, max_size: Some(102), added: 2577, mode: Measured)
+	/// Storage: System EventTopics (r:2 w:2)
+	/// Proof Skipped: System EventTopics (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `r` is `[0, 20]`.
+	fn seal_asset_balance(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1012 + r * (2560 ±0)`
+		//  Estimated: `18998 + r * (206161 ±0)`
+		// Minimum execution time: 402_113 nanoseconds.
+		Weight::from_parts(251_470_326, 0)
+			.saturating_add(Weight::from_parts(0, 18998))
+			// Standard Error: 468_902
+			.saturating_add(Weight::from_parts(263_081_447, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().reads((80_u64).saturating_mul(r.into())))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(Weight::from_parts(0, 206161).saturating_mul(r.into()))
+	}
+	/// Storage: System Account (r:1601 w:1600)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: Measured)
+	/// Storage: Contracts ContractInfoOf (r:1 w:1)
+	/// Proof: Contracts ContractInfoOf (max_values: None, max_size: Some(290), added: 2765, mode: Measured)
+	/// Storage: Contracts CodeStorage (r:1 w:0)
+	/// Proof: Contracts CodeStorage (max_values: None, max_size: Some(126001), added: 128476, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: Measured)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: Measured)
+	/// Storage: Assets Account (r:1601 w:1601)
+	/// Proof: Assets Account (max_values: None, max_size: Some(102), added: 2577, mode: Measured)
+	/// Storage: System EventTopics (r:2 w:2)
+	/// Proof Skipped: System EventTopics (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `r` is `[0, 20]`.
+	fn seal_asset_transfer(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1531 + r * (3648 ±0)`
+		//  Estimated: `24292 + r * (422262 ±0)`
+		// Minimum execution time: 421_502 nanoseconds.
+		Weight::from_parts(329_615_204, 0)
+			.saturating_add(Weight::from_parts(0, 24292))
+			// Standard Error: 1_176_398
+			.saturating_add(Weight::from_parts(2_241_762_905, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().reads((160_u64).saturating_mul(r.into())))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().writes((160_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_parts(0, 422262).saturating_mul(r.into()))
+	}
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: Measured)
+	/// Storage: Contracts ContractInfoOf (r:1 w:1)
+	/// Proof: Contracts ContractInfoOf (max_values: None, max_size: Some(290), added: 2765, mode: Measured)
+	/// Storage: Contracts CodeStorage (r:1 w:0)
+	/// Proof: Contracts CodeStorage (max_values: None, max_size: Some(126001), added: 128476, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: Measured)
+	/// Storage: Assets Approvals (r:1600 w:0)
+	/// Proof: Assets Approvals (max_values: None, max_size: Some(148), added: 2623, mode: Measured)
+	/// Storage: System EventTopics (r:2 w:2)
+	/// Proof Skipped: System EventTopics (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `r` is `[0, 20]`.
+	fn seal_asset_allowance(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1014 + r * (2592 ±0)`
+		//  Estimated: `18998 + r * (209841 ±0)`
+		// Minimum execution time: 405_781 nanoseconds.
+		Weight::from_parts(253_018_932, 0)
+			.saturating_add(Weight::from_parts(0, 18998))
+			// Standard Error: 471_655
+			.saturating_add(Weight::from_parts(268_294_713, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().reads((80_u64).saturating_mul(r.into())))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(Weight::from_parts(0, 209841).saturating_mul(r.into()))
+	}
+	/// Storage: System Account (r:1601 w:1600)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: Measured)
+	/// Storage: Contracts ContractInfoOf (r:1 w:1)
+	/// Proof: Contracts ContractInfoOf (max_values: None, max_size: Some(290), added: 2765, mode: Measured)
+	/// Storage: Contracts CodeStorage (r:1 w:0)
+	/// Proof: Contracts CodeStorage (max_values: None, max_size: Some(126001), added: 128476, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: Measured)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: Measured)
+	/// Storage: Assets Account (r:1601 w:1601)
+	/// Proof: Assets Account (max_values: None, max_size: Some(102), added: 2577, mode: Measured)
+	/// Storage: Assets Approvals (r:800 w:800)
+	/// Proof: Assets Approvals (max_values: None, max_size: Some(148), added: 2623, mode: Measured)
+	/// Storage: System EventTopics (r:2 w:2)
+	/// Proof Skipped: System EventTopics (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `r` is `[0, 10]`.
+	fn seal_asset_transfer_approved(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1626 + r * (6208 ±0)`
+		//  Estimated: `27017 + r * (1054365 ±0)`
+		// Minimum execution time: 437_309 nanoseconds.
+		Weight::from_parts(342_874_190, 0)
+			.saturating_add(Weight::from_parts(0, 27017))
+			// Standard Error: 2_314_906
+			.saturating_add(Weight::from_parts(4_318_539_671, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().reads((400_u64).saturating_mul(r.into())))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().writes((400_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_parts(0, 1054365).saturating_mul(r.into()))
+	}
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: Measured)
+	/// Storage: Contracts ContractInfoOf (r:1 w:1)
+	/// Proof: Contracts ContractInfoOf (max_values: None, max_size: Some(290), added: 2765, mode: Measured)
+	/// Storage: Contracts CodeStorage (r:1 w:0)
+	/// Proof: Contracts CodeStorage (max_values: None, max_size: Some(126001), added: 128476, mode: Measured)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: Measured)
+	/// Storage: Assets Metadata (r:1 w:0)
+	/// Proof: Assets Metadata (max_values: None, max_size: Some(140), added: 2615, mode: Measured)
+	/// Storage: System EventTopics (r:2 w:2)
+	/// Proof Skipped: System EventTopics (max_values: None, max_size: None, mode: Measured)
+	/// The range of component `r` is `[0, 20]`.
+	fn seal_asset_metadata(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1087 + r * (240 ±0)`
+		//  Estimated: `21613 + r * (1440 ±0)`
+		// Minimum execution time: 378_650 nanoseconds.
+		Weight::from_parts(383_940_516, 0)
+			.saturating_add(Weight::from_parts(0, 21613))
+			// Standard Error: 35_814
+			.saturating_add(Weight::from_parts(10_822_047, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(Weight::from_parts(0, 1440).saturating_mul(r.into()))
+	}
 	/// The range of component `r` is `[0, 50]`.
 	fn instr_i64const(r: u32, ) -> Weight {
 		// Proof Size summary in bytes:
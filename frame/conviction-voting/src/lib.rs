@@ -27,6 +27,7 @@
 #![recursion_limit = "256"]
 #![cfg_attr(not(feature = "std"), no_std)]
 
+use codec::Codec;
 use frame_support::{
 	dispatch::{DispatchError, DispatchResult},
 	ensure,
@@ -49,8 +50,8 @@ pub mod weights;
 pub use self::{
 	conviction::Conviction,
 	pallet::*,
-	types::{Delegations, Tally, UnvoteScope},
-	vote::{AccountVote, Casting, Delegating, Vote, Voting},
+	types::{ClassLock, Delegations, Tally, UnvoteScope},
+	vote::{AccountVote, Casting, Delegating, PriorLock, Vote, Voting},
 	weights::WeightInfo,
 };
 
@@ -379,6 +380,84 @@ pub mod pallet {
 			Self::try_remove_vote(&target, index, Some(class), scope)?;
 			Ok(())
 		}
+
+		/// Delegate the voting power (with some given conviction) of the sending account for
+		/// several classes of polls at once.
+		///
+		/// This is equivalent to calling `delegate` for each of `classes` with the same `to`,
+		/// `conviction` and `balance`, except that it fails as a whole if the delegation fails for
+		/// any of the classes.
+		///
+		/// The dispatch origin of this call must be _Signed_, and the signing account must be able
+		/// to delegate on each of `classes`, as for `delegate`.
+		///
+		/// - `classes`: The classes of polls to delegate.
+		/// - `to`: The account whose voting the `target` account's voting power will follow.
+		/// - `conviction`: The conviction that will be attached to the delegated votes.
+		/// - `balance`: The amount of the account's balance to be used in delegating on each class.
+		///
+		/// Emits `Delegated` for each class.
+		///
+		/// Weight: `O(C * R)` where C is the number of classes and R is the number of polls the
+		///   voter delegating to has voted on. Weight is initially charged as if maximum votes,
+		///   but is refunded later.
+		#[pallet::call_index(6)]
+		#[pallet::weight(
+			T::WeightInfo::delegate(T::MaxVotes::get()).saturating_mul(classes.len() as u64)
+		)]
+		pub fn delegate_multiple(
+			origin: OriginFor<T>,
+			classes: BoundedVec<ClassOf<T, I>, ClassCountOf<T::Polls, TallyOf<T, I>>>,
+			to: AccountIdLookupOf<T>,
+			conviction: Conviction,
+			balance: BalanceOf<T, I>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let to = T::Lookup::lookup(to)?;
+			let mut weight = Weight::zero();
+			for class in classes {
+				let votes =
+					Self::try_delegate(who.clone(), class, to.clone(), conviction, balance)?;
+				weight.saturating_accrue(T::WeightInfo::delegate(votes));
+			}
+
+			Ok(Some(weight).into())
+		}
+
+		/// Vote in several polls at once.
+		///
+		/// This is equivalent to calling `vote` for each of `votes`, except that it fails as a
+		/// whole if any of the votes fails, and that the lock on the balance of the sending
+		/// account is only extended once.
+		///
+		/// The dispatch origin of this call must be _Signed_.
+		///
+		/// - `votes`: The indices of the polls to vote for, each with the vote configuration.
+		///
+		/// Weight: `O(V * R)` where V is the number of votes and R is the number of polls the voter
+		///   has voted on.
+		#[pallet::call_index(7)]
+		#[pallet::weight(
+			T::WeightInfo::vote_new()
+				.max(T::WeightInfo::vote_existing())
+				.saturating_mul(votes.len() as u64)
+		)]
+		pub fn vote_multiple(
+			origin: OriginFor<T>,
+			votes: BoundedVec<(PollIndexOf<T, I>, AccountVote<BalanceOf<T, I>>), T::MaxVotes>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let mut class_locks: Vec<(ClassOf<T, I>, BalanceOf<T, I>)> = Vec::new();
+			for (poll_index, vote) in votes {
+				let class = Self::try_record_vote(&who, poll_index, vote)?;
+				match class_locks.iter_mut().find(|x| x.0 == class) {
+					Some(lock) => lock.1 = lock.1.max(vote.balance()),
+					None => class_locks.push((class, vote.balance())),
+				}
+			}
+			Self::extend_locks(&who, class_locks);
+			Ok(())
+		}
 	}
 }
 
@@ -389,10 +468,25 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		poll_index: PollIndexOf<T, I>,
 		vote: AccountVote<BalanceOf<T, I>>,
 	) -> DispatchResult {
+		let class = Self::try_record_vote(who, poll_index, vote)?;
+		// Extend the lock to `balance` (rather than setting it) since we don't know what other
+		// votes are in place.
+		Self::extend_lock(who, &class, vote.balance());
+		Ok(())
+	}
+
+	/// Record a vote in the poll and its tally, if legit, without extending the lock of `who`.
+	///
+	/// Return the class of the poll.
+	fn try_record_vote(
+		who: &T::AccountId,
+		poll_index: PollIndexOf<T, I>,
+		vote: AccountVote<BalanceOf<T, I>>,
+	) -> Result<ClassOf<T, I>, DispatchError> {
 		ensure!(vote.balance() <= T::Currency::free_balance(who), Error::<T, I>::InsufficientFunds);
 		T::Polls::try_access_poll(poll_index, |poll_status| {
 			let (tally, class) = poll_status.ensure_ongoing().ok_or(Error::<T, I>::NotOngoing)?;
-			VotingFor::<T, I>::try_mutate(who, &class, |voting| {
+			VotingFor::<T, I>::try_mutate(who, &class, |voting| -> DispatchResult {
 				if let Voting::Casting(Casting { ref mut votes, delegations, .. }) = voting {
 					match votes.binary_search_by_key(&poll_index, |i| i.0) {
 						Ok(i) => {
@@ -417,11 +511,9 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 				} else {
 					return Err(Error::<T, I>::AlreadyDelegating.into())
 				}
-				// Extend the lock to `balance` (rather than setting it) since we don't know what
-				// other votes are in place.
-				Self::extend_lock(who, &class, vote.balance());
 				Ok(())
-			})
+			})?;
+			Ok(class)
 		})
 	}
 
@@ -623,23 +715,52 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	}
 
 	fn extend_lock(who: &T::AccountId, class: &ClassOf<T, I>, amount: BalanceOf<T, I>) {
-		ClassLocksFor::<T, I>::mutate(who, |locks| {
-			match locks.iter().position(|x| &x.0 == class) {
-				Some(i) => locks[i].1 = locks[i].1.max(amount),
-				None => {
-					let ok = locks.try_push((class.clone(), amount)).is_ok();
-					debug_assert!(
-						ok,
-						"Vec bounded by number of classes; \
-						all items in Vec associated with a unique class; \
-						qed"
-					);
-				},
+		Self::extend_locks(who, sp_std::iter::once((class.clone(), amount)))
+	}
+
+	/// Extend the locks of `who` for several classes, extending the lock on the balance only once.
+	fn extend_locks(
+		who: &T::AccountId,
+		class_locks: impl IntoIterator<Item = (ClassOf<T, I>, BalanceOf<T, I>)>,
+	) {
+		let amount = ClassLocksFor::<T, I>::mutate(who, |locks| {
+			let mut max_amount = BalanceOf::<T, I>::zero();
+			for (class, amount) in class_locks {
+				match locks.iter().position(|x| x.0 == class) {
+					Some(i) => locks[i].1 = locks[i].1.max(amount),
+					None => {
+						let ok = locks.try_push((class, amount)).is_ok();
+						debug_assert!(
+							ok,
+							"Vec bounded by number of classes; \
+							all items in Vec associated with a unique class; \
+							qed"
+						);
+					},
+				}
+				max_amount = max_amount.max(amount);
 			}
+			max_amount
 		});
 		T::Currency::extend_lock(CONVICTION_VOTING_ID, who, amount, WithdrawReasons::TRANSFER);
 	}
 
+	/// The locks of `who` for each class of polls it has a lock for, along with the prior lock of
+	/// the class which will expire.
+	pub fn class_locks_for(
+		who: &T::AccountId,
+	) -> Vec<ClassLock<ClassOf<T, I>, BalanceOf<T, I>, T::BlockNumber>> {
+		ClassLocksFor::<T, I>::get(who)
+			.into_iter()
+			.map(|(class, amount)| {
+				let prior = *VotingFor::<T, I>::get(who, &class).prior();
+				let prior =
+					(!prior.locked().is_zero()).then(|| (prior.unlocks_at(), prior.locked()));
+				ClassLock { class, amount, prior }
+			})
+			.collect()
+	}
+
 	/// Rejig the lock on an account. It will never get more stringent (since that would indicate
 	/// a security hole) but may be reduced from what they are currently.
 	fn update_lock(class: &ClassOf<T, I>, who: &T::AccountId) {
@@ -672,3 +793,18 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		}
 	}
 }
+
+sp_api::decl_runtime_apis! {
+	/// Exposes the conviction voting locks of accounts.
+	pub trait ConvictionVotingApi<AccountId, Class, Balance, BlockNumber>
+	where
+		AccountId: Codec,
+		Class: Codec,
+		Balance: Codec,
+		BlockNumber: Codec,
+	{
+		/// The locks of `who` for each class of polls, along with the prior lock of the class
+		/// which will expire.
+		fn class_locks_for(who: AccountId) -> Vec<ClassLock<Class, Balance, BlockNumber>>;
+	}
+}
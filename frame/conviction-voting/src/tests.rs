@@ -26,7 +26,7 @@ use frame_support::{
 use sp_core::H256;
 use sp_runtime::{
 	testing::Header,
-	traits::{BlakeTwo256, IdentityLookup},
+	traits::{BlakeTwo256, Dispatchable, IdentityLookup},
 };
 
 use super::*;
@@ -85,6 +85,10 @@ impl frame_system::Config for Test {
 impl pallet_balances::Config for Test {
 	type MaxReserves = ();
 	type ReserveIdentifier = [u8; 8];
+	type HoldIdentifier = ();
+	type FreezeIdentifier = ();
+	type MaxHolds = ();
+	type MaxFreezes = ();
 	type MaxLocks = ConstU32<10>;
 	type Balance = u64;
 	type RuntimeEvent = RuntimeEvent;
@@ -881,3 +885,160 @@ fn errors_with_remove_vote_work() {
 		);
 	});
 }
+
+#[test]
+fn delegate_multiple_works() {
+	new_test_ext().execute_with(|| {
+		Polls::set(
+			vec![
+				(0, Ongoing(Tally::new(0), 0)),
+				(1, Ongoing(Tally::new(0), 1)),
+				(2, Ongoing(Tally::new(0), 2)),
+			]
+			.into_iter()
+			.collect(),
+		);
+		assert_ok!(Voting::delegate_multiple(
+			RuntimeOrigin::signed(1),
+			vec![0, 2].try_into().unwrap(),
+			2,
+			Conviction::Locked1x,
+			5
+		));
+		System::assert_has_event(Event::Delegated(1, 2).into());
+		assert!(matches!(
+			VotingFor::<Test>::get(1, 0),
+			pallet_conviction_voting::Voting::Delegating(..)
+		));
+		assert!(matches!(
+			VotingFor::<Test>::get(1, 1),
+			pallet_conviction_voting::Voting::Casting(..)
+		));
+		assert!(matches!(
+			VotingFor::<Test>::get(1, 2),
+			pallet_conviction_voting::Voting::Delegating(..)
+		));
+		assert_eq!(Balances::usable_balance(1), 5);
+
+		assert_ok!(Voting::vote(RuntimeOrigin::signed(2), 0, aye(10, 0)));
+		assert_ok!(Voting::vote(RuntimeOrigin::signed(2), 1, aye(10, 0)));
+		assert_ok!(Voting::vote(RuntimeOrigin::signed(2), 2, nay(10, 0)));
+		assert_eq!(tally(0), Tally::from_parts(6, 0, 15));
+		assert_eq!(tally(1), Tally::from_parts(1, 0, 10));
+		assert_eq!(tally(2), Tally::from_parts(0, 6, 0));
+	});
+}
+
+#[test]
+fn delegate_multiple_fails_as_a_whole() {
+	new_test_ext().execute_with(|| {
+		Polls::set(vec![(1, Ongoing(Tally::new(0), 1))].into_iter().collect());
+		assert_ok!(Voting::vote(RuntimeOrigin::signed(1), 1, aye(10, 0)));
+
+		let call = RuntimeCall::Voting(Call::delegate_multiple {
+			classes: vec![0, 1].try_into().unwrap(),
+			to: 2,
+			conviction: Conviction::Locked1x,
+			balance: 5,
+		});
+		assert_noop!(call.dispatch(RuntimeOrigin::signed(1)), Error::<Test>::AlreadyVoting);
+		assert!(matches!(
+			VotingFor::<Test>::get(1, 0),
+			pallet_conviction_voting::Voting::Casting(..)
+		));
+	});
+}
+
+#[test]
+fn vote_multiple_works() {
+	new_test_ext().execute_with(|| {
+		Polls::set(
+			vec![
+				(0, Ongoing(Tally::new(0), 0)),
+				(1, Ongoing(Tally::new(0), 1)),
+				(2, Ongoing(Tally::new(0), 1)),
+			]
+			.into_iter()
+			.collect(),
+		);
+		assert_ok!(Voting::vote_multiple(
+			RuntimeOrigin::signed(5),
+			vec![(0, split(10, 5)), (1, aye(20, 1)), (2, split_abstain(5, 5, 30))]
+				.try_into()
+				.unwrap()
+		));
+		assert_eq!(tally(0), Tally::from_parts(1, 0, 10));
+		assert_eq!(tally(1), Tally::from_parts(20, 0, 20));
+		assert_eq!(tally(2), Tally::from_parts(0, 0, 35));
+		assert_eq!(ClassLocksFor::<Test>::get(5).into_inner(), vec![(0, 15), (1, 40)]);
+		assert_eq!(Balances::usable_balance(5), 10);
+
+		// Votes may be changed and the lock is kept.
+		assert_ok!(Voting::vote_multiple(
+			RuntimeOrigin::signed(5),
+			vec![(1, nay(10, 0)), (2, aye(10, 0))].try_into().unwrap()
+		));
+		assert_eq!(tally(1), Tally::from_parts(0, 1, 0));
+		assert_eq!(tally(2), Tally::from_parts(1, 0, 10));
+		assert_eq!(Balances::usable_balance(5), 10);
+	});
+}
+
+#[test]
+fn vote_multiple_fails_as_a_whole() {
+	new_test_ext().execute_with(|| {
+		let call = RuntimeCall::Voting(Call::vote_multiple {
+			votes: vec![(3, aye(10, 0)), (1, aye(10, 0))].try_into().unwrap(),
+		});
+		assert_noop!(call.dispatch(RuntimeOrigin::signed(1)), Error::<Test>::NotOngoing);
+
+		let call = RuntimeCall::Voting(Call::vote_multiple {
+			votes: vec![(3, aye(10, 0)), (3, aye(20, 0))].try_into().unwrap(),
+		});
+		assert_noop!(call.dispatch(RuntimeOrigin::signed(1)), Error::<Test>::InsufficientFunds);
+		assert!(matches!(
+			VotingFor::<Test>::get(1, class(3)),
+			pallet_conviction_voting::Voting::Casting(Casting { votes, .. }) if votes.is_empty()
+		));
+	});
+}
+
+#[test]
+fn class_locks_for_works() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(Voting::class_locks_for(&1), vec![]);
+		assert_ok!(Voting::delegate(RuntimeOrigin::signed(1), 0, 2, Conviction::Locked1x, 5));
+		assert_ok!(Voting::delegate(RuntimeOrigin::signed(1), 1, 2, Conviction::Locked2x, 5));
+		assert_ok!(Voting::delegate(RuntimeOrigin::signed(1), 2, 2, Conviction::Locked1x, 10));
+		assert_eq!(
+			Voting::class_locks_for(&1),
+			vec![
+				ClassLock { class: 0, amount: 5, prior: None },
+				ClassLock { class: 1, amount: 5, prior: None },
+				ClassLock { class: 2, amount: 10, prior: None },
+			]
+		);
+
+		assert_ok!(Voting::undelegate(RuntimeOrigin::signed(1), 0));
+		assert_ok!(Voting::undelegate(RuntimeOrigin::signed(1), 1));
+		assert_ok!(Voting::undelegate(RuntimeOrigin::signed(1), 2));
+		assert_eq!(
+			Voting::class_locks_for(&1),
+			vec![
+				ClassLock { class: 0, amount: 5, prior: Some((4, 5)) },
+				ClassLock { class: 1, amount: 5, prior: Some((7, 5)) },
+				ClassLock { class: 2, amount: 10, prior: Some((4, 10)) },
+			]
+		);
+
+		run_to(4);
+		assert_ok!(Voting::unlock(RuntimeOrigin::signed(1), 0, 1));
+		assert_eq!(
+			Voting::class_locks_for(&1),
+			vec![
+				ClassLock { class: 1, amount: 5, prior: Some((7, 5)) },
+				ClassLock { class: 2, amount: 10, prior: Some((4, 10)) },
+			]
+		);
+	});
+}
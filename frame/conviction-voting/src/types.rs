@@ -254,6 +254,18 @@ impl<Balance: Saturating> Saturating for Delegations<Balance> {
 	}
 }
 
+/// The lock of an account for a class of polls.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct ClassLock<Class, Balance, BlockNumber> {
+	/// The class of polls.
+	pub class: Class,
+	/// The amount of balance currently locked for the class.
+	pub amount: Balance,
+	/// The lock left over from past voting or delegating on the class, as the block number from
+	/// which it may be unlocked and the amount it locks, if there is any.
+	pub prior: Option<(BlockNumber, Balance)>,
+}
+
 /// Whether an `unvote` operation is able to make actions that are not strictly always in the
 /// interest of an account.
 pub enum UnvoteScope {
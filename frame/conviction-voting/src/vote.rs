@@ -141,6 +141,11 @@ impl<BlockNumber: Ord + Copy + Zero, Balance: Ord + Copy + Zero> PriorLock<Block
 		self.1
 	}
 
+	/// The block number from which the lock may be removed.
+	pub fn unlocks_at(&self) -> BlockNumber {
+		self.0
+	}
+
 	pub fn rejig(&mut self, now: BlockNumber) {
 		if now >= self.0 {
 			self.0 = Zero::zero();
@@ -239,6 +244,14 @@ where
 		AsMut::<PriorLock<BlockNumber, Balance>>::as_mut(self).rejig(now);
 	}
 
+	/// The lock left over from past voting or delegating activity.
+	pub fn prior(&self) -> &PriorLock<BlockNumber, Balance> {
+		match self {
+			Voting::Casting(Casting { prior, .. }) => prior,
+			Voting::Delegating(Delegating { prior, .. }) => prior,
+		}
+	}
+
 	/// The amount of this account's balance that must currently be locked due to voting.
 	pub fn locked_balance(&self) -> Balance {
 		match self {
@@ -136,16 +136,18 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: ConvictionVoting ClassLocksFor (max_values: None, max_size: Some(59), added: 2534, mode: MaxEncodedLen)
 	/// Storage: Balances Locks (r:1 w:1)
 	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	/// Storage: ReferendaTracks TrackIds (r:1 w:0)
+	/// Proof: ReferendaTracks TrackIds (max_values: Some(1), max_size: Some(65), added: 560, mode: MaxEncodedLen)
 	/// The range of component `r` is `[0, 1]`.
 	fn delegate(r: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `272 + r * (1689 ±0)`
-		//  Estimated: `176657 + r * (110917 ±0)`
+		//  Estimated: `177217 + r * (110917 ±0)`
 		// Minimum execution time: 33_246 nanoseconds.
-		Weight::from_parts(34_560_391, 176657)
+		Weight::from_parts(34_560_391, 177217)
 			// Standard Error: 63_925
 			.saturating_add(Weight::from_parts(34_500_408, 0).saturating_mul(r.into()))
-			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
 			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(r.into())))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(r.into())))
@@ -267,16 +269,18 @@ impl WeightInfo for () {
 	/// Proof: ConvictionVoting ClassLocksFor (max_values: None, max_size: Some(59), added: 2534, mode: MaxEncodedLen)
 	/// Storage: Balances Locks (r:1 w:1)
 	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	/// Storage: ReferendaTracks TrackIds (r:1 w:0)
+	/// Proof: ReferendaTracks TrackIds (max_values: Some(1), max_size: Some(65), added: 560, mode: MaxEncodedLen)
 	/// The range of component `r` is `[0, 1]`.
 	fn delegate(r: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `272 + r * (1689 ±0)`
-		//  Estimated: `176657 + r * (110917 ±0)`
+		//  Estimated: `177217 + r * (110917 ±0)`
 		// Minimum execution time: 33_246 nanoseconds.
-		Weight::from_parts(34_560_391, 176657)
+		Weight::from_parts(34_560_391, 177217)
 			// Standard Error: 63_925
 			.saturating_add(Weight::from_parts(34_500_408, 0).saturating_mul(r.into()))
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
 			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(r.into())))
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
 			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(r.into())))
@@ -116,6 +116,7 @@ impl pallet_preimage::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = ();
 	type Currency = Balances;
+	type RuntimeHoldReason = RuntimeHoldReason;
 	type ManagerOrigin = EnsureRoot<u64>;
 	type BaseDeposit = ConstU64<0>;
 	type ByteDeposit = ConstU64<0>;
@@ -137,6 +138,10 @@ impl pallet_scheduler::Config for Test {
 impl pallet_balances::Config for Test {
 	type MaxReserves = ();
 	type ReserveIdentifier = [u8; 8];
+	type HoldIdentifier = RuntimeHoldReason;
+	type FreezeIdentifier = ();
+	type MaxHolds = ConstU32<1>;
+	type MaxFreezes = ();
 	type MaxLocks = ConstU32<10>;
 	type Balance = u64;
 	type RuntimeEvent = RuntimeEvent;
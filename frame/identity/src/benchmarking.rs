@@ -46,14 +46,16 @@ fn add_registrars<T: Config>(r: u32) -> Result<(), &'static str> {
 			.expect("RegistrarOrigin has no successful origin required for the benchmark");
 		Identity::<T>::add_registrar(registrar_origin, registrar_lookup)?;
 		Identity::<T>::set_fee(RawOrigin::Signed(registrar.clone()).into(), i, 10u32.into())?;
-		let fields =
-			IdentityFields(
-				IdentityField::Display |
-					IdentityField::Legal | IdentityField::Web |
-					IdentityField::Riot | IdentityField::Email |
-					IdentityField::PgpFingerprint |
-					IdentityField::Image | IdentityField::Twitter,
-			);
+		let fields = IdentityFields(
+			IdentityField::Display |
+				IdentityField::Legal |
+				IdentityField::Web |
+				IdentityField::Riot |
+				IdentityField::Email |
+				IdentityField::PgpFingerprint |
+				IdentityField::Image |
+				IdentityField::Twitter,
+		);
 		Identity::<T>::set_fields(RawOrigin::Signed(registrar.clone()).into(), i, fields)?;
 	}
 
@@ -118,6 +120,74 @@ fn create_identity_info<T: Config>(num_fields: u32) -> IdentityInfo<T::MaxAdditi
 	}
 }
 
+// Makes `authority` a username authority with the longest suffix allowed.
+fn setup_username_authority<T: Config>(authority: &T::AccountId) -> Result<(), BenchmarkError> {
+	let origin = T::UsernameAuthorityOrigin::try_successful_origin()
+		.map_err(|_| BenchmarkError::Weightless)?;
+	let suffix = vec![b'a'; T::MaxSuffixLength::get() as usize];
+	Identity::<T>::add_username_authority(
+		origin,
+		T::Lookup::unlookup(authority.clone()),
+		suffix,
+		10,
+	)?;
+	Ok(())
+}
+
+// The longest username an authority added by `setup_username_authority` can grant, without and
+// with its suffix.
+fn longest_username<T: Config>() -> (Vec<u8>, Username<T>) {
+	let suffix_len = T::MaxSuffixLength::get() as usize;
+	let len = (T::MaxUsernameLength::get() as usize).saturating_sub(suffix_len + 1).max(1);
+	let name = vec![b'b'; len];
+	let full = [&name[..], b".", &vec![b'a'; suffix_len][..]].concat();
+	(name, full.try_into().expect("username is within bounds; qed"))
+}
+
+// Grants `who` the longest username, returning it in full.
+fn grant_username<T: Config>(who: &T::AccountId) -> Result<Username<T>, BenchmarkError> {
+	let authority: T::AccountId = account("authority", 0, SEED);
+	setup_username_authority::<T>(&authority)?;
+	let (name, username) = longest_username::<T>();
+	Identity::<T>::set_username_for(
+		RawOrigin::Signed(authority).into(),
+		T::Lookup::unlookup(who.clone()),
+		name,
+	)?;
+	Ok(username)
+}
+
+// Grants `who` `n` distinct usernames of the longest length and accepts them, returning them in
+// full.
+fn accept_usernames<T: Config>(
+	who: &T::AccountId,
+	n: u32,
+) -> Result<Vec<Username<T>>, BenchmarkError> {
+	let authority: T::AccountId = account("authority", 0, SEED);
+	let mut usernames = Vec::new();
+	for i in 0..n {
+		setup_username_authority::<T>(&authority)?;
+		let (mut name, _) = longest_username::<T>();
+		name[0] = b'a' + (i % 26) as u8;
+		if name.len() > 1 {
+			name[1] = b'a' + (i / 26 % 26) as u8;
+		}
+		let suffix = vec![b'a'; T::MaxSuffixLength::get() as usize];
+		let username: Username<T> = [&name[..], b".", &suffix[..]]
+			.concat()
+			.try_into()
+			.expect("username is within bounds; qed");
+		Identity::<T>::set_username_for(
+			RawOrigin::Signed(authority.clone()).into(),
+			T::Lookup::unlookup(who.clone()),
+			name,
+		)?;
+		Identity::<T>::accept_username(RawOrigin::Signed(who.clone()).into(), username.clone())?;
+		usernames.push(username);
+	}
+	Ok(usernames)
+}
+
 benchmarks! {
 	add_registrar {
 		let r in 1 .. T::MaxRegistrars::get() - 1 => add_registrars::<T>(r)?;
@@ -365,6 +435,7 @@ benchmarks! {
 		let r in 1 .. T::MaxRegistrars::get() => add_registrars::<T>(r)?;
 		let s in 0 .. T::MaxSubAccounts::get();
 		let x in 0 .. T::MaxAdditionalFields::get();
+		let u in 0 .. T::MaxUsernamesPerAccount::get();
 
 		let target: T::AccountId = account("target", 0, SEED);
 		let target_origin: <T as frame_system::Config>::RuntimeOrigin = RawOrigin::Signed(target.clone()).into();
@@ -391,11 +462,15 @@ benchmarks! {
 			)?;
 		}
 		ensure!(IdentityOf::<T>::contains_key(&target), "Identity not set");
+		let usernames = accept_usernames::<T>(&target, u)?;
 		let origin =
 			T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
 	}: _<T::RuntimeOrigin>(origin, target_lookup)
 	verify {
 		ensure!(!IdentityOf::<T>::contains_key(&target), "Identity not removed");
+		for username in usernames {
+			ensure!(!AccountOfUsername::<T>::contains_key(&username), "Username not removed");
+		}
 	}
 
 	add_sub {
@@ -448,5 +523,66 @@ benchmarks! {
 		ensure!(!SuperOf::<T>::contains_key(&caller), "Sub not removed");
 	}
 
+	add_username_authority {
+		let origin = T::UsernameAuthorityOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+		let authority: T::AccountId = account("authority", 0, SEED);
+		let suffix = vec![b'a'; T::MaxSuffixLength::get() as usize];
+	}: _<T::RuntimeOrigin>(origin, T::Lookup::unlookup(authority.clone()), suffix, 10)
+	verify {
+		assert_last_event::<T>(Event::<T>::AuthorityAdded { authority }.into());
+	}
+
+	remove_username_authority {
+		let authority: T::AccountId = account("authority", 0, SEED);
+		setup_username_authority::<T>(&authority)?;
+		let origin = T::UsernameAuthorityOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(origin, T::Lookup::unlookup(authority.clone()))
+	verify {
+		assert_last_event::<T>(Event::<T>::AuthorityRemoved { authority }.into());
+	}
+
+	set_username_for {
+		let authority: T::AccountId = account("authority", 0, SEED);
+		setup_username_authority::<T>(&authority)?;
+		let who: T::AccountId = account("target", 0, SEED);
+		let (name, username) = longest_username::<T>();
+	}: _(RawOrigin::Signed(authority), T::Lookup::unlookup(who), name)
+	verify {
+		ensure!(PendingUsernames::<T>::contains_key(&username), "Username not queued");
+	}
+
+	accept_username {
+		let caller: T::AccountId = whitelisted_caller();
+		let username = grant_username::<T>(&caller)?;
+	}: _(RawOrigin::Signed(caller.clone()), username.clone())
+	verify {
+		assert_last_event::<T>(Event::<T>::PrimaryUsernameSet { who: caller, username }.into());
+	}
+
+	remove_expired_approval {
+		let who: T::AccountId = account("target", 0, SEED);
+		let username = grant_username::<T>(&who)?;
+		let now = frame_system::Pallet::<T>::block_number();
+		frame_system::Pallet::<T>::set_block_number(
+			now + T::PendingUsernameExpiration::get() + 1u32.into()
+		);
+		let caller: T::AccountId = whitelisted_caller();
+	}: _(RawOrigin::Signed(caller), username.clone())
+	verify {
+		assert_last_event::<T>(Event::<T>::PendingUsernameExpired { who, username }.into());
+	}
+
+	set_primary_username {
+		let caller: T::AccountId = whitelisted_caller();
+		let username = grant_username::<T>(&caller)?;
+		Identity::<T>::accept_username(RawOrigin::Signed(caller.clone()).into(), username.clone())?;
+		UsernameOf::<T>::remove(&caller);
+	}: _(RawOrigin::Signed(caller.clone()), username.clone())
+	verify {
+		assert_last_event::<T>(Event::<T>::PrimaryUsernameSet { who: caller, username }.into());
+	}
+
 	impl_benchmark_test_suite!(Identity, crate::tests::new_test_ext(), crate::tests::Test);
 }
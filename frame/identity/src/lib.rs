@@ -37,6 +37,12 @@
 //! All accounts may also have a limited number of sub-accounts which may be specified by the owner;
 //! by definition, these have equivalent ownership and each has an individual name.
 //!
+//! Separately, username authorities may grant unique usernames ending in their allotted suffix,
+//! e.g. `alice.infra`. A granted username must be accepted by its account before it expires, and
+//! the first one accepted becomes the account's primary username. Usernames can be resolved back
+//! to their accounts, including through [`UsernameLookup`]. Killing the identity of an account
+//! removes all of its usernames.
+//!
 //! The number of registrars should be limited, and the deposit made sufficiently large, to ensure
 //! no state-bloat attack is viable.
 //!
@@ -58,14 +64,24 @@
 //! * `rename_sub` - Rename a sub-identity of an identity.
 //! * `quit_sub` - Remove a sub-identity of an identity (called by the sub-identity).
 //!
+//! #### For general users with usernames
+//! * `accept_username` - Accept a username granted by an authority.
+//! * `set_primary_username` - Choose which of the sender's usernames is the primary one.
+//! * `remove_expired_approval` - Remove a granted username which was not accepted in time.
+//!
 //! #### For registrars
 //! * `set_fee` - Set the fee required to be paid for a judgement to be given by the registrar.
 //! * `set_fields` - Set the fields that a registrar cares about in their judgements.
 //! * `provide_judgement` - Provide a judgement to an identity.
 //!
+//! #### For username authorities
+//! * `set_username_for` - Grant a username, to be accepted by the account it is granted to.
+//!
 //! #### For super-users
 //! * `add_registrar` - Add a new registrar to the system.
-//! * `kill_identity` - Forcibly remove the associated identity; the deposit is lost.
+//! * `kill_identity` - Forcibly remove the associated identity and usernames; the deposit is lost.
+//! * `add_username_authority` - Allow an account to grant usernames with a given suffix.
+//! * `remove_username_authority` - Revoke the right of an account to grant usernames.
 //!
 //! [`Call`]: ./enum.Call.html
 //! [`Config`]: ./trait.Config.html
@@ -78,15 +94,23 @@ mod tests;
 mod types;
 pub mod weights;
 
-use frame_support::traits::{BalanceStatus, Currency, OnUnbalanced, ReservableCurrency};
-use sp_runtime::traits::{AppendZerosInput, Hash, Saturating, StaticLookup, Zero};
-use sp_std::prelude::*;
+use codec::Codec;
+use frame_support::{
+	traits::{BalanceStatus, Currency, Get, OnUnbalanced, ReservableCurrency},
+	BoundedVec,
+};
+use sp_runtime::{
+	traits::{AppendZerosInput, Hash, LookupError, Saturating, StaticLookup, Zero},
+	MultiAddress,
+};
+use scale_info::TypeInfo;
+use sp_std::{fmt::Debug, marker::PhantomData, prelude::*};
 pub use weights::WeightInfo;
 
 pub use pallet::*;
 pub use types::{
-	Data, IdentityField, IdentityFields, IdentityInfo, Judgement, RegistrarIndex, RegistrarInfo,
-	Registration,
+	AuthorityProperties, Data, IdentityField, IdentityFields, IdentityInfo, Judgement,
+	RegistrarIndex, RegistrarInfo, Registration,
 };
 
 type BalanceOf<T> =
@@ -96,6 +120,11 @@ type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<
 >>::NegativeImbalance;
 type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
 
+/// The suffix allotted to a username authority.
+pub type Suffix<T> = BoundedVec<u8, <T as Config>::MaxSuffixLength>;
+/// A full username, including the suffix of the authority which granted it.
+pub type Username<T> = BoundedVec<u8, <T as Config>::MaxUsernameLength>;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -147,6 +176,25 @@ pub mod pallet {
 		/// The origin which may add or remove registrars. Root can always do this.
 		type RegistrarOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
+		/// The origin which may add or remove username authorities.
+		type UsernameAuthorityOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The number of blocks within which a granted username must be accepted.
+		#[pallet::constant]
+		type PendingUsernameExpiration: Get<Self::BlockNumber>;
+
+		/// The maximum length of a suffix.
+		#[pallet::constant]
+		type MaxSuffixLength: Get<u32>;
+
+		/// The maximum length of a username, including its suffix and the dot before it.
+		#[pallet::constant]
+		type MaxUsernameLength: Get<u32>;
+
+		/// The maximum number of usernames an account may accept.
+		#[pallet::constant]
+		type MaxUsernamesPerAccount: Get<u32>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -202,6 +250,49 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// The accounts allowed to grant usernames, together with their suffix and allocation.
+	///
+	/// TWOX-NOTE: OK ― `AccountId` is a secure hash.
+	#[pallet::storage]
+	#[pallet::getter(fn username_authority)]
+	pub(super) type UsernameAuthorities<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, AuthorityProperties<Suffix<T>>, OptionQuery>;
+
+	/// The account to which each accepted username belongs.
+	#[pallet::storage]
+	#[pallet::getter(fn account_of_username)]
+	pub(super) type AccountOfUsername<T: Config> =
+		StorageMap<_, Blake2_128Concat, Username<T>, T::AccountId, OptionQuery>;
+
+	/// The primary username of an account. Usernames given by any other means, such as a
+	/// transfer to `alice.infra`, resolve through [`AccountOfUsername`] instead.
+	///
+	/// TWOX-NOTE: OK ― `AccountId` is a secure hash.
+	#[pallet::storage]
+	#[pallet::getter(fn username_of)]
+	pub(super) type UsernameOf<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, Username<T>, OptionQuery>;
+
+	/// All usernames accepted by an account, including its primary username.
+	///
+	/// TWOX-NOTE: OK ― `AccountId` is a secure hash.
+	#[pallet::storage]
+	#[pallet::getter(fn usernames_of)]
+	pub(super) type UsernamesOf<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		BoundedVec<Username<T>, T::MaxUsernamesPerAccount>,
+		ValueQuery,
+	>;
+
+	/// Usernames granted by an authority but not yet accepted, together with the account they
+	/// were granted to and the block after which the grant expires.
+	#[pallet::storage]
+	#[pallet::getter(fn pending_username)]
+	pub(super) type PendingUsernames<T: Config> =
+		StorageMap<_, Blake2_128Concat, Username<T>, (T::AccountId, T::BlockNumber), OptionQuery>;
+
 	#[pallet::error]
 	pub enum Error<T> {
 		/// Too many subs-accounts.
@@ -240,6 +331,28 @@ pub mod pallet {
 		JudgementForDifferentIdentity,
 		/// Error that occurs when there is an issue paying for judgement.
 		JudgementPaymentFailed,
+		/// The suffix is empty, too long or contains characters other than lowercase letters
+		/// and digits.
+		InvalidSuffix,
+		/// The sender is not a username authority.
+		NotUsernameAuthority,
+		/// The authority cannot grant any more usernames.
+		NoAllocation,
+		/// The username is empty, too long or contains characters other than lowercase letters
+		/// and digits.
+		InvalidUsername,
+		/// The username is already taken or awaiting acceptance.
+		UsernameTaken,
+		/// The username was not found.
+		NoUsername,
+		/// The username belongs to another account.
+		NotUsernameOwner,
+		/// The grant of the username has expired.
+		UsernameExpired,
+		/// The grant of the username has not expired yet.
+		NotExpired,
+		/// The account cannot accept any more usernames.
+		TooManyUsernames,
 	}
 
 	#[pallet::event]
@@ -266,6 +379,18 @@ pub mod pallet {
 		/// A sub-identity was cleared, and the given deposit repatriated from the
 		/// main identity account to the sub-identity account.
 		SubIdentityRevoked { sub: T::AccountId, main: T::AccountId, deposit: BalanceOf<T> },
+		/// An account was allowed to grant usernames with the given suffix.
+		AuthorityAdded { authority: T::AccountId },
+		/// An account may no longer grant usernames.
+		AuthorityRemoved { authority: T::AccountId },
+		/// A username was granted and awaits acceptance by `who` until `expiration`.
+		UsernameQueued { who: T::AccountId, username: Username<T>, expiration: T::BlockNumber },
+		/// A username was accepted by the account it was granted to.
+		UsernameSet { who: T::AccountId, username: Username<T> },
+		/// A username became the primary username of an account.
+		PrimaryUsernameSet { who: T::AccountId, username: Username<T> },
+		/// A granted username was not accepted in time and was removed.
+		PendingUsernameExpired { who: T::AccountId, username: Username<T> },
 	}
 
 	#[pallet::call]
@@ -794,7 +919,8 @@ pub mod pallet {
 				.into())
 		}
 
-		/// Remove an account's identity and sub-account information and slash the deposits.
+		/// Remove an account's identity, sub-account information and usernames and slash the
+		/// deposits.
 		///
 		/// Payment: Reserved balances from `set_subs` and `set_identity` are slashed and handled by
 		/// `Slash`. Verification request deposits are not returned; they should be cancelled
@@ -808,15 +934,17 @@ pub mod pallet {
 		/// Emits `IdentityKilled` if successful.
 		///
 		/// ## Complexity
-		/// - `O(R + S + X)`
+		/// - `O(R + S + X + U)`
 		///   - where `R` registrar-count (governance-bounded).
 		///   - where `S` subs-count (hard- and deposit-bounded).
 		///   - where `X` additional-field-count (deposit-bounded and code-bounded).
+		///   - where `U` username-count (hard-bounded).
 		#[pallet::call_index(10)]
 		#[pallet::weight(T::WeightInfo::kill_identity(
 			T::MaxRegistrars::get(), // R
 			T::MaxSubAccounts::get(), // S
 			T::MaxAdditionalFields::get(), // X
+			T::MaxUsernamesPerAccount::get(), // U
 		))]
 		pub fn kill_identity(
 			origin: OriginFor<T>,
@@ -833,6 +961,11 @@ pub mod pallet {
 			for sub in sub_ids.iter() {
 				<SuperOf<T>>::remove(sub);
 			}
+			<UsernameOf<T>>::remove(&target);
+			let usernames = <UsernamesOf<T>>::take(&target);
+			for username in usernames.iter() {
+				<AccountOfUsername<T>>::remove(username);
+			}
 			// Slash their deposit from them.
 			T::Slashed::on_unbalanced(T::Currency::slash_reserved(&target, deposit).0);
 
@@ -842,6 +975,7 @@ pub mod pallet {
 				id.judgements.len() as u32,      // R
 				sub_ids.len() as u32,            // S
 				id.info.additional.len() as u32, // X
+				usernames.len() as u32,          // U
 			))
 			.into())
 		}
@@ -960,6 +1094,164 @@ pub mod pallet {
 			});
 			Ok(())
 		}
+
+		/// Allow an account to grant usernames ending in `suffix`, or change the suffix and
+		/// allocation of an existing authority.
+		///
+		/// The dispatch origin for this call must be `T::UsernameAuthorityOrigin`.
+		///
+		/// - `authority`: The account which will grant usernames.
+		/// - `suffix`: The suffix of the usernames it grants, without the dot.
+		/// - `allocation`: The number of usernames it may grant.
+		#[pallet::call_index(15)]
+		#[pallet::weight(T::WeightInfo::add_username_authority())]
+		pub fn add_username_authority(
+			origin: OriginFor<T>,
+			authority: AccountIdLookupOf<T>,
+			suffix: Vec<u8>,
+			allocation: u32,
+		) -> DispatchResult {
+			T::UsernameAuthorityOrigin::ensure_origin(origin)?;
+			let authority = T::Lookup::lookup(authority)?;
+			ensure!(Self::is_valid_name(&suffix), Error::<T>::InvalidSuffix);
+			let suffix: Suffix<T> = suffix.try_into().map_err(|_| Error::<T>::InvalidSuffix)?;
+
+			UsernameAuthorities::<T>::insert(
+				&authority,
+				AuthorityProperties { suffix, allocation },
+			);
+			Self::deposit_event(Event::AuthorityAdded { authority });
+			Ok(())
+		}
+
+		/// Revoke the right of an account to grant usernames. Usernames it already granted are
+		/// unaffected.
+		///
+		/// The dispatch origin for this call must be `T::UsernameAuthorityOrigin`.
+		#[pallet::call_index(16)]
+		#[pallet::weight(T::WeightInfo::remove_username_authority())]
+		pub fn remove_username_authority(
+			origin: OriginFor<T>,
+			authority: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			T::UsernameAuthorityOrigin::ensure_origin(origin)?;
+			let authority = T::Lookup::lookup(authority)?;
+			UsernameAuthorities::<T>::take(&authority).ok_or(Error::<T>::NotUsernameAuthority)?;
+			Self::deposit_event(Event::AuthorityRemoved { authority });
+			Ok(())
+		}
+
+		/// Grant `who` a username, formed from `username` followed by a dot and the suffix of the
+		/// sender. It must be accepted by `who` within `T::PendingUsernameExpiration` blocks.
+		///
+		/// The dispatch origin for this call must be _Signed_ by a username authority with some
+		/// allocation left, which this consumes.
+		///
+		/// Emits `UsernameQueued` if successful.
+		#[pallet::call_index(17)]
+		#[pallet::weight(T::WeightInfo::set_username_for())]
+		pub fn set_username_for(
+			origin: OriginFor<T>,
+			who: AccountIdLookupOf<T>,
+			username: Vec<u8>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let who = T::Lookup::lookup(who)?;
+			ensure!(Self::is_valid_name(&username), Error::<T>::InvalidUsername);
+
+			let username = UsernameAuthorities::<T>::try_mutate(
+				&sender,
+				|maybe_authority| -> Result<Username<T>, DispatchError> {
+					let authority =
+						maybe_authority.as_mut().ok_or(Error::<T>::NotUsernameAuthority)?;
+					authority.allocation =
+						authority.allocation.checked_sub(1).ok_or(Error::<T>::NoAllocation)?;
+					let full = [&username[..], b".", &authority.suffix[..]].concat();
+					Ok(full.try_into().map_err(|_| Error::<T>::InvalidUsername)?)
+				},
+			)?;
+			ensure!(
+				!AccountOfUsername::<T>::contains_key(&username) &&
+					!PendingUsernames::<T>::contains_key(&username),
+				Error::<T>::UsernameTaken
+			);
+
+			let expiration = frame_system::Pallet::<T>::block_number()
+				.saturating_add(T::PendingUsernameExpiration::get());
+			PendingUsernames::<T>::insert(&username, (who.clone(), expiration));
+			Self::deposit_event(Event::UsernameQueued { who, username, expiration });
+			Ok(())
+		}
+
+		/// Accept a username granted to the sender. It becomes the sender's primary username if
+		/// it has none.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the account the username was
+		/// granted to.
+		///
+		/// Emits `UsernameSet` if successful.
+		#[pallet::call_index(18)]
+		#[pallet::weight(T::WeightInfo::accept_username())]
+		pub fn accept_username(origin: OriginFor<T>, username: Username<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let (approved_for, expiration) =
+				PendingUsernames::<T>::get(&username).ok_or(Error::<T>::NoUsername)?;
+			ensure!(approved_for == who, Error::<T>::NotUsernameOwner);
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(now <= expiration, Error::<T>::UsernameExpired);
+
+			UsernamesOf::<T>::try_mutate(&who, |usernames| usernames.try_push(username.clone()))
+				.map_err(|_| Error::<T>::TooManyUsernames)?;
+			PendingUsernames::<T>::remove(&username);
+			AccountOfUsername::<T>::insert(&username, &who);
+			Self::deposit_event(Event::UsernameSet {
+				who: who.clone(),
+				username: username.clone(),
+			});
+			if !UsernameOf::<T>::contains_key(&who) {
+				UsernameOf::<T>::insert(&who, &username);
+				Self::deposit_event(Event::PrimaryUsernameSet { who, username });
+			}
+			Ok(())
+		}
+
+		/// Remove a granted username which was not accepted before its grant expired. The
+		/// transaction fee is refunded.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		#[pallet::call_index(19)]
+		#[pallet::weight(T::WeightInfo::remove_expired_approval())]
+		pub fn remove_expired_approval(
+			origin: OriginFor<T>,
+			username: Username<T>,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			let (who, expiration) =
+				PendingUsernames::<T>::get(&username).ok_or(Error::<T>::NoUsername)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(now > expiration, Error::<T>::NotExpired);
+
+			PendingUsernames::<T>::remove(&username);
+			Self::deposit_event(Event::PendingUsernameExpired { who, username });
+			Ok(Pays::No.into())
+		}
+
+		/// Make one of the sender's usernames its primary username.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the account of the username.
+		///
+		/// Emits `PrimaryUsernameSet` if successful.
+		#[pallet::call_index(20)]
+		#[pallet::weight(T::WeightInfo::set_primary_username())]
+		pub fn set_primary_username(origin: OriginFor<T>, username: Username<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let account = AccountOfUsername::<T>::get(&username).ok_or(Error::<T>::NoUsername)?;
+			ensure!(account == who, Error::<T>::NotUsernameOwner);
+
+			UsernameOf::<T>::insert(&who, &username);
+			Self::deposit_event(Event::PrimaryUsernameSet { who, username });
+			Ok(())
+		}
 	}
 }
 
@@ -978,4 +1270,38 @@ impl<T: Config> Pallet<T> {
 		IdentityOf::<T>::get(who)
 			.map_or(false, |registration| (registration.info.fields().0.bits() & fields) == fields)
 	}
+
+	/// Check that a username or suffix is non-empty and made only of lowercase ASCII letters and
+	/// digits.
+	fn is_valid_name(name: &[u8]) -> bool {
+		!name.is_empty() && name.iter().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+	}
+}
+
+/// A [`StaticLookup`] which resolves usernames, given as the bytes of a [`MultiAddress::Raw`], to
+/// their accounts and leaves any other address to `Inner`.
+pub struct UsernameLookup<T, Inner>(PhantomData<(T, Inner)>);
+
+impl<T, Inner, AccountIndex> StaticLookup for UsernameLookup<T, Inner>
+where
+	T: Config,
+	Inner: StaticLookup<Source = MultiAddress<T::AccountId, AccountIndex>, Target = T::AccountId>,
+	MultiAddress<T::AccountId, AccountIndex>: Codec + Clone + PartialEq + Debug + TypeInfo,
+{
+	type Source = MultiAddress<T::AccountId, AccountIndex>;
+	type Target = T::AccountId;
+
+	fn lookup(s: Self::Source) -> Result<Self::Target, LookupError> {
+		match s {
+			MultiAddress::Raw(name) => {
+				let username: Username<T> = name.try_into().map_err(|_| LookupError)?;
+				AccountOfUsername::<T>::get(username).ok_or(LookupError)
+			},
+			s => Inner::lookup(s),
+		}
+	}
+
+	fn unlookup(t: Self::Target) -> Self::Source {
+		Inner::unlookup(t)
+	}
 }
@@ -22,7 +22,9 @@ use crate as pallet_identity;
 
 use codec::{Decode, Encode};
 use frame_support::{
-	assert_noop, assert_ok, ord_parameter_types, parameter_types,
+	assert_noop, assert_ok,
+	dispatch::Pays,
+	ord_parameter_types, parameter_types,
 	traits::{ConstU32, ConstU64, EitherOfDiverse},
 	BoundedVec,
 };
@@ -30,7 +32,7 @@ use frame_system::{EnsureRoot, EnsureSignedBy};
 use sp_core::H256;
 use sp_runtime::{
 	testing::Header,
-	traits::{BadOrigin, BlakeTwo256, IdentityLookup},
+	traits::{AccountIdLookup, BadOrigin, BlakeTwo256, IdentityLookup},
 };
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
@@ -84,6 +86,10 @@ impl pallet_balances::Config for Test {
 	type MaxLocks = ();
 	type MaxReserves = ();
 	type ReserveIdentifier = [u8; 8];
+	type HoldIdentifier = ();
+	type FreezeIdentifier = ();
+	type MaxHolds = ();
+	type MaxFreezes = ();
 	type WeightInfo = ();
 }
 
@@ -110,6 +116,11 @@ impl pallet_identity::Config for Test {
 	type MaxRegistrars = MaxRegistrars;
 	type RegistrarOrigin = EnsureOneOrRoot;
 	type ForceOrigin = EnsureTwoOrRoot;
+	type UsernameAuthorityOrigin = EnsureRoot<u64>;
+	type PendingUsernameExpiration = ConstU64<100>;
+	type MaxSuffixLength = ConstU32<7>;
+	type MaxUsernameLength = ConstU32<32>;
+	type MaxUsernamesPerAccount = ConstU32<2>;
 	type WeightInfo = ();
 }
 
@@ -620,3 +631,230 @@ fn test_has_identity() {
 		));
 	});
 }
+
+fn username(name: &[u8]) -> Username<Test> {
+	name.to_vec().try_into().unwrap()
+}
+
+fn grant_and_accept(who: u64, name: &[u8]) {
+	assert_ok!(Identity::set_username_for(RuntimeOrigin::signed(3), who, name.to_vec()));
+	let full = [name, b".infra"].concat();
+	assert_ok!(Identity::accept_username(RuntimeOrigin::signed(who), username(&full)));
+}
+
+#[test]
+fn username_authorities_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Identity::add_username_authority(RuntimeOrigin::signed(1), 3, b"infra".to_vec(), 2),
+			BadOrigin
+		);
+		for suffix in [&b""[..], b"Infra", b"in.fra", b"infrastructure"] {
+			assert_noop!(
+				Identity::add_username_authority(RuntimeOrigin::root(), 3, suffix.to_vec(), 2),
+				Error::<Test>::InvalidSuffix
+			);
+		}
+		assert_ok!(Identity::add_username_authority(
+			RuntimeOrigin::root(),
+			3,
+			b"infra".to_vec(),
+			2
+		));
+		assert_eq!(
+			Identity::username_authority(3),
+			Some(AuthorityProperties {
+				suffix: b"infra".to_vec().try_into().unwrap(),
+				allocation: 2
+			})
+		);
+
+		assert_noop!(
+			Identity::set_username_for(RuntimeOrigin::signed(4), 10, b"ten".to_vec()),
+			Error::<Test>::NotUsernameAuthority
+		);
+		for name in [&b""[..], b"Ten", b"ten.infra", &[b'a'; 27]] {
+			assert_noop!(
+				Identity::set_username_for(RuntimeOrigin::signed(3), 10, name.to_vec()),
+				Error::<Test>::InvalidUsername
+			);
+		}
+		assert_ok!(Identity::set_username_for(RuntimeOrigin::signed(3), 10, b"ten".to_vec()));
+		assert_noop!(
+			Identity::set_username_for(RuntimeOrigin::signed(3), 20, b"ten".to_vec()),
+			Error::<Test>::UsernameTaken
+		);
+		assert_ok!(Identity::set_username_for(RuntimeOrigin::signed(3), 20, b"twenty".to_vec()));
+		assert_noop!(
+			Identity::set_username_for(RuntimeOrigin::signed(3), 30, b"thirty".to_vec()),
+			Error::<Test>::NoAllocation
+		);
+
+		assert_ok!(Identity::remove_username_authority(RuntimeOrigin::root(), 3));
+		assert_noop!(
+			Identity::remove_username_authority(RuntimeOrigin::root(), 3),
+			Error::<Test>::NotUsernameAuthority
+		);
+		// Usernames already granted are kept.
+		assert_eq!(Identity::pending_username(username(b"ten.infra")), Some((10, 100)));
+	});
+}
+
+#[test]
+fn accepting_usernames_should_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Identity::add_username_authority(
+			RuntimeOrigin::root(),
+			3,
+			b"infra".to_vec(),
+			5
+		));
+		assert_ok!(Identity::set_username_for(RuntimeOrigin::signed(3), 10, b"ten".to_vec()));
+		System::assert_last_event(
+			Event::UsernameQueued { who: 10, username: username(b"ten.infra"), expiration: 101 }
+				.into(),
+		);
+		assert_noop!(
+			Identity::accept_username(RuntimeOrigin::signed(20), username(b"ten.infra")),
+			Error::<Test>::NotUsernameOwner
+		);
+		assert_noop!(
+			Identity::accept_username(RuntimeOrigin::signed(10), username(b"tenn.infra")),
+			Error::<Test>::NoUsername
+		);
+
+		// The first username accepted becomes the primary one.
+		assert_ok!(Identity::accept_username(RuntimeOrigin::signed(10), username(b"ten.infra")));
+		System::assert_last_event(
+			Event::PrimaryUsernameSet { who: 10, username: username(b"ten.infra") }.into(),
+		);
+		assert_eq!(Identity::account_of_username(username(b"ten.infra")), Some(10));
+		assert_eq!(Identity::username_of(10), Some(username(b"ten.infra")));
+		assert!(Identity::pending_username(username(b"ten.infra")).is_none());
+		assert_noop!(
+			Identity::set_username_for(RuntimeOrigin::signed(3), 20, b"ten".to_vec()),
+			Error::<Test>::UsernameTaken
+		);
+
+		grant_and_accept(10, b"tenth");
+		System::assert_last_event(
+			Event::UsernameSet { who: 10, username: username(b"tenth.infra") }.into(),
+		);
+		assert_eq!(Identity::username_of(10), Some(username(b"ten.infra")));
+
+		assert_noop!(
+			Identity::set_primary_username(RuntimeOrigin::signed(20), username(b"tenth.infra")),
+			Error::<Test>::NotUsernameOwner
+		);
+		assert_ok!(Identity::set_primary_username(
+			RuntimeOrigin::signed(10),
+			username(b"tenth.infra")
+		));
+		assert_eq!(Identity::username_of(10), Some(username(b"tenth.infra")));
+	});
+}
+
+#[test]
+fn pending_usernames_should_expire() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Identity::add_username_authority(
+			RuntimeOrigin::root(),
+			3,
+			b"infra".to_vec(),
+			5
+		));
+		assert_ok!(Identity::set_username_for(RuntimeOrigin::signed(3), 10, b"ten".to_vec()));
+		assert_noop!(
+			Identity::remove_expired_approval(RuntimeOrigin::signed(20), username(b"ten.infra")),
+			Error::<Test>::NotExpired
+		);
+
+		System::set_block_number(102);
+		assert_noop!(
+			Identity::accept_username(RuntimeOrigin::signed(10), username(b"ten.infra")),
+			Error::<Test>::UsernameExpired
+		);
+		let info =
+			Identity::remove_expired_approval(RuntimeOrigin::signed(20), username(b"ten.infra"))
+				.unwrap();
+		assert_eq!(info.pays_fee, Pays::No);
+		System::assert_last_event(
+			Event::PendingUsernameExpired { who: 10, username: username(b"ten.infra") }.into(),
+		);
+		assert!(Identity::pending_username(username(b"ten.infra")).is_none());
+
+		// The username can be granted again.
+		assert_ok!(Identity::set_username_for(RuntimeOrigin::signed(3), 20, b"ten".to_vec()));
+	});
+}
+
+#[test]
+fn killing_identity_should_remove_usernames() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Identity::add_username_authority(
+			RuntimeOrigin::root(),
+			3,
+			b"infra".to_vec(),
+			5
+		));
+		assert_ok!(Identity::set_identity(RuntimeOrigin::signed(10), Box::new(ten())));
+		grant_and_accept(10, b"ten");
+		grant_and_accept(10, b"tenth");
+		assert_eq!(
+			Identity::usernames_of(10).into_inner(),
+			vec![username(b"ten.infra"), username(b"tenth.infra")]
+		);
+
+		assert_ok!(Identity::kill_identity(RuntimeOrigin::signed(2), 10));
+		assert!(Identity::username_of(10).is_none());
+		assert!(Identity::usernames_of(10).is_empty());
+		assert!(Identity::account_of_username(username(b"ten.infra")).is_none());
+		assert!(Identity::account_of_username(username(b"tenth.infra")).is_none());
+
+		// The usernames can be granted again.
+		assert_ok!(Identity::set_username_for(RuntimeOrigin::signed(3), 20, b"tenth".to_vec()));
+	});
+}
+
+#[test]
+fn usernames_per_account_should_be_bounded() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Identity::add_username_authority(
+			RuntimeOrigin::root(),
+			3,
+			b"infra".to_vec(),
+			5
+		));
+		grant_and_accept(10, b"ten");
+		grant_and_accept(10, b"tenth");
+		assert_ok!(Identity::set_username_for(RuntimeOrigin::signed(3), 10, b"x".to_vec()));
+		assert_noop!(
+			Identity::accept_username(RuntimeOrigin::signed(10), username(b"x.infra")),
+			Error::<Test>::TooManyUsernames
+		);
+	});
+}
+
+#[test]
+fn username_lookup_should_work() {
+	new_test_ext().execute_with(|| {
+		type Lookup = UsernameLookup<Test, AccountIdLookup<u64, ()>>;
+		assert_ok!(Identity::add_username_authority(
+			RuntimeOrigin::root(),
+			3,
+			b"infra".to_vec(),
+			5
+		));
+		grant_and_accept(10, b"ten");
+
+		assert_eq!(Lookup::lookup(MultiAddress::Raw(b"ten.infra".to_vec())).ok(), Some(10));
+		assert!(Lookup::lookup(MultiAddress::Raw(b"twenty.infra".to_vec())).is_err());
+		assert!(Lookup::lookup(MultiAddress::Raw(vec![b'a'; 33])).is_err());
+		assert_eq!(Lookup::lookup(MultiAddress::Id(20)).ok(), Some(20));
+		assert_eq!(Lookup::unlookup(20), MultiAddress::Id(20));
+	});
+}
@@ -433,6 +433,16 @@ pub struct RegistrarInfo<
 	pub fields: IdentityFields,
 }
 
+/// Information concerning a username authority.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct AuthorityProperties<Suffix> {
+	/// The suffix appended, after a dot, to every username granted by the authority.
+	pub suffix: Suffix,
+
+	/// The number of usernames the authority may still grant.
+	pub allocation: u32,
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
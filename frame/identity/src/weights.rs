@@ -59,11 +59,17 @@ pub trait WeightInfo {
 	fn set_account_id(r: u32, ) -> Weight;
 	fn set_fields(r: u32, ) -> Weight;
 	fn provide_judgement(r: u32, x: u32, ) -> Weight;
-	fn kill_identity(r: u32, s: u32, x: u32, ) -> Weight;
+	fn kill_identity(r: u32, s: u32, x: u32, u: u32, ) -> Weight;
 	fn add_sub(s: u32, ) -> Weight;
 	fn rename_sub(s: u32, ) -> Weight;
 	fn remove_sub(s: u32, ) -> Weight;
 	fn quit_sub(s: u32, ) -> Weight;
+	fn add_username_authority() -> Weight;
+	fn remove_username_authority() -> Weight;
+	fn set_username_for() -> Weight;
+	fn accept_username() -> Weight;
+	fn remove_expired_approval() -> Weight;
+	fn set_primary_username() -> Weight;
 }
 
 /// Weights for pallet_identity using the Substrate node and recommended hardware.
@@ -268,26 +274,36 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: Identity IdentityOf (max_values: None, max_size: Some(7538), added: 10013, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Identity UsernamesOf (r:1 w:1)
+	/// Proof: Identity UsernamesOf (max_values: None, max_size: Some(305), added: 2780, mode: MaxEncodedLen)
+	/// Storage: Identity UsernameOf (r:0 w:1)
+	/// Proof: Identity UsernameOf (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: Identity AccountOfUsername (r:0 w:8)
+	/// Proof: Identity AccountOfUsername (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
 	/// Storage: Identity SuperOf (r:0 w:100)
 	/// Proof: Identity SuperOf (max_values: None, max_size: Some(114), added: 2589, mode: MaxEncodedLen)
 	/// The range of component `r` is `[1, 20]`.
 	/// The range of component `s` is `[0, 100]`.
 	/// The range of component `x` is `[0, 100]`.
-	fn kill_identity(r: u32, s: u32, x: u32, ) -> Weight {
+	/// The range of component `u` is `[0, 8]`.
+	fn kill_identity(r: u32, s: u32, x: u32, u: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `772 + r * (5 ±0) + s * (32 ±0) + x * (66 ±0)`
-		//  Estimated: `18349`
-		// Minimum execution time: 52_881 nanoseconds.
-		Weight::from_parts(38_504_388, 18349)
+		//  Measured:  `863 + r * (5 ±0) + s * (32 ±0) + x * (66 ±0) + u * (33 ±0)`
+		//  Estimated: `21129`
+		// Minimum execution time: 56_210 nanoseconds.
+		Weight::from_parts(41_927_615, 21129)
 			// Standard Error: 3_909
 			.saturating_add(Weight::from_parts(51_452, 0).saturating_mul(r.into()))
 			// Standard Error: 763
 			.saturating_add(Weight::from_parts(1_069_924, 0).saturating_mul(s.into()))
 			// Standard Error: 763
 			.saturating_add(Weight::from_parts(164_906, 0).saturating_mul(x.into()))
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+			// Standard Error: 9_537
+			.saturating_add(Weight::from_parts(1_062_118, 0).saturating_mul(u.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(s.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(u.into())))
 	}
 	/// Storage: Identity IdentityOf (r:1 w:0)
 	/// Proof: Identity IdentityOf (max_values: None, max_size: Some(7538), added: 10013, mode: MaxEncodedLen)
@@ -357,6 +373,83 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+	/// Storage: Identity UsernameAuthorities (r:0 w:1)
+	/// Proof: Identity UsernameAuthorities (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	fn add_username_authority() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_842 nanoseconds.
+		Weight::from_parts(10_217_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Identity UsernameAuthorities (r:1 w:1)
+	/// Proof: Identity UsernameAuthorities (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	fn remove_username_authority() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `79`
+		//  Estimated: `2535`
+		// Minimum execution time: 13_156 nanoseconds.
+		Weight::from_parts(13_671_000, 2535)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Identity UsernameAuthorities (r:1 w:1)
+	/// Proof: Identity UsernameAuthorities (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: Identity AccountOfUsername (r:1 w:0)
+	/// Proof: Identity AccountOfUsername (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: Identity PendingUsernames (r:1 w:1)
+	/// Proof: Identity PendingUsernames (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	fn set_username_for() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `79`
+		//  Estimated: `7651`
+		// Minimum execution time: 21_584 nanoseconds.
+		Weight::from_parts(22_306_000, 7651)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Identity PendingUsernames (r:1 w:1)
+	/// Proof: Identity PendingUsernames (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: Identity UsernamesOf (r:1 w:1)
+	/// Proof: Identity UsernamesOf (max_values: None, max_size: Some(305), added: 2780, mode: MaxEncodedLen)
+	/// Storage: Identity UsernameOf (r:1 w:1)
+	/// Proof: Identity UsernameOf (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: Identity AccountOfUsername (r:0 w:1)
+	/// Proof: Identity AccountOfUsername (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	fn accept_username() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `118`
+		//  Estimated: `7896`
+		// Minimum execution time: 19_920 nanoseconds.
+		Weight::from_parts(20_631_000, 7896)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: Identity PendingUsernames (r:1 w:1)
+	/// Proof: Identity PendingUsernames (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	fn remove_expired_approval() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `118`
+		//  Estimated: `2560`
+		// Minimum execution time: 14_273 nanoseconds.
+		Weight::from_parts(14_905_000, 2560)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Identity AccountOfUsername (r:1 w:0)
+	/// Proof: Identity AccountOfUsername (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: Identity UsernameOf (r:0 w:1)
+	/// Proof: Identity UsernameOf (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	fn set_primary_username() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `114`
+		//  Estimated: `2556`
+		// Minimum execution time: 14_718 nanoseconds.
+		Weight::from_parts(15_290_000, 2556)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -560,26 +653,36 @@ impl WeightInfo for () {
 	/// Proof: Identity IdentityOf (max_values: None, max_size: Some(7538), added: 10013, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Identity UsernamesOf (r:1 w:1)
+	/// Proof: Identity UsernamesOf (max_values: None, max_size: Some(305), added: 2780, mode: MaxEncodedLen)
+	/// Storage: Identity UsernameOf (r:0 w:1)
+	/// Proof: Identity UsernameOf (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: Identity AccountOfUsername (r:0 w:8)
+	/// Proof: Identity AccountOfUsername (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
 	/// Storage: Identity SuperOf (r:0 w:100)
 	/// Proof: Identity SuperOf (max_values: None, max_size: Some(114), added: 2589, mode: MaxEncodedLen)
 	/// The range of component `r` is `[1, 20]`.
 	/// The range of component `s` is `[0, 100]`.
 	/// The range of component `x` is `[0, 100]`.
-	fn kill_identity(r: u32, s: u32, x: u32, ) -> Weight {
+	/// The range of component `u` is `[0, 8]`.
+	fn kill_identity(r: u32, s: u32, x: u32, u: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `772 + r * (5 ±0) + s * (32 ±0) + x * (66 ±0)`
-		//  Estimated: `18349`
-		// Minimum execution time: 52_881 nanoseconds.
-		Weight::from_parts(38_504_388, 18349)
+		//  Measured:  `863 + r * (5 ±0) + s * (32 ±0) + x * (66 ±0) + u * (33 ±0)`
+		//  Estimated: `21129`
+		// Minimum execution time: 56_210 nanoseconds.
+		Weight::from_parts(41_927_615, 21129)
 			// Standard Error: 3_909
 			.saturating_add(Weight::from_parts(51_452, 0).saturating_mul(r.into()))
 			// Standard Error: 763
 			.saturating_add(Weight::from_parts(1_069_924, 0).saturating_mul(s.into()))
 			// Standard Error: 763
 			.saturating_add(Weight::from_parts(164_906, 0).saturating_mul(x.into()))
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			// Standard Error: 9_537
+			.saturating_add(Weight::from_parts(1_062_118, 0).saturating_mul(u.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(s.into())))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(u.into())))
 	}
 	/// Storage: Identity IdentityOf (r:1 w:0)
 	/// Proof: Identity IdentityOf (max_values: None, max_size: Some(7538), added: 10013, mode: MaxEncodedLen)
@@ -649,4 +752,81 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
+	/// Storage: Identity UsernameAuthorities (r:0 w:1)
+	/// Proof: Identity UsernameAuthorities (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	fn add_username_authority() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_842 nanoseconds.
+		Weight::from_parts(10_217_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Identity UsernameAuthorities (r:1 w:1)
+	/// Proof: Identity UsernameAuthorities (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	fn remove_username_authority() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `79`
+		//  Estimated: `2535`
+		// Minimum execution time: 13_156 nanoseconds.
+		Weight::from_parts(13_671_000, 2535)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Identity UsernameAuthorities (r:1 w:1)
+	/// Proof: Identity UsernameAuthorities (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: Identity AccountOfUsername (r:1 w:0)
+	/// Proof: Identity AccountOfUsername (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: Identity PendingUsernames (r:1 w:1)
+	/// Proof: Identity PendingUsernames (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	fn set_username_for() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `79`
+		//  Estimated: `7651`
+		// Minimum execution time: 21_584 nanoseconds.
+		Weight::from_parts(22_306_000, 7651)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: Identity PendingUsernames (r:1 w:1)
+	/// Proof: Identity PendingUsernames (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: Identity UsernamesOf (r:1 w:1)
+	/// Proof: Identity UsernamesOf (max_values: None, max_size: Some(305), added: 2780, mode: MaxEncodedLen)
+	/// Storage: Identity UsernameOf (r:1 w:1)
+	/// Proof: Identity UsernameOf (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: Identity AccountOfUsername (r:0 w:1)
+	/// Proof: Identity AccountOfUsername (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	fn accept_username() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `118`
+		//  Estimated: `7896`
+		// Minimum execution time: 19_920 nanoseconds.
+		Weight::from_parts(20_631_000, 7896)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: Identity PendingUsernames (r:1 w:1)
+	/// Proof: Identity PendingUsernames (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	fn remove_expired_approval() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `118`
+		//  Estimated: `2560`
+		// Minimum execution time: 14_273 nanoseconds.
+		Weight::from_parts(14_905_000, 2560)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Identity AccountOfUsername (r:1 w:0)
+	/// Proof: Identity AccountOfUsername (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: Identity UsernameOf (r:0 w:1)
+	/// Proof: Identity UsernameOf (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	fn set_primary_username() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `114`
+		//  Estimated: `2556`
+		// Minimum execution time: 14_718 nanoseconds.
+		Weight::from_parts(15_290_000, 2556)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
@@ -276,6 +276,27 @@ where
 	}
 }
 
+/// Stateless MMR ancestry proof verification.
+///
+/// This function can be used to verify a received MMR [primitives::AncestryProof] (`proof`),
+/// i.e. that the MMR with root `prev_root` is a prefix of the MMR with root `root`.
+pub fn verify_ancestry_proof<H, L>(
+	prev_root: H::Output,
+	root: H::Output,
+	proof: primitives::AncestryProof<H::Output>,
+) -> Result<(), primitives::Error>
+where
+	H: traits::Hash,
+	L: primitives::FullLeaf,
+{
+	let is_valid = mmr::verify_ancestry_proof::<H, L>(prev_root, root, proof)?;
+	if is_valid {
+		Ok(())
+	} else {
+		Err(primitives::Error::Verify.log_debug(("The ancestry proof is incorrect.", root)))
+	}
+}
+
 impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	/// Build offchain key from `parent_hash` of block that originally added node `pos` to MMR.
 	///
@@ -340,7 +361,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	pub fn generate_proof(
 		block_numbers: Vec<T::BlockNumber>,
 		best_known_block_number: Option<T::BlockNumber>,
-	) -> Result<(Vec<LeafOf<T, I>>, primitives::Proof<<T as Config<I>>::Hash>), primitives::Error> {
+	) -> Result<(Vec<LeafOf<T, I>>, primitives::Proof<<T as Config<I>>::Hash>), primitives::Error>
+	{
 		// check whether best_known_block_number provided, else use current best block
 		let best_known_block_number =
 			best_known_block_number.unwrap_or_else(|| <frame_system::Pallet<T>>::block_number());
@@ -360,6 +382,28 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		mmr.generate_proof(leaf_indices)
 	}
 
+	/// Generate an MMR ancestry proof for the prior MMR with `prev_leaf_count` leaves.
+	/// If `best_known_block_number = Some(n)`, this generates a historical proof for
+	/// the chain with head at height `n`.
+	/// Else it generates a proof for the MMR at the current block height.
+	///
+	/// Note this method can only be used from an off-chain context
+	/// (Offchain Worker or Runtime API call), since it requires
+	/// the nodes added after the prior MMR to be present.
+	pub fn generate_ancestry_proof(
+		prev_leaf_count: LeafIndex,
+		best_known_block_number: Option<T::BlockNumber>,
+	) -> Result<primitives::AncestryProof<<T as Config<I>>::Hash>, primitives::Error> {
+		let best_known_block_number =
+			best_known_block_number.unwrap_or_else(|| <frame_system::Pallet<T>>::block_number());
+
+		let leaves_count =
+			Self::block_num_to_leaf_index(best_known_block_number)?.saturating_add(1);
+
+		let mmr: ModuleMmr<mmr::storage::OffchainStorage, T, I> = mmr::Mmr::new(leaves_count);
+		mmr.generate_ancestry_proof(prev_leaf_count)
+	}
+
 	/// Return the on-chain MMR root hash.
 	pub fn mmr_root() -> <T as Config<I>>::Hash {
 		Self::mmr_root_hash()
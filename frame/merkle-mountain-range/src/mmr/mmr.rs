@@ -20,11 +20,11 @@ use crate::{
 		storage::{OffchainStorage, RuntimeStorage, Storage},
 		Hasher, Node, NodeOf,
 	},
-	primitives::{self, Error, NodeIndex},
+	primitives::{self, Error, LeafIndex, NodeIndex},
 	Config, HashingOf,
 };
-use sp_mmr_primitives::{mmr_lib, utils::NodesUtils};
-use sp_std::prelude::*;
+use sp_mmr_primitives::{mmr_lib, mmr_lib::Merge, utils::NodesUtils};
+use sp_std::{collections::btree_map::BTreeMap, prelude::*};
 
 /// Stateless verification of the proof for a batch of leaves.
 /// Note, the leaves should be sorted such that corresponding leaves and leaf indices have the
@@ -60,6 +60,71 @@ where
 		.map_err(|e| Error::Verify.log_debug(e))
 }
 
+/// Stateless verification of an ancestry proof, i.e. that the MMR with root `prev_root` is a
+/// prefix of the MMR with root `root`.
+pub fn verify_ancestry_proof<H, L>(
+	prev_root: H::Output,
+	root: H::Output,
+	proof: primitives::AncestryProof<H::Output>,
+) -> Result<bool, Error>
+where
+	H: sp_runtime::traits::Hash,
+	L: primitives::FullLeaf,
+{
+	let (positions, merges) = NodesUtils::new(proof.leaf_count)
+		.ancestry_merges(proof.prev_leaf_count)
+		.ok_or_else(|| Error::Verify.log_debug("Proof has an invalid prior leaf count"))?;
+	let prev_peak_positions =
+		mmr_lib::helper::get_peaks(NodesUtils::new(proof.prev_leaf_count).size());
+	if prev_peak_positions.len() != proof.prev_peaks.len() || positions.len() != proof.items.len() {
+		return Err(Error::Verify.log_debug("Proof has an incorrect number of peaks or items"))
+	}
+
+	let prev_peaks = proof.prev_peaks.into_iter().map(Node::Hash).collect::<Vec<_>>();
+	if bag_peaks::<H, L>(prev_peaks.clone())? != prev_root {
+		return Ok(false)
+	}
+
+	let mut nodes = prev_peak_positions
+		.into_iter()
+		.zip(prev_peaks)
+		.chain(positions.into_iter().zip(proof.items.into_iter().map(Node::Hash)))
+		.collect::<BTreeMap<_, Node<H, L>>>();
+	for (left, right) in merges {
+		let parent = match (nodes.get(&left), nodes.get(&right)) {
+			(Some(left), Some(right)) =>
+				Hasher::<H, L>::merge(left, right).map_err(|e| Error::Verify.log_debug(e))?,
+			_ => return Err(Error::Verify.log_debug(("Missing sibling of node", left, right))),
+		};
+		nodes.insert(right + 1, parent);
+	}
+	let peaks = mmr_lib::helper::get_peaks(NodesUtils::new(proof.leaf_count).size())
+		.into_iter()
+		.map(|pos| nodes.remove(&pos).ok_or_else(|| Error::Verify.log_debug(("Missing peak", pos))))
+		.collect::<Result<Vec<_>, _>>()?;
+
+	Ok(bag_peaks::<H, L>(peaks)? == root)
+}
+
+/// Calculate the root of an MMR from its peaks, the same way the MMR library does.
+fn bag_peaks<H, L>(mut peaks: Vec<Node<H, L>>) -> Result<H::Output, Error>
+where
+	H: sp_runtime::traits::Hash,
+	L: primitives::FullLeaf,
+{
+	while peaks.len() > 1 {
+		let right = peaks.pop().expect("There are at least two peaks; qed");
+		let left = peaks.pop().expect("There are at least two peaks; qed");
+		peaks.push(
+			Hasher::<H, L>::merge_peaks(&right, &left).map_err(|e| Error::Verify.log_debug(e))?,
+		);
+	}
+	peaks
+		.pop()
+		.map(|peak| peak.hash())
+		.ok_or_else(|| Error::Verify.log_debug("No peaks"))
+}
+
 /// A wrapper around an MMR library to expose limited functionality.
 ///
 /// Available functions depend on the storage kind ([Runtime](crate::mmr::storage::RuntimeStorage)
@@ -191,4 +256,33 @@ where
 			})
 			.map(|p| (leaves, p))
 	}
+
+	/// Generate an ancestry proof for the prior MMR with `prev_leaf_count` leaves.
+	///
+	/// Proof generation requires the peaks of the prior MMR and the nodes added after them to be
+	/// available in the storage.
+	pub fn generate_ancestry_proof(
+		&self,
+		prev_leaf_count: LeafIndex,
+	) -> Result<primitives::AncestryProof<<T as Config<I>>::Hash>, Error> {
+		let (positions, _) =
+			NodesUtils::new(self.leaves).ancestry_merges(prev_leaf_count).ok_or_else(|| {
+				Error::InvalidLeafIndex.log_debug(("Invalid prior leaf count", prev_leaf_count))
+			})?;
+		let store = <Storage<OffchainStorage, T, I, L>>::default();
+		let hash_at = |pos| match mmr_lib::MMRStore::get_elem(&store, pos) {
+			Ok(Some(node)) => Ok(node.hash()),
+			e => Err(Error::GenerateProof.log_error(("Node not found", pos, e))),
+		};
+
+		Ok(primitives::AncestryProof {
+			prev_peaks: mmr_lib::helper::get_peaks(NodesUtils::new(prev_leaf_count).size())
+				.into_iter()
+				.map(hash_at)
+				.collect::<Result<_, _>>()?,
+			prev_leaf_count,
+			leaf_count: self.leaves,
+			items: positions.into_iter().map(hash_at).collect::<Result<_, _>>()?,
+		})
+	}
 }
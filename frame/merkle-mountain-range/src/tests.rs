@@ -699,6 +699,75 @@ fn should_verify_batch_proof_statelessly() {
 	);
 }
 
+#[test]
+fn should_verify_ancestry_proofs_statelessly() {
+	let _ = env_logger::try_init();
+
+	// Start off with chain initialisation and storing indexing data off-chain,
+	// keeping the root of the MMR after each block.
+	let mut ext = new_test_ext();
+	let roots = ext.execute_with(|| {
+		(0..12)
+			.map(|_| {
+				new_block();
+				crate::Pallet::<Test>::mmr_root_hash()
+			})
+			.collect::<Vec<_>>()
+	});
+	ext.persist_offchain_overlay();
+
+	register_offchain_ext(&mut ext);
+	ext.execute_with(|| {
+		for prev_leaf_count in 1..=12 {
+			// when
+			let proof =
+				crate::Pallet::<Test>::generate_ancestry_proof(prev_leaf_count, None).unwrap();
+
+			// then
+			assert_eq!(proof.leaf_count, 12);
+			assert_eq!(
+				crate::verify_ancestry_proof::<<Test as Config>::Hashing, LeafData>(
+					roots[prev_leaf_count as usize - 1],
+					roots[11],
+					proof.clone(),
+				),
+				Ok(())
+			);
+			// The proof doesn't hold for another prior MMR.
+			assert_eq!(
+				crate::verify_ancestry_proof::<<Test as Config>::Hashing, LeafData>(
+					roots[prev_leaf_count as usize % 12],
+					roots[11],
+					proof,
+				),
+				Err(Error::Verify)
+			);
+		}
+
+		// when generating a historical proof
+		let proof = crate::Pallet::<Test>::generate_ancestry_proof(3, Some(8)).unwrap();
+
+		// then
+		assert_eq!(proof.leaf_count, 8);
+		assert_eq!(
+			crate::verify_ancestry_proof::<<Test as Config>::Hashing, LeafData>(
+				roots[2], roots[7], proof,
+			),
+			Ok(())
+		);
+
+		// when the prior leaf count is invalid
+		assert_eq!(
+			crate::Pallet::<Test>::generate_ancestry_proof(0, None),
+			Err(Error::InvalidLeafIndex)
+		);
+		assert_eq!(
+			crate::Pallet::<Test>::generate_ancestry_proof(9, Some(8)),
+			Err(Error::InvalidLeafIndex)
+		);
+	});
+}
+
 #[test]
 fn should_verify_on_the_next_block_since_there_is_no_pruning_yet() {
 	let _ = env_logger::try_init();
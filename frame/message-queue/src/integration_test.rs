@@ -99,6 +99,9 @@ impl Config for Test {
 	type HeapSize = HeapSize;
 	type MaxStale = MaxStale;
 	type ServiceWeight = ServiceWeight;
+	type QueuePausedQuery = ();
+	type QueuePriorityQuery = ();
+	type QueueServiceBudget = ();
 }
 
 /// Simulates heavy usage by enqueueing and processing large amounts of messages.
@@ -40,9 +40,14 @@
 //! book. Each book keeps track of its pages by indexing `Pages`. The `ReadyRing` contains all
 //! queues which hold at least one unprocessed message and are thereby *ready* to be serviced. The
 //! `ServiceHead` indicates which *ready* queue is the next to be serviced.  
+//! There are two such rings, called *lanes*: the priority lane with its `PriorityServiceHead` and
+//! the bulk lane. A queue is knitted into the priority lane if [`Config::QueuePriorityQuery`]
+//! says so at the time it becomes ready.  
 //! The pallet implements [`frame_support::traits::EnqueueMessage`],
-//! [`frame_support::traits::ServiceQueues`] and has [`frame_support::traits::ProcessMessage`] and
-//! [`OnQueueChanged`] hooks to communicate with the outside world.
+//! [`frame_support::traits::ServiceQueues`] and has [`frame_support::traits::ProcessMessage`],
+//! [`OnQueueChanged`], [`QueuePausedQuery`], [`QueuePriorityQuery`] and [`QueueServiceBudget`]
+//! hooks to communicate with the outside world. The state of the queues is exposed through the
+//! [`MessageQueueApi`] runtime API.
 //!
 //! NOTE: The storage items are not linked since they are not public.
 //!
@@ -101,10 +106,14 @@
 //! The pallet runs each block in `on_initialize` or when being manually called through
 //! [`frame_support::traits::ServiceQueues::service_queues`].
 //!
+//! The priority lane is serviced before the bulk lane, in the same manner.
+//!
 //! First it tries to "rotate" the `ReadyRing` by one through advancing the `ServiceHead` to the
-//! next *ready* queue. It then starts to service this queue by servicing as many pages of it as
-//! possible. Servicing a page means to execute as many message of it as possible. Each executed
-//! message is marked as *processed* if the [`Config::MessageProcessor`] return Ok. An event
+//! next *ready* queue. Paused queues are skipped. It then starts to service this queue by servicing
+//! as many pages of it as possible. Servicing a page means to execute as many message of it as
+//! possible. Each executed message is marked as *processed* if the [`Config::MessageProcessor`]
+//! return Ok. A queue with a [`Config::QueueServiceBudget`] uses at most its budget per block and
+//! its messages needing more than that are permanently overweight. An event
 //! [`Event::Processed`] is emitted afterwards. It is possible that the weight limit of the pallet
 //! will never allow a specific message to be executed. In this case it remains as unprocessed and
 //! is skipped. This process stops if either there are no more messages in the queue or the
@@ -178,7 +187,9 @@
 //! The average amount of weight available for message processing is the same for each queue if the
 //! number of queues is constant. Creating a new queue must therefore be, possibly economically,
 //! expensive. Currently this is archived by having one queue per para-chain/thread, which keeps the
-//! number of queues within `O(n)` and should be "good enough".
+//! number of queues within `O(n)` and should be "good enough". Per-queue budgets additionally
+//! bound how much a single queue can take from the others, whereas queues of the priority lane are
+//! deliberately favoured over those of the bulk lane.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -433,6 +444,77 @@ impl<Id> OnQueueChanged<Id> for () {
 	fn on_queue_changed(_: Id, _: u64, _: u64) {}
 }
 
+/// Can query whether a queue is paused.
+pub trait QueuePausedQuery<Origin> {
+	/// Whether the queue of `origin` is paused.
+	///
+	/// A paused queue is not serviced and its overweight messages cannot be executed, but
+	/// messages can still be enqueued into it.
+	fn is_paused(origin: &Origin) -> bool;
+}
+
+impl<Origin> QueuePausedQuery<Origin> for () {
+	fn is_paused(_: &Origin) -> bool {
+		false
+	}
+}
+
+/// Can query whether a queue is serviced in the priority lane.
+pub trait QueuePriorityQuery<Origin> {
+	/// Whether the queue of `origin` is serviced in the priority lane, i.e. before any queue of
+	/// the bulk lane.
+	///
+	/// This is queried whenever the queue becomes ready, so that a change only applies to a queue
+	/// once it has been emptied.
+	fn is_priority(origin: &Origin) -> bool;
+}
+
+impl<Origin> QueuePriorityQuery<Origin> for () {
+	fn is_priority(_: &Origin) -> bool {
+		false
+	}
+}
+
+/// Provides the weight budget of a queue.
+pub trait QueueServiceBudget<Origin> {
+	/// The maximal weight which may be used to service the queue of `origin` in one call to
+	/// [`ServiceQueues::service_queues`], or `None` if it is only limited by the weight limit of
+	/// the call.
+	///
+	/// Messages needing more than this are deemed permanently overweight.
+	fn service_budget(origin: &Origin) -> Option<Weight>;
+}
+
+impl<Origin> QueueServiceBudget<Origin> for () {
+	fn service_budget(_: &Origin) -> Option<Weight> {
+		None
+	}
+}
+
+/// The state of a queue.
+#[derive(Clone, Encode, Decode, TypeInfo, RuntimeDebug, PartialEq, Eq)]
+pub struct QueueInfo<MessageOrigin> {
+	/// The origin of the messages of the queue.
+	pub origin: MessageOrigin,
+	/// The number of unprocessed messages, including the overweight ones.
+	pub message_count: u64,
+	/// The total size of all unprocessed messages, including the overweight ones.
+	pub size: u64,
+	/// The number of pages stored at present.
+	pub page_count: PageIndex,
+	/// The number of stale pages, i.e. pages before the ready ones which are kept for their
+	/// unprocessed overweight messages.
+	pub overweight_page_count: PageIndex,
+	/// The number of unprocessed overweight messages in the stale pages.
+	pub overweight_count: u64,
+	/// Whether the queue is ready to be serviced.
+	pub ready: bool,
+	/// Whether the queue is paused.
+	pub paused: bool,
+	/// Whether the queue is serviced in the priority lane.
+	pub priority: bool,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -495,6 +577,20 @@ pub mod pallet {
 		/// `ServiceQueues::service_queues` manually.
 		#[pallet::constant]
 		type ServiceWeight: Get<Option<Weight>>;
+
+		/// Queries whether a queue is paused.
+		///
+		/// Paused queues are skipped when servicing the queues.
+		type QueuePausedQuery: QueuePausedQuery<MessageOriginOf<Self>>;
+
+		/// Queries whether a queue is serviced in the priority lane.
+		///
+		/// All ready queues of the priority lane are serviced before those of the bulk lane.
+		type QueuePriorityQuery: QueuePriorityQuery<MessageOriginOf<Self>>;
+
+		/// Provides the weight budget of each queue, so that a single queue cannot use up the
+		/// weight available for servicing all of them.
+		type QueueServiceBudget: QueueServiceBudget<MessageOriginOf<Self>>;
 	}
 
 	#[pallet::event]
@@ -538,6 +634,8 @@ pub mod pallet {
 		/// Such errors are expected, but not guaranteed, to resolve themselves eventually through
 		/// retrying.
 		TemporarilyUnprocessable,
+		/// The queue is paused and no message can be executed from it.
+		QueuePaused,
 	}
 
 	/// The index of the first and last (non-empty) pages.
@@ -545,10 +643,15 @@ pub mod pallet {
 	pub(super) type BookStateFor<T: Config> =
 		StorageMap<_, Twox64Concat, MessageOriginOf<T>, BookState<MessageOriginOf<T>>, ValueQuery>;
 
-	/// The origin at which we should begin servicing.
+	/// The origin at which we should begin servicing the bulk lane.
 	#[pallet::storage]
 	pub(super) type ServiceHead<T: Config> = StorageValue<_, MessageOriginOf<T>, OptionQuery>;
 
+	/// The origin at which we should begin servicing the priority lane.
+	#[pallet::storage]
+	pub(super) type PriorityServiceHead<T: Config> =
+		StorageValue<_, MessageOriginOf<T>, OptionQuery>;
+
 	/// The map of page indices to pages.
 	#[pallet::storage]
 	pub(super) type Pages<T: Config> = StorageDoubleMap<
@@ -624,6 +727,28 @@ pub mod pallet {
 	}
 }
 
+/// The lane of a ready ring.
+///
+/// All queues of the priority lane are serviced before any queue of the bulk lane.
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum Lane {
+	/// The lane of queues for which [`Config::QueuePriorityQuery`] returns `true`.
+	Priority,
+	/// The lane of all other queues.
+	Bulk,
+}
+
+impl Lane {
+	/// The lane into which `origin` is knitted when it becomes ready.
+	fn of<T: Config>(origin: &MessageOriginOf<T>) -> Self {
+		if T::QueuePriorityQuery::is_priority(origin) {
+			Lane::Priority
+		} else {
+			Lane::Bulk
+		}
+	}
+}
+
 /// The status of a page after trying to execute its next message.
 #[derive(PartialEq, Debug)]
 enum PageExecutionStatus {
@@ -672,11 +797,30 @@ enum MessageExecutionStatus {
 }
 
 impl<T: Config> Pallet<T> {
-	/// Knit `origin` into the ready ring right at the end.
+	/// The service head of the ready ring of `lane`.
+	fn service_head(lane: Lane) -> Option<MessageOriginOf<T>> {
+		match lane {
+			Lane::Priority => PriorityServiceHead::<T>::get(),
+			Lane::Bulk => ServiceHead::<T>::get(),
+		}
+	}
+
+	/// Set the service head of the ready ring of `lane`.
+	fn set_service_head(lane: Lane, head: Option<&MessageOriginOf<T>>) {
+		match (lane, head) {
+			(Lane::Priority, Some(head)) => PriorityServiceHead::<T>::put(head),
+			(Lane::Priority, None) => PriorityServiceHead::<T>::kill(),
+			(Lane::Bulk, Some(head)) => ServiceHead::<T>::put(head),
+			(Lane::Bulk, None) => ServiceHead::<T>::kill(),
+		}
+	}
+
+	/// Knit `origin` into the ready ring of its lane right at the end.
 	///
 	/// Return the two ready ring neighbours of `origin`.
 	fn ready_ring_knit(origin: &MessageOriginOf<T>) -> Result<Neighbours<MessageOriginOf<T>>, ()> {
-		if let Some(head) = ServiceHead::<T>::get() {
+		let lane = Lane::of::<T>(origin);
+		if let Some(head) = Self::service_head(lane) {
 			let mut head_book_state = BookStateFor::<T>::get(&head);
 			let mut head_neighbours = head_book_state.ready_neighbours.take().ok_or(())?;
 			let tail = head_neighbours.prev;
@@ -692,19 +836,26 @@ impl<T: Config> Pallet<T> {
 
 			Ok(Neighbours { next: head, prev: tail })
 		} else {
-			ServiceHead::<T>::put(origin);
+			Self::set_service_head(lane, Some(origin));
 			Ok(Neighbours { next: origin.clone(), prev: origin.clone() })
 		}
 	}
 
+	/// Unknit `origin` from whichever ready ring it is part of.
+	///
+	/// The lane is not re-queried since it could have changed after `origin` was knitted.
 	fn ready_ring_unknit(origin: &MessageOriginOf<T>, neighbours: Neighbours<MessageOriginOf<T>>) {
 		if origin == &neighbours.next {
 			debug_assert!(
 				origin == &neighbours.prev,
 				"unknitting from single item ring; outgoing must be only item"
 			);
-			// Service queue empty.
-			ServiceHead::<T>::kill();
+			// Service queue empty. The only item of a ring is always its head.
+			if PriorityServiceHead::<T>::get().as_ref() == Some(origin) {
+				PriorityServiceHead::<T>::kill();
+			} else {
+				ServiceHead::<T>::kill();
+			}
 		} else {
 			BookStateFor::<T>::mutate(&neighbours.next, |book_state| {
 				if let Some(ref mut n) = book_state.ready_neighbours {
@@ -716,28 +867,38 @@ impl<T: Config> Pallet<T> {
 					n.next = neighbours.next.clone()
 				}
 			});
-			if let Some(head) = ServiceHead::<T>::get() {
-				if &head == origin {
-					ServiceHead::<T>::put(neighbours.next);
-				}
-			} else {
-				defensive!("`ServiceHead` must be some if there was a ready queue");
+			match (PriorityServiceHead::<T>::get(), ServiceHead::<T>::get()) {
+				(Some(head), _) if &head == origin =>
+					PriorityServiceHead::<T>::put(neighbours.next),
+				(_, Some(head)) if &head == origin => ServiceHead::<T>::put(neighbours.next),
+				(None, None) => {
+					defensive!("`ServiceHead` must be some if there was a ready queue");
+				},
+				_ => (),
 			}
 		}
 	}
 
-	/// Tries to bump the current `ServiceHead` to the next ready queue.
+	/// Tries to bump the current `ServiceHead` of the bulk lane to the next ready queue.
 	///
 	/// Returns the current head if it got be bumped and `None` otherwise.
+	#[cfg(any(test, feature = "runtime-benchmarks"))]
 	fn bump_service_head(weight: &mut WeightMeter) -> Option<MessageOriginOf<T>> {
+		Self::bump_lane_service_head(Lane::Bulk, weight)
+	}
+
+	/// Tries to bump the current service head of `lane` to the next ready queue.
+	///
+	/// Returns the current head if it got be bumped and `None` otherwise.
+	fn bump_lane_service_head(lane: Lane, weight: &mut WeightMeter) -> Option<MessageOriginOf<T>> {
 		if !weight.check_accrue(T::WeightInfo::bump_service_head()) {
 			return None
 		}
 
-		if let Some(head) = ServiceHead::<T>::get() {
+		if let Some(head) = Self::service_head(lane) {
 			let mut head_book_state = BookStateFor::<T>::get(&head);
 			if let Some(head_neighbours) = head_book_state.ready_neighbours.take() {
-				ServiceHead::<T>::put(&head_neighbours.next);
+				Self::set_service_head(lane, Some(&head_neighbours.next));
 				Some(head)
 			} else {
 				None
@@ -806,6 +967,7 @@ impl<T: Config> Pallet<T> {
 		index: T::Size,
 		weight_limit: Weight,
 	) -> Result<Weight, Error<T>> {
+		ensure!(!T::QueuePausedQuery::is_paused(&origin), Error::<T>::QueuePaused);
 		let mut book_state = BookStateFor::<T>::get(&origin);
 		let mut page = Pages::<T>::get(&origin, page_index).ok_or(Error::<T>::NoPage)?;
 		let (pos, is_processed, payload) =
@@ -933,6 +1095,8 @@ impl<T: Config> Pallet<T> {
 	/// Execute any messages remaining to be processed in the queue of `origin`, using up to
 	/// `weight_limit` to do so. Any messages which would take more than `overweight_limit` to
 	/// execute are deemed overweight and ignored.
+	///
+	/// A paused queue is skipped without making progress.
 	fn service_queue(
 		origin: MessageOriginOf<T>,
 		weight: &mut WeightMeter,
@@ -946,6 +1110,9 @@ impl<T: Config> Pallet<T> {
 		}
 
 		let mut book_state = BookStateFor::<T>::get(&origin);
+		if T::QueuePausedQuery::is_paused(&origin) {
+			return (false, book_state.ready_neighbours.map(|x| x.next))
+		}
 		let mut total_processed = 0;
 
 		while book_state.end > book_state.begin {
@@ -1237,10 +1404,56 @@ impl<T: Config> ServiceQueues for Pallet<T> {
 		// The maximum weight that processing a single message may take.
 		let overweight_limit = weight_limit;
 		let mut weight = WeightMeter::from_limit(weight_limit);
+		let mut budgets_used = Vec::new();
 
-		let mut next = match Self::bump_service_head(&mut weight) {
+		// An empty priority lane is not charged, so that runtimes without priority queues keep
+		// their weight consumption.
+		if PriorityServiceHead::<T>::exists() {
+			Self::service_lane(Lane::Priority, &mut weight, overweight_limit, &mut budgets_used);
+		}
+		Self::service_lane(Lane::Bulk, &mut weight, overweight_limit, &mut budgets_used);
+		weight.consumed
+	}
+
+	/// Execute a single overweight message.
+	///
+	/// The weight limit must be enough for `execute_overweight` and the message execution itself.
+	fn execute_overweight(
+		weight_limit: Weight,
+		(message_origin, page, index): Self::OverweightMessageAddress,
+	) -> Result<Weight, ExecuteOverweightError> {
+		let mut weight = WeightMeter::from_limit(weight_limit);
+		if !weight.check_accrue(
+			T::WeightInfo::execute_overweight_page_removed()
+				.max(T::WeightInfo::execute_overweight_page_updated()),
+		) {
+			return Err(ExecuteOverweightError::InsufficientWeight)
+		}
+
+		Pallet::<T>::do_execute_overweight(message_origin, page, index, weight.remaining()).map_err(
+			|e| match e {
+				Error::<T>::InsufficientWeight => ExecuteOverweightError::InsufficientWeight,
+				Error::<T>::QueuePaused => ExecuteOverweightError::QueuePaused,
+				_ => ExecuteOverweightError::NotFound,
+			},
+		)
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Service the ready ring of `lane` round-robin until no queue can make progress anymore.
+	///
+	/// `budgets_used` tracks the weight which each queue with a [`Config::QueueServiceBudget`]
+	/// consumed within the current call to [`ServiceQueues::service_queues`].
+	fn service_lane(
+		lane: Lane,
+		weight: &mut WeightMeter,
+		overweight_limit: Weight,
+		budgets_used: &mut Vec<(MessageOriginOf<T>, Weight)>,
+	) {
+		let mut next = match Self::bump_lane_service_head(lane, weight) {
 			Some(h) => h,
-			None => return weight.consumed,
+			None => return,
 		};
 		// The last queue that did not make any progress.
 		// The loop aborts as soon as it arrives at this queue again without making any progress
@@ -1248,7 +1461,8 @@ impl<T: Config> ServiceQueues for Pallet<T> {
 		let mut last_no_progress = None;
 
 		loop {
-			let (progressed, n) = Self::service_queue(next.clone(), &mut weight, overweight_limit);
+			let (progressed, n) =
+				Self::service_queue_in_budget(next.clone(), weight, overweight_limit, budgets_used);
 			next = match n {
 				Some(n) =>
 					if !progressed {
@@ -1266,30 +1480,62 @@ impl<T: Config> ServiceQueues for Pallet<T> {
 				None => break,
 			}
 		}
-		weight.consumed
 	}
 
-	/// Execute a single overweight message.
-	///
-	/// The weight limit must be enough for `execute_overweight` and the message execution itself.
-	fn execute_overweight(
-		weight_limit: Weight,
-		(message_origin, page, index): Self::OverweightMessageAddress,
-	) -> Result<Weight, ExecuteOverweightError> {
-		let mut weight = WeightMeter::from_limit(weight_limit);
-		if !weight.check_accrue(
-			T::WeightInfo::execute_overweight_page_removed()
-				.max(T::WeightInfo::execute_overweight_page_updated()),
-		) {
-			return Err(ExecuteOverweightError::InsufficientWeight)
+	/// Service the queue of `origin` like [`Self::service_queue`] but without exceeding the
+	/// remainder of its budget, if it has one.
+	fn service_queue_in_budget(
+		origin: MessageOriginOf<T>,
+		weight: &mut WeightMeter,
+		overweight_limit: Weight,
+		budgets_used: &mut Vec<(MessageOriginOf<T>, Weight)>,
+	) -> (bool, Option<MessageOriginOf<T>>) {
+		let budget = match T::QueueServiceBudget::service_budget(&origin) {
+			Some(budget) => budget,
+			None => return Self::service_queue(origin, weight, overweight_limit),
+		};
+		let index = match budgets_used.iter().position(|(o, _)| o == &origin) {
+			Some(index) => index,
+			None => {
+				budgets_used.push((origin.clone(), Weight::zero()));
+				budgets_used.len() - 1
+			},
+		};
+		let left = budget.saturating_sub(budgets_used[index].1);
+		let mut queue_weight = WeightMeter::from_limit(weight.remaining().min(left));
+		let result = Self::service_queue(origin, &mut queue_weight, overweight_limit.min(budget));
+		weight.defensive_saturating_accrue(queue_weight.consumed);
+		budgets_used[index].1.saturating_accrue(queue_weight.consumed);
+		result
+	}
+
+	/// The state of the queue of `origin`, or `None` if it has no pages.
+	pub fn queue_info(origin: MessageOriginOf<T>) -> Option<QueueInfo<MessageOriginOf<T>>> {
+		let book_state = BookStateFor::<T>::get(&origin);
+		if book_state.count.is_zero() {
+			return None
 		}
+		let (overweight_page_count, overweight_count) = Pages::<T>::iter_prefix(&origin)
+			.filter(|(index, _)| *index < book_state.begin)
+			.fold((0, 0u64), |(pages, messages), (_, page)| {
+				(pages + 1, messages.saturating_add(page.remaining.into() as u64))
+			});
+		Some(QueueInfo {
+			message_count: book_state.message_count,
+			size: book_state.size,
+			page_count: book_state.count,
+			overweight_page_count,
+			overweight_count,
+			ready: book_state.ready_neighbours.is_some(),
+			paused: T::QueuePausedQuery::is_paused(&origin),
+			priority: T::QueuePriorityQuery::is_priority(&origin),
+			origin,
+		})
+	}
 
-		Pallet::<T>::do_execute_overweight(message_origin, page, index, weight.remaining()).map_err(
-			|e| match e {
-				Error::<T>::InsufficientWeight => ExecuteOverweightError::InsufficientWeight,
-				_ => ExecuteOverweightError::NotFound,
-			},
-		)
+	/// The state of all queues which have pages.
+	pub fn queue_infos() -> Vec<QueueInfo<MessageOriginOf<T>>> {
+		BookStateFor::<T>::iter_keys().filter_map(Self::queue_info).collect()
 	}
 }
 
@@ -1334,3 +1580,14 @@ impl<T: Config> EnqueueMessage<MessageOriginOf<T>> for Pallet<T> {
 		Footprint { count: book_state.message_count, size: book_state.size }
 	}
 }
+
+sp_api::decl_runtime_apis! {
+	/// API to inspect the queues of the message queue pallet.
+	pub trait MessageQueueApi<MessageOrigin> where MessageOrigin: Codec {
+		/// The state of all queues which have pages.
+		fn queues() -> Vec<QueueInfo<MessageOrigin>>;
+
+		/// The state of the queue of `origin`, or `None` if it has no pages.
+		fn queue(origin: MessageOrigin) -> Option<QueueInfo<MessageOrigin>>;
+	}
+}
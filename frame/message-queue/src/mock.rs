@@ -87,6 +87,9 @@ impl Config for Test {
 	type HeapSize = HeapSize;
 	type MaxStale = MaxStale;
 	type ServiceWeight = ServiceWeight;
+	type QueuePausedQuery = MockedQueuePausedQuery;
+	type QueuePriorityQuery = MockedQueuePriorityQuery;
+	type QueueServiceBudget = MockedQueueServiceBudget;
 }
 
 /// Mocked `WeightInfo` impl with allows to set the weight per call.
@@ -268,6 +271,37 @@ impl OnQueueChanged<MessageOrigin> for RecordingQueueChangeHandler {
 	}
 }
 
+parameter_types! {
+	/// Storage for the mocked queue hooks, do not use directly.
+	pub static PausedQueues: Vec<MessageOrigin> = vec![];
+	pub static PriorityQueues: Vec<MessageOrigin> = vec![];
+	pub static QueueBudgets: Vec<(MessageOrigin, Weight)> = vec![];
+}
+
+/// Pauses all queues in [`PausedQueues`].
+pub struct MockedQueuePausedQuery;
+impl QueuePausedQuery<MessageOrigin> for MockedQueuePausedQuery {
+	fn is_paused(origin: &MessageOrigin) -> bool {
+		PausedQueues::get().contains(origin)
+	}
+}
+
+/// Services all queues in [`PriorityQueues`] in the priority lane.
+pub struct MockedQueuePriorityQuery;
+impl QueuePriorityQuery<MessageOrigin> for MockedQueuePriorityQuery {
+	fn is_priority(origin: &MessageOrigin) -> bool {
+		PriorityQueues::get().contains(origin)
+	}
+}
+
+/// Limits the queues in [`QueueBudgets`] to their respective budget.
+pub struct MockedQueueServiceBudget;
+impl QueueServiceBudget<MessageOrigin> for MockedQueueServiceBudget {
+	fn service_budget(origin: &MessageOrigin) -> Option<Weight> {
+		QueueBudgets::get().into_iter().find(|(o, _)| o == origin).map(|(_, w)| w)
+	}
+}
+
 /// Create new test externalities.
 ///
 /// Is generic since it is used by the unit test, integration tests and benchmarks.
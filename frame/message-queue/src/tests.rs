@@ -1199,3 +1199,165 @@ fn enqueue_messages_works() {
 		assert_eq!(book.count as usize, Pages::<Test>::iter().count());
 	});
 }
+
+#[test]
+fn paused_queues_are_not_serviced() {
+	use MessageOrigin::*;
+	new_test_ext::<Test>().execute_with(|| {
+		PausedQueues::set(vec![Here]);
+		MessageQueue::enqueue_message(msg("a"), Here);
+		MessageQueue::enqueue_message(msg("b"), There);
+
+		assert_eq!(MessageQueue::service_queues(Weight::MAX), 1.into_weight());
+		assert_eq!(MessagesProcessed::take(), vec![(vmsg("b"), There)]);
+		assert_eq!(MessageQueue::service_queues(Weight::MAX), Weight::zero());
+		assert_ring(&[Here]);
+
+		PausedQueues::take();
+		assert_eq!(MessageQueue::service_queues(Weight::MAX), 1.into_weight());
+		assert_eq!(MessagesProcessed::take(), vec![(vmsg("a"), Here)]);
+		assert_ring(&[]);
+	});
+}
+
+#[test]
+fn execute_overweight_respects_paused_queues() {
+	use MessageOrigin::*;
+	new_test_ext::<Test>().execute_with(|| {
+		MessageQueue::enqueue_message(msg("weight=5"), Here);
+		assert_eq!(MessageQueue::service_queues(3.into_weight()), Weight::zero());
+		assert_last_event::<Test>(
+			Event::OverweightEnqueued {
+				hash: <Test as frame_system::Config>::Hashing::hash(b"weight=5"),
+				origin: Here,
+				message_index: 0,
+				page_index: 0,
+			}
+			.into(),
+		);
+
+		PausedQueues::set(vec![Here]);
+		assert_eq!(
+			<MessageQueue as ServiceQueues>::execute_overweight(Weight::MAX, (Here, 0, 0)),
+			Err(ExecuteOverweightError::QueuePaused)
+		);
+		assert_noop!(
+			MessageQueue::execute_overweight(RuntimeOrigin::signed(1), Here, 0, 0, Weight::MAX),
+			Error::<Test>::QueuePaused
+		);
+
+		PausedQueues::take();
+		assert_ok!(MessageQueue::execute_overweight(
+			RuntimeOrigin::signed(1),
+			Here,
+			0,
+			0,
+			Weight::MAX
+		));
+		assert_eq!(MessagesProcessed::take(), vec![(vmsg("weight=5"), Here)]);
+	});
+}
+
+#[test]
+fn priority_queues_are_serviced_first() {
+	use MessageOrigin::*;
+	new_test_ext::<Test>().execute_with(|| {
+		PriorityQueues::set(vec![Everywhere(0)]);
+		MessageQueue::enqueue_message(msg("a"), Here);
+		MessageQueue::enqueue_message(msg("b"), There);
+		MessageQueue::enqueue_message(msg("c"), Everywhere(0));
+		MessageQueue::enqueue_message(msg("d"), Everywhere(0));
+		assert_ring(&[Here, There]);
+		assert_eq!(PriorityServiceHead::<Test>::get(), Some(Everywhere(0)));
+
+		assert_eq!(MessageQueue::service_queues(3.into_weight()), 3.into_weight());
+		assert_eq!(
+			MessagesProcessed::take(),
+			vec![(vmsg("c"), Everywhere(0)), (vmsg("d"), Everywhere(0)), (vmsg("a"), Here)]
+		);
+		assert_eq!(PriorityServiceHead::<Test>::get(), None);
+
+		assert_eq!(MessageQueue::service_queues(Weight::MAX), 1.into_weight());
+		assert_eq!(MessagesProcessed::take(), vec![(vmsg("b"), There)]);
+		assert_ring(&[]);
+	});
+}
+
+#[test]
+fn queue_budgets_limit_service_per_call() {
+	use MessageOrigin::*;
+	new_test_ext::<Test>().execute_with(|| {
+		QueueBudgets::set(vec![(Here, 2.into_weight())]);
+		for m in ["a", "b", "c"] {
+			MessageQueue::enqueue_message(msg(m), Here);
+		}
+		MessageQueue::enqueue_message(msg("x"), There);
+
+		assert_eq!(MessageQueue::service_queues(Weight::MAX), 3.into_weight());
+		assert_eq!(
+			MessagesProcessed::take(),
+			vec![(vmsg("a"), Here), (vmsg("b"), Here), (vmsg("x"), There)]
+		);
+		assert_eq!(MessageQueue::service_queues(Weight::MAX), 1.into_weight());
+		assert_eq!(MessagesProcessed::take(), vec![(vmsg("c"), Here)]);
+
+		// A message exceeding the budget is permanently overweight.
+		MessageQueue::enqueue_message(msg("weight=3"), Here);
+		assert_eq!(MessageQueue::service_queues(Weight::MAX), Weight::zero());
+		assert_last_event::<Test>(
+			Event::OverweightEnqueued {
+				hash: <Test as frame_system::Config>::Hashing::hash(b"weight=3"),
+				origin: Here,
+				message_index: 0,
+				page_index: 1,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn queue_info_works() {
+	use MessageOrigin::*;
+	new_test_ext::<Test>().execute_with(|| {
+		PriorityQueues::set(vec![There]);
+		MessageQueue::enqueue_message(msg("weight=5"), Here);
+		MessageQueue::enqueue_message(msg("a"), Here);
+		MessageQueue::enqueue_message(msg("b"), There);
+		assert_eq!(MessageQueue::queue_info(Everywhere(0)), None);
+		assert_eq!(
+			MessageQueue::queue_info(There),
+			Some(QueueInfo {
+				origin: There,
+				message_count: 1,
+				size: 1,
+				page_count: 1,
+				overweight_page_count: 0,
+				overweight_count: 0,
+				ready: true,
+				paused: false,
+				priority: true,
+			})
+		);
+
+		// `weight=5` becomes overweight and `a` is processed.
+		PausedQueues::set(vec![There]);
+		assert_eq!(MessageQueue::service_queues(3.into_weight()), 1.into_weight());
+		assert_eq!(
+			MessageQueue::queue_info(Here),
+			Some(QueueInfo {
+				origin: Here,
+				message_count: 1,
+				size: 8,
+				page_count: 1,
+				overweight_page_count: 1,
+				overweight_count: 1,
+				ready: false,
+				paused: false,
+				priority: false,
+			})
+		);
+		assert_eq!(MessageQueue::queue_infos().len(), 2);
+		assert!(MessageQueue::queue_info(There).unwrap().paused);
+	});
+}
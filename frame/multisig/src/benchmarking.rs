@@ -47,6 +47,15 @@ fn setup_multi<T: Config>(
 	Ok((signatories, Box::new(call)))
 }
 
+fn setup_weighted_multi<T: Config>(
+	s: u32,
+	z: u32,
+) -> Result<(Vec<(T::AccountId, u16)>, Box<<T as Config>::RuntimeCall>), &'static str> {
+	let (signatories, call) = setup_multi::<T>(s, z)?;
+	// Every signatory has a weight of one, so that a threshold of `s` needs them all.
+	Ok((signatories.into_iter().map(|who| (who, 1)).collect(), call))
+}
+
 benchmarks! {
 	as_multi_threshold_1 {
 		// Transaction Length
@@ -210,5 +219,135 @@ benchmarks! {
 		assert!(!Multisigs::<T>::contains_key(multi_account_id, call_hash));
 	}
 
+	as_weighted_multi_create {
+		// Signatories, need at least 2 total people
+		let s in 2 .. T::MaxSignatories::get();
+		// Transaction Length
+		let z in 0 .. 10_000;
+		let (signatories, call) = setup_weighted_multi::<T>(s, z)?;
+		let call_hash = call.using_encoded(blake2_256);
+		let multi_account_id = Multisig::<T>::weighted_multi_account_id(&signatories, s);
+		let caller = signatories[0].0.clone();
+		// Whitelist caller account from further DB operations.
+		let caller_key = frame_system::Account::<T>::hashed_key_for(&caller);
+		frame_benchmarking::benchmarking::add_to_whitelist(caller_key.into());
+	}: as_weighted_multi(RawOrigin::Signed(caller), s, signatories, None, call, Weight::zero())
+	verify {
+		assert!(Multisigs::<T>::contains_key(multi_account_id, call_hash));
+	}
+
+	as_weighted_multi_approve {
+		// Signatories, need at least 3 people (so we don't complete the multisig)
+		let s in 3 .. T::MaxSignatories::get();
+		// Transaction Length
+		let z in 0 .. 10_000;
+		let (signatories, call) = setup_weighted_multi::<T>(s, z)?;
+		let call_hash = call.using_encoded(blake2_256);
+		let multi_account_id = Multisig::<T>::weighted_multi_account_id(&signatories, s);
+		let caller = signatories[0].0.clone();
+		// before the call, get the timepoint
+		let timepoint = Multisig::<T>::timepoint();
+		// Create the multi
+		Multisig::<T>::as_weighted_multi(RawOrigin::Signed(caller).into(), s, signatories.clone(), None, call.clone(), Weight::zero())?;
+		let caller2 = signatories[1].0.clone();
+		// Whitelist caller account from further DB operations.
+		let caller_key = frame_system::Account::<T>::hashed_key_for(&caller2);
+		frame_benchmarking::benchmarking::add_to_whitelist(caller_key.into());
+	}: as_weighted_multi(RawOrigin::Signed(caller2), s, signatories, Some(timepoint), call, Weight::zero())
+	verify {
+		let multisig = Multisigs::<T>::get(multi_account_id, call_hash).ok_or("multisig not created")?;
+		assert_eq!(multisig.approvals.len(), 2);
+	}
+
+	as_weighted_multi_complete {
+		// Signatories, need at least 2 people
+		let s in 2 .. T::MaxSignatories::get();
+		// Transaction Length
+		let z in 0 .. 10_000;
+		let (signatories, call) = setup_weighted_multi::<T>(s, z)?;
+		let call_hash = call.using_encoded(blake2_256);
+		let multi_account_id = Multisig::<T>::weighted_multi_account_id(&signatories, s);
+		// before the call, get the timepoint
+		let timepoint = Multisig::<T>::timepoint();
+		// Everyone except the last person approves
+		for (i, (who, _)) in signatories.iter().take(s as usize - 1).enumerate() {
+			let o = RawOrigin::Signed(who.clone()).into();
+			let maybe_timepoint = if i == 0 { None } else { Some(timepoint) };
+			Multisig::<T>::as_weighted_multi(o, s, signatories.clone(), maybe_timepoint, call.clone(), Weight::zero())?;
+		}
+		let caller = signatories[s as usize - 1].0.clone();
+		assert!(Multisigs::<T>::contains_key(&multi_account_id, call_hash));
+		// Whitelist caller account from further DB operations.
+		let caller_key = frame_system::Account::<T>::hashed_key_for(&caller);
+		frame_benchmarking::benchmarking::add_to_whitelist(caller_key.into());
+	}: as_weighted_multi(RawOrigin::Signed(caller), s, signatories, Some(timepoint), call, Weight::MAX)
+	verify {
+		assert!(!Multisigs::<T>::contains_key(&multi_account_id, call_hash));
+	}
+
+	cancel_as_weighted_multi {
+		// Signatories, need at least 2 people
+		let s in 2 .. T::MaxSignatories::get();
+		// Transaction Length, not a component
+		let z = 10_000;
+		let (signatories, call) = setup_weighted_multi::<T>(s, z)?;
+		let multi_account_id = Multisig::<T>::weighted_multi_account_id(&signatories, s);
+		let caller = signatories[0].0.clone();
+		let call_hash = call.using_encoded(blake2_256);
+		let timepoint = Multisig::<T>::timepoint();
+		// Create the multi
+		let o = RawOrigin::Signed(caller.clone()).into();
+		Multisig::<T>::as_weighted_multi(o, s, signatories.clone(), None, call, Weight::zero())?;
+		assert!(Multisigs::<T>::contains_key(&multi_account_id, call_hash));
+		// Whitelist caller account from further DB operations.
+		let caller_key = frame_system::Account::<T>::hashed_key_for(&caller);
+		frame_benchmarking::benchmarking::add_to_whitelist(caller_key.into());
+	}: _(RawOrigin::Signed(caller), s, signatories, timepoint, call_hash)
+	verify {
+		assert!(!Multisigs::<T>::contains_key(multi_account_id, call_hash));
+	}
+
+	set_expiry {
+		// Signatories, as many as possible for the largest operation
+		let s = T::MaxSignatories::get();
+		let (mut signatories, call) = setup_multi::<T>(s, 10_000)?;
+		let multi_account_id = Multisig::<T>::multi_account_id(&signatories, s.try_into().unwrap());
+		let caller = signatories.pop().ok_or("signatories should have len 2 or more")?;
+		let call_hash = call.using_encoded(blake2_256);
+		let timepoint = Multisig::<T>::timepoint();
+		// Create the multi
+		let o = RawOrigin::Signed(caller.clone()).into();
+		Multisig::<T>::as_multi(o, s as u16, signatories, None, call, Weight::zero())?;
+		let expiry = frame_system::Pallet::<T>::block_number();
+		// Whitelist caller account from further DB operations.
+		let caller_key = frame_system::Account::<T>::hashed_key_for(&caller);
+		frame_benchmarking::benchmarking::add_to_whitelist(caller_key.into());
+	}: _(RawOrigin::Signed(caller), multi_account_id.clone(), call_hash, timepoint, Some(expiry))
+	verify {
+		let multisig = Multisigs::<T>::get(multi_account_id, call_hash).ok_or("multisig not created")?;
+		assert_eq!(multisig.maybe_expiry, Some(expiry));
+	}
+
+	clear_expired_multisig {
+		// Signatories, as many as possible for the largest operation
+		let s = T::MaxSignatories::get();
+		let (mut signatories, call) = setup_multi::<T>(s, 10_000)?;
+		let multi_account_id = Multisig::<T>::multi_account_id(&signatories, s.try_into().unwrap());
+		let depositor = signatories.pop().ok_or("signatories should have len 2 or more")?;
+		let call_hash = call.using_encoded(blake2_256);
+		let timepoint = Multisig::<T>::timepoint();
+		// Create the multi and let it expire
+		let o = RawOrigin::Signed(depositor.clone()).into();
+		Multisig::<T>::as_multi(o, s as u16, signatories, None, call, Weight::zero())?;
+		let expiry = frame_system::Pallet::<T>::block_number();
+		let o = RawOrigin::Signed(depositor).into();
+		Multisig::<T>::set_expiry(o, multi_account_id.clone(), call_hash, timepoint, Some(expiry))?;
+		frame_system::Pallet::<T>::set_block_number(expiry + 1u32.into());
+		let caller = account("caller", 0, SEED);
+	}: _(RawOrigin::Signed(caller), multi_account_id.clone(), call_hash)
+	verify {
+		assert!(!Multisigs::<T>::contains_key(multi_account_id, call_hash));
+	}
+
 	impl_benchmark_test_suite!(Multisig, crate::tests::new_test_ext(), crate::tests::Test);
 }
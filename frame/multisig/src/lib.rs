@@ -31,6 +31,13 @@
 //! operation. This is useful for multisig wallets where cryptographic threshold signatures are
 //! not available or desired.
 //!
+//! Signatories may also be given a weight each, in which case the composite account is derived
+//! from the weighted set of account IDs and the threshold is the total weight of the approvals
+//! needed to dispatch a call.
+//!
+//! The depositor of an operation may set an expiry block for it. Once past that block, the
+//! operation can no longer be approved and anyone may clear it, returning the deposit.
+//!
 //! ## Interface
 //!
 //! ### Dispatchable Functions
@@ -39,6 +46,12 @@
 //!   number of signed origins.
 //! * `approve_as_multi` - Approve a call from a composite origin.
 //! * `cancel_as_multi` - Cancel a call from a composite origin.
+//! * `as_weighted_multi` - Approve and if possible dispatch a call from a weighted composite
+//!   origin.
+//! * `approve_as_weighted_multi` - Approve a call from a weighted composite origin.
+//! * `cancel_as_weighted_multi` - Cancel a call from a weighted composite origin.
+//! * `set_expiry` - Set the block after which an operation expires.
+//! * `clear_expired_multisig` - Remove an expired operation and refund its deposit.
 
 // Ensure we're `no_std` when compiling for Wasm.
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -116,6 +129,8 @@ where
 	depositor: AccountId,
 	/// The approvals achieved so far, including the depositor. Always sorted.
 	approvals: BoundedVec<AccountId, MaxApprovals>,
+	/// The last block at which the operation may be approved, if any.
+	maybe_expiry: Option<BlockNumber>,
 }
 
 type CallHash = [u8; 32];
@@ -125,6 +140,56 @@ enum CallOrHash<T: Config> {
 	Hash([u8; 32]),
 }
 
+/// The signatories of a composite account, as needed to count the approvals of an operation.
+enum Signatories<'a, AccountId> {
+	/// Every signatory has a weight of one. Holds the number of signatories besides the sender.
+	Plain(usize),
+	/// The signatories along with their weight, sorted by account.
+	Weighted(&'a [(AccountId, u16)]),
+}
+
+impl<'a, AccountId: Ord> Signatories<'a, AccountId> {
+	/// The weight of the approval of `who`.
+	fn approval_weight(&self, who: &AccountId) -> u32 {
+		match self {
+			Self::Plain(_) => 1,
+			Self::Weighted(signatories) => signatories
+				.binary_search_by(|(s, _)| s.cmp(who))
+				.map_or(0, |i| signatories[i].1 as u32),
+		}
+	}
+
+	/// The deposit to reserve when opening an operation.
+	fn deposit<T: Config>(&self, threshold: u32) -> BalanceOf<T> {
+		let factor = match self {
+			Self::Plain(_) => threshold,
+			Self::Weighted(signatories) => signatories.len() as u32,
+		};
+		T::DepositBase::get() + T::DepositFactor::get() * factor.into()
+	}
+
+	fn create_weight<T: Config>(&self, z: u32) -> Weight {
+		match self {
+			Self::Plain(s) => T::WeightInfo::as_multi_create(*s as u32, z),
+			Self::Weighted(s) => T::WeightInfo::as_weighted_multi_create(s.len() as u32, z),
+		}
+	}
+
+	fn approve_weight<T: Config>(&self, z: u32) -> Weight {
+		match self {
+			Self::Plain(s) => T::WeightInfo::as_multi_approve(*s as u32, z),
+			Self::Weighted(s) => T::WeightInfo::as_weighted_multi_approve(s.len() as u32, z),
+		}
+	}
+
+	fn complete_weight<T: Config>(&self, z: u32) -> Weight {
+		match self {
+			Self::Plain(s) => T::WeightInfo::as_multi_complete(*s as u32, z),
+			Self::Weighted(s) => T::WeightInfo::as_weighted_multi_complete(s.len() as u32, z),
+		}
+	}
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -169,7 +234,7 @@ pub mod pallet {
 	}
 
 	/// The current storage version.
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
@@ -189,7 +254,7 @@ pub mod pallet {
 
 	#[pallet::error]
 	pub enum Error<T> {
-		/// Threshold must be 2 or greater.
+		/// Threshold must be 2 or greater, or non-zero for a weighted multisig.
 		MinimumThreshold,
 		/// Call is already approved by this signatory.
 		AlreadyApproved,
@@ -217,6 +282,18 @@ pub mod pallet {
 		MaxWeightTooLow,
 		/// The data to be stored is already stored.
 		AlreadyStored,
+		/// The multisig operation has expired.
+		Expired,
+		/// The multisig operation has no expiry or it has not passed yet.
+		NotExpired,
+		/// The given expiry block is in the past.
+		InvalidExpiry,
+		/// The sender is not one of the weighted signatories.
+		SenderNotInSignatories,
+		/// A weighted signatory has a weight of zero.
+		ZeroSignatoryWeight,
+		/// The weights of all the signatories add up to less than the threshold.
+		UnreachableThreshold,
 	}
 
 	#[pallet::event]
@@ -246,6 +323,19 @@ pub mod pallet {
 			multisig: T::AccountId,
 			call_hash: CallHash,
 		},
+		/// The expiry of a multisig operation has been set or removed.
+		MultisigExpirySet {
+			multisig: T::AccountId,
+			call_hash: CallHash,
+			maybe_expiry: Option<T::BlockNumber>,
+		},
+		/// An expired multisig operation has been cleared.
+		MultisigExpired {
+			clearing: T::AccountId,
+			timepoint: Timepoint<T::BlockNumber>,
+			multisig: T::AccountId,
+			call_hash: CallHash,
+		},
 	}
 
 	#[pallet::hooks]
@@ -372,7 +462,7 @@ pub mod pallet {
 			max_weight: Weight,
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
-			Self::operate(
+			Self::operate_plain(
 				who,
 				threshold,
 				other_signatories,
@@ -429,7 +519,7 @@ pub mod pallet {
 			max_weight: Weight,
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
-			Self::operate(
+			Self::operate_plain(
 				who,
 				threshold,
 				other_signatories,
@@ -478,18 +568,192 @@ pub mod pallet {
 
 			let id = Self::multi_account_id(&signatories, threshold);
 
-			let m = <Multisigs<T>>::get(&id, call_hash).ok_or(Error::<T>::NotFound)?;
-			ensure!(m.when == timepoint, Error::<T>::WrongTimepoint);
-			ensure!(m.depositor == who, Error::<T>::NotOwner);
+			Self::cancel(who, id, timepoint, call_hash)
+		}
+
+		/// Register approval for a dispatch to be made from a deterministic weighted composite
+		/// account, and dispatch it once the weights of the approvals add up to `threshold`.
+		///
+		/// Payment: `DepositBase` will be reserved if this is the first approval, plus
+		/// `DepositFactor` times the number of signatories. It is returned once this dispatch
+		/// happens or is cancelled.
+		///
+		/// The dispatch origin for this call must be _Signed_ by one of the `signatories`.
+		///
+		/// - `threshold`: The total weight of the approvals for this dispatch before it is
+		/// executed.
+		/// - `signatories`: The accounts who can approve this dispatch, including the sender,
+		/// along with the weight of their approval. Must be sorted by account.
+		/// - `maybe_timepoint`: If this is the first approval, then this must be `None`. If it is
+		/// not the first approval, then it must be `Some`, with the timepoint (block number and
+		/// transaction index) of the first approval transaction.
+		/// - `call`: The call to be executed.
+		///
+		/// If the weight of the sender alone reaches `threshold`, the call is dispatched at once.
+		///
+		/// ## Complexity
+		/// Same as `as_multi`, with `S` being the number of `signatories`.
+		#[pallet::call_index(4)]
+		#[pallet::weight({
+			let s = signatories.len() as u32;
+			let z = call.using_encoded(|d| d.len()) as u32;
+
+			T::WeightInfo::as_weighted_multi_create(s, z)
+			.max(T::WeightInfo::as_weighted_multi_approve(s, z))
+			.max(T::WeightInfo::as_weighted_multi_complete(s, z))
+			.saturating_add(*max_weight)
+		})]
+		pub fn as_weighted_multi(
+			origin: OriginFor<T>,
+			threshold: u32,
+			signatories: Vec<(T::AccountId, u16)>,
+			maybe_timepoint: Option<Timepoint<T::BlockNumber>>,
+			call: Box<<T as Config>::RuntimeCall>,
+			max_weight: Weight,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let id = Self::ensure_weighted_signatories(&who, threshold, &signatories)?;
+			Self::operate(
+				who,
+				id,
+				threshold,
+				Signatories::Weighted(&signatories),
+				maybe_timepoint,
+				CallOrHash::Call(*call),
+				max_weight,
+			)
+		}
+
+		/// Register approval for a dispatch to be made from a deterministic weighted composite
+		/// account, given the hash of the call.
+		///
+		/// Deposits and parameters are as for `as_weighted_multi`, with `call_hash` being the
+		/// hash of the call to be executed.
+		///
+		/// NOTE: If this is the final approval, you will want to use `as_weighted_multi` instead.
+		#[pallet::call_index(5)]
+		#[pallet::weight({
+			let s = signatories.len() as u32;
+
+			T::WeightInfo::as_weighted_multi_create(s, 0)
+				.max(T::WeightInfo::as_weighted_multi_approve(s, 0))
+				.saturating_add(*max_weight)
+		})]
+		pub fn approve_as_weighted_multi(
+			origin: OriginFor<T>,
+			threshold: u32,
+			signatories: Vec<(T::AccountId, u16)>,
+			maybe_timepoint: Option<Timepoint<T::BlockNumber>>,
+			call_hash: [u8; 32],
+			max_weight: Weight,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let id = Self::ensure_weighted_signatories(&who, threshold, &signatories)?;
+			Self::operate(
+				who,
+				id,
+				threshold,
+				Signatories::Weighted(&signatories),
+				maybe_timepoint,
+				CallOrHash::Hash(call_hash),
+				max_weight,
+			)
+		}
+
+		/// Cancel a pre-existing, on-going weighted multisig transaction. Any deposit reserved
+		/// previously for this operation will be unreserved on success.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the depositor.
+		///
+		/// - `threshold`: The total weight of the approvals for this dispatch before it is
+		/// executed.
+		/// - `signatories`: The accounts who can approve this dispatch, including the sender,
+		/// along with the weight of their approval. Must be sorted by account.
+		/// - `timepoint`: The timepoint (block number and transaction index) of the first approval
+		/// transaction for this dispatch.
+		/// - `call_hash`: The hash of the call to be executed.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::cancel_as_weighted_multi(signatories.len() as u32))]
+		pub fn cancel_as_weighted_multi(
+			origin: OriginFor<T>,
+			threshold: u32,
+			signatories: Vec<(T::AccountId, u16)>,
+			timepoint: Timepoint<T::BlockNumber>,
+			call_hash: [u8; 32],
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let id = Self::ensure_weighted_signatories(&who, threshold, &signatories)?;
+
+			Self::cancel(who, id, timepoint, call_hash)
+		}
+
+		/// Set or remove the expiry of a pre-existing, on-going multisig transaction.
+		///
+		/// Past its expiry block, the operation can no longer be approved and anyone may clear
+		/// it with `clear_expired_multisig`.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the depositor.
+		///
+		/// - `multisig`: The composite account the call is to be dispatched from.
+		/// - `call_hash`: The hash of the call to be executed.
+		/// - `timepoint`: The timepoint (block number and transaction index) of the first approval
+		/// transaction for this dispatch.
+		/// - `maybe_expiry`: The last block at which the operation may be approved, or `None` for
+		/// the operation to never expire.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::set_expiry())]
+		pub fn set_expiry(
+			origin: OriginFor<T>,
+			multisig: T::AccountId,
+			call_hash: [u8; 32],
+			timepoint: Timepoint<T::BlockNumber>,
+			maybe_expiry: Option<T::BlockNumber>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			if let Some(expiry) = maybe_expiry {
+				ensure!(expiry >= <system::Pallet<T>>::block_number(), Error::<T>::InvalidExpiry);
+			}
+
+			<Multisigs<T>>::try_mutate(&multisig, call_hash, |maybe_m| -> DispatchResult {
+				let m = maybe_m.as_mut().ok_or(Error::<T>::NotFound)?;
+				ensure!(m.when == timepoint, Error::<T>::WrongTimepoint);
+				ensure!(m.depositor == who, Error::<T>::NotOwner);
+				m.maybe_expiry = maybe_expiry;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::MultisigExpirySet { multisig, call_hash, maybe_expiry });
+			Ok(())
+		}
+
+		/// Remove a multisig transaction which is past its expiry block, unreserving the deposit
+		/// of its depositor.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// - `multisig`: The composite account the call was to be dispatched from.
+		/// - `call_hash`: The hash of the call to be executed.
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::clear_expired_multisig())]
+		pub fn clear_expired_multisig(
+			origin: OriginFor<T>,
+			multisig: T::AccountId,
+			call_hash: [u8; 32],
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let m = <Multisigs<T>>::get(&multisig, call_hash).ok_or(Error::<T>::NotFound)?;
+			let now = <system::Pallet<T>>::block_number();
+			ensure!(m.maybe_expiry.map_or(false, |expiry| expiry < now), Error::<T>::NotExpired);
 
 			let err_amount = T::Currency::unreserve(&m.depositor, m.deposit);
 			debug_assert!(err_amount.is_zero());
-			<Multisigs<T>>::remove(&id, &call_hash);
+			<Multisigs<T>>::remove(&multisig, &call_hash);
 
-			Self::deposit_event(Event::MultisigCancelled {
-				cancelling: who,
-				timepoint,
-				multisig: id,
+			Self::deposit_event(Event::MultisigExpired {
+				clearing: who,
+				timepoint: m.when,
+				multisig,
 				call_hash,
 			});
 			Ok(())
@@ -508,7 +772,17 @@ impl<T: Config> Pallet<T> {
 			.expect("infinite length input; no invalid inputs for type; qed")
 	}
 
-	fn operate(
+	/// Derive a multi-account ID from the list of accounts, along with the weight of each of
+	/// their approvals, and the total weight of the approvals that is required.
+	///
+	/// NOTE: `who` must be sorted by account. If it is not, then you'll get the wrong answer.
+	pub fn weighted_multi_account_id(who: &[(T::AccountId, u16)], threshold: u32) -> T::AccountId {
+		let entropy = (b"modlpy/wmultisig", who, threshold).using_encoded(blake2_256);
+		Decode::decode(&mut TrailingZeroInput::new(entropy.as_ref()))
+			.expect("infinite length input; no invalid inputs for type; qed")
+	}
+
+	fn operate_plain(
 		who: T::AccountId,
 		threshold: u16,
 		other_signatories: Vec<T::AccountId>,
@@ -525,7 +799,27 @@ impl<T: Config> Pallet<T> {
 
 		let id = Self::multi_account_id(&signatories, threshold);
 
-		// Threshold > 1; this means it's a multi-step operation. We extract the `call_hash`.
+		Self::operate(
+			who,
+			id,
+			threshold.into(),
+			Signatories::Plain(other_signatories_len),
+			maybe_timepoint,
+			call_or_hash,
+			max_weight,
+		)
+	}
+
+	fn operate(
+		who: T::AccountId,
+		id: T::AccountId,
+		threshold: u32,
+		signatories: Signatories<T::AccountId>,
+		maybe_timepoint: Option<Timepoint<T::BlockNumber>>,
+		call_or_hash: CallOrHash<T>,
+		max_weight: Weight,
+	) -> DispatchResultWithPostInfo {
+		// We extract the `call_hash`.
 		let (call_hash, call_len, maybe_call) = match call_or_hash {
 			CallOrHash::Call(call) => {
 				let (call_hash, call_len) = call.using_encoded(|d| (blake2_256(d), d.len()));
@@ -539,14 +833,17 @@ impl<T: Config> Pallet<T> {
 			// Yes; ensure that the timepoint exists and agrees.
 			let timepoint = maybe_timepoint.ok_or(Error::<T>::NoTimepoint)?;
 			ensure!(m.when == timepoint, Error::<T>::WrongTimepoint);
+			let now = <system::Pallet<T>>::block_number();
+			ensure!(m.maybe_expiry.map_or(true, |expiry| now <= expiry), Error::<T>::Expired);
 
 			// Ensure that either we have not yet signed or that it is at threshold.
-			let mut approvals = m.approvals.len() as u16;
+			let mut approvals: u32 =
+				m.approvals.iter().map(|a| signatories.approval_weight(a)).sum();
 			// We only bother with the approval if we're below threshold.
 			let maybe_pos = m.approvals.binary_search(&who).err().filter(|_| approvals < threshold);
 			// Bump approvals if not yet voted and the vote is needed.
 			if maybe_pos.is_some() {
-				approvals += 1;
+				approvals += signatories.approval_weight(&who);
 			}
 
 			// We only bother fetching/decoding call if we know that we're ready to execute.
@@ -562,23 +859,14 @@ impl<T: Config> Pallet<T> {
 				<Multisigs<T>>::remove(&id, call_hash);
 				T::Currency::unreserve(&m.depositor, m.deposit);
 
-				let result = call.dispatch(RawOrigin::Signed(id.clone()).into());
-				Self::deposit_event(Event::MultisigExecuted {
-					approving: who,
+				Ok(Self::dispatch(
+					who,
+					id,
 					timepoint,
-					multisig: id,
 					call_hash,
-					result: result.map(|_| ()).map_err(|e| e.error),
-				});
-				Ok(get_result_weight(result)
-					.map(|actual_weight| {
-						T::WeightInfo::as_multi_complete(
-							other_signatories_len as u32,
-							call_len as u32,
-						)
-						.saturating_add(actual_weight)
-					})
-					.into())
+					call,
+					signatories.complete_weight::<T>(call_len as u32),
+				))
 			} else {
 				// We cannot dispatch the call now; either it isn't available, or it is, but we
 				// don't have threshold approvals even with our signature.
@@ -601,8 +889,7 @@ impl<T: Config> Pallet<T> {
 					Err(Error::<T>::AlreadyApproved)?
 				}
 
-				let final_weight =
-					T::WeightInfo::as_multi_approve(other_signatories_len as u32, call_len as u32);
+				let final_weight = signatories.approve_weight::<T>(call_len as u32);
 				// Call is not made, so the actual weight does not include call
 				Ok(Some(final_weight).into())
 			}
@@ -610,8 +897,26 @@ impl<T: Config> Pallet<T> {
 			// Not yet started; there should be no timepoint given.
 			ensure!(maybe_timepoint.is_none(), Error::<T>::UnexpectedTimepoint);
 
+			// A weighted signatory may reach the threshold on their own.
+			if let Some(call) =
+				maybe_call.filter(|_| signatories.approval_weight(&who) >= threshold)
+			{
+				ensure!(
+					call.get_dispatch_info().weight.all_lte(max_weight),
+					Error::<T>::MaxWeightTooLow
+				);
+				return Ok(Self::dispatch(
+					who,
+					id,
+					Self::timepoint(),
+					call_hash,
+					call,
+					signatories.complete_weight::<T>(call_len as u32),
+				))
+			}
+
 			// Just start the operation by recording it in storage.
-			let deposit = T::DepositBase::get() + T::DepositFactor::get() * threshold.into();
+			let deposit = signatories.deposit::<T>(threshold);
 
 			T::Currency::reserve(&who, deposit)?;
 
@@ -626,17 +931,65 @@ impl<T: Config> Pallet<T> {
 					deposit,
 					depositor: who.clone(),
 					approvals: initial_approvals,
+					maybe_expiry: None,
 				},
 			);
 			Self::deposit_event(Event::NewMultisig { approving: who, multisig: id, call_hash });
 
-			let final_weight =
-				T::WeightInfo::as_multi_create(other_signatories_len as u32, call_len as u32);
+			let final_weight = signatories.create_weight::<T>(call_len as u32);
 			// Call is not made, so the actual weight does not include call
 			Ok(Some(final_weight).into())
 		}
 	}
 
+	/// Dispatch an approved `call` from the composite account `id`, returning the actual weight
+	/// of the dispatch on top of `base_weight`, if known.
+	fn dispatch(
+		who: T::AccountId,
+		id: T::AccountId,
+		timepoint: Timepoint<T::BlockNumber>,
+		call_hash: CallHash,
+		call: <T as Config>::RuntimeCall,
+		base_weight: Weight,
+	) -> PostDispatchInfo {
+		let result = call.dispatch(RawOrigin::Signed(id.clone()).into());
+		Self::deposit_event(Event::MultisigExecuted {
+			approving: who,
+			timepoint,
+			multisig: id,
+			call_hash,
+			result: result.map(|_| ()).map_err(|e| e.error),
+		});
+		get_result_weight(result)
+			.map(|actual_weight| base_weight.saturating_add(actual_weight))
+			.into()
+	}
+
+	/// Cancel the operation of `call_hash` from the composite account `id`, which must have been
+	/// opened by `who` at `timepoint`.
+	fn cancel(
+		who: T::AccountId,
+		id: T::AccountId,
+		timepoint: Timepoint<T::BlockNumber>,
+		call_hash: CallHash,
+	) -> DispatchResult {
+		let m = <Multisigs<T>>::get(&id, call_hash).ok_or(Error::<T>::NotFound)?;
+		ensure!(m.when == timepoint, Error::<T>::WrongTimepoint);
+		ensure!(m.depositor == who, Error::<T>::NotOwner);
+
+		let err_amount = T::Currency::unreserve(&m.depositor, m.deposit);
+		debug_assert!(err_amount.is_zero());
+		<Multisigs<T>>::remove(&id, &call_hash);
+
+		Self::deposit_event(Event::MultisigCancelled {
+			cancelling: who,
+			timepoint,
+			multisig: id,
+			call_hash,
+		});
+		Ok(())
+	}
+
 	/// The current `Timepoint`.
 	pub fn timepoint() -> Timepoint<T::BlockNumber> {
 		Timepoint {
@@ -666,6 +1019,33 @@ impl<T: Config> Pallet<T> {
 		signatories.insert(index, who);
 		Ok(signatories)
 	}
+
+	/// Check that the weighted `signatories` are sorted, contain `who` and may reach
+	/// `threshold`, then derive their composite account.
+	fn ensure_weighted_signatories(
+		who: &T::AccountId,
+		threshold: u32,
+		signatories: &[(T::AccountId, u16)],
+	) -> Result<T::AccountId, DispatchError> {
+		ensure!(threshold >= 1, Error::<T>::MinimumThreshold);
+		ensure!(signatories.len() >= 2, Error::<T>::TooFewSignatories);
+		ensure!(
+			signatories.len() <= T::MaxSignatories::get() as usize,
+			Error::<T>::TooManySignatories
+		);
+		ensure!(
+			signatories.windows(2).all(|pair| pair[0].0 < pair[1].0),
+			Error::<T>::SignatoriesOutOfOrder
+		);
+		ensure!(signatories.iter().all(|(_, w)| *w > 0), Error::<T>::ZeroSignatoryWeight);
+		ensure!(
+			signatories.binary_search_by(|(s, _)| s.cmp(who)).is_ok(),
+			Error::<T>::SenderNotInSignatories
+		);
+		let total: u32 = signatories.iter().map(|(_, w)| *w as u32).sum();
+		ensure!(total >= threshold, Error::<T>::UnreachableThreshold);
+		Ok(Self::weighted_multi_account_id(signatories, threshold))
+	}
 }
 
 /// Return the weight of a dispatch call result as an `Option`.
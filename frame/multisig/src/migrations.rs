@@ -20,9 +20,10 @@
 use super::*;
 use frame_support::{
 	dispatch::GetStorageVersion,
-	traits::{OnRuntimeUpgrade, WrapperKeepOpaque},
+	traits::{Get, OnRuntimeUpgrade, StorageVersion, WrapperKeepOpaque},
 	Identity,
 };
+use sp_runtime::Saturating;
 
 #[cfg(feature = "try-runtime")]
 use frame_support::ensure;
@@ -54,7 +55,6 @@ pub mod v1 {
 		}
 
 		fn on_runtime_upgrade() -> Weight {
-			let current = Pallet::<T>::current_storage_version();
 			let onchain = Pallet::<T>::on_chain_storage_version();
 
 			if onchain > 0 {
@@ -66,7 +66,7 @@ pub mod v1 {
 				T::Currency::unreserve(&caller, deposit);
 			});
 
-			current.put::<Pallet<T>>();
+			StorageVersion::new(1).put::<Pallet<T>>();
 
 			<T as frame_system::Config>::BlockWeights::get().max_block
 		}
@@ -84,3 +84,78 @@ pub mod v1 {
 		}
 	}
 }
+
+pub mod v2 {
+	use super::*;
+
+	/// The multisig operation as stored before the expiry was added.
+	#[derive(Decode)]
+	struct OldMultisig<BlockNumber, Balance, AccountId, MaxApprovals>
+	where
+		MaxApprovals: Get<u32>,
+	{
+		when: Timepoint<BlockNumber>,
+		deposit: Balance,
+		depositor: AccountId,
+		approvals: BoundedVec<AccountId, MaxApprovals>,
+	}
+
+	/// Give every open multisig operation no expiry.
+	pub struct MigrateToV2<T>(sp_std::marker::PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			let onchain = Pallet::<T>::on_chain_storage_version();
+
+			ensure!(onchain < 2, "this migration can be deleted");
+
+			let count = Multisigs::<T>::iter_keys().count() as u32;
+			log!(info, "Number of multisig operations to migrate: {}", count);
+
+			Ok(count.encode())
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			let onchain = Pallet::<T>::on_chain_storage_version();
+
+			if onchain != 1 {
+				log!(info, "MigrateToV2 should be removed");
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut translated = 0u64;
+			Multisigs::<T>::translate::<
+				OldMultisig<T::BlockNumber, BalanceOf<T>, T::AccountId, T::MaxSignatories>,
+				_,
+			>(|_, _, old| {
+				translated.saturating_inc();
+				Some(Multisig {
+					when: old.when,
+					deposit: old.deposit,
+					depositor: old.depositor,
+					approvals: old.approvals,
+					maybe_expiry: None,
+				})
+			});
+
+			StorageVersion::new(2).put::<Pallet<T>>();
+			log!(info, "Migrated {} multisig operations", translated);
+
+			T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+			let onchain = Pallet::<T>::on_chain_storage_version();
+			ensure!(onchain == 2, "this migration needs to be run");
+
+			let count: u32 =
+				Decode::decode(&mut &state[..]).map_err(|_| "the state cannot be decoded")?;
+			ensure!(
+				Multisigs::<T>::iter().count() as u32 == count,
+				"some multisig operations could not be migrated"
+			);
+			Ok(())
+		}
+	}
+}
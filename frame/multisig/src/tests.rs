@@ -78,6 +78,10 @@ impl pallet_balances::Config for Test {
 	type MaxLocks = ();
 	type MaxReserves = ();
 	type ReserveIdentifier = [u8; 8];
+	type HoldIdentifier = ();
+	type FreezeIdentifier = ();
+	type MaxHolds = ();
+	type MaxFreezes = ();
 	type Balance = u64;
 	type RuntimeEvent = RuntimeEvent;
 	type DustRemoval = ();
@@ -726,3 +730,275 @@ fn multisig_handles_no_preimage_after_all_approve() {
 		assert_eq!(Balances::free_balance(6), 15);
 	});
 }
+
+#[test]
+fn weighted_multisig_dispatches_at_threshold_weight() {
+	new_test_ext().execute_with(|| {
+		let signatories = vec![(1, 2), (2, 1), (3, 1)];
+		let multi = Multisig::weighted_multi_account_id(&signatories, 3);
+		assert_ok!(Balances::transfer(RuntimeOrigin::signed(4), multi, 10));
+
+		let call = call_transfer(6, 5);
+		let call_weight = call.get_dispatch_info().weight;
+		assert_ok!(Multisig::as_weighted_multi(
+			RuntimeOrigin::signed(2),
+			3,
+			signatories.clone(),
+			None,
+			call.clone(),
+			Weight::zero()
+		));
+		// The deposit is taken for every signatory.
+		assert_eq!(Balances::reserved_balance(2), 4);
+
+		assert_ok!(Multisig::as_weighted_multi(
+			RuntimeOrigin::signed(3),
+			3,
+			signatories.clone(),
+			Some(now()),
+			call.clone(),
+			Weight::zero()
+		));
+		assert_eq!(Balances::free_balance(6), 0);
+
+		// The heavier signatory completes the threshold.
+		assert_ok!(Multisig::as_weighted_multi(
+			RuntimeOrigin::signed(1),
+			3,
+			signatories,
+			Some(now()),
+			call,
+			call_weight
+		));
+		assert_eq!(Balances::free_balance(6), 5);
+		assert_eq!(Balances::reserved_balance(2), 0);
+	});
+}
+
+#[test]
+fn weighted_multisig_dispatches_at_once_for_heavy_signatory() {
+	new_test_ext().execute_with(|| {
+		let signatories = vec![(1, 3), (2, 1), (3, 1)];
+		let multi = Multisig::weighted_multi_account_id(&signatories, 3);
+		assert_ok!(Balances::transfer(RuntimeOrigin::signed(4), multi, 10));
+
+		let call = call_transfer(6, 5);
+		let call_weight = call.get_dispatch_info().weight;
+		assert_ok!(Multisig::as_weighted_multi(
+			RuntimeOrigin::signed(1),
+			3,
+			signatories,
+			None,
+			call.clone(),
+			call_weight
+		));
+		assert_eq!(Balances::free_balance(6), 5);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		let call_hash = blake2_256(&call.encode());
+		assert!(!Multisigs::<Test>::contains_key(multi, call_hash));
+	});
+}
+
+#[test]
+fn weighted_multisig_checks_signatories() {
+	new_test_ext().execute_with(|| {
+		let call = call_transfer(6, 5);
+		assert_noop!(
+			Multisig::as_weighted_multi(
+				RuntimeOrigin::signed(4),
+				2,
+				vec![(1, 1), (2, 1)],
+				None,
+				call.clone(),
+				Weight::zero()
+			),
+			Error::<Test>::SenderNotInSignatories,
+		);
+		assert_noop!(
+			Multisig::as_weighted_multi(
+				RuntimeOrigin::signed(1),
+				2,
+				vec![(2, 1), (1, 1)],
+				None,
+				call.clone(),
+				Weight::zero()
+			),
+			Error::<Test>::SignatoriesOutOfOrder,
+		);
+		assert_noop!(
+			Multisig::as_weighted_multi(
+				RuntimeOrigin::signed(1),
+				2,
+				vec![(1, 1), (2, 0)],
+				None,
+				call.clone(),
+				Weight::zero()
+			),
+			Error::<Test>::ZeroSignatoryWeight,
+		);
+		assert_noop!(
+			Multisig::as_weighted_multi(
+				RuntimeOrigin::signed(1),
+				3,
+				vec![(1, 1), (2, 1)],
+				None,
+				call.clone(),
+				Weight::zero()
+			),
+			Error::<Test>::UnreachableThreshold,
+		);
+		assert_noop!(
+			Multisig::as_weighted_multi(
+				RuntimeOrigin::signed(1),
+				0,
+				vec![(1, 1), (2, 1)],
+				None,
+				call.clone(),
+				Weight::zero()
+			),
+			Error::<Test>::MinimumThreshold,
+		);
+		assert_noop!(
+			Multisig::as_weighted_multi(
+				RuntimeOrigin::signed(1),
+				2,
+				vec![(1, 1), (2, 1), (3, 1), (4, 1)],
+				None,
+				call,
+				Weight::zero()
+			),
+			Error::<Test>::TooManySignatories,
+		);
+	});
+}
+
+#[test]
+fn cancel_weighted_multisig_returns_deposit() {
+	new_test_ext().execute_with(|| {
+		let signatories = vec![(1, 1), (2, 1)];
+		let hash = blake2_256(&call_transfer(6, 5).encode());
+		assert_ok!(Multisig::approve_as_weighted_multi(
+			RuntimeOrigin::signed(1),
+			2,
+			signatories.clone(),
+			None,
+			hash,
+			Weight::zero()
+		));
+		assert_eq!(Balances::reserved_balance(1), 3);
+		assert_noop!(
+			Multisig::cancel_as_weighted_multi(
+				RuntimeOrigin::signed(2),
+				2,
+				signatories.clone(),
+				now(),
+				hash
+			),
+			Error::<Test>::NotOwner,
+		);
+		assert_ok!(Multisig::cancel_as_weighted_multi(
+			RuntimeOrigin::signed(1),
+			2,
+			signatories,
+			now(),
+			hash
+		));
+		assert_eq!(Balances::reserved_balance(1), 0);
+	});
+}
+
+#[test]
+fn expired_multisig_cannot_be_approved_and_can_be_cleared() {
+	new_test_ext().execute_with(|| {
+		let multi = Multisig::multi_account_id(&[1, 2, 3][..], 2);
+		let call = call_transfer(6, 15);
+		let hash = blake2_256(&call.encode());
+		assert_ok!(Multisig::approve_as_multi(
+			RuntimeOrigin::signed(1),
+			2,
+			vec![2, 3],
+			None,
+			hash,
+			Weight::zero()
+		));
+		let timepoint = now();
+		assert_eq!(Balances::reserved_balance(1), 3);
+
+		assert_noop!(
+			Multisig::set_expiry(RuntimeOrigin::signed(2), multi, hash, timepoint, Some(5)),
+			Error::<Test>::NotOwner,
+		);
+		assert_ok!(Multisig::set_expiry(RuntimeOrigin::signed(1), multi, hash, timepoint, Some(5)));
+		System::assert_last_event(
+			pallet_multisig::Event::MultisigExpirySet {
+				multisig: multi,
+				call_hash: hash,
+				maybe_expiry: Some(5),
+			}
+			.into(),
+		);
+
+		// The operation can only be cleared once past its expiry block.
+		System::set_block_number(5);
+		assert_noop!(
+			Multisig::clear_expired_multisig(RuntimeOrigin::signed(4), multi, hash),
+			Error::<Test>::NotExpired,
+		);
+
+		System::set_block_number(6);
+		assert_noop!(
+			Multisig::as_multi(
+				RuntimeOrigin::signed(2),
+				2,
+				vec![1, 3],
+				Some(timepoint),
+				call,
+				Weight::MAX
+			),
+			Error::<Test>::Expired,
+		);
+		assert_ok!(Multisig::clear_expired_multisig(RuntimeOrigin::signed(4), multi, hash));
+		System::assert_last_event(
+			pallet_multisig::Event::MultisigExpired {
+				clearing: 4,
+				timepoint,
+				multisig: multi,
+				call_hash: hash,
+			}
+			.into(),
+		);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert!(!Multisigs::<Test>::contains_key(multi, hash));
+	});
+}
+
+#[test]
+fn set_expiry_checks_expiry_and_can_remove_it() {
+	new_test_ext().execute_with(|| {
+		let multi = Multisig::multi_account_id(&[1, 2, 3][..], 2);
+		let hash = blake2_256(&call_transfer(6, 15).encode());
+		assert_ok!(Multisig::approve_as_multi(
+			RuntimeOrigin::signed(1),
+			2,
+			vec![2, 3],
+			None,
+			hash,
+			Weight::zero()
+		));
+		let timepoint = now();
+
+		System::set_block_number(3);
+		assert_noop!(
+			Multisig::set_expiry(RuntimeOrigin::signed(1), multi, hash, timepoint, Some(2)),
+			Error::<Test>::InvalidExpiry,
+		);
+		assert_ok!(Multisig::set_expiry(RuntimeOrigin::signed(1), multi, hash, timepoint, Some(3)));
+		assert_ok!(Multisig::set_expiry(RuntimeOrigin::signed(1), multi, hash, timepoint, None));
+
+		System::set_block_number(10);
+		assert_noop!(
+			Multisig::clear_expired_multisig(RuntimeOrigin::signed(4), multi, hash),
+			Error::<Test>::NotExpired,
+		);
+	});
+}
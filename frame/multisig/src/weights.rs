@@ -55,6 +55,12 @@ pub trait WeightInfo {
 	fn approve_as_multi_create(s: u32, ) -> Weight;
 	fn approve_as_multi_approve(s: u32, ) -> Weight;
 	fn cancel_as_multi(s: u32, ) -> Weight;
+	fn as_weighted_multi_create(s: u32, z: u32, ) -> Weight;
+	fn as_weighted_multi_approve(s: u32, z: u32, ) -> Weight;
+	fn as_weighted_multi_complete(s: u32, z: u32, ) -> Weight;
+	fn cancel_as_weighted_multi(s: u32, ) -> Weight;
+	fn set_expiry() -> Weight;
+	fn clear_expired_multisig() -> Weight;
 }
 
 /// Weights for pallet_multisig using the Substrate node and recommended hardware.
@@ -71,15 +77,15 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(494, 0).saturating_mul(z.into()))
 	}
 	/// Storage: Multisig Multisigs (r:1 w:1)
-	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(3346), added: 5821, mode: MaxEncodedLen)
+	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(3351), added: 5826, mode: MaxEncodedLen)
 	/// The range of component `s` is `[2, 100]`.
 	/// The range of component `z` is `[0, 10000]`.
 	fn as_multi_create(s: u32, z: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `352 + s * (2 ±0)`
-		//  Estimated: `5821`
+		//  Estimated: `5826`
 		// Minimum execution time: 35_377 nanoseconds.
-		Weight::from_parts(29_088_956, 5821)
+		Weight::from_parts(29_088_956, 5826)
 			// Standard Error: 335
 			.saturating_add(Weight::from_parts(67_846, 0).saturating_mul(s.into()))
 			// Standard Error: 3
@@ -88,15 +94,15 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: Multisig Multisigs (r:1 w:1)
-	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(3346), added: 5821, mode: MaxEncodedLen)
+	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(3351), added: 5826, mode: MaxEncodedLen)
 	/// The range of component `s` is `[3, 100]`.
 	/// The range of component `z` is `[0, 10000]`.
 	fn as_multi_approve(s: u32, z: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `351`
-		//  Estimated: `5821`
+		//  Estimated: `5826`
 		// Minimum execution time: 26_138 nanoseconds.
-		Weight::from_parts(20_479_380, 5821)
+		Weight::from_parts(20_479_380, 5826)
 			// Standard Error: 259
 			.saturating_add(Weight::from_parts(64_116, 0).saturating_mul(s.into()))
 			// Standard Error: 2
@@ -105,7 +111,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: Multisig Multisigs (r:1 w:1)
-	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(3346), added: 5821, mode: MaxEncodedLen)
+	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(3351), added: 5826, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	/// The range of component `s` is `[2, 100]`.
@@ -113,9 +119,9 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn as_multi_complete(s: u32, z: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `489 + s * (33 ±0)`
-		//  Estimated: `8424`
+		//  Estimated: `8429`
 		// Minimum execution time: 40_323 nanoseconds.
-		Weight::from_parts(32_311_615, 8424)
+		Weight::from_parts(32_311_615, 8429)
 			// Standard Error: 401
 			.saturating_add(Weight::from_parts(85_999, 0).saturating_mul(s.into()))
 			// Standard Error: 3
@@ -124,47 +130,136 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: Multisig Multisigs (r:1 w:1)
-	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(3346), added: 5821, mode: MaxEncodedLen)
+	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(3351), added: 5826, mode: MaxEncodedLen)
 	/// The range of component `s` is `[2, 100]`.
 	fn approve_as_multi_create(s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `359 + s * (2 ±0)`
-		//  Estimated: `5821`
+		//  Estimated: `5826`
 		// Minimum execution time: 26_938 nanoseconds.
-		Weight::from_parts(27_802_216, 5821)
+		Weight::from_parts(27_802_216, 5826)
 			// Standard Error: 342
 			.saturating_add(Weight::from_parts(69_282, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: Multisig Multisigs (r:1 w:1)
-	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(3346), added: 5821, mode: MaxEncodedLen)
+	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(3351), added: 5826, mode: MaxEncodedLen)
 	/// The range of component `s` is `[2, 100]`.
 	fn approve_as_multi_approve(s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `351`
-		//  Estimated: `5821`
+		//  Estimated: `5826`
 		// Minimum execution time: 18_050 nanoseconds.
-		Weight::from_parts(19_095_404, 5821)
+		Weight::from_parts(19_095_404, 5826)
 			// Standard Error: 419
 			.saturating_add(Weight::from_parts(66_914, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: Multisig Multisigs (r:1 w:1)
-	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(3346), added: 5821, mode: MaxEncodedLen)
+	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(3351), added: 5826, mode: MaxEncodedLen)
 	/// The range of component `s` is `[2, 100]`.
 	fn cancel_as_multi(s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `555 + s * (1 ±0)`
-		//  Estimated: `5821`
+		//  Estimated: `5826`
 		// Minimum execution time: 27_508 nanoseconds.
-		Weight::from_parts(28_702_686, 5821)
+		Weight::from_parts(28_702_686, 5826)
 			// Standard Error: 466
 			.saturating_add(Weight::from_parts(69_419, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: Multisig Multisigs (r:1 w:1)
+	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(3351), added: 5826, mode: MaxEncodedLen)
+	/// The range of component `s` is `[2, 100]`.
+	/// The range of component `z` is `[0, 10000]`.
+	fn as_weighted_multi_create(s: u32, z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `352 + s * (2 ±0)`
+		//  Estimated: `5826`
+		// Minimum execution time: 37_815 nanoseconds.
+		Weight::from_parts(31_224_507, 5826)
+			// Standard Error: 352
+			.saturating_add(Weight::from_parts(88_613, 0).saturating_mul(s.into()))
+			// Standard Error: 3
+			.saturating_add(Weight::from_parts(1_527, 0).saturating_mul(z.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Multisig Multisigs (r:1 w:1)
+	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(3351), added: 5826, mode: MaxEncodedLen)
+	/// The range of component `s` is `[3, 100]`.
+	/// The range of component `z` is `[0, 10000]`.
+	fn as_weighted_multi_approve(s: u32, z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `356`
+		//  Estimated: `5826`
+		// Minimum execution time: 28_406 nanoseconds.
+		Weight::from_parts(22_031_872, 5826)
+			// Standard Error: 281
+			.saturating_add(Weight::from_parts(91_208, 0).saturating_mul(s.into()))
+			// Standard Error: 2
+			.saturating_add(Weight::from_parts(1_522, 0).saturating_mul(z.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Multisig Multisigs (r:1 w:1)
+	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(3351), added: 5826, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `s` is `[2, 100]`.
+	/// The range of component `z` is `[0, 10000]`.
+	fn as_weighted_multi_complete(s: u32, z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `494 + s * (33 ±0)`
+		//  Estimated: `8429`
+		// Minimum execution time: 42_917 nanoseconds.
+		Weight::from_parts(34_180_330, 8429)
+			// Standard Error: 417
+			.saturating_add(Weight::from_parts(112_904, 0).saturating_mul(s.into()))
+			// Standard Error: 3
+			.saturating_add(Weight::from_parts(1_536, 0).saturating_mul(z.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Multisig Multisigs (r:1 w:1)
+	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(3351), added: 5826, mode: MaxEncodedLen)
+	/// The range of component `s` is `[2, 100]`.
+	fn cancel_as_weighted_multi(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `560 + s * (1 ±0)`
+		//  Estimated: `5826`
+		// Minimum execution time: 29_964 nanoseconds.
+		Weight::from_parts(30_841_195, 5826)
+			// Standard Error: 478
+			.saturating_add(Weight::from_parts(93_720, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Multisig Multisigs (r:1 w:1)
+	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(3351), added: 5826, mode: MaxEncodedLen)
+	fn set_expiry() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `654`
+		//  Estimated: `5826`
+		// Minimum execution time: 20_113 nanoseconds.
+		Weight::from_parts(20_733_000, 5826)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Multisig Multisigs (r:1 w:1)
+	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(3351), added: 5826, mode: MaxEncodedLen)
+	fn clear_expired_multisig() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `654`
+		//  Estimated: `5826`
+		// Minimum execution time: 28_392 nanoseconds.
+		Weight::from_parts(29_305_000, 5826)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -180,15 +275,15 @@ impl WeightInfo for () {
 			.saturating_add(Weight::from_parts(494, 0).saturating_mul(z.into()))
 	}
 	/// Storage: Multisig Multisigs (r:1 w:1)
-	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(3346), added: 5821, mode: MaxEncodedLen)
+	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(3351), added: 5826, mode: MaxEncodedLen)
 	/// The range of component `s` is `[2, 100]`.
 	/// The range of component `z` is `[0, 10000]`.
 	fn as_multi_create(s: u32, z: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `352 + s * (2 ±0)`
-		//  Estimated: `5821`
+		//  Estimated: `5826`
 		// Minimum execution time: 35_377 nanoseconds.
-		Weight::from_parts(29_088_956, 5821)
+		Weight::from_parts(29_088_956, 5826)
 			// Standard Error: 335
 			.saturating_add(Weight::from_parts(67_846, 0).saturating_mul(s.into()))
 			// Standard Error: 3
@@ -197,15 +292,15 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: Multisig Multisigs (r:1 w:1)
-	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(3346), added: 5821, mode: MaxEncodedLen)
+	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(3351), added: 5826, mode: MaxEncodedLen)
 	/// The range of component `s` is `[3, 100]`.
 	/// The range of component `z` is `[0, 10000]`.
 	fn as_multi_approve(s: u32, z: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `351`
-		//  Estimated: `5821`
+		//  Estimated: `5826`
 		// Minimum execution time: 26_138 nanoseconds.
-		Weight::from_parts(20_479_380, 5821)
+		Weight::from_parts(20_479_380, 5826)
 			// Standard Error: 259
 			.saturating_add(Weight::from_parts(64_116, 0).saturating_mul(s.into()))
 			// Standard Error: 2
@@ -214,7 +309,7 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: Multisig Multisigs (r:1 w:1)
-	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(3346), added: 5821, mode: MaxEncodedLen)
+	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(3351), added: 5826, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	/// The range of component `s` is `[2, 100]`.
@@ -222,9 +317,9 @@ impl WeightInfo for () {
 	fn as_multi_complete(s: u32, z: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `489 + s * (33 ±0)`
-		//  Estimated: `8424`
+		//  Estimated: `8429`
 		// Minimum execution time: 40_323 nanoseconds.
-		Weight::from_parts(32_311_615, 8424)
+		Weight::from_parts(32_311_615, 8429)
 			// Standard Error: 401
 			.saturating_add(Weight::from_parts(85_999, 0).saturating_mul(s.into()))
 			// Standard Error: 3
@@ -233,45 +328,134 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: Multisig Multisigs (r:1 w:1)
-	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(3346), added: 5821, mode: MaxEncodedLen)
+	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(3351), added: 5826, mode: MaxEncodedLen)
 	/// The range of component `s` is `[2, 100]`.
 	fn approve_as_multi_create(s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `359 + s * (2 ±0)`
-		//  Estimated: `5821`
+		//  Estimated: `5826`
 		// Minimum execution time: 26_938 nanoseconds.
-		Weight::from_parts(27_802_216, 5821)
+		Weight::from_parts(27_802_216, 5826)
 			// Standard Error: 342
 			.saturating_add(Weight::from_parts(69_282, 0).saturating_mul(s.into()))
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: Multisig Multisigs (r:1 w:1)
-	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(3346), added: 5821, mode: MaxEncodedLen)
+	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(3351), added: 5826, mode: MaxEncodedLen)
 	/// The range of component `s` is `[2, 100]`.
 	fn approve_as_multi_approve(s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `351`
-		//  Estimated: `5821`
+		//  Estimated: `5826`
 		// Minimum execution time: 18_050 nanoseconds.
-		Weight::from_parts(19_095_404, 5821)
+		Weight::from_parts(19_095_404, 5826)
 			// Standard Error: 419
 			.saturating_add(Weight::from_parts(66_914, 0).saturating_mul(s.into()))
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: Multisig Multisigs (r:1 w:1)
-	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(3346), added: 5821, mode: MaxEncodedLen)
+	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(3351), added: 5826, mode: MaxEncodedLen)
 	/// The range of component `s` is `[2, 100]`.
 	fn cancel_as_multi(s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `555 + s * (1 ±0)`
-		//  Estimated: `5821`
+		//  Estimated: `5826`
 		// Minimum execution time: 27_508 nanoseconds.
-		Weight::from_parts(28_702_686, 5821)
+		Weight::from_parts(28_702_686, 5826)
 			// Standard Error: 466
 			.saturating_add(Weight::from_parts(69_419, 0).saturating_mul(s.into()))
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: Multisig Multisigs (r:1 w:1)
+	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(3351), added: 5826, mode: MaxEncodedLen)
+	/// The range of component `s` is `[2, 100]`.
+	/// The range of component `z` is `[0, 10000]`.
+	fn as_weighted_multi_create(s: u32, z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `352 + s * (2 ±0)`
+		//  Estimated: `5826`
+		// Minimum execution time: 37_815 nanoseconds.
+		Weight::from_parts(31_224_507, 5826)
+			// Standard Error: 352
+			.saturating_add(Weight::from_parts(88_613, 0).saturating_mul(s.into()))
+			// Standard Error: 3
+			.saturating_add(Weight::from_parts(1_527, 0).saturating_mul(z.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Multisig Multisigs (r:1 w:1)
+	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(3351), added: 5826, mode: MaxEncodedLen)
+	/// The range of component `s` is `[3, 100]`.
+	/// The range of component `z` is `[0, 10000]`.
+	fn as_weighted_multi_approve(s: u32, z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `356`
+		//  Estimated: `5826`
+		// Minimum execution time: 28_406 nanoseconds.
+		Weight::from_parts(22_031_872, 5826)
+			// Standard Error: 281
+			.saturating_add(Weight::from_parts(91_208, 0).saturating_mul(s.into()))
+			// Standard Error: 2
+			.saturating_add(Weight::from_parts(1_522, 0).saturating_mul(z.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Multisig Multisigs (r:1 w:1)
+	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(3351), added: 5826, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `s` is `[2, 100]`.
+	/// The range of component `z` is `[0, 10000]`.
+	fn as_weighted_multi_complete(s: u32, z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `494 + s * (33 ±0)`
+		//  Estimated: `8429`
+		// Minimum execution time: 42_917 nanoseconds.
+		Weight::from_parts(34_180_330, 8429)
+			// Standard Error: 417
+			.saturating_add(Weight::from_parts(112_904, 0).saturating_mul(s.into()))
+			// Standard Error: 3
+			.saturating_add(Weight::from_parts(1_536, 0).saturating_mul(z.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: Multisig Multisigs (r:1 w:1)
+	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(3351), added: 5826, mode: MaxEncodedLen)
+	/// The range of component `s` is `[2, 100]`.
+	fn cancel_as_weighted_multi(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `560 + s * (1 ±0)`
+		//  Estimated: `5826`
+		// Minimum execution time: 29_964 nanoseconds.
+		Weight::from_parts(30_841_195, 5826)
+			// Standard Error: 478
+			.saturating_add(Weight::from_parts(93_720, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Multisig Multisigs (r:1 w:1)
+	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(3351), added: 5826, mode: MaxEncodedLen)
+	fn set_expiry() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `654`
+		//  Estimated: `5826`
+		// Minimum execution time: 20_113 nanoseconds.
+		Weight::from_parts(20_733_000, 5826)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Multisig Multisigs (r:1 w:1)
+	/// Proof: Multisig Multisigs (max_values: None, max_size: Some(3351), added: 5826, mode: MaxEncodedLen)
+	fn clear_expired_multisig() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `654`
+		//  Estimated: `5826`
+		// Minimum execution time: 28_392 nanoseconds.
+		Weight::from_parts(29_305_000, 5826)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
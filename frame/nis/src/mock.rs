@@ -86,6 +86,10 @@ impl pallet_balances::Config<Instance1> for Test {
 	type MaxLocks = ();
 	type MaxReserves = ConstU32<1>;
 	type ReserveIdentifier = [u8; 8];
+	type HoldIdentifier = ();
+	type FreezeIdentifier = ();
+	type MaxHolds = ();
+	type MaxFreezes = ();
 }
 
 impl pallet_balances::Config<Instance2> for Test {
@@ -103,6 +107,10 @@ impl pallet_balances::Config<Instance2> for Test {
 	type MaxLocks = ();
 	type MaxReserves = ();
 	type ReserveIdentifier = [u8; 8];
+	type HoldIdentifier = ();
+	type FreezeIdentifier = ();
+	type MaxHolds = ();
+	type MaxFreezes = ();
 }
 
 parameter_types! {
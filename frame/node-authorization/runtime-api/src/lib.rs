@@ -0,0 +1,35 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for node-authorization pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use sp_core::OpaquePeerId as PeerId;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	/// Runtime api for accessing the nodes allowlist of the node-authorization pallet.
+	pub trait NodeAuthorizationApi {
+		/// Returns the nodes that `node` is allowed to connect to, or `None` if the allowlist is
+		/// not in use and `node` may connect to any node.
+		///
+		/// These are the well known nodes, if `node` is a well known node itself, and the
+		/// additional connections of `node`.
+		fn authorized_nodes(node: PeerId) -> Option<Vec<PeerId>>;
+	}
+}
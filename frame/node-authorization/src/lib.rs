@@ -33,6 +33,14 @@
 //! an authority, make sure to enable offchain worker with the right CLI flag. The
 //! node can be lagged with the latest block, in this case you need to disable offchain
 //! worker and manually set reserved nodes when starting it.
+//!
+//! Alternatively, the runtime can implement the `NodeAuthorizationApi` of
+//! `pallet-node-authorization-runtime-api` with [`Pallet::get_authorized_nodes`]. The node then
+//! enforces the allowlist at the best finalized block in its networking layer, refusing the
+//! substreams and requests of unauthorized peers without relying on the offchain worker.
+//!
+//! The allowlist is only in use while there are well known nodes. Until then, neither the
+//! offchain worker nor the networking layer restrict the peers of the node.
 
 // Ensure we're `no_std` when compiling for Wasm.
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -176,7 +184,12 @@ pub mod pallet {
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
 		/// Set reserved node every block. It may not be enabled depends on the offchain
 		/// worker settings when starting the node.
+		///
+		/// Does nothing while the allowlist is not in use.
 		fn offchain_worker(now: T::BlockNumber) {
+			if !Self::allowlist_in_use() {
+				return
+			}
 			let network_state = sp_io::offchain::network_state();
 			match network_state {
 				Err(_) => log::error!(
@@ -460,7 +473,16 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
-	fn get_authorized_nodes(node: &PeerId) -> Vec<PeerId> {
+	/// Returns whether the allowlist is in use, i.e. whether there are any well known nodes.
+	pub fn allowlist_in_use() -> bool {
+		!WellKnownNodes::<T>::get().is_empty()
+	}
+
+	/// Returns the nodes that `node` is allowed to connect to.
+	///
+	/// These are the well known nodes, if `node` is a well known node itself, and the additional
+	/// connections of `node`.
+	pub fn get_authorized_nodes(node: &PeerId) -> Vec<PeerId> {
 		let mut nodes = AdditionalConnections::<T>::get(node);
 
 		let mut well_known_nodes = WellKnownNodes::<T>::get();
@@ -390,3 +390,13 @@ fn get_authorized_nodes_works() {
 		);
 	});
 }
+
+#[test]
+fn allowlist_in_use_works() {
+	new_test_ext().execute_with(|| {
+		assert!(Pallet::<Test>::allowlist_in_use());
+
+		WellKnownNodes::<Test>::kill();
+		assert!(!Pallet::<Test>::allowlist_in_use());
+	});
+}
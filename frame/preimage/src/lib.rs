@@ -46,8 +46,8 @@ use frame_support::{
 	ensure,
 	pallet_prelude::Get,
 	traits::{
-		Currency, Defensive, FetchResult, Hash as PreimageHash, PreimageProvider,
-		PreimageRecipient, QueryPreimage, ReservableCurrency, StorePreimage,
+		fungible::MutateHold, Currency, Defensive, FetchResult, Hash as PreimageHash,
+		PreimageProvider, PreimageRecipient, QueryPreimage, ReservableCurrency, StorePreimage,
 	},
 	BoundedSlice, BoundedVec,
 };
@@ -82,7 +82,7 @@ pub mod pallet {
 	use super::*;
 
 	/// The current storage version.
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
@@ -92,8 +92,12 @@ pub mod pallet {
 		/// The Weight information for this pallet.
 		type WeightInfo: weights::WeightInfo;
 
-		/// Currency type for this pallet.
-		type Currency: ReservableCurrency<Self::AccountId>;
+		/// Currency type for this pallet, which holds the deposits.
+		type Currency: ReservableCurrency<Self::AccountId>
+			+ MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason, Balance = BalanceOf<Self>>;
+
+		/// The overarching hold reason.
+		type RuntimeHoldReason: From<HoldReason>;
 
 		/// An origin that can request a preimage be placed on-chain without a deposit or fee, or
 		/// manage existing preimages.
@@ -111,6 +115,14 @@ pub mod pallet {
 	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(PhantomData<T>);
 
+	/// A reason for the pallet placing a hold on the funds of an account.
+	#[pallet::composite_enum]
+	pub enum HoldReason {
+		/// The funds are held as the deposit of a noted preimage.
+		#[codec(index = 0)]
+		Preimage,
+	}
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -182,7 +194,7 @@ pub mod pallet {
 
 		/// Request a preimage be uploaded to the chain without paying any fees or deposits.
 		///
-		/// If the preimage requests has already been provided on-chain, we unreserve any deposit
+		/// If the preimage requests has already been provided on-chain, we release any deposit
 		/// a user may have paid, and take the control of the preimage out of their hands.
 		#[pallet::call_index(2)]
 		#[pallet::weight(T::WeightInfo::request_preimage())]
@@ -245,7 +257,7 @@ impl<T: Config> Pallet<T> {
 				let length = preimage.len() as u32;
 				let deposit = T::BaseDeposit::get()
 					.saturating_add(T::ByteDeposit::get().saturating_mul(length.into()));
-				T::Currency::reserve(depositor, deposit)?;
+				T::Currency::hold(&HoldReason::Preimage.into(), depositor, deposit)?;
 				RequestStatus::Unrequested { deposit: (depositor.clone(), deposit), len }
 			},
 		};
@@ -279,7 +291,7 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
-	// Clear a preimage from the storage of the chain, returning any deposit that may be reserved.
+	// Clear a preimage from the storage of the chain, returning any deposit that may be held.
 	//
 	// If `len` is provided, it will be a much cheaper operation.
 	//
@@ -292,7 +304,7 @@ impl<T: Config> Pallet<T> {
 		match StatusFor::<T>::get(hash).ok_or(Error::<T>::NotNoted)? {
 			RequestStatus::Requested { deposit: Some((owner, deposit)), count, len } => {
 				ensure!(maybe_check_owner.map_or(true, |c| c == owner), Error::<T>::NotAuthorized);
-				T::Currency::unreserve(&owner, deposit);
+				T::Currency::release(&HoldReason::Preimage.into(), &owner, deposit, true)?;
 				StatusFor::<T>::insert(
 					hash,
 					RequestStatus::Requested { deposit: None, count, len },
@@ -305,7 +317,7 @@ impl<T: Config> Pallet<T> {
 			},
 			RequestStatus::Unrequested { deposit: (owner, deposit), len } => {
 				ensure!(maybe_check_owner.map_or(true, |c| c == owner), Error::<T>::NotAuthorized);
-				T::Currency::unreserve(&owner, deposit);
+				T::Currency::release(&HoldReason::Preimage.into(), &owner, deposit, true)?;
 				StatusFor::<T>::remove(hash);
 
 				Self::remove(hash, len);
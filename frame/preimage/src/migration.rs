@@ -181,13 +181,114 @@ pub mod v1 {
 	}
 }
 
+pub mod v2 {
+	use super::*;
+	use sp_runtime::traits::{Saturating, Zero};
+
+	/// The log target.
+	const TARGET: &'static str = "runtime::preimage::migration::v2";
+
+	/// Migration for moving the deposits of noted preimages from reserves to holds.
+	pub struct Migration<T>(sp_std::marker::PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for Migration<T> {
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			assert_eq!(StorageVersion::get::<Pallet<T>>(), 1, "can only upgrade from version 1");
+			Ok(Vec::new())
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			let mut weight = T::DbWeight::get().reads(1);
+			if StorageVersion::get::<Pallet<T>>() != 1 {
+				log::warn!(
+					target: TARGET,
+					"skipping MoveDepositsToHolds: executed on wrong storage version.\
+				Expected version 1"
+				);
+				return weight
+			}
+
+			let (mut moved, mut failed) = (0u32, 0u32);
+			for (who, deposit) in deposits::<T>().collect::<Vec<_>>() {
+				let remaining = T::Currency::unreserve(&who, deposit);
+				if !remaining.is_zero() {
+					log::error!(
+						target: TARGET,
+						"deposit of {:?} was not fully reserved, {:?} missing",
+						who,
+						remaining
+					);
+				}
+				let amount = deposit.saturating_sub(remaining);
+				match T::Currency::hold(&HoldReason::Preimage.into(), &who, amount) {
+					Ok(()) if remaining.is_zero() => moved.saturating_inc(),
+					Ok(()) => failed.saturating_inc(),
+					Err(e) => {
+						// Keep the deposit reserved rather than leaving it free.
+						let _ = T::Currency::reserve(&who, amount);
+						log::error!(target: TARGET, "failed to hold deposit of {:?}: {:?}", who, e);
+						failed.saturating_inc();
+					},
+				}
+				// Read the status, then read and write the account and its holds.
+				weight.saturating_accrue(T::DbWeight::get().reads_writes(3, 2));
+			}
+			StorageVersion::new(2).put::<Pallet<T>>();
+
+			if failed.is_zero() {
+				log::info!(target: TARGET, "moved {} deposits to holds", moved);
+			} else {
+				log::error!(
+					target: TARGET,
+					"moved {} deposits to holds, failed to fully move {}",
+					moved,
+					failed
+				);
+			}
+
+			weight.saturating_add(T::DbWeight::get().writes(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_state: Vec<u8>) -> Result<(), &'static str> {
+			let mut expected = BTreeMap::<T::AccountId, BalanceOf<T>>::new();
+			for (who, deposit) in deposits::<T>() {
+				let total = expected.entry(who).or_default();
+				*total = total.saturating_add(deposit);
+			}
+			let mut mismatches = 0u32;
+			for (who, deposit) in expected {
+				let held = T::Currency::balance_on_hold(&HoldReason::Preimage.into(), &who);
+				if held != deposit {
+					log::error!(target: TARGET, "{:?} holds {:?}, expected {:?}", who, held, deposit);
+					mismatches.saturating_inc();
+				}
+			}
+			assert_eq!(StorageVersion::get::<Pallet<T>>(), 2, "must upgrade");
+			if !mismatches.is_zero() {
+				return Err("preimage deposits were not all moved to holds")
+			}
+			Ok(())
+		}
+	}
+
+	/// The depositors and deposits of all noted preimages.
+	fn deposits<T: Config>() -> impl Iterator<Item = (T::AccountId, BalanceOf<T>)> {
+		crate::StatusFor::<T>::iter_values().filter_map(|status| match status {
+			RequestStatus::Unrequested { deposit, .. } => Some(deposit),
+			RequestStatus::Requested { deposit, .. } => deposit,
+		})
+	}
+}
+
 #[cfg(test)]
 #[cfg(feature = "try-runtime")]
 mod test {
 	use super::*;
 	use crate::mock::{Test as T, *};
 
-	use frame_support::bounded_vec;
+	use frame_support::{assert_ok, bounded_vec, traits::fungible::InspectHold};
 
 	#[test]
 	fn migration_works() {
@@ -252,6 +353,48 @@ mod test {
 		});
 	}
 
+	#[test]
+	fn migration_v2_moves_deposits_to_holds() {
+		new_test_ext().execute_with(|| {
+			StorageVersion::new(1).put::<Pallet<T>>();
+			// A preimage noted with a reserved deposit before the migration.
+			let (p, h) = preimage::<T>(16);
+			assert_ok!(Balances::reserve(&2, 18));
+			crate::StatusFor::<T>::insert(
+				h,
+				RequestStatus::Unrequested { deposit: (2, 18), len: 16 },
+			);
+			crate::PreimageFor::<T>::insert(&(h, 16), p);
+
+			let state = v2::Migration::<T>::pre_upgrade().unwrap();
+			let _w = v2::Migration::<T>::on_runtime_upgrade();
+			v2::Migration::<T>::post_upgrade(state).unwrap();
+
+			assert_eq!(StorageVersion::get::<Pallet<T>>(), 2);
+			assert_eq!(Balances::reserved_balance(2), 0);
+			assert_eq!(Balances::balance_on_hold(&HoldReason::Preimage.into(), &2), 18);
+			assert_eq!(Balances::free_balance(2), 82);
+		});
+	}
+
+	#[test]
+	fn migration_v2_fails_post_upgrade_if_a_deposit_is_not_held() {
+		new_test_ext().execute_with(|| {
+			StorageVersion::new(1).put::<Pallet<T>>();
+			// A preimage whose deposit was never reserved.
+			let (p, h) = preimage::<T>(16);
+			crate::StatusFor::<T>::insert(
+				h,
+				RequestStatus::Unrequested { deposit: (2, 18), len: 16 },
+			);
+			crate::PreimageFor::<T>::insert(&(h, 16), p);
+
+			let state = v2::Migration::<T>::pre_upgrade().unwrap();
+			let _w = v2::Migration::<T>::on_runtime_upgrade();
+			assert!(v2::Migration::<T>::post_upgrade(state).is_err());
+		});
+	}
+
 	/// Returns a preimage with a given size and its hash.
 	fn preimage<T: Config>(
 		len: usize,
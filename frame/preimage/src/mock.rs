@@ -84,6 +84,10 @@ impl pallet_balances::Config for Test {
 	type MaxLocks = ();
 	type MaxReserves = ConstU32<50>;
 	type ReserveIdentifier = [u8; 8];
+	type HoldIdentifier = RuntimeHoldReason;
+	type FreezeIdentifier = ();
+	type MaxHolds = ConstU32<1>;
+	type MaxFreezes = ();
 }
 
 ord_parameter_types! {
@@ -94,6 +98,7 @@ impl Config for Test {
 	type WeightInfo = ();
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
+	type RuntimeHoldReason = RuntimeHoldReason;
 	type ManagerOrigin = EnsureSignedBy<One, u64>;
 	type BaseDeposit = ConstU64<2>;
 	type ByteDeposit = ConstU64<1>;
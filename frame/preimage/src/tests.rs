@@ -24,7 +24,7 @@ use crate::mock::*;
 
 use frame_support::{
 	assert_err, assert_noop, assert_ok, assert_storage_noop, bounded_vec,
-	traits::{Bounded, BoundedInline, Hash as PreimageHash},
+	traits::{fungible::InspectHold, Bounded, BoundedInline, Hash as PreimageHash},
 	StorageNoopGuard,
 };
 use pallet_balances::Error as BalancesError;
@@ -51,7 +51,7 @@ pub fn make_bounded_values() -> (Bounded<Vec<u8>>, Bounded<Vec<u8>>, Bounded<Vec
 fn user_note_preimage_works() {
 	new_test_ext().execute_with(|| {
 		assert_ok!(Preimage::note_preimage(RuntimeOrigin::signed(2), vec![1]));
-		assert_eq!(Balances::reserved_balance(2), 3);
+		assert_eq!(Balances::total_balance_on_hold(&2), 3);
 		assert_eq!(Balances::free_balance(2), 97);
 
 		let h = hashed([1]);
@@ -73,7 +73,7 @@ fn user_note_preimage_works() {
 fn manager_note_preimage_works() {
 	new_test_ext().execute_with(|| {
 		assert_ok!(Preimage::note_preimage(RuntimeOrigin::signed(1), vec![1]));
-		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::total_balance_on_hold(&1), 0);
 		assert_eq!(Balances::free_balance(1), 100);
 
 		let h = hashed([1]);
@@ -191,7 +191,7 @@ fn requested_then_user_noted_preimage_is_free() {
 	new_test_ext().execute_with(|| {
 		assert_ok!(Preimage::request_preimage(RuntimeOrigin::signed(1), hashed([1])));
 		assert_ok!(Preimage::note_preimage(RuntimeOrigin::signed(2), vec![1]));
-		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_eq!(Balances::total_balance_on_hold(&2), 0);
 		assert_eq!(Balances::free_balance(2), 100);
 
 		let h = hashed([1]);
@@ -252,8 +252,8 @@ fn user_noted_then_requested_preimage_is_refunded_once_only() {
 		assert_ok!(Preimage::request_preimage(RuntimeOrigin::signed(1), hashed([1])));
 		assert_ok!(Preimage::unrequest_preimage(RuntimeOrigin::signed(1), hashed([1])));
 		assert_ok!(Preimage::unnote_preimage(RuntimeOrigin::signed(2), hashed([1])));
-		// Still have reserve from `vec[1; 3]`.
-		assert_eq!(Balances::reserved_balance(2), 5);
+		// Still have deposit held from `vec[1; 3]`.
+		assert_eq!(Balances::total_balance_on_hold(&2), 5);
 		assert_eq!(Balances::free_balance(2), 95);
 	});
 }
@@ -79,6 +79,40 @@ fn add_announcements<T: Config>(
 	Ok(())
 }
 
+fn spending_limits<T: Config>(a: u32) -> Vec<(SpendingAsset<T::AssetId>, BalanceOf<T>)> {
+	(0..a)
+		.map(|i| match i {
+			0 => SpendingAsset::Native,
+			i => SpendingAsset::Asset(T::BenchmarkHelper::asset_id(i)),
+		})
+		.map(|asset| (asset, BalanceOf::<T>::max_value()))
+		.collect()
+}
+
+fn add_meterable_proxy<T: Config>() -> Result<T::AccountId, &'static str> {
+	let caller: T::AccountId = whitelisted_caller();
+	T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+	let delegate: T::AccountId = account("target", 0, SEED);
+	Proxy::<T>::add_proxy(
+		RawOrigin::Signed(caller).into(),
+		T::Lookup::unlookup(delegate.clone()),
+		T::BenchmarkHelper::meterable_proxy_type(),
+		T::BlockNumber::zero(),
+	)?;
+	Ok(delegate)
+}
+
+fn add_spending_allowance<T: Config>(a: u32) -> Result<T::AccountId, &'static str> {
+	let delegate = add_meterable_proxy::<T>()?;
+	Proxy::<T>::set_spending_allowance(
+		RawOrigin::Signed(whitelisted_caller()).into(),
+		T::Lookup::unlookup(delegate.clone()),
+		spending_limits::<T>(a),
+		1u32.into(),
+	)?;
+	Ok(delegate)
+}
+
 benchmarks! {
 	proxy {
 		let p in 1 .. (T::MaxProxies::get() - 1) => add_proxies::<T>(p, None)?;
@@ -260,5 +294,45 @@ benchmarks! {
 		assert!(!Proxies::<T>::contains_key(&pure_account));
 	}
 
+	set_spending_allowance {
+		let a in 1 .. T::MaxAllowanceAssets::get();
+		let delegate = add_meterable_proxy::<T>()?;
+		let caller: T::AccountId = whitelisted_caller();
+		let delegate_lookup = T::Lookup::unlookup(delegate.clone());
+	}: _(RawOrigin::Signed(caller.clone()), delegate_lookup, spending_limits::<T>(a), 1u32.into())
+	verify {
+		assert_last_event::<T>(Event::SpendingAllowanceSet {
+			delegator: caller,
+			delegatee: delegate,
+			period: 1u32.into(),
+		}.into());
+	}
+
+	remove_spending_allowance {
+		let delegate = add_spending_allowance::<T>(T::MaxAllowanceAssets::get())?;
+		let caller: T::AccountId = whitelisted_caller();
+		let delegate_lookup = T::Lookup::unlookup(delegate.clone());
+	}: _(RawOrigin::Signed(caller.clone()), delegate_lookup)
+	verify {
+		assert!(!SpendingAllowances::<T>::contains_key(&caller, &delegate));
+	}
+
+	meter_spending {
+		let a in 1 .. T::MaxAllowanceAssets::get();
+		let delegate = add_spending_allowance::<T>(a)?;
+		let real: T::AccountId = whitelisted_caller();
+		let allowance = SpendingAllowances::<T>::get(&real, &delegate).ok_or("allowance not set")?;
+		// Move to a new period, so that the spending is reset too.
+		frame_system::Pallet::<T>::set_block_number(2u32.into());
+		let call: <T as Config>::RuntimeCall = frame_system::Call::<T>::remark { remark: vec![] }.into();
+		let origin = RawOrigin::Signed(real.clone()).into();
+	}: {
+		Proxy::<T>::dispatch_metered(&real, &delegate, allowance, call, origin)?;
+	}
+	verify {
+		let allowance = SpendingAllowances::<T>::get(&real, &delegate).ok_or("allowance not set")?;
+		assert_eq!(allowance.period_start, 2u32.into());
+	}
+
 	impl_benchmark_test_suite!(Proxy, crate::tests::new_test_ext(), crate::tests::Test);
 }
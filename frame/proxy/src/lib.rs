@@ -23,6 +23,12 @@
 //! wish to execute some duration prior to execution happens. In this case, the target account may
 //! reject the announcement and in doing so, veto the execution.
 //!
+//! An account may also limit what one of its proxies can spend on its behalf with a spending
+//! allowance. Calls made through the proxy are then metered against a per-period limit on each of
+//! the listed assets, native or from `Assets`, and reverted if they would exceed it. Only proxies
+//! of the types in `MeterableProxyTypes` can be held to an allowance, as metering can't see funds
+//! spent indirectly, e.g. through approvals or delegations.
+//!
 //! - [`Config`]
 //! - [`Call`]
 
@@ -37,8 +43,13 @@ use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::{
 	dispatch::{DispatchError, GetDispatchInfo},
 	ensure,
-	traits::{Currency, Get, InstanceFilter, IsSubType, IsType, OriginTrait, ReservableCurrency},
-	RuntimeDebug,
+	storage::{with_transaction, TransactionOutcome},
+	traits::{
+		tokens::{fungible, fungibles},
+		Contains, Currency, Get, InstanceFilter, IsSubType, IsType, OriginTrait,
+		ReservableCurrency,
+	},
+	BoundedVec, RuntimeDebug,
 };
 use frame_system::{self as system};
 use scale_info::TypeInfo;
@@ -59,6 +70,13 @@ type BalanceOf<T> =
 
 type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
 
+type SpendingAllowanceOf<T> = SpendingAllowance<
+	<T as Config>::AssetId,
+	BalanceOf<T>,
+	<T as frame_system::Config>::BlockNumber,
+	<T as Config>::MaxAllowanceAssets,
+>;
+
 /// The parameters under which a particular account has a proxy relationship with some other
 /// account.
 #[derive(
@@ -95,6 +113,41 @@ pub struct Announcement<AccountId, Hash, BlockNumber> {
 	height: BlockNumber,
 }
 
+/// An asset whose spending by a proxy may be limited.
+#[derive(Encode, Decode, Clone, Copy, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum SpendingAsset<AssetId> {
+	/// The native currency.
+	Native,
+	/// An asset of the `Assets` implementation.
+	Asset(AssetId),
+}
+
+/// The amount of an asset that a proxy may spend in a period, and how much it has spent so far.
+#[derive(Encode, Decode, Clone, Copy, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct SpendingLimit<AssetId, Balance> {
+	/// The asset being limited.
+	pub asset: SpendingAsset<AssetId>,
+	/// The amount which may be spent per period.
+	pub limit: Balance,
+	/// The amount spent in the current period.
+	pub spent: Balance,
+}
+
+/// The limits on what a proxy may spend of the balances of the account it acts for.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+#[scale_info(skip_type_params(MaxAssets))]
+pub struct SpendingAllowance<AssetId, Balance, BlockNumber, MaxAssets>
+where
+	MaxAssets: Get<u32>,
+{
+	/// The limit on each of the metered assets.
+	pub limits: BoundedVec<SpendingLimit<AssetId, Balance>, MaxAssets>,
+	/// The number of blocks after which the amounts spent are reset.
+	pub period: BlockNumber,
+	/// The height at which the current period began.
+	pub period_start: BlockNumber,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::{DispatchResult, *};
@@ -120,7 +173,8 @@ pub mod pallet {
 			+ IsType<<Self as frame_system::Config>::RuntimeCall>;
 
 		/// The currency mechanism.
-		type Currency: ReservableCurrency<Self::AccountId>;
+		type Currency: ReservableCurrency<Self::AccountId>
+			+ fungible::Inspect<Self::AccountId, Balance = BalanceOf<Self>>;
 
 		/// A kind of proxy; specified with the proxy and passed in to the `IsProxyable` fitler.
 		/// The instance filter determines whether a given call may be proxied under this type.
@@ -176,6 +230,32 @@ pub mod pallet {
 		/// into a pre-existing storage value.
 		#[pallet::constant]
 		type AnnouncementDepositFactor: Get<BalanceOf<Self>>;
+
+		/// Identifier for the assets, other than the native currency, whose spending may be
+		/// limited by a spending allowance.
+		type AssetId: Parameter + Member + Copy + MaxEncodedLen;
+
+		/// The assets whose balances are metered against spending allowances.
+		type Assets: fungibles::Inspect<
+			Self::AccountId,
+			AssetId = Self::AssetId,
+			Balance = BalanceOf<Self>,
+		>;
+
+		/// The maximum number of assets a single spending allowance may limit.
+		#[pallet::constant]
+		type MaxAllowanceAssets: Get<u32>;
+
+		/// The proxy types which can be held to a spending allowance.
+		///
+		/// Metering only sees how much of the delegator's balances a call leaves spendable. Proxy
+		/// types whose calls can spend funds indirectly, e.g. through approvals, delegations or
+		/// derivative accounts, must not be included.
+		type MeterableProxyTypes: Contains<Self::ProxyType>;
+
+		/// Helper for picking the assets limited and the proxy type metered in benchmarks.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<Self::AssetId, Self::ProxyType>;
 	}
 
 	#[pallet::call]
@@ -195,6 +275,7 @@ pub mod pallet {
 			(T::WeightInfo::proxy(T::MaxProxies::get())
 				 // AccountData for inner call origin accountdata.
 				.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+				.saturating_add(T::WeightInfo::meter_spending(T::MaxAllowanceAssets::get()))
 				.saturating_add(di.weight),
 			di.class)
 		})]
@@ -268,6 +349,7 @@ pub mod pallet {
 			let who = ensure_signed(origin)?;
 			let (_, old_deposit) = Proxies::<T>::take(&who);
 			T::Currency::unreserve(&who, old_deposit);
+			let _ = SpendingAllowances::<T>::clear_prefix(&who, T::MaxProxies::get(), None);
 
 			Ok(())
 		}
@@ -357,6 +439,7 @@ pub mod pallet {
 
 			let (_, deposit) = Proxies::<T>::take(&who);
 			T::Currency::unreserve(&spawner, deposit);
+			let _ = SpendingAllowances::<T>::clear_prefix(&who, T::MaxProxies::get(), None);
 
 			Ok(())
 		}
@@ -489,6 +572,7 @@ pub mod pallet {
 			(T::WeightInfo::proxy_announced(T::MaxPending::get(), T::MaxProxies::get())
 				 // AccountData for inner call origin accountdata.
 				.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+				.saturating_add(T::WeightInfo::meter_spending(T::MaxAllowanceAssets::get()))
 				.saturating_add(di.weight),
 			di.class)
 		})]
@@ -517,6 +601,106 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Limit what a proxy of the sender may spend of its balances.
+		///
+		/// Calls dispatched by `delegate` on behalf of the sender through `proxy` or
+		/// `proxy_announced` are metered against the allowance: the drop in the sender's
+		/// spendable balance of each limited asset, including what is locked, frozen or reserved
+		/// by the call, is added to the amount spent in the current period, and
+		/// the call is reverted if that exceeds the limit. The amounts spent are reset once
+		/// `period` blocks have passed since the period began. Assets which are not listed are not
+		/// metered.
+		///
+		/// Replaces any allowance `delegate` already has, starting a new period. The allowance is
+		/// dropped when `delegate` is no longer a proxy of the sender.
+		///
+		/// The dispatch origin for this call must be _Signed_ and `delegate` must be one of its
+		/// proxies. All of the proxy types `delegate` holds must be in
+		/// [`Config::MeterableProxyTypes`].
+		///
+		/// Parameters:
+		/// - `delegate`: The proxy whose spending is to be limited.
+		/// - `limits`: The assets to limit, each with the amount which may be spent per period.
+		/// - `period`: The number of blocks after which the amounts spent are reset.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::set_spending_allowance(limits.len() as u32))]
+		pub fn set_spending_allowance(
+			origin: OriginFor<T>,
+			delegate: AccountIdLookupOf<T>,
+			limits: Vec<(SpendingAsset<T::AssetId>, BalanceOf<T>)>,
+			period: T::BlockNumber,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let delegate = T::Lookup::lookup(delegate)?;
+			let proxies = Proxies::<T>::get(&who).0;
+			let mut proxy_types = proxies
+				.iter()
+				.filter(|x| x.delegate == delegate)
+				.map(|x| &x.proxy_type)
+				.peekable();
+			ensure!(proxy_types.peek().is_some(), Error::<T>::NotProxy);
+			ensure!(
+				proxy_types.all(|proxy_type| T::MeterableProxyTypes::contains(proxy_type)),
+				Error::<T>::UnmeterableProxy
+			);
+			ensure!(!period.is_zero(), Error::<T>::ZeroAllowancePeriod);
+			for (i, (asset, _)) in limits.iter().enumerate() {
+				ensure!(
+					!limits[..i].iter().any(|(other, _)| other == asset),
+					Error::<T>::DuplicateAllowanceAsset
+				);
+			}
+
+			let limits: BoundedVec<_, T::MaxAllowanceAssets> = limits
+				.into_iter()
+				.map(|(asset, limit)| SpendingLimit { asset, limit, spent: Zero::zero() })
+				.collect::<Vec<_>>()
+				.try_into()
+				.map_err(|_| Error::<T>::TooMany)?;
+			let allowance = SpendingAllowance {
+				limits,
+				period,
+				period_start: system::Pallet::<T>::block_number(),
+			};
+			SpendingAllowances::<T>::insert(&who, &delegate, allowance);
+			Self::deposit_event(Event::SpendingAllowanceSet {
+				delegator: who,
+				delegatee: delegate,
+				period,
+			});
+
+			Ok(())
+		}
+
+		/// Lift the spending allowance of a proxy of the sender, so that its spending is no
+		/// longer limited.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// Parameters:
+		/// - `delegate`: The proxy whose spending allowance is to be removed.
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::remove_spending_allowance())]
+		pub fn remove_spending_allowance(
+			origin: OriginFor<T>,
+			delegate: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let delegate = T::Lookup::lookup(delegate)?;
+			ensure!(
+				SpendingAllowances::<T>::contains_key(&who, &delegate),
+				Error::<T>::NoSpendingAllowance
+			);
+
+			SpendingAllowances::<T>::remove(&who, &delegate);
+			Self::deposit_event(Event::SpendingAllowanceRemoved {
+				delegator: who,
+				delegatee: delegate,
+			});
+
+			Ok(())
+		}
 	}
 
 	#[pallet::event]
@@ -548,11 +732,29 @@ pub mod pallet {
 			proxy_type: T::ProxyType,
 			delay: T::BlockNumber,
 		},
+		/// The spending of a proxy was limited by a new allowance.
+		SpendingAllowanceSet {
+			delegator: T::AccountId,
+			delegatee: T::AccountId,
+			period: T::BlockNumber,
+		},
+		/// The spending allowance of a proxy was removed.
+		SpendingAllowanceRemoved { delegator: T::AccountId, delegatee: T::AccountId },
+		/// A proxy spent `amount` of `asset` from its allowance, leaving `remaining` to be spent
+		/// in the current period.
+		AllowanceSpent {
+			delegator: T::AccountId,
+			delegatee: T::AccountId,
+			asset: SpendingAsset<T::AssetId>,
+			amount: BalanceOf<T>,
+			remaining: BalanceOf<T>,
+		},
 	}
 
 	#[pallet::error]
 	pub enum Error<T> {
-		/// There are too many proxies registered or too many announcements pending.
+		/// There are too many proxies registered, too many announcements pending or too many
+		/// assets limited.
 		TooMany,
 		/// Proxy registration not found.
 		NotFound,
@@ -568,6 +770,16 @@ pub mod pallet {
 		Unannounced,
 		/// Cannot add self as proxy.
 		NoSelfProxy,
+		/// The proxy has no spending allowance.
+		NoSpendingAllowance,
+		/// The call would spend more than the proxy's allowance.
+		AllowanceExceeded,
+		/// A spending allowance must have a non-zero period.
+		ZeroAllowancePeriod,
+		/// An asset was limited more than once.
+		DuplicateAllowanceAsset,
+		/// A proxy of this type can't be held to a spending allowance.
+		UnmeterableProxy,
 	}
 
 	/// The set of account proxies. Maps the account which has delegated to the accounts
@@ -598,6 +810,19 @@ pub mod pallet {
 		),
 		ValueQuery,
 	>;
+
+	/// The spending allowances of proxies, keyed by the delegating account and then by the
+	/// proxy whose spending on its behalf is limited.
+	#[pallet::storage]
+	#[pallet::getter(fn spending_allowances)]
+	pub type SpendingAllowances<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Twox64Concat,
+		T::AccountId,
+		SpendingAllowanceOf<T>,
+	>;
 }
 
 impl<T: Config> Pallet<T> {
@@ -645,6 +870,12 @@ impl<T: Config> Pallet<T> {
 		delay: T::BlockNumber,
 	) -> DispatchResult {
 		ensure!(delegator != &delegatee, Error::<T>::NoSelfProxy);
+		// A metered delegate must not gain a proxy type whose calls escape metering.
+		ensure!(
+			T::MeterableProxyTypes::contains(&proxy_type) ||
+				!SpendingAllowances::<T>::contains_key(delegator, &delegatee),
+			Error::<T>::UnmeterableProxy
+		);
 		Proxies::<T>::try_mutate(delegator, |(ref mut proxies, ref mut deposit)| {
 			let proxy_def = ProxyDefinition {
 				delegate: delegatee.clone(),
@@ -699,6 +930,9 @@ impl<T: Config> Pallet<T> {
 			} else if new_deposit < old_deposit {
 				T::Currency::unreserve(delegator, old_deposit - new_deposit);
 			}
+			if !proxies.iter().any(|p| p.delegate == delegatee) {
+				SpendingAllowances::<T>::remove(delegator, &delegatee);
+			}
 			if !proxies.is_empty() {
 				*x = Some((proxies, new_deposit))
 			}
@@ -777,12 +1011,22 @@ impl<T: Config> Pallet<T> {
 		real: T::AccountId,
 		call: <T as Config>::RuntimeCall,
 	) {
+		let maybe_allowance = SpendingAllowances::<T>::get(&real, &def.delegate);
+		let metered = maybe_allowance.is_some();
+		let delegate = def.delegate.clone();
 		// This is a freshly authenticated new account, the origin restrictions doesn't apply.
-		let mut origin: T::RuntimeOrigin = frame_system::RawOrigin::Signed(real).into();
+		let mut origin: T::RuntimeOrigin = frame_system::RawOrigin::Signed(real.clone()).into();
 		origin.add_filter(move |c: &<T as frame_system::Config>::RuntimeCall| {
 			let c = <T as Config>::RuntimeCall::from_ref(c);
 			// We make sure the proxy call does access this pallet to change modify proxies.
 			match c.is_sub_type() {
+				// A proxy with a spending allowance cannot add proxies or change allowances, as
+				// either would let it escape its own allowance.
+				Some(Call::add_proxy { .. }) |
+				Some(Call::set_spending_allowance { .. }) |
+				Some(Call::remove_spending_allowance { .. })
+					if metered =>
+					false,
 				// Proxy call cannot add or remove a proxy with more permissions than it already
 				// has.
 				Some(Call::add_proxy { ref proxy_type, .. }) |
@@ -797,7 +1041,101 @@ impl<T: Config> Pallet<T> {
 				_ => def.proxy_type.filter(c),
 			}
 		});
-		let e = call.dispatch(origin);
-		Self::deposit_event(Event::ProxyExecuted { result: e.map(|_| ()).map_err(|e| e.error) });
+		let result = match maybe_allowance {
+			Some(allowance) => Self::dispatch_metered(&real, &delegate, allowance, call, origin),
+			None => call.dispatch(origin).map(|_| ()).map_err(|e| e.error),
+		};
+		Self::deposit_event(Event::ProxyExecuted { result });
+	}
+
+	/// Dispatch `call` from `origin`, metering what it spends of the balances of `real` against
+	/// the spending allowance of `delegate`.
+	///
+	/// All effects of the call are reverted if it exceeds the allowance.
+	pub(crate) fn dispatch_metered(
+		real: &T::AccountId,
+		delegate: &T::AccountId,
+		mut allowance: SpendingAllowanceOf<T>,
+		call: <T as Config>::RuntimeCall,
+		origin: T::RuntimeOrigin,
+	) -> DispatchResult {
+		let now = system::Pallet::<T>::block_number();
+		if now.saturating_sub(allowance.period_start) >= allowance.period {
+			allowance.period_start = now;
+			allowance.limits.iter_mut().for_each(|l| l.spent = Zero::zero());
+		}
+
+		with_transaction(|| {
+			let before: Vec<_> = allowance
+				.limits
+				.iter()
+				.map(|l| Self::spendable_balance(&l.asset, real))
+				.collect();
+			let result = call.dispatch(origin).map(|_| ()).map_err(|e| e.error);
+
+			let mut spends = Vec::new();
+			for (limit, before) in allowance.limits.iter_mut().zip(before) {
+				let amount = before.saturating_sub(Self::spendable_balance(&limit.asset, real));
+				if amount.is_zero() {
+					continue
+				}
+				let spent = limit.spent.saturating_add(amount);
+				if spent > limit.limit {
+					return TransactionOutcome::Rollback(Ok(Err(
+						Error::<T>::AllowanceExceeded.into()
+					)))
+				}
+				limit.spent = spent;
+				spends.push((limit.asset, amount, limit.limit - spent));
+			}
+
+			SpendingAllowances::<T>::insert(real, delegate, &allowance);
+			for (asset, amount, remaining) in spends {
+				Self::deposit_event(Event::AllowanceSpent {
+					delegator: real.clone(),
+					delegatee: delegate.clone(),
+					asset,
+					amount,
+					remaining,
+				});
+			}
+			TransactionOutcome::Commit(Ok(result))
+		})
+		.unwrap_or_else(Err)
+	}
+
+	/// The balance of `who` in `asset` which can still be spent, as metered against spending
+	/// allowances.
+	///
+	/// Locked, frozen and reserved funds are not spendable, so a call which locks or reserves
+	/// funds is metered as spending them.
+	fn spendable_balance(asset: &SpendingAsset<T::AssetId>, who: &T::AccountId) -> BalanceOf<T> {
+		match asset {
+			SpendingAsset::Native =>
+				<T::Currency as fungible::Inspect<_>>::reducible_balance(who, false),
+			SpendingAsset::Asset(id) =>
+				<T::Assets as fungibles::Inspect<_>>::reducible_balance(*id, who, false),
+		}
+	}
+}
+
+/// Helper for the benchmarks of this pallet.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AssetId, ProxyType> {
+	/// The id of the `i`th asset limited by a spending allowance.
+	fn asset_id(i: u32) -> AssetId;
+	/// A proxy type in [`Config::MeterableProxyTypes`].
+	fn meterable_proxy_type() -> ProxyType;
+}
+
+/// Uses the default proxy type, which is only meterable if all proxy types are.
+#[cfg(feature = "runtime-benchmarks")]
+impl<AssetId: From<u32>, ProxyType: Default> BenchmarkHelper<AssetId, ProxyType> for () {
+	fn asset_id(i: u32) -> AssetId {
+		i.into()
+	}
+
+	fn meterable_proxy_type() -> ProxyType {
+		Default::default()
 	}
 }
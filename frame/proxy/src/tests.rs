@@ -26,9 +26,10 @@ use codec::{Decode, Encode};
 use frame_support::{
 	assert_noop, assert_ok,
 	dispatch::DispatchError,
-	traits::{ConstU32, ConstU64, Contains},
+	traits::{AsEnsureOriginWithArg, ConstU32, ConstU64, Contains, GenesisBuild},
 	RuntimeDebug,
 };
+use frame_system::{EnsureRoot, EnsureSigned};
 use sp_core::H256;
 use sp_runtime::{
 	testing::Header,
@@ -48,6 +49,7 @@ frame_support::construct_runtime!(
 		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
 		Proxy: proxy::{Pallet, Call, Storage, Event<T>},
 		Utility: pallet_utility::{Pallet, Call, Event},
+		Assets: pallet_assets::{Pallet, Call, Storage, Config<T>, Event<T>},
 	}
 );
 
@@ -82,6 +84,10 @@ impl pallet_balances::Config for Test {
 	type MaxLocks = ();
 	type MaxReserves = ();
 	type ReserveIdentifier = [u8; 8];
+	type HoldIdentifier = ();
+	type FreezeIdentifier = ();
+	type MaxHolds = ();
+	type MaxFreezes = ();
 	type Balance = u64;
 	type RuntimeEvent = RuntimeEvent;
 	type DustRemoval = ();
@@ -89,6 +95,33 @@ impl pallet_balances::Config for Test {
 	type AccountStore = System;
 	type WeightInfo = ();
 }
+impl pallet_assets::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = u64;
+	type AssetId = u32;
+	type AssetLink = ();
+	type AssetIdParameter = u32;
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<u64>>;
+	type ForceOrigin = EnsureRoot<u64>;
+	type AssetDeposit = ConstU64<1>;
+	type AssetAccountDeposit = ConstU64<1>;
+	type MetadataDepositBase = ConstU64<1>;
+	type MetadataDepositPerByte = ConstU64<1>;
+	type ApprovalDeposit = ConstU64<1>;
+	type StringLimit = ConstU32<50>;
+	type Freezer = ();
+	type HoldIdentifier = ();
+	type FreezeIdentifier = ();
+	type MaxHolds = ();
+	type MaxFreezes = ();
+	type Extra = ();
+	type CallbackHandle = ();
+	type WeightInfo = ();
+	type RemoveItemsLimit = ConstU32<1000>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+}
 impl pallet_utility::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeCall = RuntimeCall;
@@ -113,6 +146,7 @@ pub enum ProxyType {
 	Any,
 	JustTransfer,
 	JustUtility,
+	Spending,
 }
 impl Default for ProxyType {
 	fn default() -> Self {
@@ -127,6 +161,12 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 				matches!(c, RuntimeCall::Balances(pallet_balances::Call::transfer { .. }))
 			},
 			ProxyType::JustUtility => matches!(c, RuntimeCall::Utility { .. }),
+			ProxyType::Spending => matches!(
+				c,
+				RuntimeCall::Balances(pallet_balances::Call::transfer { .. }) |
+					RuntimeCall::Assets(pallet_assets::Call::transfer { .. }) |
+					RuntimeCall::Utility(pallet_utility::Call::batch_all { .. })
+			),
 		}
 	}
 	fn is_superset(&self, o: &Self) -> bool {
@@ -144,6 +184,24 @@ impl Contains<RuntimeCall> for BaseFilter {
 		}
 	}
 }
+pub struct MeterableProxyTypes;
+impl Contains<ProxyType> for MeterableProxyTypes {
+	fn contains(proxy_type: &ProxyType) -> bool {
+		matches!(proxy_type, ProxyType::JustTransfer | ProxyType::Spending)
+	}
+}
+#[cfg(feature = "runtime-benchmarks")]
+pub struct BenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl crate::BenchmarkHelper<u32, ProxyType> for BenchmarkHelper {
+	fn asset_id(i: u32) -> u32 {
+		i
+	}
+
+	fn meterable_proxy_type() -> ProxyType {
+		ProxyType::Spending
+	}
+}
 impl Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeCall = RuntimeCall;
@@ -157,8 +215,16 @@ impl Config for Test {
 	type MaxPending = ConstU32<2>;
 	type AnnouncementDepositBase = ConstU64<1>;
 	type AnnouncementDepositFactor = ConstU64<1>;
+	type AssetId = u32;
+	type Assets = Assets;
+	type MaxAllowanceAssets = ConstU32<2>;
+	type MeterableProxyTypes = MeterableProxyTypes;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = BenchmarkHelper;
 }
 
+const ASSET: u32 = 7;
+
 use super::{Call as ProxyCall, Event as ProxyEvent};
 use frame_system::Call as SystemCall;
 use pallet_balances::{Call as BalancesCall, Error as BalancesError, Event as BalancesEvent};
@@ -173,6 +239,13 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
 	}
 	.assimilate_storage(&mut t)
 	.unwrap();
+	pallet_assets::GenesisConfig::<Test> {
+		assets: vec![(ASSET, 1, true, 1)],
+		metadata: vec![],
+		accounts: vec![(ASSET, 1, 100)],
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
 	let mut ext = sp_io::TestExternalities::new(t);
 	ext.execute_with(|| System::set_block_number(1));
 	ext
@@ -620,3 +693,259 @@ fn pure_works() {
 		);
 	});
 }
+
+fn call_transfer_asset(target: u64, amount: u64) -> RuntimeCall {
+	RuntimeCall::Assets(pallet_assets::Call::transfer { id: ASSET, target, amount })
+}
+
+#[test]
+fn spending_allowance_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(1), 3, ProxyType::Spending, 0));
+		assert_ok!(Proxy::set_spending_allowance(
+			RuntimeOrigin::signed(1),
+			3,
+			vec![(SpendingAsset::Native, 5), (SpendingAsset::Asset(ASSET), 30)],
+			10,
+		));
+		System::assert_last_event(
+			ProxyEvent::SpendingAllowanceSet { delegator: 1, delegatee: 3, period: 10 }.into(),
+		);
+
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(3), 1, None, Box::new(call_transfer(6, 3))));
+		System::assert_has_event(
+			ProxyEvent::AllowanceSpent {
+				delegator: 1,
+				delegatee: 3,
+				asset: SpendingAsset::Native,
+				amount: 3,
+				remaining: 2,
+			}
+			.into(),
+		);
+		assert_eq!(Balances::free_balance(6), 3);
+
+		// Spending beyond the allowance is reverted.
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(3), 1, None, Box::new(call_transfer(6, 3))));
+		System::assert_last_event(
+			ProxyEvent::ProxyExecuted { result: Err(Error::<Test>::AllowanceExceeded.into()) }
+				.into(),
+		);
+		assert_eq!(Balances::free_balance(6), 3);
+
+		assert_ok!(Proxy::proxy(
+			RuntimeOrigin::signed(3),
+			1,
+			None,
+			Box::new(call_transfer_asset(6, 20))
+		));
+		System::assert_has_event(
+			ProxyEvent::AllowanceSpent {
+				delegator: 1,
+				delegatee: 3,
+				asset: SpendingAsset::Asset(ASSET),
+				amount: 20,
+				remaining: 10,
+			}
+			.into(),
+		);
+		assert_eq!(Assets::balance(ASSET, 6), 20);
+
+		// A batch is metered as a whole.
+		let batch = RuntimeCall::Utility(UtilityCall::batch_all {
+			calls: vec![call_transfer_asset(6, 5), call_transfer_asset(6, 6)],
+		});
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(3), 1, None, Box::new(batch)));
+		System::assert_last_event(
+			ProxyEvent::ProxyExecuted { result: Err(Error::<Test>::AllowanceExceeded.into()) }
+				.into(),
+		);
+		assert_eq!(Assets::balance(ASSET, 6), 20);
+
+		// The amounts spent are reset once the period is over.
+		System::set_block_number(11);
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(3), 1, None, Box::new(call_transfer(6, 3))));
+		System::assert_has_event(
+			ProxyEvent::AllowanceSpent {
+				delegator: 1,
+				delegatee: 3,
+				asset: SpendingAsset::Native,
+				amount: 3,
+				remaining: 2,
+			}
+			.into(),
+		);
+		let allowance = Proxy::spending_allowances(1, 3).unwrap();
+		assert_eq!(allowance.period_start, 11);
+		assert_eq!(allowance.limits[1].spent, 0);
+
+		// Other proxies are not metered.
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(1), 4, ProxyType::Any, 0));
+		assert_ok!(Proxy::proxy(
+			RuntimeOrigin::signed(4),
+			1,
+			None,
+			Box::new(call_transfer_asset(6, 50))
+		));
+		System::assert_last_event(ProxyEvent::ProxyExecuted { result: Ok(()) }.into());
+	});
+}
+
+#[test]
+fn setting_spending_allowance_is_checked() {
+	new_test_ext().execute_with(|| {
+		let limits = vec![(SpendingAsset::Native, 5)];
+		assert_noop!(
+			Proxy::set_spending_allowance(RuntimeOrigin::signed(1), 3, limits.clone(), 10),
+			Error::<Test>::NotProxy
+		);
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(1), 3, ProxyType::JustTransfer, 0));
+		assert_noop!(
+			Proxy::set_spending_allowance(RuntimeOrigin::signed(1), 3, limits.clone(), 0),
+			Error::<Test>::ZeroAllowancePeriod
+		);
+		assert_noop!(
+			Proxy::set_spending_allowance(
+				RuntimeOrigin::signed(1),
+				3,
+				vec![(SpendingAsset::Native, 5), (SpendingAsset::Native, 6)],
+				10
+			),
+			Error::<Test>::DuplicateAllowanceAsset
+		);
+		assert_noop!(
+			Proxy::set_spending_allowance(
+				RuntimeOrigin::signed(1),
+				3,
+				vec![
+					(SpendingAsset::Native, 5),
+					(SpendingAsset::Asset(ASSET), 6),
+					(SpendingAsset::Asset(ASSET + 1), 6)
+				],
+				10
+			),
+			Error::<Test>::TooMany
+		);
+
+		assert_noop!(
+			Proxy::remove_spending_allowance(RuntimeOrigin::signed(1), 3),
+			Error::<Test>::NoSpendingAllowance
+		);
+		assert_ok!(Proxy::set_spending_allowance(RuntimeOrigin::signed(1), 3, limits.clone(), 10));
+		assert_ok!(Proxy::remove_spending_allowance(RuntimeOrigin::signed(1), 3));
+		System::assert_last_event(
+			ProxyEvent::SpendingAllowanceRemoved { delegator: 1, delegatee: 3 }.into(),
+		);
+		assert!(Proxy::spending_allowances(1, 3).is_none());
+
+		// The allowance goes with the last proxy of the delegate.
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(1), 3, ProxyType::Spending, 0));
+		assert_ok!(Proxy::set_spending_allowance(RuntimeOrigin::signed(1), 3, limits.clone(), 10));
+		assert_ok!(Proxy::remove_proxy(RuntimeOrigin::signed(1), 3, ProxyType::JustTransfer, 0));
+		assert!(Proxy::spending_allowances(1, 3).is_some());
+		assert_ok!(Proxy::remove_proxy(RuntimeOrigin::signed(1), 3, ProxyType::Spending, 0));
+		assert!(Proxy::spending_allowances(1, 3).is_none());
+
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(1), 3, ProxyType::JustTransfer, 0));
+		assert_ok!(Proxy::set_spending_allowance(RuntimeOrigin::signed(1), 3, limits, 10));
+		assert_ok!(Proxy::remove_proxies(RuntimeOrigin::signed(1)));
+		assert!(Proxy::spending_allowances(1, 3).is_none());
+	});
+}
+
+#[test]
+fn only_meterable_proxies_can_be_metered() {
+	new_test_ext().execute_with(|| {
+		let limits = vec![(SpendingAsset::Asset(ASSET), 5)];
+
+		// An `Any` proxy could approve a transfer and have it drained through the approval.
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(1), 3, ProxyType::Any, 0));
+		assert_noop!(
+			Proxy::set_spending_allowance(RuntimeOrigin::signed(1), 3, limits.clone(), 10),
+			Error::<Test>::UnmeterableProxy
+		);
+		// Neither is a delegate holding a meterable proxy type besides it.
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(1), 3, ProxyType::Spending, 0));
+		assert_noop!(
+			Proxy::set_spending_allowance(RuntimeOrigin::signed(1), 3, limits.clone(), 10),
+			Error::<Test>::UnmeterableProxy
+		);
+
+		// A metered delegate can't gain an unmeterable proxy type.
+		assert_ok!(Proxy::remove_proxy(RuntimeOrigin::signed(1), 3, ProxyType::Any, 0));
+		assert_ok!(Proxy::set_spending_allowance(RuntimeOrigin::signed(1), 3, limits, 10));
+		assert_noop!(
+			Proxy::add_proxy(RuntimeOrigin::signed(1), 3, ProxyType::JustUtility, 0),
+			Error::<Test>::UnmeterableProxy
+		);
+
+		let approve = RuntimeCall::Assets(pallet_assets::Call::approve_transfer {
+			id: ASSET,
+			delegate: 4,
+			amount: 50,
+		});
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(3), 1, None, Box::new(approve)));
+		System::assert_last_event(
+			ProxyEvent::ProxyExecuted { result: Err(SystemError::CallFiltered.into()) }.into(),
+		);
+	});
+}
+
+#[test]
+fn reserving_is_metered_as_spending() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(1), 3, ProxyType::Spending, 0));
+		assert_ok!(Proxy::set_spending_allowance(
+			RuntimeOrigin::signed(1),
+			3,
+			vec![(SpendingAsset::Native, 5)],
+			10,
+		));
+
+		// The approval deposit is reserved from the delegator.
+		let approve = RuntimeCall::Assets(pallet_assets::Call::approve_transfer {
+			id: ASSET,
+			delegate: 4,
+			amount: 50,
+		});
+		let reserved = Balances::reserved_balance(1);
+		let allowance = Proxy::spending_allowances(1, 3).unwrap();
+		assert_ok!(Proxy::dispatch_metered(&1, &3, allowance, approve, RuntimeOrigin::signed(1)));
+		assert_eq!(Balances::reserved_balance(1), reserved + 1);
+		assert_eq!(Proxy::spending_allowances(1, 3).unwrap().limits[0].spent, 1);
+	});
+}
+
+#[test]
+fn metered_proxy_cannot_escape_allowance() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(1), 3, ProxyType::Spending, 0));
+		assert_ok!(Proxy::set_spending_allowance(
+			RuntimeOrigin::signed(1),
+			3,
+			vec![(SpendingAsset::Native, 5)],
+			10,
+		));
+
+		let calls = vec![
+			RuntimeCall::Proxy(ProxyCall::add_proxy {
+				delegate: 4,
+				proxy_type: ProxyType::Any,
+				delay: 0,
+			}),
+			RuntimeCall::Proxy(ProxyCall::set_spending_allowance {
+				delegate: 3,
+				limits: vec![(SpendingAsset::Native, 10)],
+				period: 10,
+			}),
+			RuntimeCall::Proxy(ProxyCall::remove_spending_allowance { delegate: 3 }),
+		];
+		for call in calls {
+			assert_ok!(Proxy::proxy(RuntimeOrigin::signed(3), 1, None, Box::new(call)));
+			System::assert_last_event(
+				ProxyEvent::ProxyExecuted { result: Err(SystemError::CallFiltered.into()) }.into(),
+			);
+		}
+		assert_eq!(Proxy::spending_allowances(1, 3).unwrap().limits[0].limit, 5);
+	});
+}
@@ -58,6 +58,9 @@ pub trait WeightInfo {
 	fn remove_proxies(p: u32, ) -> Weight;
 	fn create_pure(p: u32, ) -> Weight;
 	fn kill_pure(p: u32, ) -> Weight;
+	fn set_spending_allowance(a: u32, ) -> Weight;
+	fn remove_spending_allowance() -> Weight;
+	fn meter_spending(a: u32, ) -> Weight;
 }
 
 /// Weights for pallet_proxy using the Substrate node and recommended hardware.
@@ -226,6 +229,53 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: Proxy Proxies (r:1 w:0)
+	/// Proof: Proxy Proxies (max_values: None, max_size: Some(1241), added: 3716, mode: MaxEncodedLen)
+	/// Storage: Proxy SpendingAllowances (r:0 w:1)
+	/// Proof: Proxy SpendingAllowances (max_values: None, max_size: Some(385), added: 2860, mode: MaxEncodedLen)
+	/// The range of component `a` is `[1, 8]`.
+	fn set_spending_allowance(a: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `230`
+		//  Estimated: `3716`
+		// Minimum execution time: 17_104 nanoseconds.
+		Weight::from_parts(17_652_318, 3716)
+			// Standard Error: 2_417
+			.saturating_add(Weight::from_parts(297_116, 0).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Proxy SpendingAllowances (r:1 w:1)
+	/// Proof: Proxy SpendingAllowances (max_values: None, max_size: Some(385), added: 2860, mode: MaxEncodedLen)
+	fn remove_spending_allowance() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `301`
+		//  Estimated: `2860`
+		// Minimum execution time: 15_826 nanoseconds.
+		Weight::from_parts(16_318_000, 2860)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Proxy SpendingAllowances (r:1 w:1)
+	/// Proof: Proxy SpendingAllowances (max_values: None, max_size: Some(385), added: 2860, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:7 w:0)
+	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// The range of component `a` is `[1, 8]`.
+	fn meter_spending(a: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `338 + a * (37 ±0)`
+		//  Estimated: `5463 + a * (2609 ±0)`
+		// Minimum execution time: 16_892 nanoseconds.
+		Weight::from_parts(13_476_904, 5463)
+			// Standard Error: 5_862
+			.saturating_add(Weight::from_parts(3_974_251, 0).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(a.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 2609).saturating_mul(a.into()))
+	}
 }
 
 // For backwards compatibility and tests
@@ -392,5 +442,51 @@ impl WeightInfo for () {
 			.saturating_add(Weight::from_parts(34_600, 0).saturating_mul(p.into()))
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}	/// Storage: Proxy Proxies (r:1 w:0)
+	/// Proof: Proxy Proxies (max_values: None, max_size: Some(1241), added: 3716, mode: MaxEncodedLen)
+	/// Storage: Proxy SpendingAllowances (r:0 w:1)
+	/// Proof: Proxy SpendingAllowances (max_values: None, max_size: Some(385), added: 2860, mode: MaxEncodedLen)
+	/// The range of component `a` is `[1, 8]`.
+	fn set_spending_allowance(a: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `230`
+		//  Estimated: `3716`
+		// Minimum execution time: 17_104 nanoseconds.
+		Weight::from_parts(17_652_318, 3716)
+			// Standard Error: 2_417
+			.saturating_add(Weight::from_parts(297_116, 0).saturating_mul(a.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Proxy SpendingAllowances (r:1 w:1)
+	/// Proof: Proxy SpendingAllowances (max_values: None, max_size: Some(385), added: 2860, mode: MaxEncodedLen)
+	fn remove_spending_allowance() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `301`
+		//  Estimated: `2860`
+		// Minimum execution time: 15_826 nanoseconds.
+		Weight::from_parts(16_318_000, 2860)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Proxy SpendingAllowances (r:1 w:1)
+	/// Proof: Proxy SpendingAllowances (max_values: None, max_size: Some(385), added: 2860, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:7 w:0)
+	/// Proof: Assets Account (max_values: None, max_size: Some(134), added: 2609, mode: MaxEncodedLen)
+	/// The range of component `a` is `[1, 8]`.
+	fn meter_spending(a: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `338 + a * (37 ±0)`
+		//  Estimated: `5463 + a * (2609 ±0)`
+		// Minimum execution time: 16_892 nanoseconds.
+		Weight::from_parts(13_476_904, 5463)
+			// Standard Error: 5_862
+			.saturating_add(Weight::from_parts(3_974_251, 0).saturating_mul(a.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(a.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 2609).saturating_mul(a.into()))
 	}
 }
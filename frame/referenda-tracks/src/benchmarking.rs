@@ -0,0 +1,120 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Referenda Tracks pallet benchmarking.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame_benchmarking::v1::{account, benchmarks_instance_pallet, BenchmarkError};
+use frame_support::{
+	assert_ok,
+	traits::{EnsureOrigin, OriginTrait},
+};
+use frame_system::RawOrigin;
+use pallet_referenda::Curve;
+use sp_runtime::Perbill;
+
+use crate::Pallet as ReferendaTracks;
+
+const SEED: u32 = 0;
+
+/// A valid track with a name of `MaxNameLength` bytes.
+fn track_info<T: Config<I>, I: 'static>() -> TrackInfoOf<T, I> {
+	let curve = Curve::LinearDecreasing {
+		length: Perbill::from_percent(100),
+		floor: Perbill::from_percent(50),
+		ceil: Perbill::from_percent(100),
+	};
+	TrackInfo {
+		name: Cow::Owned("t".repeat(T::MaxNameLength::get() as usize)),
+		max_deciding: 1,
+		decision_deposit: 10u32.into(),
+		prepare_period: 4u32.into(),
+		decision_period: 4u32.into(),
+		confirm_period: 2u32.into(),
+		min_enactment_period: 4u32.into(),
+		min_approval: curve.clone(),
+		min_support: curve,
+	}
+}
+
+/// The origin mapped to the `i`th track.
+fn track_origin<T: Config<I>, I: 'static>(i: u32) -> PalletsOriginOf<T> {
+	let origin: T::RuntimeOrigin = RawOrigin::Signed(account("origin", i, SEED)).into();
+	origin.into_caller()
+}
+
+/// Replace any existing tracks, e.g. those of the genesis, with the first `n` tracks.
+fn add_tracks<T: Config<I>, I: 'static>(update_origin: &T::RuntimeOrigin, n: u32) {
+	for id in TrackIds::<T, I>::take() {
+		Tracks::<T, I>::remove(id);
+		if let Some(pallet_origin) = TrackOrigin::<T, I>::take(id) {
+			OriginToTrackId::<T, I>::remove(pallet_origin);
+		}
+	}
+	for i in 0..n {
+		assert_ok!(ReferendaTracks::<T, I>::insert(
+			update_origin.clone(),
+			T::BenchmarkHelper::track_id(i),
+			track_info::<T, I>(),
+			Box::new(track_origin::<T, I>(i)),
+		));
+	}
+}
+
+benchmarks_instance_pallet! {
+	insert {
+		let max_tracks = T::MaxTracks::get();
+		let update_origin =
+			T::UpdateOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		add_tracks::<T, I>(&update_origin, max_tracks - 1);
+		let id = T::BenchmarkHelper::track_id(max_tracks - 1);
+		let pallet_origin = track_origin::<T, I>(max_tracks - 1);
+	}: _<T::RuntimeOrigin>(update_origin, id, track_info::<T, I>(), Box::new(pallet_origin.clone()))
+	verify {
+		assert_eq!(OriginToTrackId::<T, I>::get(pallet_origin), Some(id));
+		assert_eq!(TrackIds::<T, I>::get().len() as u32, max_tracks);
+	}
+
+	update {
+		let max_tracks = T::MaxTracks::get();
+		let update_origin =
+			T::UpdateOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		add_tracks::<T, I>(&update_origin, max_tracks);
+		let id = T::BenchmarkHelper::track_id(max_tracks - 1);
+		let mut info = track_info::<T, I>();
+		info.max_deciding = 2;
+	}: _<T::RuntimeOrigin>(update_origin, id, info)
+	verify {
+		assert_eq!(ReferendaTracks::<T, I>::info(id).unwrap().max_deciding, 2);
+	}
+
+	remove {
+		let max_tracks = T::MaxTracks::get();
+		let update_origin =
+			T::UpdateOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		add_tracks::<T, I>(&update_origin, max_tracks);
+		let id = T::BenchmarkHelper::track_id(0);
+	}: _<T::RuntimeOrigin>(update_origin, id)
+	verify {
+		assert!(ReferendaTracks::<T, I>::info(id).is_none());
+		assert_eq!(OriginToTrackId::<T, I>::get(track_origin::<T, I>(0)), None);
+	}
+
+	impl_benchmark_test_suite!(ReferendaTracks, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,361 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Referenda Tracks Pallet
+//!
+//! - [`Config`]
+//! - [`Call`]
+//!
+//! ## Overview
+//!
+//! A pallet keeping the tracks of the referenda pallet in storage, so that a privileged origin can
+//! add, update and retire them without a runtime upgrade. [`Pallet`] implements
+//! [`TracksInfo`] over the stored tracks and may be used as the `Tracks` of a referenda pallet
+//! instance.
+//!
+//! Each track is mapped from exactly one origin. A track is only stored if its curves are sane
+//! and its periods and limits allow referenda on it to be decided at all. The initial tracks are
+//! set in the genesis config, where origins are given SCALE encoded.
+//!
+//! Since [`TracksInfo::tracks_may_change`] is `true` for this pallet, the referenda pallet keeps
+//! the info of the track of each referendum as it was on submission. A change to the tracks thus
+//! only applies to referenda submitted after it; in particular referenda on a retired track are
+//! still decided as usual.
+//!
+//! Storage holding a value per track, like the class locks of conviction voting, is bounded by
+//! `MaxTracks` rather than by the number of tracks currently stored, so that retiring a track
+//! does not invalidate it.
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! - `insert` - Add a new track, mapped from an origin which is not yet mapped to a track.
+//! - `update` - Change the info of a track.
+//! - `remove` - Retire a track, so that no more referenda can be submitted on it.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+pub mod weights;
+
+use frame_support::{
+	dispatch::DispatchResult,
+	ensure,
+	traits::{tokens::Balance, Get, OriginTrait},
+	BoundedVec,
+};
+use pallet_referenda::{TrackInfo, TracksInfo};
+use sp_runtime::traits::{MaybeSerializeDeserialize, Zero};
+use sp_std::{borrow::Cow, prelude::*};
+
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+pub type PalletsOriginOf<T> =
+	<<T as frame_system::Config>::RuntimeOrigin as OriginTrait>::PalletsOrigin;
+pub type TrackInfoOf<T, I = ()> =
+	TrackInfo<<T as Config<I>>::Balance, <T as frame_system::Config>::BlockNumber>;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::config]
+	pub trait Config<I: 'static = ()>: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self, I>>
+			+ IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The identifier of a track.
+		type TrackId: Parameter + Member + Copy + Ord + MaxEncodedLen + MaybeSerializeDeserialize;
+
+		/// The balance type of the decision deposits of the tracks.
+		type Balance: Balance + MaybeSerializeDeserialize;
+
+		/// Origin from which the tracks may be changed.
+		type UpdateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The maximum number of tracks.
+		///
+		/// This bounds storage holding a value per track, so it must never be lowered.
+		#[pallet::constant]
+		type MaxTracks: Get<u32>;
+
+		/// The maximum length of the name of a track, in bytes.
+		#[pallet::constant]
+		type MaxNameLength: Get<u32>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+
+		/// Helper for creating the tracks in benchmarks.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<Self::TrackId>;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T, I = ()>(_);
+
+	/// The ids of the tracks, sorted.
+	#[pallet::storage]
+	pub type TrackIds<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, BoundedVec<T::TrackId, T::MaxTracks>, ValueQuery>;
+
+	/// The info of each track.
+	#[pallet::storage]
+	#[pallet::unbounded]
+	pub type Tracks<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::TrackId, TrackInfoOf<T, I>>;
+
+	/// The track each origin is mapped to.
+	#[pallet::storage]
+	pub type OriginToTrackId<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, PalletsOriginOf<T>, T::TrackId>;
+
+	/// The origin each track is mapped from.
+	#[pallet::storage]
+	pub type TrackOrigin<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::TrackId, PalletsOriginOf<T>>;
+
+	#[pallet::genesis_config]
+	pub struct GenesisConfig<T: Config<I>, I: 'static = ()> {
+		/// The initial tracks, each with the SCALE encoding of the origin mapped to it.
+		pub tracks: Vec<(T::TrackId, TrackInfoOf<T, I>, Vec<u8>)>,
+	}
+
+	#[cfg(feature = "std")]
+	impl<T: Config<I>, I: 'static> Default for GenesisConfig<T, I> {
+		fn default() -> Self {
+			Self { tracks: Default::default() }
+		}
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config<I>, I: 'static> GenesisBuild<T, I> for GenesisConfig<T, I> {
+		fn build(&self) {
+			for (id, info, pallet_origin) in &self.tracks {
+				let pallet_origin = PalletsOriginOf::<T>::decode(&mut &pallet_origin[..])
+					.expect("genesis track origin must decode");
+				Pallet::<T, I>::do_insert(*id, info.clone(), pallet_origin)
+					.expect("genesis tracks must be valid and unique");
+			}
+		}
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config<I>, I: 'static = ()> {
+		/// A track has been added.
+		TrackAdded { id: T::TrackId },
+		/// The info of a track has been updated.
+		TrackUpdated { id: T::TrackId },
+		/// A track has been removed.
+		TrackRemoved { id: T::TrackId },
+	}
+
+	#[pallet::error]
+	pub enum Error<T, I = ()> {
+		/// There is already a track with the given id.
+		TrackIdTaken,
+		/// The origin is already mapped to a track.
+		OriginTaken,
+		/// There is no track with the given id.
+		TrackNotFound,
+		/// There are already `MaxTracks` tracks.
+		TooManyTracks,
+		/// The name of the track is empty or longer than `MaxNameLength`.
+		BadName,
+		/// The approval or support curve of the track is not sane.
+		BadCurve,
+		/// The track allows no referenda to be decided, or gives them no time to be.
+		BadTrackInfo,
+	}
+
+	#[pallet::call]
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
+		/// Add a track, on which referenda for `pallet_origin` are submitted.
+		///
+		/// The dispatch origin for this call must be `UpdateOrigin`.
+		///
+		/// - `id`: The id of the track. Must not be taken by another track.
+		/// - `info`: The info of the track.
+		/// - `pallet_origin`: The origin mapped to the track. Must not be mapped to another track.
+		///
+		/// Emits `TrackAdded`.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::insert())]
+		pub fn insert(
+			origin: OriginFor<T>,
+			id: T::TrackId,
+			info: TrackInfoOf<T, I>,
+			pallet_origin: Box<PalletsOriginOf<T>>,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			Self::do_insert(id, info, *pallet_origin)?;
+
+			Self::deposit_event(Event::TrackAdded { id });
+			Ok(())
+		}
+
+		/// Change the info of a track.
+		///
+		/// The dispatch origin for this call must be `UpdateOrigin`.
+		///
+		/// Ongoing referenda on the track keep to the info it had when they were submitted.
+		///
+		/// Emits `TrackUpdated`.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::update())]
+		pub fn update(
+			origin: OriginFor<T>,
+			id: T::TrackId,
+			info: TrackInfoOf<T, I>,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			Self::ensure_valid(&info)?;
+
+			Tracks::<T, I>::try_mutate(id, |track| -> DispatchResult {
+				let track = track.as_mut().ok_or(Error::<T, I>::TrackNotFound)?;
+				*track = info;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::TrackUpdated { id });
+			Ok(())
+		}
+
+		/// Retire a track, unmapping its origin so that no more referenda can be submitted on it.
+		///
+		/// The dispatch origin for this call must be `UpdateOrigin`.
+		///
+		/// Ongoing referenda on the track are still decided with the info it had when they were
+		/// submitted.
+		///
+		/// Emits `TrackRemoved`.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::remove())]
+		pub fn remove(origin: OriginFor<T>, id: T::TrackId) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			Tracks::<T, I>::take(id).ok_or(Error::<T, I>::TrackNotFound)?;
+			TrackIds::<T, I>::mutate(|ids| ids.retain(|x| *x != id));
+			if let Some(pallet_origin) = TrackOrigin::<T, I>::take(id) {
+				OriginToTrackId::<T, I>::remove(pallet_origin);
+			}
+
+			Self::deposit_event(Event::TrackRemoved { id });
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// Add the track `id` with the given `info`, mapped from `pallet_origin`.
+	fn do_insert(
+		id: T::TrackId,
+		info: TrackInfoOf<T, I>,
+		pallet_origin: PalletsOriginOf<T>,
+	) -> DispatchResult {
+		Self::ensure_valid(&info)?;
+		ensure!(!OriginToTrackId::<T, I>::contains_key(&pallet_origin), Error::<T, I>::OriginTaken);
+
+		TrackIds::<T, I>::try_mutate(|ids| -> DispatchResult {
+			let index = match ids.binary_search(&id) {
+				Ok(_) => return Err(Error::<T, I>::TrackIdTaken.into()),
+				Err(index) => index,
+			};
+			ids.try_insert(index, id).map_err(|_| Error::<T, I>::TooManyTracks)?;
+			Ok(())
+		})?;
+		Tracks::<T, I>::insert(id, info);
+		OriginToTrackId::<T, I>::insert(&pallet_origin, id);
+		TrackOrigin::<T, I>::insert(id, pallet_origin);
+		Ok(())
+	}
+
+	/// Check that referenda on a track with the given `info` can be decided.
+	fn ensure_valid(info: &TrackInfoOf<T, I>) -> DispatchResult {
+		ensure!(
+			!info.name.is_empty() && info.name.len() <= T::MaxNameLength::get() as usize,
+			Error::<T, I>::BadName
+		);
+		ensure!(info.min_approval.is_sane() && info.min_support.is_sane(), Error::<T, I>::BadCurve);
+		ensure!(
+			info.max_deciding > 0 && !info.decision_period.is_zero(),
+			Error::<T, I>::BadTrackInfo
+		);
+		Ok(())
+	}
+}
+
+impl<T: Config<I>, I: 'static> TracksInfo<T::Balance, T::BlockNumber> for Pallet<T, I> {
+	type Id = T::TrackId;
+	type RuntimeOrigin = PalletsOriginOf<T>;
+
+	fn tracks() -> Cow<'static, [(Self::Id, TrackInfoOf<T, I>)]> {
+		Cow::Owned(<Self as Get<Vec<_>>>::get())
+	}
+
+	fn track_ids() -> Vec<Self::Id> {
+		TrackIds::<T, I>::get().into_inner()
+	}
+
+	fn max_tracks() -> u32 {
+		T::MaxTracks::get()
+	}
+
+	fn track_for(origin: &Self::RuntimeOrigin) -> Result<Self::Id, ()> {
+		OriginToTrackId::<T, I>::get(origin).ok_or(())
+	}
+
+	fn info(id: Self::Id) -> Option<Cow<'static, TrackInfoOf<T, I>>> {
+		Tracks::<T, I>::get(id).map(Cow::Owned)
+	}
+
+	fn tracks_may_change() -> bool {
+		true
+	}
+}
+
+impl<T: Config<I>, I: 'static> Get<Vec<(T::TrackId, TrackInfoOf<T, I>)>> for Pallet<T, I> {
+	fn get() -> Vec<(T::TrackId, TrackInfoOf<T, I>)> {
+		TrackIds::<T, I>::get()
+			.into_iter()
+			.filter_map(|id| Tracks::<T, I>::get(id).map(|info| (id, info)))
+			.collect()
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<TrackId> {
+	/// The id of the `i`th track.
+	fn track_id(i: u32) -> TrackId;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl<TrackId: From<u16>> BenchmarkHelper<TrackId> for () {
+	fn track_id(i: u32) -> TrackId {
+		(i as u16).into()
+	}
+}
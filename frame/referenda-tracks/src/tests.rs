@@ -0,0 +1,184 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for Referenda Tracks pallet.
+
+use super::*;
+use crate::mock::*;
+use codec::Encode;
+use frame_support::{
+	assert_noop, assert_ok, dispatch::DispatchError::BadOrigin, traits::GenesisBuild,
+};
+use pallet_referenda::Curve;
+use sp_runtime::{FixedI64, Perbill};
+
+#[test]
+fn insert_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(ReferendaTracks::insert(RuntimeOrigin::root(), 2, track("two", 4), signed(2)));
+		assert_ok!(ReferendaTracks::insert(RuntimeOrigin::root(), 1, track("one", 8), signed(1)));
+		System::assert_last_event(Event::TrackAdded { id: 1 }.into());
+
+		// Tracks are kept sorted by their id.
+		assert_eq!(
+			<ReferendaTracks as TracksInfo<_, _>>::tracks().into_owned(),
+			vec![(1, track("one", 8)), (2, track("two", 4))]
+		);
+		assert_eq!(
+			<ReferendaTracks as Get<Vec<_>>>::get(),
+			vec![(1, track("one", 8)), (2, track("two", 4))]
+		);
+		assert_eq!(ReferendaTracks::info(2).map(Cow::into_owned), Some(track("two", 4)));
+		assert_eq!(ReferendaTracks::info(3), None);
+		assert_eq!(ReferendaTracks::track_for(&signed(1)), Ok(1));
+		assert_eq!(ReferendaTracks::track_for(&signed(2)), Ok(2));
+		assert_eq!(ReferendaTracks::track_for(&signed(3)), Err(()));
+		assert!(<ReferendaTracks as TracksInfo<_, _>>::tracks_may_change());
+	});
+}
+
+#[test]
+fn insert_checks_ids_and_origins() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			ReferendaTracks::insert(RuntimeOrigin::signed(1), 1, track("one", 4), signed(1)),
+			BadOrigin
+		);
+		assert_ok!(ReferendaTracks::insert(RuntimeOrigin::root(), 1, track("one", 4), signed(1)));
+		assert_noop!(
+			ReferendaTracks::insert(RuntimeOrigin::root(), 1, track("two", 4), signed(2)),
+			Error::<Test>::TrackIdTaken
+		);
+		assert_noop!(
+			ReferendaTracks::insert(RuntimeOrigin::root(), 2, track("two", 4), signed(1)),
+			Error::<Test>::OriginTaken
+		);
+		assert_ok!(ReferendaTracks::insert(RuntimeOrigin::root(), 2, track("two", 4), signed(2)));
+		assert_ok!(ReferendaTracks::insert(RuntimeOrigin::root(), 3, track("three", 4), signed(3)));
+		assert_noop!(
+			ReferendaTracks::insert(RuntimeOrigin::root(), 4, track("four", 4), signed(4)),
+			Error::<Test>::TooManyTracks
+		);
+	});
+}
+
+#[test]
+fn tracks_are_validated() {
+	new_test_ext().execute_with(|| {
+		let insert = |info| ReferendaTracks::insert(RuntimeOrigin::root(), 1, info, signed(1));
+
+		assert_noop!(insert(track("", 4)), Error::<Test>::BadName);
+		assert_noop!(insert(track("a name far too long", 4)), Error::<Test>::BadName);
+		assert_noop!(insert(track("one", 0)), Error::<Test>::BadTrackInfo);
+		let mut info = track("one", 4);
+		info.max_deciding = 0;
+		assert_noop!(insert(info), Error::<Test>::BadTrackInfo);
+
+		let mut info = track("one", 4);
+		info.min_approval = Curve::LinearDecreasing {
+			length: Perbill::from_percent(100),
+			floor: Perbill::from_percent(60),
+			ceil: Perbill::from_percent(50),
+		};
+		assert_noop!(insert(info), Error::<Test>::BadCurve);
+		let mut info = track("one", 4);
+		info.min_support = Curve::Reciprocal {
+			factor: FixedI64::from_u32(0),
+			x_offset: FixedI64::from_u32(1),
+			y_offset: FixedI64::from_u32(0),
+		};
+		assert_noop!(insert(info), Error::<Test>::BadCurve);
+
+		assert_ok!(insert(track("one", 4)));
+		assert_noop!(
+			ReferendaTracks::update(RuntimeOrigin::root(), 1, track("", 4)),
+			Error::<Test>::BadName
+		);
+	});
+}
+
+#[test]
+fn update_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(ReferendaTracks::insert(RuntimeOrigin::root(), 1, track("one", 4), signed(1)));
+		assert_noop!(
+			ReferendaTracks::update(RuntimeOrigin::signed(1), 1, track("one", 8)),
+			BadOrigin
+		);
+		assert_noop!(
+			ReferendaTracks::update(RuntimeOrigin::root(), 2, track("two", 8)),
+			Error::<Test>::TrackNotFound
+		);
+
+		assert_ok!(ReferendaTracks::update(RuntimeOrigin::root(), 1, track("one", 8)));
+		System::assert_last_event(Event::TrackUpdated { id: 1 }.into());
+		assert_eq!(ReferendaTracks::info(1).map(Cow::into_owned), Some(track("one", 8)));
+		assert_eq!(ReferendaTracks::track_for(&signed(1)), Ok(1));
+	});
+}
+
+#[test]
+fn remove_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(ReferendaTracks::insert(RuntimeOrigin::root(), 1, track("one", 4), signed(1)));
+		assert_ok!(ReferendaTracks::insert(RuntimeOrigin::root(), 2, track("two", 4), signed(2)));
+		assert_noop!(ReferendaTracks::remove(RuntimeOrigin::signed(1), 1), BadOrigin);
+		assert_noop!(
+			ReferendaTracks::remove(RuntimeOrigin::root(), 3),
+			Error::<Test>::TrackNotFound
+		);
+
+		assert_ok!(ReferendaTracks::remove(RuntimeOrigin::root(), 1));
+		System::assert_last_event(Event::TrackRemoved { id: 1 }.into());
+		assert_eq!(
+			<ReferendaTracks as TracksInfo<_, _>>::tracks().into_owned(),
+			vec![(2, track("two", 4))]
+		);
+		assert_eq!(ReferendaTracks::track_for(&signed(1)), Err(()));
+		assert_eq!(TrackOrigin::<Test>::get(1), None);
+		assert_eq!(<ReferendaTracks as TracksInfo<_, _>>::track_ids(), vec![2]);
+		// The bound of storage holding a value per track does not shrink.
+		assert_eq!(<ReferendaTracks as TracksInfo<_, _>>::max_tracks(), 3);
+
+		// The origin may be mapped to another track once its track is retired.
+		assert_ok!(ReferendaTracks::insert(RuntimeOrigin::root(), 3, track("three", 4), signed(1)));
+		assert_eq!(ReferendaTracks::track_for(&signed(1)), Ok(3));
+	});
+}
+
+#[test]
+fn genesis_tracks_work() {
+	let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	GenesisBuild::<Test>::assimilate_storage(
+		&crate::GenesisConfig::<Test> {
+			tracks: vec![
+				(2, track("two", 4), signed(2).encode()),
+				(1, track("one", 8), signed(1).encode()),
+			],
+		},
+		&mut t,
+	)
+	.unwrap();
+
+	sp_io::TestExternalities::new(t).execute_with(|| {
+		assert_eq!(
+			<ReferendaTracks as TracksInfo<_, _>>::tracks().into_owned(),
+			vec![(1, track("one", 8)), (2, track("two", 4))]
+		);
+		assert_eq!(ReferendaTracks::track_for(&signed(2)), Ok(2));
+	});
+}
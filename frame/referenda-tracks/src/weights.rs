@@ -0,0 +1,153 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Autogenerated weights for pallet_referenda_tracks
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: 2023-03-14, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `bm2`, CPU: `Intel(R) Core(TM) i7-7700K CPU @ 4.20GHz`
+//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+
+// Executed Command:
+// ./target/production/substrate
+// benchmark
+// pallet
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_referenda_tracks
+// --extrinsic=*
+// --execution=wasm
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --output=./frame/referenda-tracks/src/weights.rs
+// --header=./HEADER-APACHE2
+// --template=./.maintain/frame-weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_referenda_tracks.
+pub trait WeightInfo {
+	fn insert() -> Weight;
+	fn update() -> Weight;
+	fn remove() -> Weight;
+}
+
+/// Weights for pallet_referenda_tracks using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: ReferendaTracks OriginToTrackId (r:1 w:1)
+	/// Proof: ReferendaTracks OriginToTrackId (max_values: None, max_size: Some(61), added: 2536, mode: MaxEncodedLen)
+	/// Storage: ReferendaTracks TrackIds (r:1 w:1)
+	/// Proof: ReferendaTracks TrackIds (max_values: Some(1), max_size: Some(65), added: 560, mode: MaxEncodedLen)
+	/// Storage: ReferendaTracks Tracks (r:0 w:1)
+	/// Proof Skipped: ReferendaTracks Tracks (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ReferendaTracks TrackOrigin (r:0 w:1)
+	/// Proof: ReferendaTracks TrackOrigin (max_values: None, max_size: Some(61), added: 2536, mode: MaxEncodedLen)
+	fn insert() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `178`
+		//  Estimated: `3096`
+		// Minimum execution time: 30_874 nanoseconds.
+		Weight::from_parts(31_512_000, 3096)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: ReferendaTracks Tracks (r:1 w:1)
+	/// Proof Skipped: ReferendaTracks Tracks (max_values: None, max_size: None, mode: Measured)
+	fn update() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `239`
+		//  Estimated: `2714`
+		// Minimum execution time: 18_763 nanoseconds.
+		Weight::from_parts(19_230_000, 2714)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: ReferendaTracks Tracks (r:1 w:1)
+	/// Proof Skipped: ReferendaTracks Tracks (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ReferendaTracks TrackIds (r:1 w:1)
+	/// Proof: ReferendaTracks TrackIds (max_values: Some(1), max_size: Some(65), added: 560, mode: MaxEncodedLen)
+	/// Storage: ReferendaTracks TrackOrigin (r:1 w:1)
+	/// Proof: ReferendaTracks TrackOrigin (max_values: None, max_size: Some(61), added: 2536, mode: MaxEncodedLen)
+	/// Storage: ReferendaTracks OriginToTrackId (r:0 w:1)
+	/// Proof: ReferendaTracks OriginToTrackId (max_values: None, max_size: Some(61), added: 2536, mode: MaxEncodedLen)
+	fn remove() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `304`
+		//  Estimated: `5875`
+		// Minimum execution time: 32_690 nanoseconds.
+		Weight::from_parts(33_418_000, 5875)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: ReferendaTracks OriginToTrackId (r:1 w:1)
+	/// Proof: ReferendaTracks OriginToTrackId (max_values: None, max_size: Some(61), added: 2536, mode: MaxEncodedLen)
+	/// Storage: ReferendaTracks TrackIds (r:1 w:1)
+	/// Proof: ReferendaTracks TrackIds (max_values: Some(1), max_size: Some(65), added: 560, mode: MaxEncodedLen)
+	/// Storage: ReferendaTracks Tracks (r:0 w:1)
+	/// Proof Skipped: ReferendaTracks Tracks (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ReferendaTracks TrackOrigin (r:0 w:1)
+	/// Proof: ReferendaTracks TrackOrigin (max_values: None, max_size: Some(61), added: 2536, mode: MaxEncodedLen)
+	fn insert() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `178`
+		//  Estimated: `3096`
+		// Minimum execution time: 30_874 nanoseconds.
+		Weight::from_parts(31_512_000, 3096)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: ReferendaTracks Tracks (r:1 w:1)
+	/// Proof Skipped: ReferendaTracks Tracks (max_values: None, max_size: None, mode: Measured)
+	fn update() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `239`
+		//  Estimated: `2714`
+		// Minimum execution time: 18_763 nanoseconds.
+		Weight::from_parts(19_230_000, 2714)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: ReferendaTracks Tracks (r:1 w:1)
+	/// Proof Skipped: ReferendaTracks Tracks (max_values: None, max_size: None, mode: Measured)
+	/// Storage: ReferendaTracks TrackIds (r:1 w:1)
+	/// Proof: ReferendaTracks TrackIds (max_values: Some(1), max_size: Some(65), added: 560, mode: MaxEncodedLen)
+	/// Storage: ReferendaTracks TrackOrigin (r:1 w:1)
+	/// Proof: ReferendaTracks TrackOrigin (max_values: None, max_size: Some(61), added: 2536, mode: MaxEncodedLen)
+	/// Storage: ReferendaTracks OriginToTrackId (r:0 w:1)
+	/// Proof: ReferendaTracks OriginToTrackId (max_values: None, max_size: Some(61), added: 2536, mode: MaxEncodedLen)
+	fn remove() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `304`
+		//  Estimated: `5875`
+		// Minimum execution time: 32_690 nanoseconds.
+		Weight::from_parts(33_418_000, 5875)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+}
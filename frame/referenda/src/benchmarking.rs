@@ -106,9 +106,11 @@ fn fill_queue<T: Config<I>, I: 'static>(
 	others
 }
 
-fn info<T: Config<I>, I: 'static>(index: ReferendumIndex) -> &'static TrackInfoOf<T, I> {
+fn info<T: Config<I>, I: 'static>(index: ReferendumIndex) -> TrackInfoOf<T, I> {
 	let status = Referenda::<T, I>::ensure_ongoing(index).unwrap();
-	T::Tracks::info(status.track).expect("Id value returned from T::Tracks")
+	Referenda::<T, I>::track(index, status.track)
+		.expect("Id value returned from T::Tracks")
+		.into_owned()
 }
 
 fn make_passing_after<T: Config<I>, I: 'static>(index: ReferendumIndex, period_portion: Perbill) {
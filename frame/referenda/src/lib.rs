@@ -82,7 +82,7 @@ use sp_runtime::{
 	traits::{AtLeast32BitUnsigned, Dispatchable, One, Saturating, Zero},
 	DispatchError, Perbill,
 };
-use sp_std::{fmt::Debug, prelude::*};
+use sp_std::{borrow::Cow, fmt::Debug, prelude::*};
 
 mod branch;
 pub mod migration;
@@ -127,7 +127,7 @@ macro_rules! impl_tracksinfo_get {
 				<$tracksinfo as $crate::TracksInfo<$balance, $blocknumber>>::Id,
 				$crate::TrackInfo<$balance, $blocknumber>,
 			)> {
-				<$tracksinfo as $crate::TracksInfo<$balance, $blocknumber>>::tracks().to_vec()
+				<$tracksinfo as $crate::TracksInfo<$balance, $blocknumber>>::tracks().into_owned()
 			}
 		}
 	};
@@ -261,6 +261,15 @@ pub mod pallet {
 	pub type MetadataOf<T: Config<I>, I: 'static = ()> =
 		StorageMap<_, Blake2_128Concat, ReferendumIndex, PreimageHash>;
 
+	/// The info of the track of an ongoing referendum, as it was when the referendum was
+	/// submitted.
+	///
+	/// Only kept if the tracks may change, see `TracksInfo::tracks_may_change`.
+	#[pallet::storage]
+	#[pallet::unbounded]
+	pub type ReferendumTrackInfo<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, ReferendumIndex, TrackInfoOf<T, I>>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config<I>, I: 'static = ()> {
@@ -435,12 +444,16 @@ pub mod pallet {
 
 			let track =
 				T::Tracks::track_for(&proposal_origin).map_err(|_| Error::<T, I>::NoTrack)?;
+			let track_info = T::Tracks::info(track).ok_or(Error::<T, I>::NoTrack)?;
 			let submission_deposit = Self::take_deposit(who, T::SubmissionDeposit::get())?;
 			let index = ReferendumCount::<T, I>::mutate(|x| {
 				let r = *x;
 				*x += 1;
 				r
 			});
+			if T::Tracks::tracks_may_change() {
+				ReferendumTrackInfo::<T, I>::insert(index, track_info.into_owned());
+			}
 			let now = frame_system::Pallet::<T>::block_number();
 			let nudge_call =
 				T::Preimages::bound(CallOf::<T, I>::from(Call::nudge_referendum { index }))?;
@@ -480,7 +493,7 @@ pub mod pallet {
 			let who = ensure_signed(origin)?;
 			let mut status = Self::ensure_ongoing(index)?;
 			ensure!(status.decision_deposit.is_none(), Error::<T, I>::HasDeposit);
-			let track = Self::track(status.track).ok_or(Error::<T, I>::NoTrack)?;
+			let track = Self::track(index, status.track).ok_or(Error::<T, I>::NoTrack)?;
 			status.decision_deposit =
 				Some(Self::take_deposit(who.clone(), track.decision_deposit)?);
 			let now = frame_system::Pallet::<T>::block_number();
@@ -538,6 +551,7 @@ pub mod pallet {
 				let _ = T::Scheduler::cancel(last_alarm);
 			}
 			Self::note_one_fewer_deciding(status.track);
+			Self::clear_track_info(index);
 			Self::deposit_event(Event::<T, I>::Cancelled { index, tally: status.tally });
 			let info = ReferendumInfo::Cancelled(
 				frame_system::Pallet::<T>::block_number(),
@@ -563,6 +577,7 @@ pub mod pallet {
 				let _ = T::Scheduler::cancel(last_alarm);
 			}
 			Self::note_one_fewer_deciding(status.track);
+			Self::clear_track_info(index);
 			Self::deposit_event(Event::<T, I>::Killed { index, tally: status.tally });
 			Self::slash_deposit(Some(status.submission_deposit.clone()));
 			Self::slash_deposit(status.decision_deposit.clone());
@@ -610,13 +625,13 @@ pub mod pallet {
 			track: TrackIdOf<T, I>,
 		) -> DispatchResultWithPostInfo {
 			ensure_root(origin)?;
-			let track_info = T::Tracks::info(track).ok_or(Error::<T, I>::BadTrack)?;
 			let mut track_queue = TrackQueue::<T, I>::get(track);
 			let branch =
 				if let Some((index, mut status)) = Self::next_for_deciding(&mut track_queue) {
+					let track_info = Self::track(index, track).ok_or(Error::<T, I>::BadTrack)?;
 					let now = frame_system::Pallet::<T>::block_number();
 					let (maybe_alarm, branch) =
-						Self::begin_deciding(&mut status, index, now, track_info);
+						Self::begin_deciding(&mut status, index, now, &track_info);
 					if let Some(set_alarm) = maybe_alarm {
 						Self::ensure_alarm_at(&mut status, index, set_alarm);
 					}
@@ -704,7 +719,11 @@ impl<T: Config<I>, I: 'static> Polling<T::Tally> for Pallet<T, I> {
 	type Class = TrackIdOf<T, I>;
 
 	fn classes() -> Vec<Self::Class> {
-		T::Tracks::tracks().iter().map(|x| x.0).collect()
+		T::Tracks::track_ids()
+	}
+
+	fn max_classes() -> u32 {
+		T::Tracks::max_tracks()
 	}
 
 	fn access_poll<R>(
@@ -784,6 +803,7 @@ impl<T: Config<I>, I: 'static> Polling<T::Tally> for Pallet<T, I> {
 		let mut status = Self::ensure_ongoing(index).map_err(|_| ())?;
 		Self::ensure_no_alarm(&mut status);
 		Self::note_one_fewer_deciding(status.track);
+		Self::clear_track_info(index);
 		let now = frame_system::Pallet::<T>::block_number();
 		let info = if approved {
 			ReferendumInfo::Approved(now, Some(status.submission_deposit), status.decision_deposit)
@@ -796,7 +816,8 @@ impl<T: Config<I>, I: 'static> Polling<T::Tally> for Pallet<T, I> {
 
 	#[cfg(feature = "runtime-benchmarks")]
 	fn max_ongoing() -> (Self::Class, u32) {
-		let r = T::Tracks::tracks()
+		let tracks = T::Tracks::tracks();
+		let r = tracks
 			.iter()
 			.max_by_key(|(_, info)| info.max_deciding)
 			.expect("Always one class");
@@ -822,7 +843,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		let info = ReferendumInfoFor::<T, I>::get(ref_index).ok_or(Error::<T, I>::BadReferendum)?;
 		match info {
 			ReferendumInfo::Ongoing(status) => {
-				let track = Self::track(status.track).ok_or(Error::<T, I>::NoTrack)?;
+				let track = Self::track(ref_index, status.track).ok_or(Error::<T, I>::NoTrack)?;
 				let elapsed = if let Some(deciding) = status.deciding {
 					frame_system::Pallet::<T>::block_number().saturating_sub(deciding.since)
 				} else {
@@ -1052,10 +1073,11 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	) -> (ReferendumInfoOf<T, I>, bool, ServiceBranch) {
 		let mut dirty = false;
 		// Should it begin being decided?
-		let track = match Self::track(status.track) {
+		let track = match Self::track(index, status.track) {
 			Some(x) => x,
 			None => return (ReferendumInfo::Ongoing(status), false, ServiceBranch::Fail),
 		};
+		let track = &*track;
 		let timeout = status.submitted + T::UndecidingTimeout::get();
 		// Default the alarm to the submission timeout.
 		let mut alarm = timeout;
@@ -1106,6 +1128,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 				if status.deciding.is_none() && now >= timeout {
 					// Too long without being decided - end it.
 					Self::ensure_no_alarm(&mut status);
+					Self::clear_track_info(index);
 					Self::deposit_event(Event::<T, I>::TimedOut { index, tally: status.tally });
 					return (
 						ReferendumInfo::TimedOut(
@@ -1133,6 +1156,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 							// Passed!
 							Self::ensure_no_alarm(&mut status);
 							Self::note_one_fewer_deciding(status.track);
+							Self::clear_track_info(index);
 							let (desired, call) = (status.enactment, status.proposal);
 							Self::schedule_enactment(index, track, desired, status.origin, call);
 							Self::deposit_event(Event::<T, I>::Confirmed {
@@ -1163,6 +1187,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 						// Failed!
 						Self::ensure_no_alarm(&mut status);
 						Self::note_one_fewer_deciding(status.track);
+						Self::clear_track_info(index);
 						Self::deposit_event(Event::<T, I>::Rejected { index, tally: status.tally });
 						return (
 							ReferendumInfo::Rejected(
@@ -1243,11 +1268,26 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		}
 	}
 
-	/// Get the track info value for the track `id`.
-	fn track(id: TrackIdOf<T, I>) -> Option<&'static TrackInfoOf<T, I>> {
-		let tracks = T::Tracks::tracks();
-		let index = tracks.binary_search_by_key(&id, |x| x.0).unwrap_or_else(|x| x);
-		Some(&tracks[index].1)
+	/// Get the track info value for the track `id` of the referendum `index`.
+	///
+	/// This is the info the track had when the referendum was submitted, if the tracks may change.
+	fn track(
+		index: ReferendumIndex,
+		id: TrackIdOf<T, I>,
+	) -> Option<Cow<'static, TrackInfoOf<T, I>>> {
+		if T::Tracks::tracks_may_change() {
+			if let Some(info) = ReferendumTrackInfo::<T, I>::get(index) {
+				return Some(Cow::Owned(info))
+			}
+		}
+		T::Tracks::info(id)
+	}
+
+	/// Forget the track info kept for the referendum `index`, which is no longer ongoing.
+	fn clear_track_info(index: ReferendumIndex) {
+		if T::Tracks::tracks_may_change() {
+			ReferendumTrackInfo::<T, I>::remove(index);
+		}
 	}
 
 	/// Determine whether the given `tally` would result in a referendum passing at `elapsed` blocks
@@ -1273,3 +1313,16 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		}
 	}
 }
+
+sp_api::decl_runtime_apis! {
+	/// Exposes the tracks of referenda.
+	pub trait ReferendaTracksApi<TrackId, Balance, BlockNumber>
+	where
+		TrackId: Codec,
+		Balance: Codec,
+		BlockNumber: Codec,
+	{
+		/// The tracks referenda can currently be submitted on, sorted by id.
+		fn tracks() -> Vec<(TrackId, TrackInfo<Balance, BlockNumber>)>;
+	}
+}
@@ -94,6 +94,7 @@ impl pallet_preimage::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = ();
 	type Currency = Balances;
+	type RuntimeHoldReason = RuntimeHoldReason;
 	type ManagerOrigin = EnsureRoot<u64>;
 	type BaseDeposit = ();
 	type ByteDeposit = ();
@@ -113,6 +114,10 @@ impl pallet_scheduler::Config for Test {
 impl pallet_balances::Config for Test {
 	type MaxReserves = ();
 	type ReserveIdentifier = [u8; 8];
+	type HoldIdentifier = RuntimeHoldReason;
+	type FreezeIdentifier = ();
+	type MaxHolds = ConstU32<1>;
+	type MaxFreezes = ();
 	type MaxLocks = ConstU32<10>;
 	type Balance = u64;
 	type RuntimeEvent = RuntimeEvent;
@@ -123,6 +128,8 @@ impl pallet_balances::Config for Test {
 }
 parameter_types! {
 	pub static AlarmInterval: u64 = 1;
+	/// If `Some`, the tracks may change and all of them use this decision period.
+	pub static ChangedDecisionPeriod: Option<u64> = None;
 }
 ord_parameter_types! {
 	pub const One: u64 = 1;
@@ -145,12 +152,12 @@ pub struct TestTracksInfo;
 impl TracksInfo<u64, u64> for TestTracksInfo {
 	type Id = u8;
 	type RuntimeOrigin = <RuntimeOrigin as OriginTrait>::PalletsOrigin;
-	fn tracks() -> &'static [(Self::Id, TrackInfo<u64, u64>)] {
+	fn tracks() -> Cow<'static, [(Self::Id, TrackInfo<u64, u64>)]> {
 		static DATA: [(u8, TrackInfo<u64, u64>); 2] = [
 			(
 				0u8,
 				TrackInfo {
-					name: "root",
+					name: Cow::Borrowed("root"),
 					max_deciding: 1,
 					decision_deposit: 10,
 					prepare_period: 4,
@@ -172,7 +179,7 @@ impl TracksInfo<u64, u64> for TestTracksInfo {
 			(
 				1u8,
 				TrackInfo {
-					name: "none",
+					name: Cow::Borrowed("none"),
 					max_deciding: 3,
 					decision_deposit: 1,
 					prepare_period: 2,
@@ -192,7 +199,18 @@ impl TracksInfo<u64, u64> for TestTracksInfo {
 				},
 			),
 		];
-		&DATA[..]
+		match ChangedDecisionPeriod::get() {
+			Some(decision_period) => DATA
+				.iter()
+				.cloned()
+				.map(|(id, info)| (id, TrackInfo { decision_period, ..info }))
+				.collect::<Vec<_>>()
+				.into(),
+			None => Cow::Borrowed(&DATA[..]),
+		}
+	}
+	fn tracks_may_change() -> bool {
+		ChangedDecisionPeriod::get().is_some()
 	}
 	fn track_for(id: &Self::RuntimeOrigin) -> Result<Self::Id, ()> {
 		if let Ok(system_origin) = frame_system::RawOrigin::try_from(id.clone()) {
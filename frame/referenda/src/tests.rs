@@ -368,6 +368,48 @@ fn tracks_are_distinguished() {
 	});
 }
 
+#[test]
+fn track_changes_only_apply_to_new_referenda() {
+	new_test_ext().execute_with(|| {
+		ChangedDecisionPeriod::set(Some(4));
+		assert_ok!(Referenda::submit(
+			RuntimeOrigin::signed(1),
+			Box::new(RawOrigin::Root.into()),
+			set_balance_proposal_bounded(1),
+			DispatchTime::At(10),
+		));
+		assert_ok!(Referenda::place_decision_deposit(RuntimeOrigin::signed(2), 0));
+
+		ChangedDecisionPeriod::set(Some(8));
+		assert_ok!(Referenda::submit(
+			RuntimeOrigin::signed(1),
+			Box::new(RawOrigin::None.into()),
+			set_balance_proposal_bounded(2),
+			DispatchTime::At(20),
+		));
+		assert_ok!(Referenda::place_decision_deposit(RuntimeOrigin::signed(2), 1));
+		assert_eq!(ReferendumTrackInfo::<Test>::get(0).unwrap().decision_period, 4);
+		assert_eq!(ReferendumTrackInfo::<Test>::get(1).unwrap().decision_period, 8);
+
+		// The first referendum keeps to the decision period it was submitted with.
+		run_to(9);
+		assert_eq!(rejected_since(0), 9);
+		assert_eq!(ReferendumTrackInfo::<Test>::get(0), None);
+		assert_eq!(deciding_and_failing_since(1), 3);
+		run_to(11);
+		assert_eq!(rejected_since(1), 11);
+		assert_eq!(ReferendumTrackInfo::<Test>::get(1), None);
+	});
+}
+
+#[test]
+fn track_info_is_not_kept_for_static_tracks() {
+	new_test_ext().execute_with(|| {
+		let r = Passing.create();
+		assert_eq!(ReferendumTrackInfo::<Test>::get(r), None);
+	});
+}
+
 #[test]
 fn submit_errors_work() {
 	new_test_ext().execute_with(|| {
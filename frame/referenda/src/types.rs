@@ -26,7 +26,7 @@ use frame_support::{
 use scale_info::TypeInfo;
 use sp_arithmetic::{Rounding::*, SignedRounding::*};
 use sp_runtime::{FixedI64, PerThing, RuntimeDebug};
-use sp_std::fmt::Debug;
+use sp_std::{borrow::Cow, fmt::Debug};
 
 pub type BalanceOf<T, I = ()> =
 	<<T as Config<I>>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
@@ -113,10 +113,14 @@ pub struct Deposit<AccountId, Balance> {
 	pub amount: Balance,
 }
 
-#[derive(Clone, Encode, TypeInfo)]
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
 pub struct TrackInfo<Balance, Moment> {
 	/// Name of this track.
-	pub name: &'static str,
+	///
+	/// This used to be a `&'static str`. It encodes the same way; tracks known at compile time
+	/// should use `Cow::Borrowed("name")`.
+	pub name: Cow<'static, str>,
 	/// A limit for the number of referenda on this track that can be being decided at once.
 	/// For Root origin this should generally be just one.
 	pub max_deciding: u32,
@@ -139,22 +143,47 @@ pub struct TrackInfo<Balance, Moment> {
 }
 
 /// Information on the voting tracks.
-pub trait TracksInfo<Balance, Moment> {
+pub trait TracksInfo<Balance: Clone + 'static, Moment: Clone + 'static> {
 	/// The identifier for a track.
 	type Id: Copy + Parameter + Ord + PartialOrd + Send + Sync + 'static + MaxEncodedLen;
 
 	/// The origin type from which a track is implied.
 	type RuntimeOrigin;
 
-	/// Return the array of known tracks and their information.
-	fn tracks() -> &'static [(Self::Id, TrackInfo<Balance, Moment>)];
+	/// Return the array of known tracks and their information, sorted by `Id`.
+	///
+	/// This used to return a `&'static` slice. Tracks known at compile time are still returned
+	/// borrowed from a `static` array, wrapped in `Cow::Borrowed`.
+	fn tracks() -> Cow<'static, [(Self::Id, TrackInfo<Balance, Moment>)]>;
+
+	/// Return the ids of the known tracks, sorted, by default those of `Self::tracks()`.
+	fn track_ids() -> Vec<Self::Id> {
+		Self::tracks().iter().map(|x| x.0).collect()
+	}
+
+	/// The maximum number of tracks there may ever be, by default the number of `Self::tracks()`.
+	///
+	/// This must never decrease, even if tracks are removed.
+	fn max_tracks() -> u32 {
+		Self::tracks().len() as u32
+	}
 
 	/// Determine the voting track for the given `origin`.
 	fn track_for(origin: &Self::RuntimeOrigin) -> Result<Self::Id, ()>;
 
 	/// Return the track info for track `id`, by default this just looks it up in `Self::tracks()`.
-	fn info(id: Self::Id) -> Option<&'static TrackInfo<Balance, Moment>> {
-		Self::tracks().iter().find(|x| x.0 == id).map(|x| &x.1)
+	fn info(id: Self::Id) -> Option<Cow<'static, TrackInfo<Balance, Moment>>> {
+		match Self::tracks() {
+			Cow::Borrowed(tracks) => tracks.iter().find(|x| x.0 == id).map(|x| Cow::Borrowed(&x.1)),
+			Cow::Owned(tracks) => tracks.into_iter().find(|x| x.0 == id).map(|x| Cow::Owned(x.1)),
+		}
+	}
+
+	/// Whether the tracks may change while referenda are ongoing.
+	///
+	/// If so, each referendum keeps to the info its track had when the referendum was submitted.
+	fn tracks_may_change() -> bool {
+		false
 	}
 }
 
@@ -275,6 +304,7 @@ impl<
 /// by `(Perbill, Perbill)`.
 #[derive(Clone, Eq, PartialEq, Encode, Decode, TypeInfo, MaxEncodedLen)]
 #[cfg_attr(not(feature = "std"), derive(RuntimeDebug))]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
 pub enum Curve {
 	/// Linear curve starting at `(0, ceil)`, proceeding linearly to `(length, floor)`, then
 	/// remaining at `floor` until the end of the period.
@@ -494,6 +524,17 @@ impl Curve {
 	pub fn passing(&self, x: Perbill, y: Perbill) -> bool {
 		y >= self.threshold(x)
 	}
+
+	/// Return `true` iff the curve is well-formed, i.e. it is non-increasing and evaluating it
+	/// cannot fail.
+	pub fn is_sane(&self) -> bool {
+		match self {
+			Self::LinearDecreasing { length, floor, ceil } => !length.is_zero() && floor <= ceil,
+			Self::SteppedDecreasing { begin, end, period, .. } => !period.is_zero() && end <= begin,
+			Self::Reciprocal { factor, x_offset, .. } =>
+				factor.into_inner() > 0 && x_offset.into_inner() > 0,
+		}
+	}
 }
 
 #[cfg(feature = "std")]
@@ -627,6 +668,54 @@ mod tests {
 		}
 	}
 
+	#[test]
+	fn curve_sanity_works() {
+		fn pc(x: u32) -> Perbill {
+			Perbill::from_percent(x)
+		}
+
+		assert!(TIP_APP.is_sane());
+		assert!(TIP_SUP.is_sane());
+		assert!(ROOT_APP.is_sane());
+		assert!(WHITE_SUP.is_sane());
+		assert!(Curve::SteppedDecreasing {
+			begin: pc(80),
+			end: pc(30),
+			step: pc(10),
+			period: pc(15)
+		}
+		.is_sane());
+
+		assert!(!Curve::LinearDecreasing { length: pc(0), floor: pc(50), ceil: pc(100) }.is_sane());
+		assert!(!Curve::LinearDecreasing { length: pc(10), floor: pc(60), ceil: pc(50) }.is_sane());
+		assert!(!Curve::SteppedDecreasing {
+			begin: pc(80),
+			end: pc(30),
+			step: pc(10),
+			period: pc(0)
+		}
+		.is_sane());
+		assert!(!Curve::SteppedDecreasing {
+			begin: pc(30),
+			end: pc(80),
+			step: pc(10),
+			period: pc(15)
+		}
+		.is_sane());
+		assert!(!Curve::Reciprocal {
+			factor: FixedI64::from_float(0.03125),
+			x_offset: FixedI64::from_u32(0),
+			y_offset: FixedI64::from_float(0.139845532427),
+		}
+		.is_sane());
+		assert!(!Curve::Reciprocal {
+			factor: FixedI64::from_float(-0.03125),
+			x_offset: FixedI64::from_float(0.0363306838226),
+			y_offset: FixedI64::from_float(0.139845532427),
+		}
+		.is_sane());
+	}
+
 	#[test]
 	fn stepped_decreasing_works() {
 		fn pc(x: u32) -> Perbill {
@@ -303,5 +303,78 @@ benchmarks! {
 		);
 	}
 
+	schedule_retry {
+		let s in 1 .. T::MaxScheduledPerBlock::get();
+		let when: T::BlockNumber = BLOCK_NUMBER.into();
+		let period: T::BlockNumber = One::one();
+		let task = make_task::<T>(false, false, false, None, 0);
+		let retry_config = RetryConfig { total_retries: 10, remaining: 10, period };
+		// the retry lands in the last free slot of the agenda.
+		fill_schedule::<T>(when + period, s - 1)?;
+		let mut counter = WeightMeter::max_limit();
+	}: {
+		Scheduler::<T>::schedule_retry(&mut counter, when, when, 0, &task, retry_config);
+	} verify {
+		ensure!(
+			Retries::<T>::get((when + period, s - 1)) ==
+				Some(RetryConfig { total_retries: 10, remaining: 9, period }),
+			"didn't schedule the retry"
+		);
+	}
+
+	set_retry {
+		let s = T::MaxScheduledPerBlock::get();
+		let when = BLOCK_NUMBER.into();
+		let period = One::one();
+
+		fill_schedule::<T>(when, s)?;
+	}: _(RawOrigin::Root, (when, 0), 10, period)
+	verify {
+		ensure!(
+			Retries::<T>::get((when, 0)) ==
+				Some(RetryConfig { total_retries: 10, remaining: 10, period }),
+			"didn't set the retry configuration"
+		);
+	}
+
+	set_retry_named {
+		let s = T::MaxScheduledPerBlock::get();
+		let when = BLOCK_NUMBER.into();
+		let period = One::one();
+
+		fill_schedule::<T>(when, s)?;
+	}: _(RawOrigin::Root, u32_to_name(0), 10, period)
+	verify {
+		ensure!(
+			Retries::<T>::get((when, 0)) ==
+				Some(RetryConfig { total_retries: 10, remaining: 10, period }),
+			"didn't set the retry configuration"
+		);
+	}
+
+	cancel_retry {
+		let s = T::MaxScheduledPerBlock::get();
+		let when = BLOCK_NUMBER.into();
+		let root: <T as Config>::PalletsOrigin = frame_system::RawOrigin::Root.into();
+
+		fill_schedule::<T>(when, s)?;
+		Scheduler::<T>::do_set_retry(&root, (when, 0), 10, One::one())?;
+	}: _(RawOrigin::Root, (when, 0))
+	verify {
+		ensure!(Retries::<T>::get((when, 0)).is_none(), "didn't cancel the retry configuration");
+	}
+
+	cancel_retry_named {
+		let s = T::MaxScheduledPerBlock::get();
+		let when = BLOCK_NUMBER.into();
+		let root: <T as Config>::PalletsOrigin = frame_system::RawOrigin::Root.into();
+
+		fill_schedule::<T>(when, s)?;
+		Scheduler::<T>::do_set_retry(&root, (when, 0), 10, One::one())?;
+	}: _(RawOrigin::Root, u32_to_name(0))
+	verify {
+		ensure!(Retries::<T>::get((when, 0)).is_none(), "didn't cancel the retry configuration");
+	}
+
 	impl_benchmark_test_suite!(Scheduler, crate::mock::new_test_ext(), crate::mock::Test);
 }
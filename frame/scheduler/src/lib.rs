@@ -46,6 +46,11 @@
 //! * `schedule_named` - augments the `schedule` interface with an additional `Vec<u8>` parameter
 //!   that can be used for identification.
 //! * `cancel_named` - the named complement to the cancel function.
+//! * `set_retry` - set a retry configuration for a scheduled task, so that it is scheduled again
+//!   after a period of blocks if its dispatch fails.
+//! * `set_retry_named` - the named complement to the `set_retry` function.
+//! * `cancel_retry` - remove the retry configuration of a scheduled task.
+//! * `cancel_retry_named` - the named complement to the `cancel_retry` function.
 
 // Ensure we're `no_std` when compiling for Wasm.
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -118,6 +123,40 @@ pub struct Scheduled<Name, Call, BlockNumber, PalletsOrigin, AccountId> {
 	_phantom: PhantomData<AccountId>,
 }
 
+impl<Name, Call, BlockNumber, PalletsOrigin, AccountId>
+	Scheduled<Name, Call, BlockNumber, PalletsOrigin, AccountId>
+where
+	Name: Copy,
+	Call: Clone,
+	PalletsOrigin: Clone,
+{
+	/// Create a new task to be used for retry attempts of the original one.
+	///
+	/// The retry is never periodic. It keeps the name of the original task unless that one is
+	/// periodic, in which case the name stays with the next run of the original task.
+	pub fn as_retry(&self) -> Self {
+		Self {
+			maybe_id: if self.maybe_periodic.is_some() { None } else { self.maybe_id },
+			priority: self.priority,
+			call: self.call.clone(),
+			maybe_periodic: None,
+			origin: self.origin.clone(),
+			_phantom: Default::default(),
+		}
+	}
+}
+
+/// The retry configuration of a task, along with its current state.
+#[derive(Clone, Copy, RuntimeDebug, PartialEq, Eq, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub struct RetryConfig<Period> {
+	/// The number of retries the task was initially allowed.
+	pub total_retries: u8,
+	/// The number of retries left.
+	pub remaining: u8,
+	/// The number of blocks between two attempts.
+	pub period: Period,
+}
+
 use crate::{Scheduled as ScheduledV3, Scheduled as ScheduledV2};
 
 pub type ScheduledV2Of<T> = ScheduledV2<
@@ -248,6 +287,16 @@ pub mod pallet {
 	pub(crate) type Lookup<T: Config> =
 		StorageMap<_, Twox64Concat, TaskName, TaskAddress<T::BlockNumber>>;
 
+	/// Retry configurations of the scheduled tasks, indexed by the address of the task.
+	#[pallet::storage]
+	pub type Retries<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		TaskAddress<T::BlockNumber>,
+		RetryConfig<T::BlockNumber>,
+		OptionQuery,
+	>;
+
 	/// Events type.
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
@@ -268,6 +317,27 @@ pub mod pallet {
 		PeriodicFailed { task: TaskAddress<T::BlockNumber>, id: Option<TaskName> },
 		/// The given task can never be executed since it is overweight.
 		PermanentlyOverweight { task: TaskAddress<T::BlockNumber>, id: Option<TaskName> },
+		/// Set a retry configuration for some task.
+		RetrySet {
+			task: TaskAddress<T::BlockNumber>,
+			id: Option<TaskName>,
+			period: T::BlockNumber,
+			retries: u8,
+		},
+		/// Cancelled the retry configuration of some task.
+		RetryCancelled { task: TaskAddress<T::BlockNumber>, id: Option<TaskName> },
+		/// The given task failed and has been scheduled again at `retry`.
+		RetryScheduled {
+			task: TaskAddress<T::BlockNumber>,
+			id: Option<TaskName>,
+			retry: TaskAddress<T::BlockNumber>,
+			remaining: u8,
+		},
+		/// The given task failed and could not be retried since the agenda is full at that block
+		/// or there was not enough weight left to reschedule it.
+		RetryFailed { task: TaskAddress<T::BlockNumber>, id: Option<TaskName> },
+		/// The given task failed and has no retries left.
+		RetriesExhausted { task: TaskAddress<T::BlockNumber>, id: Option<TaskName> },
 	}
 
 	#[pallet::error]
@@ -407,6 +477,62 @@ pub mod pallet {
 			)?;
 			Ok(())
 		}
+
+		/// Set a retry configuration for a task, so that it is scheduled again `period` blocks
+		/// after each failed dispatch, for at most `retries` times or until it succeeds.
+		///
+		/// Retries are subject to weight metering and agenda space just like any other task.
+		/// The retry of a periodic task is an unnamed, non-periodic clone of it, while the task
+		/// itself keeps running on its period.
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_retry())]
+		pub fn set_retry(
+			origin: OriginFor<T>,
+			task: TaskAddress<T::BlockNumber>,
+			retries: u8,
+			period: T::BlockNumber,
+		) -> DispatchResult {
+			T::ScheduleOrigin::ensure_origin(origin.clone())?;
+			let origin = <T as Config>::RuntimeOrigin::from(origin);
+			Self::do_set_retry(origin.caller(), task, retries, period)
+		}
+
+		/// Set a retry configuration for a named task. See [`Pallet::set_retry`].
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_retry_named())]
+		pub fn set_retry_named(
+			origin: OriginFor<T>,
+			id: TaskName,
+			retries: u8,
+			period: T::BlockNumber,
+		) -> DispatchResult {
+			T::ScheduleOrigin::ensure_origin(origin.clone())?;
+			let origin = <T as Config>::RuntimeOrigin::from(origin);
+			let task = Lookup::<T>::get(id).ok_or(Error::<T>::NotFound)?;
+			Self::do_set_retry(origin.caller(), task, retries, period)
+		}
+
+		/// Remove the retry configuration of a task.
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_retry())]
+		pub fn cancel_retry(
+			origin: OriginFor<T>,
+			task: TaskAddress<T::BlockNumber>,
+		) -> DispatchResult {
+			T::ScheduleOrigin::ensure_origin(origin.clone())?;
+			let origin = <T as Config>::RuntimeOrigin::from(origin);
+			Self::do_cancel_retry(origin.caller(), task)
+		}
+
+		/// Remove the retry configuration of a named task.
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_retry_named())]
+		pub fn cancel_retry_named(origin: OriginFor<T>, id: TaskName) -> DispatchResult {
+			T::ScheduleOrigin::ensure_origin(origin.clone())?;
+			let origin = <T as Config>::RuntimeOrigin::from(origin);
+			let task = Lookup::<T>::get(id).ok_or(Error::<T>::NotFound)?;
+			Self::do_cancel_retry(origin.caller(), task)
+		}
 	}
 }
 
@@ -819,6 +945,7 @@ impl<T: Config> Pallet<T> {
 			if let Some(id) = s.maybe_id {
 				Lookup::<T>::remove(id);
 			}
+			Retries::<T>::remove((when, index));
 			Self::cleanup_agenda(when);
 			Self::deposit_event(Event::Canceled { when, index });
 			Ok(())
@@ -845,7 +972,9 @@ impl<T: Config> Pallet<T> {
 		Self::cleanup_agenda(when);
 		Self::deposit_event(Event::Canceled { when, index });
 
-		Self::place_task(new_time, task).map_err(|x| x.0)
+		let new_address = Self::place_task(new_time, task).map_err(|x| x.0)?;
+		Self::move_retry_config((when, index), new_address);
+		Ok(new_address)
 	}
 
 	fn do_schedule_named(
@@ -908,6 +1037,7 @@ impl<T: Config> Pallet<T> {
 					}
 					Ok(())
 				})?;
+				Retries::<T>::remove((when, index));
 				Self::cleanup_agenda(when);
 				Self::deposit_event(Event::Canceled { when, index });
 				Ok(())
@@ -936,7 +1066,63 @@ impl<T: Config> Pallet<T> {
 		})?;
 		Self::cleanup_agenda(when);
 		Self::deposit_event(Event::Canceled { when, index });
-		Self::place_task(new_time, task).map_err(|x| x.0)
+		let new_address = Self::place_task(new_time, task).map_err(|x| x.0)?;
+		Self::move_retry_config((when, index), new_address);
+		Ok(new_address)
+	}
+
+	/// Keep the retry configuration of a task which has been moved from `old` to `new`.
+	fn move_retry_config(old: TaskAddress<T::BlockNumber>, new: TaskAddress<T::BlockNumber>) {
+		if let Some(retry_config) = Retries::<T>::take(old) {
+			Retries::<T>::insert(new, retry_config);
+		}
+	}
+
+	/// Ensure that `origin` has at least the privileges of the origin of the task at `address`,
+	/// returning the name of the task.
+	fn ensure_task_privilege(
+		origin: &T::PalletsOrigin,
+		(when, index): TaskAddress<T::BlockNumber>,
+	) -> Result<Option<TaskName>, DispatchError> {
+		let agenda = Agenda::<T>::get(when);
+		let task = agenda
+			.get(index as usize)
+			.and_then(Option::as_ref)
+			.ok_or(Error::<T>::NotFound)?;
+		if matches!(
+			T::OriginPrivilegeCmp::cmp_privilege(origin, &task.origin),
+			Some(Ordering::Less) | None
+		) {
+			return Err(BadOrigin.into())
+		}
+		Ok(task.maybe_id)
+	}
+
+	fn do_set_retry(
+		origin: &T::PalletsOrigin,
+		task: TaskAddress<T::BlockNumber>,
+		retries: u8,
+		period: T::BlockNumber,
+	) -> DispatchResult {
+		let id = Self::ensure_task_privilege(origin, task)?;
+		// A retry can never be placed in the agenda which is being serviced.
+		let period = period.max(One::one());
+		Retries::<T>::insert(
+			task,
+			RetryConfig { total_retries: retries, remaining: retries, period },
+		);
+		Self::deposit_event(Event::RetrySet { task, id, period, retries });
+		Ok(())
+	}
+
+	fn do_cancel_retry(
+		origin: &T::PalletsOrigin,
+		task: TaskAddress<T::BlockNumber>,
+	) -> DispatchResult {
+		let id = Self::ensure_task_privilege(origin, task)?;
+		Retries::<T>::take(task).ok_or(Error::<T>::NotFound)?;
+		Self::deposit_event(Event::RetryCancelled { task, id });
+		Ok(())
 	}
 }
 
@@ -1047,6 +1233,7 @@ impl<T: Config> Pallet<T> {
 	/// - removing and potentially replacing the `Lookup` entry for the task.
 	/// - realizing the task's call which can include a preimage lookup.
 	/// - Rescheduling the task for execution in a later agenda if periodic.
+	/// - Scheduling a retry of the task if it failed and has a retry configuration.
 	fn service_task(
 		weight: &mut WeightMeter,
 		now: T::BlockNumber,
@@ -1080,11 +1267,14 @@ impl<T: Config> Pallet<T> {
 				Err((Unavailable, Some(task)))
 			},
 			Err(Overweight) if is_first => {
-				T::Preimages::drop(&task.call);
 				Self::deposit_event(Event::PermanentlyOverweight {
 					task: (when, agenda_index),
 					id: task.maybe_id,
 				});
+				if let Some(retry_config) = Retries::<T>::take((when, agenda_index)) {
+					Self::schedule_retry(weight, now, when, agenda_index, &task, retry_config);
+				}
+				T::Preimages::drop(&task.call);
 				Err((Unavailable, Some(task)))
 			},
 			Err(Overweight) => Err((Overweight, Some(task))),
@@ -1094,6 +1284,10 @@ impl<T: Config> Pallet<T> {
 					id: task.maybe_id,
 					result,
 				});
+				let maybe_retry_config = Retries::<T>::take((when, agenda_index));
+				if let (Some(retry_config), Err(_)) = (maybe_retry_config, result) {
+					Self::schedule_retry(weight, now, when, agenda_index, &task, retry_config);
+				}
 				if let &Some((period, count)) = &task.maybe_periodic {
 					if count > 1 {
 						task.maybe_periodic = Some((period, count - 1));
@@ -1102,7 +1296,10 @@ impl<T: Config> Pallet<T> {
 					}
 					let wake = now.saturating_add(period);
 					match Self::place_task(wake, task) {
-						Ok(_) => {},
+						Ok(new_address) =>
+							if let Some(retry_config) = maybe_retry_config {
+								Retries::<T>::insert(new_address, retry_config);
+							},
 						Err((_, task)) => {
 							// TODO: Leave task in storage somewhere for it to be rescheduled
 							// manually.
@@ -1121,6 +1318,58 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Schedule a retry of the failed `task`, which was serviced at `(when, agenda_index)`, if it
+	/// has any retries left and there is enough weight and agenda space to do so.
+	///
+	/// The retry holds its own reference to the call's preimage, so the caller remains
+	/// responsible for dropping the reference of `task`.
+	fn schedule_retry(
+		weight: &mut WeightMeter,
+		now: T::BlockNumber,
+		when: T::BlockNumber,
+		agenda_index: u32,
+		task: &ScheduledOf<T>,
+		retry_config: RetryConfig<T::BlockNumber>,
+	) {
+		let RetryConfig { total_retries, remaining, period } = retry_config;
+		let remaining = match remaining.checked_sub(1) {
+			Some(remaining) => remaining,
+			None => {
+				Self::deposit_event(Event::RetriesExhausted {
+					task: (when, agenda_index),
+					id: task.maybe_id,
+				});
+				return
+			},
+		};
+		if !weight.check_accrue(T::WeightInfo::schedule_retry(T::MaxScheduledPerBlock::get())) {
+			Self::deposit_event(Event::RetryFailed {
+				task: (when, agenda_index),
+				id: task.maybe_id,
+			});
+			return
+		}
+		let wake = now.saturating_add(period);
+		match Self::place_task(wake, task.as_retry()) {
+			Ok(retry) => {
+				if let Some(hash) = task.call.lookup_hash() {
+					T::Preimages::request(&hash);
+				}
+				Retries::<T>::insert(retry, RetryConfig { total_retries, remaining, period });
+				Self::deposit_event(Event::RetryScheduled {
+					task: (when, agenda_index),
+					id: task.maybe_id,
+					retry,
+					remaining,
+				});
+			},
+			Err(_) => Self::deposit_event(Event::RetryFailed {
+				task: (when, agenda_index),
+				id: task.maybe_id,
+			}),
+		}
+	}
+
 	/// Make a dispatch to the given `call` from the given `origin`, ensuring that the `weight`
 	/// counter does not exceed its limit and that it is counted accurately (e.g. accounted using
 	/// post info if available).
@@ -41,9 +41,12 @@ pub mod logger {
 	use super::{OriginCaller, OriginTrait};
 	use frame_support::{pallet_prelude::*, parameter_types};
 	use frame_system::pallet_prelude::*;
+	use sp_runtime::traits::UniqueSaturatedInto;
 
 	parameter_types! {
 		static Log: Vec<(OriginCaller, u32)> = Vec::new();
+		/// The inclusive range of blocks in which `timed_log` succeeds.
+		pub static Threshold: (u64, u64) = (0, u64::MAX);
 	}
 	pub fn log() -> Vec<(OriginCaller, u32)> {
 		Log::get().clone()
@@ -91,6 +94,21 @@ pub mod logger {
 			});
 			Ok(())
 		}
+
+		#[pallet::call_index(2)]
+		#[pallet::weight(*weight)]
+		pub fn timed_log(origin: OriginFor<T>, i: u32, weight: Weight) -> DispatchResult {
+			let now: u64 = frame_system::Pallet::<T>::block_number().unique_saturated_into();
+			let (start, end) = Threshold::get();
+			if now < start || now > end {
+				return Err(DispatchError::Other("not within the threshold"))
+			}
+			Self::deposit_event(Event::Logged(i, weight));
+			Log::mutate(|log| {
+				log.push((origin.caller().clone(), i));
+			});
+			Ok(())
+		}
 	}
 }
 
@@ -106,7 +124,8 @@ frame_support::construct_runtime!(
 		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
 		Logger: logger::{Pallet, Call, Event<T>},
 		Scheduler: scheduler::{Pallet, Call, Storage, Event<T>},
-		Preimage: pallet_preimage::{Pallet, Call, Storage, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Storage, Event<T>},
+		Preimage: pallet_preimage::{Pallet, Call, Storage, Event<T>, HoldReason},
 	}
 );
 
@@ -142,7 +161,7 @@ impl system::Config for Test {
 	type BlockHashCount = ConstU64<250>;
 	type Version = ();
 	type PalletInfo = PalletInfo;
-	type AccountData = ();
+	type AccountData = pallet_balances::AccountData<u64>;
 	type OnNewAccount = ();
 	type OnKilledAccount = ();
 	type SystemWeightInfo = ();
@@ -157,10 +176,27 @@ ord_parameter_types! {
 	pub const One: u64 = 1;
 }
 
+impl pallet_balances::Config for Test {
+	type Balance = u64;
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ConstU64<1>;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type HoldIdentifier = RuntimeHoldReason;
+	type FreezeIdentifier = ();
+	type MaxHolds = ConstU32<1>;
+	type MaxFreezes = ();
+}
+
 impl pallet_preimage::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = ();
-	type Currency = ();
+	type Currency = Balances;
+	type RuntimeHoldReason = RuntimeHoldReason;
 	type ManagerOrigin = EnsureRoot<u64>;
 	type BaseDeposit = ();
 	type ByteDeposit = ();
@@ -204,6 +240,21 @@ impl WeightInfo for TestWeightInfo {
 	fn cancel_named(_s: u32) -> Weight {
 		Weight::from_parts(50, 0)
 	}
+	fn schedule_retry(_s: u32) -> Weight {
+		Weight::from_parts(50, 0)
+	}
+	fn set_retry() -> Weight {
+		Weight::from_parts(50, 0)
+	}
+	fn set_retry_named() -> Weight {
+		Weight::from_parts(50, 0)
+	}
+	fn cancel_retry() -> Weight {
+		Weight::from_parts(50, 0)
+	}
+	fn cancel_retry_named() -> Weight {
+		Weight::from_parts(50, 0)
+	}
 }
 parameter_types! {
 	pub MaximumSchedulerWeight: Weight = Perbill::from_percent(80) *
@@ -1851,3 +1851,241 @@ fn reschedule_named_last_task_removes_agenda() {
 		assert!(Agenda::<Test>::get(when).len() == 0);
 	});
 }
+
+fn timed_log_call() -> Bounded<RuntimeCall> {
+	let call =
+		RuntimeCall::Logger(LoggerCall::timed_log { i: 42, weight: Weight::from_parts(10, 0) });
+	Preimage::bound(call).unwrap()
+}
+
+#[test]
+fn retry_scheduling_works() {
+	new_test_ext().execute_with(|| {
+		// the call fails until block 7.
+		logger::Threshold::set((7, 100));
+		assert_ok!(Scheduler::do_schedule(
+			DispatchTime::At(4),
+			None,
+			127,
+			root(),
+			timed_log_call()
+		));
+		assert_ok!(Scheduler::set_retry(RuntimeOrigin::root(), (4, 0), 10, 2));
+		assert_eq!(
+			Retries::<Test>::get((4, 0)),
+			Some(RetryConfig { total_retries: 10, remaining: 10, period: 2 })
+		);
+		run_to_block(4);
+		assert!(logger::log().is_empty());
+		// the failed task is scheduled again 2 blocks later.
+		assert!(Agenda::<Test>::get(4).is_empty());
+		assert!(Retries::<Test>::get((4, 0)).is_none());
+		assert_eq!(
+			Retries::<Test>::get((6, 0)),
+			Some(RetryConfig { total_retries: 10, remaining: 9, period: 2 })
+		);
+		assert_eq!(
+			System::events().last().unwrap().event,
+			crate::Event::RetryScheduled { task: (4, 0), id: None, retry: (6, 0), remaining: 9 }
+				.into()
+		);
+		run_to_block(6);
+		assert!(logger::log().is_empty());
+		assert_eq!(
+			Retries::<Test>::get((8, 0)),
+			Some(RetryConfig { total_retries: 10, remaining: 8, period: 2 })
+		);
+		// the retry succeeds and is not scheduled again.
+		run_to_block(8);
+		assert_eq!(logger::log(), vec![(root(), 42u32)]);
+		assert!(Retries::<Test>::iter().next().is_none());
+		run_to_block(100);
+		assert_eq!(logger::log(), vec![(root(), 42u32)]);
+		assert!(Agenda::<Test>::iter().next().is_none());
+	});
+}
+
+#[test]
+fn retries_are_exhausted() {
+	new_test_ext().execute_with(|| {
+		// the call always fails.
+		logger::Threshold::set((100, 100));
+		assert_ok!(Scheduler::do_schedule(
+			DispatchTime::At(4),
+			None,
+			127,
+			root(),
+			timed_log_call()
+		));
+		assert_ok!(Scheduler::set_retry(RuntimeOrigin::root(), (4, 0), 2, 3));
+		run_to_block(7);
+		assert_eq!(Retries::<Test>::get((10, 0)).map(|c| c.remaining), Some(0));
+		run_to_block(10);
+		assert_eq!(
+			System::events().last().unwrap().event,
+			crate::Event::RetriesExhausted { task: (10, 0), id: None }.into()
+		);
+		assert!(Retries::<Test>::iter().next().is_none());
+		assert!(Agenda::<Test>::iter().next().is_none());
+		run_to_block(100);
+		assert!(logger::log().is_empty());
+	});
+}
+
+#[test]
+fn named_retry_keeps_the_name() {
+	new_test_ext().execute_with(|| {
+		logger::Threshold::set((100, 100));
+		assert_ok!(Scheduler::do_schedule_named(
+			[1u8; 32],
+			DispatchTime::At(4),
+			None,
+			127,
+			root(),
+			timed_log_call()
+		));
+		assert_ok!(Scheduler::set_retry_named(RuntimeOrigin::root(), [1u8; 32], 10, 3));
+		run_to_block(4);
+		assert_eq!(Lookup::<Test>::get([1u8; 32]), Some((7, 0)));
+		assert!(Retries::<Test>::get((7, 0)).is_some());
+		// canceling the task by name also cancels its retries.
+		assert_ok!(Scheduler::cancel_named(RuntimeOrigin::root(), [1u8; 32]));
+		assert!(Retries::<Test>::iter().next().is_none());
+		run_to_block(100);
+		assert!(logger::log().is_empty());
+		assert!(Agenda::<Test>::iter().next().is_none());
+	});
+}
+
+#[test]
+fn periodic_task_is_retried_by_an_unnamed_clone() {
+	new_test_ext().execute_with(|| {
+		// the call fails at block 4 only.
+		logger::Threshold::set((5, 100));
+		// at #4, every 4 blocks, 3 times.
+		assert_ok!(Scheduler::do_schedule_named(
+			[1u8; 32],
+			DispatchTime::At(4),
+			Some((4, 3)),
+			127,
+			root(),
+			timed_log_call()
+		));
+		assert_ok!(Scheduler::set_retry_named(RuntimeOrigin::root(), [1u8; 32], 1, 1));
+		run_to_block(4);
+		assert!(logger::log().is_empty());
+		// the periodic task keeps its name and its full retry configuration.
+		assert_eq!(Lookup::<Test>::get([1u8; 32]), Some((8, 0)));
+		assert_eq!(
+			Retries::<Test>::get((8, 0)),
+			Some(RetryConfig { total_retries: 1, remaining: 1, period: 1 })
+		);
+		assert_eq!(
+			Retries::<Test>::get((5, 0)),
+			Some(RetryConfig { total_retries: 1, remaining: 0, period: 1 })
+		);
+		assert!(Agenda::<Test>::get(5)[0].as_ref().unwrap().maybe_id.is_none());
+		run_to_block(5);
+		assert_eq!(logger::log(), vec![(root(), 42u32)]);
+		run_to_block(12);
+		assert_eq!(logger::log(), vec![(root(), 42u32), (root(), 42u32), (root(), 42u32)]);
+		run_to_block(100);
+		assert_eq!(logger::log().len(), 3);
+		assert!(Retries::<Test>::iter().next().is_none());
+		assert!(Agenda::<Test>::iter().next().is_none());
+	});
+}
+
+#[test]
+fn retry_fails_when_agenda_is_full() {
+	new_test_ext().execute_with(|| {
+		logger::Threshold::set((100, 100));
+		assert_ok!(Scheduler::do_schedule(
+			DispatchTime::At(4),
+			None,
+			127,
+			root(),
+			timed_log_call()
+		));
+		assert_ok!(Scheduler::set_retry(RuntimeOrigin::root(), (4, 0), 10, 3));
+		let max: u32 = <Test as Config>::MaxScheduledPerBlock::get();
+		for i in 0..max {
+			let call =
+				RuntimeCall::Logger(LoggerCall::log { i, weight: Weight::from_parts(10, 0) });
+			assert_ok!(Scheduler::do_schedule(
+				DispatchTime::At(7),
+				None,
+				127,
+				root(),
+				Preimage::bound(call).unwrap()
+			));
+		}
+		run_to_block(4);
+		assert_eq!(
+			System::events().last().unwrap().event,
+			crate::Event::RetryFailed { task: (4, 0), id: None }.into()
+		);
+		assert!(Retries::<Test>::iter().next().is_none());
+		assert_eq!(Agenda::<Test>::get(7).len(), max as usize);
+	});
+}
+
+#[test]
+fn set_and_cancel_retry_check_origin_and_task() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		assert_ok!(Scheduler::do_schedule(
+			DispatchTime::At(4),
+			None,
+			127,
+			root(),
+			timed_log_call()
+		));
+		assert_noop!(
+			Scheduler::set_retry(RuntimeOrigin::root(), (4, 1), 10, 3),
+			Error::<Test>::NotFound
+		);
+		assert_noop!(
+			Scheduler::set_retry_named(RuntimeOrigin::root(), [1u8; 32], 10, 3),
+			Error::<Test>::NotFound
+		);
+		// a signed origin has no privilege over a root task.
+		assert_noop!(Scheduler::set_retry(RuntimeOrigin::signed(1), (4, 0), 10, 3), BadOrigin);
+		assert_noop!(Scheduler::set_retry(RuntimeOrigin::signed(2), (4, 0), 10, 3), BadOrigin);
+		assert_noop!(
+			Scheduler::cancel_retry(RuntimeOrigin::root(), (4, 0)),
+			Error::<Test>::NotFound
+		);
+
+		// a zero period is raised to a single block.
+		assert_ok!(Scheduler::set_retry(RuntimeOrigin::root(), (4, 0), 10, 0));
+		assert_eq!(
+			System::events().last().unwrap().event,
+			crate::Event::RetrySet { task: (4, 0), id: None, period: 1, retries: 10 }.into()
+		);
+		assert_noop!(Scheduler::cancel_retry(RuntimeOrigin::signed(1), (4, 0)), BadOrigin);
+		assert_ok!(Scheduler::cancel_retry(RuntimeOrigin::root(), (4, 0)));
+		assert_eq!(
+			System::events().last().unwrap().event,
+			crate::Event::RetryCancelled { task: (4, 0), id: None }.into()
+		);
+		assert!(Retries::<Test>::get((4, 0)).is_none());
+	});
+}
+
+#[test]
+fn retry_config_follows_the_task() {
+	new_test_ext().execute_with(|| {
+		let address =
+			Scheduler::do_schedule(DispatchTime::At(4), None, 127, root(), timed_log_call())
+				.unwrap();
+		assert_ok!(Scheduler::set_retry(RuntimeOrigin::root(), address, 10, 3));
+		// rescheduling moves the retry configuration along with the task.
+		let address = Scheduler::do_reschedule(address, DispatchTime::At(5)).unwrap();
+		assert!(Retries::<Test>::get((4, 0)).is_none());
+		assert_eq!(Retries::<Test>::get(address).map(|c| c.remaining), Some(10));
+		// canceling removes it.
+		assert_ok!(Scheduler::do_cancel(None, address));
+		assert!(Retries::<Test>::iter().next().is_none());
+	});
+}
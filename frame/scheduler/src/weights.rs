@@ -60,6 +60,11 @@ pub trait WeightInfo {
 	fn cancel(s: u32, ) -> Weight;
 	fn schedule_named(s: u32, ) -> Weight;
 	fn cancel_named(s: u32, ) -> Weight;
+	fn schedule_retry(s: u32, ) -> Weight;
+	fn set_retry() -> Weight;
+	fn set_retry_named() -> Weight;
+	fn cancel_retry() -> Weight;
+	fn cancel_retry_named() -> Weight;
 }
 
 /// Weights for pallet_scheduler using the Substrate node and recommended hardware.
@@ -90,46 +95,59 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: Scheduler Retries (r:1 w:1)
+	/// Proof: Scheduler Retries (max_values: None, max_size: Some(30), added: 2505, mode: MaxEncodedLen)
 	fn service_task_base() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
-		//  Estimated: `0`
+		//  Estimated: `2505`
 		// Minimum execution time: 5_192 nanoseconds.
-		Weight::from_parts(5_528_000, 0)
+		Weight::from_parts(5_528_000, 2505)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: Preimage PreimageFor (r:1 w:1)
 	/// Proof: Preimage PreimageFor (max_values: None, max_size: Some(4194344), added: 4196819, mode: Measured)
 	/// Storage: Preimage StatusFor (r:1 w:1)
 	/// Proof: Preimage StatusFor (max_values: None, max_size: Some(91), added: 2566, mode: MaxEncodedLen)
+	/// Storage: Scheduler Retries (r:1 w:1)
+	/// Proof: Scheduler Retries (max_values: None, max_size: Some(30), added: 2505, mode: MaxEncodedLen)
 	/// The range of component `s` is `[128, 4194304]`.
 	fn service_task_fetched(s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `211 + s * (1 ±0)`
-		//  Estimated: `5252 + s * (1 ±0)`
+		//  Estimated: `7757 + s * (1 ±0)`
 		// Minimum execution time: 17_284 nanoseconds.
-		Weight::from_parts(17_574_000, 5252)
+		Weight::from_parts(17_574_000, 7757)
 			// Standard Error: 0
 			.saturating_add(Weight::from_parts(1_126, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 			.saturating_add(Weight::from_parts(0, 1).saturating_mul(s.into()))
 	}
+	/// Storage: Scheduler Retries (r:1 w:1)
+	/// Proof: Scheduler Retries (max_values: None, max_size: Some(30), added: 2505, mode: MaxEncodedLen)
 	/// Storage: Scheduler Lookup (r:0 w:1)
 	/// Proof: Scheduler Lookup (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
 	fn service_task_named() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
-		//  Estimated: `0`
+		//  Estimated: `2505`
 		// Minimum execution time: 7_020 nanoseconds.
-		Weight::from_parts(7_262_000, 0)
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		Weight::from_parts(7_262_000, 2505)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+	/// Storage: Scheduler Retries (r:1 w:1)
+	/// Proof: Scheduler Retries (max_values: None, max_size: Some(30), added: 2505, mode: MaxEncodedLen)
 	fn service_task_periodic() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
-		//  Estimated: `0`
+		//  Estimated: `2505`
 		// Minimum execution time: 5_187 nanoseconds.
-		Weight::from_parts(5_368_000, 0)
+		Weight::from_parts(5_368_000, 2505)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	fn execute_dispatch_signed() -> Weight {
 		// Proof Size summary in bytes:
@@ -207,6 +225,78 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+	/// Storage: Scheduler Agenda (r:1 w:1)
+	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(107022), added: 109497, mode: MaxEncodedLen)
+	/// Storage: Scheduler Retries (r:0 w:1)
+	/// Proof: Scheduler Retries (max_values: None, max_size: Some(30), added: 2505, mode: MaxEncodedLen)
+	/// The range of component `s` is `[1, 512]`.
+	fn schedule_retry(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `112 + s * (177 ±0)`
+		//  Estimated: `109497`
+		// Minimum execution time: 12_386 nanoseconds.
+		Weight::from_parts(15_861_040, 109497)
+			// Standard Error: 641
+			.saturating_add(Weight::from_parts(284_913, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Scheduler Agenda (r:1 w:0)
+	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(107022), added: 109497, mode: MaxEncodedLen)
+	/// Storage: Scheduler Retries (r:0 w:1)
+	/// Proof: Scheduler Retries (max_values: None, max_size: Some(30), added: 2505, mode: MaxEncodedLen)
+	fn set_retry() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `90705`
+		//  Estimated: `109497`
+		// Minimum execution time: 99_810 nanoseconds.
+		Weight::from_parts(102_377_000, 109497)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Scheduler Lookup (r:1 w:0)
+	/// Proof: Scheduler Lookup (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Scheduler Agenda (r:1 w:0)
+	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(107022), added: 109497, mode: MaxEncodedLen)
+	/// Storage: Scheduler Retries (r:0 w:1)
+	/// Proof: Scheduler Retries (max_values: None, max_size: Some(30), added: 2505, mode: MaxEncodedLen)
+	fn set_retry_named() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `91747`
+		//  Estimated: `112020`
+		// Minimum execution time: 106_452 nanoseconds.
+		Weight::from_parts(109_141_000, 112020)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Scheduler Agenda (r:1 w:0)
+	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(107022), added: 109497, mode: MaxEncodedLen)
+	/// Storage: Scheduler Retries (r:1 w:1)
+	/// Proof: Scheduler Retries (max_values: None, max_size: Some(30), added: 2505, mode: MaxEncodedLen)
+	fn cancel_retry() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `90717`
+		//  Estimated: `109497`
+		// Minimum execution time: 101_245 nanoseconds.
+		Weight::from_parts(103_816_000, 109497)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Scheduler Lookup (r:1 w:0)
+	/// Proof: Scheduler Lookup (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Scheduler Agenda (r:1 w:0)
+	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(107022), added: 109497, mode: MaxEncodedLen)
+	/// Storage: Scheduler Retries (r:1 w:1)
+	/// Proof: Scheduler Retries (max_values: None, max_size: Some(30), added: 2505, mode: MaxEncodedLen)
+	fn cancel_retry_named() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `91759`
+		//  Estimated: `112020`
+		// Minimum execution time: 107_903 nanoseconds.
+		Weight::from_parts(110_470_000, 112020)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -236,46 +326,59 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: Scheduler Retries (r:1 w:1)
+	/// Proof: Scheduler Retries (max_values: None, max_size: Some(30), added: 2505, mode: MaxEncodedLen)
 	fn service_task_base() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
-		//  Estimated: `0`
+		//  Estimated: `2505`
 		// Minimum execution time: 5_192 nanoseconds.
-		Weight::from_parts(5_528_000, 0)
+		Weight::from_parts(5_528_000, 2505)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: Preimage PreimageFor (r:1 w:1)
 	/// Proof: Preimage PreimageFor (max_values: None, max_size: Some(4194344), added: 4196819, mode: Measured)
 	/// Storage: Preimage StatusFor (r:1 w:1)
 	/// Proof: Preimage StatusFor (max_values: None, max_size: Some(91), added: 2566, mode: MaxEncodedLen)
+	/// Storage: Scheduler Retries (r:1 w:1)
+	/// Proof: Scheduler Retries (max_values: None, max_size: Some(30), added: 2505, mode: MaxEncodedLen)
 	/// The range of component `s` is `[128, 4194304]`.
 	fn service_task_fetched(s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `211 + s * (1 ±0)`
-		//  Estimated: `5252 + s * (1 ±0)`
+		//  Estimated: `7757 + s * (1 ±0)`
 		// Minimum execution time: 17_284 nanoseconds.
-		Weight::from_parts(17_574_000, 5252)
+		Weight::from_parts(17_574_000, 7757)
 			// Standard Error: 0
 			.saturating_add(Weight::from_parts(1_126, 0).saturating_mul(s.into()))
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 			.saturating_add(Weight::from_parts(0, 1).saturating_mul(s.into()))
 	}
+	/// Storage: Scheduler Retries (r:1 w:1)
+	/// Proof: Scheduler Retries (max_values: None, max_size: Some(30), added: 2505, mode: MaxEncodedLen)
 	/// Storage: Scheduler Lookup (r:0 w:1)
 	/// Proof: Scheduler Lookup (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
 	fn service_task_named() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
-		//  Estimated: `0`
+		//  Estimated: `2505`
 		// Minimum execution time: 7_020 nanoseconds.
-		Weight::from_parts(7_262_000, 0)
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		Weight::from_parts(7_262_000, 2505)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
+	/// Storage: Scheduler Retries (r:1 w:1)
+	/// Proof: Scheduler Retries (max_values: None, max_size: Some(30), added: 2505, mode: MaxEncodedLen)
 	fn service_task_periodic() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
-		//  Estimated: `0`
+		//  Estimated: `2505`
 		// Minimum execution time: 5_187 nanoseconds.
-		Weight::from_parts(5_368_000, 0)
+		Weight::from_parts(5_368_000, 2505)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	fn execute_dispatch_signed() -> Weight {
 		// Proof Size summary in bytes:
@@ -353,4 +456,76 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
+	/// Storage: Scheduler Agenda (r:1 w:1)
+	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(107022), added: 109497, mode: MaxEncodedLen)
+	/// Storage: Scheduler Retries (r:0 w:1)
+	/// Proof: Scheduler Retries (max_values: None, max_size: Some(30), added: 2505, mode: MaxEncodedLen)
+	/// The range of component `s` is `[1, 512]`.
+	fn schedule_retry(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `112 + s * (177 ±0)`
+		//  Estimated: `109497`
+		// Minimum execution time: 12_386 nanoseconds.
+		Weight::from_parts(15_861_040, 109497)
+			// Standard Error: 641
+			.saturating_add(Weight::from_parts(284_913, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: Scheduler Agenda (r:1 w:0)
+	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(107022), added: 109497, mode: MaxEncodedLen)
+	/// Storage: Scheduler Retries (r:0 w:1)
+	/// Proof: Scheduler Retries (max_values: None, max_size: Some(30), added: 2505, mode: MaxEncodedLen)
+	fn set_retry() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `90705`
+		//  Estimated: `109497`
+		// Minimum execution time: 99_810 nanoseconds.
+		Weight::from_parts(102_377_000, 109497)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Scheduler Lookup (r:1 w:0)
+	/// Proof: Scheduler Lookup (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Scheduler Agenda (r:1 w:0)
+	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(107022), added: 109497, mode: MaxEncodedLen)
+	/// Storage: Scheduler Retries (r:0 w:1)
+	/// Proof: Scheduler Retries (max_values: None, max_size: Some(30), added: 2505, mode: MaxEncodedLen)
+	fn set_retry_named() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `91747`
+		//  Estimated: `112020`
+		// Minimum execution time: 106_452 nanoseconds.
+		Weight::from_parts(109_141_000, 112020)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Scheduler Agenda (r:1 w:0)
+	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(107022), added: 109497, mode: MaxEncodedLen)
+	/// Storage: Scheduler Retries (r:1 w:1)
+	/// Proof: Scheduler Retries (max_values: None, max_size: Some(30), added: 2505, mode: MaxEncodedLen)
+	fn cancel_retry() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `90717`
+		//  Estimated: `109497`
+		// Minimum execution time: 101_245 nanoseconds.
+		Weight::from_parts(103_816_000, 109497)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Scheduler Lookup (r:1 w:0)
+	/// Proof: Scheduler Lookup (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Scheduler Agenda (r:1 w:0)
+	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(107022), added: 109497, mode: MaxEncodedLen)
+	/// Storage: Scheduler Retries (r:1 w:1)
+	/// Proof: Scheduler Retries (max_values: None, max_size: Some(30), added: 2505, mode: MaxEncodedLen)
+	fn cancel_retry_named() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `91759`
+		//  Estimated: `112020`
+		// Minimum execution time: 107_903 nanoseconds.
+		Weight::from_parts(110_470_000, 112020)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
@@ -0,0 +1,106 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License
+use crate::construct_runtime::Pallet;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use std::str::FromStr;
+use syn::spanned::Spanned;
+
+/// Expand the outer composite enum `Runtime<composite_name>` aggregating the composite enum
+/// `<composite_name>` of every pallet declaring it, e.g. `RuntimeHoldReason` for `HoldReason`.
+///
+/// The outer enum is always declared, even when no pallet declares the composite enum.
+pub fn expand_outer_composite(
+	pallet_decls: &[Pallet],
+	scrate: &TokenStream,
+	composite_name: &str,
+) -> syn::Result<TokenStream> {
+	let outer_name = format_ident!("Runtime{}", composite_name);
+	let composite_ident = format_ident!("{}", composite_name);
+	let mut variants = TokenStream::new();
+	let mut conversions = TokenStream::new();
+
+	for pallet_decl in pallet_decls {
+		if let Some(pallet_entry) = pallet_decl.find_part(composite_name) {
+			let path = &pallet_decl.path;
+			let variant_name = &pallet_decl.name;
+			let index = pallet_decl.index;
+
+			if pallet_decl.instance.is_some() {
+				let msg = format!(
+					"Instantiable pallet cannot declare `{}`: pallet `{}` must not use \
+					 `#[pallet::composite_enum]`",
+					composite_name, variant_name,
+				);
+				return Err(syn::Error::new(pallet_entry.keyword.span(), msg))
+			}
+
+			let attr = pallet_decl.cfg_pattern.iter().fold(TokenStream::new(), |acc, pattern| {
+				let attr = TokenStream::from_str(&format!("#[cfg({})]", pattern.original()))
+					.expect("was successfully parsed before; qed");
+				quote! {
+					#acc
+					#attr
+				}
+			});
+
+			variants.extend(quote! {
+				#attr
+				#[codec(index = #index)]
+				#variant_name(#path::#composite_ident),
+			});
+			conversions.extend(quote! {
+				#attr
+				impl From<#path::#composite_ident> for #outer_name {
+					fn from(x: #path::#composite_ident) -> Self {
+						#outer_name::#variant_name(x)
+					}
+				}
+				#attr
+				impl TryInto<#path::#composite_ident> for #outer_name {
+					type Error = ();
+
+					fn try_into(
+						self,
+					) -> #scrate::sp_std::result::Result<#path::#composite_ident, Self::Error> {
+						match self {
+							Self::#variant_name(composite) => Ok(composite),
+							_ => Err(()),
+						}
+					}
+				}
+			});
+		}
+	}
+
+	Ok(quote! {
+		#[derive(
+			Copy, Clone, Eq, PartialEq, Ord, PartialOrd,
+			#scrate::codec::Encode,
+			#scrate::codec::Decode,
+			#scrate::codec::MaxEncodedLen,
+			#scrate::scale_info::TypeInfo,
+			#scrate::RuntimeDebug,
+		)]
+		#[allow(non_camel_case_types)]
+		pub enum #outer_name {
+			#variants
+		}
+
+		#conversions
+	})
+}
@@ -16,6 +16,7 @@
 // limitations under the License
 
 mod call;
+mod composite;
 mod config;
 mod event;
 mod inherent;
@@ -24,6 +25,7 @@ mod origin;
 mod unsigned;
 
 pub use call::expand_outer_dispatch;
+pub use composite::expand_outer_composite;
 pub use config::expand_outer_config;
 pub use event::expand_outer_event;
 pub use inherent::expand_outer_inherent;
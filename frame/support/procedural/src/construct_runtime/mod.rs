@@ -256,6 +256,8 @@ fn construct_runtime_final_expansion(
 	let outer_event = expand::expand_outer_event(&name, &pallets, &scrate)?;
 
 	let outer_origin = expand::expand_outer_origin(&name, system_pallet, &pallets, &scrate)?;
+	let outer_freeze_reason = expand::expand_outer_composite(&pallets, &scrate, "FreezeReason")?;
+	let outer_hold_reason = expand::expand_outer_composite(&pallets, &scrate, "HoldReason")?;
 	let all_pallets = decl_all_pallets(&name, pallets.iter(), &features);
 	let pallet_to_index = decl_pallet_runtime_setup(&name, &pallets, &scrate);
 
@@ -293,6 +295,10 @@ fn construct_runtime_final_expansion(
 
 		#outer_origin
 
+		#outer_freeze_reason
+
+		#outer_hold_reason
+
 		#all_pallets
 
 		#pallet_to_index
@@ -363,22 +363,25 @@ pub fn decl_storage(input: TokenStream) -> TokenStream {
 ///     storage with `GenesisConfig`
 ///   - `Inherent` - If the pallet provides/can check inherents.
 ///   - `ValidateUnsigned` - If the pallet validates unsigned extrinsics.
+///   - `HoldReason` or `FreezeReason` - If the pallet declares the composite enum with
+///     `#[pallet::composite_enum]`. These are aggregated into the outer `RuntimeHoldReason` and
+///     `RuntimeFreezeReason` enums, which are always declared by `construct_runtime`.
 ///
 ///   It is important to list these parts here to export them correctly in the metadata or to make
 /// the pallet usable in the runtime.
 ///
 /// * `exclude_parts { Part1, Part2 }` optional: comma separated parts without generics. I.e. one of
-///   `Pallet`, `Call`, `Storage`, `Event`, `Origin`, `Config`, `Inherent`, `ValidateUnsigned`. It
-///   is incompatible with `use_parts`. This specifies the part to exclude. In order to select
-///   subset of the pallet parts.
+///   `Pallet`, `Call`, `Storage`, `Event`, `Origin`, `Config`, `Inherent`, `ValidateUnsigned`,
+///   `HoldReason`, `FreezeReason`. It is incompatible with `use_parts`. This specifies the part to
+///   exclude. In order to select subset of the pallet parts.
 ///
 ///   For example excluding the part `Call` can be useful if the runtime doesn't want to make the
 ///   pallet calls available.
 ///
 /// * `use_parts { Part1, Part2 }` optional: comma separated parts without generics. I.e. one of
-///   `Pallet`, `Call`, `Storage`, `Event`, `Origin`, `Config`, `Inherent`, `ValidateUnsigned`. It
-///   is incompatible with `exclude_parts`. This specifies the part to use. In order to select a
-///   subset of the pallet parts.
+///   `Pallet`, `Call`, `Storage`, `Event`, `Origin`, `Config`, `Inherent`, `ValidateUnsigned`,
+///   `HoldReason`, `FreezeReason`. It is incompatible with `exclude_parts`. This specifies the part
+///   to use. In order to select a subset of the pallet parts.
 ///
 ///   For example not using the part `Call` can be useful if the runtime doesn't want to make the
 ///   pallet calls available.
@@ -1359,3 +1362,35 @@ pub fn validate_unsigned(_: TokenStream, _: TokenStream) -> TokenStream {
 pub fn origin(_: TokenStream, _: TokenStream) -> TokenStream {
 	pallet_macro_stub()
 }
+
+/// The `#[pallet::composite_enum]` attribute allows you to define an enum that gets composed as
+/// an aggregate enum by `construct_runtime`. This is similar in principle with
+/// `#[pallet::event]` and `#[pallet::error]`.
+///
+/// The attribute currently only supports enum definitions named `HoldReason` and
+/// `FreezeReason`, which are aggregated into `RuntimeHoldReason` and `RuntimeFreezeReason`. The
+/// enum must be public, must not be generic and the pallet must not be instantiable.
+///
+/// E.g.:
+///
+/// ```ignore
+/// #[pallet::composite_enum]
+/// pub enum HoldReason {
+/// 	/// The funds are held as a deposit.
+/// 	Deposit,
+/// }
+/// ```
+///
+/// ## Macro expansion
+///
+/// The macro derives `Copy`, `Clone`, `Eq`, `PartialEq`, `Ord`, `PartialOrd`, `Encode`,
+/// `Decode`, `MaxEncodedLen`, `TypeInfo` and `RuntimeDebug` on the enum, and adds it as a part
+/// of the pallet for `construct_runtime`.
+///
+/// **WARNING**: the outer enum is stored on-chain by the holds and freezes of the pallets using
+/// it, thus any change to a composite enum must be done with care as it might require some
+/// migration.
+#[proc_macro_attribute]
+pub fn composite_enum(_: TokenStream, _: TokenStream) -> TokenStream {
+	pallet_macro_stub()
+}
@@ -20,6 +20,7 @@
 //! Parse the module into `Def` struct through `Def::try_from` function.
 
 pub mod call;
+pub mod composite;
 pub mod config;
 pub mod error;
 pub mod event;
@@ -57,6 +58,7 @@ pub struct Def {
 	pub validate_unsigned: Option<validate_unsigned::ValidateUnsignedDef>,
 	pub extra_constants: Option<extra_constants::ExtraConstantsDef>,
 	pub type_values: Vec<type_value::TypeValueDef>,
+	pub composites: Vec<composite::CompositeDef>,
 	pub frame_system: syn::Ident,
 	pub frame_support: syn::Ident,
 	pub dev_mode: bool,
@@ -91,6 +93,7 @@ impl Def {
 		let mut extra_constants = None;
 		let mut storages = vec![];
 		let mut type_values = vec![];
+		let mut composites: Vec<composite::CompositeDef> = vec![];
 
 		for (index, item) in items.iter_mut().enumerate() {
 			let pallet_attr: Option<PalletAttr> = helper::take_first_item_pallet_attr(item)?;
@@ -135,6 +138,20 @@ impl Def {
 				Some(PalletAttr::ExtraConstants(_)) =>
 					extra_constants =
 						Some(extra_constants::ExtraConstantsDef::try_from(index, item)?),
+				Some(PalletAttr::Composite(span)) => {
+					let composite =
+						composite::CompositeDef::try_from(span, index, &frame_support, item)?;
+					if composites.iter().any(|def| {
+						def.composite_keyword.to_string() == composite.composite_keyword.to_string()
+					}) {
+						let msg = format!(
+							"Invalid duplicated `{}` definition",
+							composite.composite_keyword
+						);
+						return Err(syn::Error::new(composite.composite_keyword.span(), msg))
+					}
+					composites.push(composite);
+				},
 				Some(attr) => {
 					let msg = "Invalid duplicated attribute";
 					return Err(syn::Error::new(attr.span(), msg))
@@ -172,6 +189,7 @@ impl Def {
 			inherent,
 			storages,
 			type_values,
+			composites,
 			frame_system,
 			frame_support,
 			dev_mode,
@@ -179,6 +197,7 @@ impl Def {
 
 		def.check_instance_usage()?;
 		def.check_event_usage()?;
+		def.check_composite_usage()?;
 
 		Ok(def)
 	}
@@ -203,6 +222,19 @@ impl Def {
 		}
 	}
 
+	/// Check that composite enums are only declared on non-instantiable pallets, as the outer
+	/// enum aggregated by `construct_runtime!` has a single variant type per pallet.
+	fn check_composite_usage(&self) -> syn::Result<()> {
+		match self.composites.first() {
+			Some(composite) if self.config.has_instance => {
+				let msg = "Invalid usage of `#[pallet::composite_enum]`, composite enums are not \
+					supported on instantiable pallets";
+				Err(syn::Error::new(composite.attr_span, msg))
+			},
+			_ => Ok(()),
+		}
+	}
+
 	/// Check that usage of trait `Config` is consistent with the definition, i.e. it is used with
 	/// instance iff it is defined with instance.
 	fn check_instance_usage(&self) -> syn::Result<()> {
@@ -385,6 +417,7 @@ mod keyword {
 	syn::custom_keyword!(generate_store);
 	syn::custom_keyword!(Store);
 	syn::custom_keyword!(extra_constants);
+	syn::custom_keyword!(composite_enum);
 }
 
 /// Parse attributes for item in pallet module
@@ -404,6 +437,7 @@ enum PalletAttr {
 	ValidateUnsigned(proc_macro2::Span),
 	TypeValue(proc_macro2::Span),
 	ExtraConstants(proc_macro2::Span),
+	Composite(proc_macro2::Span),
 }
 
 impl PalletAttr {
@@ -423,6 +457,7 @@ impl PalletAttr {
 			Self::ValidateUnsigned(span) => *span,
 			Self::TypeValue(span) => *span,
 			Self::ExtraConstants(span) => *span,
+			Self::Composite(span) => *span,
 		}
 	}
 }
@@ -464,6 +499,8 @@ impl syn::parse::Parse for PalletAttr {
 			Ok(PalletAttr::TypeValue(content.parse::<keyword::type_value>()?.span()))
 		} else if lookahead.peek(keyword::extra_constants) {
 			Ok(PalletAttr::ExtraConstants(content.parse::<keyword::extra_constants>()?.span()))
+		} else if lookahead.peek(keyword::composite_enum) {
+			Ok(PalletAttr::Composite(content.parse::<keyword::composite_enum>()?.span()))
 		} else {
 			Err(lookahead.error())
 		}
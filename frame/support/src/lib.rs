@@ -2271,6 +2271,28 @@ pub mod pallet_prelude {
 ///
 /// Also see [`pallet::origin`](`frame_support::pallet_macros::origin`)
 ///
+/// # Composite enums: `#[pallet::composite_enum]` (optional)
+///
+/// The `#[pallet::composite_enum]` attribute allows you to define an enum named `HoldReason`
+/// or `FreezeReason`, which `construct_runtime!` aggregates over all pallets into the outer
+/// `RuntimeHoldReason` or `RuntimeFreezeReason` enum. It must be public and not generic, and
+/// the pallet must not be instantiable.
+///
+/// E.g.:
+///
+/// ```ignore
+/// #[pallet::composite_enum]
+/// pub enum HoldReason {
+/// 	/// The funds are held as a deposit.
+/// 	Deposit,
+/// }
+/// ```
+///
+/// The pallet can then require `type RuntimeHoldReason: From<HoldReason>` in its config and
+/// use `HoldReason::Deposit.into()` as the reason of its holds.
+///
+/// Also see [`pallet::composite_enum`](`frame_support::pallet_macros::composite_enum`)
+///
 /// # General notes on instantiable pallets
 ///
 /// An instantiable pallet is one where Config is generic, i.e. `Config<I>`. This allows
@@ -2802,10 +2824,11 @@ pub use frame_support_procedural::pallet;
 /// Contains macro stubs for all of the pallet:: macros
 pub mod pallet_macros {
 	pub use frame_support_procedural::{
-		call_index, compact, config, constant, disable_frame_system_supertrait_check, error, event,
-		extra_constants, generate_deposit, generate_storage_info, generate_store, genesis_build,
-		genesis_config, getter, hooks, inherent, origin, storage, storage_prefix, storage_version,
-		type_value, unbounded, validate_unsigned, weight, whitelist_storage,
+		call_index, compact, composite_enum, config, constant,
+		disable_frame_system_supertrait_check, error, event, extra_constants, generate_deposit,
+		generate_storage_info, generate_store, genesis_build, genesis_config, getter, hooks,
+		inherent, origin, storage, storage_prefix, storage_version, type_value, unbounded,
+		validate_unsigned, weight, whitelist_storage,
 	};
 }
 
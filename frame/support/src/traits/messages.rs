@@ -70,6 +70,8 @@ pub enum ExecuteOverweightError {
 	NotFound,
 	/// The available weight was insufficient to execute the message.
 	InsufficientWeight,
+	/// The queue of the message is paused and cannot be serviced.
+	QueuePaused,
 }
 
 /// Can service queues and execute overweight messages.
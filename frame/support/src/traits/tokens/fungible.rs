@@ -25,6 +25,8 @@ use crate::{
 	dispatch::{DispatchError, DispatchResult},
 	traits::misc::Get,
 };
+use codec::Encode;
+use scale_info::TypeInfo;
 use sp_runtime::traits::Saturating;
 
 mod balanced;
@@ -135,40 +137,47 @@ pub trait Transfer<AccountId>: Inspect<AccountId> {
 	fn reactivate(_: Self::Balance) {}
 }
 
-/// Trait for inspecting a fungible asset which can be reserved.
+/// Trait for inspecting a fungible asset which can be placed on hold.
+///
+/// Funds are held for a `Reason`, so that funds held for one purpose cannot be released or slashed
+/// for another. The reasons are usually aggregated by the runtime over all of its pallets.
 pub trait InspectHold<AccountId>: Inspect<AccountId> {
-	/// Amount of funds held in reserve by `who`.
-	fn balance_on_hold(who: &AccountId) -> Self::Balance;
+	/// An identifier for the reason of a hold.
+	type Reason: Encode + TypeInfo + 'static;
 
-	/// Check to see if some `amount` of funds of `who` may be placed on hold.
-	fn can_hold(who: &AccountId, amount: Self::Balance) -> bool;
+	/// Amount of funds on hold of `who`, for all reasons.
+	fn total_balance_on_hold(who: &AccountId) -> Self::Balance;
+
+	/// Amount of funds on hold of `who` for the given `reason`.
+	fn balance_on_hold(reason: &Self::Reason, who: &AccountId) -> Self::Balance;
+
+	/// Check to see if some `amount` of funds of `who` may be placed on hold for `reason`.
+	fn can_hold(reason: &Self::Reason, who: &AccountId, amount: Self::Balance) -> bool;
 }
 
-// TODO: Introduce `HoldReason`.
-/// Trait for mutating a fungible asset which can be reserved.
+/// Trait for mutating a fungible asset which can be placed on hold.
 pub trait MutateHold<AccountId>: InspectHold<AccountId> + Transfer<AccountId> {
-	/// Hold some funds in an account.
-	fn hold(who: &AccountId, amount: Self::Balance) -> DispatchResult;
+	/// Hold some funds in an account for `reason`.
+	fn hold(reason: &Self::Reason, who: &AccountId, amount: Self::Balance) -> DispatchResult;
 
-	/// Release up to `amount` held funds in an account.
+	/// Release up to `amount` funds held for `reason` in an account.
 	///
 	/// The actual amount released is returned with `Ok`.
 	///
 	/// If `best_effort` is `true`, then the amount actually unreserved and returned as the inner
 	/// value of `Ok` may be smaller than the `amount` passed.
 	fn release(
+		reason: &Self::Reason,
 		who: &AccountId,
 		amount: Self::Balance,
 		best_effort: bool,
 	) -> Result<Self::Balance, DispatchError>;
 
-	// TODO: Introduce repatriate_held
-
-	/// Transfer held funds into a destination account.
+	/// Transfer funds held for `reason` into a destination account.
 	///
 	/// If `on_hold` is `true`, then the destination account must already exist and the assets
-	/// transferred will still be on hold in the destination account. If not, then the destination
-	/// account need not already exist, but must be creatable.
+	/// transferred will still be on hold for `reason` in the destination account. If not, then the
+	/// destination account need not already exist, but must be creatable.
 	///
 	/// If `best_effort` is `true`, then an amount less than `amount` may be transferred without
 	/// error.
@@ -176,23 +185,55 @@ pub trait MutateHold<AccountId>: InspectHold<AccountId> + Transfer<AccountId> {
 	/// The actual amount transferred is returned, or `Err` in the case of error and nothing is
 	/// changed.
 	fn transfer_held(
+		reason: &Self::Reason,
 		source: &AccountId,
 		dest: &AccountId,
 		amount: Self::Balance,
 		best_effort: bool,
-		on_held: bool,
+		on_hold: bool,
 	) -> Result<Self::Balance, DispatchError>;
 }
 
+/// Trait for inspecting a fungible asset which can be frozen.
+///
+/// A freeze sets a minimum below which the balance of an account may not drop for any reason.
+/// Freezes overlap: the largest one is what is in effect.
+pub trait InspectFreeze<AccountId>: Inspect<AccountId> {
+	/// An identifier for a freeze.
+	type Id: Encode + TypeInfo + 'static;
+
+	/// Amount of funds of `who` frozen by `id`.
+	fn balance_frozen(id: &Self::Id, who: &AccountId) -> Self::Balance;
+
+	/// Returns `true` if it's possible to introduce a freeze for `id` onto the account of `who`.
+	/// This will be `true` if it already has a freeze for `id` or if there is room for another.
+	fn can_freeze(id: &Self::Id, who: &AccountId) -> bool;
+}
+
+/// Trait for introducing, altering and removing freezes on a fungible asset.
+pub trait MutateFreeze<AccountId>: InspectFreeze<AccountId> {
+	/// Prevent actions which would reduce the balance of the account of `who` below `amount`,
+	/// replacing any existing freeze for `id`.
+	fn set_freeze(id: &Self::Id, who: &AccountId, amount: Self::Balance) -> DispatchResult;
+
+	/// Prevent actions which would reduce the balance of the account of `who` below `amount`.
+	/// If a freeze for `id` already exists, it is only increased, never reduced.
+	fn extend_freeze(id: &Self::Id, who: &AccountId, amount: Self::Balance) -> DispatchResult;
+
+	/// Remove the freeze for `id` from the account of `who`.
+	fn thaw(id: &Self::Id, who: &AccountId) -> DispatchResult;
+}
+
 /// Trait for slashing a fungible asset which can be reserved.
 pub trait BalancedHold<AccountId>: Balanced<AccountId> + MutateHold<AccountId> {
-	/// Reduce the balance of some funds on hold in an account.
+	/// Reduce the balance of some funds on hold for `reason` in an account.
 	///
 	/// The resulting imbalance is the first item of the tuple returned.
 	///
 	/// As much funds that are on hold up to `amount` will be deducted as possible. If this is less
 	/// than `amount`, then a non-zero second item will be returned.
 	fn slash_held(
+		reason: &Self::Reason,
 		who: &AccountId,
 		amount: Self::Balance,
 	) -> (CreditOf<AccountId, Self>, Self::Balance);
@@ -201,10 +242,11 @@ pub trait BalancedHold<AccountId>: Balanced<AccountId> + MutateHold<AccountId> {
 impl<AccountId, T: Balanced<AccountId> + MutateHold<AccountId>> BalancedHold<AccountId> for T {
 	// TODO: This should be implemented properly, and `slash` should be removed.
 	fn slash_held(
+		reason: &Self::Reason,
 		who: &AccountId,
 		amount: Self::Balance,
 	) -> (CreditOf<AccountId, Self>, Self::Balance) {
-		let actual = match Self::release(who, amount, true) {
+		let actual = match Self::release(reason, who, amount, true) {
 			Ok(x) => x,
 			Err(_) => return (Imbalance::default(), amount),
 		};
@@ -292,11 +334,16 @@ impl<
 		AccountId,
 	> InspectHold<AccountId> for ItemOf<F, A, AccountId>
 {
-	fn balance_on_hold(who: &AccountId) -> Self::Balance {
-		<F as fungibles::InspectHold<AccountId>>::balance_on_hold(A::get(), who)
+	type Reason = <F as fungibles::InspectHold<AccountId>>::Reason;
+
+	fn total_balance_on_hold(who: &AccountId) -> Self::Balance {
+		<F as fungibles::InspectHold<AccountId>>::total_balance_on_hold(A::get(), who)
+	}
+	fn balance_on_hold(reason: &Self::Reason, who: &AccountId) -> Self::Balance {
+		<F as fungibles::InspectHold<AccountId>>::balance_on_hold(A::get(), reason, who)
 	}
-	fn can_hold(who: &AccountId, amount: Self::Balance) -> bool {
-		<F as fungibles::InspectHold<AccountId>>::can_hold(A::get(), who, amount)
+	fn can_hold(reason: &Self::Reason, who: &AccountId, amount: Self::Balance) -> bool {
+		<F as fungibles::InspectHold<AccountId>>::can_hold(A::get(), reason, who, amount)
 	}
 }
 
@@ -306,17 +353,19 @@ impl<
 		AccountId,
 	> MutateHold<AccountId> for ItemOf<F, A, AccountId>
 {
-	fn hold(who: &AccountId, amount: Self::Balance) -> DispatchResult {
-		<F as fungibles::MutateHold<AccountId>>::hold(A::get(), who, amount)
+	fn hold(reason: &Self::Reason, who: &AccountId, amount: Self::Balance) -> DispatchResult {
+		<F as fungibles::MutateHold<AccountId>>::hold(A::get(), reason, who, amount)
 	}
 	fn release(
+		reason: &Self::Reason,
 		who: &AccountId,
 		amount: Self::Balance,
 		best_effort: bool,
 	) -> Result<Self::Balance, DispatchError> {
-		<F as fungibles::MutateHold<AccountId>>::release(A::get(), who, amount, best_effort)
+		<F as fungibles::MutateHold<AccountId>>::release(A::get(), reason, who, amount, best_effort)
 	}
 	fn transfer_held(
+		reason: &Self::Reason,
 		source: &AccountId,
 		dest: &AccountId,
 		amount: Self::Balance,
@@ -325,6 +374,7 @@ impl<
 	) -> Result<Self::Balance, DispatchError> {
 		<F as fungibles::MutateHold<AccountId>>::transfer_held(
 			A::get(),
+			reason,
 			source,
 			dest,
 			amount,
@@ -334,6 +384,39 @@ impl<
 	}
 }
 
+impl<
+		F: fungibles::InspectFreeze<AccountId>,
+		A: Get<<F as fungibles::Inspect<AccountId>>::AssetId>,
+		AccountId,
+	> InspectFreeze<AccountId> for ItemOf<F, A, AccountId>
+{
+	type Id = <F as fungibles::InspectFreeze<AccountId>>::Id;
+
+	fn balance_frozen(id: &Self::Id, who: &AccountId) -> Self::Balance {
+		<F as fungibles::InspectFreeze<AccountId>>::balance_frozen(A::get(), id, who)
+	}
+	fn can_freeze(id: &Self::Id, who: &AccountId) -> bool {
+		<F as fungibles::InspectFreeze<AccountId>>::can_freeze(A::get(), id, who)
+	}
+}
+
+impl<
+		F: fungibles::MutateFreeze<AccountId>,
+		A: Get<<F as fungibles::Inspect<AccountId>>::AssetId>,
+		AccountId,
+	> MutateFreeze<AccountId> for ItemOf<F, A, AccountId>
+{
+	fn set_freeze(id: &Self::Id, who: &AccountId, amount: Self::Balance) -> DispatchResult {
+		<F as fungibles::MutateFreeze<AccountId>>::set_freeze(A::get(), id, who, amount)
+	}
+	fn extend_freeze(id: &Self::Id, who: &AccountId, amount: Self::Balance) -> DispatchResult {
+		<F as fungibles::MutateFreeze<AccountId>>::extend_freeze(A::get(), id, who, amount)
+	}
+	fn thaw(id: &Self::Id, who: &AccountId) -> DispatchResult {
+		<F as fungibles::MutateFreeze<AccountId>>::thaw(A::get(), id, who)
+	}
+}
+
 impl<
 		F: fungibles::Unbalanced<AccountId>,
 		A: Get<<F as fungibles::Inspect<AccountId>>::AssetId>,
@@ -22,6 +22,8 @@ use super::{
 	*,
 };
 use crate::dispatch::{DispatchError, DispatchResult};
+use codec::Encode;
+use scale_info::TypeInfo;
 use sp_runtime::traits::Saturating;
 use sp_std::vec::Vec;
 
@@ -195,35 +197,59 @@ pub trait Transfer<AccountId>: Inspect<AccountId> {
 }
 
 /// Trait for inspecting a set of named fungible assets which can be placed on hold.
+///
+/// Funds are held for a `Reason`, so that funds held for one purpose cannot be released or slashed
+/// for another.
 pub trait InspectHold<AccountId>: Inspect<AccountId> {
-	/// Amount of funds held in hold.
-	fn balance_on_hold(asset: Self::AssetId, who: &AccountId) -> Self::Balance;
+	/// An identifier for the reason of a hold.
+	type Reason: Encode + TypeInfo + 'static;
 
-	/// Check to see if some `amount` of `asset` may be held on the account of `who`.
-	fn can_hold(asset: Self::AssetId, who: &AccountId, amount: Self::Balance) -> bool;
+	/// Amount of funds of `asset` on hold of `who`, for all reasons.
+	fn total_balance_on_hold(asset: Self::AssetId, who: &AccountId) -> Self::Balance;
+
+	/// Amount of funds of `asset` on hold of `who` for the given `reason`.
+	fn balance_on_hold(
+		asset: Self::AssetId,
+		reason: &Self::Reason,
+		who: &AccountId,
+	) -> Self::Balance;
+
+	/// Check to see if some `amount` of `asset` may be held for `reason` on the account of `who`.
+	fn can_hold(
+		asset: Self::AssetId,
+		reason: &Self::Reason,
+		who: &AccountId,
+		amount: Self::Balance,
+	) -> bool;
 }
 
 /// Trait for mutating a set of named fungible assets which can be placed on hold.
 pub trait MutateHold<AccountId>: InspectHold<AccountId> + Transfer<AccountId> {
-	/// Hold some funds in an account.
-	fn hold(asset: Self::AssetId, who: &AccountId, amount: Self::Balance) -> DispatchResult;
+	/// Hold some funds in an account for `reason`.
+	fn hold(
+		asset: Self::AssetId,
+		reason: &Self::Reason,
+		who: &AccountId,
+		amount: Self::Balance,
+	) -> DispatchResult;
 
-	/// Release some funds in an account from being on hold.
+	/// Release some funds held for `reason` in an account.
 	///
 	/// If `best_effort` is `true`, then the amount actually released and returned as the inner
 	/// value of `Ok` may be smaller than the `amount` passed.
 	fn release(
 		asset: Self::AssetId,
+		reason: &Self::Reason,
 		who: &AccountId,
 		amount: Self::Balance,
 		best_effort: bool,
 	) -> Result<Self::Balance, DispatchError>;
 
-	/// Transfer held funds into a destination account.
+	/// Transfer funds held for `reason` into a destination account.
 	///
 	/// If `on_hold` is `true`, then the destination account must already exist and the assets
-	/// transferred will still be on hold in the destination account. If not, then the destination
-	/// account need not already exist, but must be creatable.
+	/// transferred will still be on hold for `reason` in the destination account. If not, then the
+	/// destination account need not already exist, but must be creatable.
 	///
 	/// If `best_effort` is `true`, then an amount less than `amount` may be transferred without
 	/// error.
@@ -232,6 +258,7 @@ pub trait MutateHold<AccountId>: InspectHold<AccountId> + Transfer<AccountId> {
 	/// changed.
 	fn transfer_held(
 		asset: Self::AssetId,
+		reason: &Self::Reason,
 		source: &AccountId,
 		dest: &AccountId,
 		amount: Self::Balance,
@@ -240,9 +267,50 @@ pub trait MutateHold<AccountId>: InspectHold<AccountId> + Transfer<AccountId> {
 	) -> Result<Self::Balance, DispatchError>;
 }
 
+/// Trait for inspecting a set of named fungible assets which can be frozen.
+///
+/// A freeze sets a minimum below which the balance of an account may not drop. Freezes overlap:
+/// the largest one is what is in effect.
+pub trait InspectFreeze<AccountId>: Inspect<AccountId> {
+	/// An identifier for a freeze.
+	type Id: Encode + TypeInfo + 'static;
+
+	/// Amount of funds of `asset` of `who` frozen by `id`.
+	fn balance_frozen(asset: Self::AssetId, id: &Self::Id, who: &AccountId) -> Self::Balance;
+
+	/// Returns `true` if it's possible to introduce a freeze for `id` onto the `asset` account of
+	/// `who`. This will be `true` if it already has a freeze for `id` or if there is room for
+	/// another.
+	fn can_freeze(asset: Self::AssetId, id: &Self::Id, who: &AccountId) -> bool;
+}
+
+/// Trait for introducing, altering and removing freezes on a set of named fungible assets.
+pub trait MutateFreeze<AccountId>: InspectFreeze<AccountId> {
+	/// Prevent actions which would reduce the `asset` balance of `who` below `amount`, replacing
+	/// any existing freeze for `id`.
+	fn set_freeze(
+		asset: Self::AssetId,
+		id: &Self::Id,
+		who: &AccountId,
+		amount: Self::Balance,
+	) -> DispatchResult;
+
+	/// Prevent actions which would reduce the `asset` balance of `who` below `amount`. If a freeze
+	/// for `id` already exists, it is only increased, never reduced.
+	fn extend_freeze(
+		asset: Self::AssetId,
+		id: &Self::Id,
+		who: &AccountId,
+		amount: Self::Balance,
+	) -> DispatchResult;
+
+	/// Remove the freeze for `id` from the `asset` account of `who`.
+	fn thaw(asset: Self::AssetId, id: &Self::Id, who: &AccountId) -> DispatchResult;
+}
+
 /// Trait for mutating one of several types of fungible assets which can be held.
 pub trait BalancedHold<AccountId>: Balanced<AccountId> + MutateHold<AccountId> {
-	/// Release and slash some funds in an account.
+	/// Release and slash some funds held for `reason` in an account.
 	///
 	/// The resulting imbalance is the first item of the tuple returned.
 	///
@@ -250,6 +318,7 @@ pub trait BalancedHold<AccountId>: Balanced<AccountId> + MutateHold<AccountId> {
 	/// then a non-zero second item will be returned.
 	fn slash_held(
 		asset: Self::AssetId,
+		reason: &Self::Reason,
 		who: &AccountId,
 		amount: Self::Balance,
 	) -> (CreditOf<AccountId, Self>, Self::Balance);
@@ -258,10 +327,11 @@ pub trait BalancedHold<AccountId>: Balanced<AccountId> + MutateHold<AccountId> {
 impl<AccountId, T: Balanced<AccountId> + MutateHold<AccountId>> BalancedHold<AccountId> for T {
 	fn slash_held(
 		asset: Self::AssetId,
+		reason: &Self::Reason,
 		who: &AccountId,
 		amount: Self::Balance,
 	) -> (CreditOf<AccountId, Self>, Self::Balance) {
-		let actual = match Self::release(asset, who, amount, true) {
+		let actual = match Self::release(asset, reason, who, amount, true) {
 			Ok(x) => x,
 			Err(_) => return (Imbalance::zero(asset), amount),
 		};
@@ -138,7 +138,7 @@ impl<Tally, Moment, Class> PollStatus<Tally, Moment, Class> {
 pub struct ClassCountOf<P, T>(sp_std::marker::PhantomData<(P, T)>);
 impl<T, P: Polling<T>> sp_runtime::traits::Get<u32> for ClassCountOf<P, T> {
 	fn get() -> u32 {
-		P::classes().len() as u32
+		P::max_classes()
 	}
 }
 
@@ -151,6 +151,13 @@ pub trait Polling<Tally> {
 	/// Provides a vec of values that `T` may take.
 	fn classes() -> Vec<Self::Class>;
 
+	/// The maximum number of classes there may ever be, by default the number of `classes()`.
+	///
+	/// This bounds storage items holding a value per class, so it must never decrease.
+	fn max_classes() -> u32 {
+		Self::classes().len() as u32
+	}
+
 	/// `Some` if the referendum `index` can be voted on, along with the tally and class of
 	/// referendum.
 	///
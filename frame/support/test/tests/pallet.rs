@@ -410,6 +410,11 @@ pub mod pallet {
 	)]
 	pub struct Origin<T>(PhantomData<T>);
 
+	#[pallet::composite_enum]
+	pub enum HoldReason {
+		Staking,
+	}
+
 	#[pallet::validate_unsigned]
 	impl<T: Config> ValidateUnsigned for Pallet<T>
 	where
@@ -748,6 +753,20 @@ fn error_expand() {
 	assert_eq!(<pallet::Error::<Runtime> as PalletError>::MAX_ENCODED_SIZE, 3);
 }
 
+#[test]
+fn composite_expand() {
+	use codec::Encode;
+
+	let hold_reason: RuntimeHoldReason = pallet::HoldReason::Staking.into();
+	assert_eq!(hold_reason, RuntimeHoldReason::Example(pallet::HoldReason::Staking));
+	// Variants are indexed by the pallet index.
+	assert_eq!(hold_reason.encode(), vec![1, 0]);
+	assert_eq!(
+		TryInto::<pallet::HoldReason>::try_into(hold_reason),
+		Ok(pallet::HoldReason::Staking)
+	);
+}
+
 #[test]
 fn instance_expand() {
 	// Assert same type.
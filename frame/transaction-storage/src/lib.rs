@@ -83,6 +83,18 @@ fn num_chunks(bytes: u32) -> u32 {
 	((bytes as u64 + CHUNK_SIZE as u64 - 1) / CHUNK_SIZE as u64) as u32
 }
 
+/// Prefix of the binary encoded CIDv1 of raw data hashed with Blake2b-256: the CID version, the
+/// `raw` multicodec, and the multihash code and length.
+const CID_PREFIX: [u8; 6] = [0x01, 0x55, 0xa0, 0xe4, 0x02, 0x20];
+
+/// Binary encoded CID of the data stored with `content_hash`.
+///
+/// Offchain workers retrieve the data from the nodes serving it over bitswap by passing the CID
+/// to `sp_io::offchain::bitswap_get`.
+pub fn content_cid(content_hash: &[u8; 32]) -> Vec<u8> {
+	CID_PREFIX.iter().chain(content_hash).copied().collect()
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -61,6 +61,31 @@ fn setup_pot_account<T: Config<I>, I: 'static>() {
 	let _ = T::Currency::make_free_balance_be(&pot_account, value);
 }
 
+// Create an asset spend of an asset held by the treasury account, bypassing `SpendOrigin`.
+fn create_asset_spend<T: Config<I>, I: 'static>() -> (SpendIndex, T::AccountId) {
+	let amount: AssetBalanceOf<T, I> = 100u32.into();
+	let asset_id = T::BenchmarkHelper::create_funded_asset(
+		&Treasury::<T, I>::account_id(),
+		amount.saturating_mul(10u32.into()),
+	);
+	let beneficiary: T::AccountId = account("beneficiary", 0, SEED);
+	let now = frame_system::Pallet::<T>::block_number();
+	let index = AssetSpendCount::<T, I>::get();
+	AssetSpends::<T, I>::insert(
+		index,
+		AssetSpend {
+			asset_id,
+			amount,
+			beneficiary: beneficiary.clone(),
+			valid_from: now,
+			expire_at: now.saturating_add(T::PayoutPeriod::get()),
+			status: PaymentState::Pending,
+		},
+	);
+	AssetSpendCount::<T, I>::put(index + 1);
+	(index, beneficiary)
+}
+
 fn assert_last_event<T: Config<I>, I: 'static>(generic_event: <T as Config<I>>::RuntimeEvent) {
 	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
 }
@@ -138,5 +163,65 @@ benchmarks_instance_pallet! {
 		Treasury::<T, _>::on_initialize(T::BlockNumber::zero());
 	}
 
+	// This benchmark is short-circuited if `SpendOrigin` cannot provide
+	// a successful origin, in which case `spend_asset` is un-callable and can use weight=0.
+	spend_asset {
+		let amount: AssetBalanceOf<T, I> = 1u32.into();
+		let asset_id = T::BenchmarkHelper::create_funded_asset(&Treasury::<T, I>::account_id(), amount);
+		let beneficiary: T::AccountId = account("beneficiary", 0, SEED);
+		let beneficiary_lookup = T::Lookup::unlookup(beneficiary.clone());
+		let origin = T::SpendOrigin::try_successful_origin();
+		let call = Call::<T, I>::spend_asset {
+			asset_id,
+			amount,
+			beneficiary: beneficiary_lookup,
+			valid_from: None,
+		};
+	}: {
+		if let Ok(origin) = origin.clone() {
+			call.dispatch_bypass_filter(origin)?;
+		}
+	}
+	verify {
+		if origin.is_ok() {
+			let valid_from = frame_system::Pallet::<T>::block_number();
+			let expire_at = valid_from.saturating_add(T::PayoutPeriod::get());
+			assert_last_event::<T, I>(Event::AssetSpendApproved {
+				index: 0,
+				asset_id,
+				amount,
+				beneficiary,
+				valid_from,
+				expire_at,
+			}.into())
+		}
+	}
+
+	payout {
+		let (index, _) = create_asset_spend::<T, I>();
+		let caller: T::AccountId = account("caller", 0, SEED);
+	}: _(RawOrigin::Signed(caller), index)
+	verify {
+		assert_last_event::<T, I>(Event::Paid { index }.into());
+	}
+
+	check_status {
+		let (index, _) = create_asset_spend::<T, I>();
+		let caller: T::AccountId = account("caller", 0, SEED);
+		Treasury::<T, I>::payout(RawOrigin::Signed(caller.clone()).into(), index)?;
+	}: _(RawOrigin::Signed(caller), index)
+	verify {
+		assert_last_event::<T, I>(Event::SpendProcessed { index }.into());
+	}
+
+	void_spend {
+		let (index, _) = create_asset_spend::<T, I>();
+		let reject_origin =
+			T::RejectOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(reject_origin, index)
+	verify {
+		assert!(AssetSpends::<T, I>::get(index).is_none());
+	}
+
 	impl_benchmark_test_suite!(Treasury, crate::tests::new_test_ext(), crate::tests::Test);
 }
@@ -52,6 +52,12 @@
 //! - `approve_proposal` - Accept the proposal, returning the deposit.
 //! - `remove_approval` - Remove an approval, the deposit will no longer be returned.
 //!
+//! Spending of fungible assets held by the treasury account (e.g. system tokens):
+//! - `spend_asset` - Approve a spend of an amount of an asset, valid for `PayoutPeriod` blocks.
+//! - `payout` - Pay out an approved asset spend, or retry a failed payout.
+//! - `check_status` - Remove a paid or expired asset spend from storage.
+//! - `void_spend` - Cancel an asset spend that has not been paid out.
+//!
 //! ## GenesisConfig
 //!
 //! The Treasury pallet depends on the [`GenesisConfig`].
@@ -68,15 +74,15 @@ use scale_info::TypeInfo;
 
 use sp_runtime::{
 	traits::{AccountIdConversion, CheckedAdd, Saturating, StaticLookup, Zero},
-	Permill, RuntimeDebug,
+	PerThing, Perbill, Permill, Rounding, RuntimeDebug,
 };
 use sp_std::{collections::btree_map::BTreeMap, prelude::*};
 
 use frame_support::{
 	print,
 	traits::{
-		Currency, ExistenceRequirement::KeepAlive, Get, Imbalance, OnUnbalanced,
-		ReservableCurrency, WithdrawReasons,
+		fungibles, tokens::BalanceConversion, Currency, ExistenceRequirement::KeepAlive, Get,
+		Imbalance, OnUnbalanced, ReservableCurrency, WithdrawReasons,
 	},
 	weights::Weight,
 	PalletId,
@@ -93,6 +99,18 @@ pub type PositiveImbalanceOf<T, I = ()> = <<T as Config<I>>::Currency as Currenc
 pub type NegativeImbalanceOf<T, I = ()> = <<T as Config<I>>::Currency as Currency<
 	<T as frame_system::Config>::AccountId,
 >>::NegativeImbalance;
+pub type AssetIdOf<T, I = ()> = <<T as Config<I>>::Assets as fungibles::Inspect<
+	<T as frame_system::Config>::AccountId,
+>>::AssetId;
+pub type AssetBalanceOf<T, I = ()> = <<T as Config<I>>::Assets as fungibles::Inspect<
+	<T as frame_system::Config>::AccountId,
+>>::Balance;
+pub type AssetSpendOf<T, I = ()> = AssetSpend<
+	AssetIdOf<T, I>,
+	AssetBalanceOf<T, I>,
+	<T as frame_system::Config>::AccountId,
+	<T as frame_system::Config>::BlockNumber,
+>;
 type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
 
 /// A trait to allow the Treasury Pallet to spend it's funds for other purposes.
@@ -133,10 +151,84 @@ pub struct Proposal<AccountId, Balance> {
 	bond: Balance,
 }
 
+/// An index of an asset spend. Just a `u32`.
+pub type SpendIndex = u32;
+
+/// The state of the payout of an asset spend.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub enum PaymentState {
+	/// The spend has not been paid out yet.
+	Pending,
+	/// The spend has been paid out to the beneficiary.
+	Paid,
+	/// The last payout failed. It can be retried until the spend expires.
+	Failed,
+	/// The spend was not paid out before it expired.
+	Expired,
+}
+
+/// A spend of a fungible asset from the treasury account.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub struct AssetSpend<AssetId, AssetBalance, AccountId, BlockNumber> {
+	/// The asset to be paid out.
+	pub asset_id: AssetId,
+	/// The amount of the asset to be paid out.
+	pub amount: AssetBalance,
+	/// The account to whom the payment should be made.
+	pub beneficiary: AccountId,
+	/// The block from which the spend can be paid out.
+	pub valid_from: BlockNumber,
+	/// The block from which the spend can no longer be paid out.
+	pub expire_at: BlockNumber,
+	/// The state of the payout.
+	pub status: PaymentState,
+}
+
+/// Helper to create and fund assets for benchmarks.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AssetId, AccountId, AssetBalance> {
+	/// Create an asset that can be spent by the treasury, give `amount` of it to `who` and
+	/// return its id.
+	fn create_funded_asset(who: &AccountId, amount: AssetBalance) -> AssetId;
+}
+
+/// Creates the sufficient asset `1` of `Assets` with `who` as its owner if it does not exist yet
+/// and mints the requested amount into the account of `who`.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct CreateFundedAsset<Assets>(sp_std::marker::PhantomData<Assets>);
+
+#[cfg(feature = "runtime-benchmarks")]
+impl<Assets, AccountId>
+	BenchmarkHelper<
+		<Assets as fungibles::Inspect<AccountId>>::AssetId,
+		AccountId,
+		<Assets as fungibles::Inspect<AccountId>>::Balance,
+	> for CreateFundedAsset<Assets>
+where
+	Assets: fungibles::Create<AccountId> + fungibles::Mutate<AccountId>,
+	<Assets as fungibles::Inspect<AccountId>>::AssetId: From<u32>,
+	AccountId: Clone,
+{
+	fn create_funded_asset(
+		who: &AccountId,
+		amount: <Assets as fungibles::Inspect<AccountId>>::Balance,
+	) -> <Assets as fungibles::Inspect<AccountId>>::AssetId {
+		let id = 1u32.into();
+		if !Assets::asset_exists(id) {
+			Assets::create(id, who.clone(), true, sp_runtime::traits::One::one())
+				.expect("the asset does not exist yet; qed");
+		}
+		Assets::mint_into(id, who, amount).expect("the asset exists; qed");
+		id
+	}
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
-	use frame_support::{dispatch_context::with_context, pallet_prelude::*};
+	use frame_support::{
+		dispatch_context::with_context, pallet_prelude::*, storage::with_storage_layer,
+	};
 	use frame_system::pallet_prelude::*;
 
 	#[pallet::pallet]
@@ -205,6 +297,29 @@ pub mod pallet {
 		/// process. The `Success` value is the maximum amount that this origin is allowed to
 		/// spend at a time.
 		type SpendOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = BalanceOf<Self, I>>;
+
+		/// The fungible assets that can be spent from the treasury account, e.g. system tokens.
+		type Assets: fungibles::Transfer<Self::AccountId>;
+
+		/// Converts the native balance limit of the `SpendOrigin` into an amount of an asset, to
+		/// check asset spends against it.
+		type BalanceConverter: BalanceConversion<
+			BalanceOf<Self, I>,
+			AssetIdOf<Self, I>,
+			AssetBalanceOf<Self, I>,
+		>;
+
+		/// The number of blocks during which an approved asset spend can be paid out.
+		#[pallet::constant]
+		type PayoutPeriod: Get<Self::BlockNumber>;
+
+		/// Helper to create funded assets for benchmarks.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<
+			AssetIdOf<Self, I>,
+			Self::AccountId,
+			AssetBalanceOf<Self, I>,
+		>;
 	}
 
 	/// Number of proposals that have been made.
@@ -234,6 +349,15 @@ pub mod pallet {
 	pub type Approvals<T: Config<I>, I: 'static = ()> =
 		StorageValue<_, BoundedVec<ProposalIndex, T::MaxApprovals>, ValueQuery>;
 
+	/// Number of asset spends that have been made.
+	#[pallet::storage]
+	pub(crate) type AssetSpendCount<T, I = ()> = StorageValue<_, SpendIndex, ValueQuery>;
+
+	/// Asset spends that have been approved and not yet removed.
+	#[pallet::storage]
+	pub type AssetSpends<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, SpendIndex, AssetSpendOf<T, I>, OptionQuery>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig;
 
@@ -295,6 +419,25 @@ pub mod pallet {
 		},
 		/// The inactive funds of the pallet have been updated.
 		UpdatedInactive { reactivated: BalanceOf<T, I>, deactivated: BalanceOf<T, I> },
+		/// A new asset spend has been approved.
+		AssetSpendApproved {
+			index: SpendIndex,
+			asset_id: AssetIdOf<T, I>,
+			amount: AssetBalanceOf<T, I>,
+			beneficiary: T::AccountId,
+			valid_from: T::BlockNumber,
+			expire_at: T::BlockNumber,
+		},
+		/// An asset spend has been paid out.
+		Paid { index: SpendIndex },
+		/// The payout of an asset spend failed. It can be retried until the spend expires.
+		PaymentFailed { index: SpendIndex, error: DispatchError },
+		/// An asset spend expired before it was paid out.
+		SpendExpired { index: SpendIndex },
+		/// A paid or expired asset spend has been removed from storage.
+		SpendProcessed { index: SpendIndex },
+		/// An asset spend has been voided.
+		AssetSpendVoided { index: SpendIndex },
 	}
 
 	/// Error for the treasury pallet.
@@ -311,6 +454,16 @@ pub mod pallet {
 		InsufficientPermission,
 		/// Proposal has not been approved.
 		ProposalNotApproved,
+		/// The native balance could not be converted into an amount of the asset.
+		FailedToConvertBalance,
+		/// The asset spend is not valid yet.
+		EarlyPayout,
+		/// The asset spend has already been paid out.
+		AlreadyPaid,
+		/// The asset spend has expired.
+		SpendExpired,
+		/// The asset spend has neither been paid out nor expired.
+		SpendNotConcluded,
 	}
 
 	#[pallet::hooks]
@@ -438,27 +591,7 @@ pub mod pallet {
 			beneficiary: AccountIdLookupOf<T>,
 		) -> DispatchResult {
 			let max_amount = T::SpendOrigin::ensure_origin(origin)?;
-			ensure!(amount <= max_amount, Error::<T, I>::InsufficientPermission);
-
-			with_context::<SpendContext<BalanceOf<T, I>>, _>(|v| {
-				let context = v.or_default();
-
-				// We group based on `max_amount`, to dinstinguish between different kind of
-				// origins. (assumes that all origins have different `max_amount`)
-				//
-				// Worst case is that we reject some "valid" request.
-				let spend = context.spend_in_context.entry(max_amount).or_default();
-
-				// Ensure that we don't overflow nor use more than `max_amount`
-				if spend.checked_add(&amount).map(|s| s > max_amount).unwrap_or(true) {
-					Err(Error::<T, I>::InsufficientPermission)
-				} else {
-					*spend = spend.saturating_add(amount);
-
-					Ok(())
-				}
-			})
-			.unwrap_or(Ok(()))?;
+			Self::ensure_spend_allowed(amount, max_amount)?;
 
 			let beneficiary = T::Lookup::lookup(beneficiary)?;
 			let proposal_index = Self::proposal_count();
@@ -509,6 +642,203 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Approve a spend of an amount of a fungible asset held by the treasury account.
+		///
+		/// - `origin`: Must be `SpendOrigin` with the `Success` value, converted into the asset by
+		///   `BalanceConverter`, being at least `amount`.
+		/// - `asset_id`: The asset to be paid out.
+		/// - `amount`: The amount of the asset to be transferred to the `beneficiary`.
+		/// - `beneficiary`: The destination account for the transfer.
+		/// - `valid_from`: The block from which the spend can be paid out. Defaults to the current
+		///   block.
+		///
+		/// Within one dispatch, e.g. a batch, spends of all assets and native spends count against
+		/// the same limit, each valued at the share of the limit it uses.
+		///
+		/// The spend must be paid out with `payout` before `valid_from + PayoutPeriod`.
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::spend_asset())]
+		pub fn spend_asset(
+			origin: OriginFor<T>,
+			asset_id: AssetIdOf<T, I>,
+			#[pallet::compact] amount: AssetBalanceOf<T, I>,
+			beneficiary: AccountIdLookupOf<T>,
+			valid_from: Option<T::BlockNumber>,
+		) -> DispatchResult {
+			let max_amount = T::SpendOrigin::ensure_origin(origin)?;
+			Self::ensure_asset_spend_allowed(asset_id, amount, max_amount)?;
+
+			let beneficiary = T::Lookup::lookup(beneficiary)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			let valid_from = valid_from.unwrap_or(now);
+			let expire_at = valid_from.saturating_add(T::PayoutPeriod::get());
+			ensure!(expire_at > now, Error::<T, I>::SpendExpired);
+
+			let index = AssetSpendCount::<T, I>::get();
+			AssetSpends::<T, I>::insert(
+				index,
+				AssetSpend {
+					asset_id,
+					amount,
+					beneficiary: beneficiary.clone(),
+					valid_from,
+					expire_at,
+					status: PaymentState::Pending,
+				},
+			);
+			AssetSpendCount::<T, I>::put(index + 1);
+
+			Self::deposit_event(Event::AssetSpendApproved {
+				index,
+				asset_id,
+				amount,
+				beneficiary,
+				valid_from,
+				expire_at,
+			});
+			Ok(())
+		}
+
+		/// Pay out an approved asset spend, or retry a payout that failed.
+		///
+		/// May be called by any signed origin.
+		///
+		/// The transfer from the treasury account is attempted immediately. If it fails, the spend
+		/// is marked as `Failed` and `PaymentFailed` is emitted; the call itself succeeds so that
+		/// the failure is recorded. A spend reaching its expiry is marked as `Expired` instead.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::payout())]
+		pub fn payout(origin: OriginFor<T>, index: SpendIndex) -> DispatchResult {
+			ensure_signed(origin)?;
+			let mut spend = AssetSpends::<T, I>::get(index).ok_or(Error::<T, I>::InvalidIndex)?;
+			match spend.status {
+				PaymentState::Pending | PaymentState::Failed => {},
+				PaymentState::Paid => return Err(Error::<T, I>::AlreadyPaid.into()),
+				PaymentState::Expired => return Err(Error::<T, I>::SpendExpired.into()),
+			}
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(now >= spend.valid_from, Error::<T, I>::EarlyPayout);
+
+			if now >= spend.expire_at {
+				spend.status = PaymentState::Expired;
+				Self::deposit_event(Event::SpendExpired { index });
+			} else {
+				// Roll back any partial change of the transfer, only the status is recorded on
+				// failure.
+				match with_storage_layer(|| {
+					<T::Assets as fungibles::Transfer<_>>::transfer(
+						spend.asset_id,
+						&Self::account_id(),
+						&spend.beneficiary,
+						spend.amount,
+						true,
+					)
+				}) {
+					Ok(_) => {
+						spend.status = PaymentState::Paid;
+						Self::deposit_event(Event::Paid { index });
+					},
+					Err(error) => {
+						spend.status = PaymentState::Failed;
+						Self::deposit_event(Event::PaymentFailed { index, error });
+					},
+				}
+			}
+			AssetSpends::<T, I>::insert(index, spend);
+			Ok(())
+		}
+
+		/// Remove an asset spend that has been paid out or has expired.
+		///
+		/// May be called by any signed origin. The fee is refunded if the spend is removed.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::check_status())]
+		pub fn check_status(origin: OriginFor<T>, index: SpendIndex) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			let spend = AssetSpends::<T, I>::get(index).ok_or(Error::<T, I>::InvalidIndex)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			match spend.status {
+				PaymentState::Paid | PaymentState::Expired => {},
+				PaymentState::Pending | PaymentState::Failed if now >= spend.expire_at =>
+					Self::deposit_event(Event::SpendExpired { index }),
+				PaymentState::Pending | PaymentState::Failed =>
+					return Err(Error::<T, I>::SpendNotConcluded.into()),
+			}
+
+			AssetSpends::<T, I>::remove(index);
+			Self::deposit_event(Event::SpendProcessed { index });
+			Ok(Pays::No.into())
+		}
+
+		/// Void an asset spend that has not been paid out.
+		///
+		/// May only be called from `T::RejectOrigin`.
+		#[pallet::call_index(8)]
+		#[pallet::weight((T::WeightInfo::void_spend(), DispatchClass::Operational))]
+		pub fn void_spend(origin: OriginFor<T>, index: SpendIndex) -> DispatchResult {
+			T::RejectOrigin::ensure_origin(origin)?;
+			let spend = AssetSpends::<T, I>::get(index).ok_or(Error::<T, I>::InvalidIndex)?;
+			ensure!(spend.status != PaymentState::Paid, Error::<T, I>::AlreadyPaid);
+
+			AssetSpends::<T, I>::remove(index);
+			Self::deposit_event(Event::AssetSpendVoided { index });
+			Ok(())
+		}
+	}
+
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
+		/// Ensure that the `SpendOrigin` allowed to spend `max_amount` may spend `amount`, also
+		/// accounting for the other spends made by origins with the same limit in the current
+		/// dispatch context.
+		fn ensure_spend_allowed(
+			amount: BalanceOf<T, I>,
+			max_amount: BalanceOf<T, I>,
+		) -> Result<(), Error<T, I>> {
+			ensure!(amount <= max_amount, Error::<T, I>::InsufficientPermission);
+
+			with_context::<SpendContext<BalanceOf<T, I>>, _>(|v| {
+				let context = v.or_default();
+
+				// We group based on `max_amount`, to dinstinguish between different kind of
+				// origins. (assumes that all origins have different `max_amount`)
+				//
+				// Worst case is that we reject some "valid" request.
+				let spend = context.spend_in_context.entry(max_amount).or_default();
+
+				// Ensure that we don't overflow nor use more than `max_amount`
+				if spend.checked_add(&amount).map(|s| s > max_amount).unwrap_or(true) {
+					Err(Error::<T, I>::InsufficientPermission)
+				} else {
+					*spend = spend.saturating_add(amount);
+
+					Ok(())
+				}
+			})
+			.unwrap_or(Ok(()))
+		}
+
+		/// Ensure that the `SpendOrigin` allowed to spend `max_amount` may spend `amount` of
+		/// `asset_id`.
+		///
+		/// The spend is valued as the share of `max_amount` it uses, so that it counts against the
+		/// same native-denominated total as the native spends and the spends of any other asset
+		/// made by origins with the same limit in the current dispatch context.
+		fn ensure_asset_spend_allowed(
+			asset_id: AssetIdOf<T, I>,
+			amount: AssetBalanceOf<T, I>,
+			max_amount: BalanceOf<T, I>,
+		) -> Result<(), Error<T, I>> {
+			let max_asset_amount = T::BalanceConverter::to_asset_balance(max_amount, asset_id)
+				.map_err(|_| Error::<T, I>::FailedToConvertBalance)?;
+			ensure!(amount <= max_asset_amount, Error::<T, I>::InsufficientPermission);
+
+			// Round up, so that splitting a spend into several ones can't exceed the limit.
+			let share =
+				Perbill::from_rational_with_rounding(amount, max_asset_amount, Rounding::Up)
+					.unwrap_or_else(|_| Perbill::one());
+			Self::ensure_spend_allowed(share.mul_ceil(max_amount), max_amount)
+		}
 	}
 }
 
@@ -29,7 +29,7 @@ use frame_support::{
 	assert_err_ignore_postinfo, assert_noop, assert_ok,
 	pallet_prelude::GenesisBuild,
 	parameter_types,
-	traits::{ConstU32, ConstU64, OnInitialize},
+	traits::{AsEnsureOriginWithArg, ConstU32, ConstU64, OnInitialize},
 	PalletId,
 };
 
@@ -51,6 +51,7 @@ frame_support::construct_runtime!(
 		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
 		Treasury: treasury::{Pallet, Call, Storage, Config, Event<T>},
 		Utility: pallet_utility,
+		Assets: pallet_assets::{Pallet, Call, Storage, Event<T>},
 	}
 );
 
@@ -84,6 +85,10 @@ impl pallet_balances::Config for Test {
 	type MaxLocks = ();
 	type MaxReserves = ();
 	type ReserveIdentifier = [u8; 8];
+	type HoldIdentifier = ();
+	type FreezeIdentifier = ();
+	type MaxHolds = ();
+	type MaxFreezes = ();
 	type Balance = u64;
 	type RuntimeEvent = RuntimeEvent;
 	type DustRemoval = ();
@@ -92,6 +97,34 @@ impl pallet_balances::Config for Test {
 	type WeightInfo = ();
 }
 
+impl pallet_assets::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = u64;
+	type AssetId = u32;
+	type AssetLink = ();
+	type AssetIdParameter = u32;
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<frame_system::EnsureSigned<u128>>;
+	type ForceOrigin = frame_system::EnsureRoot<u128>;
+	type AssetDeposit = ConstU64<1>;
+	type AssetAccountDeposit = ConstU64<1>;
+	type MetadataDepositBase = ConstU64<1>;
+	type MetadataDepositPerByte = ConstU64<1>;
+	type ApprovalDeposit = ConstU64<1>;
+	type StringLimit = ConstU32<50>;
+	type Freezer = ();
+	type HoldIdentifier = ();
+	type FreezeIdentifier = ();
+	type MaxHolds = ();
+	type MaxFreezes = ();
+	type WeightInfo = ();
+	type CallbackHandle = ();
+	type Extra = ();
+	type RemoveItemsLimit = ConstU32<5>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+}
+
 impl pallet_utility::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeCall = RuntimeCall;
@@ -123,6 +156,20 @@ impl frame_support::traits::EnsureOrigin<RuntimeOrigin> for TestSpendOrigin {
 	}
 }
 
+/// Values one unit of the native balance at two units of asset `1` and four units of asset `3`.
+/// Other assets cannot be converted.
+pub struct TestBalanceConverter;
+impl BalanceConversion<u64, u32, u64> for TestBalanceConverter {
+	type Error = ();
+	fn to_asset_balance(balance: u64, asset_id: u32) -> Result<u64, Self::Error> {
+		match asset_id {
+			1 => Ok(balance.saturating_mul(2)),
+			3 => Ok(balance.saturating_mul(4)),
+			_ => Err(()),
+		}
+	}
+}
+
 impl Config for Test {
 	type PalletId = TreasuryPalletId;
 	type Currency = pallet_balances::Pallet<Test>;
@@ -140,6 +187,11 @@ impl Config for Test {
 	type SpendFunds = ();
 	type MaxApprovals = ConstU32<100>;
 	type SpendOrigin = TestSpendOrigin;
+	type Assets = Assets;
+	type BalanceConverter = TestBalanceConverter;
+	type PayoutPeriod = ConstU64<10>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = CreateFundedAsset<Assets>;
 }
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
@@ -506,3 +558,230 @@ fn spending_in_batch_respects_max_total() {
 		);
 	})
 }
+
+fn create_funded_asset(id: u32, amount: u64) {
+	assert_ok!(Assets::force_create(RuntimeOrigin::root(), id, 0, true, 1));
+	assert_ok!(<Assets as fungibles::Mutate<_>>::mint_into(id, &Treasury::account_id(), amount));
+}
+
+fn last_event() -> RuntimeEvent {
+	System::events().pop().expect("RuntimeEvent expected").event
+}
+
+#[test]
+fn spend_asset_origin_permissioning_works() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(Treasury::spend_asset(RuntimeOrigin::signed(1), 1, 1, 6, None), BadOrigin);
+		// The limit of 5 is worth 10 units of asset 1.
+		assert_noop!(
+			Treasury::spend_asset(RuntimeOrigin::signed(10), 1, 11, 6, None),
+			Error::<Test>::InsufficientPermission
+		);
+		assert_noop!(
+			Treasury::spend_asset(RuntimeOrigin::signed(10), 2, 1, 6, None),
+			Error::<Test>::FailedToConvertBalance
+		);
+		assert_ok!(Treasury::spend_asset(RuntimeOrigin::signed(10), 1, 10, 6, None));
+	});
+}
+
+#[test]
+fn spend_asset_shares_limit_with_spend_in_batch() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		// 2 * 4 + 3 = 11 is above the limit of 5, which is worth 10 units of asset 1.
+		assert_err_ignore_postinfo!(
+			RuntimeCall::from(UtilityCall::batch_all {
+				calls: vec![
+					RuntimeCall::from(TreasuryCall::spend { amount: 4, beneficiary: 6 }),
+					RuntimeCall::from(TreasuryCall::spend_asset {
+						asset_id: 1,
+						amount: 3,
+						beneficiary: 6,
+						valid_from: None,
+					}),
+				]
+			})
+			.dispatch(RuntimeOrigin::signed(10)),
+			Error::<Test, _>::InsufficientPermission
+		);
+		// Spends of the same asset count against the limit together.
+		let spend_asset = |amount| {
+			RuntimeCall::from(TreasuryCall::spend_asset {
+				asset_id: 1,
+				amount,
+				beneficiary: 6,
+				valid_from: None,
+			})
+		};
+		assert_err_ignore_postinfo!(
+			RuntimeCall::from(UtilityCall::batch_all {
+				calls: vec![spend_asset(6), spend_asset(5)]
+			})
+			.dispatch(RuntimeOrigin::signed(10)),
+			Error::<Test, _>::InsufficientPermission
+		);
+		assert_ok!(RuntimeCall::from(UtilityCall::batch_all {
+			calls: vec![
+				RuntimeCall::from(TreasuryCall::spend { amount: 4, beneficiary: 6 }),
+				spend_asset(2),
+			]
+		})
+		.dispatch(RuntimeOrigin::signed(10)));
+	});
+}
+
+#[test]
+fn spend_asset_shares_limit_across_assets_in_batch() {
+	new_test_ext().execute_with(|| {
+		let spend_asset = |asset_id, amount| {
+			RuntimeCall::from(TreasuryCall::spend_asset {
+				asset_id,
+				amount,
+				beneficiary: 6,
+				valid_from: None,
+			})
+		};
+		// 6 units of asset 1 and 12 units of asset 3 are worth 3 + 3 = 6, above the limit of 5.
+		assert_err_ignore_postinfo!(
+			RuntimeCall::from(UtilityCall::batch_all {
+				calls: vec![spend_asset(1, 6), spend_asset(3, 12)]
+			})
+			.dispatch(RuntimeOrigin::signed(10)),
+			Error::<Test, _>::InsufficientPermission
+		);
+		// The order of the spends doesn't matter, nor does a native spend coming last.
+		assert_err_ignore_postinfo!(
+			RuntimeCall::from(UtilityCall::batch_all {
+				calls: vec![
+					spend_asset(3, 8),
+					RuntimeCall::from(TreasuryCall::spend { amount: 4, beneficiary: 6 }),
+				]
+			})
+			.dispatch(RuntimeOrigin::signed(10)),
+			Error::<Test, _>::InsufficientPermission
+		);
+		assert_ok!(RuntimeCall::from(UtilityCall::batch_all {
+			calls: vec![spend_asset(1, 4), spend_asset(3, 8), spend_asset(3, 4)]
+		})
+		.dispatch(RuntimeOrigin::signed(10)));
+	});
+}
+
+#[test]
+fn spend_asset_and_payout_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		create_funded_asset(1, 100);
+		assert_ok!(Treasury::spend_asset(RuntimeOrigin::signed(13), 1, 10, 6, Some(3)));
+		assert_eq!(
+			last_event(),
+			RuntimeEvent::Treasury(Event::AssetSpendApproved {
+				index: 0,
+				asset_id: 1,
+				amount: 10,
+				beneficiary: 6,
+				valid_from: 3,
+				expire_at: 13,
+			})
+		);
+
+		assert_noop!(Treasury::payout(RuntimeOrigin::signed(1), 0), Error::<Test>::EarlyPayout);
+		assert_noop!(Treasury::payout(RuntimeOrigin::signed(1), 1), Error::<Test>::InvalidIndex);
+
+		System::set_block_number(3);
+		assert_ok!(Treasury::payout(RuntimeOrigin::signed(1), 0));
+		assert_eq!(last_event(), RuntimeEvent::Treasury(Event::Paid { index: 0 }));
+		assert_eq!(Assets::balance(1, 6), 10);
+		assert_eq!(Assets::balance(1, Treasury::account_id()), 90);
+		assert_eq!(AssetSpends::<Test>::get(0).unwrap().status, PaymentState::Paid);
+		assert_noop!(Treasury::payout(RuntimeOrigin::signed(1), 0), Error::<Test>::AlreadyPaid);
+	});
+}
+
+#[test]
+fn failed_payout_can_be_retried() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Treasury::spend_asset(RuntimeOrigin::signed(13), 1, 10, 6, None));
+
+		// The asset does not exist yet, so the transfer fails.
+		assert_ok!(Treasury::payout(RuntimeOrigin::signed(1), 0));
+		assert!(matches!(
+			last_event(),
+			RuntimeEvent::Treasury(Event::PaymentFailed { index: 0, .. })
+		));
+		assert_eq!(AssetSpends::<Test>::get(0).unwrap().status, PaymentState::Failed);
+		assert_noop!(
+			Treasury::check_status(RuntimeOrigin::signed(1), 0),
+			Error::<Test>::SpendNotConcluded
+		);
+
+		create_funded_asset(1, 100);
+		assert_ok!(Treasury::payout(RuntimeOrigin::signed(1), 0));
+		assert_eq!(last_event(), RuntimeEvent::Treasury(Event::Paid { index: 0 }));
+		assert_eq!(Assets::balance(1, 6), 10);
+	});
+}
+
+#[test]
+fn spend_asset_expires() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		create_funded_asset(1, 100);
+		assert_ok!(Treasury::spend_asset(RuntimeOrigin::signed(13), 1, 10, 6, None));
+
+		System::set_block_number(11);
+		// A spend that would already be expired is rejected.
+		assert_noop!(
+			Treasury::spend_asset(RuntimeOrigin::signed(13), 1, 10, 6, Some(1)),
+			Error::<Test>::SpendExpired
+		);
+		assert_ok!(Treasury::payout(RuntimeOrigin::signed(1), 0));
+		assert_eq!(last_event(), RuntimeEvent::Treasury(Event::SpendExpired { index: 0 }));
+		assert_eq!(Assets::balance(1, 6), 0);
+		assert_noop!(Treasury::payout(RuntimeOrigin::signed(1), 0), Error::<Test>::SpendExpired);
+	});
+}
+
+#[test]
+fn check_status_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		create_funded_asset(1, 100);
+		assert_ok!(Treasury::spend_asset(RuntimeOrigin::signed(13), 1, 10, 6, None));
+		assert_ok!(Treasury::spend_asset(RuntimeOrigin::signed(13), 1, 10, 6, None));
+		assert_noop!(
+			Treasury::check_status(RuntimeOrigin::signed(1), 0),
+			Error::<Test>::SpendNotConcluded
+		);
+
+		assert_ok!(Treasury::payout(RuntimeOrigin::signed(1), 0));
+		let info = Treasury::check_status(RuntimeOrigin::signed(1), 0).unwrap();
+		assert_eq!(info.pays_fee, frame_support::dispatch::Pays::No);
+		assert_eq!(last_event(), RuntimeEvent::Treasury(Event::SpendProcessed { index: 0 }));
+		assert!(AssetSpends::<Test>::get(0).is_none());
+
+		// An unpaid spend can be removed once it has expired.
+		System::set_block_number(11);
+		assert_ok!(Treasury::check_status(RuntimeOrigin::signed(1), 1));
+		assert!(AssetSpends::<Test>::get(1).is_none());
+	});
+}
+
+#[test]
+fn void_spend_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		create_funded_asset(1, 100);
+		assert_ok!(Treasury::spend_asset(RuntimeOrigin::signed(13), 1, 10, 6, None));
+		assert_ok!(Treasury::spend_asset(RuntimeOrigin::signed(13), 1, 10, 6, None));
+		assert_ok!(Treasury::payout(RuntimeOrigin::signed(1), 1));
+
+		assert_noop!(Treasury::void_spend(RuntimeOrigin::signed(1), 0), BadOrigin);
+		assert_noop!(Treasury::void_spend(RuntimeOrigin::root(), 1), Error::<Test>::AlreadyPaid);
+		assert_ok!(Treasury::void_spend(RuntimeOrigin::root(), 0));
+		assert_eq!(last_event(), RuntimeEvent::Treasury(Event::AssetSpendVoided { index: 0 }));
+		assert_noop!(Treasury::payout(RuntimeOrigin::signed(1), 0), Error::<Test>::InvalidIndex);
+	});
+}
@@ -54,6 +54,10 @@ pub trait WeightInfo {
 	fn approve_proposal(p: u32, ) -> Weight;
 	fn remove_approval() -> Weight;
 	fn on_initialize_proposals(p: u32, ) -> Weight;
+	fn spend_asset() -> Weight;
+	fn payout() -> Weight;
+	fn check_status() -> Weight;
+	fn void_spend() -> Weight;
 }
 
 /// Weights for pallet_treasury using the Substrate node and recommended hardware.
@@ -152,6 +156,62 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(p.into())))
 			.saturating_add(Weight::from_parts(0, 7789).saturating_mul(p.into()))
 	}
+	/// Storage: Assets Asset (r:1 w:0)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Assets ParaFeeRate (r:1 w:0)
+	/// Proof: Assets ParaFeeRate (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Treasury AssetSpendCount (r:1 w:1)
+	/// Proof: Treasury AssetSpendCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Treasury AssetSpends (r:0 w:1)
+	/// Proof: Treasury AssetSpends (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	fn spend_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `377`
+		//  Estimated: `3683`
+		// Minimum execution time: 24_917 nanoseconds.
+		Weight::from_parts(25_480_000, 3683)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Treasury AssetSpends (r:1 w:1)
+	/// Proof: Treasury AssetSpends (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:2 w:2)
+	/// Proof: Assets Account (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn payout() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `705`
+		//  Estimated: `12990`
+		// Minimum execution time: 58_310 nanoseconds.
+		Weight::from_parts(59_537_000, 12990)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: Treasury AssetSpends (r:1 w:1)
+	/// Proof: Treasury AssetSpends (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	fn check_status() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `194`
+		//  Estimated: `2548`
+		// Minimum execution time: 15_427 nanoseconds.
+		Weight::from_parts(15_873_000, 2548)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Treasury AssetSpends (r:1 w:1)
+	/// Proof: Treasury AssetSpends (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	fn void_spend() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `194`
+		//  Estimated: `2548`
+		// Minimum execution time: 14_652 nanoseconds.
+		Weight::from_parts(15_110_000, 2548)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -249,4 +309,60 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(p.into())))
 			.saturating_add(Weight::from_parts(0, 7789).saturating_mul(p.into()))
 	}
+	/// Storage: Assets Asset (r:1 w:0)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Assets ParaFeeRate (r:1 w:0)
+	/// Proof: Assets ParaFeeRate (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Treasury AssetSpendCount (r:1 w:1)
+	/// Proof: Treasury AssetSpendCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Treasury AssetSpends (r:0 w:1)
+	/// Proof: Treasury AssetSpends (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	fn spend_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `377`
+		//  Estimated: `3683`
+		// Minimum execution time: 24_917 nanoseconds.
+		Weight::from_parts(25_480_000, 3683)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: Treasury AssetSpends (r:1 w:1)
+	/// Proof: Treasury AssetSpends (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: Assets Asset (r:1 w:1)
+	/// Proof: Assets Asset (max_values: None, max_size: Some(210), added: 2685, mode: MaxEncodedLen)
+	/// Storage: Assets Account (r:2 w:2)
+	/// Proof: Assets Account (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn payout() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `705`
+		//  Estimated: `12990`
+		// Minimum execution time: 58_310 nanoseconds.
+		Weight::from_parts(59_537_000, 12990)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: Treasury AssetSpends (r:1 w:1)
+	/// Proof: Treasury AssetSpends (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	fn check_status() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `194`
+		//  Estimated: `2548`
+		// Minimum execution time: 15_427 nanoseconds.
+		Weight::from_parts(15_873_000, 2548)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Treasury AssetSpends (r:1 w:1)
+	/// Proof: Treasury AssetSpends (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	fn void_spend() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `194`
+		//  Estimated: `2548`
+		// Minimum execution time: 14_652 nanoseconds.
+		Weight::from_parts(15_110_000, 2548)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
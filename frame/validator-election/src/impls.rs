@@ -1,4 +1,6 @@
 use crate::*;
+use frame_support::{traits::IsType, weights::Weight};
+use sp_runtime::SaturatedConversion;
 
 pub trait CollectiveInterface<AccountId> {
 	fn set_new_members(new: Vec<AccountId>);
@@ -8,6 +10,52 @@ impl<AccountId> CollectiveInterface<AccountId> for () {
 	fn set_new_members(_new: Vec<AccountId>) {}
 }
 
+/// Something that provides how much the vote of a collective member weighs.
+pub trait CollectiveVoteWeight<AccountId> {
+	/// The weight of `who`'s vote. A member of zero weight cannot sway a motion.
+	fn vote_weight(who: &AccountId) -> u32;
+
+	/// The weight of looking up the vote weights of `members` members.
+	fn lookup_weight(_members: u32) -> Weight {
+		Weight::zero()
+	}
+}
+
+/// Every member's vote counts once.
+impl<AccountId> CollectiveVoteWeight<AccountId> for () {
+	fn vote_weight(_who: &AccountId) -> u32 {
+		1
+	}
+}
+
+/// Weighs the votes of the elected validators by the outcome of the election.
+///
+/// Meant as the `VoteWeight` of the collective set as `CollectiveInterface`, whose members are the
+/// elected validators. A seed trust validator weighs `SeedTrustWeight`, a PoT validator weighs its
+/// vote points in `PotValidatorPool` (saturated to `u32`) and any other account weighs nothing.
+pub struct PotVoteWeight<T, SeedTrustWeight>(sp_std::marker::PhantomData<(T, SeedTrustWeight)>);
+
+impl<T: Config, SeedTrustWeight: Get<u32>> CollectiveVoteWeight<T::AccountId>
+	for PotVoteWeight<T, SeedTrustWeight>
+{
+	fn vote_weight(who: &T::AccountId) -> u32 {
+		if SeedTrustValidators::<T>::get().contains(who) {
+			return SeedTrustWeight::get()
+		}
+		PotValidatorPool::<T>::get()
+			.status
+			.iter()
+			.find(|(vote_account, _)| vote_account.into_ref() == who)
+			.map(|(_, points)| (*points).saturated_into())
+			.unwrap_or_default()
+	}
+
+	fn lookup_weight(members: u32) -> Weight {
+		// Each lookup reads `SeedTrustValidators` and `PotValidatorPool`.
+		T::DbWeight::get().reads(2 * members as u64)
+	}
+}
+
 pub trait SessionAlert<BlockNumber> {
 	/// Whether new session has triggered
 	fn is_new_session(n: BlockNumber) -> bool;
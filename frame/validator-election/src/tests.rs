@@ -108,3 +108,38 @@ fn pot_works() {
 			)
 		})
 }
+
+#[test]
+fn pot_vote_weight_works() {
+	frame_support::parameter_types! {
+		pub const SeedTrustWeight: u32 = 10;
+	}
+	type Weight = PotVoteWeight<TestRuntime, SeedTrustWeight>;
+
+	ExtBuilder::default()
+		.pot_enable(true)
+		.vote_status(|| {
+			let mut vote_status = create_mock_vote_status(2, true);
+			vote_status.increase_vote_point(sp_keyring::Sr25519Keyring::Ferdie.to_account_id());
+			vote_status
+		})
+		.build_and_execute(|| {
+			SeedTrustValidators::<TestRuntime>::put(vec![
+				sp_keyring::Sr25519Keyring::Alice.to_account_id()
+			]);
+			// Seed trust validators weigh the configured amount.
+			assert_eq!(Weight::vote_weight(&sp_keyring::Sr25519Keyring::Alice.to_account_id()), 10);
+			// PoT validators weigh their vote points.
+			assert_eq!(Weight::vote_weight(&sp_keyring::Sr25519Keyring::Dave.to_account_id()), 2);
+			assert_eq!(Weight::vote_weight(&sp_keyring::Sr25519Keyring::Ferdie.to_account_id()), 3);
+			// Anyone else weighs nothing.
+			assert_eq!(Weight::vote_weight(&sp_keyring::Sr25519Keyring::Eve.to_account_id()), 0);
+			// By default every member weighs the same.
+			assert_eq!(
+				<() as CollectiveVoteWeight<AccountId>>::vote_weight(
+					&sp_keyring::Sr25519Keyring::Eve.to_account_id()
+				),
+				1
+			);
+		})
+}
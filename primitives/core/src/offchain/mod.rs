@@ -182,6 +182,19 @@ impl TryFrom<u32> for HttpRequestStatus {
 	}
 }
 
+/// An error returned when retrieving a block over bitswap.
+#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug, Encode, Decode, PassByCodec)]
+pub enum BitswapError {
+	/// The block couldn't be retrieved within the deadline.
+	DeadlineReached,
+	/// The CID is malformed or doesn't reference a Blake2b-256 hash.
+	InvalidCid,
+	/// None of the connected peers returned the block.
+	NotFound,
+	/// The node can't retrieve blocks over bitswap.
+	Unavailable,
+}
+
 /// A blob to hold information about the local node's network state
 /// without committing to its format.
 #[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, PassByCodec, TypeInfo)]
@@ -278,6 +291,8 @@ bitflags::bitflags! {
 		const NODE_AUTHORIZATION = 0b0000_1000_0000;
 		/// Access time related functionality
 		const TIME = 0b0001_0000_0000;
+		/// Retrieve blocks from other nodes over bitswap
+		const BITSWAP = 0b0010_0000_0000;
 	}
 }
 
@@ -432,6 +447,18 @@ pub trait Externalities: Send {
 	/// - `authorized_only`: if true, only the authorized nodes are allowed to connect,
 	/// otherwise unauthorized nodes can also be connected through other mechanism.
 	fn set_authorized_nodes(&mut self, nodes: Vec<OpaquePeerId>, authorized_only: bool);
+
+	/// Retrieve the block referenced by the binary encoded `cid` from the connected peers.
+	///
+	/// The data is verified against the hash referenced by the CID, which must be a CIDv1 of a
+	/// Blake2b-256 hash, like the content hash of the transactions stored by
+	/// `pallet-transaction-storage`.
+	/// Passing `None` as a deadline blocks until every peer has been asked.
+	fn bitswap_get(
+		&mut self,
+		cid: &[u8],
+		deadline: Option<Timestamp>,
+	) -> Result<Vec<u8>, BitswapError>;
 }
 
 impl<T: Externalities + ?Sized> Externalities for Box<T> {
@@ -506,6 +533,14 @@ impl<T: Externalities + ?Sized> Externalities for Box<T> {
 	fn set_authorized_nodes(&mut self, nodes: Vec<OpaquePeerId>, authorized_only: bool) {
 		(&mut **self).set_authorized_nodes(nodes, authorized_only)
 	}
+
+	fn bitswap_get(
+		&mut self,
+		cid: &[u8],
+		deadline: Option<Timestamp>,
+	) -> Result<Vec<u8>, BitswapError> {
+		(&mut **self).bitswap_get(cid, deadline)
+	}
 }
 
 /// An `*Externalities` implementation with limited capabilities.
@@ -614,6 +649,15 @@ impl<T: Externalities> Externalities for LimitedExternalities<T> {
 		self.check(Capabilities::NODE_AUTHORIZATION, "set_authorized_nodes");
 		self.externalities.set_authorized_nodes(nodes, authorized_only)
 	}
+
+	fn bitswap_get(
+		&mut self,
+		cid: &[u8],
+		deadline: Option<Timestamp>,
+	) -> Result<Vec<u8>, BitswapError> {
+		self.check(Capabilities::BITSWAP, "bitswap_get");
+		self.externalities.bitswap_get(cid, deadline)
+	}
 }
 
 #[cfg(feature = "std")]
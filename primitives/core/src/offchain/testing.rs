@@ -22,7 +22,7 @@
 
 use crate::{
 	offchain::{
-		self, storage::InMemOffchainStorage, HttpError, HttpRequestId as RequestId,
+		self, storage::InMemOffchainStorage, BitswapError, HttpError, HttpRequestId as RequestId,
 		HttpRequestStatus as RequestStatus, OffchainOverlayedChange, OffchainStorage,
 		OpaqueNetworkState, StorageKind, Timestamp, TransactionPool,
 	},
@@ -134,6 +134,8 @@ pub struct OffchainState {
 	pub seed: [u8; 32],
 	/// A timestamp simulating the current time.
 	pub timestamp: Timestamp,
+	/// Blocks retrievable over bitswap, by binary encoded CID.
+	pub bitswap_blocks: BTreeMap<Vec<u8>, Vec<u8>>,
 }
 
 impl OffchainState {
@@ -353,6 +355,14 @@ impl offchain::Externalities for TestOffchainExt {
 	fn set_authorized_nodes(&mut self, _nodes: Vec<OpaquePeerId>, _authorized_only: bool) {
 		unimplemented!()
 	}
+
+	fn bitswap_get(
+		&mut self,
+		cid: &[u8],
+		_deadline: Option<Timestamp>,
+	) -> Result<Vec<u8>, BitswapError> {
+		self.0.read().bitswap_blocks.get(cid).cloned().ok_or(BitswapError::NotFound)
+	}
 }
 
 impl offchain::DbExternalities for TestOffchainExt {
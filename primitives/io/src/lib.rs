@@ -49,7 +49,8 @@ use sp_core::{
 	crypto::KeyTypeId,
 	ecdsa, ed25519,
 	offchain::{
-		HttpError, HttpRequestId, HttpRequestStatus, OpaqueNetworkState, StorageKind, Timestamp,
+		BitswapError, HttpError, HttpRequestId, HttpRequestStatus, OpaqueNetworkState, StorageKind,
+		Timestamp,
 	},
 	sr25519,
 	storage::StateVersion,
@@ -1419,6 +1420,21 @@ pub trait Offchain {
 			.expect("set_authorized_nodes can be called only in the offchain worker context")
 			.set_authorized_nodes(nodes, authorized_only)
 	}
+
+	/// Retrieve the block referenced by the binary encoded `cid` from the connected peers.
+	///
+	/// The CID must reference a Blake2b-256 hash. The data is verified against the hash before
+	/// being returned.
+	/// Passing `None` as a deadline blocks until every peer has been asked.
+	fn bitswap_get(
+		&mut self,
+		cid: &[u8],
+		deadline: Option<Timestamp>,
+	) -> Result<Vec<u8>, BitswapError> {
+		self.extension::<OffchainWorkerExt>()
+			.expect("bitswap_get can be called only in the offchain worker context")
+			.bitswap_get(cid, deadline)
+	}
 }
 
 /// Wasm only interface that provides functions for calling into the allocator.
@@ -359,6 +359,20 @@ pub struct Proof<Hash> {
 	pub items: Vec<Hash>,
 }
 
+/// An MMR ancestry proof, i.e. a proof that a prior MMR is a prefix of the current one.
+#[derive(codec::Encode, codec::Decode, RuntimeDebug, Clone, PartialEq, Eq, TypeInfo)]
+pub struct AncestryProof<Hash> {
+	/// The peaks of the prior MMR, from left to right.
+	pub prev_peaks: Vec<Hash>,
+	/// Number of leaves in the prior MMR.
+	pub prev_leaf_count: LeafIndex,
+	/// Number of leaves in MMR, when the proof was generated.
+	pub leaf_count: LeafIndex,
+	/// Proof elements (hashes of the nodes needed, besides the prior peaks, to build the peaks of
+	/// the MMR), in the order given by [`utils::NodesUtils::ancestry_merges`].
+	pub items: Vec<Hash>,
+}
+
 /// Merkle Mountain Range operation error.
 #[cfg_attr(feature = "std", derive(thiserror::Error))]
 #[derive(RuntimeDebug, codec::Encode, codec::Decode, PartialEq, Eq, TypeInfo)]
@@ -453,6 +467,15 @@ sp_api::decl_runtime_apis! {
 		/// same position in both the `leaves` vector and the `leaf_indices` vector contained in the [Proof]
 		fn verify_proof_stateless(root: Hash, leaves: Vec<EncodableOpaqueLeaf>, proof: Proof<Hash>)
 			-> Result<(), Error>;
+
+		/// Generate an MMR ancestry proof for the prior MMR with `prev_leaf_count` leaves. If
+		/// `best_known_block_number = Some(n)`, use historical MMR state at given block height `n`.
+		/// Else, use current MMR state.
+		#[api_version(3)]
+		fn generate_ancestry_proof(
+			prev_leaf_count: LeafIndex,
+			best_known_block_number: Option<BlockNumber>
+		) -> Result<AncestryProof<Hash>, Error>;
 	}
 }
 
@@ -21,6 +21,7 @@ use codec::Encode;
 use mmr_lib::helper;
 
 use sp_runtime::traits::{CheckedAdd, CheckedSub, Header, One};
+use sp_std::collections::btree_set::BTreeSet;
 #[cfg(not(feature = "std"))]
 use sp_std::prelude::Vec;
 
@@ -115,6 +116,70 @@ impl NodesUtils {
 		return (pos..=pos + num_parents).collect()
 	}
 
+	/// Starting from any leaf index, get the positions of the children of the inner nodes added
+	/// to the mmr when this leaf was added.
+	///
+	/// None of these nodes is needed to prove the leaves following this one, nor is any of them a
+	/// peak of an MMR containing this leaf.
+	pub fn children_of_right_branch_ending_in_leaf(leaf_index: LeafIndex) -> Vec<NodeIndex> {
+		let pos = helper::leaf_index_to_pos(leaf_index);
+		let num_parents = leaf_index.trailing_ones();
+		(1..=num_parents)
+			.flat_map(|height| {
+				let parent = pos + height as u64;
+				[parent - helper::parent_offset(height - 1), parent - 1]
+			})
+			.collect()
+	}
+
+	/// Get what is needed to build the peaks of the MMR from the peaks of the prior MMR with
+	/// `prev_leaf_count` leaves.
+	///
+	/// Returns the positions of the nodes to take, besides the prior peaks, and the sibling
+	/// nodes to merge in order, as `(left, right)` pairs. The parent of each pair is at
+	/// `right + 1`.
+	/// Returns `None` if the prior MMR is empty or not smaller than this one.
+	pub fn ancestry_merges(
+		&self,
+		prev_leaf_count: LeafIndex,
+	) -> Option<(Vec<NodeIndex>, Vec<(NodeIndex, NodeIndex)>)> {
+		if prev_leaf_count == 0 || prev_leaf_count > self.no_of_leaves {
+			return None
+		}
+
+		let peaks = helper::get_peaks(self.size());
+		let mut frontier = helper::get_peaks(NodesUtils::new(prev_leaf_count).size())
+			.into_iter()
+			.map(|pos| (helper::pos_height_in_tree(pos), pos))
+			.collect::<BTreeSet<_>>();
+		let mut reached_peaks = BTreeSet::new();
+		let mut items = Vec::new();
+		let mut merges = Vec::new();
+		// Lower nodes are merged first, so that the sibling of a node is only taken as an item
+		// if it can't be built from the prior peaks.
+		while let Some((height, pos)) = frontier.pop_first() {
+			if peaks.contains(&pos) {
+				reached_peaks.insert(pos);
+				continue
+			}
+			let (left, right) = if helper::pos_height_in_tree(pos + 1) > height {
+				(pos - helper::sibling_offset(height), pos)
+			} else {
+				(pos, pos + helper::sibling_offset(height))
+			};
+			let sibling = if left == pos { right } else { left };
+			if !frontier.remove(&(height, sibling)) {
+				items.push(sibling);
+			}
+			merges.push((left, right));
+			frontier.insert((height + 1, right + 1));
+		}
+		// The peaks over leaves added after the prior MMR are taken as they are.
+		items.extend(peaks.into_iter().filter(|pos| !reached_peaks.contains(pos)));
+
+		Some((items, merges))
+	}
+
 	/// Build offchain key from `parent_hash` of block that originally added node `pos` to MMR.
 	///
 	/// This combination makes the offchain (key,value) entry resilient to chain forks.
@@ -139,7 +204,12 @@ impl NodesUtils {
 #[cfg(test)]
 mod tests {
 	use super::*;
-	use mmr_lib::helper::leaf_index_to_pos;
+	use mmr_lib::{
+		helper::leaf_index_to_pos,
+		util::{MemMMR, MemStore},
+		MMRStore, Merge,
+	};
+	use sp_std::collections::btree_map::BTreeMap;
 
 	#[test]
 	fn should_calculate_node_index_from_leaf_index() {
@@ -177,6 +247,78 @@ mod tests {
 		}
 	}
 
+	struct TestMerge;
+
+	impl mmr_lib::Merge for TestMerge {
+		type Item = u64;
+
+		fn merge(left: &u64, right: &u64) -> mmr_lib::Result<u64> {
+			Ok(left.wrapping_mul(0x100000001b3).wrapping_add(*right).rotate_left(17))
+		}
+	}
+
+	fn test_mmr(leaves: u64) -> MemMMR<u64, TestMerge> {
+		let mut mmr = MemMMR::default();
+		for leaf in 0..leaves {
+			mmr.push(leaf).unwrap();
+		}
+		mmr
+	}
+
+	#[test]
+	fn should_keep_what_proves_later_leaves_when_pruning_children() {
+		let full = test_mmr(40);
+		for leaf_count in 1..=40 {
+			let size = NodesUtils::new(leaf_count).size();
+			for last_pruned in 0..leaf_count {
+				let pruned = (0..=last_pruned)
+					.flat_map(NodesUtils::children_of_right_branch_ending_in_leaf)
+					.collect::<BTreeSet<_>>();
+				let store = MemStore::default();
+				for pos in (0..size).filter(|pos| !pruned.contains(pos)) {
+					let elem = (&full.store()).get_elem(pos).unwrap().unwrap();
+					(&store).append(pos, vec![elem]).unwrap();
+				}
+				let mmr = MemMMR::<_, TestMerge>::new(size, store);
+				let root = mmr.get_root().unwrap();
+
+				for leaf in last_pruned + 1..leaf_count {
+					let pos = leaf_index_to_pos(leaf);
+					let proof = mmr.gen_proof(vec![pos]).unwrap();
+					assert!(proof.verify(root, vec![(pos, leaf)]).unwrap());
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn should_build_peaks_from_prior_peaks() {
+		let full = test_mmr(40);
+		let elem = |pos| (&full.store()).get_elem(pos).unwrap().unwrap();
+		for leaf_count in 1..=40 {
+			let nodes = NodesUtils::new(leaf_count);
+			assert_eq!(nodes.ancestry_merges(0), None);
+			assert_eq!(nodes.ancestry_merges(leaf_count + 1), None);
+
+			for prev_leaf_count in 1..=leaf_count {
+				let (items, merges) = nodes.ancestry_merges(prev_leaf_count).unwrap();
+				let mut known = helper::get_peaks(NodesUtils::new(prev_leaf_count).size())
+					.into_iter()
+					.chain(items)
+					.map(|pos| (pos, elem(pos)))
+					.collect::<BTreeMap<_, _>>();
+				for (left, right) in merges {
+					let parent = TestMerge::merge(&known[&left], &known[&right]).unwrap();
+					assert_eq!(parent, elem(right + 1));
+					known.insert(right + 1, parent);
+				}
+				for peak in helper::get_peaks(nodes.size()) {
+					assert_eq!(known.get(&peak), Some(&elem(peak)));
+				}
+			}
+		}
+	}
+
 	#[test]
 	fn should_calculate_depth_correctly() {
 		assert_eq!(